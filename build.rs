@@ -0,0 +1,64 @@
+//! Build script - expose Cargo metadata to `forge features`
+//!
+//! Reads the product edition from `[package.metadata.forge]` and the optional
+//! features from `[features]` in Cargo.toml, and passes them to the crate as
+//! `FORGE_EDITION`, `FORGE_BUILD` and `FORGE_CARGO_FEATURES` (`name:on` or
+//! `name:off`, comma-separated), so the report never drifts from the manifest.
+
+use std::env;
+use std::fs;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    let manifest = fs::read_to_string("Cargo.toml").expect("Cargo.toml is readable");
+    for (key, var) in [("edition", "FORGE_EDITION"), ("build", "FORGE_BUILD")] {
+        let value = section_value(&manifest, "package.metadata.forge", key)
+            .unwrap_or_else(|| panic!("[package.metadata.forge] must set {key}"));
+        println!("cargo:rustc-env={var}={value}");
+    }
+
+    // Cargo sets CARGO_FEATURE_<NAME> for each enabled feature
+    let features: Vec<String> = section_keys(&manifest, "features")
+        .filter(|name| *name != "default")
+        .map(|name| {
+            let var = format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"));
+            let state = if env::var_os(var).is_some() {
+                "on"
+            } else {
+                "off"
+            };
+            format!("{name}:{state}")
+        })
+        .collect();
+    println!(
+        "cargo:rustc-env=FORGE_CARGO_FEATURES={}",
+        features.join(",")
+    );
+}
+
+/// `key = value` lines of a `[section]`, skipping comments and blank lines
+fn section_lines<'a>(manifest: &'a str, section: &'a str) -> impl Iterator<Item = &'a str> {
+    let header = format!("[{section}]");
+    manifest
+        .lines()
+        .map(str::trim)
+        .skip_while(move |line| *line != header)
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Keys declared in a `[section]`
+fn section_keys<'a>(manifest: &'a str, section: &'a str) -> impl Iterator<Item = &'a str> {
+    section_lines(manifest, section)
+        .filter_map(|line| line.split_once('=').map(|(key, _)| key.trim()))
+}
+
+/// Unquoted string value of `key` in a `[section]`
+fn section_value<'a>(manifest: &'a str, section: &'a str, key: &str) -> Option<&'a str> {
+    section_lines(manifest, section)
+        .filter_map(|line| line.split_once('='))
+        .find(|(k, _)| k.trim() == key)
+        .map(|(_, value)| value.trim().trim_matches('"'))
+}
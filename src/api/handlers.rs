@@ -9,10 +9,14 @@ use axum::{extract::State, response::IntoResponse, Json};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::cli::commands::results::CalculationResult;
 use crate::cli::{
-    audit as cli_audit, calculate as cli_calculate, export as cli_export, import as cli_import,
-    validate as cli_validate,
+    audit as cli_audit, calculate as cli_calculate, calculate_core, export as cli_export,
+    import as cli_import, validate as cli_validate, CalculateOptions, ImportOptions,
 };
+use crate::core::CalculationCache;
+use crate::error::ForgeResult;
+use crate::parser::ParseLimits;
 
 use super::server::AppState;
 
@@ -167,7 +171,7 @@ pub struct ValidateResponse {
 pub async fn validate(Json(req): Json<ValidateRequest>) -> impl IntoResponse {
     let path = PathBuf::from(&req.file_path);
 
-    match cli_validate(&[path]) {
+    match cli_validate(&[path], false, false, ParseLimits::default()) {
         Ok(()) => Json(ApiResponse::ok(ValidateResponse {
             valid: true,
             file_path: req.file_path,
@@ -196,14 +200,55 @@ pub struct CalculateResponse {
     pub file_path: String,
     pub dry_run: bool,
     pub message: String,
+    /// Whether the result was served from the calculation cache
+    pub cached: bool,
+    /// Calculated values (cached dry runs only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<CalculationResult>,
 }
 
 /// POST /api/v1/calculate - Calculate formulas
-pub async fn calculate(Json(req): Json<CalculateRequest>) -> impl IntoResponse {
+///
+/// Dry runs go through the server's calculation cache when it is enabled;
+/// runs that write the file are always calculated.
+pub async fn calculate(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<CalculateRequest>,
+) -> impl IntoResponse {
     let path = PathBuf::from(&req.file_path);
     let dry_run = req.dry_run;
 
-    match cli_calculate(&path, dry_run, false, None) {
+    if let (true, Some(cache)) = (dry_run, &state.cache) {
+        return Json(match cached_dry_run(cache, &path) {
+            Ok((result, cached)) => ApiResponse::ok(CalculateResponse {
+                calculated: true,
+                file_path: req.file_path,
+                dry_run,
+                message: if cached {
+                    "Dry run served from cache".to_string()
+                } else {
+                    "Dry run completed".to_string()
+                },
+                cached,
+                result: Some(result),
+            }),
+            Err(e) => ApiResponse::ok(CalculateResponse {
+                calculated: false,
+                file_path: req.file_path,
+                dry_run,
+                message: format!("Error: {e}"),
+                ..CalculateResponse::default()
+            }),
+        });
+    }
+
+    match cli_calculate(
+        &path,
+        &CalculateOptions {
+            dry_run,
+            ..CalculateOptions::default()
+        },
+    ) {
         Ok(()) => Json(ApiResponse::ok(CalculateResponse {
             calculated: true,
             file_path: req.file_path,
@@ -213,16 +258,38 @@ pub async fn calculate(Json(req): Json<CalculateRequest>) -> impl IntoResponse {
             } else {
                 "Calculation completed and file updated".to_string()
             },
+            ..CalculateResponse::default()
         })),
         Err(e) => Json(ApiResponse::ok(CalculateResponse {
             calculated: false,
             file_path: req.file_path,
             dry_run,
             message: format!("Error: {e}"),
+            ..CalculateResponse::default()
         })),
     }
 }
 
+/// Dry-run `path` through `cache`; the flag is `true` on a cache hit
+///
+/// Models with `_includes` depend on other files, so their own content is
+/// not a safe key and they bypass the cache.
+fn cached_dry_run(
+    cache: &CalculationCache<CalculationResult>,
+    path: &std::path::Path,
+) -> ForgeResult<(CalculationResult, bool)> {
+    let content = std::fs::read_to_string(path)?;
+    if content.contains("_includes") {
+        return Ok((
+            calculate_core(path, true, None, ParseLimits::default())?,
+            false,
+        ));
+    }
+    cache.get_or_insert_with(&content, || {
+        calculate_core(path, true, None, ParseLimits::default())
+    })
+}
+
 /// Audit request
 #[derive(Deserialize)]
 pub struct AuditRequest {
@@ -244,7 +311,7 @@ pub async fn audit(Json(req): Json<AuditRequest>) -> impl IntoResponse {
     let path = PathBuf::from(&req.file_path);
     let variable = req.variable.clone();
 
-    match cli_audit(&path, &variable) {
+    match cli_audit(&path, &variable, ParseLimits::default()) {
         Ok(()) => Json(ApiResponse::ok(AuditResponse {
             audited: true,
             file_path: req.file_path,
@@ -281,7 +348,7 @@ pub async fn export(Json(req): Json<ExportRequest>) -> impl IntoResponse {
     let yaml_path = PathBuf::from(&req.yaml_path);
     let excel_path = PathBuf::from(&req.excel_path);
 
-    match cli_export(&yaml_path, &excel_path, false) {
+    match cli_export(&yaml_path, &excel_path, false, ParseLimits::default()) {
         Ok(()) => Json(ApiResponse::ok(ExportResponse {
             exported: true,
             yaml_path: req.yaml_path,
@@ -302,6 +369,14 @@ pub async fn export(Json(req): Json<ExportRequest>) -> impl IntoResponse {
 pub struct ImportRequest {
     pub excel_path: String,
     pub yaml_path: String,
+    /// Forced column types (`column=Type` or `table.column=Type`)
+    #[serde(default)]
+    pub types: Vec<String>,
+    /// Sheets to import (empty = all)
+    #[serde(default)]
+    pub sheets: Vec<String>,
+    /// Cell range read from each sheet (e.g. `A3:F100`)
+    pub range: Option<String>,
 }
 
 /// Import response
@@ -318,7 +393,17 @@ pub async fn import_excel(Json(req): Json<ImportRequest>) -> impl IntoResponse {
     let excel_path = PathBuf::from(&req.excel_path);
     let yaml_path = PathBuf::from(&req.yaml_path);
 
-    match cli_import(&excel_path, &yaml_path, false, false, false) {
+    match cli_import(
+        &excel_path,
+        &yaml_path,
+        &ImportOptions {
+            types: &req.types,
+            sheets: &req.sheets,
+            range: req.range.as_deref(),
+            ..ImportOptions::default()
+        },
+        false,
+    ) {
         Ok(()) => Json(ApiResponse::ok(ImportResponse {
             imported: true,
             excel_path: req.excel_path,
@@ -338,6 +423,13 @@ pub async fn import_excel(Json(req): Json<ImportRequest>) -> impl IntoResponse {
 mod tests {
     use super::*;
 
+    fn uncached_state() -> Arc<AppState> {
+        Arc::new(AppState {
+            version: "5.0.0".to_string(),
+            cache: None,
+        })
+    }
+
     // ==================== ApiResponse Tests ====================
 
     #[test]
@@ -538,6 +630,7 @@ mod tests {
             file_path: "model.yaml".to_string(),
             dry_run: false,
             message: "Calculation completed".to_string(),
+            ..CalculateResponse::default()
         };
         let json = serde_json::to_string(&response).unwrap();
 
@@ -626,6 +719,7 @@ mod tests {
 
         let state = Arc::new(AppState {
             version: "5.0.0".to_string(),
+            cache: None,
         });
 
         let response = version(State(state)).await;
@@ -640,6 +734,7 @@ mod tests {
 
         let state = Arc::new(AppState {
             version: "5.0.0".to_string(),
+            cache: None,
         });
 
         let response = root(State(state)).await;
@@ -686,7 +781,7 @@ mod tests {
             dry_run: true,
         };
 
-        let response = calculate(Json(req)).await;
+        let response = calculate(State(uncached_state()), Json(req)).await;
         let response = response.into_response();
 
         assert_eq!(response.status(), axum::http::StatusCode::OK);
@@ -701,12 +796,39 @@ mod tests {
             dry_run: true,
         };
 
-        let response = calculate(Json(req)).await;
+        let response = calculate(State(uncached_state()), Json(req)).await;
         let response = response.into_response();
 
         assert_eq!(response.status(), axum::http::StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_calculate_handler_caches_dry_runs() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let model = "_forge_version: \"5.0.0\"\nprice:\n  value: 10\ntotal:\n  value: null\n  formula: \"=price * 2\"\n";
+        std::fs::write(file.path(), model).unwrap();
+
+        let cache = Arc::new(CalculationCache::new(8, None));
+        let state = Arc::new(AppState {
+            version: "5.0.0".to_string(),
+            cache: Some(Arc::clone(&cache)),
+        });
+        let request = || CalculateRequest {
+            file_path: file.path().display().to_string(),
+            dry_run: true,
+        };
+
+        // Two identical requests: the second is a hit
+        calculate(State(Arc::clone(&state)), Json(request())).await;
+        calculate(State(Arc::clone(&state)), Json(request())).await;
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        // A changed model misses
+        std::fs::write(file.path(), model.replace("value: 10", "value: 12")).unwrap();
+        calculate(State(Arc::clone(&state)), Json(request())).await;
+        assert_eq!((cache.hits(), cache.misses()), (1, 2));
+    }
+
     #[tokio::test]
     async fn test_audit_handler() {
         use axum::response::IntoResponse;
@@ -778,6 +900,9 @@ mod tests {
         let req = ImportRequest {
             excel_path: "/nonexistent/file.xlsx".to_string(),
             yaml_path: "/tmp/test.yaml".to_string(),
+            types: Vec::new(),
+            sheets: Vec::new(),
+            range: None,
         };
 
         let response = import_excel(Json(req)).await;
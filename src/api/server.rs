@@ -8,6 +8,7 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::{
     routing::{get, post},
@@ -18,12 +19,18 @@ use tower_http::trace::TraceLayer;
 use tracing::info;
 
 use super::handlers;
+use crate::cli::commands::results::CalculationResult;
+use crate::core::CalculationCache;
 
 /// API Server configuration
 #[derive(Clone)]
 pub struct ApiConfig {
     pub host: String,
     pub port: u16,
+    /// Maximum number of cached calculation results (0 disables the cache)
+    pub cache_capacity: usize,
+    /// How long a cached result stays valid (`None`: until evicted)
+    pub cache_ttl: Option<Duration>,
 }
 
 impl Default for ApiConfig {
@@ -31,6 +38,8 @@ impl Default for ApiConfig {
         Self {
             host: "127.0.0.1".to_string(),
             port: 8080,
+            cache_capacity: 0,
+            cache_ttl: None,
         }
     }
 }
@@ -39,6 +48,8 @@ impl Default for ApiConfig {
 #[derive(Clone)]
 pub struct AppState {
     pub version: String,
+    /// Dry-run calculation results keyed by model hash, when caching is enabled
+    pub cache: Option<Arc<CalculationCache<CalculationResult>>>,
 }
 
 /// Run the API server
@@ -63,6 +74,12 @@ pub async fn run_api_server(config: ApiConfig) -> anyhow::Result<()> {
 
     let state = Arc::new(AppState {
         version: env!("CARGO_PKG_VERSION").to_string(),
+        cache: (config.cache_capacity > 0).then(|| {
+            Arc::new(CalculationCache::new(
+                config.cache_capacity,
+                config.cache_ttl,
+            ))
+        }),
     });
 
     // CORS configuration
@@ -161,6 +178,7 @@ mod tests {
         let config = ApiConfig {
             host: "0.0.0.0".to_string(),
             port: 3000,
+            ..ApiConfig::default()
         };
         assert_eq!(config.host, "0.0.0.0");
         assert_eq!(config.port, 3000);
@@ -179,6 +197,7 @@ mod tests {
         let config = ApiConfig {
             host: "192.168.1.100".to_string(),
             port: 9090,
+            ..ApiConfig::default()
         };
         let addr_str = format!("{}:{}", config.host, config.port);
         assert_eq!(addr_str, "192.168.1.100:9090");
@@ -194,6 +213,7 @@ mod tests {
     fn test_app_state_version() {
         let state = AppState {
             version: "2.0.0".to_string(),
+            cache: None,
         };
         assert_eq!(state.version, "2.0.0");
     }
@@ -202,6 +222,7 @@ mod tests {
     fn test_app_state_clone() {
         let state1 = AppState {
             version: "2.0.0".to_string(),
+            cache: None,
         };
         let state2 = state1.clone();
         assert_eq!(state1.version, state2.version);
@@ -211,6 +232,7 @@ mod tests {
     fn test_app_state_in_arc() {
         let state = Arc::new(AppState {
             version: "2.0.0".to_string(),
+            cache: None,
         });
         let state_clone = Arc::clone(&state);
         assert_eq!(state.version, state_clone.version);
@@ -223,6 +245,7 @@ mod tests {
     fn test_build_router() {
         let state = Arc::new(AppState {
             version: "5.0.0".to_string(),
+            cache: None,
         });
 
         // Build the router with explicit type
@@ -254,6 +277,7 @@ mod tests {
         let config = ApiConfig {
             host: "::1".to_string(),
             port: 8080,
+            ..ApiConfig::default()
         };
         let addr_str = format!("[{}]:{}", config.host, config.port);
         let addr: Result<SocketAddr, _> = addr_str.parse();
@@ -280,6 +304,7 @@ mod tests {
 
         let state = Arc::new(AppState {
             version: "5.0.0".to_string(),
+            cache: None,
         });
 
         let app = Router::new()
@@ -307,6 +332,7 @@ mod tests {
 
         let state = Arc::new(AppState {
             version: "5.0.0".to_string(),
+            cache: None,
         });
 
         let app = Router::new()
@@ -334,6 +360,7 @@ mod tests {
 
         let state = Arc::new(AppState {
             version: "5.0.0".to_string(),
+            cache: None,
         });
 
         let app = Router::new()
@@ -356,6 +383,7 @@ mod tests {
 
         let state = Arc::new(AppState {
             version: "5.0.0".to_string(),
+            cache: None,
         });
 
         let app = Router::new()
@@ -387,6 +415,7 @@ mod tests {
 
         let state = Arc::new(AppState {
             version: "5.0.0".to_string(),
+            cache: None,
         });
 
         let app = Router::new()
@@ -418,6 +447,7 @@ mod tests {
 
         let state = Arc::new(AppState {
             version: "5.0.0".to_string(),
+            cache: None,
         });
 
         let app = Router::new()
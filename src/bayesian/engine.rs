@@ -5,7 +5,7 @@
 use super::config::BayesianConfig;
 use super::inference::BeliefPropagation;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Query result for a variable
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,6 +61,21 @@ impl BayesianResult {
     }
 }
 
+/// Most probable explanation: the jointly most likely unobserved states
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapResult {
+    /// Network name
+    pub name: String,
+    /// Most probable state of each unobserved node
+    pub states: BTreeMap<String, String>,
+    /// Evidence used
+    pub evidence: HashMap<String, String>,
+    /// Joint probability of these states together with the evidence
+    pub joint_probability: f64,
+    /// Probability of these states given the evidence
+    pub posterior_probability: f64,
+}
+
 /// Bayesian Network Engine
 pub struct BayesianEngine {
     config: BayesianConfig,
@@ -117,24 +132,7 @@ impl BayesianEngine {
         target: &str,
         evidence: &HashMap<String, &str>,
     ) -> Result<VariableResult, String> {
-        // Convert evidence from state names to indices
-        let mut evidence_indices = HashMap::new();
-        for (var, state) in evidence {
-            let node = self
-                .config
-                .nodes
-                .get(var.as_str())
-                .ok_or_else(|| format!("Evidence variable '{var}' not found"))?;
-
-            let idx = node
-                .states
-                .iter()
-                .position(|s| s == state)
-                .ok_or_else(|| format!("State '{state}' not found for variable '{var}'"))?;
-
-            evidence_indices.insert(var.clone(), idx);
-        }
-
+        let evidence_indices = self.evidence_indices(evidence)?;
         let probs = self.bp.query_with_evidence(target, &evidence_indices)?;
 
         let node = self
@@ -228,6 +226,71 @@ impl BayesianEngine {
         Ok(explanation)
     }
 
+    /// Most probable explanation (MAP): the single most likely joint
+    /// assignment of every unobserved node given the evidence
+    ///
+    /// Unlike [`Self::most_likely_explanation`], states are chosen jointly by
+    /// max-product variable elimination, so the combination itself is the
+    /// most probable one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if evidence names an unknown variable or state, or the
+    /// evidence is impossible.
+    pub fn most_probable_explanation(
+        &self,
+        evidence: &HashMap<String, &str>,
+    ) -> Result<MapResult, String> {
+        let evidence_indices = self.evidence_indices(evidence)?;
+        let (assignment, joint_probability) =
+            self.bp.most_probable_explanation(&evidence_indices)?;
+        let evidence_probability = self.bp.evidence_probability(&evidence_indices);
+
+        let states = assignment
+            .into_iter()
+            .filter(|(var, _)| !evidence.contains_key(var))
+            .filter_map(|(var, idx)| {
+                let state = self.config.nodes.get(&var)?.states.get(idx)?.clone();
+                Some((var, state))
+            })
+            .collect();
+
+        Ok(MapResult {
+            name: self.config.name.clone(),
+            states,
+            evidence: evidence
+                .iter()
+                .map(|(k, v)| (k.clone(), (*v).to_string()))
+                .collect(),
+            joint_probability,
+            posterior_probability: joint_probability / evidence_probability,
+        })
+    }
+
+    /// Convert evidence from state names to state indices
+    fn evidence_indices(
+        &self,
+        evidence: &HashMap<String, &str>,
+    ) -> Result<HashMap<String, usize>, String> {
+        let mut evidence_indices = HashMap::new();
+        for (var, state) in evidence {
+            let node = self
+                .config
+                .nodes
+                .get(var.as_str())
+                .ok_or_else(|| format!("Evidence variable '{var}' not found"))?;
+
+            let idx = node
+                .states
+                .iter()
+                .position(|s| s == state)
+                .ok_or_else(|| format!("State '{state}' not found for variable '{var}'"))?;
+
+            evidence_indices.insert(var.clone(), idx);
+        }
+        Ok(evidence_indices)
+    }
+
     /// Get the configuration
     #[must_use]
     pub const fn config(&self) -> &BayesianConfig {
@@ -335,6 +398,33 @@ mod engine_tests {
         assert_eq!(mpe.get("economic_conditions"), Some(&"neutral".to_string()));
     }
 
+    #[test]
+    fn test_most_probable_explanation() {
+        let config = create_credit_risk_network();
+        let engine = BayesianEngine::new(config).unwrap();
+
+        let evidence = HashMap::from([("default_probability".to_string(), "high")]);
+        let map = engine.most_probable_explanation(&evidence).unwrap();
+
+        // Joint: P(bad, low, high) = 0.2 * 0.6 * 0.6 = 0.072 beats
+        // P(neutral, low, high) = 0.06, although neutral is the more likely
+        // economy on its own given a high default
+        assert_eq!(map.states["economic_conditions"], "bad");
+        assert_eq!(map.states["company_revenue"], "low");
+        assert!(!map.states.contains_key("default_probability"));
+        assert!((map.joint_probability - 0.072).abs() < 1e-10);
+        // P(default = high) = 0.045 + 0.1175 + 0.085
+        assert!((map.posterior_probability - 0.072 / 0.2475).abs() < 1e-10);
+
+        let marginal = engine
+            .query_with_evidence("economic_conditions", &evidence)
+            .unwrap();
+        assert_eq!(marginal.most_likely, "neutral");
+
+        let bad_state = HashMap::from([("default_probability".to_string(), "extreme")]);
+        assert!(engine.most_probable_explanation(&bad_state).is_err());
+    }
+
     #[test]
     fn test_yaml_export() {
         let config = create_credit_risk_network();
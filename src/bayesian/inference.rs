@@ -122,6 +122,17 @@ impl Factor {
     /// Marginalize (sum out) a variable
     #[must_use]
     pub fn marginalize(&self, var: &str) -> Self {
+        self.eliminate(var, |a, b| a + b)
+    }
+
+    /// Max out a variable, keeping the best value over its states
+    #[must_use]
+    pub fn maximize(&self, var: &str) -> Self {
+        self.eliminate(var, f64::max)
+    }
+
+    /// Remove a variable, combining the values across its states
+    fn eliminate(&self, var: &str, combine: fn(f64, f64) -> f64) -> Self {
         let Some(var_idx) = self.variables.iter().position(|v| v == var) else {
             return self.clone();
         };
@@ -143,11 +154,11 @@ impl Factor {
             .collect();
 
         if new_variables.is_empty() {
-            // Marginalizing the last variable
+            // Eliminating the last variable
             return Self {
                 variables: vec![],
                 cardinalities: vec![],
-                values: vec![self.values.iter().sum()],
+                values: vec![self.values.iter().copied().fold(0.0, combine)],
             };
         }
 
@@ -157,7 +168,7 @@ impl Factor {
         for i in 0..self.values.len() {
             let indices = Self::decode_index(i, &self.cardinalities);
 
-            // Get new index (without marginalized variable)
+            // Get new index (without eliminated variable)
             let new_idx_vec: Vec<usize> = indices
                 .iter()
                 .enumerate()
@@ -171,7 +182,7 @@ impl Factor {
                 Self::encode_index(&new_idx_vec, &new_cardinalities)
             };
 
-            new_values[new_idx] += self.values[i];
+            new_values[new_idx] = combine(new_values[new_idx], self.values[i]);
         }
 
         Self {
@@ -430,6 +441,85 @@ impl BeliefPropagation {
         }
     }
 
+    /// Most probable joint assignment of every discrete variable given evidence
+    ///
+    /// Max-product variable elimination over the same order as marginal
+    /// queries: each variable is maxed out of the product of the factors that
+    /// mention it, then states are recovered in reverse elimination order.
+    /// Returns the state index of each variable (evidence included) and the
+    /// joint probability of that assignment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the evidence has zero probability.
+    pub fn most_probable_explanation(
+        &self,
+        evidence: &HashMap<String, usize>,
+    ) -> Result<(HashMap<String, usize>, f64), String> {
+        let (probability, eliminated) = self.eliminate_all(evidence, Factor::maximize);
+        if probability <= 0.0 {
+            return Err("Evidence has zero probability in this network".to_string());
+        }
+
+        let mut assignment = HashMap::new();
+        for (var, product) in eliminated.iter().rev() {
+            let var_idx = product
+                .variables
+                .iter()
+                .position(|v| v == var)
+                .unwrap_or_default();
+            let mut best = (0, f64::NEG_INFINITY);
+            for state in 0..product.cardinalities[var_idx] {
+                assignment.insert(var.clone(), state);
+                let value = product.get_probability(&assignment);
+                if value > best.1 {
+                    best = (state, value);
+                }
+            }
+            assignment.insert(var.clone(), best.0);
+        }
+
+        Ok((assignment, probability))
+    }
+
+    /// Probability of the evidence, summed over every other variable
+    #[must_use]
+    pub fn evidence_probability(&self, evidence: &HashMap<String, usize>) -> f64 {
+        self.eliminate_all(evidence, Factor::marginalize).0
+    }
+
+    /// Eliminate every variable with `eliminate` (sum or max), returning the
+    /// resulting constant and each variable's factor product before removal
+    fn eliminate_all(
+        &self,
+        evidence: &HashMap<String, usize>,
+        eliminate: fn(&Factor, &str) -> Factor,
+    ) -> (f64, Vec<(String, Factor)>) {
+        let mut factors: Vec<Factor> = self
+            .factors
+            .iter()
+            .map(|f| Self::apply_evidence(f, evidence))
+            .collect();
+
+        let mut eliminated = Vec::new();
+        for var in self.get_elimination_order("") {
+            let (containing, remaining): (Vec<_>, Vec<_>) = factors
+                .into_iter()
+                .partition(|f| f.variables.contains(&var));
+            factors = remaining;
+
+            let Some((first, rest)) = containing.split_first() else {
+                continue;
+            };
+            let product = rest.iter().fold(first.clone(), |acc, f| acc.multiply(f));
+            factors.push(eliminate(&product, &var));
+            eliminated.push((var, product));
+        }
+
+        let constant: f64 = factors.iter().filter_map(|f| f.values.first()).product();
+        (constant, eliminated)
+    }
+
     /// Apply evidence to a factor
     fn apply_evidence(factor: &Factor, evidence: &HashMap<String, usize>) -> Factor {
         let mut new_values = factor.values.clone();
@@ -524,6 +614,40 @@ mod inference_tests {
         );
     }
 
+    #[test]
+    fn test_most_probable_explanation_is_joint() {
+        // The marginal favourites (a1: 0.6, b0: 0.58) are not the best joint
+        // assignment: P(a1, b0) = 0.18 < P(a0, b0) = 0.4 < P(a1, b1) = 0.42
+        let config = BayesianConfig::new("joint")
+            .with_node(
+                "a",
+                BayesianNode::discrete(vec!["a0", "a1"]).with_prior(vec![0.4, 0.6]),
+            )
+            .with_node(
+                "b",
+                BayesianNode::discrete(vec!["b0", "b1"])
+                    .with_parents(vec!["a"])
+                    .with_cpt_entry("a0", vec![1.0, 0.0])
+                    .with_cpt_entry("a1", vec![0.3, 0.7]),
+            );
+        let bp = BeliefPropagation::new(config).unwrap();
+
+        let (assignment, probability) = bp.most_probable_explanation(&HashMap::new()).unwrap();
+        assert_eq!(assignment["a"], 1);
+        assert_eq!(assignment["b"], 1);
+        assert!((probability - 0.42).abs() < 1e-10);
+
+        let evidence = HashMap::from([("b".to_string(), 0)]);
+        let (assignment, probability) = bp.most_probable_explanation(&evidence).unwrap();
+        assert_eq!(assignment["a"], 0);
+        assert_eq!(assignment["b"], 0);
+        assert!((probability - 0.4).abs() < 1e-10);
+        assert!((bp.evidence_probability(&evidence) - 0.58).abs() < 1e-10);
+
+        let impossible = HashMap::from([("a".to_string(), 0), ("b".to_string(), 1)]);
+        assert!(bp.most_probable_explanation(&impossible).is_err());
+    }
+
     #[test]
     fn test_evidence_query() {
         let config = create_simple_network();
@@ -25,6 +25,28 @@ pub enum BootstrapStatistic {
     Max,
 }
 
+/// Resampling scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BootstrapMethod {
+    /// Resample individual observations independently
+    #[default]
+    Iid,
+    /// Moving-block bootstrap: resample contiguous blocks to preserve autocorrelation
+    Block,
+}
+
+/// Confidence interval construction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BootstrapCiType {
+    /// Percentiles of the bootstrap distribution
+    #[default]
+    Percentile,
+    /// Bias-corrected and accelerated (`BCa`) percentiles
+    Bca,
+}
+
 /// Configuration for bootstrap resampling
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BootstrapConfig {
@@ -45,6 +67,14 @@ pub struct BootstrapConfig {
     /// Percentile value (if statistic is Percentile)
     #[serde(default = "default_percentile")]
     pub percentile_value: f64,
+    /// Resampling scheme
+    #[serde(default)]
+    pub method: BootstrapMethod,
+    /// Block length for `method: block` (defaults to ⌈n^(1/3)⌉)
+    pub block_size: Option<usize>,
+    /// Confidence interval method
+    #[serde(default)]
+    pub ci_type: BootstrapCiType,
 }
 
 const fn default_iterations() -> usize {
@@ -68,6 +98,9 @@ impl Default for BootstrapConfig {
             data: Vec::new(),
             statistic: BootstrapStatistic::Mean,
             percentile_value: 50.0,
+            method: BootstrapMethod::Iid,
+            block_size: None,
+            ci_type: BootstrapCiType::Percentile,
         }
     }
 }
@@ -122,13 +155,42 @@ impl BootstrapConfig {
         self
     }
 
+    /// Use the moving-block bootstrap with the given block length
+    #[must_use]
+    pub const fn with_block_size(mut self, block_size: usize) -> Self {
+        self.method = BootstrapMethod::Block;
+        self.block_size = Some(block_size);
+        self
+    }
+
+    /// Set the confidence interval method
+    #[must_use]
+    pub const fn with_ci_type(mut self, ci_type: BootstrapCiType) -> Self {
+        self.ci_type = ci_type;
+        self
+    }
+
+    /// Block length used by the moving-block bootstrap
+    // Data length is far below 2^52 and its cube root is non-negative
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    #[must_use]
+    pub fn effective_block_size(&self) -> usize {
+        self.block_size
+            .unwrap_or_else(|| (self.data.len() as f64).cbrt().ceil() as usize)
+    }
+
     /// Validate configuration
     ///
     /// # Errors
     ///
     /// Returns an error if data is empty or has fewer than 2 observations,
     /// iterations is zero, confidence levels are missing or out of range,
-    /// or percentile value is out of range when using `Percentile` statistic.
+    /// percentile value is out of range when using `Percentile` statistic,
+    /// or the block size is zero or longer than the data.
     pub fn validate(&self) -> Result<(), String> {
         if self.data.is_empty() {
             return Err("Data cannot be empty".to_string());
@@ -161,6 +223,16 @@ impl BootstrapConfig {
             ));
         }
 
+        if self.method == BootstrapMethod::Block {
+            let block_size = self.effective_block_size();
+            if block_size == 0 || block_size > self.data.len() {
+                return Err(format!(
+                    "Block size {block_size} must be between 1 and the number of observations ({})",
+                    self.data.len()
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -207,4 +279,29 @@ mod config_tests {
 
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_block_size_validation() {
+        let data: Vec<f64> = (1..=27).map(f64::from).collect();
+
+        let config = BootstrapConfig::new().with_data(data.clone());
+        assert_eq!(config.method, BootstrapMethod::Iid);
+
+        let mut config = config.with_block_size(5);
+        assert!(config.validate().is_ok());
+
+        config.block_size = None;
+        assert_eq!(config.effective_block_size(), 3);
+
+        assert!(BootstrapConfig::new()
+            .with_data(data.clone())
+            .with_block_size(0)
+            .validate()
+            .is_err());
+        assert!(BootstrapConfig::new()
+            .with_data(data)
+            .with_block_size(28)
+            .validate()
+            .is_err());
+    }
 }
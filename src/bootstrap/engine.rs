@@ -3,10 +3,11 @@
 //! Implements non-parametric bootstrap for confidence intervals.
 //! Validated against R's boot package.
 
-use super::config::{BootstrapConfig, BootstrapStatistic};
+use super::config::{BootstrapCiType, BootstrapConfig, BootstrapMethod, BootstrapStatistic};
 use rand::rngs::StdRng;
 use rand::{RngExt, SeedableRng};
 use serde::{Deserialize, Serialize};
+use statrs::distribution::{ContinuousCDF, Normal};
 
 /// A confidence interval
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,6 +51,9 @@ pub struct BootstrapResult {
     pub bias: f64,
     /// Confidence intervals
     pub confidence_intervals: Vec<ConfidenceInterval>,
+    /// Method used to build the intervals (`BCa` falls back to percentile
+    /// when the bias correction is undefined)
+    pub ci_type: BootstrapCiType,
     /// Bootstrap distribution (all resampled statistics)
     pub distribution: Vec<f64>,
     /// Number of bootstrap iterations
@@ -118,12 +122,17 @@ impl BootstrapEngine {
 
         for _ in 0..self.config.iterations {
             // Resample with replacement
-            let sample: Vec<f64> = (0..n)
-                .map(|_| {
-                    let idx = self.rng.random_range(0..n);
-                    data[idx]
-                })
-                .collect();
+            let sample: Vec<f64> = match self.config.method {
+                BootstrapMethod::Iid => (0..n)
+                    .map(|_| {
+                        let idx = self.rng.random_range(0..n);
+                        data[idx]
+                    })
+                    .collect(),
+                BootstrapMethod::Block => {
+                    resample_blocks(&mut self.rng, data, self.config.effective_block_size())
+                },
+            };
 
             let stat = self.compute_statistic(&sample);
             distribution.push(stat);
@@ -143,7 +152,8 @@ impl BootstrapEngine {
         let bias = bootstrap_mean - original_estimate;
 
         // Calculate confidence intervals
-        let confidence_intervals = self.calculate_confidence_intervals(&distribution);
+        let (ci_type, confidence_intervals) =
+            self.calculate_confidence_intervals(&distribution, original_estimate);
 
         Ok(BootstrapResult {
             original_estimate,
@@ -151,6 +161,7 @@ impl BootstrapEngine {
             bootstrap_std_error,
             bias,
             confidence_intervals,
+            ci_type,
             distribution,
             iterations: self.config.iterations,
         })
@@ -198,26 +209,83 @@ impl BootstrapEngine {
         }
     }
 
-    /// Calculate confidence intervals using percentile method
-    fn calculate_confidence_intervals(&self, distribution: &[f64]) -> Vec<ConfidenceInterval> {
-        self.config
+    /// Calculate confidence intervals using the percentile or `BCa` method
+    fn calculate_confidence_intervals(
+        &self,
+        distribution: &[f64],
+        original_estimate: f64,
+    ) -> (BootstrapCiType, Vec<ConfidenceInterval>) {
+        let adjustment = match self.config.ci_type {
+            BootstrapCiType::Percentile => None,
+            BootstrapCiType::Bca => self.bca_adjustment(distribution, original_estimate),
+        };
+
+        let intervals = self
+            .config
             .confidence_levels
             .iter()
             .map(|&level| {
                 let alpha = 1.0 - level;
-                // cast_possible_truncation: indices are bounded by [0, distribution.len()]
-                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-                let lower_idx = ((alpha / 2.0) * distribution.len() as f64) as usize;
-                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-                let upper_idx = ((1.0 - alpha / 2.0) * distribution.len() as f64) as usize;
+                let (lower_p, upper_p) =
+                    adjustment.map_or((alpha / 2.0, 1.0 - alpha / 2.0), |(z0, a)| {
+                        (
+                            bca_probability(alpha / 2.0, z0, a),
+                            bca_probability(1.0 - alpha / 2.0, z0, a),
+                        )
+                    });
 
                 ConfidenceInterval::new(
                     level,
-                    distribution[lower_idx.min(distribution.len() - 1)],
-                    distribution[upper_idx.min(distribution.len() - 1)],
+                    quantile(distribution, lower_p),
+                    quantile(distribution, upper_p),
                 )
             })
-            .collect()
+            .collect();
+
+        let ci_type = if adjustment.is_some() {
+            BootstrapCiType::Bca
+        } else {
+            BootstrapCiType::Percentile
+        };
+        (ci_type, intervals)
+    }
+
+    /// `BCa` bias-correction `z0` and jackknife acceleration `a`
+    ///
+    /// Returns `None` when every bootstrap statistic falls on one side of the
+    /// original estimate (z0 is infinite) or the jackknife is degenerate.
+    fn bca_adjustment(&self, distribution: &[f64], original_estimate: f64) -> Option<(f64, f64)> {
+        let below = distribution
+            .iter()
+            .filter(|&&x| x < original_estimate)
+            .count();
+        let proportion = below as f64 / distribution.len() as f64;
+        if proportion <= 0.0 || proportion >= 1.0 {
+            return None;
+        }
+        let z0 = Normal::standard().inverse_cdf(proportion);
+
+        // Jackknife: leave-one-out estimates of the statistic
+        let data = &self.config.data;
+        let jackknife: Vec<f64> = (0..data.len())
+            .map(|i| {
+                let mut sample = data.clone();
+                sample.remove(i);
+                self.compute_statistic(&sample)
+            })
+            .collect();
+        let jackknife_mean = jackknife.iter().sum::<f64>() / jackknife.len() as f64;
+        let (cubes, squares) = jackknife.iter().fold((0.0, 0.0), |(cubes, squares), x| {
+            let diff = jackknife_mean - x;
+            (diff.powi(3) + cubes, diff.mul_add(diff, squares))
+        });
+        let acceleration = if squares > 0.0 {
+            cubes / (6.0 * squares.powf(1.5))
+        } else {
+            0.0
+        };
+
+        acceleration.is_finite().then_some((z0, acceleration))
     }
 
     /// Get the configuration
@@ -227,6 +295,36 @@ impl BootstrapEngine {
     }
 }
 
+/// Moving-block resample: concatenate randomly chosen contiguous blocks,
+/// truncated to the original length
+fn resample_blocks(rng: &mut StdRng, data: &[f64], block_size: usize) -> Vec<f64> {
+    let n = data.len();
+    let starts = n - block_size + 1;
+
+    let mut sample = Vec::with_capacity(n + block_size);
+    while sample.len() < n {
+        let start = rng.random_range(0..starts);
+        sample.extend_from_slice(&data[start..start + block_size]);
+    }
+    sample.truncate(n);
+    sample
+}
+
+/// Adjusted percentile for `BCa`: `Φ(z0 + (z0 + z_p) / (1 - a (z0 + z_p)))`
+fn bca_probability(p: f64, z0: f64, acceleration: f64) -> f64 {
+    let normal = Normal::standard();
+    let shifted = z0 + normal.inverse_cdf(p);
+    normal.cdf(z0 + shifted / acceleration.mul_add(-shifted, 1.0))
+}
+
+/// Percentile of a sorted bootstrap distribution
+fn quantile(distribution: &[f64], p: f64) -> f64 {
+    // cast_possible_truncation: index is clamped to [0, distribution.len() - 1]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let idx = (p * distribution.len() as f64) as usize;
+    distribution[idx.min(distribution.len() - 1)]
+}
+
 #[cfg(test)]
 mod engine_tests {
     use super::*;
@@ -369,6 +467,95 @@ mod engine_tests {
         );
     }
 
+    #[test]
+    fn test_block_bootstrap_autocorrelated() {
+        use rand_distr::{Distribution as _, StandardNormal};
+
+        // AR(1) with phi = 0.8: long-run variance of the mean is ~9x the iid one
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut x = 0.0;
+        let data: Vec<f64> = (0..300)
+            .map(|_| {
+                let noise: f64 = StandardNormal.sample(&mut rng);
+                x = 0.8f64.mul_add(x, noise);
+                x
+            })
+            .collect();
+
+        let run = |config: BootstrapConfig| {
+            let config = config
+                .with_data(data.clone())
+                .with_iterations(4000)
+                .with_seed(42)
+                .with_confidence_levels(vec![0.95]);
+            let mut engine = BootstrapEngine::new(config).unwrap();
+            engine.analyze().unwrap().confidence_intervals[0].width()
+        };
+
+        let iid_width = run(BootstrapConfig::new());
+        let block_width = run(BootstrapConfig::new().with_block_size(15));
+
+        assert!(
+            block_width > 1.5 * iid_width,
+            "Block CI ({block_width}) should be much wider than iid CI ({iid_width})"
+        );
+    }
+
+    #[test]
+    fn test_bca_intervals_skewed() {
+        let data = vec![1.0, 1.0, 1.5, 2.0, 2.0, 3.0, 5.0, 8.0, 13.0, 40.0];
+        let run = |ci_type: BootstrapCiType| {
+            let config = BootstrapConfig::new()
+                .with_data(data.clone())
+                .with_iterations(10000)
+                .with_seed(12345)
+                .with_confidence_levels(vec![0.95])
+                .with_ci_type(ci_type);
+            BootstrapEngine::new(config).unwrap().analyze().unwrap()
+        };
+
+        let percentile = run(BootstrapCiType::Percentile);
+        let bca = run(BootstrapCiType::Bca);
+        assert_eq!(percentile.ci_type, BootstrapCiType::Percentile);
+        assert_eq!(bca.ci_type, BootstrapCiType::Bca);
+
+        // Right skew: positive bias correction and acceleration shift both bounds up
+        let (p, b) = (
+            &percentile.confidence_intervals[0],
+            &bca.confidence_intervals[0],
+        );
+        assert!(
+            b.lower >= p.lower,
+            "BCa lower {} < percentile {}",
+            b.lower,
+            p.lower
+        );
+        assert!(
+            b.upper > p.upper,
+            "BCa upper {} <= percentile {}",
+            b.upper,
+            p.upper
+        );
+        assert!(b.lower < bca.original_estimate && bca.original_estimate < b.upper);
+    }
+
+    #[test]
+    fn test_bca_falls_back_to_percentile() {
+        // Every resample has the same mean, so the bias correction is undefined
+        let config = BootstrapConfig::new()
+            .with_data(vec![2.0, 2.0, 2.0, 2.0])
+            .with_iterations(100)
+            .with_seed(1)
+            .with_ci_type(BootstrapCiType::Bca);
+
+        let result = BootstrapEngine::new(config).unwrap().analyze().unwrap();
+        assert_eq!(result.ci_type, BootstrapCiType::Percentile);
+        assert!(result
+            .confidence_intervals
+            .iter()
+            .all(|ci| ci.width().abs() < f64::EPSILON));
+    }
+
     #[test]
     fn test_yaml_export() {
         let config = BootstrapConfig::new()
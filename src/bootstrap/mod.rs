@@ -2,6 +2,7 @@
 //!
 //! Non-parametric uncertainty quantification via resampling:
 //! - Resample from historical data with replacement
+//! - Moving-block resampling for autocorrelated series
 //! - No distribution assumptions required
 //! - Confidence intervals from empirical distribution
 //! - R boot package validated calculations
@@ -18,6 +19,8 @@
 //!     historical_returns: [0.05, -0.02, 0.08, 0.03, -0.05, 0.12, 0.01, -0.01, 0.06, 0.04]
 //!
 //!   statistic: mean  # or median, std, var, percentile
+//!   method: iid      # or block (with block_size) for time series
+//!   ci_type: percentile  # or bca (bias-corrected and accelerated)
 //! ```
 //!
 //! See ADR for architecture decisions.
@@ -26,7 +29,7 @@ pub mod config;
 pub mod engine;
 
 // Re-exports
-pub use config::{BootstrapConfig, BootstrapStatistic};
+pub use config::{BootstrapCiType, BootstrapConfig, BootstrapMethod, BootstrapStatistic};
 pub use engine::{BootstrapEngine, BootstrapResult, ConfidenceInterval};
 
 #[cfg(test)]
@@ -1,13 +1,20 @@
 //! Analysis commands: variance, sensitivity, `goal_seek`, `break_even`, compare
 
+use crate::cli::envelope;
 use crate::core::ArrayCalculator;
 use crate::error::{ForgeError, ForgeResult};
-use crate::parser;
+use crate::parser::ParseLimits;
+use crate::scenarios::config::ScalarOverride;
+use crate::scenarios::{ScenarioConfig, ScenarioEngine};
+use crate::types::ParsedModel;
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
 use std::fmt::Write as _;
 use std::fs;
 use std::path::Path;
 
+use super::heatmap::{render_csv, render_heatmap};
+use super::prediction::load_scenario_config;
 use super::{apply_scenario, format_number};
 
 /// Variance result for a single variable
@@ -31,10 +38,11 @@ pub struct VarianceResult {
 pub fn compare_core(
     file: &Path,
     scenarios: &[String],
+    limits: ParseLimits,
 ) -> ForgeResult<super::results::ComparisonResult> {
     use std::collections::HashMap;
 
-    let base_model = parser::parse_model(file)?;
+    let base_model = super::load_model(file, limits)?;
 
     for scenario_name in scenarios {
         if !base_model.scenarios.contains_key(scenario_name) {
@@ -84,16 +92,21 @@ pub fn compare_core(
 ///
 /// Returns an error if the file cannot be parsed, a scenario does not exist,
 /// or calculation fails.
-pub fn compare(file: &Path, scenarios: &[String], verbose: bool) -> ForgeResult<()> {
-    println!("{}", "🔥 Forge - Scenario Comparison".bold().green());
-    println!("   File: {}", file.display());
-    println!(
+pub fn compare(
+    file: &Path,
+    scenarios: &[String],
+    verbose: bool,
+    limits: ParseLimits,
+) -> ForgeResult<()> {
+    outln!("{}", "🔥 Forge - Scenario Comparison".bold().green());
+    outln!("   File: {}", file.display());
+    outln!(
         "   Scenarios: {}\n",
         scenarios.join(", ").bright_yellow().bold()
     );
 
     // Parse model
-    let base_model = parser::parse_model(file)?;
+    let base_model = super::load_model(file, limits)?;
 
     // Validate scenarios exist
     for scenario_name in scenarios {
@@ -106,7 +119,7 @@ pub fn compare(file: &Path, scenarios: &[String], verbose: bool) -> ForgeResult<
     }
 
     if verbose {
-        println!(
+        outln!(
             "   Found {} tables, {} scalars, {} scenarios",
             base_model.tables.len(),
             base_model.scalars.len(),
@@ -135,37 +148,37 @@ pub fn compare(file: &Path, scenarios: &[String], verbose: bool) -> ForgeResult<
     all_scalars.dedup();
 
     // Print comparison table
-    println!("\n{}", "📊 Scenario Comparison:".bold().cyan());
-    println!("{}", "─".repeat(20 + scenarios.len() * 15));
+    outln!("\n{}", "📊 Scenario Comparison:".bold().cyan());
+    outln!("{}", "─".repeat(20 + scenarios.len() * 15));
 
     // Header row
-    print!("{:<20}", "Variable".bold());
+    out!("{:<20}", "Variable".bold());
     for scenario_name in scenarios {
-        print!("{:>15}", scenario_name.bright_yellow().bold());
+        out!("{:>15}", scenario_name.bright_yellow().bold());
     }
-    println!();
-    println!("{}", "─".repeat(20 + scenarios.len() * 15));
+    outln!();
+    outln!("{}", "─".repeat(20 + scenarios.len() * 15));
 
     // Data rows
     for scalar_name in &all_scalars {
-        print!("{:<20}", scalar_name.bright_blue());
+        out!("{:<20}", scalar_name.bright_blue());
 
         for (_, result_model) in &results {
             if let Some(var) = result_model.scalars.get(scalar_name) {
                 if let Some(value) = var.value {
-                    print!("{:>15}", format_number(value).green());
+                    out!("{:>15}", format_number(value).green());
                 } else {
-                    print!("{:>15}", "-".dimmed());
+                    out!("{:>15}", "-".dimmed());
                 }
             } else {
-                print!("{:>15}", "-".dimmed());
+                out!("{:>15}", "-".dimmed());
             }
         }
-        println!();
+        outln!();
     }
 
-    println!("{}", "─".repeat(20 + scenarios.len() * 15));
-    println!("\n{}", "✅ Comparison complete".bold().green());
+    outln!("{}", "─".repeat(20 + scenarios.len() * 15));
+    outln!("\n{}", "✅ Comparison complete".bold().green());
 
     Ok(())
 }
@@ -179,9 +192,10 @@ pub fn variance_core(
     budget_path: &Path,
     actual_path: &Path,
     threshold: f64,
+    limits: ParseLimits,
 ) -> ForgeResult<super::results::VarianceAnalysis> {
-    let budget_model = parser::parse_model(budget_path)?;
-    let actual_model = parser::parse_model(actual_path)?;
+    let budget_model = super::load_model(budget_path, limits)?;
+    let actual_model = super::load_model(actual_path, limits)?;
 
     let budget_calculator = ArrayCalculator::new(budget_model);
     let budget_result = budget_calculator.calculate_all()?;
@@ -229,23 +243,24 @@ pub fn variance(
     threshold: f64,
     output: Option<&Path>,
     verbose: bool,
+    limits: ParseLimits,
 ) -> ForgeResult<()> {
-    println!("{}", "🔥 Forge - Variance Analysis".bold().green());
-    println!("   Budget: {}", budget_path.display());
-    println!("   Actual: {}", actual_path.display());
-    println!("   Threshold: {threshold}%\n");
+    outln!("{}", "🔥 Forge - Variance Analysis".bold().green());
+    outln!("   Budget: {}", budget_path.display());
+    outln!("   Actual: {}", actual_path.display());
+    outln!("   Threshold: {threshold}%\n");
 
     // Parse both files
     if verbose {
-        println!("{}", "📖 Parsing YAML files...".cyan());
+        outln!("{}", "📖 Parsing YAML files...".cyan());
     }
 
-    let budget_model = parser::parse_model(budget_path)?;
-    let actual_model = parser::parse_model(actual_path)?;
+    let budget_model = super::load_model(budget_path, limits)?;
+    let actual_model = super::load_model(actual_path, limits)?;
 
     // Calculate both models
     if verbose {
-        println!("{}", "🧮 Calculating formulas...".cyan());
+        outln!("{}", "🧮 Calculating formulas...".cyan());
     }
 
     let budget_calculator = ArrayCalculator::new(budget_model);
@@ -266,7 +281,7 @@ pub fn variance(
         match extension {
             "xlsx" => {
                 export_variance_to_excel(output_path, &variances, threshold)?;
-                println!(
+                outln!(
                     "{}",
                     format!("✅ Variance report exported to {}", output_path.display())
                         .bold()
@@ -275,7 +290,7 @@ pub fn variance(
             },
             "yaml" | "yml" => {
                 export_variance_to_yaml(output_path, &variances, threshold)?;
-                println!(
+                outln!(
                     "{}",
                     format!("✅ Variance report exported to {}", output_path.display())
                         .bold()
@@ -298,8 +313,8 @@ pub fn variance(
     let unfavorable_count = variances.len() - favorable_count;
     let alert_count = variances.iter().filter(|v| v.exceeds_threshold).count();
 
-    println!();
-    println!(
+    outln!();
+    outln!(
         "   {} Favorable: {}  {} Unfavorable: {}  {} Alerts (>{:.0}%): {}",
         "✅".green(),
         favorable_count.to_string().green(),
@@ -375,11 +390,11 @@ fn collect_variances(
 
 /// Print variance results as a table
 pub fn print_variance_table(variances: &[VarianceResult], threshold: f64) {
-    println!("\n{}", "📊 Budget vs Actual Variance:".bold().cyan());
-    println!("{}", "─".repeat(85));
+    outln!("\n{}", "📊 Budget vs Actual Variance:".bold().cyan());
+    outln!("{}", "─".repeat(85));
 
     // Header
-    println!(
+    outln!(
         "{:<20} {:>12} {:>12} {:>12} {:>10} {:>8}",
         "Variable".bold(),
         "Budget".bold(),
@@ -388,7 +403,7 @@ pub fn print_variance_table(variances: &[VarianceResult], threshold: f64) {
         "Var %".bold(),
         "Status".bold()
     );
-    println!("{}", "─".repeat(85));
+    outln!("{}", "─".repeat(85));
 
     // Data rows
     for v in variances {
@@ -417,7 +432,7 @@ pub fn print_variance_table(variances: &[VarianceResult], threshold: f64) {
             pct_str.red()
         };
 
-        println!(
+        outln!(
             "{:<20} {:>12} {:>12} {:>12} {:>10} {:>8}",
             v.name.bright_blue(),
             format_number(v.budget),
@@ -428,8 +443,8 @@ pub fn print_variance_table(variances: &[VarianceResult], threshold: f64) {
         );
     }
 
-    println!("{}", "─".repeat(85));
-    println!("   {} = exceeds {:.0}% threshold", "⚠️".yellow(), threshold);
+    outln!("{}", "─".repeat(85));
+    outln!("   {} = exceeds {:.0}% threshold", "⚠️".yellow(), threshold);
 }
 
 /// Export variance report to Excel
@@ -618,11 +633,102 @@ pub fn parse_range(range: &str) -> ForgeResult<Vec<f64>> {
     Ok(values)
 }
 
+/// A table cell named as `table.column[row]`, with rows numbered from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CellRef<'a> {
+    table: &'a str,
+    column: &'a str,
+    row: usize,
+}
+
+impl<'a> CellRef<'a> {
+    /// Parse `table.column[row]`; `None` for any other (scalar) name
+    fn parse(name: &'a str) -> ForgeResult<Option<Self>> {
+        let Some((path, index)) = name.strip_suffix(']').and_then(|rest| rest.split_once('['))
+        else {
+            return Ok(None);
+        };
+        let (table, column) = path.split_once('.').ok_or_else(|| {
+            ForgeError::Validation(format!(
+                "Cell '{name}' must be written as table.column[row]"
+            ))
+        })?;
+        let row = index
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .filter(|&row| row >= 1)
+            .ok_or_else(|| {
+                ForgeError::Validation(format!(
+                    "Cell '{name}': row must be a number from 1, got '{index}'"
+                ))
+            })?;
+        Ok(Some(Self { table, column, row }))
+    }
+
+    /// The cell's numeric values column in `model`, with the row checked
+    fn values<'m>(&self, model: &'m ParsedModel) -> ForgeResult<&'m [f64]> {
+        let Self { table, column, row } = *self;
+        let table_def = model
+            .tables
+            .get(table)
+            .ok_or_else(|| ForgeError::Validation(format!("Table '{table}' not found")))?;
+        let Some(col) = table_def.columns.get(column) else {
+            let reason = if table_def.row_formulas.contains_key(column) {
+                "is calculated by a formula; vary one of its inputs instead"
+            } else {
+                "not found"
+            };
+            return Err(ForgeError::Validation(format!(
+                "Column '{table}.{column}' {reason}"
+            )));
+        };
+        let crate::types::ColumnValue::Number(values) = &col.values else {
+            return Err(ForgeError::Validation(format!(
+                "Cell '{table}.{column}[{row}]' is not numeric ({} column)",
+                col.values.type_name()
+            )));
+        };
+        if row > values.len() {
+            return Err(ForgeError::Validation(format!(
+                "Cell '{table}.{column}[{row}]' is out of bounds: table '{table}' has {} rows",
+                values.len()
+            )));
+        }
+        Ok(values)
+    }
+
+    /// Current value of the cell in `model`
+    fn get(&self, model: &ParsedModel) -> ForgeResult<f64> {
+        Ok(self.values(model)?[self.row - 1])
+    }
+
+    /// Overwrite the cell in `model`
+    fn set(&self, model: &mut ParsedModel, value: f64) -> ForgeResult<()> {
+        self.values(model)?;
+        if let Some(crate::types::ColumnValue::Number(values)) = model
+            .tables
+            .get_mut(self.table)
+            .and_then(|t| t.columns.get_mut(self.column))
+            .map(|c| &mut c.values)
+        {
+            values[self.row - 1] = value;
+        }
+        Ok(())
+    }
+}
+
 /// Calculate model with a specific variable override and return the output value
 ///
+/// Either name may be a table cell, `table.column[row]` (rows from 1); the
+/// varied cell is overwritten before recalculating. Scalar names may be short
+/// (`units` for `assumptions.units`) as with scenario overrides.
+///
 /// # Errors
 ///
-/// Returns an error if calculation fails or the output variable is not found.
+/// Returns an error if calculation fails, the output variable is not found or
+/// its short name is ambiguous, or a named cell is missing, not numeric or out
+/// of bounds.
 pub fn calculate_with_override(
     base_model: &crate::types::ParsedModel,
     var_name: &str,
@@ -630,16 +736,24 @@ pub fn calculate_with_override(
     output_name: &str,
 ) -> ForgeResult<f64> {
     let mut model = base_model.clone();
+    let var_name = &model
+        .resolve_scalar_name(var_name)
+        .map_err(ForgeError::Validation)?;
+    let output_name = &model
+        .resolve_scalar_name(output_name)
+        .map_err(ForgeError::Validation)?;
 
     // Override the variable
-    if let Some(scalar) = model.scalars.get_mut(var_name) {
+    if let Some(cell) = CellRef::parse(var_name)? {
+        cell.set(&mut model, var_value)?;
+    } else if let Some(scalar) = model.scalars.get_mut(var_name) {
         scalar.value = Some(var_value);
         scalar.formula = None; // Clear formula since we're using override
     } else {
         // Create new scalar
         model.scalars.insert(
-            var_name.to_string(),
-            crate::types::Variable::new(var_name.to_string(), Some(var_value), None),
+            var_name.clone(),
+            crate::types::Variable::new(var_name.clone(), Some(var_value), None),
         );
     }
 
@@ -648,6 +762,9 @@ pub fn calculate_with_override(
     let result = calculator.calculate_all()?;
 
     // Get output value
+    if let Some(cell) = CellRef::parse(output_name)? {
+        return cell.get(&result);
+    }
     result.scalars.get(output_name).map_or_else(
         || {
             Err(ForgeError::Validation(format!(
@@ -675,8 +792,9 @@ pub fn sensitivity_core(
     vary2: Option<&str>,
     range2: Option<&str>,
     output: &str,
+    limits: ParseLimits,
 ) -> ForgeResult<super::results::SensitivityResult> {
-    let base_model = parser::parse_model(file)?;
+    let base_model = super::load_model(file, limits)?;
 
     if !base_model.scalars.contains_key(vary) {
         return Err(ForgeError::Validation(format!(
@@ -751,12 +869,39 @@ pub fn sensitivity_core(
     })
 }
 
+/// How the sensitivity command prints its result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SensitivityFormat {
+    /// Plain sensitivity table, optionally with verbose progress
+    Table {
+        /// Show verbose output
+        verbose: bool,
+    },
+    /// Two-variable table with colored cells (plain table when colors are off)
+    Heatmap,
+    /// Two-variable matrix as CSV
+    Csv,
+    /// [`sensitivity_core`] result in the JSON envelope
+    Json,
+}
+
+impl Default for SensitivityFormat {
+    fn default() -> Self {
+        Self::Table { verbose: false }
+    }
+}
+
 /// Execute the sensitivity command
 ///
+/// A two-variable result can be drawn as a heatmap, which falls back to the
+/// plain table when colors are disabled, or written as CSV.
+///
 /// # Errors
 ///
 /// Returns an error if the file cannot be parsed, variables are not found,
-/// ranges are invalid, or calculation fails.
+/// ranges are invalid, calculation fails, or CSV is requested for a
+/// one-variable analysis.
+#[allow(clippy::too_many_arguments)] // each is a separate CLI flag
 pub fn sensitivity(
     file: &Path,
     vary: &str,
@@ -764,22 +909,47 @@ pub fn sensitivity(
     vary2: Option<&str>,
     range2: Option<&str>,
     output: &str,
-    verbose: bool,
+    format: SensitivityFormat,
+    limits: ParseLimits,
 ) -> ForgeResult<()> {
-    println!("{}", "🔥 Forge - Sensitivity Analysis".bold().green());
-    println!("   File: {}", file.display());
-    println!("   Vary: {} ({})", vary.bright_yellow(), range);
+    let verbose = match format {
+        SensitivityFormat::Json => {
+            let result = sensitivity_core(file, vary, range, vary2, range2, output, limits);
+            return envelope::print_outcome("sensitivity", result);
+        },
+        SensitivityFormat::Csv => {
+            let result = sensitivity_core(file, vary, range, vary2, range2, output, limits)?;
+            let csv = render_csv(&result).ok_or_else(|| {
+                ForgeError::Validation("CSV output requires --vary2 and --range2".to_string())
+            })?;
+            out!("{csv}");
+            return Ok(());
+        },
+        SensitivityFormat::Heatmap if colored::control::SHOULD_COLORIZE.should_colorize() => {
+            let result = sensitivity_core(file, vary, range, vary2, range2, output, limits)?;
+            outln!("{}", "🔥 Forge - Sensitivity Heatmap".bold().green());
+            outln!("   File: {}\n", file.display());
+            out!("{}", render_heatmap(&result).unwrap_or_default());
+            return Ok(());
+        },
+        SensitivityFormat::Heatmap => false,
+        SensitivityFormat::Table { verbose } => verbose,
+    };
+
+    outln!("{}", "🔥 Forge - Sensitivity Analysis".bold().green());
+    outln!("   File: {}", file.display());
+    outln!("   Vary: {} ({})", vary.bright_yellow(), range);
     if let Some(v2) = vary2 {
-        println!(
+        outln!(
             "   Vary2: {} ({})",
             v2.bright_yellow(),
             range2.unwrap_or("?")
         );
     }
-    println!("   Output: {}\n", output.bright_blue());
+    outln!("   Output: {}\n", output.bright_blue());
 
     // Parse model
-    let base_model = parser::parse_model(file)?;
+    let base_model = super::load_model(file, limits)?;
 
     // Validate that vary variable exists
     if !base_model.scalars.contains_key(vary) {
@@ -794,7 +964,7 @@ pub fn sensitivity(
     let values1 = parse_range(range)?;
 
     if verbose {
-        println!(
+        outln!(
             "   Range 1: {} values from {} to {}",
             values1.len(),
             values1.first().unwrap_or(&0.0),
@@ -807,27 +977,27 @@ pub fn sensitivity(
         run_two_var_sensitivity(&base_model, vary, v2, r2, output, &values1, verbose)?;
     } else {
         // One-variable analysis
-        println!(
+        outln!(
             "\n{} {} → {}",
             "📊 Sensitivity Table:".bold().cyan(),
             vary.yellow(),
             output.bright_blue()
         );
-        println!("{}", "─".repeat(30));
-        println!("{:>12} {:>15}", vary.bold(), output.bold());
-        println!("{}", "─".repeat(30));
+        outln!("{}", "─".repeat(30));
+        outln!("{:>12} {:>15}", vary.bold(), output.bold());
+        outln!("{}", "─".repeat(30));
 
         for val in &values1 {
             match calculate_with_override(&base_model, vary, *val, output) {
                 Ok(result) => {
-                    println!(
+                    outln!(
                         "{:>12} {:>15}",
                         format!("{val:.4}").bright_yellow(),
                         format_number(result).green()
                     );
                 },
                 Err(e) => {
-                    println!(
+                    outln!(
                         "{:>12} {:>15}",
                         format!("{val:.4}").bright_yellow(),
                         format!("ERR: {e}").red()
@@ -835,115 +1005,396 @@ pub fn sensitivity(
                 },
             }
         }
-        println!("{}", "─".repeat(30));
+        outln!("{}", "─".repeat(30));
     }
 
-    println!("\n{}", "✅ Sensitivity analysis complete".bold().green());
+    outln!("\n{}", "✅ Sensitivity analysis complete".bold().green());
     Ok(())
 }
 
-/// Run goal-seek and return structured results (no printing).
+/// Expected value of `target` across all scenarios with `vary` overridden in one scenario
+///
+/// The override is applied to the raw scenario definitions, so scenarios that
+/// `extends` the varied one inherit the trial value too.
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be parsed, variables are not found,
-/// or no solution exists in the search range.
-pub fn goal_seek_core(
-    file: &Path,
-    target: &str,
-    value: f64,
+/// Returns an error if the scenario does not exist, the scenario engine fails,
+/// or the target has no expected value.
+pub fn calculate_scenario_expected_value(
+    base_model: &ParsedModel,
+    config: &ScenarioConfig,
+    scenario: &str,
     vary: &str,
-    bounds: (Option<f64>, Option<f64>),
-    tolerance: f64,
-) -> ForgeResult<super::results::GoalSeekResult> {
-    let (min, max) = bounds;
-    let base_model = parser::parse_model(file)?;
+    var_value: f64,
+    target: &str,
+) -> ForgeResult<f64> {
+    let mut config = config.clone();
+    let definition = config
+        .scenarios
+        .get_mut(scenario)
+        .ok_or_else(|| ForgeError::Validation(format!("Scenario '{scenario}' not found")))?;
+    definition
+        .scalars
+        .insert(vary.to_string(), ScalarOverride::Value(var_value));
+
+    let engine = ScenarioEngine::new(config, base_model.clone()).map_err(ForgeError::Validation)?;
+    let results = engine.run().map_err(ForgeError::Eval)?;
+
+    let resolved = base_model
+        .resolve_scalar_name(target)
+        .map_err(ForgeError::Validation)?;
+    results
+        .expected_values
+        .get(&resolved)
+        .copied()
+        .ok_or_else(|| {
+            ForgeError::Validation(format!(
+                "Output variable '{target}' has no expected value across scenarios"
+            ))
+        })
+}
 
-    if !base_model.scalars.contains_key(vary) {
-        return Err(ForgeError::Validation(format!(
-            "Variable '{}' not found. Available scalars: {:?}",
-            vary,
-            base_model.scalars.keys().collect::<Vec<_>>()
-        )));
+/// Goal-seek input: the model, optional scenario scope, and current value of `vary`
+struct GoalSeekInput {
+    base_model: ParsedModel,
+    scenarios: Option<ScenarioConfig>,
+    current_value: f64,
+}
+
+impl GoalSeekInput {
+    /// Parse the model (and scenarios, when scoped) and validate `vary`
+    fn load(
+        file: &Path,
+        vary: &str,
+        scenario: Option<&str>,
+        limits: ParseLimits,
+    ) -> ForgeResult<Self> {
+        let base_model = super::load_model(file, limits)?;
+
+        if let Some(cell) = CellRef::parse(vary)? {
+            if scenario.is_some() {
+                return Err(ForgeError::Validation(format!(
+                    "Cannot vary cell '{vary}' with --scenario: scenarios override scalars only"
+                )));
+            }
+            let current_value = cell.get(&base_model)?;
+            return Ok(Self {
+                base_model,
+                scenarios: None,
+                current_value,
+            });
+        }
+
+        let Some(scenario_name) = scenario else {
+            let resolved = base_model
+                .resolve_scalar_name(vary)
+                .map_err(ForgeError::Validation)?;
+            let Some(scalar) = base_model.scalars.get(&resolved) else {
+                return Err(ForgeError::Validation(format!(
+                    "Variable '{}' not found (use table.column[row] for a table cell). Available scalars: {:?}",
+                    vary,
+                    base_model.scalars.keys().collect::<Vec<_>>()
+                )));
+            };
+            let current_value = scalar.value.unwrap_or(1.0);
+            return Ok(Self {
+                base_model,
+                scenarios: None,
+                current_value,
+            });
+        };
+
+        let config = load_scenario_config(file)?;
+        let Some(definition) = config.get_scenario(scenario_name) else {
+            return Err(ForgeError::Validation(format!(
+                "Scenario '{}' not found. Available scenarios: {:?}",
+                scenario_name,
+                config.scenario_names()
+            )));
+        };
+
+        let resolved = base_model
+            .resolve_scalar_name(vary)
+            .map_err(ForgeError::Validation)?;
+        if !base_model.scalars.contains_key(&resolved) && !definition.scalars.contains_key(vary) {
+            return Err(ForgeError::Validation(format!(
+                "Variable '{vary}' is neither a scalar nor an override of scenario '{scenario_name}'"
+            )));
+        }
+
+        let current_value = definition
+            .scalars
+            .get(vary)
+            .and_then(ScalarOverride::as_value)
+            .or_else(|| base_model.scalars.get(&resolved).and_then(|s| s.value))
+            .unwrap_or(1.0);
+
+        Ok(Self {
+            base_model,
+            scenarios: Some(config),
+            current_value,
+        })
     }
 
-    let current_value = base_model
-        .scalars
-        .get(vary)
-        .and_then(|s| s.value)
-        .unwrap_or(1.0);
+    /// Evaluate the target for a trial value of `vary`
+    ///
+    /// Unscoped, this recalculates the model with `vary` overridden. Scoped to a
+    /// scenario, it reruns the scenario engine and returns the target's
+    /// probability-weighted expected value.
+    fn evaluate(
+        &self,
+        scenario: Option<&str>,
+        vary: &str,
+        var_value: f64,
+        target: &str,
+    ) -> ForgeResult<f64> {
+        match (&self.scenarios, scenario) {
+            (Some(config), Some(name)) => calculate_scenario_expected_value(
+                &self.base_model,
+                config,
+                name,
+                vary,
+                var_value,
+                target,
+            ),
+            _ => calculate_with_override(&self.base_model, vary, var_value, target),
+        }
+    }
 
-    let lower = min.unwrap_or_else(|| {
-        if current_value > 0.0 {
-            current_value * 0.01
-        } else if current_value < 0.0 {
+    /// Default search bounds: 0.01x to 100x the current value
+    fn bounds(&self, min: Option<f64>, max: Option<f64>) -> (f64, f64) {
+        let current_value = self.current_value;
+        let lower = min.unwrap_or_else(|| {
+            if current_value > 0.0 {
+                current_value * 0.01
+            } else if current_value < 0.0 {
+                current_value * 100.0
+            } else {
+                -1000.0
+            }
+        });
+        let upper = max.unwrap_or(if current_value > 0.0 {
             current_value * 100.0
+        } else if current_value < 0.0 {
+            current_value * 0.01
         } else {
-            -1000.0
-        }
-    });
-    let upper = max.unwrap_or(if current_value > 0.0 {
-        current_value * 100.0
-    } else if current_value < 0.0 {
-        current_value * 0.01
-    } else {
-        1000.0
-    });
+            1000.0
+        });
+        (lower, upper)
+    }
+}
 
-    let mut low = lower;
-    let mut high = upper;
+/// Root-finding strategy for goal-seek
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SolverMethod {
+    /// Halve a bracketing interval until it is narrower than the tolerance
+    #[default]
+    Bisection,
+    /// Newton-Raphson with a central-difference derivative, falling back to
+    /// bisection when it cannot make progress inside the bounds
+    Newton,
+}
 
-    let f_low = calculate_with_override(&base_model, vary, low, target)? - value;
-    let f_high = calculate_with_override(&base_model, vary, high, target)? - value;
+impl std::str::FromStr for SolverMethod {
+    type Err = ForgeError;
 
-    if f_low * f_high > 0.0 {
-        let expanded = expand_search_range_quiet(&base_model, vary, target, value, lower, upper)?;
-        low = expanded.0;
-        high = expanded.1;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bisection" => Ok(Self::Bisection),
+            "newton" => Ok(Self::Newton),
+            other => Err(ForgeError::Validation(format!(
+                "Unknown goal-seek method '{other}' (expected bisection or newton)"
+            ))),
+        }
     }
+}
 
-    let max_iterations = 100;
+/// Iteration cap for both goal-seek strategies
+const MAX_SOLVER_ITERATIONS: i32 = 100;
+
+/// A root found by one of the goal-seek strategies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solution {
+    pub x: f64,
+    pub iterations: i32,
+}
+
+/// Find a root of `f` in `[low, high]` by bisection
+///
+/// The interval should bracket the root (`f(low)` and `f(high)` of opposite
+/// signs). Stops once `|f(x)| < tolerance` or the interval is narrower than
+/// `tolerance`.
+///
+/// # Errors
+///
+/// Returns an error if evaluating `f` fails.
+pub fn solve_bisection(
+    f: &impl Fn(f64) -> ForgeResult<f64>,
+    low: f64,
+    high: f64,
+    tolerance: f64,
+) -> ForgeResult<Solution> {
+    let (mut low, mut high) = (low, high);
     let mut mid = f64::midpoint(low, high);
-    let mut iteration = 0;
+    let mut iterations = 0;
 
-    while (high - low) > tolerance && iteration < max_iterations {
+    while (high - low) > tolerance && iterations < MAX_SOLVER_ITERATIONS {
         mid = f64::midpoint(low, high);
-        let f_mid = calculate_with_override(&base_model, vary, mid, target)? - value;
-        let f_low_check = calculate_with_override(&base_model, vary, low, target)? - value;
+        let f_mid = f(mid)?;
+        let f_low = f(low)?;
 
         if f_mid.abs() < tolerance {
             break;
         }
 
-        if f_low_check * f_mid < 0.0 {
+        if f_low * f_mid < 0.0 {
             high = mid;
         } else {
             low = mid;
         }
 
-        iteration += 1;
+        iterations += 1;
+    }
+
+    Ok(Solution { x: mid, iterations })
+}
+
+/// Find a root of `f` by Newton-Raphson iteration from `start`
+///
+/// The derivative is a central difference. Returns `None` when the
+/// derivative is (near) zero, a step would leave `bounds`, or no root within
+/// `tolerance` is reached in time; callers then fall back to bisection.
+///
+/// # Errors
+///
+/// Returns an error if evaluating `f` fails.
+pub fn solve_newton(
+    f: &impl Fn(f64) -> ForgeResult<f64>,
+    start: f64,
+    bounds: (f64, f64),
+    tolerance: f64,
+) -> ForgeResult<Option<Solution>> {
+    let (low, high) = bounds;
+    let mut x = start.max(low).min(high);
+
+    for iterations in 0..MAX_SOLVER_ITERATIONS {
+        let fx = f(x)?;
+        if fx.abs() < tolerance {
+            return Ok(Some(Solution { x, iterations }));
+        }
+
+        let h = 1e-6 * x.abs().max(1.0);
+        let slope = (f(x + h)? - f(x - h)?) / (2.0 * h);
+        if !slope.is_finite() || slope.abs() < 1e-12 {
+            return Ok(None);
+        }
+
+        let next = x - fx / slope;
+        if !next.is_finite() || next < low || next > high {
+            return Ok(None);
+        }
+        x = next;
+    }
+
+    Ok(None)
+}
+
+/// Search settings shared by goal-seek and break-even
+#[derive(Debug, Clone, Copy)]
+pub struct GoalSeekOptions<'a> {
+    /// Search range; missing ends default to 0.01x and 100x the current value
+    pub bounds: (Option<f64>, Option<f64>),
+    /// Stop once the target is within this distance
+    pub tolerance: f64,
+    /// Vary this scenario's override and target the expected value
+    pub scenario: Option<&'a str>,
+    /// Root-finding strategy
+    pub method: SolverMethod,
+    /// Row and cell caps applied while parsing the model
+    pub limits: ParseLimits,
+}
+
+impl Default for GoalSeekOptions<'_> {
+    fn default() -> Self {
+        Self {
+            bounds: (None, None),
+            tolerance: 0.0001,
+            scenario: None,
+            method: SolverMethod::Bisection,
+            limits: ParseLimits::default(),
+        }
     }
+}
 
-    let final_value = calculate_with_override(&base_model, vary, mid, target)?;
+/// Run goal-seek and return structured results (no printing).
+///
+/// With `scenario` set, `vary` is that scenario's override and `target` is the
+/// expected value across all scenarios. The result records the method that
+/// found the solution, which is bisection when Newton-Raphson fell back.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be parsed, variables are not found,
+/// or no solution exists in the search range.
+pub fn goal_seek_core(
+    file: &Path,
+    target: &str,
+    value: f64,
+    vary: &str,
+    options: &GoalSeekOptions,
+) -> ForgeResult<super::results::GoalSeekResult> {
+    let GoalSeekOptions {
+        bounds: (min, max),
+        tolerance,
+        scenario,
+        method,
+        ..
+    } = *options;
+    let input = GoalSeekInput::load(file, vary, scenario, options.limits)?;
+    let evaluate = |x: f64| input.evaluate(scenario, vary, x, target);
+    let offset = |x: f64| evaluate(x).map(|y| y - value);
+
+    let (lower, upper) = input.bounds(min, max);
+
+    let newton = match method {
+        SolverMethod::Newton => {
+            solve_newton(&offset, input.current_value, (lower, upper), tolerance)?
+        },
+        SolverMethod::Bisection => None,
+    };
+    let (solution, method) = if let Some(solution) = newton {
+        (solution, SolverMethod::Newton)
+    } else {
+        let (mut low, mut high) = (lower, upper);
+        if offset(low)? * offset(high)? > 0.0 {
+            (low, high) = expand_search_range_quiet(&evaluate, vary, value, lower, upper)?;
+        }
+        let solution = solve_bisection(&offset, low, high, tolerance)?;
+        (solution, SolverMethod::Bisection)
+    };
+
+    let final_value = evaluate(solution.x)?;
     let error = (final_value - value).abs();
 
     Ok(super::results::GoalSeekResult {
         vary: vary.to_string(),
         target: target.to_string(),
         target_value: value,
-        solution: mid,
+        solution: solution.x,
         achieved: final_value,
         error,
-        iterations: iteration,
+        iterations: solution.iterations,
         converged: error < tolerance,
+        method,
     })
 }
 
 /// Expand search range without printing (for core function)
 fn expand_search_range_quiet(
-    base_model: &crate::types::ParsedModel,
+    evaluate: &impl Fn(f64) -> ForgeResult<f64>,
     vary: &str,
-    target: &str,
     value: f64,
     lower: f64,
     upper: f64,
@@ -960,8 +1411,8 @@ fn expand_search_range_quiet(
             upper / factor
         };
 
-        let f_exp_low = calculate_with_override(base_model, vary, exp_low, target)? - value;
-        let f_exp_high = calculate_with_override(base_model, vary, exp_high, target)? - value;
+        let f_exp_low = evaluate(exp_low)? - value;
+        let f_exp_high = evaluate(exp_high)? - value;
 
         if f_exp_low * f_exp_high <= 0.0 {
             return Ok((exp_low, exp_high));
@@ -984,71 +1435,95 @@ pub fn goal_seek(
     target: &str,
     value: f64,
     vary: &str,
-    bounds: (Option<f64>, Option<f64>),
-    tolerance: f64,
+    options: &GoalSeekOptions,
     verbose: bool,
 ) -> ForgeResult<()> {
-    let (min, max) = bounds;
-    println!("{}", "🔥 Forge - Goal Seek".bold().green());
-    println!("   File: {}", file.display());
-    println!("   Target: {} = {}", target.bright_blue(), value);
-    println!("   Vary: {}", vary.bright_yellow());
-    println!("   Tolerance: {tolerance}\n");
-
-    // Parse model
-    let base_model = parser::parse_model(file)?;
+    print_goal_seek(file, target, value, vary, options, verbose)?;
+    Ok(())
+}
 
-    // Validate variables
-    if !base_model.scalars.contains_key(vary) {
-        return Err(ForgeError::Validation(format!(
-            "Variable '{}' not found. Available scalars: {:?}",
-            vary,
-            base_model.scalars.keys().collect::<Vec<_>>()
-        )));
+/// Run goal-seek with progress output and return the solution
+fn print_goal_seek(
+    file: &Path,
+    target: &str,
+    value: f64,
+    vary: &str,
+    options: &GoalSeekOptions,
+    verbose: bool,
+) -> ForgeResult<f64> {
+    let GoalSeekOptions {
+        bounds: (min, max),
+        tolerance,
+        scenario,
+        method,
+        ..
+    } = *options;
+    outln!("{}", "🔥 Forge - Goal Seek".bold().green());
+    outln!("   File: {}", file.display());
+    if let Some(name) = scenario {
+        outln!(
+            "   Target: E[{}] across scenarios = {}",
+            target.bright_blue(),
+            value
+        );
+        outln!(
+            "   Vary: {} in scenario {}",
+            vary.bright_yellow(),
+            name.bright_blue()
+        );
+    } else {
+        outln!("   Target: {} = {}", target.bright_blue(), value);
+        outln!("   Vary: {}", vary.bright_yellow());
     }
+    outln!("   Tolerance: {tolerance}\n");
 
-    // Get current value of vary to set default bounds
-    let current_value = base_model
-        .scalars
-        .get(vary)
-        .and_then(|s| s.value)
-        .unwrap_or(1.0);
+    // Parse model (and scenarios when scoped) and validate variables
+    let input = GoalSeekInput::load(file, vary, scenario, options.limits)?;
+    let evaluate = |x: f64| input.evaluate(scenario, vary, x, target);
 
     // Set bounds (default: 0.01x to 100x current value)
-    let lower = min.unwrap_or_else(|| {
-        if current_value > 0.0 {
-            current_value * 0.01
-        } else if current_value < 0.0 {
-            current_value * 100.0
-        } else {
-            -1000.0
-        }
-    });
-    let upper = max.unwrap_or(if current_value > 0.0 {
-        current_value * 100.0
-    } else if current_value < 0.0 {
-        current_value * 0.01
-    } else {
-        1000.0
-    });
+    let (lower, upper) = input.bounds(min, max);
 
     if verbose {
-        println!("   Current value of {vary}: {current_value}");
-        println!("   Search bounds: [{lower}, {upper}]");
+        outln!("   Current value of {vary}: {}", input.current_value);
+        outln!("   Search bounds: [{lower}, {upper}]");
+    }
+
+    if method == SolverMethod::Newton {
+        let offset = |x: f64| evaluate(x).map(|y| y - value);
+        if let Some(solution) =
+            solve_newton(&offset, input.current_value, (lower, upper), tolerance)?
+        {
+            let final_value = evaluate(solution.x)?;
+            print_goal_seek_result(
+                vary,
+                target,
+                solution.x,
+                final_value,
+                value,
+                tolerance,
+                solution.iterations,
+            );
+            return Ok(solution.x);
+        }
+        outln!(
+            "{}",
+            "   Newton-Raphson left the search bounds or found a flat slope; using bisection"
+                .dimmed()
+        );
     }
 
     // Bisection method
-    let max_iterations = 100;
     let mut low = lower;
     let mut high = upper;
 
     // Check bounds first
-    let f_low = calculate_with_override(&base_model, vary, low, target)? - value;
-    let f_high = calculate_with_override(&base_model, vary, high, target)? - value;
+    let f_low = evaluate(low)? - value;
+    let f_high = evaluate(high)? - value;
 
     if verbose {
-        println!("   f({}) = {} (target diff: {})", low, f_low + value, f_low);
-        println!(
+        outln!("   f({}) = {} (target diff: {})", low, f_low + value, f_low);
+        outln!(
             "   f({}) = {} (target diff: {})",
             high,
             f_high + value,
@@ -1058,22 +1533,19 @@ pub fn goal_seek(
 
     // Check if solution exists in range (signs should differ)
     if f_low * f_high > 0.0 {
-        let expanded =
-            expand_search_range(&base_model, vary, target, value, lower, upper, verbose)?;
-        low = expanded.0;
-        high = expanded.1;
+        (low, high) = expand_search_range(&evaluate, vary, value, lower, upper, verbose)?;
     }
 
     // Bisection iteration
     let mut mid = f64::midpoint(low, high);
     let mut iteration = 0;
 
-    while (high - low) > tolerance && iteration < max_iterations {
+    while (high - low) > tolerance && iteration < MAX_SOLVER_ITERATIONS {
         mid = f64::midpoint(low, high);
-        let f_mid = calculate_with_override(&base_model, vary, mid, target)? - value;
+        let f_mid = evaluate(mid)? - value;
 
         if verbose && iteration % 10 == 0 {
-            println!(
+            outln!(
                 "   Iteration {}: {} = {} (diff: {:.6})",
                 iteration,
                 vary,
@@ -1082,7 +1554,7 @@ pub fn goal_seek(
             );
         }
 
-        let f_low_check = calculate_with_override(&base_model, vary, low, target)? - value;
+        let f_low_check = evaluate(low)? - value;
 
         if f_mid.abs() < tolerance {
             break;
@@ -1098,9 +1570,9 @@ pub fn goal_seek(
     }
 
     // Final result
-    let final_value = calculate_with_override(&base_model, vary, mid, target)?;
+    let final_value = evaluate(mid)?;
     print_goal_seek_result(vary, target, mid, final_value, value, tolerance, iteration);
-    Ok(())
+    Ok(mid)
 }
 
 /// Run two-variable sensitivity analysis matrix
@@ -1124,7 +1596,7 @@ fn run_two_var_sensitivity(
     let values2 = parse_range(r2)?;
 
     if verbose {
-        println!(
+        outln!(
             "   Range 2: {} values from {} to {}",
             values2.len(),
             values2.first().unwrap_or(&0.0),
@@ -1133,7 +1605,7 @@ fn run_two_var_sensitivity(
     }
 
     // Calculate matrix
-    println!(
+    outln!(
         "\n{} {} → {}",
         "📊 Sensitivity Matrix:".bold().cyan(),
         format!("({vary}, {v2})").yellow(),
@@ -1141,16 +1613,16 @@ fn run_two_var_sensitivity(
     );
 
     // Header row
-    print!("{:>12}", vary.bright_yellow());
+    out!("{:>12}", vary.bright_yellow());
     for val2 in &values2 {
-        print!("{:>12}", format!("{val2:.4}").dimmed());
+        out!("{:>12}", format!("{val2:.4}").dimmed());
     }
-    println!();
-    println!("{}", "─".repeat(12 + values2.len() * 12));
+    outln!();
+    outln!("{}", "─".repeat(12 + values2.len() * 12));
 
     // Data rows
     for val1 in values1 {
-        print!("{:>12}", format!("{val1:.4}").bright_yellow());
+        out!("{:>12}", format!("{val1:.4}").bright_yellow());
 
         for val2 in &values2 {
             let mut model = base_model.clone();
@@ -1169,35 +1641,34 @@ fn run_two_var_sensitivity(
                 Ok(result) => {
                     if let Some(scalar) = result.scalars.get(output) {
                         if let Some(v) = scalar.value {
-                            print!("{:>12}", format_number(v).green());
+                            out!("{:>12}", format_number(v).green());
                         } else {
-                            print!("{:>12}", "-".dimmed());
+                            out!("{:>12}", "-".dimmed());
                         }
                     } else {
-                        print!("{:>12}", "?".red());
+                        out!("{:>12}", "?".red());
                     }
                 },
                 Err(_) => {
-                    print!("{:>12}", "ERR".red());
+                    out!("{:>12}", "ERR".red());
                 },
             }
         }
-        println!();
+        outln!();
     }
     Ok(())
 }
 
 /// Expand the search range when initial bounds have the same sign
 fn expand_search_range(
-    base_model: &crate::types::ParsedModel,
+    evaluate: &impl Fn(f64) -> ForgeResult<f64>,
     vary: &str,
-    target: &str,
     value: f64,
     lower: f64,
     upper: f64,
     verbose: bool,
 ) -> ForgeResult<(f64, f64)> {
-    println!(
+    outln!(
         "{}",
         "⚠️  No sign change in initial range - expanding search...".yellow()
     );
@@ -1214,12 +1685,12 @@ fn expand_search_range(
             upper / factor
         };
 
-        let f_exp_low = calculate_with_override(base_model, vary, exp_low, target)? - value;
-        let f_exp_high = calculate_with_override(base_model, vary, exp_high, target)? - value;
+        let f_exp_low = evaluate(exp_low)? - value;
+        let f_exp_high = evaluate(exp_high)? - value;
 
         if f_exp_low * f_exp_high <= 0.0 {
             if verbose {
-                println!("   Found valid range: [{exp_low}, {exp_high}]");
+                outln!("   Found valid range: [{exp_low}, {exp_high}]");
             }
             return Ok((exp_low, exp_high));
         }
@@ -1240,14 +1711,14 @@ fn print_goal_seek_result(
     tolerance: f64,
     iteration: i32,
 ) {
-    println!("{}", "─".repeat(50));
-    println!(
+    outln!("{}", "─".repeat(50));
+    outln!(
         "{}",
         format!("🎯 Solution found in {iteration} iterations:")
             .bold()
             .green()
     );
-    println!(
+    outln!(
         "   {} = {} → {} = {}",
         vary.bright_yellow().bold(),
         format_number(mid).bold().green(),
@@ -1257,20 +1728,74 @@ fn print_goal_seek_result(
 
     let error = (final_value - value).abs();
     if error < tolerance {
-        println!("   {} Within tolerance", "✅".green());
+        outln!("   {} Within tolerance", "✅".green());
     } else {
-        println!(
+        outln!(
             "   {} Error: {} (tolerance: {})",
             "⚠️".yellow(),
             error,
             tolerance
         );
     }
-    println!("{}", "─".repeat(50));
+    outln!("{}", "─".repeat(50));
+}
+
+/// Current value of `vary` in the model (short names resolve like goal-seek)
+fn current_input_value(file: &Path, vary: &str, limits: ParseLimits) -> ForgeResult<Option<f64>> {
+    let model = super::load_model(file, limits)?;
+    if let Some(cell) = CellRef::parse(vary)? {
+        return cell.get(&model).map(Some);
+    }
+    let name = model
+        .resolve_scalar_name(vary)
+        .map_err(ForgeError::Validation)?;
+    Ok(model.scalars.get(&name).and_then(|s| s.value))
+}
+
+/// Percent distance of `current` from `break_even`, relative to `current`
+/// (positive when the current value is above break-even)
+fn margin_of_safety(current: f64, break_even: f64) -> Option<f64> {
+    (current != 0.0).then(|| (current - break_even) / current.abs() * 100.0)
+}
+
+/// Run break-even analysis and return structured results (no printing).
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be parsed, variables are not found,
+/// or no zero-crossing exists in the search range.
+pub fn break_even_core(
+    file: &Path,
+    output: &str,
+    vary: &str,
+    bounds: (Option<f64>, Option<f64>),
+    limits: ParseLimits,
+) -> ForgeResult<super::results::BreakEvenResult> {
+    // Break-even is just goal-seek with value = 0
+    let goal_seek = goal_seek_core(
+        file,
+        output,
+        0.0,
+        vary,
+        &GoalSeekOptions {
+            bounds,
+            limits,
+            ..GoalSeekOptions::default()
+        },
+    )?;
+    let current_value = current_input_value(file, vary, limits)?;
+    Ok(super::results::BreakEvenResult {
+        margin_of_safety: current_value.and_then(|c| margin_of_safety(c, goal_seek.solution)),
+        current_value,
+        goal_seek,
+    })
 }
 
 /// Execute the break-even command
 ///
+/// After the zero crossing, prints the varied input's current value and its
+/// margin of safety above break-even.
+///
 /// # Errors
 ///
 /// Returns an error if goal-seek fails to find a zero-crossing point.
@@ -1281,10 +1806,308 @@ pub fn break_even(
     min: Option<f64>,
     max: Option<f64>,
     verbose: bool,
+    limits: ParseLimits,
 ) -> ForgeResult<()> {
-    println!("{}", "🔥 Forge - Break-Even Analysis".bold().green());
-    println!("   Finding where {} = 0\n", output.bright_blue());
+    outln!("{}", "🔥 Forge - Break-Even Analysis".bold().green());
+    outln!("   Finding where {} = 0\n", output.bright_blue());
 
     // Break-even is just goal-seek with value = 0
-    goal_seek(file, output, 0.0, vary, (min, max), 0.0001, verbose)
+    let solution = print_goal_seek(
+        file,
+        output,
+        0.0,
+        vary,
+        &GoalSeekOptions {
+            bounds: (min, max),
+            limits,
+            ..GoalSeekOptions::default()
+        },
+        verbose,
+    )?;
+
+    let Some(current) = current_input_value(file, vary, limits)? else {
+        return Ok(());
+    };
+    outln!(
+        "   Current {}: {}",
+        vary.bright_yellow(),
+        format_number(current).bold()
+    );
+    if let Some(margin) = margin_of_safety(current, solution) {
+        let text = format!("{}%", format_number((margin * 100.0).round() / 100.0));
+        let text = if margin >= 0.0 {
+            text.green()
+        } else {
+            text.red()
+        };
+        outln!("   Margin of safety: {}", text.bold());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    const SCENARIO_MODEL: &str = r#"
+_forge_version: "5.0.0"
+
+assumptions:
+  price:
+    value: 10
+    formula: null
+  units:
+    value: 2
+    formula: null
+  revenue:
+    value: null
+    formula: "=price * units"
+
+scenarios:
+  base_case:
+    probability: 0.5
+    scalars:
+      price: 10
+  bull_case:
+    probability: 0.5
+    scalars:
+      price: 20
+"#;
+
+    #[test]
+    fn test_goal_seek_scenario_override_expected_value() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "{SCENARIO_MODEL}").unwrap();
+
+        // E[revenue] = 0.5 * 10 * 2 + 0.5 * price * 2 = 40 -> bull price = 30
+        let result = goal_seek_core(
+            file.path(),
+            "revenue",
+            40.0,
+            "price",
+            &GoalSeekOptions {
+                bounds: (Some(0.0), Some(100.0)),
+                scenario: Some("bull_case"),
+                ..GoalSeekOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert!(result.converged, "goal seek did not converge: {result:?}");
+        assert!(
+            (result.solution - 30.0).abs() < 0.001,
+            "Expected bull price of 30, got {}",
+            result.solution
+        );
+        assert!((result.achieved - 40.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_break_even_margin_of_safety() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+_forge_version: "5.0.0"
+
+assumptions:
+  units:
+    value: 150
+    formula: null
+  profit:
+    value: null
+    formula: "=units * 20 - 2000"
+"#
+        )
+        .unwrap();
+
+        // Break-even at 100 units; 150 current units is 33.3% above it
+        let result = break_even_core(
+            file.path(),
+            "profit",
+            "units",
+            (None, None),
+            ParseLimits::default(),
+        )
+        .unwrap();
+        assert!((result.goal_seek.solution - 100.0).abs() < 0.001);
+        assert_eq!(result.current_value, Some(150.0));
+        let margin = result.margin_of_safety.unwrap();
+        assert!((margin - 100.0 / 3.0).abs() < 0.01, "margin {margin}");
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert!(json["solution"].is_number());
+        assert!(json["margin_of_safety"].as_f64().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_goal_seek_unknown_scenario_rejected() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "{SCENARIO_MODEL}").unwrap();
+
+        let err = goal_seek_core(
+            file.path(),
+            "revenue",
+            40.0,
+            "price",
+            &GoalSeekOptions {
+                scenario: Some("moon_case"),
+                ..GoalSeekOptions::default()
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("moon_case"), "got: {err}");
+    }
+
+    const PRICING_MODEL: &str = r#"
+_forge_version: "5.0.0"
+
+pricing:
+  product: ["basic", "plus", "pro"]
+  price: [10, 20, 30]
+  cost: [8, 15, 20]
+  margin: "=price - cost"
+
+summary:
+  total_margin:
+    value: null
+    formula: "=SUM(pricing.margin) / SUM(pricing.price)"
+"#;
+
+    #[test]
+    fn test_goal_seek_table_cells() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "{PRICING_MODEL}").unwrap();
+
+        // (2 + 5 + p - 20) / (30 + p) = 20% -> p = 23.75; the margin moves ~0.015
+        // per unit of price, so the tolerance is tightened to pin p to 0.001
+        let result = goal_seek_core(
+            file.path(),
+            "summary.total_margin",
+            0.2,
+            "pricing.price[3]",
+            &GoalSeekOptions {
+                tolerance: 1e-6,
+                ..GoalSeekOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(result.converged, "goal seek did not converge: {result:?}");
+        assert!((result.solution - 23.75).abs() < 0.001, "{result:?}");
+
+        // A calculated cell can be the target
+        let result = goal_seek_core(
+            file.path(),
+            "pricing.margin[2]",
+            10.0,
+            "pricing.cost[2]",
+            &GoalSeekOptions::default(),
+        )
+        .unwrap();
+        assert!((result.solution - 10.0).abs() < 0.001, "{result:?}");
+    }
+
+    #[test]
+    fn test_goal_seek_rejects_bad_cells() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "{PRICING_MODEL}").unwrap();
+
+        for (vary, expected) in [
+            (
+                "pricing.price[4]",
+                "out of bounds: table 'pricing' has 3 rows",
+            ),
+            ("pricing.price[0]", "row must be a number from 1"),
+            ("pricing.product[1]", "is not numeric (Text column)"),
+            ("pricing.margin[1]", "is calculated by a formula"),
+            ("pricing.volume[1]", "'pricing.volume' not found"),
+        ] {
+            let err = goal_seek_core(
+                file.path(),
+                "summary.total_margin",
+                0.2,
+                vary,
+                &GoalSeekOptions::default(),
+            )
+            .unwrap_err()
+            .to_string();
+            assert!(err.contains(expected), "{vary}: {err}");
+        }
+
+        let err = goal_seek_core(
+            file.path(),
+            "pricing.margin[9]",
+            0.0,
+            "pricing.price[1]",
+            &GoalSeekOptions::default(),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("out of bounds"), "{err}");
+    }
+
+    #[test]
+    fn test_newton_converges_faster_than_bisection() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+_forge_version: "5.0.0"
+
+project:
+  rate:
+    value: 0.05
+    formula: null
+  npv:
+    value: null
+    formula: "=NPV(rate, -1000, 300, 400, 500, 200)"
+"#
+        )
+        .unwrap();
+        let run = |method| {
+            goal_seek_core(
+                file.path(),
+                "project.npv",
+                0.0,
+                "project.rate",
+                &GoalSeekOptions {
+                    method,
+                    ..GoalSeekOptions::default()
+                },
+            )
+            .unwrap()
+        };
+
+        let bisection = run(SolverMethod::Bisection);
+        let newton = run(SolverMethod::Newton);
+        assert!(newton.converged, "{newton:?}");
+        assert_eq!(newton.method, SolverMethod::Newton);
+        assert!((newton.solution - bisection.solution).abs() < 0.001);
+        assert!(
+            newton.iterations * 3 <= bisection.iterations,
+            "newton {} vs bisection {} iterations",
+            newton.iterations,
+            bisection.iterations
+        );
+    }
+
+    #[test]
+    fn test_newton_falls_back_to_bisection() {
+        // Zero slope at the start: Newton cannot move
+        let flat = |x: f64| -> ForgeResult<f64> { Ok(if x < 5.0 { -1.0 } else { x - 6.0 }) };
+        assert_eq!(solve_newton(&flat, 1.0, (0.0, 10.0), 1e-6).unwrap(), None);
+        // A step that leaves the bounds also gives up
+        let line = |x: f64| -> ForgeResult<f64> { Ok(x - 50.0) };
+        assert_eq!(solve_newton(&line, 1.0, (0.0, 10.0), 1e-6).unwrap(), None);
+
+        let solution = solve_bisection(&flat, 0.0, 10.0, 1e-6).unwrap();
+        assert!((solution.x - 6.0).abs() < 1e-5, "{solution:?}");
+        let square = |x: f64| -> ForgeResult<f64> { Ok(x.mul_add(x, -4.0)) };
+        let newton = solve_newton(&square, 3.0, (0.0, 10.0), 1e-9)
+            .unwrap()
+            .unwrap();
+        assert!((newton.x - 2.0).abs() < 1e-9);
+    }
 }
@@ -2,7 +2,8 @@
 
 use crate::core::ArrayCalculator;
 use crate::error::{ForgeError, ForgeResult};
-use crate::parser;
+use crate::parser::ParseLimits;
+use crate::types::{Metadata, ParsedModel};
 use colored::Colorize;
 use std::path::Path;
 
@@ -36,6 +37,10 @@ pub struct AuditDependency {
     pub dep_type: String,
     pub formula: Option<String>,
     pub value: Option<f64>,
+    /// `notes` metadata, when the dependency has any
+    pub notes: Option<String>,
+    /// `source` metadata, when the dependency has any
+    pub source: Option<String>,
     pub children: Vec<Self>,
 }
 
@@ -46,6 +51,8 @@ fn to_audit_dep(dep: &AuditDependency) -> super::results::AuditDep {
         dep_type: dep.dep_type.clone(),
         formula: dep.formula.clone(),
         value: dep.value,
+        notes: dep.notes.clone(),
+        source: dep.source.clone(),
         children: dep.children.iter().map(to_audit_dep).collect(),
     }
 }
@@ -56,9 +63,16 @@ fn to_audit_dep(dep: &AuditDependency) -> super::results::AuditDep {
 ///
 /// Returns an error if the file cannot be parsed, the variable is not found,
 /// or formula calculation fails.
-pub fn audit_core(file: &Path, variable: &str) -> ForgeResult<super::results::AuditResult> {
-    let model = parser::parse_model(file)?;
+pub fn audit_core(
+    file: &Path,
+    variable: &str,
+    limits: ParseLimits,
+) -> ForgeResult<super::results::AuditResult> {
+    let model = super::load_model(file, limits)?;
     let (var_type, formula, current_value) = find_variable(&model, variable)?;
+    let metadata = variable_metadata(&model, variable)
+        .cloned()
+        .unwrap_or_default();
 
     let dependencies = if formula.is_some() {
         let deps = build_dependency_tree(&model, variable, formula.as_ref(), 0)?;
@@ -87,6 +101,8 @@ pub fn audit_core(file: &Path, variable: &str) -> ForgeResult<super::results::Au
         current_value,
         calculated_value,
         formula,
+        notes: metadata.notes,
+        source: metadata.source,
         dependencies,
         is_valid,
     })
@@ -98,61 +114,49 @@ pub fn audit_core(file: &Path, variable: &str) -> ForgeResult<super::results::Au
 ///
 /// Returns an error if the file cannot be parsed, the variable is not found,
 /// or formula calculation fails.
-pub fn audit(file: &Path, variable: &str) -> ForgeResult<()> {
-    println!("{}", "🔍 Forge - Audit Trail".bold().green());
-    println!("   File: {}", file.display());
-    println!("   Variable: {}\n", variable.bright_blue().bold());
+pub fn audit(file: &Path, variable: &str, limits: ParseLimits) -> ForgeResult<()> {
+    outln!("{}", "🔍 Forge - Audit Trail".bold().green());
+    outln!("   File: {}", file.display());
+    outln!("   Variable: {}\n", variable.bright_blue().bold());
 
     // Parse the model
-    let model = parser::parse_model(file)?;
-
-    // Try to find the variable
-    let (var_type, formula, current_value) = find_variable(&model, variable)?;
-
-    println!("{}", "📋 Variable Information:".bold().cyan());
-    println!("   Type: {}", var_type.cyan());
-    if let Some(val) = current_value {
-        println!("   Current Value: {}", format_number(val).bold().green());
-    }
-    if let Some(ref f) = formula {
-        println!("   Formula: {}", f.bright_yellow());
-    }
-    println!();
+    let model = super::load_model(file, limits)?;
+    let (formula, current_value) = print_variable_info(&model, variable)?;
 
     // Build and display dependency tree
     if formula.is_some() {
-        println!("{}", "🌳 Dependency Tree:".bold().cyan());
+        outln!("{}", "🌳 Dependency Tree:".bold().cyan());
         let deps = build_dependency_tree(&model, variable, formula.as_ref(), 0)?;
 
         if deps.is_empty() {
-            println!("   No dependencies (literal value)");
+            outln!("   No dependencies (literal value)");
         } else {
             for dep in &deps {
                 print_dependency(dep, 1);
             }
         }
-        println!();
+        outln!();
     }
 
     // Calculate and verify
-    println!("{}", "🧮 Calculation Chain:".bold().cyan());
+    outln!("{}", "🧮 Calculation Chain:".bold().cyan());
     let calculator = ArrayCalculator::new(model);
     match calculator.calculate_all() {
         Ok(result) => {
             // Find the calculated value
             if let Some(scalar) = result.scalars.get(variable) {
                 if let Some(calc_val) = scalar.value {
-                    println!("   Calculated: {}", format_number(calc_val).bold().green());
+                    outln!("   Calculated: {}", format_number(calc_val).bold().green());
 
                     // Check if it matches current value
                     if let Some(curr) = current_value {
                         let diff = (curr - calc_val).abs();
                         if diff < 0.0001 {
-                            println!("   {} Values match!", "✅".green());
+                            outln!("   {} Values match!", "✅".green());
                         } else {
-                            println!("   {} Value mismatch!", "⚠️".yellow());
-                            println!("      Current:    {}", format_number(curr).red());
-                            println!("      Calculated: {}", format_number(calc_val).green());
+                            outln!("   {} Value mismatch!", "⚠️".yellow());
+                            outln!("      Current:    {}", format_number(curr).red());
+                            outln!("      Calculated: {}", format_number(calc_val).green());
                         }
                     }
                 }
@@ -160,23 +164,99 @@ pub fn audit(file: &Path, variable: &str) -> ForgeResult<()> {
                 // Check in tables
                 for (table_name, table) in &result.tables {
                     if let Some(col) = table.columns.get(variable) {
-                        println!("   Table: {}", table_name.bright_blue());
-                        println!("   Column values: {:?}", col.values);
+                        outln!("   Table: {}", table_name.bright_blue());
+                        outln!("   Column values: {:?}", col.values);
                         break;
                     }
                 }
             }
         },
         Err(e) => {
-            println!("   {} Calculation error: {}", "❌".red(), e);
+            outln!("   {} Calculation error: {}", "❌".red(), e);
         },
     }
 
-    println!();
-    println!("{}", "✅ Audit complete".bold().green());
+    outln!();
+    outln!("{}", "✅ Audit complete".bold().green());
+    Ok(())
+}
+
+/// Execute `audit --dependents` - show everything that depends on a variable.
+///
+/// The tree is the dependency tree turned upside down: each level lists the
+/// scalars, aggregations and table columns whose formulas reference the level
+/// above, so it shows every output a change to `variable` would affect.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be parsed or the variable is not found.
+pub fn audit_dependents(file: &Path, variable: &str, limits: ParseLimits) -> ForgeResult<()> {
+    outln!("{}", "🔍 Forge - Audit Trail".bold().green());
+    outln!("   File: {}", file.display());
+    outln!("   Variable: {}\n", variable.bright_blue().bold());
+
+    let model = super::load_model(file, limits)?;
+    print_variable_info(&model, variable)?;
+
+    outln!("{}", "🌳 Dependents Tree:".bold().cyan());
+    let dependents = build_dependents_tree(&model, variable, 0);
+    if dependents.is_empty() {
+        outln!("   No dependents (nothing references {variable})");
+    } else {
+        for dep in &dependents {
+            print_dependency(dep, 1);
+        }
+    }
+    outln!();
+    outln!("{}", "✅ Audit complete".bold().green());
     Ok(())
 }
 
+/// Print a variable's type, value, formula and metadata; returns its formula and value
+fn print_variable_info(
+    model: &ParsedModel,
+    variable: &str,
+) -> ForgeResult<(Option<String>, Option<f64>)> {
+    let (var_type, formula, current_value) = find_variable(model, variable)?;
+
+    outln!("{}", "📋 Variable Information:".bold().cyan());
+    outln!("   Type: {}", var_type.cyan());
+    if let Some(val) = current_value {
+        outln!("   Current Value: {}", format_number(val).bold().green());
+    }
+    if let Some(ref f) = formula {
+        outln!("   Formula: {}", f.bright_yellow());
+    }
+    if let Some(metadata) = variable_metadata(model, variable) {
+        for (label, text) in metadata_notes(metadata) {
+            outln!("   {label}: {}", text.italic());
+        }
+    }
+    outln!();
+    Ok((formula, current_value))
+}
+
+/// Metadata of a scalar or table column, if `name` is one
+#[must_use]
+pub fn variable_metadata<'a>(model: &'a ParsedModel, name: &str) -> Option<&'a Metadata> {
+    model.scalars.get(name).map(|s| &s.metadata).or_else(|| {
+        model
+            .tables
+            .values()
+            .find_map(|t| t.columns.get(name))
+            .map(|c| &c.metadata)
+    })
+}
+
+/// The reviewer-facing parts of a variable's metadata, labelled for display
+#[must_use]
+pub fn metadata_notes(metadata: &Metadata) -> Vec<(&'static str, &str)> {
+    [("Notes", &metadata.notes), ("Source", &metadata.source)]
+        .into_iter()
+        .filter_map(|(label, text)| text.as_deref().map(|t| (label, t)))
+        .collect()
+}
+
 /// Find a variable in the model and return its type, formula, and current value.
 ///
 /// # Errors
@@ -222,7 +302,7 @@ pub fn find_variable(
 /// Returns an error if recursive dependency resolution fails.
 pub fn build_dependency_tree(
     model: &crate::types::ParsedModel,
-    _name: &str,
+    name: &str,
     formula: Option<&String>,
     depth: usize,
 ) -> ForgeResult<Vec<AuditDependency>> {
@@ -243,14 +323,18 @@ pub fn build_dependency_tree(
                 dep_type: "Unknown".to_string(),
                 formula: None,
                 value: None,
+                notes: None,
+                source: None,
                 children: vec![],
             };
 
             // Try to find this reference in the model
-            if let Some(scalar) = model.scalars.get(&ref_name) {
+            if let Some(scalar) = resolve_scalar(model, name, &ref_name) {
                 dep.dep_type = "Scalar".to_string();
                 dep.formula.clone_from(&scalar.formula);
                 dep.value = scalar.value;
+                dep.notes.clone_from(&scalar.metadata.notes);
+                dep.source.clone_from(&scalar.metadata.source);
 
                 // Recursively get children
                 if scalar.formula.is_some() {
@@ -268,9 +352,11 @@ pub fn build_dependency_tree(
             } else {
                 // Check if it's a table column
                 for (table_name, table) in &model.tables {
-                    if table.columns.contains_key(&ref_name) {
+                    if let Some(column) = table.columns.get(&ref_name) {
                         dep.dep_type = format!("Column[{table_name}]");
                         dep.formula = table.row_formulas.get(&ref_name).cloned();
+                        dep.notes.clone_from(&column.metadata.notes);
+                        dep.source.clone_from(&column.metadata.source);
                         break;
                     }
                 }
@@ -283,14 +369,191 @@ pub fn build_dependency_tree(
     Ok(deps)
 }
 
-/// Extract variable references from a formula.
+/// Build the tree of variables that depend on `name`, transitively.
+///
+/// Scans every scalar, aggregation and table row formula for references that
+/// resolve to `name` (short scalar names resolve as in
+/// [`build_dependency_tree`]), then does the same for each dependent found.
+/// Dependents are listed scalars first, then aggregations, then columns, each
+/// alphabetically.
+#[must_use]
+pub fn build_dependents_tree(
+    model: &ParsedModel,
+    name: &str,
+    depth: usize,
+) -> Vec<AuditDependency> {
+    // Prevent infinite recursion
+    if depth > 20 {
+        return vec![];
+    }
+
+    let target_scalar = model.scalars.get(name);
+    let references_target = |dependent: &str, formula: &str| {
+        dependent != name
+            && extract_references_from_formula(formula).iter().any(|r| {
+                target_scalar.map_or_else(
+                    || r == name,
+                    |target| {
+                        resolve_scalar(model, dependent, r).is_some_and(|s| std::ptr::eq(s, target))
+                    },
+                )
+            })
+    };
+
+    let mut dependents = Vec::new();
+    let mut scalars: Vec<_> = model.scalars.iter().collect();
+    scalars.sort_by(|a, b| a.0.cmp(b.0));
+    for (scalar_name, scalar) in scalars {
+        if let Some(formula) = scalar.formula.as_deref() {
+            if references_target(scalar_name, formula) {
+                dependents.push(AuditDependency {
+                    name: scalar_name.clone(),
+                    dep_type: "Scalar".to_string(),
+                    formula: scalar.formula.clone(),
+                    value: scalar.value,
+                    notes: scalar.metadata.notes.clone(),
+                    source: scalar.metadata.source.clone(),
+                    children: build_dependents_tree(model, scalar_name, depth + 1),
+                });
+            }
+        }
+    }
+
+    let mut aggregations: Vec<_> = model.aggregations.iter().collect();
+    aggregations.sort_by(|a, b| a.0.cmp(b.0));
+    for (agg_name, formula) in aggregations {
+        if references_target(agg_name, formula) {
+            dependents.push(AuditDependency {
+                name: agg_name.clone(),
+                dep_type: "Aggregation".to_string(),
+                formula: Some(formula.clone()),
+                value: None,
+                notes: None,
+                source: None,
+                children: build_dependents_tree(model, agg_name, depth + 1),
+            });
+        }
+    }
+
+    let mut tables: Vec<_> = model.tables.iter().collect();
+    tables.sort_by(|a, b| a.0.cmp(b.0));
+    for (table_name, table) in tables {
+        let mut columns: Vec<_> = table.row_formulas.iter().collect();
+        columns.sort_by(|a, b| a.0.cmp(b.0));
+        for (col_name, formula) in columns {
+            if references_target(col_name, formula) {
+                let metadata = table.columns.get(col_name).map(|c| &c.metadata);
+                dependents.push(AuditDependency {
+                    name: col_name.clone(),
+                    dep_type: format!("Column[{table_name}]"),
+                    formula: Some(formula.clone()),
+                    value: None,
+                    notes: metadata.and_then(|m| m.notes.clone()),
+                    source: metadata.and_then(|m| m.source.clone()),
+                    children: build_dependents_tree(model, col_name, depth + 1),
+                });
+            }
+        }
+    }
+
+    dependents
+}
+
+/// Look up a scalar by its full name, or by short name like the calculator
+/// does, preferring the section of the variable whose formula references it
+/// (`price` in `summary.total` resolves to `summary.price`)
+pub(super) fn resolve_scalar<'a>(
+    model: &'a crate::types::ParsedModel,
+    context: &str,
+    reference: &str,
+) -> Option<&'a crate::types::Variable> {
+    model
+        .scalars
+        .get(reference)
+        .or_else(|| {
+            let (section, _) = context.rsplit_once('.')?;
+            model.scalars.get(&format!("{section}.{reference}"))
+        })
+        .or_else(|| {
+            model
+                .scalars
+                .iter()
+                .find(|(key, _)| {
+                    key.rsplit_once('.')
+                        .is_some_and(|(_, short)| short == reference)
+                })
+                .map(|(_, scalar)| scalar)
+        })
+}
+
+/// How a scalar's value is produced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScalarExplanation {
+    /// The scalar's formula (`None` for a literal value)
+    pub formula: Option<String>,
+    /// One `name = value` line per input the formula references
+    pub inputs: Vec<String>,
+}
+
+/// Explain a scalar: its formula and the value in `model` of each input it references
 ///
-/// # Panics
+/// Pass a calculated model to see the values behind the expected result.
+/// References that resolve to nothing (e.g. table names) are omitted.
 ///
-/// Panics if a non-empty word has no first character, which cannot happen since
-/// empty words are skipped.
+/// # Errors
+///
+/// Returns an error if the scalar is not found or its dependencies cannot be resolved.
+pub fn explain_scalar(
+    model: &crate::types::ParsedModel,
+    name: &str,
+) -> ForgeResult<ScalarExplanation> {
+    let (_, formula, _) = find_variable(model, name)?;
+    let mut inputs = Vec::new();
+    for dep in build_dependency_tree(model, name, formula.as_ref(), 0)? {
+        if let Some(value) = dep.value {
+            inputs.push(format!("{} = {}", dep.name, format_number(value)));
+        } else if let Some(column) = model.tables.values().find_map(|t| t.columns.get(&dep.name)) {
+            inputs.push(format!("{} = {}", dep.name, preview_column(&column.values)));
+        }
+    }
+    Ok(ScalarExplanation { formula, inputs })
+}
+
+/// First few values of a column, e.g. `[100, 200, 300, ...] (8 rows)`
+fn preview_column(values: &crate::types::ColumnValue) -> String {
+    use crate::types::ColumnValue;
+    const SHOWN: usize = 5;
+    let items: Vec<String> = match values {
+        ColumnValue::Number(v) => v.iter().take(SHOWN).map(|n| format_number(*n)).collect(),
+        ColumnValue::Text(v) | ColumnValue::Date(v) => v.iter().take(SHOWN).cloned().collect(),
+        ColumnValue::Boolean(v) => v.iter().take(SHOWN).map(ToString::to_string).collect(),
+    };
+    let rows = values.len();
+    if rows > SHOWN {
+        format!("[{}, ...] ({rows} rows)", items.join(", "))
+    } else {
+        format!("[{}]", items.join(", "))
+    }
+}
+
+/// Extract variable references from a formula.
 #[must_use]
 pub fn extract_references_from_formula(formula: &str) -> Vec<String> {
+    extract_references(formula, |c| c.is_alphanumeric() || c == '_')
+}
+
+/// Extract variable references from a formula, keeping dotted paths and
+/// cross-file references whole (`sales.revenue`, `@pricing.rate`).
+#[must_use]
+pub fn extract_qualified_references(formula: &str) -> Vec<String> {
+    extract_references(formula, |c| {
+        c.is_alphanumeric() || matches!(c, '_' | '.' | '@')
+    })
+}
+
+/// Split a formula into words of `is_word_char` characters, skipping numbers,
+/// function names and the contents of string literals
+fn extract_references(formula: &str, is_word_char: fn(char) -> bool) -> Vec<String> {
     let formula = formula.trim_start_matches('=');
     // Strip string literals to avoid parsing their contents as variable references
     // e.g., =LEN("Hello") should not treat "Hello" as a variable reference
@@ -341,6 +604,8 @@ pub fn extract_references_from_formula(formula: &str) -> Vec<String> {
         "MATCH",
         "INDEX",
         "XLOOKUP",
+        "XMATCH",
+        "LOOKUP",
         "VLOOKUP",
         "IFERROR",
         "TRUE",
@@ -349,12 +614,12 @@ pub fn extract_references_from_formula(formula: &str) -> Vec<String> {
         "COUNTUNIQUE",
     ];
 
-    for word in formula_stripped.split(|c: char| !c.is_alphanumeric() && c != '_') {
+    for word in formula_stripped.split(|c: char| !is_word_char(c)) {
         if word.is_empty() {
             continue;
         }
         // Skip if starts with number
-        if word.chars().next().unwrap().is_numeric() {
+        if word.starts_with(|c: char| c.is_numeric() || c == '.') {
             continue;
         }
         // Skip function names
@@ -375,20 +640,158 @@ pub fn print_dependency(dep: &AuditDependency, indent: usize) {
     let prefix = "   ".repeat(indent);
     let arrow = if indent > 0 { "└─ " } else { "" };
 
-    print!("{}{}{} ", prefix, arrow, dep.name.bright_blue());
-    print!("({})", dep.dep_type.cyan());
+    out!("{}{}{} ", prefix, arrow, dep.name.bright_blue());
+    out!("({})", dep.dep_type.cyan());
 
     if let Some(val) = dep.value {
-        print!(" = {}", format_number(val).green());
+        out!(" = {}", format_number(val).green());
     }
 
     if let Some(ref f) = dep.formula {
-        print!(" {}", f.yellow());
+        out!(" {}", f.yellow());
     }
 
-    println!();
+    outln!();
+
+    let detail_prefix = "   ".repeat(indent + 1);
+    for (label, text) in [("Notes", &dep.notes), ("Source", &dep.source)] {
+        if let Some(text) = text {
+            outln!("{detail_prefix}{label}: {}", text.italic().dimmed());
+        }
+    }
 
     for child in &dep.children {
         print_dependency(child, indent + 1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::theme::capture;
+    use super::*;
+    use crate::parser;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// `summary.total` was saved as 100 but its inputs now give 36
+    const STALE_MODEL: &str = r#"_forge_version: "5.0.0"
+sales:
+  revenue: [10, 20, 30]
+summary:
+  price:
+    value: 12
+    formula: null
+    source: 2025 price list
+  qty:
+    value: 3
+    formula: null
+  total:
+    value: 100
+    formula: "=price * qty"
+    notes: Units times list price
+  revenue_total:
+    value: 60
+    formula: "=SUM(sales.revenue)"
+"#;
+
+    #[test]
+    fn test_explain_stale_scalar() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("model.yaml");
+        fs::write(&path, STALE_MODEL).unwrap();
+
+        let model = parser::parse_model(&path).unwrap();
+        let calculated = ArrayCalculator::new(model).calculate_all().unwrap();
+        let explained = explain_scalar(&calculated, "summary.total").unwrap();
+        assert_eq!(explained.formula.as_deref(), Some("=price * qty"));
+        assert_eq!(explained.inputs, vec!["price = 12", "qty = 3"]);
+
+        let explained = explain_scalar(&calculated, "summary.revenue_total").unwrap();
+        assert!(explained
+            .inputs
+            .contains(&"revenue = [10, 20, 30]".to_string()));
+
+        let output = capture(|| {
+            assert!(super::super::validate(
+                std::slice::from_ref(&path),
+                true,
+                false,
+                ParseLimits::default()
+            )
+            .is_err());
+        });
+        assert!(output.contains("=price * qty"), "{output}");
+        assert!(output.contains("price = 12"), "{output}");
+        // The mismatch report carries the stale scalar's notes too
+        assert!(output.contains("Notes:"), "{output}");
+        assert!(output.contains("Units times list price"), "{output}");
+    }
+
+    #[test]
+    fn test_audit_shows_notes_and_source() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("model.yaml");
+        fs::write(&path, STALE_MODEL).unwrap();
+
+        let output = capture(|| audit(&path, "summary.total", ParseLimits::default()).unwrap());
+        assert!(output.contains("Notes: "), "{output}");
+        assert!(output.contains("Units times list price"), "{output}");
+        // Dependencies show their own metadata in the tree
+        assert!(output.contains("Source: "), "{output}");
+        assert!(output.contains("2025 price list"), "{output}");
+
+        let result = audit_core(&path, "summary.total", ParseLimits::default()).unwrap();
+        assert_eq!(result.notes.as_deref(), Some("Units times list price"));
+        assert_eq!(
+            result.dependencies[0].source.as_deref(),
+            Some("2025 price list")
+        );
+    }
+
+    #[test]
+    fn test_dependents_tree_walks_downward() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("model.yaml");
+        fs::write(
+            &path,
+            r#"_forge_version: "5.0.0"
+orders:
+  amount: [100, 200]
+  taxed: "=amount * (1 + tax_rate)"
+tax_rate:
+  value: 0.2
+  formula: null
+summary:
+  taxed_total:
+    value: null
+    formula: "=SUM(orders.taxed)"
+  report:
+    value: null
+    formula: "=taxed_total * 2"
+  unrelated:
+    value: null
+    formula: "=SUM(orders.amount)"
+"#,
+        )
+        .unwrap();
+        let model = parser::parse_model(&path).unwrap();
+
+        let tree = build_dependents_tree(&model, "tax_rate", 0);
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].name, "taxed");
+        assert_eq!(tree[0].dep_type, "Column[orders]");
+        let total = &tree[0].children[0];
+        assert_eq!(total.name, "summary.taxed_total");
+        assert_eq!(total.children[0].name, "summary.report");
+        assert!(total.children[0].children.is_empty());
+
+        // Inputs nothing references have no dependents
+        assert!(build_dependents_tree(&model, "summary.report", 0).is_empty());
+
+        let output =
+            capture(|| audit_dependents(&path, "tax_rate", ParseLimits::default()).unwrap());
+        assert!(output.contains("Dependents Tree"), "{output}");
+        assert!(output.contains("summary.report"), "{output}");
+        assert!(!output.contains("summary.unrelated"), "{output}");
+    }
+}
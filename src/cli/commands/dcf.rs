@@ -0,0 +1,239 @@
+//! DCF command - build a discounted cash flow schedule
+//!
+//! Without dates, flows are discounted like NPV: the first flow sits one
+//! period out. With dates, each flow is discounted by the years elapsed since
+//! the first date (actual/365), like XNPV. The summed NPV comes from the
+//! formula evaluator, so the schedule always agrees with `NPV`/`XNPV`.
+
+use super::results::{DcfResult, DcfRow};
+use super::sample::number_value;
+use crate::core::array_calculator::evaluator::{evaluate, EvalContext, Value};
+use crate::core::array_calculator::parser::parse;
+use crate::core::array_calculator::tokenizer::tokenize;
+use crate::error::{ForgeError, ForgeResult};
+use crate::writer::to_yaml;
+use chrono::NaiveDate;
+use colored::Colorize;
+use serde_yaml_ng::{Mapping, Value as YamlValue};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Build the discounted cash flow schedule for `flows` at `rate`.
+///
+/// # Errors
+///
+/// Returns an error if there are no flows, the rate is -100% or below, the
+/// dates are malformed or do not match the flows one-to-one, or the
+/// evaluator rejects the NPV calculation.
+pub fn dcf_core(rate: f64, flows: &[f64], dates: Option<&[String]>) -> ForgeResult<DcfResult> {
+    if flows.is_empty() {
+        return Err(ForgeError::Validation(
+            "DCF requires at least one cash flow".to_string(),
+        ));
+    }
+    if rate <= -1.0 {
+        return Err(ForgeError::Validation(format!(
+            "DCF rate must be greater than -1 (got {rate})"
+        )));
+    }
+
+    let serials = dates.map(|d| date_serials(d, flows.len())).transpose()?;
+    #[allow(clippy::cast_precision_loss)] // period counts are small
+    let periods: Vec<f64> = serials.as_ref().map_or_else(
+        || (1..=flows.len()).map(|p| p as f64).collect(),
+        |serials| serials.iter().map(|s| (s - serials[0]) / 365.0).collect(),
+    );
+
+    let rows = flows
+        .iter()
+        .zip(&periods)
+        .enumerate()
+        .map(|(i, (&cash_flow, &period))| {
+            let discount_factor = (1.0 + rate).powf(-period);
+            DcfRow {
+                period,
+                date: dates.map(|d| d[i].clone()),
+                cash_flow,
+                discount_factor,
+                discounted_value: cash_flow * discount_factor,
+            }
+        })
+        .collect();
+
+    Ok(DcfResult {
+        rate,
+        rows,
+        npv: evaluate_npv(rate, flows, serials.as_deref())?,
+    })
+}
+
+/// Excel date serials for ISO dates, checked against the flow count
+///
+/// # Panics
+///
+/// Cannot panic in practice: the Excel epoch date (1899-12-30) is always valid.
+fn date_serials(dates: &[String], count: usize) -> ForgeResult<Vec<f64>> {
+    if dates.len() != count {
+        return Err(ForgeError::Validation(format!(
+            "DCF has {count} cash flows but {} dates",
+            dates.len()
+        )));
+    }
+    let epoch = NaiveDate::from_ymd_opt(1899, 12, 30).expect("Excel epoch is a valid date");
+    #[allow(clippy::cast_precision_loss)] // day counts are small
+    let serial = |date: NaiveDate| (date - epoch).num_days() as f64;
+    dates
+        .iter()
+        .map(|d| {
+            NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d")
+                .map(serial)
+                .map_err(|_| ForgeError::Validation(format!("Invalid date '{d}' (use YYYY-MM-DD)")))
+        })
+        .collect()
+}
+
+/// NPV (or XNPV with date serials) through the formula evaluator
+fn evaluate_npv(rate: f64, flows: &[f64], serials: Option<&[f64]>) -> ForgeResult<f64> {
+    let numbers =
+        |values: &[f64]| -> Vec<Value> { values.iter().map(|n| Value::Number(*n)).collect() };
+    let mut columns = HashMap::from([("flows".to_string(), numbers(flows))]);
+    let formula = serials.map_or_else(
+        || format!("NPV({rate}, t.flows)"),
+        |serials| {
+            columns.insert("dates".to_string(), numbers(serials));
+            format!("XNPV({rate}, t.flows, t.dates)")
+        },
+    );
+
+    let mut ctx = EvalContext::new();
+    ctx.tables.insert("t".to_string(), columns);
+    let tokens = tokenize(&formula).map_err(|e| ForgeError::Eval(e.message))?;
+    let ast = parse(tokens).map_err(|e| ForgeError::Eval(e.message))?;
+    evaluate(&ast, &ctx)
+        .map_err(|e| ForgeError::Eval(e.message))?
+        .as_number()
+        .ok_or_else(|| ForgeError::Eval(format!("{formula} did not return a number")))
+}
+
+/// Render a schedule as a standalone Forge model: a `dcf` table and a
+/// `summary` group whose `npv` re-sums the discounted values
+#[must_use]
+pub fn render_dcf_yaml(result: &DcfResult) -> String {
+    let column = |f: fn(&DcfRow) -> f64| -> YamlValue {
+        YamlValue::Sequence(result.rows.iter().map(|r| number_value(f(r))).collect())
+    };
+
+    let mut table = Mapping::new();
+    table.insert("period".into(), column(|r| r.period));
+    if result.rows.iter().all(|r| r.date.is_some()) {
+        let dates = result
+            .rows
+            .iter()
+            .filter_map(|r| r.date.clone().map(YamlValue::String))
+            .collect();
+        table.insert("date".into(), YamlValue::Sequence(dates));
+    }
+    table.insert("cash_flow".into(), column(|r| r.cash_flow));
+    table.insert("discount_factor".into(), column(|r| r.discount_factor));
+    table.insert("discounted_value".into(), column(|r| r.discounted_value));
+
+    let mut rate = Mapping::new();
+    rate.insert("value".into(), number_value(result.rate));
+    let mut npv = Mapping::new();
+    npv.insert("value".into(), number_value(result.npv));
+    npv.insert("formula".into(), "=SUM(dcf.discounted_value)".into());
+    let mut summary = Mapping::new();
+    summary.insert("rate".into(), YamlValue::Mapping(rate));
+    summary.insert("npv".into(), YamlValue::Mapping(npv));
+
+    let mut root = Mapping::new();
+    root.insert("_forge_version".into(), "5.0.0".into());
+    root.insert("dcf".into(), YamlValue::Mapping(table));
+    root.insert("summary".into(), YamlValue::Mapping(summary));
+    format!(
+        "# Discounted cash flows at {} per period\n{}",
+        result.rate,
+        to_yaml(&YamlValue::Mapping(root))
+    )
+}
+
+/// Execute the dcf command - write (or print) a discounted cash flow schedule.
+///
+/// # Errors
+///
+/// Returns an error if the schedule cannot be built (see [`dcf_core`]) or
+/// the output file cannot be written.
+pub fn dcf(
+    rate: f64,
+    flows: &[f64],
+    dates: Option<&[String]>,
+    output: Option<&Path>,
+) -> ForgeResult<()> {
+    let result = dcf_core(rate, flows, dates)?;
+    let yaml = render_dcf_yaml(&result);
+    let Some(output) = output else {
+        out!("{yaml}");
+        return Ok(());
+    };
+
+    fs::write(output, yaml)?;
+    outln!("{}", "🔥 Forge - Discounted Cash Flows".bold().green());
+    outln!(
+        "   {} periods at {} → {}",
+        result.rows.len(),
+        super::format_number(rate),
+        output.display()
+    );
+    outln!(
+        "   {} {}",
+        "NPV:".bright_white(),
+        super::format_number(result.npv).bold()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ParseLimits;
+    use tempfile::TempDir;
+
+    fn discounted_sum(result: &DcfResult) -> f64 {
+        result.rows.iter().map(|r| r.discounted_value).sum()
+    }
+
+    #[test]
+    fn test_dcf_sums_to_npv() {
+        let result = dcf_core(0.1, &[100.0, 200.0, 300.0], None).unwrap();
+        assert!((result.npv - 481.592_787_4).abs() < 1e-6, "{}", result.npv);
+        assert!((discounted_sum(&result) - result.npv).abs() < 1e-9);
+        assert!((result.rows[0].discount_factor - 1.0 / 1.1).abs() < 1e-12);
+        assert!((result.rows[2].period - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dcf_irregular_dates_match_xnpv() {
+        let dates = ["2025-01-01", "2025-07-01", "2026-03-15"].map(String::from);
+        let result = dcf_core(0.08, &[-1000.0, 400.0, 800.0], Some(&dates)).unwrap();
+        assert!((result.rows[0].discount_factor - 1.0).abs() < f64::EPSILON);
+        assert!((result.rows[1].period - 181.0 / 365.0).abs() < 1e-12);
+        assert!((discounted_sum(&result) - result.npv).abs() < 1e-9);
+
+        let short = ["2025-01-01"].map(String::from);
+        assert!(dcf_core(0.08, &[1.0, 2.0], Some(&short)).is_err());
+        let bad = ["2025-13-01", "2026-01-01"].map(String::from);
+        assert!(dcf_core(0.08, &[1.0, 2.0], Some(&bad)).is_err());
+    }
+
+    #[test]
+    fn test_dcf_output_is_a_valid_model() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("dcf.yaml");
+        dcf(0.1, &[100.0, 200.0, 300.0], None, Some(&path)).unwrap();
+
+        let result = super::super::validate_core(&path, ParseLimits::default()).unwrap();
+        assert!(result.scalars_valid, "{:?}", result.mismatches);
+        assert_eq!(result.table_count, 1);
+    }
+}
@@ -1,5 +1,6 @@
 //! Examples command - display runnable YAML examples for Forge capabilities
 
+use crate::cli::envelope;
 use crate::error::{ForgeError, ForgeResult};
 use colored::Colorize;
 use std::io::Write;
@@ -137,8 +138,7 @@ pub fn examples_core(name: Option<&str>) -> ForgeResult<serde_json::Value> {
 pub fn examples(name: Option<String>, run: bool, json: bool) -> ForgeResult<()> {
     // JSON output mode for tooling
     if json {
-        print_examples_json();
-        return Ok(());
+        return envelope::print_outcome("examples", examples_core(name.as_deref()));
     }
 
     // No name specified - show list
@@ -157,21 +157,21 @@ pub fn examples(name: Option<String>, run: bool, json: bool) -> ForgeResult<()>
     })?;
 
     // Print the example
-    println!("{}", format!("# {}", example.name).bold().green());
-    println!("{}", format!("# {}", example.description).dimmed());
-    println!(
+    outln!("{}", format!("# {}", example.name).bold().green());
+    outln!("{}", format!("# {}", example.description).dimmed());
+    outln!(
         "{}",
         format!("# Run with: {} <file>", example.command).dimmed()
     );
-    println!();
-    println!("{}", example.content);
+    outln!();
+    outln!("{}", example.content);
 
     // Run the example if requested
     if run {
-        println!();
-        println!("{}", "─".repeat(60).dimmed());
-        println!("{}", "Running example...".bold().cyan());
-        println!();
+        outln!();
+        outln!("{}", "─".repeat(60).dimmed());
+        outln!("{}", "Running example...".bold().cyan());
+        outln!();
         run_example(example)?;
     }
 
@@ -180,43 +180,27 @@ pub fn examples(name: Option<String>, run: bool, json: bool) -> ForgeResult<()>
 
 /// Print list of available examples
 fn print_examples_list() {
-    println!("{}", "Available Forge Examples".bold().green());
-    println!();
-    println!(
+    outln!("{}", "Available Forge Examples".bold().green());
+    outln!();
+    outln!(
         "{}",
         "Forge-specific capabilities beyond Excel functions:".dimmed()
     );
-    println!();
+    outln!();
 
     for example in EXAMPLES {
-        println!(
+        outln!(
             "  {:16} {}",
             example.name.cyan().bold(),
             example.description
         );
     }
 
-    println!();
-    println!("Usage: {}", "forge examples <name>".yellow());
-    println!("       {}", "forge examples <name> --run".yellow());
-    println!();
-    println!("Example: {}", "forge examples monte-carlo".dimmed());
-}
-
-/// Print examples as JSON for tooling
-fn print_examples_json() {
-    let examples_json: Vec<serde_json::Value> = EXAMPLES
-        .iter()
-        .map(|e| {
-            serde_json::json!({
-                "name": e.name,
-                "description": e.description,
-                "command": e.command
-            })
-        })
-        .collect();
-
-    println!("{}", serde_json::to_string_pretty(&examples_json).unwrap());
+    outln!();
+    outln!("Usage: {}", "forge examples <name>".yellow());
+    outln!("       {}", "forge examples <name> --run".yellow());
+    outln!();
+    outln!("Example: {}", "forge examples monte-carlo".dimmed());
 }
 
 /// Run an example by writing to temp file and executing
@@ -258,7 +242,7 @@ fn run_example(example: &Example) -> ForgeResult<()> {
 
     // Print output
     if !output.stdout.is_empty() {
-        print!("{}", String::from_utf8_lossy(&output.stdout));
+        out!("{}", String::from_utf8_lossy(&output.stdout));
     }
     if !output.stderr.is_empty() {
         eprint!("{}", String::from_utf8_lossy(&output.stderr));
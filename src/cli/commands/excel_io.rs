@@ -1,9 +1,10 @@
 //! Excel export and import commands
 
 use crate::error::{ForgeError, ForgeResult};
-use crate::excel::{ExcelExporter, ExcelImporter};
-use crate::parser;
+use crate::excel::{ColumnType, ExcelExporter, ExcelImporter};
+use crate::parser::ParseLimits;
 use colored::Colorize;
+use std::collections::HashMap;
 use std::fmt::Write as _;
 use std::fs;
 use std::path::Path;
@@ -13,8 +14,12 @@ use std::path::Path;
 /// # Errors
 ///
 /// Returns an error if the YAML file cannot be parsed or the Excel export fails.
-pub fn export_core(input: &Path, output: &Path) -> ForgeResult<super::results::ExportResult> {
-    let model = parser::parse_model(input)?;
+pub fn export_core(
+    input: &Path,
+    output: &Path,
+    limits: ParseLimits,
+) -> ForgeResult<super::results::ExportResult> {
+    let model = super::load_model(input, limits)?;
     let table_count = model.tables.len();
     let scalar_count = model.scalars.len();
 
@@ -34,10 +39,13 @@ pub fn export_core(input: &Path, output: &Path) -> ForgeResult<super::results::E
 /// # Errors
 ///
 /// Returns an error if the YAML file cannot be parsed or the Excel buffer export fails.
-pub fn export_buffer_core(input: &Path) -> ForgeResult<super::results::ExportBufferResult> {
+pub fn export_buffer_core(
+    input: &Path,
+    limits: ParseLimits,
+) -> ForgeResult<super::results::ExportBufferResult> {
     use base64::{engine::general_purpose::STANDARD, Engine as _};
 
-    let model = parser::parse_model(input)?;
+    let model = super::load_model(input, limits)?;
     let table_count = model.tables.len();
     let scalar_count = model.scalars.len();
 
@@ -53,6 +61,51 @@ pub fn export_buffer_core(input: &Path) -> ForgeResult<super::results::ExportBuf
     })
 }
 
+/// Parse `--types` entries of the form `column=Type` or `table.column=Type`.
+///
+/// # Errors
+///
+/// Returns an error if an entry has no `=` or names an unknown type.
+pub fn parse_column_types(types: &[String]) -> ForgeResult<HashMap<String, ColumnType>> {
+    types
+        .iter()
+        .map(|entry| {
+            let (column, column_type) = entry.split_once('=').ok_or_else(|| {
+                ForgeError::Import(format!(
+                    "Invalid --types entry '{entry}' (expected col=Type)"
+                ))
+            })?;
+            Ok((column.trim().to_string(), column_type.parse()?))
+        })
+        .collect()
+}
+
+/// Importer for `input` configured from the import flags
+/// How `forge import` reads the workbook and lays out the YAML
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImportOptions<'a> {
+    /// Write one YAML file per sheet into the output directory
+    pub split_files: bool,
+    /// Write one YAML document per sheet into a single file
+    pub multi_doc: bool,
+    /// Column type overrides as `table.column=type`
+    pub types: &'a [String],
+    /// Import only these sheets (all when empty)
+    pub sheets: &'a [String],
+    /// Import only this A1 range of each sheet
+    pub range: Option<&'a str>,
+}
+
+fn build_importer(input: &Path, options: &ImportOptions) -> ForgeResult<ExcelImporter> {
+    let importer = ExcelImporter::new(input)
+        .with_column_types(parse_column_types(options.types)?)
+        .with_sheets(options.sheets.to_vec());
+    match options.range {
+        Some(range) => importer.with_range(range),
+        None => Ok(importer),
+    }
+}
+
 /// Import Excel to YAML and return structured results (no printing).
 ///
 /// # Errors
@@ -61,10 +114,14 @@ pub fn export_buffer_core(input: &Path) -> ForgeResult<super::results::ExportBuf
 pub fn import_core(
     input: &Path,
     output: &Path,
-    split_files: bool,
-    multi_doc: bool,
+    options: &ImportOptions,
 ) -> ForgeResult<super::results::ImportResult> {
-    let importer = ExcelImporter::new(input);
+    let ImportOptions {
+        split_files,
+        multi_doc,
+        ..
+    } = *options;
+    let importer = build_importer(input, options)?;
     let model = importer.import()?;
     let table_count = model.tables.len();
     let scalar_count = model.scalars.len();
@@ -127,19 +184,19 @@ fn write_split_files_quiet(output: &Path, model: crate::types::ParsedModel) -> F
 /// # Errors
 ///
 /// Returns an error if the YAML file cannot be parsed or the Excel export fails.
-pub fn export(input: &Path, output: &Path, verbose: bool) -> ForgeResult<()> {
-    println!("{}", "🔥 Forge - Excel Export".bold().green());
-    println!("   Input:  {}", input.display());
-    println!("   Output: {}\n", output.display());
+pub fn export(input: &Path, output: &Path, verbose: bool, limits: ParseLimits) -> ForgeResult<()> {
+    outln!("{}", "🔥 Forge - Excel Export".bold().green());
+    outln!("   Input:  {}", input.display());
+    outln!("   Output: {}\n", output.display());
 
     if verbose {
-        println!("{}", "📖 Parsing YAML file...".cyan());
+        outln!("{}", "📖 Parsing YAML file...".cyan());
     }
 
-    let model = parser::parse_model(input)?;
+    let model = super::load_model(input, limits)?;
 
     if verbose {
-        println!(
+        outln!(
             "   Found {} tables, {} scalars\n",
             model.tables.len(),
             model.scalars.len()
@@ -147,22 +204,22 @@ pub fn export(input: &Path, output: &Path, verbose: bool) -> ForgeResult<()> {
     }
 
     if verbose {
-        println!("{}", "📊 Exporting to Excel...".cyan());
+        outln!("{}", "📊 Exporting to Excel...".cyan());
     }
 
     let exporter = ExcelExporter::new(model);
     exporter.export(output)?;
 
-    println!("{}", "✅ Export Complete!".bold().green());
-    println!("   Excel file: {}\n", output.display());
-    println!("{}", "✅ Phase 3: Excel Export Complete!".bold().green());
-    println!("   ✅ Table columns → Excel columns");
-    println!("   ✅ Data values exported");
-    println!("   ✅ Multiple worksheets");
-    println!("   ✅ Scalars worksheet");
-    println!("   ✅ Row formulas → Excel cell formulas (=A2-B2)");
-    println!("   ✅ Cross-table references (=Sheet!Column)");
-    println!("   ✅ Supports 60+ Excel functions (IFERROR, SUMIF, VLOOKUP, etc.)\n");
+    outln!("{}", "✅ Export Complete!".bold().green());
+    outln!("   Excel file: {}\n", output.display());
+    outln!("{}", "✅ Phase 3: Excel Export Complete!".bold().green());
+    outln!("   ✅ Table columns → Excel columns");
+    outln!("   ✅ Data values exported");
+    outln!("   ✅ Multiple worksheets");
+    outln!("   ✅ Scalars worksheet");
+    outln!("   ✅ Row formulas → Excel cell formulas (=A2-B2)");
+    outln!("   ✅ Cross-table references (=Sheet!Column)");
+    outln!("   ✅ Supports 60+ Excel functions (IFERROR, SUMIF, VLOOKUP, etc.)\n");
 
     Ok(())
 }
@@ -175,40 +232,45 @@ pub fn export(input: &Path, output: &Path, verbose: bool) -> ForgeResult<()> {
 pub fn import(
     input: &Path,
     output: &Path,
+    options: &ImportOptions,
     verbose: bool,
-    split_files: bool,
-    multi_doc: bool,
 ) -> ForgeResult<()> {
-    println!("{}", "🔥 Forge - Excel Import".bold().green());
-    println!("   Input:  {}", input.display());
-    println!("   Output: {}", output.display());
+    let ImportOptions {
+        split_files,
+        multi_doc,
+        ..
+    } = *options;
+    let importer = build_importer(input, options)?;
+
+    outln!("{}", "🔥 Forge - Excel Import".bold().green());
+    outln!("   Input:  {}", input.display());
+    outln!("   Output: {}", output.display());
     if split_files {
-        println!("   Mode:   Split files (one YAML per sheet)");
+        outln!("   Mode:   Split files (one YAML per sheet)");
     } else if multi_doc {
-        println!("   Mode:   Multi-document YAML");
+        outln!("   Mode:   Multi-document YAML");
     }
-    println!();
+    outln!();
 
     if verbose {
-        println!("{}", "📖 Reading Excel file...".cyan());
+        outln!("{}", "📖 Reading Excel file...".cyan());
     }
 
-    let importer = ExcelImporter::new(input);
     let model = importer.import()?;
 
     if verbose {
-        println!("   Found {} tables", model.tables.len());
-        println!("   Found {} scalars\n", model.scalars.len());
+        outln!("   Found {} tables", model.tables.len());
+        outln!("   Found {} scalars\n", model.scalars.len());
         for (table_name, table) in &model.tables {
-            println!("   📊 Table: {}", table_name.bright_blue());
-            println!(
+            outln!("   📊 Table: {}", table_name.bright_blue());
+            outln!(
                 "      {} columns, {} rows",
                 table.columns.len(),
                 table.row_count()
             );
         }
-        println!();
-        println!("{}", "💾 Writing YAML file(s)...".cyan());
+        outln!();
+        outln!("{}", "💾 Writing YAML file(s)...".cyan());
     }
 
     if split_files {
@@ -218,8 +280,8 @@ pub fn import(
     } else {
         let yaml_string = serde_yaml_ng::to_string(&model).map_err(ForgeError::Yaml)?;
         fs::write(output, yaml_string).map_err(ForgeError::Io)?;
-        println!("{}", "✅ Import Complete!".bold().green());
-        println!("   YAML file: {}\n", output.display());
+        outln!("{}", "✅ Import Complete!".bold().green());
+        outln!("   YAML file: {}\n", output.display());
     }
 
     print_import_summary(split_files, multi_doc);
@@ -227,19 +289,19 @@ pub fn import(
 }
 
 fn print_import_summary(split_files: bool, multi_doc: bool) {
-    println!("{}", "✅ Phase 4: Excel Import Complete!".bold().green());
-    println!("   ✅ Excel worksheets → YAML tables");
-    println!("   ✅ Data values imported");
+    outln!("{}", "✅ Phase 4: Excel Import Complete!".bold().green());
+    outln!("   ✅ Excel worksheets → YAML tables");
+    outln!("   ✅ Data values imported");
     if split_files {
-        println!("   ✅ Multiple worksheets → Separate YAML files (v4.4.2)");
+        outln!("   ✅ Multiple worksheets → Separate YAML files (v4.4.2)");
     } else if multi_doc {
-        println!("   ✅ Multiple worksheets → Multi-document YAML (v4.4.2)");
+        outln!("   ✅ Multiple worksheets → Multi-document YAML (v4.4.2)");
     } else {
-        println!("   ✅ Multiple worksheets → One YAML file");
+        outln!("   ✅ Multiple worksheets → One YAML file");
     }
-    println!("   ✅ Scalars sheet detected");
-    println!("   ✅ Formula translation (Excel → YAML syntax)");
-    println!("   ✅ Supports 60+ Excel functions (IFERROR, SUMIF, VLOOKUP, etc.)\n");
+    outln!("   ✅ Scalars sheet detected");
+    outln!("   ✅ Formula translation (Excel → YAML syntax)");
+    outln!("   ✅ Supports 60+ Excel functions (IFERROR, SUMIF, VLOOKUP, etc.)\n");
 }
 
 fn write_split_files(
@@ -259,7 +321,7 @@ fn write_split_files(
         );
         fs::write(&file_path, yaml_string).map_err(ForgeError::Io)?;
         if verbose {
-            println!("   Created: {}", file_path.display());
+            outln!("   Created: {}", file_path.display());
         }
     }
     if !model.scalars.is_empty() {
@@ -272,11 +334,11 @@ fn write_split_files(
         );
         fs::write(&file_path, yaml_string).map_err(ForgeError::Io)?;
         if verbose {
-            println!("   Created: {}", file_path.display());
+            outln!("   Created: {}", file_path.display());
         }
     }
-    println!("{}", "✅ Import Complete!".bold().green());
-    println!("   Output directory: {}\n", output.display());
+    outln!("{}", "✅ Import Complete!".bold().green());
+    outln!("   Output directory: {}\n", output.display());
     Ok(())
 }
 
@@ -302,7 +364,7 @@ fn write_multi_doc(output: &Path, model: crate::types::ParsedModel) -> ForgeResu
             .push_str(&serde_yaml_ng::to_string(&scalar_model.scalars).map_err(ForgeError::Yaml)?);
     }
     fs::write(output, yaml_output).map_err(ForgeError::Io)?;
-    println!("{}", "✅ Import Complete!".bold().green());
-    println!("   YAML file: {}\n", output.display());
+    outln!("{}", "✅ Import Complete!".bold().green());
+    outln!("   YAML file: {}\n", output.display());
     Ok(())
 }
@@ -0,0 +1,138 @@
+//! Features command - report what this build of Forge includes
+//!
+//! Lists the edition, the enterprise modules, optional cargo features and the
+//! function count, so users can tell what a given binary supports.
+
+use crate::cli::envelope;
+use crate::error::ForgeResult;
+use crate::functions::registry;
+use colored::Colorize;
+
+/// Enterprise analysis modules and the commands that expose them
+const ENTERPRISE_MODULES: &[(&str, &str)] = &[
+    ("monte_carlo", "forge simulate"),
+    ("scenarios", "forge scenarios"),
+    ("decision_trees", "forge decision-tree"),
+    ("real_options", "forge real-options"),
+    ("tornado", "forge tornado"),
+    ("bootstrap", "forge bootstrap"),
+    ("bayesian", "forge bayesian"),
+    ("schema_upgrade", "forge upgrade"),
+];
+
+/// Product edition and build, from `[package.metadata.forge]` in Cargo.toml
+const EDITION: &str = env!("FORGE_EDITION");
+const BUILD: &str = env!("FORGE_BUILD");
+
+/// Optional cargo features declared in Cargo.toml and whether each is compiled in
+fn cargo_features() -> impl Iterator<Item = (&'static str, bool)> {
+    env!("FORGE_CARGO_FEATURES")
+        .split(',')
+        .filter_map(|feature| feature.split_once(':'))
+        .map(|(name, state)| (name, state == "on"))
+}
+
+/// Return the feature report as a JSON value (no printing).
+///
+/// # Errors
+///
+/// This function is infallible but returns `ForgeResult` for API consistency.
+pub fn features_core() -> ForgeResult<serde_json::Value> {
+    Ok(serde_json::json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "edition": EDITION,
+        "build": BUILD,
+        "functions": {
+            "total": registry::all().len(),
+            "demo": registry::count_demo(),
+        },
+        "modules": ENTERPRISE_MODULES.iter().map(|(name, command)| {
+            serde_json::json!({ "name": name, "command": command, "enabled": true })
+        }).collect::<Vec<_>>(),
+        "cargo_features": cargo_features().map(|(name, enabled)| {
+            serde_json::json!({ "name": name, "enabled": enabled })
+        }).collect::<Vec<_>>(),
+    }))
+}
+
+/// Execute the features command - print the compiled-in feature set.
+///
+/// # Errors
+///
+/// Returns an error if the report cannot be built.
+pub fn features(json_output: bool) -> ForgeResult<()> {
+    if json_output {
+        return envelope::print_outcome("features", features_core());
+    }
+
+    outln!("{}", "🔥 Forge - Build Features".bold().green());
+    outln!();
+    outln!(
+        "   {} {}",
+        "Version:".bright_white(),
+        env!("CARGO_PKG_VERSION")
+    );
+    outln!("   {} {EDITION} ({BUILD} build)", "Edition:".bright_white());
+    outln!(
+        "   {} {} ({} in the demo subset)",
+        "Functions:".bright_white(),
+        registry::all().len(),
+        registry::count_demo()
+    );
+
+    outln!();
+    outln!("{}", "Enterprise modules".bold().cyan());
+    outln!("{}", "─".repeat(50));
+    for (name, command) in ENTERPRISE_MODULES {
+        outln!("  {} {:16} {}", "✓".green(), name, command.bright_black());
+    }
+
+    outln!();
+    outln!("{}", "Cargo features".bold().cyan());
+    outln!("{}", "─".repeat(50));
+    for (name, enabled) in cargo_features() {
+        if enabled {
+            outln!("  {} {name}", "✓".green());
+        } else {
+            outln!(
+                "  {} {:16} {}",
+                "✗".red(),
+                name,
+                format!("rebuild with --features {name}").bright_black()
+            );
+        }
+    }
+    outln!();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_count_matches_registry() {
+        let report = features_core().unwrap();
+        assert_eq!(
+            report["functions"]["total"].as_u64(),
+            Some(registry::all().len() as u64)
+        );
+        assert_eq!(
+            report["functions"]["demo"].as_u64(),
+            Some(registry::count_demo() as u64)
+        );
+    }
+
+    #[test]
+    fn test_cargo_features_reflect_build() {
+        let report = features_core().unwrap();
+        let parquet = report["cargo_features"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["name"] == "parquet")
+            .unwrap();
+        assert_eq!(parquet["enabled"], cfg!(feature = "parquet"));
+    }
+}
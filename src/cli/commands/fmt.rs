@@ -0,0 +1,105 @@
+//! Fmt command - rewrite YAML models in canonical style
+//!
+//! Files are rewritten in place after a `.yaml.bak` backup, like other
+//! write-back commands. Files with comments the formatter would drop are
+//! reported and left untouched.
+
+use crate::error::{ForgeError, ForgeResult};
+use crate::writer::format_yaml;
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Format `file`, returning the canonical text and whether it differs from the file
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or is not valid YAML.
+pub fn fmt_core(file: &Path) -> ForgeResult<(String, bool)> {
+    let content = fs::read_to_string(file)
+        .map_err(|e| ForgeError::IO(format!("Failed to read {}: {}", file.display(), e)))?;
+    let formatted =
+        format_yaml(&content).map_err(|e| ForgeError::Parse(format!("{}: {e}", file.display())))?;
+    let changed = formatted != content;
+    Ok((formatted, changed))
+}
+
+/// Execute the fmt command - format files in place, or report unformatted files with `check`.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read, parsed or written, or if `check`
+/// is set and any file is not already formatted.
+pub fn fmt(files: &[PathBuf], check: bool) -> ForgeResult<()> {
+    let mut unformatted = 0;
+    for file in files {
+        let (formatted, changed) = fmt_core(file)?;
+        if !changed {
+            outln!("   {} {}", "✓".green(), file.display());
+        } else if check {
+            unformatted += 1;
+            outln!("   {} {} (needs formatting)", "✗".red(), file.display());
+        } else {
+            let backup = file.with_extension("yaml.bak");
+            fs::copy(file, &backup).map_err(|e| {
+                ForgeError::IO(format!("Failed to back up {}: {}", file.display(), e))
+            })?;
+            fs::write(file, formatted).map_err(|e| {
+                ForgeError::IO(format!("Failed to write {}: {}", file.display(), e))
+            })?;
+            outln!("   {} {} (formatted)", "↻".cyan(), file.display());
+        }
+    }
+
+    if unformatted > 0 {
+        return Err(ForgeError::Validation(format!(
+            "{unformatted} file(s) need formatting (run 'forge fmt' to fix)"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_fmt_check_flags_then_formats_untidy_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("untidy.yaml");
+        fs::write(
+            &path,
+            "price:\n    formula: =cost * 2\n    value: 20\ncost: {value: 10}\n_forge_version: '5.0.0'\n",
+        )
+        .unwrap();
+        let files = vec![path.clone()];
+
+        let err = fmt(&files, true).unwrap_err().to_string();
+        assert!(err.contains("1 file(s) need formatting"));
+
+        fmt(&files, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "_forge_version: \"5.0.0\"\nprice:\n  value: 20\n  formula: \"=cost * 2\"\ncost:\n  value: 10\n"
+        );
+        assert!(fmt(&files, true).is_ok());
+        assert!(path.with_extension("yaml.bak").exists());
+    }
+
+    #[test]
+    fn test_fmt_leaves_file_with_body_comments_untouched() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("commented.yaml");
+        let original =
+            "# Budget\ncost: {value: 10}\n# Pricing: twice cost\nprice:\n    formula: =cost * 2\n";
+        fs::write(&path, original).unwrap();
+
+        let err = fmt(std::slice::from_ref(&path), false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'# Pricing: twice cost'"), "{err}");
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+        assert!(!path.with_extension("yaml.bak").exists());
+    }
+}
@@ -3,6 +3,7 @@
 //! Uses the function registry as the single source of truth.
 //! See src/functions/registry.rs for the authoritative list.
 
+use crate::cli::envelope;
 use crate::error::ForgeResult;
 use crate::functions::registry::{self, Category, FunctionDef};
 use colored::Colorize;
@@ -14,20 +15,11 @@ use std::collections::BTreeMap;
 ///
 /// This function is infallible but returns `ForgeResult` for API consistency.
 pub fn functions_core() -> ForgeResult<serde_json::Value> {
-    let all_functions: Vec<&FunctionDef> = registry::enterprise_functions().collect();
-    let total = all_functions.len();
-
-    let mut by_category: BTreeMap<String, Vec<&FunctionDef>> = BTreeMap::new();
-    for func in &all_functions {
-        by_category
-            .entry(func.category.to_string())
-            .or_default()
-            .push(func);
-    }
+    let by_category = group_by_category();
 
     Ok(serde_json::json!({
-        "total": total,
-        "edition": "enterprise",
+        "total": registry::all().len(),
+        "edition": env!("FORGE_EDITION"),
         "categories": by_category.iter().map(|(name, funcs)| {
             serde_json::json!({
                 "name": name,
@@ -37,6 +29,8 @@ pub fn functions_core() -> ForgeResult<serde_json::Value> {
                         "name": f.name,
                         "description": f.description,
                         "syntax": f.syntax,
+                        "min_args": f.min_args,
+                        "max_args": f.max_args,
                         "scalar": f.scalar
                     })
                 }).collect::<Vec<_>>()
@@ -45,65 +39,42 @@ pub fn functions_core() -> ForgeResult<serde_json::Value> {
     }))
 }
 
-/// Execute the functions command - list all supported Excel-compatible functions.
-///
-/// # Errors
-///
-/// Returns an error if function listing fails.
-///
-/// # Panics
-///
-/// Panics if JSON serialization of the function registry fails, which should
-/// never happen with static data.
-pub fn functions(json_output: bool) -> ForgeResult<()> {
-    // Get all functions from registry
-    let all_functions: Vec<&FunctionDef> = registry::enterprise_functions().collect();
-
-    let total = all_functions.len();
-
-    // Group by category
+/// Group registry functions by category name
+fn group_by_category() -> BTreeMap<String, Vec<&'static FunctionDef>> {
     let mut by_category: BTreeMap<String, Vec<&FunctionDef>> = BTreeMap::new();
-    for func in &all_functions {
+    for func in registry::all() {
         by_category
             .entry(func.category.to_string())
             .or_default()
             .push(func);
     }
+    by_category
+}
 
+/// Execute the functions command - list all supported Excel-compatible functions.
+///
+/// # Errors
+///
+/// Returns an error if function listing fails.
+pub fn functions(json_output: bool) -> ForgeResult<()> {
     if json_output {
         // JSON output for tooling
-        let json = serde_json::json!({
-            "total": total,
-            "edition": "enterprise",
-            "categories": by_category.iter().map(|(name, funcs)| {
-                serde_json::json!({
-                    "name": name,
-                    "count": funcs.len(),
-                    "functions": funcs.iter().map(|f| {
-                        serde_json::json!({
-                            "name": f.name,
-                            "description": f.description,
-                            "syntax": f.syntax,
-                            "scalar": f.scalar
-                        })
-                    }).collect::<Vec<_>>()
-                })
-            }).collect::<Vec<_>>()
-        });
-        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        envelope::print_outcome("functions", functions_core())?;
     } else {
         // Human-readable output
-        println!(
+        let total = registry::all().len();
+        let by_category = group_by_category();
+        outln!(
             "{}",
             "🔥 Forge Enterprise - Supported Functions".bold().green()
         );
-        println!();
-        println!(
+        outln!();
+        outln!(
             "{}",
             format!("   {total} Excel-compatible functions for financial modeling").bright_white()
         );
-        println!();
-        println!("{}", "═".repeat(70));
+        outln!();
+        outln!("{}", "═".repeat(70));
 
         // Display order for categories
         let category_order = [
@@ -130,12 +101,12 @@ pub fn functions(json_output: bool) -> ForgeResult<()> {
                 if funcs.is_empty() {
                     continue;
                 }
-                println!();
-                println!("{} ({})", cat_name.bold().cyan(), funcs.len());
-                println!("{}", "─".repeat(70));
+                outln!();
+                outln!("{} ({})", cat_name.bold().cyan(), funcs.len());
+                outln!("{}", "─".repeat(70));
 
                 for func in funcs {
-                    println!(
+                    outln!(
                         "  {:12} {}",
                         func.name.bold().yellow(),
                         format!("{} - {}", func.description, func.syntax).bright_white()
@@ -144,15 +115,15 @@ pub fn functions(json_output: bool) -> ForgeResult<()> {
             }
         }
 
-        println!();
-        println!("{}", "═".repeat(70));
-        println!();
-        println!(
+        outln!();
+        outln!("{}", "═".repeat(70));
+        outln!();
+        outln!(
             "{}",
             "Use these functions in your YAML formulas: formula: \"=NPV(0.1, cashflows)\""
                 .bright_black()
         );
-        println!();
+        outln!();
     }
 
     Ok(())
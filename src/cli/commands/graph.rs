@@ -0,0 +1,338 @@
+//! Graph command - export a model's formula dependency graph
+//!
+//! `forge graph model.yaml` prints Graphviz DOT (or a Mermaid flowchart with
+//! `--format mermaid`) with a node per scalar and table column, and an edge
+//! from each variable to every formula that references it. Row formulas that
+//! reach into another table, and references into included files
+//! (`@alias.var`), are drawn with distinct edge styles.
+
+use super::audit::{extract_qualified_references, resolve_scalar};
+use crate::error::{ForgeError, ForgeResult};
+use crate::parser::ParseLimits;
+use crate::types::ParsedModel;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// How a reference crosses the model's structure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EdgeKind {
+    /// Within a table, or between scalars and columns
+    Local,
+    /// A row formula referencing a column of another table
+    CrossTable,
+    /// A reference into an included file (`@alias.var`)
+    CrossFile,
+}
+
+/// Variables of a model and the references between them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyGraph {
+    /// Scalars and aggregations, by full name
+    pub scalars: BTreeSet<String>,
+    /// Column names of each table; a column's node is `table.column`
+    pub tables: BTreeMap<String, BTreeSet<String>>,
+    /// Variables of included files, as `@alias.var`
+    pub external: BTreeSet<String>,
+    /// `(from, to, kind)`: `to`'s formula references `from`
+    pub edges: BTreeSet<(String, String, EdgeKind)>,
+}
+
+impl DependencyGraph {
+    /// Build the graph of every formula in `model`
+    #[must_use]
+    pub fn of(model: &ParsedModel) -> Self {
+        let mut graph = Self {
+            scalars: model
+                .scalars
+                .keys()
+                .chain(model.aggregations.keys())
+                .cloned()
+                .collect(),
+            ..Self::default()
+        };
+        for (table_name, table) in &model.tables {
+            let columns = table
+                .columns
+                .keys()
+                .chain(table.row_formulas.keys())
+                .cloned()
+                .collect();
+            graph.tables.insert(table_name.clone(), columns);
+        }
+
+        let scalar_formulas = model
+            .scalars
+            .iter()
+            .filter_map(|(name, var)| Some((name, var.formula.as_ref()?)));
+        for (name, formula) in scalar_formulas.chain(&model.aggregations) {
+            graph.add_references(model, name, None, formula);
+        }
+        for (table_name, table) in &model.tables {
+            for (col_name, formula) in &table.row_formulas {
+                let node = format!("{table_name}.{col_name}");
+                graph.add_references(model, &node, Some(table_name), formula);
+            }
+        }
+        graph
+    }
+
+    /// Add an edge to `node` for each variable its formula references
+    fn add_references(
+        &mut self,
+        model: &ParsedModel,
+        node: &str,
+        table: Option<&str>,
+        formula: &str,
+    ) {
+        for reference in extract_qualified_references(formula) {
+            if reference.starts_with('@') {
+                self.external.insert(reference.clone());
+                self.edges
+                    .insert((reference, node.to_string(), EdgeKind::CrossFile));
+                continue;
+            }
+            let Some((from, from_table)) = resolve(model, node, table, &reference) else {
+                continue;
+            };
+            let kind = match (table, from_table) {
+                (Some(own), Some(other)) if own != other => EdgeKind::CrossTable,
+                _ => EdgeKind::Local,
+            };
+            if from != node {
+                self.edges.insert((from, node.to_string(), kind));
+            }
+        }
+    }
+}
+
+/// Node a reference in `node`'s formula points at, with the table it belongs
+/// to (`None` for scalars)
+fn resolve<'a>(
+    model: &'a ParsedModel,
+    node: &str,
+    table: Option<&str>,
+    reference: &str,
+) -> Option<(String, Option<&'a str>)> {
+    let column_of = |table_name: &str, column: &str| {
+        let (name, table) = model.tables.get_key_value(table_name)?;
+        (table.columns.contains_key(column) || table.row_formulas.contains_key(column))
+            .then(|| (format!("{name}.{column}"), Some(name.as_str())))
+    };
+
+    // A row formula's bare names are its own table's columns first
+    if let Some(found) = table.and_then(|t| column_of(t, reference)) {
+        return Some(found);
+    }
+    if let Some(found) = reference
+        .split_once('.')
+        .and_then(|(t, column)| column_of(t, column))
+    {
+        return Some(found);
+    }
+    if model.aggregations.contains_key(reference) {
+        return Some((reference.to_string(), None));
+    }
+    if let Some(scalar) = resolve_scalar(model, node, reference) {
+        return Some((scalar.path.clone(), None));
+    }
+    // A bare column name used outside its table
+    let mut tables: Vec<&String> = model.tables.keys().collect();
+    tables.sort();
+    tables.into_iter().find_map(|t| column_of(t, reference))
+}
+
+/// Quote an identifier for DOT or Mermaid labels
+fn quoted(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\\\""))
+}
+
+/// Render the graph as Graphviz DOT
+#[must_use]
+pub fn render_dot(graph: &DependencyGraph) -> String {
+    let mut out = String::from("digraph forge {\n    rankdir=LR;\n    node [shape=box];\n");
+    for name in &graph.scalars {
+        let _ = writeln!(out, "    {};", quoted(name));
+    }
+    for (table, columns) in &graph.tables {
+        let _ = writeln!(
+            out,
+            "    subgraph {} {{",
+            quoted(&format!("cluster_{table}"))
+        );
+        let _ = writeln!(out, "        label={};", quoted(table));
+        for column in columns {
+            let _ = writeln!(
+                out,
+                "        {} [label={}, shape=ellipse];",
+                quoted(&format!("{table}.{column}")),
+                quoted(column)
+            );
+        }
+        out.push_str("    }\n");
+    }
+    for name in &graph.external {
+        let _ = writeln!(out, "    {} [shape=note, style=dashed];", quoted(name));
+    }
+    for (from, to, kind) in &graph.edges {
+        let style = match kind {
+            EdgeKind::Local => "",
+            EdgeKind::CrossTable => " [color=blue, penwidth=2]",
+            EdgeKind::CrossFile => " [color=darkorange, style=dashed]",
+        };
+        let _ = writeln!(out, "    {} -> {}{style};", quoted(from), quoted(to));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Render the graph as a Mermaid flowchart
+#[must_use]
+pub fn render_mermaid(graph: &DependencyGraph) -> String {
+    let mut ids: BTreeMap<String, String> = BTreeMap::new();
+    let mut id_for = |name: String| {
+        let id = format!("n{}", ids.len());
+        ids.insert(name, id.clone());
+        id
+    };
+
+    let mut out = String::from("flowchart LR\n");
+    for name in &graph.scalars {
+        let id = id_for(name.clone());
+        let _ = writeln!(out, "    {id}[{}]", quoted(name));
+    }
+    for (index, (table, columns)) in graph.tables.iter().enumerate() {
+        let _ = writeln!(out, "    subgraph t{index} [{}]", quoted(table));
+        for column in columns {
+            let id = id_for(format!("{table}.{column}"));
+            let _ = writeln!(out, "        {id}([{}])", quoted(column));
+        }
+        out.push_str("    end\n");
+    }
+    for name in &graph.external {
+        let id = id_for(name.clone());
+        let _ = writeln!(out, "    {id}[/{}/]", quoted(name));
+    }
+    for (from, to, kind) in &graph.edges {
+        let arrow = match kind {
+            EdgeKind::Local => "-->",
+            EdgeKind::CrossTable => "==>",
+            EdgeKind::CrossFile => "-.->",
+        };
+        let _ = writeln!(out, "    {} {arrow} {}", ids[from], ids[to]);
+    }
+    out
+}
+
+/// Build the dependency graph of a model file
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be parsed.
+pub fn graph_core(file: &Path, limits: ParseLimits) -> ForgeResult<DependencyGraph> {
+    let model = super::load_model(file, limits)?;
+    Ok(DependencyGraph::of(&model))
+}
+
+/// Execute the graph command: print the dependency graph as DOT or Mermaid
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be parsed or `format` is unknown.
+pub fn graph(file: &Path, format: &str, limits: ParseLimits) -> ForgeResult<()> {
+    let graph = graph_core(file, limits)?;
+    let rendered = match format {
+        "dot" => render_dot(&graph),
+        "mermaid" => render_mermaid(&graph),
+        other => {
+            return Err(ForgeError::Validation(format!(
+                "Unknown graph format '{other}' (expected dot or mermaid)"
+            )))
+        },
+    };
+    out!("{rendered}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const MODEL: &str = r#"_forge_version: "5.0.0"
+_includes:
+  - file: "pricing.yaml"
+    as: "pricing"
+sales:
+  units: [10, 20]
+  revenue: "=units * price"
+costs:
+  unit_cost: [2, 3]
+  margin: "=sales.revenue - unit_cost"
+price:
+  value: 5
+  formula: null
+total:
+  value: null
+  formula: "=SUM(costs.margin) * @pricing.markup"
+"#;
+
+    const PRICING: &str = r#"_forge_version: "5.0.0"
+markup:
+  value: 1.1
+  formula: null
+"#;
+
+    fn model_graph() -> DependencyGraph {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("pricing.yaml"), PRICING).unwrap();
+        let path = dir.path().join("model.yaml");
+        fs::write(&path, MODEL).unwrap();
+        graph_core(&path, ParseLimits::default()).unwrap()
+    }
+
+    fn edge(from: &str, to: &str, kind: EdgeKind) -> (String, String, EdgeKind) {
+        (from.to_string(), to.to_string(), kind)
+    }
+
+    #[test]
+    fn test_graph_edges_and_kinds() {
+        let graph = model_graph();
+        assert_eq!(
+            graph.edges,
+            BTreeSet::from([
+                edge("@pricing.markup", "total", EdgeKind::CrossFile),
+                edge("costs.margin", "total", EdgeKind::Local),
+                edge("costs.unit_cost", "costs.margin", EdgeKind::Local),
+                edge("price", "sales.revenue", EdgeKind::Local),
+                edge("sales.revenue", "costs.margin", EdgeKind::CrossTable),
+                edge("sales.units", "sales.revenue", EdgeKind::Local),
+            ])
+        );
+        assert_eq!(graph.tables["sales"].len(), 2);
+        assert!(graph.external.contains("@pricing.markup"));
+    }
+
+    #[test]
+    fn test_graph_renders_dot_and_mermaid() {
+        let graph = model_graph();
+        let dot = render_dot(&graph);
+        assert!(dot.starts_with("digraph forge {"), "{dot}");
+        assert!(dot.contains("subgraph \"cluster_sales\""), "{dot}");
+        assert!(
+            dot.contains("\"sales.revenue\" -> \"costs.margin\" [color=blue, penwidth=2];"),
+            "{dot}"
+        );
+        assert!(
+            dot.contains("\"@pricing.markup\" -> \"total\" [color=darkorange, style=dashed];"),
+            "{dot}"
+        );
+
+        let mermaid = render_mermaid(&graph);
+        assert!(mermaid.starts_with("flowchart LR\n"), "{mermaid}");
+        assert_eq!(mermaid.matches("==>").count(), 1, "{mermaid}");
+        assert_eq!(mermaid.matches("-.->").count(), 1, "{mermaid}");
+        assert_eq!(mermaid.matches(" --> ").count(), 4, "{mermaid}");
+    }
+}
@@ -0,0 +1,205 @@
+//! Heatmap rendering for two-variable sensitivity tables
+//!
+//! On a color terminal each cell's background runs from red (lowest output)
+//! to green (highest). Piped output gets the same matrix as CSV instead.
+
+use super::format_number;
+use super::results::{SensitivityData, SensitivityResult};
+use colored::Colorize;
+use std::fmt::Write as _;
+
+/// Width of each rendered cell
+const CELL: usize = 12;
+
+/// Axes and matrix of a two-variable result
+struct Grid<'a> {
+    vary: &'a str,
+    vary2: &'a str,
+    rows: &'a [f64],
+    cols: &'a [f64],
+    matrix: &'a [Vec<Option<f64>>],
+}
+
+impl<'a> Grid<'a> {
+    fn of(result: &'a SensitivityResult) -> Option<Self> {
+        match &result.data {
+            SensitivityData::TwoVar {
+                vary2,
+                row_values,
+                col_values,
+                matrix,
+            } => Some(Self {
+                vary: &result.vary,
+                vary2,
+                rows: row_values,
+                cols: col_values,
+                matrix,
+            }),
+            SensitivityData::OneVar { .. } => None,
+        }
+    }
+
+    /// Smallest and largest calculated value
+    fn bounds(&self) -> Option<(f64, f64)> {
+        self.matrix
+            .iter()
+            .flatten()
+            .flatten()
+            .fold(None, |acc, &v| {
+                Some(acc.map_or((v, v), |(lo, hi): (f64, f64)| (lo.min(v), hi.max(v))))
+            })
+    }
+}
+
+/// Background color for `t` in `0.0..=1.0`: red through yellow to green
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // clamped to 0..=255
+fn heat(t: f64) -> (u8, u8, u8) {
+    let t = t.clamp(0.0, 1.0);
+    let red = (2.0 * (1.0 - t)).min(1.0) * 200.0;
+    let green = (2.0 * t).min(1.0) * 180.0;
+    (red as u8 + 40, green as u8 + 40, 40)
+}
+
+/// Render a two-variable result as a colored heatmap with axis labels
+///
+/// Returns `None` for one-variable results.
+#[must_use]
+pub fn render_heatmap(result: &SensitivityResult) -> Option<String> {
+    let grid = Grid::of(result)?;
+    let (lo, hi) = grid.bounds().unwrap_or((0.0, 0.0));
+    let span = hi - lo;
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:>CELL$} {}",
+        "",
+        format!("{} →", grid.vary2).bright_yellow()
+    );
+    let _ = write!(out, "{:>CELL$}", format!("{} ↓", grid.vary).bright_yellow());
+    for col in grid.cols {
+        let _ = write!(out, "{:>CELL$}", format_number(*col).dimmed());
+    }
+    out.push('\n');
+
+    for (row, cells) in grid.rows.iter().zip(grid.matrix) {
+        let _ = write!(out, "{:>CELL$}", format_number(*row).bright_yellow());
+        for cell in cells {
+            match cell {
+                Some(value) => {
+                    let position = if span > 0.0 { (value - lo) / span } else { 0.5 };
+                    let (red, green, blue) = heat(position);
+                    let text = format!("{:>CELL$}", format_number(*value));
+                    let _ = write!(out, "{}", text.black().on_truecolor(red, green, blue));
+                },
+                None => {
+                    let _ = write!(out, "{:>CELL$}", "ERR".red());
+                },
+            }
+        }
+        out.push('\n');
+    }
+
+    let ((lr, lg, lb), (hr, hg, hb)) = (heat(0.0), heat(1.0));
+    let _ = writeln!(
+        out,
+        "\n   {}: {} (low) → {} (high)",
+        result.output.bright_blue(),
+        format_number(lo).black().on_truecolor(lr, lg, lb),
+        format_number(hi).black().on_truecolor(hr, hg, hb)
+    );
+    Some(out)
+}
+
+/// Render a two-variable result as CSV: a header row of column values,
+/// then one row per value of the first variable (failed cells left empty)
+///
+/// Returns `None` for one-variable results.
+#[must_use]
+pub fn render_csv(result: &SensitivityResult) -> Option<String> {
+    let grid = Grid::of(result)?;
+    let mut out = format!("{}\\{}", grid.vary, grid.vary2);
+    for col in grid.cols {
+        let _ = write!(out, ",{col}");
+    }
+    out.push('\n');
+    for (row, cells) in grid.rows.iter().zip(grid.matrix) {
+        let _ = write!(out, "{row}");
+        for cell in cells {
+            out.push(',');
+            if let Some(v) = cell {
+                let _ = write!(out, "{v}");
+            }
+        }
+        out.push('\n');
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::sensitivity_core;
+    use crate::parser::ParseLimits;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn two_var_result() -> SensitivityResult {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"_forge_version: "5.0.0"
+price:
+  value: 10
+units:
+  value: 100
+revenue:
+  value: null
+  formula: "=price * units"
+"#
+        )
+        .unwrap();
+        sensitivity_core(
+            file.path(),
+            "price",
+            "10,30,10",
+            Some("units"),
+            Some("100,500,100"),
+            "revenue",
+            ParseLimits::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_two_var_json_dimensions() {
+        let result = two_var_result();
+        let json = serde_json::to_value(&result).unwrap();
+        let data = &json["data"]["TwoVar"];
+        assert_eq!(data["row_values"].as_array().unwrap().len(), 3);
+        assert_eq!(data["col_values"].as_array().unwrap().len(), 5);
+
+        let matrix = data["matrix"].as_array().unwrap();
+        assert_eq!(matrix.len(), 3);
+        for row in matrix {
+            assert_eq!(row.as_array().unwrap().len(), 5);
+        }
+        assert_eq!(matrix[2][4], 15000.0);
+    }
+
+    #[test]
+    fn test_heatmap_and_csv_rendering() {
+        let result = two_var_result();
+
+        let csv = render_csv(&result).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "price\\units,100,200,300,400,500");
+        assert_eq!(lines[1], "10,1000,2000,3000,4000,5000");
+
+        let heatmap = render_heatmap(&result).unwrap();
+        assert!(heatmap.contains("15000"));
+        assert_eq!(heat(0.0), (240, 40, 40));
+        assert_eq!(heat(1.0), (40, 220, 40));
+    }
+}
@@ -0,0 +1,166 @@
+//! Output locale - decimal/thousands separators and date order
+//!
+//! Only presentation is affected: models are parsed and calculated the same
+//! way regardless of locale. Without a locale numbers keep the plain
+//! `1234.56` form and dates stay ISO `YYYY-MM-DD`.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// A supported output locale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// `1,234.56`, `12/31/2025`
+    EnUs,
+    /// `1,234.56`, `31/12/2025`
+    EnGb,
+    /// `1.234,56`, `31.12.2025`
+    DeDe,
+}
+
+impl Locale {
+    /// All supported locales, in the form accepted by `--locale`
+    pub const NAMES: &'static [&'static str] = &["en-US", "en-GB", "de-DE"];
+
+    const fn separators(self) -> (char, char) {
+        match self {
+            Self::EnUs | Self::EnGb => (',', '.'),
+            Self::DeDe => ('.', ','),
+        }
+    }
+
+    /// Format a number: rounded to 6 decimals, trailing zeros dropped,
+    /// thousands grouped
+    #[must_use]
+    pub fn format_number(self, n: f64) -> String {
+        let (thousands, decimal) = self.separators();
+        let plain = plain_number(n);
+        let (sign, digits) = plain
+            .strip_prefix('-')
+            .map_or(("", plain.as_str()), |rest| ("-", rest));
+        let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+
+        let mut out = String::from(sign);
+        for (i, c) in int_part.chars().enumerate() {
+            if i > 0 && (int_part.len() - i) % 3 == 0 {
+                out.push(thousands);
+            }
+            out.push(c);
+        }
+        if !frac_part.is_empty() {
+            out.push(decimal);
+            out.push_str(frac_part);
+        }
+        out
+    }
+
+    /// Reorder an ISO date (`YYYY-MM-DD`, or `YYYY-MM`); other text is returned as-is
+    #[must_use]
+    pub fn format_date(self, date: &str) -> String {
+        let parts: Vec<&str> = date.split('-').collect();
+        let valid = parts.len() >= 2
+            && parts.len() <= 3
+            && parts[0].len() == 4
+            && parts
+                .iter()
+                .all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()));
+        if !valid {
+            return date.to_string();
+        }
+        match (self, parts.as_slice()) {
+            (Self::EnUs, [y, m, d]) => format!("{m}/{d}/{y}"),
+            (Self::EnGb, [y, m, d]) => format!("{d}/{m}/{y}"),
+            (Self::DeDe, [y, m, d]) => format!("{d}.{m}.{y}"),
+            (Self::EnUs | Self::EnGb, [y, m]) => format!("{m}/{y}"),
+            (Self::DeDe, [y, m]) => format!("{m}.{y}"),
+            _ => date.to_string(),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.replace('_', "-").to_ascii_lowercase().as_str() {
+            "en-us" | "en" => Ok(Self::EnUs),
+            "en-gb" => Ok(Self::EnGb),
+            "de-de" | "de" => Ok(Self::DeDe),
+            _ => Err(format!(
+                "Unknown locale '{s}'. Supported: {}",
+                Self::NAMES.join(", ")
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::EnUs => "en-US",
+            Self::EnGb => "en-GB",
+            Self::DeDe => "de-DE",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Up to 6 decimal places with trailing zeros removed (`1234.5`)
+pub(super) fn plain_number(n: f64) -> String {
+    // Round to 6 decimal places for display (sufficient for most financial calculations)
+    let rounded = (n * 1e6).round() / 1e6;
+    // Format with up to 6 decimal places, removing trailing zeros
+    format!("{rounded:.6}")
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+static LOCALE: RwLock<Option<Locale>> = RwLock::new(None);
+
+/// Set the process-wide locale used by [`format_number`](super::format_number)
+/// and [`format_date`](super::format_date)
+pub fn set_locale(locale: Option<Locale>) {
+    if let Ok(mut current) = LOCALE.write() {
+        *current = locale;
+    }
+}
+
+/// Process-wide output locale (`None` keeps plain, locale-neutral output)
+#[must_use]
+pub fn locale() -> Option<Locale> {
+    LOCALE.read().ok().and_then(|l| *l)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_separators_per_locale() {
+        assert_eq!(Locale::DeDe.format_number(1234.56), "1.234,56");
+        assert_eq!(Locale::EnUs.format_number(1234.56), "1,234.56");
+        assert_eq!(Locale::EnGb.format_number(-1_234_567.0), "-1,234,567");
+        assert_eq!(Locale::DeDe.format_number(999.5), "999,5");
+        assert_eq!(Locale::DeDe.format_number(0.1 + 0.2), "0,3");
+        assert_eq!(plain_number(1234.56), "1234.56");
+    }
+
+    #[test]
+    fn test_date_order_per_locale() {
+        assert_eq!(Locale::EnUs.format_date("2025-12-31"), "12/31/2025");
+        assert_eq!(Locale::EnGb.format_date("2025-12-31"), "31/12/2025");
+        assert_eq!(Locale::DeDe.format_date("2025-12-31"), "31.12.2025");
+        assert_eq!(Locale::DeDe.format_date("2025-01"), "01.2025");
+        assert_eq!(Locale::DeDe.format_date("Q1 2025"), "Q1 2025");
+    }
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!("de_DE".parse::<Locale>(), Ok(Locale::DeDe));
+        assert_eq!("EN-us".parse::<Locale>(), Ok(Locale::EnUs));
+        assert!("fr-FR".parse::<Locale>().unwrap_err().contains("en-US"));
+        assert_eq!(Locale::EnGb.to_string(), "en-GB");
+    }
+}
@@ -1,14 +1,23 @@
 //! CLI commands for Forge
 //!
+//! Output is printed through the [`theme`] macros so `--theme plain` applies
+//! everywhere.
+//!
 //! This module provides all CLI command implementations:
 //! - calculate: Calculate formulas in YAML files
 //! - validate: Validate YAML files for consistency
 //! - watch: Watch files for changes and recalculate
 //! - audit: Show calculation dependency chain
-//! - export/import: Excel file I/O
+//! - graph: Export the formula dependency graph (DOT or Mermaid)
+//! - stats: Profile numeric columns
+//! - sample: Draw random rows from a table
+//! - dcf: Discounted cash flow schedules
+//! - export/import: Excel file I/O, single-table Markdown/JSON/Parquet export
 //! - `variance/sensitivity/goal_seek/break_even`: Analysis tools
 //! - compare: Scenario comparison
+//! - fmt: Rewrite YAML models in canonical style
 //! - functions: List supported functions
+//! - features: Report the compiled-in feature set
 //! - simulate: Monte Carlo simulation (enterprise only)
 //! - upgrade: Schema migration (enterprise only)
 //! - scenarios: Scenario analysis (enterprise only)
@@ -18,42 +27,75 @@
 //! - bootstrap: Bootstrap resampling (enterprise only)
 //! - bayesian: Bayesian network inference (enterprise only)
 
+#[macro_use]
+pub mod theme;
+
 mod analysis;
 mod audit;
+mod dcf;
 mod examples;
 mod excel_io;
+mod features;
+mod fmt;
 mod functions;
+mod graph;
+mod heatmap;
+mod locale;
 mod prediction;
 pub mod results;
+mod sample;
 mod schema;
 mod simulate;
+mod snapshot;
+mod stats;
+mod table_export;
 mod update;
 mod upgrade;
 
 // Re-exports
 pub use analysis::{break_even, compare, goal_seek, sensitivity, variance};
-pub use audit::audit;
+pub use audit::{audit, audit_dependents};
+pub use dcf::dcf;
 pub use examples::examples;
-pub use excel_io::{export, import};
+pub use excel_io::{export, import, ImportOptions};
+pub use features::features;
+pub use fmt::fmt;
 pub use functions::functions;
+pub use graph::graph;
+pub use locale::{set_locale, Locale};
 pub use prediction::{bayesian, bootstrap, decision_tree, real_options, scenarios, tornado};
+pub use sample::sample;
 pub use schema::schema;
 pub use simulate::simulate;
+pub use snapshot::snapshot;
+pub use stats::stats;
+pub use table_export::{export_json_records, export_markdown, export_parquet};
+pub use theme::{set_theme, Theme};
 pub use update::update;
 pub use upgrade::{auto_upgrade_schema, needs_schema_upgrade, upgrade};
 
 // Core function re-exports (return structured results, no printing)
-pub use analysis::{compare_core, goal_seek_core, sensitivity_core, variance_core};
+pub use analysis::{
+    break_even_core, compare_core, goal_seek_core, sensitivity_core, solve_bisection, solve_newton,
+    variance_core, GoalSeekOptions, SensitivityFormat, Solution, SolverMethod,
+};
 pub use audit::audit_core;
+pub use dcf::dcf_core;
 pub use examples::examples_core;
 pub use excel_io::{export_buffer_core, export_core, import_core};
+pub use features::features_core;
+pub use fmt::fmt_core;
 pub use functions::functions_core;
+pub use graph::{graph_core, render_dot, render_mermaid, DependencyGraph, EdgeKind};
 pub use prediction::{
     bayesian_core, bootstrap_core, decision_tree_core, real_options_core, scenarios_core,
     tornado_core,
 };
+pub use sample::sample_core;
 pub use schema::schema_core;
 pub use simulate::simulate_core;
+pub use snapshot::{snapshot_core, snapshot_path, Snapshot};
+pub use stats::stats_core;
 
 // Re-exports for tests (internal functions)
 #[cfg(test)]
@@ -69,13 +111,18 @@ pub use audit::{
 #[cfg(test)]
 pub use upgrade::split_scalars_to_inputs_outputs;
 
-use crate::core::{ArrayCalculator, UnitValidator};
+use crate::cli::envelope::JsonEnvelope;
+use crate::core::{ArrayCalculator, FormulaError, OutputSelection, UnitValidator};
 use crate::error::{ForgeError, ForgeResult};
-use crate::parser;
+use crate::excel::ExcelExporter;
+use crate::parser::{self, ParseLimits};
 use crate::writer;
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 // Watch-related imports only for non-coverage builds (see ADR-006)
 #[cfg(not(coverage))]
@@ -88,16 +135,27 @@ use std::sync::mpsc::channel;
 use std::time::Duration;
 
 /// Format a number for display, removing unnecessary decimal places
+///
+/// Separators follow the output locale set with `--locale`, if any.
 #[must_use]
 pub fn format_number(n: f64) -> String {
-    // Round to 6 decimal places for display (sufficient for most financial calculations)
-    // This also handles f32 precision artifacts from xlformula_engine
-    let rounded = (n * 1e6).round() / 1e6;
-    // Format with up to 6 decimal places, removing trailing zeros
-    format!("{rounded:.6}")
-        .trim_end_matches('0')
-        .trim_end_matches('.')
-        .to_string()
+    locale::locale().map_or_else(|| locale::plain_number(n), |l| l.format_number(n))
+}
+
+/// Format an ISO date for display in the output locale set with `--locale`
+#[must_use]
+pub fn format_date(date: &str) -> String {
+    locale::locale().map_or_else(|| date.to_string(), |l| l.format_date(date))
+}
+
+/// Parse a model for a command, printing its parse warnings (such as an
+/// assumed `_forge_version`) to stderr
+fn load_model(file: &Path, limits: ParseLimits) -> ForgeResult<crate::types::ParsedModel> {
+    let model = parser::parse_model_with_limits(file, limits)?;
+    for warning in &model.parse_warnings {
+        eoutln!("{}", format!("⚠️  {warning}").yellow());
+    }
+    Ok(model)
 }
 
 /// Calculate formulas and return structured results (no printing).
@@ -110,8 +168,9 @@ pub fn calculate_core(
     file: &Path,
     dry_run: bool,
     scenario: Option<&str>,
+    limits: ParseLimits,
 ) -> ForgeResult<results::CalculationResult> {
-    let mut model = parser::parse_model(file)?;
+    let mut model = load_model(file, limits)?;
 
     // Apply scenario overrides if specified
     if let Some(scenario_name) = scenario {
@@ -126,34 +185,13 @@ pub fn calculate_core(
         .map(ToString::to_string)
         .collect();
 
-    // Calculate
+    // Calculate, after checking every formula so all errors are reported together
+    crate::core::check(&model)?;
     let calculator = ArrayCalculator::new(model);
     let result = calculator.calculate_all()?;
 
-    // Build table summaries
-    let mut tables = std::collections::HashMap::new();
-    for (table_name, table) in &result.tables {
-        let row_count = table
-            .columns
-            .values()
-            .next()
-            .map_or(0, |col| col.values.len());
-        tables.insert(
-            table_name.clone(),
-            results::TableSummary {
-                name: table_name.clone(),
-                column_count: table.columns.len(),
-                row_count,
-                columns: table.columns.keys().cloned().collect(),
-            },
-        );
-    }
-
-    // Build scalar map
-    let mut scalars = std::collections::HashMap::new();
-    for (name, var) in &result.scalars {
-        scalars.insert(name.clone(), var.value);
-    }
+    let tables = table_summaries(&result).collect();
+    let scalars = scalar_values(&result).collect();
 
     // Write results if not dry run
     let file_updated = if dry_run {
@@ -171,27 +209,165 @@ pub fn calculate_core(
     })
 }
 
-/// Execute the calculate command
+/// Summary of each calculated table
+fn table_summaries(
+    model: &crate::types::ParsedModel,
+) -> impl Iterator<Item = (String, results::TableSummary)> + '_ {
+    model.tables.iter().map(|(table_name, table)| {
+        let row_count = table
+            .columns
+            .values()
+            .next()
+            .map_or(0, |col| col.values.len());
+        let summary = results::TableSummary {
+            name: table_name.clone(),
+            column_count: table.columns.len(),
+            row_count,
+            columns: table.columns.keys().cloned().collect(),
+        };
+        (table_name.clone(), summary)
+    })
+}
+
+/// Value of each scalar (`None` when not calculated)
+fn scalar_values(
+    model: &crate::types::ParsedModel,
+) -> impl Iterator<Item = (String, Option<f64>)> + '_ {
+    model
+        .scalars
+        .iter()
+        .map(|(name, var)| (name.clone(), var.value))
+}
+
+/// Machine-readable result of `calculate --json` and `validate --json`
+///
+/// This is the envelope's `result` for `calculate`, and the entry for each
+/// file under `result.files` for `validate`. All four keys are always present
+/// and maps are sorted by name, so CI can parse the output without regexes:
+///
+/// ```json
+/// { "tables": { "sales": { "name": "sales", "column_count": 2, ... } },
+///   "scalars": { "summary.total": 600.0 },
+///   "mismatches": [ { "name": "summary.total", "current_value": 1.0,
+///                     "expected_value": 600.0, "diff": 599.0 } ],
+///   "warnings": [] }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JsonReport {
+    /// Calculated tables by name
+    pub tables: BTreeMap<String, results::TableSummary>,
+    /// Calculated scalar values by name (`null` when not calculated)
+    pub scalars: BTreeMap<String, Option<f64>>,
+    /// Scalars whose stored value differs from the calculated one; always
+    /// empty for `calculate`, which writes the calculated values
+    pub mismatches: Vec<results::ValidationMismatch>,
+    /// Non-fatal issues such as unit warnings
+    pub warnings: Vec<String>,
+}
+
+impl From<results::CalculationResult> for JsonReport {
+    fn from(result: results::CalculationResult) -> Self {
+        Self {
+            tables: result.tables.into_iter().collect(),
+            scalars: result.scalars.into_iter().collect(),
+            mismatches: Vec::new(),
+            warnings: result.unit_warnings,
+        }
+    }
+}
+
+/// Execute the calculate command with `--json`: print the [`calculate_core`]
+/// result as a [`JsonReport`] in the standard envelope, with unit warnings
+/// also as the envelope's `warnings`.
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be parsed, calculation fails,
-/// or results cannot be written back to the file.
-pub fn calculate(
+/// Returns the calculation error after printing it in the envelope.
+pub fn calculate_json(
     file: &Path,
     dry_run: bool,
-    verbose: bool,
     scenario: Option<&str>,
+    limits: ParseLimits,
 ) -> ForgeResult<()> {
-    println!("{}", "🔥 Forge - Calculating formulas".bold().green());
-    println!("   File: {}", file.display());
+    match calculate_core(file, dry_run, scenario, limits) {
+        Ok(result) => {
+            let report = JsonReport::from(result);
+            let warnings = report.warnings.clone();
+            JsonEnvelope::new("calculate", report)
+                .with_warnings(warnings)
+                .print();
+            Ok(())
+        },
+        Err(e) => {
+            JsonEnvelope::failure("calculate", &e).print();
+            Err(e)
+        },
+    }
+}
+
+/// Flags for the calculate command
+///
+/// `Default` is a plain write-back calculation of every output.
+#[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)] // each switch toggles an independent behaviour
+pub struct CalculateOptions<'a> {
+    /// Calculate without writing results
+    pub dry_run: bool,
+    /// Print progress while parsing and calculating
+    pub verbose: bool,
+    /// Scenario whose overrides are applied before calculating
+    pub scenario: Option<&'a str>,
+    /// Round written formula results to this many decimals
+    pub round: Option<u32>,
+    /// Write results here (YAML, or `.xlsx`) instead of back to the source
+    pub output: Option<&'a Path>,
+    /// Fail on incompatible units instead of warning
+    pub strict_units: bool,
+    /// Record failing formulas and keep calculating
+    pub continue_on_error: bool,
+    /// Print `EXPLAIN` traces
+    pub trace: bool,
+    /// Row and cell caps applied while parsing the model
+    pub limits: ParseLimits,
+    /// Fail when an `ASSERT` condition is false
+    pub strict: bool,
+    /// Which outputs to compute
+    pub selection: OutputSelection,
+}
+
+/// Execute the calculate command
+///
+/// With `continue_on_error`, failing formulas are recorded and calculation
+/// proceeds; all failures are reported at the end and nothing is written.
+/// False `ASSERT` conditions are printed as warnings, or with `strict` fail
+/// the command before anything is written. With `output`, results go to that
+/// file (YAML, or an Excel workbook for `.xlsx`) and `file` is left untouched.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be parsed, calculation fails,
+/// or results cannot be written back to the file or to `output`.
+pub fn calculate(file: &Path, options: &CalculateOptions) -> ForgeResult<()> {
+    let CalculateOptions {
+        dry_run,
+        verbose,
+        scenario,
+        strict_units,
+        continue_on_error,
+        trace,
+        strict,
+        ..
+    } = *options;
+
+    outln!("{}", "🔥 Forge - Calculating formulas".bold().green());
+    outln!("   File: {}", file.display());
     if let Some(s) = scenario {
-        println!("   Scenario: {}", s.bright_yellow().bold());
+        outln!("   Scenario: {}", s.bright_yellow().bold());
     }
-    println!();
+    outln!();
 
     if dry_run {
-        println!(
+        outln!(
             "{}",
             "📋 DRY RUN MODE - No changes will be written\n".yellow()
         );
@@ -199,32 +375,32 @@ pub fn calculate(
 
     // Parse file
     if verbose {
-        println!("{}", "📖 Parsing YAML file...".cyan());
+        outln!("{}", "📖 Parsing YAML file...".cyan());
     }
 
-    let mut model = parser::parse_model(file)?;
+    let mut model = load_model(file, options.limits)?;
 
     if verbose {
-        println!(
+        outln!(
             "   Found {} tables, {} scalars",
             model.tables.len(),
             model.scalars.len()
         );
         if !model.scenarios.is_empty() {
-            println!(
+            outln!(
                 "   Found {} scenarios: {:?}",
                 model.scenarios.len(),
                 model.scenario_names()
             );
         }
-        println!();
+        outln!();
     }
 
     // Apply scenario overrides if specified
     if let Some(scenario_name) = scenario {
         apply_scenario(&mut model, scenario_name)?;
         if verbose {
-            println!("{}", format!("📊 Applied scenario: {scenario_name}").cyan());
+            outln!("{}", format!("📊 Applied scenario: {scenario_name}").cyan());
         }
     }
 
@@ -232,38 +408,186 @@ pub fn calculate(
     let unit_validator = UnitValidator::new(&model);
     let unit_warnings = unit_validator.validate();
     if !unit_warnings.is_empty() {
-        println!("{}", "⚠️  Unit Consistency Warnings:".yellow().bold());
+        outln!("{}", "⚠️  Unit Consistency Warnings:".yellow().bold());
         for warning in &unit_warnings {
-            println!("   {}", warning.to_string().yellow());
+            outln!("   {}", warning.to_string().yellow());
         }
-        println!();
+        outln!();
     }
 
     // Calculate using ArrayCalculator
     if verbose {
-        println!("{}", "🧮 Calculating tables and scalars...".cyan());
+        outln!("{}", "🧮 Calculating tables and scalars...".cyan());
     }
 
-    let calculator = ArrayCalculator::new(model);
-    let result = calculator.calculate_all()?;
+    // Check every formula so all errors are reported together; with
+    // continue-on-error they are recorded as failures instead
+    if !continue_on_error {
+        crate::core::check(&model)?;
+    }
 
-    // Display results
-    println!("{}", "✅ Calculation Results:".bold().green());
+    let assertions: Arc<Mutex<Vec<String>>> = Arc::default();
+    let mut calculator = collect_assertions(
+        ArrayCalculator::new(model)
+            .with_strict_units(strict_units)
+            .with_selection(options.selection.clone()),
+        Arc::clone(&assertions),
+    );
+    let traces: Arc<Mutex<Vec<String>>> = Arc::default();
+    if trace {
+        calculator = collect_traces(calculator, Arc::clone(&traces));
+    }
+    let (result, failures) = if continue_on_error {
+        calculator.calculate_all_with_errors()?
+    } else {
+        (calculator.calculate_all()?, Vec::new())
+    };
+
+    print_calculation_results(&result);
+    if trace {
+        print_traces(&traces.lock().map(|t| t.clone()).unwrap_or_default());
+    }
+
+    report_formula_failures(&failures)?;
+    report_assertions(
+        &assertions.lock().map(|a| a.clone()).unwrap_or_default(),
+        strict,
+    )?;
+
+    if dry_run {
+        outln!("{}", "📋 Dry run complete - no changes written".yellow());
+        Ok(())
+    } else {
+        write_calculation(file, result, options)
+    }
+}
+
+/// Write results back to `file` (v4.3.0), or to `--output` leaving the source untouched
+fn write_calculation(
+    file: &Path,
+    result: crate::types::ParsedModel,
+    options: &CalculateOptions,
+) -> ForgeResult<()> {
+    let target = options.output.filter(|out| !same_file(out, file));
+    if let Some(out) = target.filter(|out| {
+        out.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("xlsx"))
+    }) {
+        ExcelExporter::new(result).export(out)?;
+        outln!(
+            "{}",
+            format!("💾 Results written to {}", out.display())
+                .bold()
+                .green()
+        );
+        return Ok(());
+    }
+
+    let dest = target.unwrap_or(file);
+    let wrote = writer::write_calculated_results_to(file, dest, &result, options.round)?;
+    if wrote {
+        outln!(
+            "{}",
+            format!("💾 Results written to {}", dest.display())
+                .bold()
+                .green()
+        );
+        if target.is_none() {
+            outln!(
+                "{}",
+                format!("   Backup saved to {}.bak", file.display()).dimmed()
+            );
+        }
+    } else {
+        // Multi-document YAML - write-back not supported (v4.4.2)
+        outln!(
+            "{}",
+            "⚠️  Multi-document YAML - write-back not supported yet".yellow()
+        );
+        outln!(
+            "{}",
+            "   Results displayed above. Split into separate files to persist.".dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+/// Whether two paths name the same file; a path that does not exist yet only
+/// matches itself
+fn same_file(a: &Path, b: &Path) -> bool {
+    a == b || matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+}
+
+/// Execute the compile command: tokenize, parse and resolve every formula
+/// without calculating, reporting all problems at once
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be parsed or any formula fails to compile.
+pub fn compile(file: &Path, limits: ParseLimits) -> ForgeResult<()> {
+    outln!("{}", "🔧 Forge - Compiling formulas".bold().green());
+    outln!("   File: {}\n", file.display());
+
+    let model = load_model(file, limits)?;
+    let formula_count = model
+        .tables
+        .values()
+        .map(|t| t.row_formulas.len())
+        .sum::<usize>()
+        + model
+            .scalars
+            .values()
+            .filter(|v| v.formula.is_some())
+            .count();
+
+    let errors = crate::core::compile(&model);
+    if errors.is_empty() {
+        outln!(
+            "{}",
+            format!("✅ All {formula_count} formulas compiled")
+                .bold()
+                .green()
+        );
+        return Ok(());
+    }
+
+    outln!(
+        "{}",
+        format!("❌ {} error(s) in {formula_count} formulas", errors.len())
+            .bold()
+            .red()
+    );
+    for error in &errors {
+        outln!("   {}", error.location.bright_blue().bold());
+        outln!("      {}", error.message.red());
+        outln!("      {}", error.formula.dimmed());
+    }
+
+    Err(ForgeError::Validation(format!(
+        "{} formula(s) failed to compile",
+        errors.len()
+    )))
+}
+
+/// Print the tables and scalars produced by a calculation
+fn print_calculation_results(result: &crate::types::ParsedModel) {
+    outln!("{}", "✅ Calculation Results:".bold().green());
 
     // Show table results
     for (table_name, table) in &result.tables {
-        println!("   📊 Table: {}", table_name.bright_blue().bold());
+        outln!("   📊 Table: {}", table_name.bright_blue().bold());
         for (col_name, column) in &table.columns {
-            println!("      {} ({} rows)", col_name.cyan(), column.values.len());
+            outln!("      {} ({} rows)", col_name.cyan(), column.values.len());
         }
     }
 
     // Show scalar results
     if !result.scalars.is_empty() {
-        println!("\n   📐 Scalars:");
+        outln!("\n   📐 Scalars:");
         for (name, var) in &result.scalars {
             if let Some(value) = var.value {
-                println!(
+                outln!(
                     "      {} = {}",
                     name.bright_blue(),
                     format!("{value}").bold()
@@ -271,38 +595,139 @@ pub fn calculate(
             }
         }
     }
-    println!();
+    outln!();
+}
 
-    // Write results back to file (v4.3.0)
-    if dry_run {
-        println!("{}", "📋 Dry run complete - no changes written".yellow());
-    } else {
-        let wrote = writer::write_calculated_results(file, &result)?;
-        if wrote {
-            println!(
-                "{}",
-                format!("💾 Results written to {}", file.display())
-                    .bold()
-                    .green()
-            );
-            println!(
-                "{}",
-                format!("   Backup saved to {}.bak", file.display()).dimmed()
-            );
-        } else {
-            // Multi-document YAML - write-back not supported (v4.4.2)
-            println!(
-                "{}",
-                "⚠️  Multi-document YAML - write-back not supported yet".yellow()
-            );
-            println!(
-                "{}",
-                "   Results displayed above. Split into separate files to persist.".dimmed()
-            );
+/// Record each EXPLAIN value as a `location (row N): expr = value` line
+fn collect_traces(calculator: ArrayCalculator, lines: Arc<Mutex<Vec<String>>>) -> ArrayCalculator {
+    calculator.with_trace_hook(move |output, entry| {
+        let line = entry.row.map_or_else(
+            || format!("{output}: {entry}"),
+            |row| format!("{output} (row {row}): {entry}"),
+        );
+        if let Ok(mut lines) = lines.lock() {
+            lines.push(line);
         }
+    })
+}
+
+/// Print the values recorded by EXPLAIN (`--trace`)
+fn print_traces(lines: &[String]) {
+    outln!("{}", "🔍 Trace (EXPLAIN):".bold().cyan());
+    if lines.is_empty() {
+        outln!("   (no EXPLAIN calls evaluated)");
+    }
+    for line in lines {
+        outln!("   {line}");
     }
+    outln!();
+}
 
-    Ok(())
+/// Record each false ASSERT as a `location (row N): message` line
+fn collect_assertions(
+    calculator: ArrayCalculator,
+    lines: Arc<Mutex<Vec<String>>>,
+) -> ArrayCalculator {
+    calculator.with_assert_hook(move |output, failure| {
+        let line = failure.row.map_or_else(
+            || format!("{output}: {failure}"),
+            |row| format!("{output} (row {row}): {failure}"),
+        );
+        if let Ok(mut lines) = lines.lock() {
+            lines.push(line);
+        }
+    })
+}
+
+/// Print the ASSERT conditions that were false
+///
+/// # Errors
+///
+/// Returns an error if `strict` is set and at least one assertion failed.
+fn report_assertions(lines: &[String], strict: bool) -> ForgeResult<()> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+    let header = format!("{} assertion(s) failed:", lines.len());
+    if strict {
+        outln!("{}", format!("❌ {header}").bold().red());
+    } else {
+        outln!("{}", format!("⚠️  {header}").yellow().bold());
+    }
+    for line in lines {
+        outln!("   {}", if strict { line.red() } else { line.yellow() });
+    }
+    outln!();
+    if !strict {
+        return Ok(());
+    }
+    Err(ForgeError::Validation(format!(
+        "{} assertion(s) failed: {}",
+        lines.len(),
+        lines.join("; ")
+    )))
+}
+
+/// Print formula failures recorded in continue-on-error mode
+///
+/// # Errors
+///
+/// Returns an error if there is at least one failure.
+fn report_formula_failures(failures: &[FormulaError]) -> ForgeResult<()> {
+    if failures.is_empty() {
+        return Ok(());
+    }
+    outln!(
+        "{}",
+        format!("❌ {} formula failure(s):", failures.len())
+            .bold()
+            .red()
+    );
+    for failure in failures {
+        outln!("   {}", failure.to_string().red());
+    }
+    outln!();
+    outln!("{}", "📋 Failures found - no changes written".yellow());
+    Err(ForgeError::Eval(format!(
+        "{} formula(s) failed during calculation",
+        failures.len()
+    )))
+}
+
+/// Floating point tolerance when comparing stored and calculated values
+const VALUE_TOLERANCE: f64 = 0.0001;
+
+/// A mismatch when `current_value` and `expected_value` differ by more than
+/// [`VALUE_TOLERANCE`]
+fn value_mismatch(
+    name: &str,
+    current_value: f64,
+    expected_value: f64,
+) -> Option<results::ValidationMismatch> {
+    let diff = (current_value - expected_value).abs();
+    (diff > VALUE_TOLERANCE).then(|| results::ValidationMismatch {
+        name: name.to_string(),
+        current_value,
+        expected_value,
+        diff,
+    })
+}
+
+/// Print a mismatch's current and expected values and their difference
+fn print_value_diff(mismatch: &results::ValidationMismatch) {
+    // Format numbers with reasonable precision (remove trailing zeros)
+    outln!(
+        "      Current:  {}",
+        format_number(mismatch.current_value).red()
+    );
+    outln!(
+        "      Expected: {}",
+        format_number(mismatch.expected_value).green()
+    );
+    outln!(
+        "      Diff:     {}",
+        format!("{:.6}", mismatch.diff).yellow()
+    );
 }
 
 /// Validate a single file and return structured results (no printing).
@@ -310,60 +735,126 @@ pub fn calculate(
 /// # Errors
 ///
 /// Returns an error if the file cannot be parsed or calculation fails.
-pub fn validate_core(file: &Path) -> ForgeResult<results::ValidationResult> {
-    const TOLERANCE: f64 = 0.0001;
+pub fn validate_core(file: &Path, limits: ParseLimits) -> ForgeResult<results::ValidationResult> {
+    let model = load_model(file, limits)?;
+    crate::core::check(&model)?;
+    let calculated = ArrayCalculator::new(model.clone()).calculate_all()?;
+    Ok(validation_result(&model, &calculated))
+}
 
-    let model = parser::parse_model(file)?;
+/// Compare a model's stored scalar values with its calculated ones
+fn validation_result(
+    model: &crate::types::ParsedModel,
+    calculated: &crate::types::ParsedModel,
+) -> results::ValidationResult {
     let table_count = model.tables.len();
     let scalar_count = model.scalars.len();
 
-    let calculator = ArrayCalculator::new(model.clone());
-    let calculated = calculator.calculate_all()?;
-
     let mut mismatches = Vec::new();
     for (var_name, var) in &calculated.scalars {
         if let Some(calculated_value) = var.value {
             if let Some(original) = model.scalars.get(var_name) {
                 if let Some(current_value) = original.value {
-                    let diff = (current_value - calculated_value).abs();
-                    if diff > TOLERANCE {
-                        mismatches.push(results::ValidationMismatch {
-                            name: var_name.clone(),
-                            current_value,
-                            expected_value: calculated_value,
-                            diff,
-                        });
-                    }
+                    mismatches.extend(value_mismatch(var_name, current_value, calculated_value));
                 }
             }
         }
     }
 
     let scalars_valid = mismatches.is_empty();
-    Ok(results::ValidationResult {
+    results::ValidationResult {
         tables_valid: true,
         scalars_valid,
         table_count,
         scalar_count,
         mismatches,
+    }
+}
+
+/// Validate a single file into the `--json` [`JsonReport`]
+fn validate_report(file: &Path, limits: ParseLimits) -> ForgeResult<JsonReport> {
+    let model = load_model(file, limits)?;
+    let warnings = UnitValidator::new(&model)
+        .validate()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    crate::core::check(&model)?;
+    let calculated = ArrayCalculator::new(model.clone()).calculate_all()?;
+    Ok(JsonReport {
+        tables: table_summaries(&calculated).collect(),
+        scalars: scalar_values(&calculated).collect(),
+        mismatches: validation_result(&model, &calculated).mismatches,
+        warnings,
     })
 }
 
+/// Execute the validate command with `--json`: one envelope covering all
+/// files, with each file's [`JsonReport`] under `result.files` and every
+/// mismatch or parse failure listed in `errors`.
+///
+/// # Errors
+///
+/// Returns an error after printing if any file fails validation.
+pub fn validate_json(files: &[PathBuf], limits: ParseLimits) -> ForgeResult<()> {
+    let mut results = serde_json::Map::new();
+    let mut errors = Vec::new();
+    for file in files {
+        let name = file.display().to_string();
+        match validate_report(file, limits) {
+            Ok(result) => {
+                errors.extend(result.mismatches.iter().map(|m| {
+                    format!(
+                        "{name}: {} is {} but calculates to {}",
+                        m.name, m.current_value, m.expected_value
+                    )
+                }));
+                results.insert(name, serde_json::json!(result));
+            },
+            Err(e) => {
+                errors.push(format!("{name}: {e}"));
+                results.insert(name, serde_json::Value::Null);
+            },
+        }
+    }
+
+    let failed = errors.len();
+    JsonEnvelope::new("validate", serde_json::json!({ "files": results }))
+        .with_errors(errors)
+        .print();
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(ForgeError::Validation(format!(
+            "{failed} validation error(s)"
+        )))
+    }
+}
+
 /// Execute the validate command for one or more files
 ///
+/// With `explain`, each mismatched scalar also shows its formula and the
+/// calculated values of the inputs it references. With `strict`, a false
+/// `ASSERT` condition fails the file instead of only being reported.
+///
 /// # Errors
 ///
 /// Returns an error if any file fails validation or cannot be parsed.
-pub fn validate(files: &[PathBuf]) -> ForgeResult<()> {
+pub fn validate(
+    files: &[PathBuf],
+    explain: bool,
+    strict: bool,
+    limits: ParseLimits,
+) -> ForgeResult<()> {
     let file_count = files.len();
     let is_batch = file_count > 1;
 
     if is_batch {
-        println!(
+        outln!(
             "{}",
             format!("✅ Validating {file_count} files").bold().green()
         );
-        println!();
+        outln!();
     }
 
     let mut all_passed = true;
@@ -371,17 +862,17 @@ pub fn validate(files: &[PathBuf]) -> ForgeResult<()> {
 
     for file in files {
         if is_batch {
-            println!("{}", format!("─── {} ───", file.display()).cyan());
+            outln!("{}", format!("─── {} ───", file.display()).cyan());
         } else {
-            println!("{}", "✅ Validating model".bold().green());
-            println!("   File: {}\n", file.display());
+            outln!("{}", "✅ Validating model".bold().green());
+            outln!("   File: {}\n", file.display());
         }
 
-        match validate_single_file(file) {
+        match validate_single_file(file, explain, strict, limits) {
             Ok(()) => {
                 if is_batch {
-                    println!("{}", format!("   ✅ {} - OK", file.display()).green());
-                    println!();
+                    outln!("{}", format!("   ✅ {} - OK", file.display()).green());
+                    outln!();
                 }
             },
             Err(e) => {
@@ -391,18 +882,18 @@ pub fn validate(files: &[PathBuf]) -> ForgeResult<()> {
                 }
                 all_passed = false;
                 failed_files.push(format!("{}: {}", file.display(), e));
-                println!("{}", format!("   ❌ {} - FAILED", file.display()).red());
-                println!("      {}", e.to_string().red());
-                println!();
+                outln!("{}", format!("   ❌ {} - FAILED", file.display()).red());
+                outln!("      {}", e.to_string().red());
+                outln!();
             },
         }
     }
 
     // Summary for batch validation
     if is_batch {
-        println!("{}", "─".repeat(50));
+        outln!("{}", "─".repeat(50));
         let passed = file_count - failed_files.len();
-        println!(
+        outln!(
             "   {} passed, {} failed out of {} files",
             passed.to_string().green(),
             failed_files.len().to_string().red(),
@@ -421,32 +912,47 @@ pub fn validate(files: &[PathBuf]) -> ForgeResult<()> {
 }
 
 /// Validate a single file
-fn validate_single_file(file: &std::path::Path) -> ForgeResult<()> {
-    const TOLERANCE: f64 = 0.0001; // Floating point comparison tolerance
-
+fn validate_single_file(
+    file: &std::path::Path,
+    explain: bool,
+    strict: bool,
+    limits: ParseLimits,
+) -> ForgeResult<()> {
     // Parse YAML file
-    let model = parser::parse_model(file)?;
+    let model = load_model(file, limits)?;
 
     if model.tables.is_empty() && model.scalars.is_empty() {
-        println!("{}", "⚠️  No tables or scalars found in YAML file".yellow());
+        outln!("{}", "⚠️  No tables or scalars found in YAML file".yellow());
         return Ok(());
     }
 
-    println!(
+    outln!(
         "   Found {} tables, {} scalars",
         model.tables.len(),
         model.scalars.len()
     );
 
-    // Note: Table column length validation is deferred to calculation time
-    // Row-wise operations will validate at runtime in array_calculator
+    // Structural checks shared with programmatic callers (ParsedModel::validate)
+    let warnings = match model.validate() {
+        Ok(warnings) => warnings,
+        Err(e) => {
+            outln!("\n{}", format!("❌ {e}").bold().red());
+            return Err(e);
+        },
+    };
+    for warning in &warnings {
+        outln!("   {}", format!("⚠️  {warning}").yellow());
+    }
 
-    // Calculate what values SHOULD be based on formulas
-    let calculator = ArrayCalculator::new(model.clone());
-    let calculated = match calculator.calculate_all() {
+    // Calculate what values SHOULD be based on formulas, after checking
+    // every formula so all errors are reported together
+    let assertions: Arc<Mutex<Vec<String>>> = Arc::default();
+    let calculator =
+        collect_assertions(ArrayCalculator::new(model.clone()), Arc::clone(&assertions));
+    let calculated = match crate::core::check(&model).and_then(|()| calculator.calculate_all()) {
         Ok(vals) => vals,
         Err(e) => {
-            println!(
+            outln!(
                 "\n{}",
                 format!("❌ Formula validation failed: {e}").bold().red()
             );
@@ -462,46 +968,57 @@ fn validate_single_file(file: &std::path::Path) -> ForgeResult<()> {
             if let Some(original) = model.scalars.get(var_name) {
                 if let Some(current_value) = original.value {
                     // Check if values match within tolerance
-                    let diff = (current_value - calculated_value).abs();
-                    if diff > TOLERANCE {
-                        mismatches.push((var_name.clone(), current_value, calculated_value, diff));
-                    }
+                    mismatches.extend(value_mismatch(var_name, current_value, calculated_value));
                 }
             }
         }
     }
 
     // Report results
-    println!();
+    outln!();
+    report_assertions(
+        &assertions.lock().map(|a| a.clone()).unwrap_or_default(),
+        strict,
+    )?;
     if mismatches.is_empty() {
-        println!("{}", "✅ All tables are valid!".bold().green());
-        println!(
+        outln!("{}", "✅ All tables are valid!".bold().green());
+        outln!(
             "{}",
             "✅ All scalar values match their formulas!".bold().green()
         );
         Ok(())
     } else {
-        println!(
+        outln!(
             "{}",
             format!("❌ Found {} value mismatches!", mismatches.len())
                 .bold()
                 .red()
         );
-        println!("{}", "   File needs recalculation!\n".yellow());
-
-        for (name, current, expected, diff) in &mismatches {
-            println!("   {}", name.bright_blue().bold());
-            // Format numbers with reasonable precision (remove trailing zeros)
-            println!("      Current:  {}", format_number(*current).clone().red());
-            println!(
-                "      Expected: {}",
-                format_number(*expected).clone().green()
-            );
-            println!("      Diff:     {}", format!("{diff:.6}").yellow());
-            println!();
+        outln!("{}", "   File needs recalculation!\n".yellow());
+
+        for mismatch in &mismatches {
+            let name = &mismatch.name;
+            outln!("   {}", name.bright_blue().bold());
+            if let Some(metadata) = audit::variable_metadata(&model, name) {
+                for (label, text) in audit::metadata_notes(metadata) {
+                    outln!("      {:<9} {}", format!("{label}:"), text.italic());
+                }
+            }
+            print_value_diff(mismatch);
+            if explain {
+                let explained = audit::explain_scalar(&calculated, name)?;
+                if let Some(formula) = &explained.formula {
+                    outln!("      Formula:  {}", formula.bright_yellow());
+                }
+                for (i, input) in explained.inputs.iter().enumerate() {
+                    let label = if i == 0 { "Inputs:" } else { "" };
+                    outln!("      {label:<9} {}", input.cyan());
+                }
+            }
+            outln!();
         }
 
-        println!(
+        outln!(
             "{}",
             "💡 Run 'forge calculate' to update values".bold().yellow()
         );
@@ -523,10 +1040,15 @@ fn validate_single_file(file: &std::path::Path) -> ForgeResult<()> {
 /// Contains infinite loop waiting for file system events - cannot unit test.
 /// Tested via: `cli_integration_tests.rs` (manual termination after initial run)
 #[cfg(not(coverage))]
-pub fn watch(file: &Path, validate_only: bool, verbose: bool) -> ForgeResult<()> {
-    println!("{}", "👁️  Forge - Watch Mode".bold().green());
-    println!("   Watching: {}", file.display());
-    println!(
+pub fn watch(
+    file: &Path,
+    validate_only: bool,
+    verbose: bool,
+    limits: ParseLimits,
+) -> ForgeResult<()> {
+    outln!("{}", "👁️  Forge - Watch Mode".bold().green());
+    outln!("   Watching: {}", file.display());
+    outln!(
         "   Mode: {}",
         if validate_only {
             "validate only"
@@ -534,7 +1056,7 @@ pub fn watch(file: &Path, validate_only: bool, verbose: bool) -> ForgeResult<()>
             "calculate"
         }
     );
-    println!("   Press {} to stop\n", "Ctrl+C".bold().yellow());
+    outln!("   Press {} to stop\n", "Ctrl+C".bold().yellow());
 
     // Verify file exists
     if !file.exists() {
@@ -564,7 +1086,7 @@ pub fn watch(file: &Path, validate_only: bool, verbose: bool) -> ForgeResult<()>
         .map_err(|e| ForgeError::Validation(format!("Failed to watch directory: {e}")))?;
 
     if verbose {
-        println!(
+        outln!(
             "   {} {}",
             "Watching directory:".cyan(),
             parent_dir.display()
@@ -572,9 +1094,9 @@ pub fn watch(file: &Path, validate_only: bool, verbose: bool) -> ForgeResult<()>
     }
 
     // Run initial validation/calculation
-    println!("{}", "🔄 Initial run...".cyan());
-    run_watch_action(file, validate_only, verbose);
-    println!();
+    outln!("{}", "🔄 Initial run...".cyan());
+    run_watch_action(file, validate_only, verbose, limits);
+    outln!();
 
     // Watch loop
     loop {
@@ -611,22 +1133,22 @@ pub fn watch(file: &Path, validate_only: bool, verbose: bool) -> ForgeResult<()>
                 if relevant {
                     // Clear screen for fresh output (optional, can be verbose mode only)
                     if verbose {
-                        print!("\x1B[2J\x1B[1;1H"); // ANSI clear screen
+                        out!("\x1B[2J\x1B[1;1H"); // ANSI clear screen
                     }
-                    println!(
+                    outln!(
                         "\n{} {}",
                         "🔄 Change detected at".cyan(),
                         chrono_lite_timestamp().cyan()
                     );
-                    run_watch_action(file, validate_only, verbose);
-                    println!();
+                    run_watch_action(file, validate_only, verbose, limits);
+                    outln!();
                 }
             },
             Ok(Err(error)) => {
-                eprintln!("{} Watch error: {}", "❌".red(), error);
+                eoutln!("{} Watch error: {}", "❌".red(), error);
             },
             Err(e) => {
-                eprintln!("{} Channel error: {}", "❌".red(), e);
+                eoutln!("{} Channel error: {}", "❌".red(), e);
                 break;
             },
         }
@@ -637,7 +1159,12 @@ pub fn watch(file: &Path, validate_only: bool, verbose: bool) -> ForgeResult<()>
 
 /// Stub for coverage builds - see ADR-006
 #[cfg(coverage)]
-pub fn watch(file: &Path, _validate_only: bool, _verbose: bool) -> ForgeResult<()> {
+pub fn watch(
+    file: &Path,
+    _validate_only: bool,
+    _verbose: bool,
+    _limits: ParseLimits,
+) -> ForgeResult<()> {
     // Validate file exists (testable error path)
     if !file.exists() {
         return Err(ForgeError::Validation(format!(
@@ -664,29 +1191,29 @@ fn chrono_lite_timestamp() -> String {
 
 /// Run the watch action (validate or calculate)
 #[cfg(any(not(coverage), test))]
-fn run_watch_action(file: &Path, validate_only: bool, verbose: bool) {
+fn run_watch_action(file: &Path, validate_only: bool, verbose: bool, limits: ParseLimits) {
     if validate_only {
-        match validate_internal(file, verbose) {
-            Ok(()) => println!("{}", "✅ Validation passed".bold().green()),
-            Err(e) => println!("{} {}", "❌ Validation failed:".bold().red(), e),
+        match validate_internal(file, verbose, limits) {
+            Ok(()) => outln!("{}", "✅ Validation passed".bold().green()),
+            Err(e) => outln!("{} {}", "❌ Validation failed:".bold().red(), e),
         }
     } else {
-        match calculate_internal(file, verbose) {
-            Ok(()) => println!("{}", "✅ Calculation complete".bold().green()),
-            Err(e) => println!("{} {}", "❌ Calculation failed:".bold().red(), e),
+        match calculate_internal(file, verbose, limits) {
+            Ok(()) => outln!("{}", "✅ Calculation complete".bold().green()),
+            Err(e) => outln!("{} {}", "❌ Calculation failed:".bold().red(), e),
         }
     }
 }
 
 /// Internal validation function for watch mode
 #[cfg(any(not(coverage), test))]
-fn validate_internal(file: &Path, verbose: bool) -> ForgeResult<()> {
+fn validate_internal(file: &Path, verbose: bool, limits: ParseLimits) -> ForgeResult<()> {
     const TOLERANCE: f64 = 0.0001;
 
-    let model = parser::parse_model(file)?;
+    let model = load_model(file, limits)?;
 
     if verbose {
-        println!(
+        outln!(
             "   Found {} tables, {} scalars",
             model.tables.len(),
             model.scalars.len()
@@ -736,11 +1263,11 @@ fn validate_internal(file: &Path, verbose: bool) -> ForgeResult<()> {
 
 /// Internal calculation function for watch mode
 #[cfg(any(not(coverage), test))]
-fn calculate_internal(file: &Path, verbose: bool) -> ForgeResult<()> {
-    let model = parser::parse_model(file)?;
+fn calculate_internal(file: &Path, verbose: bool, limits: ParseLimits) -> ForgeResult<()> {
+    let model = load_model(file, limits)?;
 
     if verbose {
-        println!(
+        outln!(
             "   Found {} tables, {} scalars",
             model.tables.len(),
             model.scalars.len()
@@ -752,7 +1279,7 @@ fn calculate_internal(file: &Path, verbose: bool) -> ForgeResult<()> {
 
     // Show summary
     for (table_name, table) in &result.tables {
-        println!(
+        outln!(
             "   📊 {} ({} columns)",
             table_name.bright_blue(),
             table.columns.len()
@@ -760,7 +1287,7 @@ fn calculate_internal(file: &Path, verbose: bool) -> ForgeResult<()> {
     }
 
     if !result.scalars.is_empty() && verbose {
-        println!("   📐 {} scalars calculated", result.scalars.len());
+        outln!("   📐 {} scalars calculated", result.scalars.len());
     }
 
     Ok(())
@@ -768,9 +1295,14 @@ fn calculate_internal(file: &Path, verbose: bool) -> ForgeResult<()> {
 
 /// Apply scenario overrides to the model
 ///
+/// Scalar overrides replace values; column overrides swap in whole table
+/// columns before calculation.
+///
 /// # Errors
 ///
-/// Returns an error if the named scenario does not exist in the model.
+/// Returns an error if the named scenario does not exist in the model, or a
+/// column override targets a missing or non-numeric column or does not match
+/// the table's row count.
 pub fn apply_scenario(
     model: &mut crate::types::ParsedModel,
     scenario_name: &str,
@@ -784,6 +1316,7 @@ pub fn apply_scenario(
 
     // Clone the overrides to avoid borrow checker issues
     let overrides = scenario.overrides.clone();
+    let column_overrides = scenario.column_overrides.clone();
 
     // Apply overrides to scalars (resolving grouped names like "rate" → "assumptions.rate")
     for (var_name, override_value) in &overrides {
@@ -804,5 +1337,199 @@ pub fn apply_scenario(
         }
     }
 
+    // Swap in replacement columns
+    for (target, values) in &column_overrides {
+        model
+            .override_column(target, values)
+            .map_err(|e| ForgeError::Validation(format!("Scenario '{scenario_name}': {e}")))?;
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::theme::capture;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_model(dir: &TempDir, npv: f64) -> PathBuf {
+        let path = dir.path().join("model.yaml");
+        fs::write(
+            &path,
+            format!(
+                r#"_forge_version: "5.0.0"
+irr:
+  value: 0.1
+npv_at_irr:
+  value: {npv}
+check:
+  value: 1
+  formula: "=ASSERT(ABS(npv_at_irr) < 0.01, \"NPV at IRR must be zero\")"
+"#
+            ),
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_apply_scenario_column_override() {
+        let mut model = crate::types::ParsedModel::new();
+        let mut table = crate::types::Table::new("forecast".to_string());
+        table.add_column(crate::types::Column::new(
+            "monthly_growth".to_string(),
+            crate::types::ColumnValue::Number(vec![0.01, 0.01, 0.01]),
+        ));
+        model.add_table(table);
+
+        let mut bull = crate::types::Scenario::new();
+        bull.add_override("rate".to_string(), 0.2);
+        bull.add_column_override(
+            "forecast.monthly_growth".to_string(),
+            vec![0.04, 0.05, 0.06],
+        );
+        model.add_scenario("bull".to_string(), bull);
+
+        let mut short = crate::types::Scenario::new();
+        short.add_column_override("forecast.monthly_growth".to_string(), vec![0.04]);
+        model.add_scenario("short".to_string(), short);
+
+        let mut bull_model = model.clone();
+        apply_scenario(&mut bull_model, "bull").unwrap();
+        assert_eq!(bull_model.scalars["rate"].value, Some(0.2));
+        assert_eq!(
+            bull_model.tables["forecast"].columns["monthly_growth"].values,
+            crate::types::ColumnValue::Number(vec![0.04, 0.05, 0.06])
+        );
+
+        let err = apply_scenario(&mut model, "short").unwrap_err().to_string();
+        assert!(err.contains("Scenario 'short'"), "{err}");
+        assert!(
+            err.contains("has 1 values but table 'forecast' has 3 rows"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_strict_fails_on_false_assert() {
+        let dir = TempDir::new().unwrap();
+        let path = write_model(&dir, 12.5);
+        let run = |strict| {
+            calculate(
+                &path,
+                &CalculateOptions {
+                    dry_run: true,
+                    strict,
+                    ..CalculateOptions::default()
+                },
+            )
+        };
+
+        // Without --strict the failure is only a warning
+        let output = capture(|| run(false).unwrap());
+        assert!(output.contains("1 assertion(s) failed"), "{output}");
+
+        let err = run(true).unwrap_err().to_string();
+        assert!(err.contains("NPV at IRR must be zero"), "{err}");
+        let err = validate(
+            std::slice::from_ref(&path),
+            false,
+            true,
+            ParseLimits::default(),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("check: NPV at IRR must be zero"), "{err}");
+    }
+
+    #[test]
+    fn test_strict_passes_on_true_assert() {
+        let dir = TempDir::new().unwrap();
+        let path = write_model(&dir, 0.0);
+        calculate(
+            &path,
+            &CalculateOptions {
+                dry_run: true,
+                strict: true,
+                ..CalculateOptions::default()
+            },
+        )
+        .unwrap();
+        validate(&[path], false, true, ParseLimits::default()).unwrap();
+    }
+
+    #[test]
+    fn test_output_leaves_source_untouched() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("model.yaml");
+        let source = r#"_forge_version: "5.0.0"
+price:
+  value: 10
+total:
+  value: null
+  formula: "=price * 3"
+"#;
+        fs::write(&path, source).unwrap();
+        let run = |output: &Path| {
+            calculate(
+                &path,
+                &CalculateOptions {
+                    output: Some(output),
+                    ..CalculateOptions::default()
+                },
+            )
+        };
+
+        let yaml = dir.path().join("result.yaml");
+        capture(|| run(&yaml).unwrap());
+        assert!(fs::read_to_string(&yaml).unwrap().contains("30"));
+        let xlsx = dir.path().join("result.xlsx");
+        capture(|| run(&xlsx).unwrap());
+        assert!(fs::metadata(&xlsx).unwrap().len() > 0);
+        assert_eq!(fs::read_to_string(&path).unwrap(), source);
+        assert!(!path.with_extension("yaml.bak").exists());
+
+        // Writing to the input itself is the usual in-place update
+        capture(|| run(&path).unwrap());
+        assert!(fs::read_to_string(&path).unwrap().contains("30"));
+        assert!(path.with_extension("yaml.bak").exists());
+    }
+
+    #[test]
+    fn test_error_values_are_written_and_read_back() {
+        use crate::core::array_calculator::evaluator::ErrorKind;
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("model.yaml");
+        fs::write(
+            &path,
+            r#"_forge_version: "5.0.0"
+sales:
+  revenue: [100, 200, 300]
+  units: [10, 0, 30]
+  per_unit: "=revenue / units"
+zero:
+  value: 0
+ratio:
+  value: null
+  formula: "=1 / zero"
+"#,
+        )
+        .unwrap();
+        let run = || calculate(&path, &CalculateOptions::default());
+
+        // One divide-by-zero row no longer aborts the calculation
+        capture(|| run().unwrap());
+        let written = fs::read_to_string(&path).unwrap();
+        assert!(written.contains("'#DIV/0!'"), "{written}");
+
+        let model = parser::parse_model(&path).unwrap();
+        let ratio = model.scalars["ratio"].value.unwrap();
+        assert_eq!(ErrorKind::from_nan(ratio), Some(ErrorKind::DivZero));
+
+        // The written file calculates again
+        capture(|| run().unwrap());
+    }
+}
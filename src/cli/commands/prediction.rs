@@ -9,52 +9,64 @@
 //! - bayesian: Bayesian network inference and queries
 
 use crate::bayesian::{BayesianConfig, BayesianEngine};
-use crate::bootstrap::{BootstrapConfig, BootstrapEngine};
+use crate::bootstrap::{BootstrapCiType, BootstrapConfig, BootstrapEngine, BootstrapMethod};
 use crate::decision_trees::{DecisionTreeConfig, DecisionTreeEngine};
 use crate::error::{ForgeError, ForgeResult};
-use crate::parser;
+use crate::parser::ParseLimits;
 use crate::real_options::{RealOptionsConfig, RealOptionsEngine};
 use crate::scenarios::{ScenarioConfig, ScenarioEngine};
+use crate::tornado::config::DEFAULT_AUTO_SWING;
 use crate::tornado::{TornadoConfig, TornadoEngine};
 use colored::Colorize;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-/// Run scenario analysis and return structured results (no printing).
+/// Read the `scenarios` section of a model file into a `ScenarioConfig`
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be parsed, no scenarios section exists,
-/// or the scenario engine fails.
-pub fn scenarios_core(
-    file: &Path,
-    scenario_filter: Option<&str>,
-) -> ForgeResult<crate::scenarios::ScenarioResults> {
+/// Returns an error if the file cannot be read, the YAML is invalid, there is
+/// no `scenarios` section, or a scenario definition is malformed.
+pub fn load_scenario_config(file: &Path) -> ForgeResult<ScenarioConfig> {
     let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
-    let model = parser::parse_model(file)?;
 
     let value: serde_yaml_ng::Value = serde_yaml_ng::from_str(&yaml_content)
         .map_err(|e| ForgeError::Validation(format!("YAML parse error: {e}")))?;
 
-    let config: ScenarioConfig = if let Some(scenarios_value) = value.get("scenarios") {
-        let scenarios_map: HashMap<String, serde_yaml_ng::Value> =
-            serde_yaml_ng::from_value(scenarios_value.clone())
-                .map_err(|e| ForgeError::Validation(format!("scenarios config error: {e}")))?;
-
-        let mut config = ScenarioConfig::default();
-        for (name, def) in scenarios_map {
-            let scenario_def = serde_yaml_ng::from_value(def)
-                .map_err(|e| ForgeError::Validation(format!("scenario '{name}' error: {e}")))?;
-            config.scenarios.insert(name, scenario_def);
-        }
-        config
-    } else {
+    let Some(scenarios_value) = value.get("scenarios") else {
         return Err(ForgeError::Validation(
             "No 'scenarios' section found in YAML".to_string(),
         ));
     };
 
+    let scenarios_map: HashMap<String, serde_yaml_ng::Value> =
+        serde_yaml_ng::from_value(scenarios_value.clone())
+            .map_err(|e| ForgeError::Validation(format!("scenarios config error: {e}")))?;
+
+    let mut config = ScenarioConfig::default();
+    for (name, def) in scenarios_map {
+        let scenario_def = serde_yaml_ng::from_value(def)
+            .map_err(|e| ForgeError::Validation(format!("scenario '{name}' error: {e}")))?;
+        config.scenarios.insert(name, scenario_def);
+    }
+    Ok(config)
+}
+
+/// Run scenario analysis and return structured results (no printing).
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be parsed, no scenarios section exists,
+/// or the scenario engine fails.
+pub fn scenarios_core(
+    file: &Path,
+    scenario_filter: Option<&str>,
+    limits: ParseLimits,
+) -> ForgeResult<crate::scenarios::ScenarioResults> {
+    let model = super::load_model(file, limits)?;
+    let config = load_scenario_config(file)?;
+
     let engine = ScenarioEngine::new(config, model).map_err(ForgeError::Validation)?;
     let _ = scenario_filter; // filtering done in display, engine runs all
     engine.run().map_err(ForgeError::Eval)
@@ -114,33 +126,74 @@ pub fn real_options_core(file: &Path) -> ForgeResult<crate::real_options::Option
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be parsed, no tornado section exists,
-/// or the analysis fails.
+/// Returns an error if the file cannot be parsed, no tornado section exists
+/// and no output can be chosen, or the analysis fails.
 pub fn tornado_core(
     file: &Path,
     output_var: Option<&str>,
+    limits: ParseLimits,
 ) -> ForgeResult<crate::tornado::TornadoResult> {
-    let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
-    let model = parser::parse_model(file)?;
+    let model = super::load_model(file, limits)?;
+    let (config, _) = load_tornado_config(file, &model, output_var)?;
+
+    let engine = TornadoEngine::new(config, model).map_err(ForgeError::Validation)?;
+    engine.analyze().map_err(ForgeError::Eval)
+}
 
+/// Read the `tornado` section, or derive one from the model when absent
+///
+/// Without a section, every input scalar (a value and no formula) is varied
+/// by ±10%. The output is `output_var`, or the model's only formula scalar.
+/// The flag is `true` when the inputs were discovered this way.
+fn load_tornado_config(
+    file: &Path,
+    model: &crate::types::ParsedModel,
+    output_var: Option<&str>,
+) -> ForgeResult<(TornadoConfig, bool)> {
+    let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
     let value: serde_yaml_ng::Value = serde_yaml_ng::from_str(&yaml_content)
         .map_err(|e| ForgeError::Validation(format!("YAML parse error: {e}")))?;
 
-    let mut config: TornadoConfig = if let Some(tornado_value) = value.get("tornado") {
-        serde_yaml_ng::from_value(tornado_value.clone())
-            .map_err(|e| ForgeError::Validation(format!("tornado config error: {e}")))?
+    if let Some(tornado_value) = value.get("tornado") {
+        let mut config: TornadoConfig = serde_yaml_ng::from_value(tornado_value.clone())
+            .map_err(|e| ForgeError::Validation(format!("tornado config error: {e}")))?;
+        if let Some(out_var) = output_var {
+            config.output = out_var.to_string();
+        }
+        return Ok((config, false));
+    }
+
+    let output = if let Some(out_var) = output_var {
+        model
+            .resolve_scalar_name(out_var)
+            .map_err(ForgeError::Validation)?
     } else {
-        return Err(ForgeError::Validation(
-            "No 'tornado' section found in YAML".to_string(),
-        ));
+        let mut formulas: Vec<&str> = model
+            .scalars
+            .iter()
+            .filter(|(_, var)| var.formula.is_some())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        formulas.sort_unstable();
+        match formulas.as_slice() {
+            [only] => (*only).to_string(),
+            [] => {
+                return Err(ForgeError::Validation(
+                    "No 'tornado' section found in YAML and no formula scalar to analyze"
+                        .to_string(),
+                ))
+            },
+            _ => {
+                return Err(ForgeError::Validation(format!(
+                    "No 'tornado' section found in YAML; pass --output-var to pick one of: {}",
+                    formulas.join(", ")
+                )))
+            },
+        }
     };
 
-    if let Some(out_var) = output_var {
-        config.output = out_var.to_string();
-    }
-
-    let engine = TornadoEngine::new(config, model).map_err(ForgeError::Validation)?;
-    engine.analyze().map_err(ForgeError::Eval)
+    let config = TornadoConfig::from_model(model, &output, DEFAULT_AUTO_SWING);
+    Ok((config, true))
 }
 
 /// Run bootstrap resampling and return structured results (no printing).
@@ -256,90 +309,94 @@ pub fn bayesian_core(
 pub fn scenarios(
     file: &Path,
     scenario_filter: Option<&str>,
+    confidence: f64,
     output_file: Option<PathBuf>,
     verbose: bool,
+    limits: ParseLimits,
 ) -> ForgeResult<()> {
-    println!("{}", "📊 Forge - Scenario Analysis".bold().green());
-    println!("   File: {}", file.display());
-    println!();
-
-    // Parse YAML
-    let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
-    let model = parser::parse_model(file)?;
-
-    // Parse scenarios config
-    let value: serde_yaml_ng::Value = serde_yaml_ng::from_str(&yaml_content)
-        .map_err(|e| ForgeError::Validation(format!("YAML parse error: {e}")))?;
+    outln!("{}", "📊 Forge - Scenario Analysis".bold().green());
+    outln!("   File: {}", file.display());
+    outln!();
 
-    let config: ScenarioConfig = if let Some(scenarios_value) = value.get("scenarios") {
-        let scenarios_map: HashMap<String, serde_yaml_ng::Value> =
-            serde_yaml_ng::from_value(scenarios_value.clone())
-                .map_err(|e| ForgeError::Validation(format!("scenarios config error: {e}")))?;
-
-        // Convert to ScenarioConfig
-        let mut config = ScenarioConfig::default();
-        for (name, def) in scenarios_map {
-            let scenario_def = serde_yaml_ng::from_value(def)
-                .map_err(|e| ForgeError::Validation(format!("scenario '{name}' error: {e}")))?;
-            config.scenarios.insert(name, scenario_def);
-        }
-        config
-    } else {
-        return Err(ForgeError::Validation(
-            "No 'scenarios' section found in YAML".to_string(),
-        ));
-    };
+    // Parse YAML and scenarios config
+    let model = super::load_model(file, limits)?;
+    let config = load_scenario_config(file)?;
 
     // Display config
-    println!("   {}", "Scenarios:".bold());
+    outln!("   {}", "Scenarios:".bold());
     for (name, def) in &config.scenarios {
-        println!(
+        outln!(
             "      {} (p={:.0}%): {}",
             name.bright_blue(),
             def.probability * 100.0,
             &def.description
         );
     }
-    println!();
+    outln!();
 
     // Create engine and run
-    let engine = ScenarioEngine::new(config, model).map_err(ForgeError::Validation)?;
+    let engine = ScenarioEngine::new(config, model)
+        .map_err(ForgeError::Validation)?
+        .with_confidence(confidence);
 
     if let Some(filter) = scenario_filter {
         if verbose {
-            println!("{}", format!("🎯 Running scenario: {filter}").cyan());
+            outln!("{}", format!("🎯 Running scenario: {filter}").cyan());
         }
     } else if verbose {
-        println!("{}", "🔄 Running all scenarios...".cyan());
+        outln!("{}", "🔄 Running all scenarios...".cyan());
     }
     let results = engine.run().map_err(ForgeError::Eval)?;
 
     // Display results
-    println!("{}", "📈 Scenario Results:".bold().green());
-    println!();
+    outln!("{}", "📈 Scenario Results:".bold().green());
+    outln!();
 
     for result in &results.scenarios {
-        println!("   {}:", result.name.bright_blue().bold());
-        println!("      Probability: {:.1}%", result.probability * 100.0);
-        println!("      Key Scalars:");
+        outln!("   {}:", result.name.bright_blue().bold());
+        outln!("      Probability: {:.1}%", result.probability * 100.0);
+        outln!("      Key Scalars:");
         for (var, value) in result.scalars.iter().take(5) {
-            println!("         {}: {:.2}", var.cyan(), value);
+            outln!("         {}: {:.2}", var.cyan(), value);
         }
-        println!();
+        outln!();
     }
 
     // Expected value
-    println!("   {}", "Expected Values (probability-weighted):".bold());
+    outln!("   {}", "Expected Values (probability-weighted):".bold());
     for (var, ev) in &results.expected_values {
-        println!("      {}: {:.2}", var.bright_blue(), ev);
+        outln!("      {}: {:.2}", var.bright_blue(), ev);
     }
-    println!();
+    outln!();
+
+    // Downside risk
+    outln!(
+        "   {}",
+        format!(
+            "Risk Metrics ({:.0}% confidence):",
+            results.confidence * 100.0
+        )
+        .bold()
+    );
+    for (var, value_at_risk) in &results.value_at_risk {
+        let cvar = results
+            .conditional_value_at_risk
+            .get(var)
+            .copied()
+            .unwrap_or(*value_at_risk);
+        outln!(
+            "      {}: VaR {:.2}, CVaR {:.2}",
+            var.bright_blue(),
+            value_at_risk,
+            cvar
+        );
+    }
+    outln!();
 
     // Write output if specified
     if let Some(output_path) = output_file {
-        let output_str = format!("{results:#?}");
-        fs::write(&output_path, output_str).map_err(ForgeError::Io)?;
-        println!(
+        fs::write(&output_path, results.to_yaml()).map_err(ForgeError::Io)?;
+        outln!(
             "{}",
             format!("💾 Results written to {}", output_path.display())
                 .bold()
@@ -347,7 +404,7 @@ pub fn scenarios(
         );
     }
 
-    println!("{}", "✅ Scenario analysis complete".bold().green());
+    outln!("{}", "✅ Scenario analysis complete".bold().green());
     Ok(())
 }
 
@@ -360,12 +417,13 @@ pub fn scenarios(
 pub fn decision_tree(
     file: &Path,
     export_dot: bool,
+    evpi_node: Option<&str>,
     output_file: Option<PathBuf>,
     verbose: bool,
 ) -> ForgeResult<()> {
-    println!("{}", "🌳 Forge - Decision Tree Analysis".bold().green());
-    println!("   File: {}", file.display());
-    println!();
+    outln!("{}", "🌳 Forge - Decision Tree Analysis".bold().green());
+    outln!("   File: {}", file.display());
+    outln!();
 
     // Parse YAML
     let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
@@ -384,60 +442,89 @@ pub fn decision_tree(
     };
 
     // Display config
-    println!("   {}", format!("Tree: {}", config.name).bold());
+    outln!("   {}", format!("Tree: {}", config.name).bold());
     if verbose {
-        println!("      Root: {:?}", config.root.as_ref().map(|r| &r.name));
-        println!("      Nodes: {}", config.nodes.len());
+        outln!("      Root: {:?}", config.root.as_ref().map(|r| &r.name));
+        outln!("      Nodes: {}", config.nodes.len());
     }
-    println!();
+    outln!();
 
     // Create engine and analyze
     let engine = DecisionTreeEngine::new(config).map_err(ForgeError::Validation)?;
 
     if verbose {
-        println!("{}", "🔄 Running backward induction...".cyan());
+        outln!("{}", "🔄 Running backward induction...".cyan());
     }
 
-    let result = engine.analyze().map_err(ForgeError::Eval)?;
+    let mut result = engine.analyze().map_err(ForgeError::Eval)?;
+    if let Some(node) = evpi_node {
+        result.evpi = Some(
+            engine
+                .value_of_perfect_information(node)
+                .map_err(ForgeError::Eval)?,
+        );
+    }
 
     // Display results
-    println!("{}", "📊 Decision Tree Results:".bold().green());
-    println!();
+    outln!("{}", "📊 Decision Tree Results:".bold().green());
+    outln!();
 
     // Optimal path
-    println!("   {}", "Optimal Path:".bold());
+    outln!("   {}", "Optimal Path:".bold());
     for step in &result.optimal_path {
-        println!("      → {}", step.cyan());
+        outln!("      → {}", step.cyan());
     }
-    println!();
+    outln!();
 
     // Expected value at root
-    println!(
+    outln!(
         "   Expected Value: {}",
         format!("${:.2}", result.root_expected_value).bold().green()
     );
 
     // Decision policy
-    println!();
-    println!("   {}", "Decision Policy:".bold());
+    outln!();
+    outln!("   {}", "Decision Policy:".bold());
     for (node, choice) in &result.decision_policy {
-        println!("      At \"{}\": choose \"{}\"", node.bright_blue(), choice);
+        outln!("      At \"{}\": choose \"{}\"", node.bright_blue(), choice);
     }
 
     // Risk profile
-    println!();
-    println!("   {}", "Risk Profile:".bold());
-    println!("      Best case:  ${:.2}", result.risk_profile.best_case);
-    println!("      Worst case: ${:.2}", result.risk_profile.worst_case);
-    println!(
+    outln!();
+    outln!("   {}", "Risk Profile:".bold());
+    outln!("      Best case:  ${:.2}", result.risk_profile.best_case);
+    outln!("      Worst case: ${:.2}", result.risk_profile.worst_case);
+    outln!(
         "      P(value > 0): {:.1}%",
         result.risk_profile.probability_positive * 100.0
     );
-    println!();
+    outln!();
+
+    // Value of perfect information
+    if let Some(ref info) = result.evpi {
+        outln!(
+            "   {}",
+            format!("Perfect Information on \"{}\":", info.node).bold()
+        );
+        let mut outcomes: Vec<_> = info.outcome_values.iter().collect();
+        outcomes.sort_by(|a, b| a.0.cmp(b.0));
+        for (outcome, value) in outcomes {
+            outln!("      If {outcome}: ${value:.2}");
+        }
+        outln!(
+            "      EV with information: ${:.2}",
+            info.expected_value_with_information
+        );
+        outln!(
+            "      EVPI: {}",
+            format!("${:.2}", info.evpi).bold().green()
+        );
+        outln!();
+    }
 
     // Export DOT if requested (feature not yet implemented, just note it)
     if export_dot {
-        println!(
+        outln!(
             "{}",
             "⚠️  DOT export not yet implemented. Use --output for YAML export.".yellow()
         );
@@ -446,7 +533,7 @@ pub fn decision_tree(
     if let Some(output_path) = output_file {
         let output_str = format!("{result:#?}");
         fs::write(&output_path, output_str).map_err(ForgeError::Io)?;
-        println!(
+        outln!(
             "{}",
             format!("💾 Results written to {}", output_path.display())
                 .bold()
@@ -454,7 +541,7 @@ pub fn decision_tree(
         );
     }
 
-    println!("{}", "✅ Decision tree analysis complete".bold().green());
+    outln!("{}", "✅ Decision tree analysis complete".bold().green());
     Ok(())
 }
 
@@ -471,9 +558,9 @@ pub fn real_options(
     output_file: Option<PathBuf>,
     verbose: bool,
 ) -> ForgeResult<()> {
-    println!("{}", "💎 Forge - Real Options Analysis".bold().green());
-    println!("   File: {}", file.display());
-    println!();
+    outln!("{}", "💎 Forge - Real Options Analysis".bold().green());
+    outln!("   File: {}", file.display());
+    outln!();
 
     // Parse YAML
     let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
@@ -492,34 +579,34 @@ pub fn real_options(
     };
 
     // Display config
-    println!("   {}", format!("Analysis: {}", config.name).bold());
-    println!(
+    outln!("   {}", format!("Analysis: {}", config.name).bold());
+    outln!(
         "      Method: {}",
         format!("{:?}", config.method).bright_blue()
     );
-    println!(
+    outln!(
         "      Underlying value: ${:.2}",
         config.underlying.current_value
     );
-    println!(
+    outln!(
         "      Volatility: {:.1}%",
         config.underlying.volatility * 100.0
     );
-    println!(
+    outln!(
         "      Risk-free rate: {:.1}%",
         config.underlying.risk_free_rate * 100.0
     );
-    println!(
+    outln!(
         "      Time horizon: {} years",
         config.underlying.time_horizon
     );
-    println!();
+    outln!();
 
     // Create engine and value options
     let engine = RealOptionsEngine::new(config).map_err(ForgeError::Validation)?;
 
     if verbose {
-        println!("{}", "🔄 Valuing options...".cyan());
+        outln!("{}", "🔄 Valuing options...".cyan());
     }
 
     let result = engine.analyze().map_err(ForgeError::Eval)?;
@@ -542,7 +629,7 @@ pub fn real_options(
     if let Some(output_path) = output_file {
         let output_str = format!("{result:#?}");
         fs::write(&output_path, output_str).map_err(ForgeError::Io)?;
-        println!(
+        outln!(
             "{}",
             format!("💾 Results written to {}", output_path.display())
                 .bold()
@@ -550,7 +637,7 @@ pub fn real_options(
         );
     }
 
-    println!("{}", "✅ Real options analysis complete".bold().green());
+    outln!("{}", "✅ Real options analysis complete".bold().green());
     Ok(())
 }
 
@@ -558,46 +645,37 @@ pub fn real_options(
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be parsed, no tornado section exists,
-/// or the analysis fails.
+/// Returns an error if the file cannot be parsed, no tornado section exists
+/// and no output can be chosen, or the analysis fails.
 pub fn tornado(
     file: &Path,
     output_var: Option<&str>,
     output_file: Option<PathBuf>,
     verbose: bool,
+    limits: ParseLimits,
 ) -> ForgeResult<()> {
-    println!("{}", "🌪️ Forge - Tornado Diagram".bold().green());
-    println!("   File: {}", file.display());
-    println!();
+    outln!("{}", "🌪️ Forge - Tornado Diagram".bold().green());
+    outln!("   File: {}", file.display());
+    outln!();
 
-    // Parse YAML and model
-    let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
-    let model = parser::parse_model(file)?;
-
-    // Parse tornado config
-    let value: serde_yaml_ng::Value = serde_yaml_ng::from_str(&yaml_content)
-        .map_err(|e| ForgeError::Validation(format!("YAML parse error: {e}")))?;
+    // Parse model and tornado config (or discover inputs without one)
+    let model = super::load_model(file, limits)?;
+    let (config, discovered) = load_tornado_config(file, &model, output_var)?;
 
-    let mut config: TornadoConfig = if let Some(tornado_value) = value.get("tornado") {
-        serde_yaml_ng::from_value(tornado_value.clone())
-            .map_err(|e| ForgeError::Validation(format!("tornado config error: {e}")))?
+    // Display config
+    outln!("   Output variable: {}", config.output.bright_blue());
+    if discovered {
+        outln!(
+            "   Inputs to vary: {} (auto-discovered, ±{}%)",
+            config.inputs.len(),
+            DEFAULT_AUTO_SWING * 100.0
+        );
     } else {
-        return Err(ForgeError::Validation(
-            "No 'tornado' section found in YAML".to_string(),
-        ));
-    };
-
-    // Override output variable if specified
-    if let Some(out_var) = output_var {
-        config.output = out_var.to_string();
+        outln!("   Inputs to vary: {}", config.inputs.len());
     }
-
-    // Display config
-    println!("   Output variable: {}", config.output.bright_blue());
-    println!("   Inputs to vary: {}", config.inputs.len());
     if verbose {
         for input in &config.inputs {
-            println!(
+            outln!(
                 "      {} [{:.2} - {:.2}]",
                 input.name.cyan(),
                 input.low,
@@ -605,24 +683,24 @@ pub fn tornado(
             );
         }
     }
-    println!();
+    outln!();
 
     // Create engine and analyze
     let engine = TornadoEngine::new(config, model).map_err(ForgeError::Validation)?;
 
     if verbose {
-        println!("{}", "🔄 Calculating sensitivities...".cyan());
+        outln!("{}", "🔄 Calculating sensitivities...".cyan());
     }
 
     let result = engine.analyze().map_err(ForgeError::Eval)?;
 
     // Display results
-    println!("{}", "📊 Tornado Diagram:".bold().green());
-    println!(
+    outln!("{}", "📊 Tornado Diagram:".bold().green());
+    outln!(
         "   Base value: {}",
         format!("{:.2}", result.base_value).bold()
     );
-    println!();
+    outln!();
 
     // Tornado bars (already sorted by impact)
     let max_impact = result
@@ -641,26 +719,27 @@ pub fn tornado(
         };
         let bar_str = "█".repeat(bar_width.max(1));
 
-        println!(
+        outln!(
             "   {:20} |{}| ±{:.2}",
             bar.input_name.bright_blue(),
             bar_str.cyan(),
             bar.swing.abs()
         );
         if verbose {
-            println!(
+            outln!(
                 "                        Low: {:.2}  High: {:.2}",
-                bar.output_at_low, bar.output_at_high
+                bar.output_at_low,
+                bar.output_at_high
             );
         }
     }
-    println!();
+    outln!();
 
     // Write output if specified
     if let Some(output_path) = output_file {
         let output_str = format!("{result:#?}");
         fs::write(&output_path, output_str).map_err(ForgeError::Io)?;
-        println!(
+        outln!(
             "{}",
             format!("💾 Results written to {}", output_path.display())
                 .bold()
@@ -668,7 +747,7 @@ pub fn tornado(
         );
     }
 
-    println!("{}", "✅ Tornado diagram complete".bold().green());
+    outln!("{}", "✅ Tornado diagram complete".bold().green());
     Ok(())
 }
 
@@ -686,9 +765,9 @@ pub fn bootstrap(
     output_file: Option<PathBuf>,
     verbose: bool,
 ) -> ForgeResult<()> {
-    println!("{}", "🔄 Forge - Bootstrap Resampling".bold().green());
-    println!("   File: {}", file.display());
-    println!();
+    outln!("{}", "🔄 Forge - Bootstrap Resampling".bold().green());
+    outln!("   File: {}", file.display());
+    outln!();
 
     // Parse YAML
     let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
@@ -718,61 +797,75 @@ pub fn bootstrap(
     }
 
     // Display config
-    println!("   {}", "Configuration:".bold());
-    println!(
+    outln!("   {}", "Configuration:".bold());
+    outln!(
         "      Iterations: {}",
         config.iterations.to_string().bright_blue()
     );
-    println!(
+    outln!(
         "      Statistic: {}",
         format!("{:?}", config.statistic).bright_blue()
     );
-    println!("      Data points: {}", config.data.len());
-    println!("      Confidence levels: {:?}", config.confidence_levels);
+    if config.method == BootstrapMethod::Block {
+        outln!(
+            "      Method: {}",
+            format!("block (size {})", config.effective_block_size()).bright_blue()
+        );
+    }
+    outln!("      Data points: {}", config.data.len());
+    outln!("      Confidence levels: {:?}", config.confidence_levels);
     if let Some(seed) = config.seed {
-        println!("      Seed: {seed}");
+        outln!("      Seed: {seed}");
     }
-    println!();
+    outln!();
 
     // Create engine and analyze
     let mut engine = BootstrapEngine::new(config).map_err(ForgeError::Validation)?;
 
     if verbose {
-        println!("{}", "🔄 Resampling...".cyan());
+        outln!("{}", "🔄 Resampling...".cyan());
     }
 
     let result = engine.analyze().map_err(ForgeError::Eval)?;
 
     // Display results
-    println!("{}", "📊 Bootstrap Results:".bold().green());
-    println!();
+    outln!("{}", "📊 Bootstrap Results:".bold().green());
+    outln!();
 
-    println!(
+    outln!(
         "   Original statistic: {}",
         format!("{:.4}", result.original_estimate).bold()
     );
-    println!("   Bootstrap mean: {:.4}", result.bootstrap_mean);
-    println!("   Bootstrap std error: {:.4}", result.bootstrap_std_error);
-    println!("   Bias: {:.4}", result.bias);
-    println!();
+    outln!("   Bootstrap mean: {:.4}", result.bootstrap_mean);
+    outln!("   Bootstrap std error: {:.4}", result.bootstrap_std_error);
+    outln!("   Bias: {:.4}", result.bias);
+    outln!();
 
     // Confidence intervals
-    println!("   {}", "Confidence Intervals:".bold());
+    let ci_label = match result.ci_type {
+        BootstrapCiType::Percentile => "percentile",
+        BootstrapCiType::Bca => "BCa",
+    };
+    outln!(
+        "   {} ({})",
+        "Confidence Intervals:".bold(),
+        ci_label.bright_blue()
+    );
     for ci in &result.confidence_intervals {
-        println!(
+        outln!(
             "      {:.0}% CI: [{:.4}, {:.4}]",
             ci.level * 100.0,
             ci.lower,
             ci.upper
         );
     }
-    println!();
+    outln!();
 
     // Write output if specified
     if let Some(output_path) = output_file {
         let output_str = format!("{result:#?}");
         fs::write(&output_path, output_str).map_err(ForgeError::Io)?;
-        println!(
+        outln!(
             "{}",
             format!("💾 Results written to {}", output_path.display())
                 .bold()
@@ -780,7 +873,7 @@ pub fn bootstrap(
         );
     }
 
-    println!("{}", "✅ Bootstrap analysis complete".bold().green());
+    outln!("{}", "✅ Bootstrap analysis complete".bold().green());
     Ok(())
 }
 
@@ -794,12 +887,13 @@ pub fn bayesian(
     file: &Path,
     query_var: Option<&str>,
     evidence: &[String],
+    map: bool,
     output_file: Option<PathBuf>,
     verbose: bool,
 ) -> ForgeResult<()> {
-    println!("{}", "🔮 Forge - Bayesian Network Inference".bold().green());
-    println!("   File: {}", file.display());
-    println!();
+    outln!("{}", "🔮 Forge - Bayesian Network Inference".bold().green());
+    outln!("   File: {}", file.display());
+    outln!();
 
     // Parse YAML
     let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
@@ -818,8 +912,8 @@ pub fn bayesian(
     };
 
     // Display config
-    println!("   {}", format!("Network: {}", config.name).bold());
-    println!("   Nodes: {}", config.nodes.len());
+    outln!("   {}", format!("Network: {}", config.name).bold());
+    outln!("   Nodes: {}", config.nodes.len());
     if verbose {
         for (name, node) in &config.nodes {
             let parents = if node.parents.is_empty() {
@@ -827,7 +921,7 @@ pub fn bayesian(
             } else {
                 node.parents.join(", ")
             };
-            println!(
+            outln!(
                 "      {} ({} states, parents: {})",
                 name.bright_blue(),
                 node.states.len(),
@@ -835,7 +929,7 @@ pub fn bayesian(
             );
         }
     }
-    println!();
+    outln!();
 
     // Parse evidence
     let mut evidence_map: HashMap<String, &str> = HashMap::new();
@@ -847,41 +941,44 @@ pub fn bayesian(
     }
 
     if !evidence_map.is_empty() {
-        println!("   {}", "Evidence:".bold());
+        outln!("   {}", "Evidence:".bold());
         for (var, val) in &evidence_map {
-            println!("      {} = {}", var.bright_blue(), val.cyan());
+            outln!("      {} = {}", var.bright_blue(), val.cyan());
         }
-        println!();
+        outln!();
     }
 
     // Create engine
     let engine = BayesianEngine::new(config).map_err(ForgeError::Validation)?;
 
     if verbose {
-        println!("{}", "🔄 Running inference...".cyan());
+        outln!("{}", "🔄 Running inference...".cyan());
     }
 
     // Run query
-    if let Some(target) = query_var {
+    if map {
+        let map_result = engine
+            .most_probable_explanation(&evidence_map)
+            .map_err(ForgeError::Eval)?;
+        print_bayesian_map_result(&map_result);
+        if let Some(output_path) = output_file {
+            let output_str = serde_yaml_ng::to_string(&map_result)
+                .map_err(|e| ForgeError::Export(format!("YAML serialization error: {e}")))?;
+            write_bayesian_output(&output_path, &output_str)?;
+        }
+    } else if let Some(target) = query_var {
         let var_result = if evidence_map.is_empty() {
-            println!("{}", "📊 Query Result:".bold().green());
+            outln!("{}", "📊 Query Result:".bold().green());
             engine.query(target).map_err(ForgeError::Eval)?
         } else {
-            println!("{}", "📊 Query Result (with evidence):".bold().green());
+            outln!("{}", "📊 Query Result (with evidence):".bold().green());
             engine
                 .query_with_evidence(target, &evidence_map)
                 .map_err(ForgeError::Eval)?
         };
         print_bayesian_var_result(target, &var_result);
         if let Some(output_path) = output_file {
-            let output_str = format!("{var_result:#?}");
-            fs::write(&output_path, output_str).map_err(ForgeError::Io)?;
-            println!(
-                "{}",
-                format!("💾 Results written to {}", output_path.display())
-                    .bold()
-                    .green()
-            );
+            write_bayesian_output(&output_path, &format!("{var_result:#?}"))?;
         }
     } else {
         // Query all nodes
@@ -893,26 +990,31 @@ pub fn bayesian(
                 .map_err(ForgeError::Eval)?
         };
 
-        println!("{}", "📊 All Node Probabilities:".bold().green());
-        println!();
+        outln!("{}", "📊 All Node Probabilities:".bold().green());
+        outln!();
 
         for (name, var_result) in &all_results.queries {
             print_bayesian_var_result(name, var_result);
         }
 
         if let Some(output_path) = output_file {
-            let output_str = format!("{all_results:#?}");
-            fs::write(&output_path, output_str).map_err(ForgeError::Io)?;
-            println!(
-                "{}",
-                format!("💾 Results written to {}", output_path.display())
-                    .bold()
-                    .green()
-            );
+            write_bayesian_output(&output_path, &format!("{all_results:#?}"))?;
         }
     }
 
-    println!("{}", "✅ Bayesian inference complete".bold().green());
+    outln!("{}", "✅ Bayesian inference complete".bold().green());
+    Ok(())
+}
+
+/// Write bayesian results to the `--output` file and report where they went
+fn write_bayesian_output(output_path: &Path, content: &str) -> ForgeResult<()> {
+    fs::write(output_path, content).map_err(ForgeError::Io)?;
+    outln!(
+        "{}",
+        format!("💾 Results written to {}", output_path.display())
+            .bold()
+            .green()
+    );
     Ok(())
 }
 
@@ -922,62 +1024,83 @@ fn print_real_options_results(
     option_filter: Option<&str>,
     compare_npv: bool,
 ) {
-    println!("{}", "📊 Real Options Results:".bold().green());
-    println!();
+    outln!("{}", "📊 Real Options Results:".bold().green());
+    outln!();
 
     if compare_npv {
-        println!(
+        outln!(
             "   Traditional NPV: {}",
             format!("${:.2}", result.traditional_npv).yellow()
         );
     }
 
-    println!("   {}", "Option Values:".bold());
+    outln!("   {}", "Option Values:".bold());
     for (name, opt_result) in &result.options {
         if let Some(filter) = option_filter {
             if name != filter {
                 continue;
             }
         }
-        println!(
+        outln!(
             "      {} ({}): {}",
             opt_result.name.bright_blue(),
             format!("{:?}", opt_result.option_type).dimmed(),
             format!("${:.2}", opt_result.value).bold().green()
         );
         if let Some(ref trigger) = opt_result.optimal_trigger {
-            println!("         Trigger: {trigger}");
+            outln!("         Trigger: {trigger}");
         }
         if let Some(prob) = opt_result.probability_exercise {
-            println!("         P(exercise): {:.1}%", prob * 100.0);
+            outln!("         P(exercise): {:.1}%", prob * 100.0);
         }
     }
-    println!();
+    outln!();
 
-    println!(
+    outln!(
         "   Total Option Value: {}",
         format!("${:.2}", result.total_option_value).bold().green()
     );
-    println!(
+    outln!(
         "   Project Value (with options): {}",
         format!("${:.2}", result.project_value_with_options)
             .bold()
             .green()
     );
 
-    println!();
-    println!(
+    outln!();
+    outln!(
         "   {}: {}",
         "Decision".bold(),
         result.decision.bright_yellow()
     );
-    println!("   {}: {}", "Recommendation".bold(), result.recommendation);
-    println!();
+    outln!("   {}: {}", "Recommendation".bold(), result.recommendation);
+    outln!();
+}
+
+/// Print the most probable joint assignment and its probability
+fn print_bayesian_map_result(map_result: &crate::bayesian::MapResult) {
+    outln!("{}", "📊 Most Probable Explanation:".bold().green());
+    outln!();
+    for (name, state) in &map_result.states {
+        outln!("   {:25} = {}", name.bright_blue(), state.bold().green());
+    }
+    outln!();
+    outln!(
+        "   Joint probability:     {:.4}%",
+        map_result.joint_probability * 100.0
+    );
+    if !map_result.evidence.is_empty() {
+        outln!(
+            "   Given the evidence:    {:.4}%",
+            map_result.posterior_probability * 100.0
+        );
+    }
+    outln!();
 }
 
 /// Print Bayesian variable result with probability bars
 fn print_bayesian_var_result(name: &str, var_result: &crate::bayesian::VariableResult) {
-    println!("   {}:", name.bright_blue().bold());
+    outln!("   {}:", name.bright_blue().bold());
     for (state, prob) in var_result
         .states
         .iter()
@@ -987,15 +1110,15 @@ fn print_bayesian_var_result(name: &str, var_result: &crate::bayesian::VariableR
         #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
         let bar_width = (prob * 30.0) as usize;
         let bar = "█".repeat(bar_width.max(1));
-        println!("      {:15} |{}| {:.2}%", state, bar.cyan(), prob * 100.0);
+        outln!("      {:15} |{}| {:.2}%", state, bar.cyan(), prob * 100.0);
     }
-    println!();
-    println!(
+    outln!();
+    outln!(
         "   Most likely: {} ({:.1}%)",
         var_result.most_likely.bold().green(),
         var_result.max_probability * 100.0
     );
-    println!();
+    outln!();
 }
 
 #[cfg(test)]
@@ -1069,4 +1192,31 @@ bootstrap:
         assert_eq!(config.iterations, 1000);
         assert_eq!(config.data.len(), 5);
     }
+
+    #[test]
+    fn test_tornado_without_section_discovers_inputs() {
+        let yaml = r#"
+_forge_version: "5.0.0"
+
+assumptions:
+  price:
+    value: 20
+  units:
+    value: 100
+  profit:
+    value: null
+    formula: "=price * units - 500"
+"#;
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "{yaml}").unwrap();
+
+        let result = tornado_core(file.path(), None, ParseLimits::default()).unwrap();
+        assert_eq!(result.output, "assumptions.profit");
+        assert_eq!(result.bars.len(), 2);
+        // ±10% of either input moves price * units by ±200
+        for bar in &result.bars {
+            assert!((bar.swing - 400.0).abs() < 1e-6, "{bar:?}");
+        }
+        assert!((result.base_value - 1500.0).abs() < 1e-9);
+    }
 }
@@ -25,6 +25,18 @@ pub struct ValidationMismatch {
     pub diff: f64,
 }
 
+/// Result of the snapshot command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotResult {
+    pub snapshot_path: String,
+    /// Whether the snapshot file was (re)written instead of compared
+    pub written: bool,
+    /// Numeric outputs that moved beyond tolerance (current vs snapshot)
+    pub mismatches: Vec<ValidationMismatch>,
+    /// Other differences: changed text, new or missing outputs
+    pub changes: Vec<String>,
+}
+
 /// Result of the calculate command
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalculationResult {
@@ -52,6 +64,10 @@ pub struct AuditResult {
     pub current_value: Option<f64>,
     pub calculated_value: Option<f64>,
     pub formula: Option<String>,
+    /// `notes` metadata of the audited variable
+    pub notes: Option<String>,
+    /// `source` metadata of the audited variable
+    pub source: Option<String>,
     pub dependencies: Vec<AuditDep>,
     pub is_valid: bool,
 }
@@ -63,6 +79,8 @@ pub struct AuditDep {
     pub dep_type: String,
     pub formula: Option<String>,
     pub value: Option<f64>,
+    pub notes: Option<String>,
+    pub source: Option<String>,
     pub children: Vec<Self>,
 }
 
@@ -137,6 +155,21 @@ pub struct GoalSeekResult {
     pub error: f64,
     pub iterations: i32,
     pub converged: bool,
+    /// Strategy that found the solution (bisection after a Newton fallback)
+    pub method: super::analysis::SolverMethod,
+}
+
+/// Result of break-even analysis: the zero crossing plus headroom from the
+/// varied input's current value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BreakEvenResult {
+    #[serde(flatten)]
+    pub goal_seek: GoalSeekResult,
+    /// Value of the varied input in the model
+    pub current_value: Option<f64>,
+    /// Percent distance of the current value above (positive) or below
+    /// (negative) break-even, relative to the current value
+    pub margin_of_safety: Option<f64>,
 }
 
 /// Result of variance analysis
@@ -168,3 +201,44 @@ pub struct ComparisonResult {
     pub variables: Vec<String>,
     pub values: HashMap<String, HashMap<String, Option<f64>>>,
 }
+
+/// Result of the stats command: a profile of every numeric column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsResult {
+    pub columns: Vec<ColumnStats>,
+}
+
+/// Summary statistics for one numeric column (`None` when undefined, e.g.
+/// the standard deviation of fewer than two values)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnStats {
+    pub table: String,
+    pub column: String,
+    pub count: usize,
+    pub nulls: usize,
+    pub mean: Option<f64>,
+    pub median: Option<f64>,
+    pub std: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+/// Result of the dcf command: a discounted cash flow schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DcfResult {
+    pub rate: f64,
+    pub rows: Vec<DcfRow>,
+    /// Sum of the discounted values (NPV, or XNPV when dated)
+    pub npv: f64,
+}
+
+/// One period of a discounted cash flow schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DcfRow {
+    /// Periods from the start: 1, 2, ... or, with dates, years since the first date
+    pub period: f64,
+    pub date: Option<String>,
+    pub cash_flow: f64,
+    pub discount_factor: f64,
+    pub discounted_value: f64,
+}
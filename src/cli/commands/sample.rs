@@ -0,0 +1,281 @@
+//! Sample command - draw random rows from a calculated table
+//!
+//! Rows are picked without replacement by default; a seed makes the draw
+//! reproducible. The sample is printed as a Forge table with every column's
+//! type preserved (formula columns carry their calculated values).
+
+use super::table_export::calculated_table;
+use crate::error::{ForgeError, ForgeResult};
+use crate::parser::ParseLimits;
+use crate::types::{Column, ColumnValue, Table};
+use crate::writer::to_yaml;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use serde_yaml_ng::{Mapping, Value};
+use std::path::Path;
+
+/// A random sample of a table's rows and the seed that produced it
+#[derive(Debug, Clone)]
+pub struct SampleResult {
+    pub table: Table,
+    pub seed: u64,
+}
+
+/// Draw `n` random rows from `table` (optional when the model has one table).
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be parsed or calculated, the table
+/// does not exist, or `n` exceeds the row count without `with_replacement`.
+pub fn sample_core(
+    file: &Path,
+    table: Option<&str>,
+    n: usize,
+    seed: Option<u64>,
+    with_replacement: bool,
+    limits: ParseLimits,
+) -> ForgeResult<SampleResult> {
+    let source = calculated_table(file, table, limits)?;
+    let rows = source.row_count();
+    if (!with_replacement && n > rows) || (with_replacement && rows == 0 && n > 0) {
+        return Err(ForgeError::Validation(format!(
+            "Cannot sample {n} rows from '{}', which has {rows} rows{}",
+            source.name,
+            if with_replacement {
+                ""
+            } else {
+                " (use --with-replacement to allow repeats)"
+            }
+        )));
+    }
+
+    let seed = seed.unwrap_or_else(|| rand::rng().random());
+    let mut rng = StdRng::seed_from_u64(seed);
+    let indices: Vec<usize> = if with_replacement {
+        (0..n).map(|_| rng.random_range(0..rows)).collect()
+    } else {
+        rand::seq::index::sample(&mut rng, rows, n).into_vec()
+    };
+
+    Ok(SampleResult {
+        table: take_rows(&source, &indices)?,
+        seed,
+    })
+}
+
+/// A copy of `table` holding only the rows at `indices`, in that order
+///
+/// Fails on a ragged table, whose shorter columns have no value at some indices.
+fn take_rows(table: &Table, indices: &[usize]) -> ForgeResult<Table> {
+    fn pick<T: Clone>(values: &[T], indices: &[usize]) -> Vec<T> {
+        indices.iter().map(|&i| values[i].clone()).collect()
+    }
+    table
+        .validate_lengths()
+        .map_err(|e| ForgeError::Validation(format!("Table '{}': {e}", table.name)))?;
+    let mut sample = Table::new(table.name.clone());
+    for column in table.columns.values() {
+        let values = match &column.values {
+            ColumnValue::Number(v) => ColumnValue::Number(pick(v, indices)),
+            ColumnValue::Text(v) => ColumnValue::Text(pick(v, indices)),
+            ColumnValue::Date(v) => ColumnValue::Date(pick(v, indices)),
+            ColumnValue::Boolean(v) => ColumnValue::Boolean(pick(v, indices)),
+        };
+        sample.add_column(Column::with_metadata(
+            column.name.clone(),
+            values,
+            column.metadata.clone(),
+        ));
+    }
+    Ok(sample)
+}
+
+/// Render a sampled table as a standalone Forge model
+#[must_use]
+pub fn render_sample_yaml(result: &SampleResult) -> String {
+    let mut columns: Vec<&Column> = result.table.columns.values().collect();
+    columns.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut table = Mapping::new();
+    for column in columns {
+        let values: Vec<Value> = match &column.values {
+            ColumnValue::Number(v) => v.iter().map(|n| number_value(*n)).collect(),
+            ColumnValue::Text(v) | ColumnValue::Date(v) => {
+                v.iter().map(|s| Value::String(s.clone())).collect()
+            },
+            ColumnValue::Boolean(v) => v.iter().map(|b| Value::Bool(*b)).collect(),
+        };
+        table.insert(Value::String(column.name.clone()), Value::Sequence(values));
+    }
+
+    let mut root = Mapping::new();
+    root.insert("_forge_version".into(), "5.0.0".into());
+    root.insert(
+        Value::String(result.table.name.clone()),
+        Value::Mapping(table),
+    );
+    format!(
+        "# Random sample of {} rows from {} (seed {})\n{}",
+        result.table.row_count(),
+        result.table.name,
+        result.seed,
+        to_yaml(&Value::Mapping(root))
+    )
+}
+
+/// Whole numbers without a trailing `.0`
+pub(super) fn number_value(n: f64) -> Value {
+    #[allow(clippy::cast_possible_truncation)] // guarded by the magnitude check
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        Value::Number((n as i64).into())
+    } else {
+        Value::Number(n.into())
+    }
+}
+
+/// Execute the sample command - print a random sample of a table's rows.
+///
+/// # Errors
+///
+/// Returns an error if the sample cannot be drawn (see [`sample_core`]).
+pub fn sample(
+    file: &Path,
+    table: Option<&str>,
+    n: usize,
+    seed: Option<u64>,
+    with_replacement: bool,
+    limits: ParseLimits,
+) -> ForgeResult<()> {
+    let result = sample_core(file, table, n, seed, with_replacement, limits)?;
+    out!("{}", render_sample_yaml(&result));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const MODEL: &str = r#"_forge_version: "5.0.0"
+orders:
+  id: [1, 2, 3, 4, 5, 6, 7, 8]
+  region: ["N", "S", "E", "W", "N", "S", "E", "W"]
+  shipped: [true, false, true, false, true, false, true, false]
+  amount: [10, 20, 30, 40, 50, 60, 70, 80]
+  doubled: "=amount * 2"
+"#;
+
+    fn write_model(dir: &TempDir) -> std::path::PathBuf {
+        let path = dir.path().join("model.yaml");
+        fs::write(&path, MODEL).unwrap();
+        path
+    }
+
+    fn ids(result: &SampleResult) -> Vec<f64> {
+        match &result.table.columns["id"].values {
+            ColumnValue::Number(v) => v.clone(),
+            other => panic!("expected numbers, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_seeded_sample_is_reproducible() {
+        let dir = TempDir::new().unwrap();
+        let path = write_model(&dir);
+
+        let first = sample_core(
+            &path,
+            Some("orders"),
+            5,
+            Some(42),
+            false,
+            ParseLimits::default(),
+        )
+        .unwrap();
+        let second = sample_core(
+            &path,
+            Some("orders"),
+            5,
+            Some(42),
+            false,
+            ParseLimits::default(),
+        )
+        .unwrap();
+        assert_eq!(first.table.row_count(), 5);
+        assert_eq!(ids(&first), ids(&second));
+
+        // Without replacement: distinct rows, and every column stays aligned
+        let mut picked = ids(&first);
+        picked.sort_by(f64::total_cmp);
+        picked.dedup();
+        assert_eq!(picked.len(), 5);
+        let ColumnValue::Number(doubled) = &first.table.columns["doubled"].values else {
+            panic!("doubled should be numeric");
+        };
+        for (id, doubled) in ids(&first).iter().zip(doubled) {
+            assert!((doubled - id * 20.0).abs() < f64::EPSILON);
+        }
+        assert!(matches!(
+            first.table.columns["region"].values,
+            ColumnValue::Text(_)
+        ));
+        assert!(matches!(
+            first.table.columns["shipped"].values,
+            ColumnValue::Boolean(_)
+        ));
+    }
+
+    #[test]
+    fn test_sample_larger_than_table_needs_replacement() {
+        let dir = TempDir::new().unwrap();
+        let path = write_model(&dir);
+
+        let err = sample_core(&path, None, 20, Some(1), false, ParseLimits::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("which has 8 rows"));
+        assert!(err.contains("--with-replacement"));
+
+        let result = sample_core(&path, None, 20, Some(1), true, ParseLimits::default()).unwrap();
+        assert_eq!(result.table.row_count(), 20);
+    }
+
+    #[test]
+    fn test_render_sample_yaml_parses_as_model() {
+        let dir = TempDir::new().unwrap();
+        let result = sample_core(
+            &write_model(&dir),
+            None,
+            3,
+            Some(7),
+            false,
+            ParseLimits::default(),
+        )
+        .unwrap();
+        let yaml = render_sample_yaml(&result);
+        assert!(yaml.starts_with("# Random sample of 3 rows from orders (seed 7)\n"));
+
+        let out = dir.path().join("sample.yaml");
+        fs::write(&out, &yaml).unwrap();
+        let model = crate::parser::parse_model(&out).unwrap();
+        assert_eq!(model.tables["orders"].row_count(), 3);
+    }
+
+    #[test]
+    fn test_take_rows_rejects_ragged_table() {
+        let mut table = Table::new("orders".to_string());
+        table.add_column(Column::new(
+            "id".to_string(),
+            ColumnValue::Number(vec![1.0, 2.0, 3.0]),
+        ));
+        table.add_column(Column::new(
+            "region".to_string(),
+            ColumnValue::Text(vec!["N".to_string()]),
+        ));
+
+        let err = take_rows(&table, &[2]).unwrap_err().to_string();
+        assert!(err.contains("Table 'orders'"), "{err}");
+        assert!(err.contains("rows"), "{err}");
+    }
+}
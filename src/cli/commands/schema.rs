@@ -47,13 +47,13 @@ pub fn schema(version: Option<&str>, list: bool) -> ForgeResult<()> {
         Some("v1" | "v1.0.0" | "1" | "1.0.0") => SCHEMA_V1,
         Some("v5" | "v5.0.0" | "5" | "5.0.0") => SCHEMA_V5,
         Some(v) => {
-            eprintln!(
+            eoutln!(
                 "{}: Unknown schema version '{}'. Use 'v1' or 'v5'.",
                 "Error".red().bold(),
                 v
             );
-            eprintln!();
-            eprintln!(
+            eoutln!();
+            eoutln!(
                 "Run {} to see available versions.",
                 "forge schema --list".yellow()
             );
@@ -65,35 +65,35 @@ pub fn schema(version: Option<&str>, list: bool) -> ForgeResult<()> {
         },
     };
 
-    println!("{schema}");
+    outln!("{schema}");
     Ok(())
 }
 
 /// Print list of available schema versions
 fn print_schema_list() {
-    println!("{}", "Available Forge Schema Versions".bold().green());
-    println!();
-    println!(
+    outln!("{}", "Available Forge Schema Versions".bold().green());
+    outln!();
+    outln!(
         "  {}  Scalar-only models (simple key-value pairs)",
         "v1.0.0".cyan().bold()
     );
-    println!("         - Named variables with value/formula/metadata");
-    println!("         - Scenarios for what-if analysis");
-    println!("         - No arrays or tables");
-    println!();
-    println!("  {}  Full enterprise support", "v5.0.0".cyan().bold());
-    println!("         - Arrays and tables with row-wise formulas");
-    println!("         - inputs/outputs separation");
-    println!("         - Monte Carlo, Decision Trees, Real Options");
-    println!("         - Cross-file includes");
-    println!();
-    println!(
+    outln!("         - Named variables with value/formula/metadata");
+    outln!("         - Scenarios for what-if analysis");
+    outln!("         - No arrays or tables");
+    outln!();
+    outln!("  {}  Full enterprise support", "v5.0.0".cyan().bold());
+    outln!("         - Arrays and tables with row-wise formulas");
+    outln!("         - inputs/outputs separation");
+    outln!("         - Monte Carlo, Decision Trees, Real Options");
+    outln!("         - Cross-file includes");
+    outln!();
+    outln!(
         "Usage: {} or {}",
         "forge schema v1".yellow(),
         "forge schema v5".yellow()
     );
-    println!();
-    println!("Pipe to file: {}", "forge schema v5 > schema.json".dimmed());
+    outln!();
+    outln!("Pipe to file: {}", "forge schema v5 > schema.json".dimmed());
 }
 
 #[cfg(test)]
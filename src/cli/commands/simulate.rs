@@ -5,10 +5,11 @@
 use crate::core::array_calculator::ArrayCalculator;
 use crate::error::{ForgeError, ForgeResult};
 use crate::monte_carlo::{MonteCarloConfig, MonteCarloEngine};
-use crate::parser;
+use crate::parser::ParseLimits;
 use colored::Colorize;
 use std::collections::HashMap;
 use std::fs;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 
 /// Run Monte Carlo simulation and return structured results (no printing).
@@ -22,6 +23,7 @@ pub fn simulate_core(
     iterations_override: Option<usize>,
     seed_override: Option<u64>,
     sampling_override: Option<&str>,
+    limits: ParseLimits,
 ) -> ForgeResult<crate::monte_carlo::SimulationResult> {
     let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
     let mut config = parse_monte_carlo_config(&yaml_content)?;
@@ -37,7 +39,7 @@ pub fn simulate_core(
     }
     config.validate().map_err(ForgeError::Validation)?;
 
-    let model = parser::parse_model(file)?;
+    let model = super::load_model(file, limits)?;
     let mut engine = MonteCarloEngine::new(config.clone()).map_err(ForgeError::Validation)?;
     engine
         .parse_distributions_from_model(&model)
@@ -46,7 +48,7 @@ pub fn simulate_core(
     let output_vars: Vec<String> = config.outputs.iter().map(|o| o.variable.clone()).collect();
 
     engine
-        .run_with_evaluator(|inputs: &HashMap<String, f64>| {
+        .run_parallel(|inputs: &HashMap<String, f64>| {
             let mut iter_model = model.clone();
             for (var_name, &value) in inputs {
                 if let Some(scalar) = iter_model.scalars.get_mut(var_name) {
@@ -87,14 +89,15 @@ pub fn simulate(
     sampling_override: Option<&str>,
     output_file: Option<PathBuf>,
     verbose: bool,
+    limits: ParseLimits,
 ) -> ForgeResult<()> {
-    println!("{}", "🎲 Forge - Monte Carlo Simulation".bold().green());
-    println!("   File: {}", file.display());
-    println!();
+    outln!("{}", "🎲 Forge - Monte Carlo Simulation".bold().green());
+    outln!("   File: {}", file.display());
+    outln!();
 
     // Parse the YAML file
     if verbose {
-        println!("{}", "📖 Parsing YAML file...".cyan());
+        outln!("{}", "📖 Parsing YAML file...".cyan());
     }
 
     let yaml_content = fs::read_to_string(file).map_err(ForgeError::Io)?;
@@ -117,22 +120,22 @@ pub fn simulate(
     config.validate().map_err(ForgeError::Validation)?;
 
     // Display config
-    println!("   {}", "Configuration:".bold());
-    println!(
+    outln!("   {}", "Configuration:".bold());
+    outln!(
         "      Iterations: {}",
         config.iterations.to_string().bright_blue()
     );
-    println!("      Sampling:   {}", config.sampling.bright_blue());
+    outln!("      Sampling:   {}", config.sampling.bright_blue());
     if let Some(seed) = config.seed {
-        println!("      Seed:       {}", seed.to_string().bright_blue());
+        outln!("      Seed:       {}", seed.to_string().bright_blue());
     }
-    println!();
+    outln!();
 
     // Parse the full model to extract distributions
-    let model = parser::parse_model(file)?;
+    let model = super::load_model(file, limits)?;
 
     if verbose {
-        println!(
+        outln!(
             "   Found {} tables, {} scalars",
             model.tables.len(),
             model.scalars.len()
@@ -149,7 +152,7 @@ pub fn simulate(
 
     // Run simulation with formula evaluation
     if verbose {
-        println!("{}", "🎲 Running simulation...".cyan());
+        outln!("{}", "🎲 Running simulation...".cyan());
     }
 
     // Get output variable names from config
@@ -157,7 +160,7 @@ pub fn simulate(
 
     // Create evaluator that runs formulas for each iteration
     let result = engine
-        .run_with_evaluator(|inputs: &HashMap<String, f64>| {
+        .run_parallel(|inputs: &HashMap<String, f64>| {
             // Clone the model and substitute sampled values
             let mut iter_model = model.clone();
 
@@ -204,26 +207,34 @@ pub fn simulate(
         write_simulation_output(&result, &output_path)?;
     }
 
-    println!("{}", "✅ Simulation complete".bold().green());
+    outln!("{}", "✅ Simulation complete".bold().green());
 
     Ok(())
 }
 
 /// Print simulation results to stdout
 fn print_simulation_results(result: &crate::monte_carlo::SimulationResult) {
-    println!("{}", "📊 Simulation Results:".bold().green());
-    println!("   Iterations:     {}", result.iterations_completed);
-    println!("   Execution time: {} ms", result.execution_time_ms);
-    println!();
+    outln!("{}", "📊 Simulation Results:".bold().green());
+    if result.iterations_completed < result.config.iterations {
+        outln!(
+            "   Iterations:     {} (converged early, cap {})",
+            result.iterations_completed,
+            result.config.iterations
+        );
+    } else {
+        outln!("   Iterations:     {}", result.iterations_completed);
+    }
+    outln!("   Execution time: {} ms", result.execution_time_ms);
+    outln!();
 
     // Show input distributions
-    println!("   {}", "Input Distributions:".bold());
+    outln!("   {}", "Input Distributions:".bold());
     for (var_name, samples) in &result.input_samples {
         #[allow(clippy::cast_precision_loss)] // sample counts are always small enough for f64
         let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
         let min = samples.iter().copied().fold(f64::INFINITY, f64::min);
         let max = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-        println!(
+        outln!(
             "      {} mean={:.2} min={:.2} max={:.2}",
             var_name.bright_blue(),
             mean,
@@ -231,36 +242,48 @@ fn print_simulation_results(result: &crate::monte_carlo::SimulationResult) {
             max
         );
     }
-    println!();
+    outln!();
+
+    // Histograms are for humans; keep piped output free of block characters
+    let show_histogram = std::io::stdout().is_terminal();
 
     // Show output results
     if !result.outputs.is_empty() {
-        println!("   {}", "Output Statistics:".bold());
+        outln!("   {}", "Output Statistics:".bold());
         for (var_name, output) in &result.outputs {
             let stats = &output.statistics;
-            println!("      {}:", var_name.bright_blue().bold());
-            println!("         Mean:      {:.4}", stats.mean);
-            println!("         Median:    {:.4}", stats.median);
-            println!("         Std Dev:   {:.4}", stats.std_dev);
-            println!("         Min:       {:.4}", stats.min);
-            println!("         Max:       {:.4}", stats.max);
+            outln!("      {}:", var_name.bright_blue().bold());
+            outln!("         Mean:      {:.4}", stats.mean);
+            outln!("         Median:    {:.4}", stats.median);
+            outln!("         Std Dev:   {:.4}", stats.std_dev);
+            outln!("         Std Error: {:.4}", output.standard_error);
+            outln!("         Min:       {:.4}", stats.min);
+            outln!("         Max:       {:.4}", stats.max);
+            if show_histogram && !output.histogram.counts.is_empty() {
+                outln!(
+                    "         Histogram: {} {:.2} … {:.2}",
+                    output.histogram.sparkline().bright_cyan(),
+                    stats.min,
+                    stats.max
+                );
+            }
 
             // Percentiles
-            println!("         Percentiles:");
+            outln!("         Percentiles:");
             for (p, v) in &stats.percentiles {
-                println!("            P{p}: {v:.4}");
+                outln!("            P{p}: {v:.4}");
             }
 
             // Thresholds
             for (threshold, prob) in &output.threshold_probabilities {
-                println!(
+                outln!(
                     "         P({} {}) = {:.2}%",
                     var_name,
                     threshold,
                     prob * 100.0
                 );
             }
-            println!();
+            outln!();
         }
     }
 }
@@ -288,7 +311,7 @@ fn write_simulation_output(
         },
     }
 
-    println!(
+    outln!(
         "{}",
         format!("💾 Results written to {}", output_path.display())
             .bold()
@@ -432,7 +455,7 @@ scalars:
 
         // Run with formula evaluation (the fix)
         let result = engine
-            .run_with_evaluator(|inputs: &HashMap<String, f64>| {
+            .run_parallel(|inputs: &HashMap<String, f64>| {
                 let mut iter_model = model.clone();
 
                 // Substitute sampled values
@@ -0,0 +1,298 @@
+//! Snapshot command - pin a model's calculated outputs to a golden file
+//!
+//! `forge snapshot model.yaml` calculates the model and compares every scalar
+//! and table cell with `model.snapshot.json`, which is written on the first
+//! run or with `--update`. Numbers are compared with the same tolerance (and
+//! reported with the same diff) as `forge validate`.
+
+use super::results::SnapshotResult;
+use super::{print_value_diff, value_mismatch};
+use crate::core::ArrayCalculator;
+use crate::error::{ForgeError, ForgeResult};
+use crate::parser::ParseLimits;
+use crate::types::ColumnValue;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Calculated outputs of a model, as stored in the snapshot file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub scalars: BTreeMap<String, Option<f64>>,
+    pub tables: BTreeMap<String, BTreeMap<String, ColumnValue>>,
+}
+
+/// One snapshotted output value
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    Number(f64),
+    Other(String),
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", super::format_number(*n)),
+            Self::Other(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl Snapshot {
+    /// Calculate `file` and capture its outputs: formula scalars and formula columns
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model cannot be parsed or calculated.
+    pub fn of(file: &Path, limits: ParseLimits) -> ForgeResult<Self> {
+        let model = super::load_model(file, limits)?;
+        let calculated = ArrayCalculator::new(model).calculate_all()?;
+        Ok(Self {
+            scalars: calculated
+                .scalars
+                .iter()
+                .filter(|(_, var)| var.formula.is_some())
+                .map(|(name, var)| (name.clone(), var.value))
+                .collect(),
+            tables: calculated
+                .tables
+                .iter()
+                .map(|(name, table)| {
+                    let columns = table
+                        .columns
+                        .iter()
+                        .filter(|(col, _)| table.row_formulas.contains_key(*col))
+                        .map(|(col, column)| (col.clone(), column.values.clone()))
+                        .collect();
+                    (name.clone(), columns)
+                })
+                .collect(),
+        })
+    }
+
+    /// Every output as a named cell (`margin`, `sales.revenue[3]`), rows 1-based
+    fn cells(&self) -> Vec<(String, Cell)> {
+        let mut cells: Vec<(String, Cell)> = self
+            .scalars
+            .iter()
+            .map(|(name, value)| {
+                let cell = value.map_or_else(|| Cell::Other("null".to_string()), Cell::Number);
+                (name.clone(), cell)
+            })
+            .collect();
+        for (table, columns) in &self.tables {
+            for (column, values) in columns {
+                let row_cells: Vec<Cell> = match values {
+                    ColumnValue::Number(v) => v.iter().copied().map(Cell::Number).collect(),
+                    ColumnValue::Text(v) | ColumnValue::Date(v) => {
+                        v.iter().cloned().map(Cell::Other).collect()
+                    },
+                    ColumnValue::Boolean(v) => {
+                        v.iter().map(|b| Cell::Other(b.to_string())).collect()
+                    },
+                };
+                for (row, cell) in row_cells.into_iter().enumerate() {
+                    cells.push((format!("{table}.{column}[{}]", row + 1), cell));
+                }
+            }
+        }
+        cells
+    }
+}
+
+/// Snapshot file for a model: `model.yaml` -> `model.snapshot.json`
+#[must_use]
+pub fn snapshot_path(file: &Path) -> PathBuf {
+    file.with_extension("snapshot.json")
+}
+
+/// Calculate `file` and compare its outputs with the stored snapshot
+///
+/// The snapshot is (re)written instead when `update` is set or none exists
+/// yet; the result then has no differences.
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be calculated or the snapshot file
+/// cannot be read, parsed or written.
+pub fn snapshot_core(
+    file: &Path,
+    update: bool,
+    limits: ParseLimits,
+) -> ForgeResult<SnapshotResult> {
+    let path = snapshot_path(file);
+    let current = Snapshot::of(file, limits)?;
+    let mut result = SnapshotResult {
+        snapshot_path: path.display().to_string(),
+        written: false,
+        mismatches: Vec::new(),
+        changes: Vec::new(),
+    };
+
+    if update || !path.exists() {
+        let json = serde_json::to_string_pretty(&current)
+            .map_err(|e| ForgeError::Export(format!("Failed to serialize snapshot: {e}")))?;
+        fs::write(&path, json + "\n")
+            .map_err(|e| ForgeError::IO(format!("Failed to write {}: {e}", path.display())))?;
+        result.written = true;
+        return Ok(result);
+    }
+
+    let stored = fs::read_to_string(&path)
+        .map_err(|e| ForgeError::IO(format!("Failed to read {}: {e}", path.display())))?;
+    let expected: Snapshot = serde_json::from_str(&stored)
+        .map_err(|e| ForgeError::Parse(format!("{}: {e}", path.display())))?;
+
+    let mut expected_cells: HashMap<String, Cell> = expected.cells().into_iter().collect();
+    for (name, cell) in current.cells() {
+        match (expected_cells.remove(&name), cell) {
+            (None, cell) => result.changes.push(format!("{name}: new output ({cell})")),
+            (Some(Cell::Number(expected)), Cell::Number(current)) => {
+                result
+                    .mismatches
+                    .extend(value_mismatch(&name, current, expected));
+            },
+            (Some(expected), cell) if expected != cell => {
+                result
+                    .changes
+                    .push(format!("{name}: {cell} (snapshot: {expected})"));
+            },
+            _ => {},
+        }
+    }
+    let mut removed: Vec<_> = expected_cells.into_iter().collect();
+    removed.sort_by(|a, b| a.0.cmp(&b.0));
+    for (name, cell) in removed {
+        result
+            .changes
+            .push(format!("{name}: missing (snapshot: {cell})"));
+    }
+    Ok(result)
+}
+
+/// Execute the snapshot command
+///
+/// # Errors
+///
+/// Returns an error if the snapshot cannot be produced, or if the outputs
+/// differ from it and `update` is not set.
+pub fn snapshot(file: &Path, update: bool, limits: ParseLimits) -> ForgeResult<()> {
+    outln!("{}", "📸 Forge - Snapshot".bold().green());
+    outln!("   File: {}", file.display());
+
+    let result = snapshot_core(file, update, limits)?;
+    outln!("   Snapshot: {}\n", result.snapshot_path);
+
+    if result.written {
+        outln!("{}", "✅ Snapshot written".bold().green());
+        return Ok(());
+    }
+    let differences = result.mismatches.len() + result.changes.len();
+    if differences == 0 {
+        outln!("{}", "✅ All outputs match the snapshot!".bold().green());
+        return Ok(());
+    }
+
+    outln!(
+        "{}",
+        format!("❌ {differences} output(s) differ from the snapshot!")
+            .bold()
+            .red()
+    );
+    outln!();
+    for mismatch in &result.mismatches {
+        outln!("   {}", mismatch.name.bright_blue().bold());
+        print_value_diff(mismatch);
+        outln!();
+    }
+    for change in &result.changes {
+        outln!("   {}", change.yellow());
+    }
+    if !result.changes.is_empty() {
+        outln!();
+    }
+    outln!(
+        "{}",
+        "💡 Run 'forge snapshot --update' to accept the new outputs"
+            .bold()
+            .yellow()
+    );
+    Err(ForgeError::Validation(format!(
+        "{differences} output(s) differ from the snapshot"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::theme;
+    use tempfile::TempDir;
+
+    const MODEL: &str = r#"_forge_version: "5.0.0"
+sales:
+  units: [10, 20]
+  revenue: "=units * 5"
+total:
+  value: null
+  formula: "=SUM(sales.revenue)"
+"#;
+
+    #[test]
+    fn test_matching_snapshot_passes() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("model.yaml");
+        fs::write(&file, MODEL).unwrap();
+
+        // First run writes the snapshot next to the model
+        assert!(
+            snapshot_core(&file, false, ParseLimits::default())
+                .unwrap()
+                .written
+        );
+        assert!(dir.path().join("model.snapshot.json").exists());
+
+        let result = snapshot_core(&file, false, ParseLimits::default()).unwrap();
+        assert!(!result.written);
+        assert!(result.mismatches.is_empty());
+        assert!(result.changes.is_empty());
+        assert!(snapshot(&file, false, ParseLimits::default()).is_ok());
+    }
+
+    #[test]
+    fn test_changed_output_fails_with_delta() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("model.yaml");
+        fs::write(&file, MODEL).unwrap();
+        snapshot_core(&file, false, ParseLimits::default()).unwrap();
+
+        fs::write(&file, MODEL.replace("[10, 20]", "[10, 30]")).unwrap();
+        let result = snapshot_core(&file, false, ParseLimits::default()).unwrap();
+        let names: Vec<&str> = result.mismatches.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"total"));
+        assert!(names.contains(&"sales.revenue[2]"));
+        let total = result
+            .mismatches
+            .iter()
+            .find(|m| m.name == "total")
+            .unwrap();
+        assert!((total.expected_value - 150.0).abs() < f64::EPSILON);
+        assert!((total.current_value - 200.0).abs() < f64::EPSILON);
+        assert!((total.diff - 50.0).abs() < f64::EPSILON);
+
+        let output =
+            theme::capture(|| assert!(snapshot(&file, false, ParseLimits::default()).is_err()));
+        assert!(output.contains("Diff:     50.000000"), "{output}");
+
+        // --update accepts the new outputs
+        assert!(
+            snapshot_core(&file, true, ParseLimits::default())
+                .unwrap()
+                .written
+        );
+        assert!(snapshot(&file, false, ParseLimits::default()).is_ok());
+    }
+}
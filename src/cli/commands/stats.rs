@@ -0,0 +1,220 @@
+//! Stats command - profile the numeric columns of a model
+//!
+//! Count, mean, median, standard deviation, min, max and missing values per
+//! numeric column, computed with the same evaluators as the matching formula
+//! functions (AVERAGE, MEDIAN, STDEV, MIN, MAX).
+
+use super::format_number;
+use super::results::{ColumnStats, StatsResult};
+use crate::cli::envelope;
+use crate::core::array_calculator::evaluator::{evaluate, EvalContext, Value};
+use crate::core::array_calculator::parser::parse;
+use crate::core::array_calculator::tokenizer::tokenize;
+use crate::core::ArrayCalculator;
+use crate::error::{ForgeError, ForgeResult};
+use crate::parser::ParseLimits;
+use crate::types::ColumnValue;
+use colored::Colorize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Profile the numeric columns of `file` (all tables, or just `table`).
+///
+/// Formula columns are calculated first, so they are profiled too. Missing
+/// values (NaN) are counted as nulls and left out of the other statistics.
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be parsed or calculated, or if `table`
+/// does not exist.
+pub fn stats_core(
+    file: &Path,
+    table: Option<&str>,
+    limits: ParseLimits,
+) -> ForgeResult<StatsResult> {
+    let model = super::load_model(file, limits)?;
+    let model = ArrayCalculator::new(model).calculate_all()?;
+
+    let mut table_names: Vec<&String> = model.tables.keys().collect();
+    table_names.sort();
+    if let Some(name) = table {
+        if !model.tables.contains_key(name) {
+            let list: Vec<&str> = table_names.iter().map(|s| s.as_str()).collect();
+            return Err(ForgeError::Validation(format!(
+                "Table '{name}' not found. Available tables: {}",
+                list.join(", ")
+            )));
+        }
+        table_names.retain(|t| *t == name);
+    }
+
+    let mut columns = Vec::new();
+    for table_name in table_names {
+        let mut table_columns: Vec<_> = model.tables[table_name].columns.values().collect();
+        table_columns.sort_by(|a, b| a.name.cmp(&b.name));
+        for column in table_columns {
+            if let ColumnValue::Number(values) = &column.values {
+                columns.push(column_stats(table_name, &column.name, values));
+            }
+        }
+    }
+    Ok(StatsResult { columns })
+}
+
+/// Statistics for one column of numbers
+fn column_stats(table: &str, column: &str, values: &[f64]) -> ColumnStats {
+    let present: Vec<Value> = values
+        .iter()
+        .filter(|v| !v.is_nan())
+        .map(|v| Value::Number(*v))
+        .collect();
+    let count = present.len();
+
+    let mut ctx = EvalContext::new();
+    ctx.tables
+        .insert("t".to_string(), HashMap::from([("v".to_string(), present)]));
+    let stat = |function: &str| -> Option<f64> {
+        let tokens = tokenize(&format!("{function}(t.v)")).ok()?;
+        evaluate(&parse(tokens).ok()?, &ctx).ok()?.as_number()
+    };
+
+    ColumnStats {
+        table: table.to_string(),
+        column: column.to_string(),
+        count,
+        nulls: values.len() - count,
+        mean: stat("AVERAGE"),
+        median: stat("MEDIAN"),
+        std: stat("STDEV"),
+        min: stat("MIN"),
+        max: stat("MAX"),
+    }
+}
+
+/// Execute the stats command - print a profile of each numeric column.
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be parsed or calculated, or if `table`
+/// does not exist.
+pub fn stats(
+    file: &Path,
+    table: Option<&str>,
+    json_output: bool,
+    limits: ParseLimits,
+) -> ForgeResult<()> {
+    if json_output {
+        return envelope::print_outcome("stats", stats_core(file, table, limits));
+    }
+    let result = stats_core(file, table, limits)?;
+
+    outln!("{}", "🔥 Forge - Column Statistics".bold().green());
+    outln!("   File: {}\n", file.display());
+    if result.columns.is_empty() {
+        outln!("   No numeric columns found.");
+        return Ok(());
+    }
+
+    let cell = |value: Option<f64>| value.map_or_else(|| "-".to_string(), format_number);
+    outln!(
+        "   {:<28} {:>6} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12}",
+        "Column".bold(),
+        "Count".bold(),
+        "Nulls".bold(),
+        "Mean".bold(),
+        "Median".bold(),
+        "Std".bold(),
+        "Min".bold(),
+        "Max".bold()
+    );
+    outln!("   {}", "─".repeat(108));
+    for col in &result.columns {
+        let nulls = if col.nulls > 0 {
+            col.nulls.to_string().yellow()
+        } else {
+            col.nulls.to_string().normal()
+        };
+        outln!(
+            "   {:<28} {:>6} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12}",
+            format!("{}.{}", col.table, col.column).bright_blue(),
+            col.count,
+            nulls,
+            cell(col.mean),
+            cell(col.median),
+            cell(col.std),
+            cell(col.min),
+            cell(col.max)
+        );
+    }
+    outln!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const MODEL: &str = r#"_forge_version: "5.0.0"
+sales:
+  month: ["Jan", "Feb", "Mar", "Apr"]
+  revenue: [100, 200, 300, 600]
+  cost: [50, 50, 50, 50]
+  profit: "=revenue - cost"
+"#;
+
+    fn write_model(dir: &TempDir) -> std::path::PathBuf {
+        let path = dir.path().join("model.yaml");
+        fs::write(&path, MODEL).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_stats_reports_count_and_mean() {
+        let dir = TempDir::new().unwrap();
+        let result = stats_core(&write_model(&dir), None, ParseLimits::default()).unwrap();
+
+        // Text columns are skipped; the formula column is calculated first
+        let names: Vec<&str> = result.columns.iter().map(|c| c.column.as_str()).collect();
+        assert_eq!(names, ["cost", "profit", "revenue"]);
+
+        let revenue = &result.columns[2];
+        assert_eq!(revenue.count, 4);
+        assert_eq!(revenue.nulls, 0);
+        assert_eq!(revenue.mean, Some(300.0));
+        assert_eq!(revenue.median, Some(250.0));
+        assert_eq!(revenue.min, Some(100.0));
+        assert_eq!(revenue.max, Some(600.0));
+        assert_eq!(result.columns[1].mean, Some(250.0));
+        assert_eq!(result.columns[0].std, Some(0.0));
+    }
+
+    #[test]
+    fn test_stats_unknown_table_lists_available() {
+        let dir = TempDir::new().unwrap();
+        let err = stats_core(&write_model(&dir), Some("costs"), ParseLimits::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Table 'costs' not found. Available tables: sales"));
+    }
+
+    #[test]
+    fn test_stats_honors_parse_limits() {
+        let dir = TempDir::new().unwrap();
+        let err = stats_core(&write_model(&dir), None, ParseLimits::new(3, 100))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("exceeding the limit of 3"), "{err}");
+    }
+
+    #[test]
+    fn test_column_stats_counts_missing_values() {
+        let stats = column_stats("t", "v", &[1.0, f64::NAN, 3.0]);
+        assert_eq!((stats.count, stats.nulls), (2, 1));
+        assert_eq!(stats.mean, Some(2.0));
+
+        let single = column_stats("t", "v", &[5.0]);
+        assert_eq!(single.std, None);
+    }
+}
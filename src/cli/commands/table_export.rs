@@ -0,0 +1,424 @@
+//! Single-table exports of calculated results (Markdown, JSON records, Parquet)
+//!
+//! Parquet output requires the `parquet` cargo feature.
+
+use super::{format_date, format_number};
+use crate::core::ArrayCalculator;
+use crate::error::{ForgeError, ForgeResult};
+use crate::parser::ParseLimits;
+use crate::types::{Column, ColumnValue, ParsedModel, Table};
+use colored::Colorize;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// Parse and calculate a model, then pick the table to export
+///
+/// `table` may be omitted when the model has exactly one table.
+pub(super) fn calculated_table(
+    input: &Path,
+    table: Option<&str>,
+    limits: ParseLimits,
+) -> ForgeResult<Table> {
+    let model = super::load_model(input, limits)?;
+    let mut result = ArrayCalculator::new(model).calculate_all()?;
+    let name = select_table(&result, table)?;
+    Ok(result
+        .tables
+        .remove(&name)
+        .expect("select_table returns an existing table"))
+}
+
+fn select_table(model: &ParsedModel, table: Option<&str>) -> ForgeResult<String> {
+    let mut available: Vec<&String> = model.tables.keys().collect();
+    available.sort();
+    match table {
+        Some(name) if model.tables.contains_key(name) => Ok(name.to_string()),
+        None if available.len() == 1 => Ok(available[0].clone()),
+        _ => {
+            let list = available
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            Err(ForgeError::Export(table.map_or_else(
+                || format!("Choose a table with --table. Available tables: {list}"),
+                |name| format!("Table '{name}' not found. Available tables: {list}"),
+            )))
+        },
+    }
+}
+
+/// Columns in alphabetical order (tables do not keep declaration order)
+fn sorted_columns(table: &Table) -> Vec<&Column> {
+    let mut columns: Vec<&Column> = table.columns.values().collect();
+    columns.sort_by(|a, b| a.name.cmp(&b.name));
+    columns
+}
+
+/// Render one cell as Markdown text (empty past the end of a short column)
+fn markdown_cell(values: &ColumnValue, row: usize) -> String {
+    let text = match values {
+        ColumnValue::Number(v) => v.get(row).map(|n| format_number(*n)),
+        ColumnValue::Text(v) => v.get(row).cloned(),
+        ColumnValue::Date(v) => v.get(row).map(|d| format_date(d)),
+        ColumnValue::Boolean(v) => v
+            .get(row)
+            .map(|b| if *b { "TRUE" } else { "FALSE" }.to_string()),
+    };
+    text.unwrap_or_default().replace('|', "\\|")
+}
+
+/// Render a table as a GitHub-flavored Markdown table
+///
+/// Numeric columns are right-aligned; values use [`format_number`] and
+/// dates [`format_date`], so both follow `--locale`.
+#[must_use]
+pub fn render_markdown(table: &Table) -> String {
+    let columns = sorted_columns(table);
+    let rows = columns.iter().map(|c| c.len()).max().unwrap_or(0);
+    let mut out = String::new();
+
+    let header: Vec<String> = columns.iter().map(|c| c.name.replace('|', "\\|")).collect();
+    let _ = writeln!(out, "| {} |", header.join(" | "));
+    let separator: Vec<&str> = columns
+        .iter()
+        .map(|c| match c.values {
+            ColumnValue::Number(_) => "---:",
+            _ => "---",
+        })
+        .collect();
+    let _ = writeln!(out, "| {} |", separator.join(" | "));
+
+    for row in 0..rows {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|c| markdown_cell(&c.values, row))
+            .collect();
+        let _ = writeln!(out, "| {} |", cells.join(" | "));
+    }
+    out
+}
+
+/// Render a table as JSON records: one object per row, keyed by column name
+///
+/// Values keep their type: numbers as JSON numbers (non-finite as `null`),
+/// text and dates as strings, booleans as booleans. Short columns yield `null`.
+#[must_use]
+pub fn render_json_records(table: &Table) -> serde_json::Value {
+    let columns = sorted_columns(table);
+    let rows = columns.iter().map(|c| c.len()).max().unwrap_or(0);
+    let records = (0..rows)
+        .map(|row| {
+            let record: serde_json::Map<String, serde_json::Value> = columns
+                .iter()
+                .map(|c| (c.name.clone(), json_cell(&c.values, row)))
+                .collect();
+            serde_json::Value::Object(record)
+        })
+        .collect();
+    serde_json::Value::Array(records)
+}
+
+fn json_cell(values: &ColumnValue, row: usize) -> serde_json::Value {
+    use serde_json::Value;
+    match values {
+        ColumnValue::Number(v) => v
+            .get(row)
+            .and_then(|n| serde_json::Number::from_f64(*n))
+            .map_or(Value::Null, Value::Number),
+        ColumnValue::Text(v) | ColumnValue::Date(v) => {
+            v.get(row).map_or(Value::Null, |s| Value::String(s.clone()))
+        },
+        ColumnValue::Boolean(v) => v.get(row).map_or(Value::Null, |b| Value::Bool(*b)),
+    }
+}
+
+/// Export a calculated table as a Markdown file.
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be parsed or calculated, the table
+/// does not exist, or the output file cannot be written.
+pub fn export_markdown(
+    input: &Path,
+    table: Option<&str>,
+    output: &Path,
+    limits: ParseLimits,
+) -> ForgeResult<()> {
+    let table = calculated_table(input, table, limits)?;
+    fs::write(output, render_markdown(&table))?;
+
+    outln!("{}", "✅ Markdown Export Complete!".bold().green());
+    outln!(
+        "   Table: {} ({} columns, {} rows)",
+        table.name.bright_blue(),
+        table.columns.len(),
+        table.row_count()
+    );
+    outln!("   File:  {}\n", output.display());
+    Ok(())
+}
+
+/// Export a calculated table as a JSON array of row objects.
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be parsed or calculated, the table
+/// does not exist, or the output file cannot be written.
+pub fn export_json_records(
+    input: &Path,
+    table: Option<&str>,
+    output: &Path,
+    limits: ParseLimits,
+) -> ForgeResult<()> {
+    let table = calculated_table(input, table, limits)?;
+    let json = serde_json::to_string_pretty(&render_json_records(&table))
+        .map_err(|e| ForgeError::Export(format!("JSON serialization failed: {e}")))?;
+    fs::write(output, json + "\n")?;
+
+    outln!("{}", "✅ JSON Records Export Complete!".bold().green());
+    outln!(
+        "   Table: {} ({} records)",
+        table.name.bright_blue(),
+        table.row_count()
+    );
+    outln!("   File:  {}\n", output.display());
+    Ok(())
+}
+
+/// Export a calculated table as a Parquet file.
+///
+/// Column types map Number→float64, Text→utf8, Boolean→bool and Date→date32
+/// (`YYYY-MM` dates are the first of the month).
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be parsed or calculated, the table
+/// does not exist or has uneven columns, or the file cannot be written.
+#[cfg(feature = "parquet")]
+pub fn export_parquet(
+    input: &Path,
+    table: Option<&str>,
+    output: &Path,
+    limits: ParseLimits,
+) -> ForgeResult<()> {
+    use parquet::arrow::ArrowWriter;
+
+    let table = calculated_table(input, table, limits)?;
+    let batch = record_batch(&table)?;
+    let file = fs::File::create(output)?;
+    let parquet_err =
+        |e: parquet::errors::ParquetError| ForgeError::Export(format!("Parquet: {e}"));
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None).map_err(parquet_err)?;
+    writer.write(&batch).map_err(parquet_err)?;
+    writer.close().map_err(parquet_err)?;
+
+    outln!("{}", "✅ Parquet Export Complete!".bold().green());
+    outln!(
+        "   Table: {} ({} columns, {} rows)",
+        table.name.bright_blue(),
+        batch.num_columns(),
+        batch.num_rows()
+    );
+    outln!("   File:  {}\n", output.display());
+    Ok(())
+}
+
+/// Parquet export is unavailable without the `parquet` feature.
+///
+/// # Errors
+///
+/// Always returns an error explaining how to enable the feature.
+#[cfg(not(feature = "parquet"))]
+pub fn export_parquet(
+    _input: &Path,
+    _table: Option<&str>,
+    _output: &Path,
+    _limits: ParseLimits,
+) -> ForgeResult<()> {
+    Err(ForgeError::Export(
+        "Parquet export is not available in this build; rebuild with `--features parquet`"
+            .to_string(),
+    ))
+}
+
+/// Build an Arrow record batch from a table's columns
+#[cfg(feature = "parquet")]
+fn record_batch(table: &Table) -> ForgeResult<arrow_array::RecordBatch> {
+    use arrow_array::{
+        ArrayRef, BooleanArray, Date32Array, Float64Array, RecordBatch, StringArray,
+    };
+    use arrow_schema::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    let columns = sorted_columns(table);
+    let mut fields = Vec::with_capacity(columns.len());
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(columns.len());
+    for column in columns {
+        let (data_type, array): (DataType, ArrayRef) = match &column.values {
+            ColumnValue::Number(v) => (DataType::Float64, Arc::new(Float64Array::from(v.clone()))),
+            ColumnValue::Text(v) => (DataType::Utf8, Arc::new(StringArray::from(v.clone()))),
+            ColumnValue::Boolean(v) => (DataType::Boolean, Arc::new(BooleanArray::from(v.clone()))),
+            ColumnValue::Date(v) => {
+                let days = v
+                    .iter()
+                    .map(|d| date32(d))
+                    .collect::<ForgeResult<Vec<i32>>>()?;
+                (DataType::Date32, Arc::new(Date32Array::from(days)))
+            },
+        };
+        fields.push(Field::new(column.name.clone(), data_type, false));
+        arrays.push(array);
+    }
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+        .map_err(|e| ForgeError::Export(format!("Table '{}': {e}", table.name)))
+}
+
+/// Days since the Unix epoch for a `YYYY-MM-DD` or `YYYY-MM` date
+#[cfg(feature = "parquet")]
+fn date32(date: &str) -> ForgeResult<i32> {
+    use chrono::NaiveDate;
+
+    let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(&format!("{date}-01"), "%Y-%m-%d"))
+        .map_err(|_| ForgeError::Export(format!("Invalid date '{date}'")))?;
+    let days = parsed
+        .signed_duration_since(NaiveDate::default())
+        .num_days();
+    i32::try_from(days).map_err(|_| ForgeError::Export(format!("Date '{date}' out of range")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sales_table() -> Table {
+        let mut table = Table::new("sales".to_string());
+        table.add_column(Column::new(
+            "month".to_string(),
+            ColumnValue::Date(vec!["2025-01".to_string(), "2025-02".to_string()]),
+        ));
+        table.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![1500.0, 1234.5]),
+        ));
+        table.add_column(Column::new(
+            "region".to_string(),
+            ColumnValue::Text(vec!["North|East".to_string(), "South".to_string()]),
+        ));
+        table
+    }
+
+    #[test]
+    fn test_render_markdown_table_shape() {
+        let md = render_markdown(&sales_table());
+        let lines: Vec<&str> = md.lines().collect();
+        assert_eq!(lines.len(), 4, "header + separator + 2 rows:\n{md}");
+
+        // Every line has the same number of cells (3 columns -> 4 unescaped pipes)
+        for line in &lines {
+            assert!(line.starts_with("| ") && line.ends_with(" |"), "{line}");
+            let pipes = line.replace("\\|", "").matches('|').count();
+            assert_eq!(pipes, 4, "{line}");
+        }
+
+        assert_eq!(lines[0], "| month | region | revenue |");
+        assert_eq!(lines[1], "| --- | --- | ---: |");
+        assert_eq!(lines[2], "| 2025-01 | North\\|East | 1500 |");
+        assert_eq!(lines[3], "| 2025-02 | South | 1234.5 |");
+    }
+
+    #[test]
+    fn test_select_table() {
+        let mut model = ParsedModel::new();
+        model.add_table(sales_table());
+        assert_eq!(select_table(&model, None).unwrap(), "sales");
+        assert_eq!(select_table(&model, Some("sales")).unwrap(), "sales");
+        let err = select_table(&model, Some("costs")).unwrap_err();
+        assert!(err.to_string().contains("Available tables: sales"));
+
+        model.add_table(Table::new("costs".to_string()));
+        assert!(select_table(&model, None).is_err());
+    }
+
+    #[test]
+    fn test_render_json_records() {
+        let mut table = sales_table();
+        table.add_column(Column::new(
+            "closed".to_string(),
+            ColumnValue::Boolean(vec![true, false]),
+        ));
+        let records = render_json_records(&table);
+        let records = records.as_array().expect("array of records");
+        assert_eq!(records.len(), table.row_count());
+
+        let first = records[0].as_object().expect("record object");
+        assert_eq!(first.len(), 4);
+        assert_eq!(first["revenue"].as_f64(), Some(1500.0));
+        assert_eq!(first["month"].as_str(), Some("2025-01"));
+        assert_eq!(first["region"].as_str(), Some("North|East"));
+        assert_eq!(first["closed"].as_bool(), Some(true));
+        assert_eq!(records[1]["revenue"].as_f64(), Some(1234.5));
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet_round_trip() {
+        use arrow_schema::DataType;
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+        use std::io::Write;
+
+        let yaml = r#"
+_forge_version: "5.0.0"
+sales:
+  month: ["2025-01", "2025-02-15"]
+  revenue: [100, 200]
+  region: ["North", "South"]
+  closed: [true, false]
+"#;
+        let mut model_file = tempfile::NamedTempFile::with_suffix(".yaml").unwrap();
+        model_file.write_all(yaml.as_bytes()).unwrap();
+        let out = tempfile::NamedTempFile::with_suffix(".parquet").unwrap();
+
+        export_parquet(
+            model_file.path(),
+            Some("sales"),
+            out.path(),
+            ParseLimits::default(),
+        )
+        .unwrap();
+
+        let reader =
+            ParquetRecordBatchReaderBuilder::try_new(fs::File::open(out.path()).unwrap()).unwrap();
+        let schema = reader.schema().clone();
+        let types: Vec<(&str, &DataType)> = schema
+            .fields()
+            .iter()
+            .map(|f| (f.name().as_str(), f.data_type()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("closed", &DataType::Boolean),
+                ("month", &DataType::Date32),
+                ("region", &DataType::Utf8),
+                ("revenue", &DataType::Float64),
+            ]
+        );
+        let rows: usize = reader
+            .build()
+            .unwrap()
+            .map(|batch| batch.unwrap().num_rows())
+            .sum();
+        assert_eq!(rows, 2);
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_date32() {
+        assert_eq!(date32("1970-01-02").unwrap(), 1);
+        assert_eq!(date32("2025-01").unwrap(), date32("2025-01-01").unwrap());
+        assert!(date32("not-a-date").is_err());
+    }
+}
@@ -0,0 +1,304 @@
+//! Output theme - emoji and box-drawing, or plain ASCII
+//!
+//! Every line the CLI commands print goes through `out!`/`outln!` (and the
+//! stderr variants), which pass it through [`themed`]. The default theme
+//! prints text unchanged; the plain theme swaps each symbol in [`SYMBOLS`]
+//! for its ASCII equivalent, so adding a symbol there is the only switch.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// How symbols are rendered in CLI output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    /// Emoji status markers and box-drawing rules
+    #[default]
+    Emoji,
+    /// ASCII only (`[OK]`, `[FAIL]`, `---`) for limited terminals and logs
+    Plain,
+}
+
+/// Symbol -> plain replacement
+///
+/// An empty replacement drops a purely decorative symbol along with one
+/// following space. Longer sequences come before their prefixes.
+pub const SYMBOLS: &[(&str, &str)] = &[
+    // Status
+    ("✅", "[OK]"),
+    ("✓", "[OK]"),
+    ("❌", "[FAIL]"),
+    ("✗", "[FAIL]"),
+    ("⚠️", "[WARN]"),
+    ("⚠", "[WARN]"),
+    ("💡", "[TIP]"),
+    ("🆕", "[NEW]"),
+    ("⏭️", "[SKIP]"),
+    ("↻", "[FIXED]"),
+    // Rules, trees and bars
+    ("└─", "`-"),
+    ("─", "-"),
+    ("═", "="),
+    ("█", "#"),
+    ("▇", "*"),
+    ("▆", "+"),
+    ("▅", "="),
+    ("▄", "-"),
+    ("▃", ":"),
+    ("▂", "."),
+    ("▁", "_"),
+    // Punctuation and math
+    ("→", "->"),
+    ("↑", "^"),
+    ("↓", "v"),
+    ("—", "-"),
+    ("…", "..."),
+    ("≈", "~"),
+    ("±", "+/-"),
+    ("×", "x"),
+    ("σ", "sd"),
+    ("Σ", "sum"),
+    // Decorative
+    ("🔥", ""),
+    ("📊", ""),
+    ("📖", ""),
+    ("📋", ""),
+    ("🧮", ""),
+    ("🔄", ""),
+    ("💾", ""),
+    ("🔍", ""),
+    ("📐", ""),
+    ("🎲", ""),
+    ("🎯", ""),
+    ("🌳", ""),
+    ("🔮", ""),
+    ("🔧", ""),
+    ("🔗", ""),
+    ("📦", ""),
+    ("📥", ""),
+    ("📤", ""),
+    ("📡", ""),
+    ("📈", ""),
+    ("📂", ""),
+    ("💎", ""),
+    ("👁️", ""),
+    ("🌪️", ""),
+    ("⬇️", ""),
+    // Stray emoji presentation selector
+    ("\u{fe0f}", ""),
+];
+
+impl Theme {
+    /// Render `text` in this theme
+    #[must_use]
+    pub fn apply(self, text: &str) -> Cow<'_, str> {
+        if self == Self::Emoji || text.is_ascii() {
+            return Cow::Borrowed(text);
+        }
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        'scan: while let Some(c) = rest.chars().next() {
+            for (symbol, plain) in SYMBOLS {
+                if let Some(after) = rest.strip_prefix(symbol) {
+                    out.push_str(plain);
+                    rest = if plain.is_empty() {
+                        after.strip_prefix(' ').unwrap_or(after)
+                    } else {
+                        after
+                    };
+                    continue 'scan;
+                }
+            }
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+        Cow::Owned(out)
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "emoji" | "default" => Ok(Self::Emoji),
+            "plain" | "ascii" => Ok(Self::Plain),
+            _ => Err(format!("Unknown theme '{s}'. Supported: emoji, plain")),
+        }
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Emoji => "emoji",
+            Self::Plain => "plain",
+        })
+    }
+}
+
+static THEME: RwLock<Theme> = RwLock::new(Theme::Emoji);
+
+/// Set the process-wide output theme
+pub fn set_theme(theme: Theme) {
+    if let Ok(mut current) = THEME.write() {
+        *current = theme;
+    }
+}
+
+/// Process-wide output theme
+#[must_use]
+pub fn theme() -> Theme {
+    THEME.read().map(|t| *t).unwrap_or_default()
+}
+
+/// Render `text` in the process-wide theme
+#[must_use]
+pub fn themed(text: &str) -> Cow<'_, str> {
+    theme().apply(text)
+}
+
+#[cfg(test)]
+thread_local! {
+    static CAPTURE: std::cell::RefCell<Option<String>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Write themed text to stdout (or stderr), backing the output macros
+pub fn emit(text: &str, stderr: bool) {
+    let text = themed(text);
+    #[cfg(test)]
+    {
+        let captured = CAPTURE.with(|c| {
+            c.borrow_mut()
+                .as_mut()
+                .map(|buf| buf.push_str(&text))
+                .is_some()
+        });
+        if captured {
+            return;
+        }
+    }
+    if stderr {
+        eprint!("{text}");
+    } else {
+        print!("{text}");
+    }
+}
+
+/// Run `f` and return everything it printed through the output macros
+#[cfg(test)]
+pub fn capture(f: impl FnOnce()) -> String {
+    CAPTURE.with(|c| *c.borrow_mut() = Some(String::new()));
+    f();
+    CAPTURE.with(|c| c.borrow_mut().take()).unwrap_or_default()
+}
+
+/// `print!` through the output theme
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::cli::commands::theme::emit(&format!($($arg)*), false)
+    };
+}
+
+/// `println!` through the output theme
+macro_rules! outln {
+    () => {
+        $crate::cli::commands::theme::emit("\n", false)
+    };
+    ($($arg:tt)*) => {
+        $crate::cli::commands::theme::emit(&format!("{}\n", format_args!($($arg)*)), false)
+    };
+}
+
+/// `eprintln!` through the output theme
+macro_rules! eoutln {
+    () => {
+        $crate::cli::commands::theme::emit("\n", true)
+    };
+    ($($arg:tt)*) => {
+        $crate::cli::commands::theme::emit(&format!("{}\n", format_args!($($arg)*)), true)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ParseLimits;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_plain_theme_symbols() {
+        assert_eq!(
+            Theme::Plain.apply("🔥 Forge - Audit Trail"),
+            "Forge - Audit Trail"
+        );
+        assert_eq!(
+            Theme::Plain.apply("   ✅ a.yaml - OK"),
+            "   [OK] a.yaml - OK"
+        );
+        assert_eq!(
+            Theme::Plain.apply("⚠️  Unit warning"),
+            "[WARN]  Unit warning"
+        );
+        assert_eq!(Theme::Plain.apply("───"), "---");
+        assert_eq!(Theme::Plain.apply("a → b"), "a -> b");
+        assert_eq!(Theme::Emoji.apply("✅ done"), "✅ done");
+        for (symbol, _) in SYMBOLS {
+            assert!(Theme::Plain.apply(symbol).is_ascii(), "{symbol} not mapped");
+        }
+    }
+
+    #[test]
+    fn test_plain_theme_output_is_ascii() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("model.yaml");
+        fs::write(
+            &path,
+            r#"_forge_version: "5.0.0"
+sales:
+  revenue: [100, 200, 300]
+  cost: [60, 120, 150]
+  profit: "=revenue - cost"
+summary:
+  total:
+    value: null
+    formula: "=SUM(sales.profit)"
+"#,
+        )
+        .unwrap();
+
+        set_theme(Theme::Plain);
+        let output = capture(|| {
+            super::super::calculate(
+                &path,
+                &super::super::CalculateOptions {
+                    dry_run: true,
+                    verbose: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            super::super::validate(
+                std::slice::from_ref(&path),
+                false,
+                false,
+                ParseLimits::default(),
+            )
+            .unwrap();
+            super::super::audit(&path, "summary.total", ParseLimits::default()).unwrap();
+            super::super::stats(&path, None, false, ParseLimits::default()).unwrap();
+            super::super::functions(false).unwrap();
+            super::super::features(false).unwrap();
+        });
+        set_theme(Theme::Emoji);
+
+        assert!(
+            output.contains("[OK]"),
+            "expected status markers:\n{output}"
+        );
+        let stray: Vec<char> = output.chars().filter(|c| !c.is_ascii()).collect();
+        assert!(stray.is_empty(), "non-ASCII in plain output: {stray:?}");
+    }
+}
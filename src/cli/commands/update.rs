@@ -190,8 +190,8 @@ fn download_and_install(
     let download_path = temp_dir.join(&asset.name);
 
     if verbose {
-        println!("   Downloading: {}", asset.download_url);
-        println!("   Size: {} bytes", asset.size);
+        outln!("   Downloading: {}", asset.download_url);
+        outln!("   Size: {} bytes", asset.size);
     }
 
     // Download the asset
@@ -259,7 +259,7 @@ fn download_and_install(
     if install_path.exists() {
         let backup_path = install_path.with_extension("bak");
         if verbose {
-            println!(
+            outln!(
                 "   Backup: {} -> {}",
                 install_path.display(),
                 backup_path.display()
@@ -313,18 +313,18 @@ fn get_install_path() -> ForgeResult<PathBuf> {
 /// Tested via: manual integration testing
 #[cfg(not(coverage))]
 pub fn update(check_only: bool, verbose: bool) -> ForgeResult<()> {
-    println!("{}", "🔄 Forge - Update Check".bold().green());
-    println!("   Current version: {}", CURRENT_VERSION.cyan());
-    println!();
+    outln!("{}", "🔄 Forge - Update Check".bold().green());
+    outln!("   Current version: {}", CURRENT_VERSION.cyan());
+    outln!();
 
     // Fetch latest release
     if verbose {
-        println!("{}", "📡 Fetching latest release from GitHub...".cyan());
+        outln!("{}", "📡 Fetching latest release from GitHub...".cyan());
     }
 
     let release = fetch_latest_release()?;
 
-    println!(
+    outln!(
         "   Latest version:  {} ({})",
         release.version.bright_yellow().bold(),
         release.published_at.dimmed()
@@ -332,13 +332,13 @@ pub fn update(check_only: bool, verbose: bool) -> ForgeResult<()> {
 
     // Check if update needed
     if !is_newer_version(&release.version, CURRENT_VERSION) {
-        println!();
-        println!("{}", "✅ You're running the latest version!".bold().green());
+        outln!();
+        outln!("{}", "✅ You're running the latest version!".bold().green());
         return Ok(());
     }
 
-    println!();
-    println!(
+    outln!();
+    outln!(
         "{}",
         format!(
             "🆕 Update available: {} → {}",
@@ -349,15 +349,15 @@ pub fn update(check_only: bool, verbose: bool) -> ForgeResult<()> {
     );
 
     if check_only {
-        println!();
-        println!("   Run {} to install the update", "forge update".cyan());
+        outln!();
+        outln!("   Run {} to install the update", "forge update".cyan());
         return Ok(());
     }
 
     // Detect platform and find asset
     let platform = detect_platform()?;
     if verbose {
-        println!("   Platform: {platform}");
+        outln!("   Platform: {platform}");
     }
 
     let install_path = get_install_path()?;
@@ -378,35 +378,35 @@ pub fn update(check_only: bool, verbose: bool) -> ForgeResult<()> {
         ))
     })?;
 
-    println!();
-    println!("   📦 Asset: {}", asset.name.cyan());
-    println!("   📂 Install to: {}", install_path.display());
+    outln!();
+    outln!("   📦 Asset: {}", asset.name.cyan());
+    outln!("   📂 Install to: {}", install_path.display());
 
     // Confirm with user
-    print!("\n   Proceed with update? [y/N] ");
+    out!("\n   Proceed with update? [y/N] ");
     io::stdout().flush().unwrap();
 
     let mut input = String::new();
     io::stdin().read_line(&mut input).unwrap();
 
     if !input.trim().eq_ignore_ascii_case("y") {
-        println!("{}", "   Update cancelled.".yellow());
+        outln!("{}", "   Update cancelled.".yellow());
         return Ok(());
     }
 
-    println!();
-    println!("{}", "⬇️  Downloading...".cyan());
+    outln!();
+    outln!("{}", "⬇️  Downloading...".cyan());
 
     download_and_install(asset, &install_path, verbose)?;
 
-    println!();
-    println!(
+    outln!();
+    outln!(
         "{}",
         format!("✅ Successfully updated to v{}!", release.version)
             .bold()
             .green()
     );
-    println!("{}", "   Restart forge to use the new version.".dimmed());
+    outln!("{}", "   Restart forge to use the new version.".dimmed());
 
     Ok(())
 }
@@ -1,6 +1,7 @@
 //! Upgrade command - migrate YAML files to latest schema version
 
 use crate::error::{ForgeError, ForgeResult};
+use crate::parser::is_legacy_version;
 use colored::Colorize;
 use std::collections::HashSet;
 use std::fs;
@@ -57,14 +58,14 @@ pub fn auto_upgrade_schema(file: &Path, verbose: bool) -> ForgeResult<()> {
 /// Returns an error if the file cannot be read, parsed, or written,
 /// or if any included files fail during recursive upgrade.
 pub fn upgrade(file: &Path, dry_run: bool, target_version: &str, verbose: bool) -> ForgeResult<()> {
-    println!("{}", "🔥 Forge - Schema Upgrade".bold().green());
-    println!();
-    println!("   File:    {}", file.display());
-    println!("   Target:  v{target_version}");
+    outln!("{}", "🔥 Forge - Schema Upgrade".bold().green());
+    outln!();
+    outln!("   File:    {}", file.display());
+    outln!("   Target:  v{target_version}");
     if dry_run {
-        println!("   Mode:    {} (no files modified)", "DRY RUN".yellow());
+        outln!("   Mode:    {} (no files modified)", "DRY RUN".yellow());
     }
-    println!();
+    outln!();
 
     // Track upgraded files to avoid circular processing
     let mut upgraded_files: HashSet<PathBuf> = HashSet::new();
@@ -74,26 +75,26 @@ pub fn upgrade(file: &Path, dry_run: bool, target_version: &str, verbose: bool)
         upgrade_file_recursive(file, target_version, dry_run, verbose, &mut upgraded_files)?;
 
     // Summary
-    println!();
-    println!("{}", "═".repeat(70));
-    println!();
+    outln!();
+    outln!("{}", "═".repeat(70));
+    outln!();
     if dry_run {
-        println!(
+        outln!(
             "{} {} file(s) would be upgraded",
             "DRY RUN:".yellow().bold(),
             changes
         );
-        println!();
-        println!("   Run without --dry-run to apply changes.");
+        outln!();
+        outln!("   Run without --dry-run to apply changes.");
     } else {
-        println!(
+        outln!(
             "{} {} file(s) upgraded to v{}",
             "✅".green(),
             changes,
             target_version
         );
     }
-    println!();
+    outln!();
 
     Ok(())
 }
@@ -116,7 +117,7 @@ pub fn upgrade_file_recursive(
     // Skip if already processed (circular include protection)
     if upgraded_files.contains(&canonical) {
         if verbose {
-            println!(
+            outln!(
                 "   {} {} (already processed)",
                 "⏭️".dimmed(),
                 file.display()
@@ -164,7 +165,7 @@ pub fn upgrade_file_recursive(
     // Check if upgrade needed
     if current_version == target_version {
         if verbose {
-            println!(
+            outln!(
                 "   {} {} (already v{})",
                 "✓".green(),
                 file.display(),
@@ -174,7 +175,7 @@ pub fn upgrade_file_recursive(
         return Ok(changes);
     }
 
-    println!(
+    outln!(
         "   {} {} (v{} → v{})",
         if dry_run {
             "→".yellow()
@@ -186,6 +187,8 @@ pub fn upgrade_file_recursive(
         target_version
     );
 
+    let legacy = is_legacy_version(current_version);
+
     // Perform transformations
     let yaml_map = yaml
         .as_mapping_mut()
@@ -197,7 +200,12 @@ pub fn upgrade_file_recursive(
         serde_yaml_ng::Value::String(target_version.to_string()),
     );
 
-    // 2. Split scalars into inputs/outputs if upgrading to 5.0.0
+    // 2. Bring pre-1.0 scalar models up to the current layout
+    if legacy {
+        migrate_legacy_model(yaml_map, verbose);
+    }
+
+    // 3. Split scalars into inputs/outputs if upgrading to 5.0.0
     if target_version == "5.0.0" {
         split_scalars_to_inputs_outputs(yaml_map, verbose);
     }
@@ -208,7 +216,7 @@ pub fn upgrade_file_recursive(
         fs::copy(file, &backup_path)
             .map_err(|e| ForgeError::IO(format!("Failed to create backup: {e}")))?;
         if verbose {
-            println!("      {} Backup: {}", "📋".dimmed(), backup_path.display());
+            outln!("      {} Backup: {}", "📋".dimmed(), backup_path.display());
         }
 
         // Write upgraded content
@@ -226,6 +234,62 @@ pub fn upgrade_file_recursive(
     Ok(changes + 1)
 }
 
+/// Migrate a pre-1.0 (v0.2.0) scalar model to the current layout.
+///
+/// Renames `includes` to `_includes`, prefixes bare formulas with `=` and gives
+/// formula-only variables a null `value`, as the schema requires.
+pub fn migrate_legacy_model(yaml_map: &mut serde_yaml_ng::Mapping, verbose: bool) {
+    if let Some(includes) = yaml_map.remove("includes") {
+        yaml_map.insert(
+            serde_yaml_ng::Value::String("_includes".to_string()),
+            includes,
+        );
+        if verbose {
+            outln!("      {} includes → _includes", "🔗".dimmed());
+        }
+    }
+
+    for (key, value) in yaml_map.iter_mut() {
+        if key.as_str().is_some_and(|k| k.starts_with('_')) {
+            continue;
+        }
+        let Some(mapping) = value.as_mapping_mut() else {
+            continue;
+        };
+        if is_legacy_variable(mapping) {
+            normalize_legacy_variable(mapping);
+        } else {
+            // Variable group: { name: { value, formula } }
+            for (_, member) in mapping.iter_mut() {
+                if let Some(variable) = member.as_mapping_mut() {
+                    if is_legacy_variable(variable) {
+                        normalize_legacy_variable(variable);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A v0.2.0 variable is a mapping with a `value` and/or a `formula`
+fn is_legacy_variable(mapping: &serde_yaml_ng::Mapping) -> bool {
+    mapping.contains_key("value") || mapping.contains_key("formula")
+}
+
+fn normalize_legacy_variable(variable: &mut serde_yaml_ng::Mapping) {
+    if let Some(serde_yaml_ng::Value::String(formula)) = variable.get_mut("formula") {
+        if !formula.starts_with('=') {
+            formula.insert(0, '=');
+        }
+    }
+    if !variable.contains_key("value") {
+        variable.insert(
+            serde_yaml_ng::Value::String("value".to_string()),
+            serde_yaml_ng::Value::Null,
+        );
+    }
+}
+
 /// Split scalars section into inputs and outputs based on formula presence.
 pub fn split_scalars_to_inputs_outputs(yaml_map: &mut serde_yaml_ng::Mapping, verbose: bool) {
     // Check if there's a top-level scalars-like structure (not in a table)
@@ -278,7 +342,7 @@ pub fn split_scalars_to_inputs_outputs(yaml_map: &mut serde_yaml_ng::Mapping, ve
                 if has_formula {
                     outputs.insert(key.clone(), value.clone());
                     if verbose {
-                        println!(
+                        outln!(
                             "      {} {} → outputs (has formula)",
                             "📤".dimmed(),
                             key_str
@@ -287,7 +351,7 @@ pub fn split_scalars_to_inputs_outputs(yaml_map: &mut serde_yaml_ng::Mapping, ve
                 } else {
                     inputs.insert(key.clone(), value.clone());
                     if verbose {
-                        println!("      {} {} → inputs (value only)", "📥".dimmed(), key_str);
+                        outln!("      {} {} → inputs (value only)", "📥".dimmed(), key_str);
                     }
                 }
                 keys_to_remove.push(key.clone());
@@ -436,4 +500,46 @@ x:
         let content = std::fs::read_to_string(&yaml_path).unwrap();
         assert!(content.contains("5.0.0"));
     }
+
+    #[test]
+    fn test_upgrade_migrates_legacy_v0_2_0_model() {
+        let dir = TempDir::new().unwrap();
+        let yaml_path = dir.path().join("legacy.yaml");
+        std::fs::write(
+            &yaml_path,
+            r#"_forge_version: "0.2.0"
+price:
+  value: 10
+units:
+  value: 5
+revenue:
+  q1:
+    value: 100
+  total:
+    formula: "revenue.q1 * 2"
+sales:
+  formula: "=price * units"
+"#,
+        )
+        .unwrap();
+
+        let err = crate::parser::parse_model(&yaml_path)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("0.2.0") && err.contains("forge upgrade"),
+            "{err}"
+        );
+
+        let mut upgraded = HashSet::new();
+        let changes = upgrade_file_recursive(&yaml_path, "5.0.0", false, false, &mut upgraded);
+        assert_eq!(changes.unwrap(), 1);
+
+        let model = crate::parser::parse_model(&yaml_path).unwrap();
+        assert_eq!(
+            model.scalars["revenue.total"].formula.as_deref(),
+            Some("=revenue.q1 * 2")
+        );
+        assert!(model.scalars.keys().any(|k| k.ends_with("sales")));
+    }
 }
@@ -0,0 +1,212 @@
+//! Shared `--json` output envelope
+//!
+//! Every command that accepts `--json` prints one object of the same shape:
+//!
+//! ```json
+//! { "command": "validate", "ok": false, "result": { ... },
+//!   "warnings": ["..."], "errors": ["..."] }
+//! ```
+//!
+//! `result` holds the command's structured result (`null` when it failed
+//! before producing one). `ok` is `false` exactly when `errors` is non-empty.
+
+use super::commands::theme;
+use crate::error::ForgeResult;
+use serde::{Deserialize, Serialize};
+
+/// Top-level object printed by `--json` output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonEnvelope {
+    /// Command name as typed on the command line (`calculate`, `validate`, ...)
+    pub command: String,
+    /// Whether the command succeeded
+    pub ok: bool,
+    /// Command-specific structured result
+    pub result: serde_json::Value,
+    /// Non-fatal issues (e.g. unit warnings)
+    pub warnings: Vec<String>,
+    /// Failures; non-empty exactly when `ok` is false
+    pub errors: Vec<String>,
+}
+
+impl JsonEnvelope {
+    /// A successful envelope around `result`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `result` cannot be represented as JSON, which cannot happen
+    /// for the result types in [`results`](super::commands::results).
+    #[must_use]
+    pub fn new(command: &str, result: impl Serialize) -> Self {
+        Self {
+            command: command.to_string(),
+            ok: true,
+            result: serde_json::to_value(result).expect("command results serialize to JSON"),
+            warnings: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// A failed envelope with no result
+    #[must_use]
+    pub fn failure(command: &str, error: &impl ToString) -> Self {
+        Self {
+            command: command.to_string(),
+            ok: false,
+            result: serde_json::Value::Null,
+            warnings: Vec::new(),
+            errors: vec![error.to_string()],
+        }
+    }
+
+    /// Attach warnings
+    #[must_use]
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    /// Attach errors; any error marks the envelope as failed
+    #[must_use]
+    pub fn with_errors(mut self, errors: Vec<String>) -> Self {
+        self.ok = errors.is_empty();
+        self.errors = errors;
+        self
+    }
+
+    /// Pretty-printed JSON
+    ///
+    /// # Panics
+    ///
+    /// Panics if serialization fails, which cannot happen for a `serde_json::Value` tree.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("envelope serializes to JSON")
+    }
+
+    /// Print the envelope to stdout
+    pub fn print(&self) {
+        theme::emit(&format!("{}\n", self.to_json()), false);
+    }
+}
+
+/// Print the outcome of a structured (`*_core`) command as an envelope
+///
+/// # Errors
+///
+/// Returns the command's error after printing it in the envelope, so the
+/// process still exits non-zero.
+pub fn print_outcome<T: Serialize>(command: &str, outcome: ForgeResult<T>) -> ForgeResult<()> {
+    match outcome {
+        Ok(result) => {
+            JsonEnvelope::new(command, result).print();
+            Ok(())
+        },
+        Err(e) => {
+            JsonEnvelope::failure(command, &e).print();
+            Err(e)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::theme::capture;
+    use crate::parser::ParseLimits;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn write(dir: &TempDir, name: &str, total: f64) -> std::path::PathBuf {
+        let path = dir.path().join(name);
+        fs::write(
+            &path,
+            format!(
+                r#"_forge_version: "5.0.0"
+sales:
+  revenue: [100, 200, 300]
+summary:
+  total:
+    value: {total}
+    formula: "=SUM(sales.revenue)"
+"#
+            ),
+        )
+        .unwrap();
+        path
+    }
+
+    fn envelope(output: &str) -> JsonEnvelope {
+        serde_json::from_str(output).unwrap_or_else(|e| panic!("not an envelope ({e}):\n{output}"))
+    }
+
+    #[test]
+    fn test_calculate_json_envelope() {
+        let dir = TempDir::new().unwrap();
+        let path = write(&dir, "model.yaml", 0.0);
+
+        let output = capture(|| {
+            crate::cli::calculate_json(&path, true, None, ParseLimits::default()).unwrap();
+        });
+        let env = envelope(&output);
+        assert_eq!(env.command, "calculate");
+        assert!(env.ok);
+        assert!(env.errors.is_empty());
+        assert_eq!(env.result["scalars"]["summary.total"], 600.0);
+        for key in ["tables", "scalars", "mismatches", "warnings"] {
+            assert!(
+                env.result.get(key).is_some(),
+                "missing {key}: {}",
+                env.result
+            );
+        }
+
+        let missing = Path::new("/nonexistent/model.yaml");
+        let output = capture(|| {
+            assert!(
+                crate::cli::calculate_json(missing, true, None, ParseLimits::default()).is_err()
+            );
+        });
+        let env = envelope(&output);
+        assert!(!env.ok);
+        assert!(env.result.is_null());
+        assert_eq!(env.errors.len(), 1);
+    }
+
+    #[test]
+    fn test_validate_json_envelope() {
+        let dir = TempDir::new().unwrap();
+        let fresh = write(&dir, "fresh.yaml", 600.0);
+        let stale = write(&dir, "stale.yaml", 1.0);
+
+        let output = capture(|| {
+            crate::cli::validate_json(std::slice::from_ref(&fresh), ParseLimits::default())
+                .unwrap();
+        });
+        let env = envelope(&output);
+        assert_eq!(env.command, "validate");
+        assert!(env.ok);
+        let key = fresh.display().to_string();
+        assert_eq!(
+            env.result["files"][&key]["mismatches"],
+            serde_json::json!([])
+        );
+        assert_eq!(env.result["files"][&key]["scalars"]["summary.total"], 600.0);
+
+        let output = capture(|| {
+            assert!(
+                crate::cli::validate_json(&[fresh, stale.clone()], ParseLimits::default()).is_err()
+            );
+        });
+        let env = envelope(&output);
+        assert!(!env.ok);
+        assert_eq!(env.errors.len(), 1);
+        assert!(env.errors[0].contains("summary.total"), "{:?}", env.errors);
+        let report: crate::cli::commands::JsonReport =
+            serde_json::from_value(env.result["files"][&stale.display().to_string()].clone())
+                .unwrap();
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].name, "summary.total");
+    }
+}
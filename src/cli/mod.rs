@@ -1,22 +1,34 @@
 //! CLI command handlers
 
 pub mod commands;
+pub mod envelope;
 
 pub use commands::{
-    audit, break_even, calculate, compare, examples, export, functions, goal_seek, import, schema,
-    sensitivity, update, validate, variance, watch,
+    audit, audit_dependents, break_even, calculate, calculate_json, compare, compile, dcf,
+    examples, export, export_json_records, export_markdown, export_parquet, features, fmt,
+    functions, goal_seek, graph, import, sample, schema, sensitivity, snapshot, stats, update,
+    validate, validate_json, variance, watch, CalculateOptions, ImportOptions,
 };
 
+pub use envelope::JsonEnvelope;
+
 pub use commands::upgrade;
 
+pub use commands::{set_locale, set_theme, Locale, Theme};
+
 pub use commands::simulate;
 
 pub use commands::{bayesian, bootstrap, decision_tree, real_options, scenarios, tornado};
 
 // Core function re-exports (structured results, no printing)
 pub use commands::{
-    audit_core, bayesian_core, bootstrap_core, calculate_core, compare_core, decision_tree_core,
-    examples_core, export_buffer_core, export_core, functions_core, goal_seek_core, import_core,
-    real_options_core, scenarios_core, schema_core, sensitivity_core, simulate_core, tornado_core,
-    validate_core, variance_core,
+    audit_core, bayesian_core, bootstrap_core, break_even_core, calculate_core, compare_core,
+    dcf_core, decision_tree_core, examples_core, export_buffer_core, export_core, features_core,
+    fmt_core, functions_core, goal_seek_core, graph_core, import_core, real_options_core,
+    sample_core, scenarios_core, schema_core, sensitivity_core, simulate_core, snapshot_core,
+    stats_core, tornado_core, validate_core, variance_core,
+};
+
+pub use commands::{
+    solve_bisection, solve_newton, GoalSeekOptions, SensitivityFormat, Solution, SolverMethod,
 };
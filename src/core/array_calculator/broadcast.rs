@@ -0,0 +1,222 @@
+//! Whole-column aggregations inside row formulas
+//!
+//! In a row formula such as `=IF(value > SUM(thresholds.min), 1, 0)` the
+//! aggregation does not depend on the row, so it is evaluated once over whole
+//! columns and its result is used as a constant in every row. Aggregating a
+//! column of the formula's own table is rejected: there `SUM(value)` could mean
+//! the row's value or the whole column.
+
+use super::evaluator::{self, EvalContext, Value};
+use super::parser::{Expr, Reference};
+use crate::error::{ForgeError, ForgeResult};
+use crate::types::Table;
+
+/// Functions that reduce whole columns to one value
+const AGGREGATION_FUNCTIONS: &[&str] = &[
+    "SUM",
+    "SUBTOTAL",
+    "AVERAGE",
+    "AVG",
+    "MAX",
+    "MIN",
+    "COUNT",
+    "SUMIF",
+    "COUNTIF",
+    "AVERAGEIF",
+    "SUMIFS",
+    "COUNTIFS",
+    "AVERAGEIFS",
+    "MAXIFS",
+    "MINIFS",
+    "MEDIAN",
+    "GEOMEAN",
+    "HARMEAN",
+    "VAR",
+    "VAR.S",
+    "VAR.P",
+    "STDEV",
+    "STDEV.S",
+    "STDEV.P",
+    "PERCENTILE",
+    "PERCENTILE.INC",
+    "PERCENTILE.EXC",
+    "QUARTILE",
+    "QUARTILE.INC",
+    "QUARTILE.EXC",
+    "CORREL",
+    "SLOPE",
+    "INTERCEPT",
+];
+
+/// Functions whose bodies bind their own names; aggregations inside are left
+/// to row evaluation, where those names are in scope
+const BINDING_FUNCTIONS: &[&str] = &["LAMBDA", "LET"];
+
+/// Replace each whole-column aggregation in a row formula with its value
+///
+/// `ctx` must be the table's context in scalar mode (no current row), so
+/// column references evaluate to whole columns. Aggregations that fail or do
+/// not produce a number, text or boolean are kept and evaluated per row.
+///
+/// # Errors
+///
+/// Returns an error if an aggregation references a column of `table`.
+pub fn broadcast_aggregations(expr: &Expr, table: &Table, ctx: &EvalContext) -> ForgeResult<Expr> {
+    let rebuild = |e: &Expr| broadcast_aggregations(e, table, ctx);
+    let rebuild_all = |items: &[Expr]| items.iter().map(rebuild).collect::<ForgeResult<Vec<_>>>();
+    let boxed = |e: &Expr| rebuild(e).map(Box::new);
+
+    Ok(match expr {
+        Expr::FunctionCall { name, .. }
+            if BINDING_FUNCTIONS.contains(&name.to_uppercase().as_str()) =>
+        {
+            expr.clone()
+        },
+        Expr::FunctionCall { name, args }
+            if AGGREGATION_FUNCTIONS.contains(&name.to_uppercase().as_str()) =>
+        {
+            if let Some(column) = args.iter().find_map(|arg| sibling_column(arg, table)) {
+                return Err(ForgeError::Eval(format!(
+                    "{name}({column}) in a row formula of table '{}' is ambiguous: \
+                     '{column}' is a column of the same table. Aggregate it in the \
+                     scalars section and reference the scalar instead",
+                    table.name
+                )));
+            }
+            match evaluator::evaluate(expr, ctx) {
+                Ok(Value::Number(n)) => Expr::Number(n),
+                Ok(Value::Text(s)) => Expr::Text(s),
+                Ok(Value::Boolean(b)) => Expr::Boolean(b),
+                _ => expr.clone(),
+            }
+        },
+        Expr::FunctionCall { name, args } => Expr::FunctionCall {
+            name: name.clone(),
+            args: rebuild_all(args)?,
+        },
+        Expr::CallResult { callable, args } => Expr::CallResult {
+            callable: boxed(callable)?,
+            args: rebuild_all(args)?,
+        },
+        Expr::BinaryOp { op, left, right } => Expr::BinaryOp {
+            op: op.clone(),
+            left: boxed(left)?,
+            right: boxed(right)?,
+        },
+        Expr::UnaryOp { op, operand } => Expr::UnaryOp {
+            op: op.clone(),
+            operand: boxed(operand)?,
+        },
+        Expr::ArrayIndex { array, index } => Expr::ArrayIndex {
+            array: boxed(array)?,
+            index: boxed(index)?,
+        },
+        Expr::ArraySlice { array, start, end } => Expr::ArraySlice {
+            array: boxed(array)?,
+            start: start.as_deref().map(boxed).transpose()?,
+            end: end.as_deref().map(boxed).transpose()?,
+        },
+        Expr::Number(_)
+        | Expr::Text(_)
+        | Expr::Boolean(_)
+        | Expr::Reference(_)
+        | Expr::Range { .. } => expr.clone(),
+    })
+}
+
+/// First column of `table` referenced anywhere in `expr`
+fn sibling_column<'a>(expr: &'a Expr, table: &Table) -> Option<&'a str> {
+    match expr {
+        Expr::Reference(Reference::Scalar(name)) => {
+            table.columns.contains_key(name).then_some(name.as_str())
+        },
+        Expr::Reference(Reference::TableColumn { table: t, column }) => {
+            (*t == table.name && table.columns.contains_key(column)).then_some(column.as_str())
+        },
+        Expr::FunctionCall { args, .. } => args.iter().find_map(|a| sibling_column(a, table)),
+        Expr::CallResult { callable, args } => sibling_column(callable, table)
+            .or_else(|| args.iter().find_map(|a| sibling_column(a, table))),
+        Expr::BinaryOp { left, right, .. } => {
+            sibling_column(left, table).or_else(|| sibling_column(right, table))
+        },
+        Expr::UnaryOp { operand, .. } => sibling_column(operand, table),
+        Expr::ArrayIndex { array, index } => {
+            sibling_column(array, table).or_else(|| sibling_column(index, table))
+        },
+        Expr::ArraySlice { array, start, end } => sibling_column(array, table)
+            .or_else(|| start.as_deref().and_then(|s| sibling_column(s, table)))
+            .or_else(|| end.as_deref().and_then(|e| sibling_column(e, table))),
+        Expr::Range { start, end } => {
+            sibling_column(start, table).or_else(|| sibling_column(end, table))
+        },
+        Expr::Number(_) | Expr::Text(_) | Expr::Boolean(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::array_calculator::ArrayCalculator;
+    use crate::types::{Column, ColumnValue, ParsedModel, Table, Variable};
+
+    fn model(row_formula: &str) -> ParsedModel {
+        let mut benchmarks = Table::new("benchmarks".to_string());
+        benchmarks.add_column(Column::new(
+            "score".to_string(),
+            ColumnValue::Number(vec![40.0, 50.0, 60.0, 70.0]),
+        ));
+        let mut teams = Table::new("teams".to_string());
+        teams.add_column(Column::new(
+            "score".to_string(),
+            ColumnValue::Number(vec![45.0, 65.0]),
+        ));
+        teams.add_row_formula("result".to_string(), row_formula.to_string());
+
+        let mut model = ParsedModel::new();
+        model.add_table(benchmarks);
+        model.add_table(teams);
+        model.add_scalar(
+            "bonus".to_string(),
+            Variable::new("bonus".to_string(), Some(5.0), None),
+        );
+        model
+    }
+
+    fn result(row_formula: &str) -> Vec<f64> {
+        let calculated = ArrayCalculator::new(model(row_formula))
+            .calculate_all()
+            .unwrap();
+        match &calculated.tables["teams"].columns["result"].values {
+            ColumnValue::Number(values) => values.clone(),
+            other => panic!("Expected Number column, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cross_table_aggregation_is_broadcast() {
+        assert_eq!(
+            result("=score - AVERAGE(benchmarks.score)"),
+            vec![-10.0, 10.0]
+        );
+        assert_eq!(
+            result("=IF(score > MEDIAN(benchmarks.score), 1, 0)"),
+            vec![0.0, 1.0]
+        );
+        // Scalars and nested aggregations work too
+        assert_eq!(
+            result("=score + MAX(benchmarks.score) - SUM(bonus, 10)"),
+            vec![100.0, 120.0]
+        );
+    }
+
+    #[test]
+    fn test_same_table_aggregation_is_ambiguous() {
+        for formula in ["=score / SUM(score)", "=MAX(teams.score, 0)"] {
+            let err = ArrayCalculator::new(model(formula))
+                .calculate_all()
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("ambiguous"), "{formula}: {err}");
+            assert!(err.contains("'score'"), "{formula}: {err}");
+        }
+    }
+}
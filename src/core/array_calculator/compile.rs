@@ -0,0 +1,397 @@
+//! Formula pre-validation ("compile" pass)
+//!
+//! Tokenizes and parses every formula in a model and resolves each reference
+//! and function name against the model, collecting every problem instead of
+//! stopping at the first. A typo in a rarely computed formula is reported up
+//! front rather than deep into calculation.
+
+use super::parser::{self, Expr, Reference};
+use super::tokenizer;
+use crate::error::{ForgeError, ForgeResult};
+use crate::functions::registry::enterprise_functions;
+use crate::types::{ParsedModel, Table};
+use std::collections::HashSet;
+
+/// A problem found in one formula by [`compile`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileError {
+    /// Location (table.column or scalar name)
+    pub location: String,
+    /// The formula with the problem
+    pub formula: String,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl std::fmt::Display for CompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} ({})", self.location, self.message, self.formula)
+    }
+}
+
+/// Check every table and scalar formula in `model`, returning all problems found
+///
+/// Results are ordered by table, then column, then scalar name.
+#[must_use]
+pub fn compile(model: &ParsedModel) -> Vec<CompileError> {
+    let mut errors = Vec::new();
+
+    let mut table_names: Vec<&String> = model.tables.keys().collect();
+    table_names.sort();
+    for table_name in table_names {
+        let table = &model.tables[table_name];
+        let mut columns: Vec<&String> = table.row_formulas.keys().collect();
+        columns.sort();
+        for col_name in columns {
+            let formula = &table.row_formulas[col_name];
+            let location = format!("{table_name}.{col_name}");
+            check_formula(model, Some(table), &location, formula, &mut errors);
+        }
+    }
+
+    let mut scalar_names: Vec<&String> = model.scalars.keys().collect();
+    scalar_names.sort();
+    for name in scalar_names {
+        if let Some(formula) = &model.scalars[name].formula {
+            check_formula(model, None, name, formula, &mut errors);
+        }
+    }
+
+    errors
+}
+
+/// Run [`compile`] and fail with every problem found, so a model is checked
+/// once up front instead of failing at the first bad formula mid-calculation
+///
+/// # Errors
+///
+/// Returns an `Eval` error listing every formula that failed to compile.
+pub fn check(model: &ParsedModel) -> ForgeResult<()> {
+    let errors = compile(model);
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let details: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    Err(ForgeError::Eval(format!(
+        "{} formula error(s):\n  - {}",
+        errors.len(),
+        details.join("\n  - ")
+    )))
+}
+
+/// Tokenize, parse and resolve a single formula in the scope of `table`
+fn check_formula(
+    model: &ParsedModel,
+    table: Option<&Table>,
+    location: &str,
+    formula: &str,
+    errors: &mut Vec<CompileError>,
+) {
+    let mut report = |message: String| {
+        errors.push(CompileError {
+            location: location.to_string(),
+            formula: formula.to_string(),
+            message,
+        });
+    };
+
+    let formula_str = formula.trim_start_matches('=').trim();
+    let ast = match tokenizer::tokenize(formula_str) {
+        Ok(tokens) => match parser::parse(tokens) {
+            Ok(ast) => ast,
+            Err(e) => return report(format!("Parse: {}", e.message)),
+        },
+        Err(e) => return report(format!("Tokenize: {}", e.message)),
+    };
+
+    // A scalar's formula may reference scalars in its own section by short name
+    let section = table
+        .is_none()
+        .then(|| location.rsplit_once('.').map(|(section, _)| section))
+        .flatten();
+    let scope = Scope {
+        model,
+        table,
+        section,
+    };
+    let mut problems = Vec::new();
+    scope.resolve(&ast, &mut Vec::new(), &mut problems);
+    let mut seen = HashSet::new();
+    for problem in problems {
+        if seen.insert(problem.clone()) {
+            report(problem);
+        }
+    }
+}
+
+/// Names visible to a formula: the model, plus the table or scalar section it
+/// belongs to
+struct Scope<'a> {
+    model: &'a ParsedModel,
+    table: Option<&'a Table>,
+    section: Option<&'a str>,
+}
+
+impl Scope<'_> {
+    /// Walk `expr`, recording unresolved references and unknown functions
+    ///
+    /// `locals` holds names bound by enclosing LAMBDA parameters and LET bindings.
+    fn resolve(&self, expr: &Expr, locals: &mut Vec<String>, problems: &mut Vec<String>) {
+        match expr {
+            Expr::Number(_) | Expr::Text(_) | Expr::Boolean(_) | Expr::Range { .. } => {},
+            Expr::Reference(reference) => {
+                if let Some(problem) = self.check_reference(reference, locals) {
+                    problems.push(problem);
+                }
+            },
+            Expr::ArrayIndex { array, index } => {
+                self.resolve(array, locals, problems);
+                self.resolve(index, locals, problems);
+            },
+            Expr::ArraySlice { array, start, end } => {
+                self.resolve(array, locals, problems);
+                for bound in [start, end].into_iter().flatten() {
+                    self.resolve(bound, locals, problems);
+                }
+            },
+            Expr::FunctionCall { name, args } => {
+                let upper = name.to_uppercase();
+                if !is_known_function(&upper) {
+                    problems.push(format!("Unknown function: {name}"));
+                }
+                self.resolve_call(&upper, args, locals, problems);
+            },
+            Expr::CallResult { callable, args } => {
+                self.resolve(callable, locals, problems);
+                for arg in args {
+                    self.resolve(arg, locals, problems);
+                }
+            },
+            Expr::BinaryOp { left, right, .. } => {
+                self.resolve(left, locals, problems);
+                self.resolve(right, locals, problems);
+            },
+            Expr::UnaryOp { operand, .. } => self.resolve(operand, locals, problems),
+        }
+    }
+
+    /// Resolve function arguments, binding LAMBDA parameters and LET names
+    fn resolve_call(
+        &self,
+        name: &str,
+        args: &[Expr],
+        locals: &mut Vec<String>,
+        problems: &mut Vec<String>,
+    ) {
+        let depth = locals.len();
+        match (name, args.split_last()) {
+            ("LAMBDA", Some((body, params))) => {
+                locals.extend(params.iter().filter_map(local_name));
+                self.resolve(body, locals, problems);
+            },
+            ("LET", Some((body, pairs))) => {
+                for pair in pairs.chunks(2) {
+                    if let [name, value] = pair {
+                        self.resolve(value, locals, problems);
+                        locals.extend(local_name(name));
+                    }
+                }
+                self.resolve(body, locals, problems);
+            },
+            _ => {
+                for arg in args {
+                    self.resolve(arg, locals, problems);
+                }
+            },
+        }
+        locals.truncate(depth);
+    }
+
+    fn check_reference(&self, reference: &Reference, locals: &[String]) -> Option<String> {
+        match reference {
+            Reference::Scalar(name) => {
+                let known = locals.contains(name)
+                    || self.model.names.contains_key(name)
+                    || self.model.scalars.contains_key(name)
+                    || self.model.tables.contains_key(name)
+                    || self.table.is_some_and(|t| has_column(t, name));
+                if known {
+                    return None;
+                }
+                match self
+                    .model
+                    .short_scalar_names(self.section)
+                    .get(name.as_str())
+                {
+                    Some(targets) if targets.len() == 1 => None,
+                    Some(targets) => Some(format!(
+                        "Ambiguous reference: {name} could be {}",
+                        targets
+                            .iter()
+                            .map(|target| target.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )),
+                    None => Some(format!("Unknown variable: {name}")),
+                }
+            },
+            Reference::TableColumn { table, column } => {
+                let known = self
+                    .model
+                    .scalars
+                    .contains_key(&format!("{table}.{column}"))
+                    || self
+                        .model
+                        .tables
+                        .get(table)
+                        .is_some_and(|t| has_column(t, column));
+                (!known).then(|| format!("Unknown column: {table}.{column}"))
+            },
+        }
+    }
+}
+
+/// Registered function name (case-insensitive)
+fn is_known_function(upper: &str) -> bool {
+    enterprise_functions().any(|f| f.name.eq_ignore_ascii_case(upper))
+}
+
+/// Data column or row formula in `table`
+fn has_column(table: &Table, name: &str) -> bool {
+    table.columns.contains_key(name) || table.row_formulas.contains_key(name)
+}
+
+/// Name bound by a LAMBDA parameter or LET name argument
+fn local_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Reference(Reference::Scalar(name)) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Column, ColumnValue, Variable};
+
+    fn model() -> ParsedModel {
+        let mut table = Table::new("sales".to_string());
+        table.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0]),
+        ));
+        table.add_row_formula("cost".to_string(), "=revenue * rate".to_string());
+        table.add_row_formula("margin".to_string(), "=revenue - cost".to_string());
+
+        let mut model = ParsedModel::new();
+        model.add_table(table);
+        model.add_scalar(
+            "inputs.rate".to_string(),
+            Variable::new("inputs.rate".to_string(), Some(0.4), None),
+        );
+        model.add_scalar(
+            "total".to_string(),
+            Variable::new(
+                "total".to_string(),
+                None,
+                Some("=SUM(sales.margin) * inputs.rate".to_string()),
+            ),
+        );
+        model
+    }
+
+    #[test]
+    fn test_compile_valid_model() {
+        assert!(compile(&model()).is_empty());
+    }
+
+    #[test]
+    fn test_compile_reports_all_errors() {
+        let mut model = model();
+        let table = model.tables.get_mut("sales").unwrap();
+        table.add_row_formula("bad_ref".to_string(), "=revenue * rat".to_string());
+        table.add_row_formula("bad_syntax".to_string(), "=(revenue + 1".to_string());
+        model.add_scalar(
+            "bad_fn".to_string(),
+            Variable::new(
+                "bad_fn".to_string(),
+                None,
+                Some("=SUMM(sales.revenu)".to_string()),
+            ),
+        );
+
+        let errors = compile(&model);
+        let locations: Vec<&str> = errors.iter().map(|e| e.location.as_str()).collect();
+        assert_eq!(
+            locations,
+            vec!["sales.bad_ref", "sales.bad_syntax", "bad_fn", "bad_fn"]
+        );
+        assert_eq!(errors[0].message, "Unknown variable: rat");
+        assert!(errors[1].message.starts_with("Parse:"));
+        assert_eq!(errors[2].message, "Unknown function: SUMM");
+        assert_eq!(errors[3].message, "Unknown column: sales.revenu");
+    }
+
+    #[test]
+    fn test_check_lists_every_error() {
+        assert!(check(&model()).is_ok());
+
+        let mut model = model();
+        let table = model.tables.get_mut("sales").unwrap();
+        table.add_row_formula("bad_ref".to_string(), "=revenue * rat".to_string());
+        table.add_row_formula("legacy".to_string(), "=LOG(revenue) + RAND()".to_string());
+        model.add_scalar(
+            "bad_fn".to_string(),
+            Variable::new("bad_fn".to_string(), None, Some("=SUMM(1)".to_string())),
+        );
+
+        let message = check(&model).unwrap_err().to_string();
+        assert!(message.contains("2 formula error(s)"), "{message}");
+        assert!(message.contains("sales.bad_ref: Unknown variable: rat"));
+        assert!(message.contains("bad_fn: Unknown function: SUMM"));
+    }
+
+    #[test]
+    fn test_compile_reports_ambiguous_short_names() {
+        let mut model = model();
+        model.add_scalar(
+            "other.rate".to_string(),
+            Variable::new("other.rate".to_string(), Some(0.1), None),
+        );
+
+        let errors = compile(&model);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].location, "sales.cost");
+        assert_eq!(
+            errors[0].message,
+            "Ambiguous reference: rate could be inputs.rate, other.rate"
+        );
+    }
+
+    #[test]
+    fn test_compile_binds_lambda_and_let_names() {
+        let mut model = model();
+        model.add_scalar(
+            "doubled".to_string(),
+            Variable::new(
+                "doubled".to_string(),
+                None,
+                Some("=LET(x, 2, LAMBDA(v, v * x)(SUM(sales.revenue)))".to_string()),
+            ),
+        );
+        assert!(compile(&model).is_empty());
+
+        // Bindings do not leak outside the call that introduces them
+        model.add_scalar(
+            "leaked".to_string(),
+            Variable::new(
+                "leaked".to_string(),
+                None,
+                Some("=LET(x, 2, x) + x".to_string()),
+            ),
+        );
+        let errors = compile(&model);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Unknown variable: x");
+    }
+}
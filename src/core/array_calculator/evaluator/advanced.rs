@@ -1,6 +1,6 @@
-//! Advanced functions: LAMBDA, LET, SWITCH
+//! Advanced functions: LAMBDA, LET
 
-use super::{evaluate, values_equal, EvalContext, EvalError, Expr, Reference, Value};
+use super::{evaluate, EvalContext, EvalError, Expr, Reference, Value};
 
 /// Try to evaluate an advanced function. Returns None if function not recognized.
 pub fn try_evaluate(
@@ -65,31 +65,6 @@ pub fn try_evaluate(
             evaluate(&args[args.len() - 1], &new_ctx)?
         },
 
-        "SWITCH" => {
-            // SWITCH(expression, value1, result1, [value2, result2], ..., [default])
-            if args.len() < 2 {
-                return Err(EvalError::new("SWITCH requires at least 2 arguments"));
-            }
-
-            let expr_val = evaluate(&args[0], ctx)?;
-            let remaining = &args[1..];
-
-            let mut i = 0;
-            while i + 1 < remaining.len() {
-                let check_val = evaluate(&remaining[i], ctx)?;
-                if values_equal(&expr_val, &check_val) {
-                    return Ok(Some(evaluate(&remaining[i + 1], ctx)?));
-                }
-                i += 2;
-            }
-
-            if remaining.len() % 2 == 1 {
-                evaluate(&remaining[remaining.len() - 1], ctx)?
-            } else {
-                return Err(EvalError::new("SWITCH: No match found"));
-            }
-        },
-
         _ => return Ok(None),
     };
 
@@ -111,6 +86,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_let_shadows_scalars_without_mutating_context() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars.insert("rate".to_string(), Value::Number(0.5));
+        assert_eq!(
+            eval("LET(rate, 0.1, 100 * rate)", &ctx).unwrap(),
+            Value::Number(10.0)
+        );
+        // Later values see earlier names, including a shadowed one
+        assert_eq!(
+            eval("LET(rate, rate * 2, rate, rate + 1, rate)", &ctx).unwrap(),
+            Value::Number(2.0)
+        );
+        assert_eq!(ctx.scalars["rate"], Value::Number(0.5));
+        assert!(eval("LET(x, 1, rate + x) + x", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_let_requires_a_calculation() {
+        let ctx = EvalContext::new();
+        let err = eval("LET(x, 1, y, 2)", &ctx).unwrap_err();
+        assert!(err.message.contains("calculation"), "{}", err.message);
+        assert!(eval("LET(1, 2, 3)", &ctx).is_err());
+    }
+
     #[test]
     fn test_switch() {
         let ctx = EvalContext::new();
@@ -1,12 +1,13 @@
 //! Aggregation functions: SUM, AVERAGE, COUNT, MIN, MAX, PRODUCT, MEDIAN
 //!
 //! DEMO functions (5): SUM, AVERAGE, MIN, MAX, COUNT
-//! ENTERPRISE functions: PRODUCT, COUNTA, MEDIAN
+//! ENTERPRISE functions: PRODUCT, COUNTA, MEDIAN, SUBTOTAL
 
-// Aggregation casts: array lengths (usize) to f64 for AVERAGE/COUNT (bounded by worksheet size).
-#![allow(clippy::cast_precision_loss)]
+// Aggregation casts: array lengths (usize) to f64 for AVERAGE/COUNT (bounded by worksheet size),
+// and SUBTOTAL's function_num (range-checked by the match) from f64 to i64.
+#![allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
 
-use super::{collect_numeric_values, evaluate, EvalContext, EvalError, Expr, Value};
+use super::{collect_numeric_values, evaluate, EvalContext, EvalError, Expr, Reference, Value};
 
 /// Try to evaluate an aggregation function. Returns None if function not recognized.
 pub fn try_evaluate(
@@ -114,15 +115,80 @@ pub fn try_evaluate(
             }
         },
 
+        "SUBTOTAL" => eval_subtotal(args, ctx)?,
+
         _ => return Ok(None),
     };
 
     Ok(Some(result))
 }
 
+/// `SUBTOTAL(function_num, ref1, ...)`
+///
+/// Dispatches to the aggregation for `function_num` (1-11, or 101-111 which are
+/// identical here since Forge has no hidden rows). Like Excel, arguments that are
+/// themselves SUBTOTAL results - nested calls or scalars whose formula is a
+/// SUBTOTAL - are skipped so layered totals are not double counted. Scalar
+/// references are resolved to their fully qualified name before that check.
+/// The exclusion is always on: Excel offers no switch for it either (101-111
+/// only concern hidden rows), and models must agree with their Excel export.
+fn eval_subtotal(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    if args.len() < 2 {
+        return Err(EvalError::new(
+            "SUBTOTAL requires function_num and at least one reference",
+        ));
+    }
+    let function_num = evaluate(&args[0], ctx)?
+        .as_number()
+        .ok_or_else(|| EvalError::new("SUBTOTAL function_num must be a number"))?
+        .trunc() as i64;
+    let name = match function_num % 100 {
+        _ if !(1..=111).contains(&function_num) || (12..=100).contains(&function_num) => {
+            return Err(EvalError::new(format!(
+                "SUBTOTAL function_num must be 1-11 or 101-111, got {function_num}"
+            )));
+        },
+        1 => "AVERAGE",
+        2 => "COUNT",
+        3 => "COUNTA",
+        4 => "MAX",
+        5 => "MIN",
+        6 => "PRODUCT",
+        9 => "SUM",
+        _ => {
+            return Err(EvalError::new(format!(
+                "SUBTOTAL function_num {function_num} (STDEV/VAR) is not supported"
+            )));
+        },
+    };
+
+    let refs: Vec<Expr> = args[1..]
+        .iter()
+        .filter(|arg| !is_subtotal_output(arg, ctx))
+        .cloned()
+        .collect();
+    try_evaluate(name, &refs, ctx)?
+        .ok_or_else(|| EvalError::new(format!("SUBTOTAL: {name} unavailable")))
+}
+
+/// Whether an argument is the result of another SUBTOTAL
+fn is_subtotal_output(arg: &Expr, ctx: &EvalContext) -> bool {
+    match arg {
+        Expr::FunctionCall { name, .. } => name.eq_ignore_ascii_case("SUBTOTAL"),
+        Expr::Reference(Reference::Scalar(name)) => {
+            ctx.subtotals.contains(ctx.qualified_name(name))
+        },
+        Expr::Reference(Reference::TableColumn { table, column }) => {
+            ctx.subtotals.contains(&format!("{table}.{column}"))
+        },
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::float_cmp)] // Exact float comparison validated against Excel/Gnumeric/R
+    use super::super::array::fixtures::table_ctx;
     use super::super::tests::eval;
     use super::*;
     use std::collections::HashMap;
@@ -242,6 +308,49 @@ mod tests {
         let ctx = EvalContext::new();
         assert_eq!(eval("PRODUCT(2, 3, 4)", &ctx).unwrap(), Value::Number(24.0));
     }
+
+    fn subtotal_ctx() -> EvalContext {
+        table_ctx(&[("values", &[10.0, 20.0, 30.0, 40.0])])
+    }
+
+    #[test]
+    fn test_subtotal_matches_aggregations() {
+        let ctx = subtotal_ctx();
+        for (num, func) in [
+            (1, "AVERAGE"),
+            (2, "COUNT"),
+            (4, "MAX"),
+            (5, "MIN"),
+            (9, "SUM"),
+        ] {
+            assert_eq!(
+                eval(&format!("SUBTOTAL({num}, t.values)"), &ctx).unwrap(),
+                eval(&format!("{func}(t.values)"), &ctx).unwrap(),
+                "SUBTOTAL({num}) should equal {func}"
+            );
+        }
+        assert_eq!(
+            eval("SUBTOTAL(109, t.values)", &ctx).unwrap(),
+            Value::Number(100.0)
+        );
+    }
+
+    #[test]
+    fn test_subtotal_skips_nested_subtotal() {
+        let ctx = subtotal_ctx();
+        assert_eq!(
+            eval("SUBTOTAL(9, t.values, SUBTOTAL(9, t.values))", &ctx).unwrap(),
+            Value::Number(100.0)
+        );
+    }
+
+    #[test]
+    fn test_subtotal_invalid_function_num() {
+        let ctx = subtotal_ctx();
+        assert!(eval("SUBTOTAL(12, t.values)", &ctx).is_err());
+        assert!(eval("SUBTOTAL(7, t.values)", &ctx).is_err());
+        assert!(eval("SUBTOTAL(9)", &ctx).is_err());
+    }
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -254,7 +363,7 @@ mod integration_tests {
     #![allow(clippy::float_cmp)] // Exact float comparison validated against Excel/Gnumeric/R
 
     use crate::core::array_calculator::ArrayCalculator;
-    use crate::types::{Column, ColumnValue, ParsedModel, Table, Variable};
+    use crate::types::{Column, ColumnValue, NamedRange, ParsedModel, Table, Variable};
 
     #[test]
     fn test_aggregation_sum() {
@@ -961,6 +1070,67 @@ mod integration_tests {
         assert_eq!(count, 4.0);
     }
 
+    #[test]
+    fn test_rank_row_wise() {
+        let mut model = ParsedModel::new();
+        let mut data = Table::new("data".to_string());
+        data.add_column(Column::new(
+            "score".to_string(),
+            ColumnValue::Number(vec![85.0, 92.0, 85.0, 70.0]),
+        ));
+        data.add_row_formula(
+            "rank".to_string(),
+            "=RANK.EQ(score, data.score)".to_string(),
+        );
+        data.add_row_formula(
+            "rank_avg".to_string(),
+            "=RANK.AVG(score, data.score)".to_string(),
+        );
+        model.add_table(data);
+
+        let result = ArrayCalculator::new(model).calculate_all().unwrap();
+        let data = &result.tables["data"];
+        assert_eq!(
+            data.columns["rank"].values,
+            ColumnValue::Number(vec![2.0, 1.0, 2.0, 4.0])
+        );
+        assert_eq!(
+            data.columns["rank_avg"].values,
+            ColumnValue::Number(vec![2.5, 1.0, 2.5, 4.0])
+        );
+    }
+
+    #[test]
+    fn test_forecast_linear_row_wise() {
+        let mut model = ParsedModel::new();
+        let mut history = Table::new("history".to_string());
+        history.add_column(Column::new(
+            "year".to_string(),
+            ColumnValue::Number(vec![1.0, 2.0, 3.0]),
+        ));
+        history.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 110.0, 120.0]),
+        ));
+        model.add_table(history);
+        let mut plan = Table::new("plan".to_string());
+        plan.add_column(Column::new(
+            "year".to_string(),
+            ColumnValue::Number(vec![4.0, 5.0]),
+        ));
+        plan.add_row_formula(
+            "revenue".to_string(),
+            "=FORECAST.LINEAR(year, history.revenue, history.year)".to_string(),
+        );
+        model.add_table(plan);
+
+        let result = ArrayCalculator::new(model).calculate_all().unwrap();
+        assert_eq!(
+            result.tables["plan"].columns["revenue"].values,
+            ColumnValue::Number(vec![130.0, 140.0])
+        );
+    }
+
     #[test]
     fn test_rank_eq_function() {
         let mut model = ParsedModel::new();
@@ -1141,4 +1311,85 @@ mod integration_tests {
             150.0
         );
     }
+
+    #[test]
+    fn test_subtotal_excludes_subtotal_scalars() {
+        let mut model = ParsedModel::new();
+        for (name, value) in [("north", 100.0), ("south", 50.0), ("east", 25.0)] {
+            model.add_scalar(
+                name.to_string(),
+                Variable::new(name.to_string(), Some(value), None),
+            );
+        }
+        model.add_scalar(
+            "north_south".to_string(),
+            Variable::new(
+                "north_south".to_string(),
+                None,
+                Some("=SUBTOTAL(9, north, south)".to_string()),
+            ),
+        );
+        model.add_scalar(
+            "grand_total".to_string(),
+            Variable::new(
+                "grand_total".to_string(),
+                None,
+                Some("=SUBTOTAL(9, north, south, north_south, east)".to_string()),
+            ),
+        );
+
+        let calculator = ArrayCalculator::new(model);
+        let result = calculator.calculate_all().expect("Should calculate");
+
+        assert_eq!(
+            result.scalars.get("north_south").unwrap().value.unwrap(),
+            150.0
+        );
+        // north_south is itself a subtotal, so it is not counted twice
+        assert_eq!(
+            result.scalars.get("grand_total").unwrap().value.unwrap(),
+            175.0
+        );
+    }
+
+    #[test]
+    fn test_subtotal_resolves_references_to_qualified_names() {
+        let mut model = ParsedModel::new();
+        for (name, value) in [("north", 100.0), ("south", 50.0), ("east", 25.0)] {
+            model.add_scalar(
+                name.to_string(),
+                Variable::new(name.to_string(), Some(value), None),
+            );
+        }
+        model.add_scalar(
+            "summary.total".to_string(),
+            Variable::new(
+                "summary.total".to_string(),
+                None,
+                Some("=SUBTOTAL(9, north, south)".to_string()),
+            ),
+        );
+        // `layer` names the subtotal; `total` shares its short name but names `east`
+        model.add_name(
+            "layer".to_string(),
+            NamedRange::parse("summary.total").unwrap(),
+        );
+        model.add_name("total".to_string(), NamedRange::parse("east").unwrap());
+        model.add_scalar(
+            "grand_total".to_string(),
+            Variable::new(
+                "grand_total".to_string(),
+                None,
+                Some("=SUBTOTAL(9, north, south, layer, total)".to_string()),
+            ),
+        );
+
+        let calculator = ArrayCalculator::new(model);
+        let result = calculator.calculate_all().expect("Should calculate");
+
+        assert_eq!(
+            result.scalars.get("grand_total").unwrap().value.unwrap(),
+            175.0
+        );
+    }
 }
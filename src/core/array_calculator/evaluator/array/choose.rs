@@ -0,0 +1,139 @@
+//! CHOOSECOLS and CHOOSEROWS array functions
+
+// Index casts: 1-based f64 indices to usize after range validation.
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+
+use super::{collect_columns, columns_to_value};
+use crate::core::array_calculator::evaluator::{evaluate, EvalContext, EvalError, Expr, Value};
+
+/// Evaluate CHOOSECOLS function - selects columns of a range by 1-based index
+/// CHOOSECOLS(range, col1, [col2], ...)
+pub fn eval_choosecols(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let columns = range_arg("CHOOSECOLS", args, ctx)?;
+    let picked = indices("CHOOSECOLS", "column", &args[1..], columns.len(), ctx)?
+        .into_iter()
+        .map(|i| columns[i].clone())
+        .collect();
+    Ok(columns_to_value(picked))
+}
+
+/// Evaluate CHOOSEROWS function - selects rows of a range by 1-based index
+/// CHOOSEROWS(range, row1, [row2], ...)
+pub fn eval_chooserows(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let columns = range_arg("CHOOSEROWS", args, ctx)?;
+    let rows = columns.first().map_or(0, Vec::len);
+    let picked = indices("CHOOSEROWS", "row", &args[1..], rows, ctx)?;
+    let selected = columns
+        .iter()
+        .map(|col| picked.iter().map(|&i| col[i].clone()).collect())
+        .collect();
+    Ok(columns_to_value(selected))
+}
+
+fn range_arg(func: &str, args: &[Expr], ctx: &EvalContext) -> Result<Vec<Vec<Value>>, EvalError> {
+    if args.len() < 2 {
+        return Err(EvalError::new(format!(
+            "{func} requires a range and at least one index"
+        )));
+    }
+    collect_columns(func, &args[0], ctx)
+}
+
+/// Resolve 1-based indices (negative counts from the end) into 0-based positions
+fn indices(
+    func: &str,
+    kind: &str,
+    args: &[Expr],
+    len: usize,
+    ctx: &EvalContext,
+) -> Result<Vec<usize>, EvalError> {
+    args.iter()
+        .map(|arg| {
+            let raw = evaluate(arg, ctx)?
+                .as_number()
+                .ok_or_else(|| EvalError::new(format!("{func}: {kind} index must be a number")))?
+                .trunc();
+            let magnitude = raw.abs() as usize;
+            if raw == 0.0 || magnitude > len {
+                return Err(EvalError::new(format!(
+                    "{func}: {kind} index {raw} is out of range (1 to {len})"
+                )));
+            }
+            Ok(if raw > 0.0 {
+                magnitude - 1
+            } else {
+                len - magnitude
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::eval;
+    use super::super::fixtures::{nums, table_ctx};
+    use crate::core::array_calculator::evaluator::{EvalContext, Value};
+
+    /// Three-column range [a, b, c] exposed both as a list of columns and as table `t`
+    fn grid_ctx() -> EvalContext {
+        let columns: [(&str, &[f64]); 3] = [
+            ("a", &[1.0, 2.0, 3.0]),
+            ("b", &[10.0, 20.0, 30.0]),
+            ("c", &[100.0, 200.0, 300.0]),
+        ];
+        let mut ctx = table_ctx(&columns);
+        ctx.scalars.insert(
+            "grid".to_string(),
+            Value::Array(columns.iter().map(|(_, c)| nums(c)).collect()),
+        );
+        ctx
+    }
+
+    #[test]
+    fn test_choosecols_first_and_third() {
+        let ctx = grid_ctx();
+        let expected = Value::Array(vec![nums(&[1.0, 2.0, 3.0]), nums(&[100.0, 200.0, 300.0])]);
+        assert_eq!(eval("CHOOSECOLS(grid, 1, 3)", &ctx).unwrap(), expected);
+        assert_eq!(eval("CHOOSECOLS(t, 1, 3)", &ctx).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_choosecols_single_column_is_flat() {
+        let ctx = grid_ctx();
+        assert_eq!(
+            eval("CHOOSECOLS(grid, -1)", &ctx).unwrap(),
+            nums(&[100.0, 200.0, 300.0])
+        );
+        assert_eq!(
+            eval("SUM(CHOOSECOLS(grid, 2))", &ctx).unwrap(),
+            Value::Number(60.0)
+        );
+        assert_eq!(
+            eval("SUM(CHOOSECOLS(grid, 1, 3))", &ctx).unwrap(),
+            Value::Number(606.0)
+        );
+    }
+
+    #[test]
+    fn test_chooserows() {
+        let ctx = grid_ctx();
+        assert_eq!(
+            eval("CHOOSEROWS(grid, 3, 1)", &ctx).unwrap(),
+            Value::Array(vec![
+                nums(&[3.0, 1.0]),
+                nums(&[30.0, 10.0]),
+                nums(&[300.0, 100.0]),
+            ])
+        );
+        assert_eq!(eval("CHOOSEROWS(t.b, 2)", &ctx).unwrap(), nums(&[20.0]));
+    }
+
+    #[test]
+    fn test_choose_invalid_indices() {
+        let ctx = grid_ctx();
+        assert!(eval("CHOOSECOLS(grid, 4)", &ctx).is_err());
+        assert!(eval("CHOOSECOLS(grid, 0)", &ctx).is_err());
+        assert!(eval("CHOOSEROWS(grid, -4)", &ctx).is_err());
+        assert!(eval("CHOOSECOLS(grid)", &ctx).is_err());
+    }
+}
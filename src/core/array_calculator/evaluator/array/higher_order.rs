@@ -0,0 +1,129 @@
+//! MAP, REDUCE and SCAN array functions
+//!
+//! Each takes a LAMBDA and calls it once per array element, in order (column
+//! by column for multi-column ranges).
+
+use super::{collect_columns, columns_to_value};
+use crate::core::array_calculator::evaluator::{
+    call_lambda, evaluate, require_args, EvalContext, EvalError, Expr, Value,
+};
+
+/// Evaluate MAP function - applies a 1-argument lambda to every element
+/// MAP(array, lambda)
+pub fn eval_map(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    require_args("MAP", args, 2)?;
+    let columns = collect_columns("MAP", &args[0], ctx)?;
+    let (params, body) = lambda_arg("MAP", &args[1], 1, ctx)?;
+    let mapped = columns
+        .into_iter()
+        .map(|col| {
+            col.into_iter()
+                .map(|value| call_lambda(&params, &body, vec![value], ctx))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(columns_to_value(mapped))
+}
+
+/// Evaluate REDUCE function - folds the array with a 2-argument lambda
+/// REDUCE(initial, array, lambda(accumulator, value))
+pub fn eval_reduce(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    require_args("REDUCE", args, 3)?;
+    let mut acc = evaluate(&args[0], ctx)?;
+    let columns = collect_columns("REDUCE", &args[1], ctx)?;
+    let (params, body) = lambda_arg("REDUCE", &args[2], 2, ctx)?;
+    for value in columns.into_iter().flatten() {
+        acc = call_lambda(&params, &body, vec![acc, value], ctx)?;
+    }
+    Ok(acc)
+}
+
+/// Evaluate SCAN function - like REDUCE, but returns every intermediate
+/// accumulator in an array shaped like the input
+/// SCAN(initial, array, lambda(accumulator, value))
+pub fn eval_scan(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    require_args("SCAN", args, 3)?;
+    let mut acc = evaluate(&args[0], ctx)?;
+    let columns = collect_columns("SCAN", &args[1], ctx)?;
+    let (params, body) = lambda_arg("SCAN", &args[2], 2, ctx)?;
+    let mut scanned = Vec::with_capacity(columns.len());
+    for col in columns {
+        let mut out = Vec::with_capacity(col.len());
+        for value in col {
+            acc = call_lambda(&params, &body, vec![acc, value], ctx)?;
+            out.push(acc.clone());
+        }
+        scanned.push(out);
+    }
+    Ok(columns_to_value(scanned))
+}
+
+/// Evaluate a lambda argument, checking it takes `arity` parameters
+fn lambda_arg(
+    func: &str,
+    expr: &Expr,
+    arity: usize,
+    ctx: &EvalContext,
+) -> Result<(Vec<String>, Expr), EvalError> {
+    match evaluate(expr, ctx)? {
+        Value::Lambda { params, body } if params.len() == arity => Ok((params, *body)),
+        Value::Lambda { params, .. } => Err(EvalError::new(format!(
+            "{func}: LAMBDA must take {arity} parameter(s), got {}",
+            params.len()
+        ))),
+        _ => Err(EvalError::new(format!("{func} requires a LAMBDA"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::eval;
+    use super::super::fixtures::nums;
+    use crate::core::array_calculator::evaluator::{EvalContext, Value};
+
+    fn values_ctx() -> EvalContext {
+        let mut ctx = EvalContext::new();
+        ctx.scalars
+            .insert("values".to_string(), nums(&[1.0, 2.0, 3.0, 4.0]));
+        ctx
+    }
+
+    #[test]
+    fn test_map() {
+        let ctx = values_ctx();
+        assert_eq!(
+            eval("MAP(values, LAMBDA(x, x * 10))", &ctx).unwrap(),
+            nums(&[10.0, 20.0, 30.0, 40.0])
+        );
+        assert_eq!(
+            eval("SUM(MAP(values, LAMBDA(x, x ^ 2)))", &ctx).unwrap(),
+            Value::Number(30.0)
+        );
+    }
+
+    #[test]
+    fn test_reduce_and_scan() {
+        let ctx = values_ctx();
+        assert_eq!(
+            eval("REDUCE(0, values, LAMBDA(acc, x, acc + x))", &ctx).unwrap(),
+            Value::Number(10.0)
+        );
+        assert_eq!(
+            eval("REDUCE(1, values, LAMBDA(acc, x, acc * x))", &ctx).unwrap(),
+            Value::Number(24.0)
+        );
+        assert_eq!(
+            eval("SCAN(0, values, LAMBDA(acc, x, acc + x))", &ctx).unwrap(),
+            nums(&[1.0, 3.0, 6.0, 10.0])
+        );
+    }
+
+    #[test]
+    fn test_higher_order_rejects_bad_lambdas() {
+        let ctx = values_ctx();
+        let err = eval("MAP(values, LAMBDA(a, b, a + b))", &ctx).unwrap_err();
+        assert!(err.message.contains("1 parameter"), "{}", err.message);
+        assert!(eval("REDUCE(0, values, LAMBDA(x, x))", &ctx).is_err());
+        assert!(eval("SCAN(0, values, 5)", &ctx).is_err());
+    }
+}
@@ -1,11 +1,20 @@
-//! Array functions: UNIQUE, COUNTUNIQUE, SORT, FILTER, SEQUENCE, RANDARRAY
+//! Array functions: UNIQUE, COUNTUNIQUE, SORT, FILTER, SEQUENCE, RANDARRAY, CHOOSECOLS, CHOOSEROWS,
+//! HSTACK, VSTACK, TAKE, DROP, WRAPROWS, WRAPCOLS, MAP, REDUCE, SCAN
+//!
+//! Multi-column ranges are represented as a list of columns: a `Value::Array` whose
+//! elements are themselves `Value::Array` columns of equal length.
 
+mod choose;
 mod filter;
 mod generators;
+mod higher_order;
+mod slice;
 mod sort;
+mod stack;
 mod unique;
+mod wrap;
 
-use super::{EvalContext, EvalError, Expr, Value};
+use super::{evaluate, EvalContext, EvalError, Expr, Reference, Value};
 
 /// Try to evaluate an array function. Returns None if function not recognized.
 pub fn try_evaluate(
@@ -20,8 +29,109 @@ pub fn try_evaluate(
         "FILTER" => filter::eval_filter(args, ctx)?,
         "SEQUENCE" => generators::eval_sequence(args, ctx)?,
         "RANDARRAY" => generators::eval_randarray(args, ctx)?,
+        "CHOOSECOLS" => choose::eval_choosecols(args, ctx)?,
+        "CHOOSEROWS" => choose::eval_chooserows(args, ctx)?,
+        "HSTACK" => stack::eval_hstack(args, ctx)?,
+        "VSTACK" => stack::eval_vstack(args, ctx)?,
+        "TAKE" => slice::eval_take(args, ctx)?,
+        "DROP" => slice::eval_drop(args, ctx)?,
+        "WRAPROWS" => wrap::eval_wraprows(args, ctx)?,
+        "WRAPCOLS" => wrap::eval_wrapcols(args, ctx)?,
+        "MAP" => higher_order::eval_map(args, ctx)?,
+        "REDUCE" => higher_order::eval_reduce(args, ctx)?,
+        "SCAN" => higher_order::eval_scan(args, ctx)?,
         _ => return Ok(None),
     };
 
     Ok(Some(result))
 }
+
+/// Evaluate a range argument into its columns
+///
+/// Accepts a list-of-columns value, a single column (one-column range), a scalar
+/// (1x1 range) or a bare table name. Tables do not keep declaration order, so a
+/// table's columns are taken in alphabetical order.
+fn collect_columns(
+    func: &str,
+    expr: &Expr,
+    ctx: &EvalContext,
+) -> Result<Vec<Vec<Value>>, EvalError> {
+    if let Expr::Reference(Reference::Scalar(name)) = expr {
+        if ctx.get_scalar(name).is_none() {
+            if let Some(table) = ctx.tables.get(name) {
+                let mut names: Vec<&String> = table.keys().collect();
+                names.sort();
+                let columns: Vec<Vec<Value>> =
+                    names.into_iter().map(|n| table[n].clone()).collect();
+                return check_rectangular(func, columns);
+            }
+        }
+    }
+
+    let columns = match evaluate(expr, ctx)? {
+        Value::Array(items) if items.iter().all(|v| matches!(v, Value::Array(_))) => items
+            .into_iter()
+            .map(|v| match v {
+                Value::Array(col) => col,
+                _ => unreachable!("checked above"),
+            })
+            .collect(),
+        Value::Array(items) => vec![items],
+        other => vec![vec![other]],
+    };
+    check_rectangular(func, columns)
+}
+
+/// Ensure all columns of a range have the same number of rows
+fn check_rectangular(func: &str, columns: Vec<Vec<Value>>) -> Result<Vec<Vec<Value>>, EvalError> {
+    if let Some(first) = columns.first() {
+        if let Some((i, col)) = columns
+            .iter()
+            .enumerate()
+            .find(|(_, c)| c.len() != first.len())
+        {
+            return Err(EvalError::new(format!(
+                "{func}: column {} has {} rows but column 1 has {}",
+                i + 1,
+                col.len(),
+                first.len()
+            )));
+        }
+    }
+    Ok(columns)
+}
+
+/// Convert columns back into a value; a single column collapses to a flat array
+fn columns_to_value(mut columns: Vec<Vec<Value>>) -> Value {
+    if columns.len() == 1 {
+        Value::Array(columns.remove(0))
+    } else {
+        Value::Array(columns.into_iter().map(Value::Array).collect())
+    }
+}
+
+/// Fixtures shared by the array (and SUBTOTAL) function tests
+#[cfg(test)]
+pub(super) mod fixtures {
+    use super::{EvalContext, Value};
+    use std::collections::HashMap;
+
+    /// Numeric array value
+    pub fn nums(values: &[f64]) -> Value {
+        Value::Array(values.iter().map(|&n| Value::Number(n)).collect())
+    }
+
+    /// Context with a single table `t` holding the given numeric columns
+    pub fn table_ctx(columns: &[(&str, &[f64])]) -> EvalContext {
+        let table: HashMap<String, Vec<Value>> = columns
+            .iter()
+            .map(|(name, values)| {
+                let column = values.iter().map(|&n| Value::Number(n)).collect();
+                ((*name).to_string(), column)
+            })
+            .collect();
+        let mut ctx = EvalContext::new();
+        ctx.tables.insert("t".to_string(), table);
+        ctx
+    }
+}
@@ -0,0 +1,103 @@
+//! TAKE and DROP array functions
+
+// Count casts: f64 row counts to usize, clamped to the array length.
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+
+use super::{collect_columns, columns_to_value};
+use crate::core::array_calculator::evaluator::{
+    evaluate, require_args, EvalContext, EvalError, Expr, Value,
+};
+
+/// Evaluate TAKE function - keeps the first n rows, or the last |n| if negative
+/// TAKE(array, n)
+pub fn eval_take(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    slice_rows("TAKE", args, ctx, |len, n, from_end| {
+        if from_end {
+            len - n..len
+        } else {
+            0..n
+        }
+    })
+}
+
+/// Evaluate DROP function - removes the first n rows, or the last |n| if negative
+/// DROP(array, n)
+pub fn eval_drop(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    slice_rows("DROP", args, ctx, |len, n, from_end| {
+        if from_end {
+            0..len - n
+        } else {
+            n..len
+        }
+    })
+}
+
+/// Apply a row range to every column; `n` is already clamped to the row count
+fn slice_rows(
+    func: &str,
+    args: &[Expr],
+    ctx: &EvalContext,
+    rows: impl Fn(usize, usize, bool) -> std::ops::Range<usize>,
+) -> Result<Value, EvalError> {
+    require_args(func, args, 2)?;
+    let columns = collect_columns(func, &args[0], ctx)?;
+    let n = evaluate(&args[1], ctx)?
+        .as_number()
+        .ok_or_else(|| EvalError::new(format!("{func}: n must be a number")))?
+        .trunc();
+    let len = columns.first().map_or(0, Vec::len);
+    let range = rows(len, (n.abs() as usize).min(len), n < 0.0);
+    Ok(columns_to_value(
+        columns
+            .into_iter()
+            .map(|col| col[range.clone()].to_vec())
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::eval;
+    use super::super::fixtures::{nums, table_ctx};
+    use crate::core::array_calculator::evaluator::{EvalContext, Value};
+
+    fn slice_ctx() -> EvalContext {
+        table_ctx(&[("v", &[1.0, 2.0, 3.0, 4.0, 5.0])])
+    }
+
+    #[test]
+    fn test_take() {
+        let ctx = slice_ctx();
+        assert_eq!(eval("TAKE(t.v, 2)", &ctx).unwrap(), nums(&[1.0, 2.0]));
+        assert_eq!(eval("TAKE(t.v, -2)", &ctx).unwrap(), nums(&[4.0, 5.0]));
+        assert_eq!(eval("SUM(TAKE(t.v, 3))", &ctx).unwrap(), Value::Number(6.0));
+    }
+
+    #[test]
+    fn test_drop() {
+        let ctx = slice_ctx();
+        assert_eq!(eval("DROP(t.v, 2)", &ctx).unwrap(), nums(&[3.0, 4.0, 5.0]));
+        assert_eq!(eval("DROP(t.v, -2)", &ctx).unwrap(), nums(&[1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_take_drop_clamp() {
+        let ctx = slice_ctx();
+        assert_eq!(
+            eval("TAKE(t.v, 10)", &ctx).unwrap(),
+            nums(&[1.0, 2.0, 3.0, 4.0, 5.0])
+        );
+        assert_eq!(eval("TAKE(t.v, 0)", &ctx).unwrap(), nums(&[]));
+        assert_eq!(eval("DROP(t.v, 10)", &ctx).unwrap(), nums(&[]));
+        assert_eq!(eval("DROP(t.v, -10)", &ctx).unwrap(), nums(&[]));
+    }
+
+    #[test]
+    fn test_take_multi_column() {
+        let ctx = slice_ctx();
+        assert_eq!(
+            eval("TAKE(HSTACK(t.v, t.v), -1)", &ctx).unwrap(),
+            Value::Array(vec![nums(&[5.0]), nums(&[5.0])])
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! HSTACK and VSTACK array functions
+
+use super::{check_rectangular, collect_columns, columns_to_value};
+use crate::core::array_calculator::evaluator::{EvalContext, EvalError, Expr, Value};
+
+/// Evaluate HSTACK function - places ranges side by side as columns
+/// HSTACK(range1, [range2], ...)
+pub fn eval_hstack(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    if args.is_empty() {
+        return Err(EvalError::new("HSTACK requires at least one array"));
+    }
+    let mut columns = Vec::new();
+    for arg in args {
+        columns.extend(collect_columns("HSTACK", arg, ctx)?);
+    }
+    Ok(columns_to_value(check_rectangular("HSTACK", columns)?))
+}
+
+/// Evaluate VSTACK function - appends ranges end-to-end
+/// VSTACK(range1, [range2], ...)
+///
+/// Every range must have the same number of columns, and values stacked into a
+/// column must share a type (numbers, text or booleans; empty cells fit anywhere).
+pub fn eval_vstack(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    if args.is_empty() {
+        return Err(EvalError::new("VSTACK requires at least one array"));
+    }
+    let mut stacked: Vec<Vec<Value>> = Vec::new();
+    for (n, arg) in args.iter().enumerate() {
+        let columns = collect_columns("VSTACK", arg, ctx)?;
+        if n == 0 {
+            stacked = columns;
+            continue;
+        }
+        if columns.len() != stacked.len() {
+            return Err(EvalError::new(format!(
+                "VSTACK: array {} has {} columns but array 1 has {}",
+                n + 1,
+                columns.len(),
+                stacked.len()
+            )));
+        }
+        for (target, column) in stacked.iter_mut().zip(columns) {
+            if let (Some(have), Some(got)) = (type_of(target), type_of(&column)) {
+                if have != got {
+                    return Err(EvalError::new(format!(
+                        "VSTACK: cannot stack {got} values onto {have} values (array {})",
+                        n + 1
+                    )));
+                }
+            }
+            target.extend(column);
+        }
+    }
+    Ok(columns_to_value(stacked))
+}
+
+/// Type name of the first non-empty value in a column
+fn type_of(column: &[Value]) -> Option<&'static str> {
+    column.iter().find_map(|v| match v {
+        Value::Number(_) => Some("number"),
+        Value::Text(_) => Some("text"),
+        Value::Boolean(_) => Some("boolean"),
+        Value::Array(_) => Some("array"),
+        Value::Lambda { .. } => Some("lambda"),
+        Value::Error(_) => Some("error"),
+        Value::Null => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::eval;
+    use super::super::fixtures::{nums, table_ctx};
+    use crate::core::array_calculator::evaluator::{EvalContext, Value};
+
+    fn stack_ctx() -> EvalContext {
+        let mut ctx = table_ctx(&[
+            ("a", &[1.0, 2.0]),
+            ("b", &[3.0, 4.0]),
+            ("c", &[5.0, 6.0, 7.0]),
+        ]);
+        ctx.tables.get_mut("t").unwrap().insert(
+            "names".to_string(),
+            vec![Value::Text("x".to_string()), Value::Text("y".to_string())],
+        );
+        ctx
+    }
+
+    #[test]
+    fn test_vstack_concatenates() {
+        let ctx = stack_ctx();
+        assert_eq!(
+            eval("VSTACK(t.a, t.c)", &ctx).unwrap(),
+            nums(&[1.0, 2.0, 5.0, 6.0, 7.0])
+        );
+        assert_eq!(
+            eval("SUM(VSTACK(t.a, t.c))", &ctx).unwrap(),
+            Value::Number(21.0)
+        );
+    }
+
+    #[test]
+    fn test_vstack_rejects_mixed_types() {
+        let ctx = stack_ctx();
+        let err = eval("VSTACK(t.a, t.names)", &ctx).unwrap_err();
+        assert!(err.message.contains("cannot stack text"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_hstack_builds_columns() {
+        let ctx = stack_ctx();
+        assert_eq!(
+            eval("HSTACK(t.a, t.b)", &ctx).unwrap(),
+            Value::Array(vec![nums(&[1.0, 2.0]), nums(&[3.0, 4.0]),])
+        );
+        assert_eq!(
+            eval("CHOOSECOLS(HSTACK(t.a, t.b), 2)", &ctx).unwrap(),
+            nums(&[3.0, 4.0])
+        );
+        assert!(eval("HSTACK(t.a, t.c)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_vstack_multi_column() {
+        let ctx = stack_ctx();
+        assert_eq!(
+            eval("VSTACK(HSTACK(t.a, t.b), HSTACK(t.b, t.a))", &ctx).unwrap(),
+            Value::Array(vec![
+                nums(&[1.0, 2.0, 3.0, 4.0]),
+                nums(&[3.0, 4.0, 1.0, 2.0]),
+            ])
+        );
+        assert!(eval("VSTACK(HSTACK(t.a, t.b), t.a)", &ctx).is_err());
+    }
+}
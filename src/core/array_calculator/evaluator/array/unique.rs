@@ -3,29 +3,83 @@
 // Unique casts: count of unique values (usize) to f64 (bounded by array length).
 #![allow(clippy::cast_precision_loss)]
 
+use super::{collect_columns, columns_to_value};
 use crate::core::array_calculator::evaluator::{
-    collect_values_as_vec, require_args, EvalContext, EvalError, Expr, Value,
+    collect_values_as_vec, evaluate, require_args, require_args_range, EvalContext, EvalError,
+    Expr, Value,
 };
+use std::collections::{HashMap, HashSet};
 
-/// Evaluate UNIQUE function - returns unique values from an array
+/// Evaluate UNIQUE function - returns unique rows (or columns) of an array
+/// UNIQUE(array, [`by_col`], [`exactly_once`])
+///
+/// With `by_col` TRUE whole columns are compared instead of rows. With
+/// `exactly_once` TRUE only entries that occur a single time are returned.
 pub fn eval_unique(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
-    require_args("UNIQUE", args, 1)?;
-    let values = collect_values_as_vec(&args[0], ctx)?;
-    let mut seen = Vec::new();
-    for v in values {
-        let text = v.as_text();
-        if !seen.iter().any(|s: &Value| s.as_text() == text) {
-            seen.push(v);
+    require_args_range("UNIQUE", args, 1, 3)?;
+    let columns = collect_columns("UNIQUE", &args[0], ctx)?;
+    let by_col = match args.get(1) {
+        Some(expr) => evaluate(expr, ctx)?.is_truthy(),
+        None => false,
+    };
+    let exactly_once = match args.get(2) {
+        Some(expr) => evaluate(expr, ctx)?.is_truthy(),
+        None => false,
+    };
+
+    let width = columns.len();
+    let entries = if by_col {
+        columns
+    } else {
+        transpose(columns, 0)
+    };
+    let keys: Vec<Vec<String>> = entries
+        .iter()
+        .map(|entry| entry.iter().map(Value::as_text).collect())
+        .collect();
+    let mut counts: HashMap<&[String], usize> = HashMap::new();
+    for key in &keys {
+        *counts.entry(key).or_default() += 1;
+    }
+
+    let mut emitted: HashSet<&[String]> = HashSet::new();
+    let kept: Vec<Vec<Value>> = entries
+        .into_iter()
+        .zip(&keys)
+        .filter(|(_, key)| {
+            if exactly_once {
+                counts[key.as_slice()] == 1
+            } else {
+                emitted.insert(key)
+            }
+        })
+        .map(|(entry, _)| entry)
+        .collect();
+
+    Ok(columns_to_value(if by_col {
+        kept
+    } else {
+        transpose(kept, width)
+    }))
+}
+
+/// Swap rows and columns; `width` is the result width used when there are no rows
+fn transpose(grid: Vec<Vec<Value>>, width: usize) -> Vec<Vec<Value>> {
+    let width = grid.first().map_or(width, Vec::len);
+    let mut out = vec![Vec::with_capacity(grid.len()); width];
+    for line in grid {
+        for (target, value) in out.iter_mut().zip(line) {
+            target.push(value);
         }
     }
-    Ok(Value::Array(seen))
+    out
 }
 
 /// Evaluate COUNTUNIQUE function - returns count of unique values
 pub fn eval_countunique(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
     require_args("COUNTUNIQUE", args, 1)?;
     let values = collect_values_as_vec(&args[0], ctx)?;
-    let mut seen = std::collections::HashSet::new();
+    let mut seen = HashSet::new();
     for v in values {
         seen.insert(v.as_text());
     }
@@ -81,6 +135,77 @@ mod tests {
             Value::Number(3.0)
         );
     }
+
+    fn duplicates_ctx() -> EvalContext {
+        let mut ctx = EvalContext::new();
+        let mut table = HashMap::new();
+        table.insert(
+            "data".to_string(),
+            ["A", "B", "A", "C", "B", "D"]
+                .iter()
+                .map(|s| Value::Text((*s).to_string()))
+                .collect(),
+        );
+        table.insert(
+            "qty".to_string(),
+            [1.0, 2.0, 1.0, 3.0, 5.0, 4.0]
+                .iter()
+                .map(|&n| Value::Number(n))
+                .collect(),
+        );
+        ctx.tables.insert("t".to_string(), table);
+        ctx
+    }
+
+    fn texts(values: &[&str]) -> Value {
+        Value::Array(
+            values
+                .iter()
+                .map(|s| Value::Text((*s).to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_unique_distinct_vs_exactly_once() {
+        let ctx = duplicates_ctx();
+        assert_eq!(
+            eval("UNIQUE(t.data)", &ctx).unwrap(),
+            texts(&["A", "B", "C", "D"])
+        );
+        assert_eq!(
+            eval("UNIQUE(t.data, FALSE, TRUE)", &ctx).unwrap(),
+            texts(&["C", "D"])
+        );
+    }
+
+    #[test]
+    fn test_unique_rows_of_range() {
+        let ctx = duplicates_ctx();
+        // Rows (A,1) repeat; (B,2) and (B,5) differ, so both are kept
+        let result = eval("UNIQUE(HSTACK(t.data, t.qty))", &ctx).unwrap();
+        let Value::Array(columns) = result else {
+            panic!("Expected array");
+        };
+        assert_eq!(columns[0], texts(&["A", "B", "C", "B", "D"]));
+
+        let once = eval("UNIQUE(HSTACK(t.data, t.qty), FALSE, TRUE)", &ctx).unwrap();
+        let Value::Array(columns) = once else {
+            panic!("Expected array");
+        };
+        assert_eq!(columns[0], texts(&["B", "C", "B", "D"]));
+    }
+
+    #[test]
+    fn test_unique_by_col() {
+        let ctx = duplicates_ctx();
+        let result = eval("UNIQUE(HSTACK(t.qty, t.data, t.qty), TRUE)", &ctx).unwrap();
+        let Value::Array(columns) = result else {
+            panic!("Expected array");
+        };
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[1], eval("t.data", &ctx).unwrap());
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,165 @@
+//! WRAPROWS and WRAPCOLS array functions
+
+// Size casts: f64 widths/heights to usize after validating they are positive.
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+
+use super::{collect_columns, columns_to_value};
+use crate::core::array_calculator::evaluator::{
+    evaluate, require_args_range, ErrorKind, EvalContext, EvalError, Expr, Value,
+};
+
+/// Evaluate WRAPROWS function - fills rows of `width` values from a 1D array
+/// WRAPROWS(array, width, [`pad_with`])
+pub fn eval_wraprows(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let (values, width, pad) = wrap_args("WRAPROWS", args, ctx)?;
+    let rows = values.len().div_ceil(width);
+    let columns = (0..width)
+        .map(|c| {
+            (0..rows)
+                .map(|r| {
+                    values
+                        .get(r * width + c)
+                        .cloned()
+                        .unwrap_or_else(|| pad.clone())
+                })
+                .collect()
+        })
+        .collect();
+    Ok(columns_to_value(columns))
+}
+
+/// Evaluate WRAPCOLS function - fills columns of `height` values from a 1D array
+/// WRAPCOLS(array, height, [`pad_with`])
+pub fn eval_wrapcols(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let (values, height, pad) = wrap_args("WRAPCOLS", args, ctx)?;
+    let columns = values
+        .chunks(height)
+        .map(|chunk| {
+            let mut col = chunk.to_vec();
+            col.resize(height, pad.clone());
+            col
+        })
+        .collect();
+    Ok(columns_to_value(columns))
+}
+
+/// Parse the 1D array, wrap size and pad value (blank when omitted)
+fn wrap_args(
+    func: &str,
+    args: &[Expr],
+    ctx: &EvalContext,
+) -> Result<(Vec<Value>, usize, Value), EvalError> {
+    require_args_range(func, args, 2, 3)?;
+    let mut columns = collect_columns(func, &args[0], ctx)?;
+    if columns.len() != 1 {
+        return Err(EvalError::new(format!(
+            "{func}: array must be one-dimensional, got {} columns",
+            columns.len()
+        )));
+    }
+    // A non-numeric size is #VALUE!, a size below 1 (or NaN/infinite) is #NUM!
+    let size = match evaluate(&args[1], ctx)? {
+        Value::Error(kind) => return Err(kind.into()),
+        size => size.as_number().ok_or(ErrorKind::Value)?.trunc(),
+    };
+    if !size.is_finite() || size < 1.0 {
+        return Err(ErrorKind::Num.into());
+    }
+    let pad = match args.get(2) {
+        Some(expr) => evaluate(expr, ctx)?,
+        None => Value::Null,
+    };
+    let values = columns.remove(0);
+    // Wider than the array only adds padding, so never allocate past its length
+    let size = (size as usize).min(values.len().max(1));
+    Ok((values, size, pad))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::eval;
+    use super::super::fixtures::{nums, table_ctx};
+    use crate::core::array_calculator::evaluator::{ErrorKind, EvalContext, Value};
+
+    fn months_ctx(count: u32) -> EvalContext {
+        let months: Vec<f64> = (1..=count).map(f64::from).collect();
+        table_ctx(&[("m", &months)])
+    }
+
+    #[test]
+    fn test_wraprows_twelve_by_three() {
+        let ctx = months_ctx(12);
+        // 4 rows of width 3, stored as 3 columns of 4 rows
+        assert_eq!(
+            eval("WRAPROWS(t.m, 3)", &ctx).unwrap(),
+            Value::Array(vec![
+                nums(&[1.0, 4.0, 7.0, 10.0]),
+                nums(&[2.0, 5.0, 8.0, 11.0]),
+                nums(&[3.0, 6.0, 9.0, 12.0]),
+            ])
+        );
+        assert_eq!(
+            eval("CHOOSEROWS(WRAPROWS(t.m, 3), 4)", &ctx).unwrap(),
+            Value::Array(vec![nums(&[10.0]), nums(&[11.0]), nums(&[12.0])])
+        );
+    }
+
+    #[test]
+    fn test_wrapcols() {
+        let ctx = months_ctx(12);
+        assert_eq!(
+            eval("WRAPCOLS(t.m, 6)", &ctx).unwrap(),
+            Value::Array(vec![
+                nums(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+                nums(&[7.0, 8.0, 9.0, 10.0, 11.0, 12.0]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_wrap_padding() {
+        let ctx = months_ctx(5);
+        assert_eq!(
+            eval("WRAPCOLS(t.m, 3, 0)", &ctx).unwrap(),
+            Value::Array(vec![nums(&[1.0, 2.0, 3.0]), nums(&[4.0, 5.0, 0.0])])
+        );
+        assert_eq!(
+            eval("WRAPROWS(t.m, 3)", &ctx).unwrap(),
+            Value::Array(vec![
+                nums(&[1.0, 4.0]),
+                nums(&[2.0, 5.0]),
+                Value::Array(vec![Value::Number(3.0), Value::Null]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_wrap_invalid() {
+        let ctx = months_ctx(4);
+        assert!(eval("WRAPROWS(t.m, 0)", &ctx).is_err());
+        assert!(eval("WRAPCOLS(HSTACK(t.m, t.m), 2)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_wrap_size_errors() {
+        let ctx = months_ctx(4);
+        let kind = |formula: &str| eval(formula, &ctx).unwrap_err().kind;
+        assert_eq!(kind("WRAPROWS(t.m, 0)"), Some(ErrorKind::Num));
+        assert_eq!(kind("WRAPCOLS(t.m, -3)"), Some(ErrorKind::Num));
+        assert_eq!(kind("WRAPCOLS(t.m, 1/0)"), Some(ErrorKind::DivZero));
+        assert_eq!(kind("WRAPROWS(t.m, \"wide\")"), Some(ErrorKind::Value));
+    }
+
+    #[test]
+    fn test_wrap_size_clamped_to_array_length() {
+        let ctx = months_ctx(4);
+        assert_eq!(
+            eval("WRAPCOLS(t.m, 1e18)", &ctx).unwrap(),
+            nums(&[1.0, 2.0, 3.0, 4.0])
+        );
+        assert_eq!(
+            eval("WRAPROWS(t.m, 1e18)", &ctx).unwrap(),
+            Value::Array(vec![nums(&[1.0]), nums(&[2.0]), nums(&[3.0]), nums(&[4.0])])
+        );
+    }
+}
@@ -14,6 +14,7 @@ use super::super::{
     collect_values_as_vec, evaluate, matches_criteria, require_args_range, EvalContext, EvalError,
     Expr, Value,
 };
+use super::criteria_mask;
 
 /// Evaluate AVERAGEIF function
 pub fn eval_averageif(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
@@ -51,16 +52,12 @@ pub fn eval_averageifs(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalEr
         ));
     }
     let avg_range = collect_values_as_vec(&args[0], ctx)?;
-    let mut matches = vec![true; avg_range.len()];
-    for pair in args[1..].chunks(2) {
-        let criteria_range = collect_values_as_vec(&pair[0], ctx)?;
-        let criteria = evaluate(&pair[1], ctx)?;
-        for (i, val) in criteria_range.iter().enumerate() {
-            if i < matches.len() && !matches_criteria(val, &criteria) {
-                matches[i] = false;
-            }
-        }
-    }
+    let matches = criteria_mask(
+        "AVERAGEIFS",
+        ("average_range", avg_range.len()),
+        &args[1..],
+        ctx,
+    )?;
     let matching: Vec<f64> = avg_range
         .iter()
         .enumerate()
@@ -239,4 +236,14 @@ mod tests {
         let result = calculator.calculate_all().expect("Should calculate");
         assert_eq!(result.scalars.get("result").unwrap().value, Some(4.0));
     }
+
+    #[test]
+    fn test_averageifs_length_mismatch() {
+        let ctx = super::super::mismatched_ctx();
+        let err = eval("AVERAGEIFS(t.amount, s.flag, \"Y\")", &ctx).unwrap_err();
+        assert_eq!(
+            err.message,
+            "AVERAGEIFS: criteria_range1 has 2 values but average_range has 3"
+        );
+    }
 }
@@ -14,6 +14,7 @@ use super::super::{
     collect_values_as_vec, evaluate, matches_criteria, require_args, EvalContext, EvalError, Expr,
     Value,
 };
+use super::criteria_mask;
 
 /// Evaluate COUNTIF function
 pub fn eval_countif(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
@@ -35,16 +36,12 @@ pub fn eval_countifs(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalErro
         ));
     }
     let first_range = collect_values_as_vec(&args[0], ctx)?;
-    let mut matches = vec![true; first_range.len()];
-    for pair in args.chunks(2) {
-        let criteria_range = collect_values_as_vec(&pair[0], ctx)?;
-        let criteria = evaluate(&pair[1], ctx)?;
-        for (i, val) in criteria_range.iter().enumerate() {
-            if i < matches.len() && !matches_criteria(val, &criteria) {
-                matches[i] = false;
-            }
-        }
-    }
+    let matches = criteria_mask(
+        "COUNTIFS",
+        ("criteria_range1", first_range.len()),
+        args,
+        ctx,
+    )?;
     let count = matches.iter().filter(|&&m| m).count();
     Ok(Value::Number(count as f64))
 }
@@ -291,4 +288,23 @@ mod tests {
         let result = calculator.calculate_all().expect("Should calculate");
         assert_eq!(result.scalars.get("result").unwrap().value, Some(0.0));
     }
+
+    #[test]
+    fn test_countifs_zero_matches() {
+        let ctx = super::super::mismatched_ctx();
+        assert_eq!(
+            eval("COUNTIFS(t.region, \"East\", t.amount, \">100\")", &ctx).unwrap(),
+            Value::Number(0.0)
+        );
+    }
+
+    #[test]
+    fn test_countifs_length_mismatch() {
+        let ctx = super::super::mismatched_ctx();
+        let err = eval("COUNTIFS(t.region, \"East\", s.flag, \"Y\")", &ctx).unwrap_err();
+        assert_eq!(
+            err.message,
+            "COUNTIFS: criteria_range2 has 2 values but criteria_range1 has 3"
+        );
+    }
 }
@@ -7,9 +7,8 @@
 //! - `MINIFS(min_range`, `criteria_range1`, criteria1, [`criteria_range2`, criteria2], ...)
 //! - `MAXIFS(max_range`, `criteria_range1`, criteria1, [`criteria_range2`, criteria2], ...)
 
-use super::super::{
-    collect_values_as_vec, evaluate, matches_criteria, EvalContext, EvalError, Expr, Value,
-};
+use super::super::{collect_values_as_vec, EvalContext, EvalError, Expr, Value};
+use super::criteria_mask;
 
 /// Evaluate MAXIFS function
 pub fn eval_maxifs(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
@@ -19,16 +18,7 @@ pub fn eval_maxifs(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError>
         ));
     }
     let max_range = collect_values_as_vec(&args[0], ctx)?;
-    let mut matches = vec![true; max_range.len()];
-    for pair in args[1..].chunks(2) {
-        let criteria_range = collect_values_as_vec(&pair[0], ctx)?;
-        let criteria = evaluate(&pair[1], ctx)?;
-        for (i, val) in criteria_range.iter().enumerate() {
-            if i < matches.len() && !matches_criteria(val, &criteria) {
-                matches[i] = false;
-            }
-        }
-    }
+    let matches = criteria_mask("MAXIFS", ("max_range", max_range.len()), &args[1..], ctx)?;
     let matching: Vec<f64> = max_range
         .iter()
         .enumerate()
@@ -52,16 +42,7 @@ pub fn eval_minifs(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError>
         ));
     }
     let min_range = collect_values_as_vec(&args[0], ctx)?;
-    let mut matches = vec![true; min_range.len()];
-    for pair in args[1..].chunks(2) {
-        let criteria_range = collect_values_as_vec(&pair[0], ctx)?;
-        let criteria = evaluate(&pair[1], ctx)?;
-        for (i, val) in criteria_range.iter().enumerate() {
-            if i < matches.len() && !matches_criteria(val, &criteria) {
-                matches[i] = false;
-            }
-        }
-    }
+    let matches = criteria_mask("MINIFS", ("min_range", min_range.len()), &args[1..], ctx)?;
     let matching: Vec<f64> = min_range
         .iter()
         .enumerate()
@@ -8,13 +8,18 @@
 //!
 //! All functions support comparison operators in criteria strings:
 //! `>`, `<`, `>=`, `<=`, `<>`, `=`
+//!
+//! The multi-criteria functions require every criteria range to be the same
+//! length as the target range and report the first mismatched pair.
 
 mod averageif;
 mod countif;
 mod minmax;
 mod sumif;
 
-use super::{EvalContext, EvalError, Expr, Value};
+use super::{
+    collect_values_as_vec, evaluate, matches_criteria, EvalContext, EvalError, Expr, Value,
+};
 
 /// Try to evaluate a conditional aggregation function. Returns None if function not recognized.
 pub fn try_evaluate(
@@ -40,3 +45,68 @@ pub fn try_evaluate(
 
     Ok(Some(result))
 }
+
+/// Evaluate `criteria_range, criteria` pairs into a per-row match mask
+///
+/// `target` names the range the mask applies to (e.g. `sum_range`) and its length.
+/// Every criteria range must have exactly that length.
+fn criteria_mask(
+    func: &str,
+    target: (&str, usize),
+    pairs: &[Expr],
+    ctx: &EvalContext,
+) -> Result<Vec<bool>, EvalError> {
+    let (target_name, target_len) = target;
+    let mut matches = vec![true; target_len];
+    for (n, pair) in pairs.chunks(2).enumerate() {
+        let criteria_range = collect_values_as_vec(&pair[0], ctx)?;
+        if criteria_range.len() != target_len {
+            return Err(EvalError::new(format!(
+                "{func}: criteria_range{} has {} values but {target_name} has {target_len}",
+                n + 1,
+                criteria_range.len()
+            )));
+        }
+        let criteria = evaluate(&pair[1], ctx)?;
+        for (matched, val) in matches.iter_mut().zip(&criteria_range) {
+            if !matches_criteria(val, &criteria) {
+                *matched = false;
+            }
+        }
+    }
+    Ok(matches)
+}
+
+/// Context with table `t` (3 rows of `region`, `amount`) and table `s` (2 rows
+/// of `flag`), for criteria-range length mismatch tests
+#[cfg(test)]
+fn mismatched_ctx() -> EvalContext {
+    use std::collections::HashMap;
+
+    let mut ctx = EvalContext::new();
+    let mut table = HashMap::new();
+    table.insert(
+        "region".to_string(),
+        vec![
+            Value::Text("East".to_string()),
+            Value::Text("West".to_string()),
+            Value::Text("East".to_string()),
+        ],
+    );
+    table.insert(
+        "amount".to_string(),
+        vec![
+            Value::Number(10.0),
+            Value::Number(20.0),
+            Value::Number(30.0),
+        ],
+    );
+    ctx.tables.insert("t".to_string(), table);
+    let mut short = HashMap::new();
+    short.insert(
+        "flag".to_string(),
+        vec![Value::Text("Y".to_string()), Value::Text("Y".to_string())],
+    );
+    ctx.tables.insert("s".to_string(), short);
+    ctx
+}
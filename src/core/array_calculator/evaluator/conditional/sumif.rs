@@ -11,6 +11,7 @@ use super::super::{
     collect_values_as_vec, evaluate, matches_criteria, require_args_range, EvalContext, EvalError,
     Expr, Value,
 };
+use super::criteria_mask;
 
 /// Evaluate SUMIF function
 pub fn eval_sumif(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
@@ -43,16 +44,7 @@ pub fn eval_sumifs(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError>
         ));
     }
     let sum_range = collect_values_as_vec(&args[0], ctx)?;
-    let mut matches = vec![true; sum_range.len()];
-    for pair in args[1..].chunks(2) {
-        let criteria_range = collect_values_as_vec(&pair[0], ctx)?;
-        let criteria = evaluate(&pair[1], ctx)?;
-        for (i, val) in criteria_range.iter().enumerate() {
-            if i < matches.len() && !matches_criteria(val, &criteria) {
-                matches[i] = false;
-            }
-        }
-    }
+    let matches = criteria_mask("SUMIFS", ("sum_range", sum_range.len()), &args[1..], ctx)?;
     let total: f64 = sum_range
         .iter()
         .enumerate()
@@ -308,4 +300,23 @@ mod tests {
         let result = calculator.calculate_all().expect("Should calculate");
         assert_eq!(result.scalars.get("result").unwrap().value, Some(30.0));
     }
+
+    #[test]
+    fn test_sumifs_zero_matches() {
+        let ctx = super::super::mismatched_ctx();
+        assert_eq!(
+            eval("SUMIFS(t.amount, t.region, \"North\")", &ctx).unwrap(),
+            Value::Number(0.0)
+        );
+    }
+
+    #[test]
+    fn test_sumifs_length_mismatch() {
+        let ctx = super::super::mismatched_ctx();
+        let err = eval("SUMIFS(t.amount, t.region, \"East\", s.flag, \"Y\")", &ctx).unwrap_err();
+        assert_eq!(
+            err.message,
+            "SUMIFS: criteria_range2 has 2 values but sum_range has 3"
+        );
+    }
 }
@@ -0,0 +1,167 @@
+//! Unit conversion: CONVERT
+//!
+//! Supports time, mass and length units (Excel's unit names, case-sensitive)
+//! plus scale units for money and counts: a scale prefix (`k`, `M`, `B`)
+//! optionally followed by a currency code, e.g. `kUSD` or `MEUR`. Plain
+//! scale units convert to `1` (base units); currencies only convert to the
+//! same currency at another scale, never to each other.
+
+use super::{evaluate, EvalContext, EvalError, Expr, Value};
+
+/// What a unit measures; conversion is only defined within one dimension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dimension<'a> {
+    Time,
+    Mass,
+    Length,
+    /// Counts and money; `Some(code)` for a currency
+    Scale(Option<&'a str>),
+}
+
+impl std::fmt::Display for Dimension<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Time => write!(f, "time"),
+            Self::Mass => write!(f, "mass"),
+            Self::Length => write!(f, "length"),
+            Self::Scale(None) => write!(f, "scale"),
+            Self::Scale(Some(code)) => write!(f, "{code}"),
+        }
+    }
+}
+
+/// Fixed units and their size in the dimension's base unit (seconds, grams, meters)
+const UNITS: &[(&str, Dimension<'static>, f64)] = &[
+    ("sec", Dimension::Time, 1.0),
+    ("s", Dimension::Time, 1.0),
+    ("mn", Dimension::Time, 60.0),
+    ("min", Dimension::Time, 60.0),
+    ("hr", Dimension::Time, 3_600.0),
+    ("day", Dimension::Time, 86_400.0),
+    ("d", Dimension::Time, 86_400.0),
+    ("wk", Dimension::Time, 604_800.0),
+    ("yr", Dimension::Time, 31_557_600.0), // 365.25 days, as in Excel
+    ("mg", Dimension::Mass, 0.001),
+    ("g", Dimension::Mass, 1.0),
+    ("kg", Dimension::Mass, 1_000.0),
+    ("ozm", Dimension::Mass, 28.349_523_125),
+    ("lbm", Dimension::Mass, 453.592_37),
+    ("ton", Dimension::Mass, 907_184.74), // short ton (2000 lbm)
+    ("mm", Dimension::Length, 0.001),
+    ("cm", Dimension::Length, 0.01),
+    ("m", Dimension::Length, 1.0),
+    ("km", Dimension::Length, 1_000.0),
+    ("in", Dimension::Length, 0.0254),
+    ("ft", Dimension::Length, 0.3048),
+    ("yd", Dimension::Length, 0.9144),
+    ("mi", Dimension::Length, 1_609.344),
+    ("Nmi", Dimension::Length, 1_852.0),
+];
+
+/// Scale prefixes for counts and currencies
+const SCALES: &[(&str, f64)] = &[("k", 1e3), ("M", 1e6), ("B", 1e9), ("bn", 1e9)];
+
+/// Try to evaluate a conversion function. Returns None if function not recognized.
+pub fn try_evaluate(
+    name: &str,
+    args: &[Expr],
+    ctx: &EvalContext,
+) -> Result<Option<Value>, EvalError> {
+    let result = match name {
+        "CONVERT" => eval_convert(args, ctx)?,
+        _ => return Ok(None),
+    };
+    Ok(Some(result))
+}
+
+/// CONVERT(number, `from_unit`, `to_unit`)
+fn eval_convert(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let number = evaluate(&args[0], ctx)?
+        .as_number()
+        .ok_or_else(|| EvalError::new("CONVERT requires a number"))?;
+    let from = evaluate(&args[1], ctx)?.as_text();
+    let to = evaluate(&args[2], ctx)?.as_text();
+
+    let (from_dim, from_size) = unit(&from)?;
+    let (to_dim, to_size) = unit(&to)?;
+    if from_dim != to_dim {
+        return Err(EvalError::new(format!(
+            "CONVERT: cannot convert '{from}' ({from_dim}) to '{to}' ({to_dim})"
+        )));
+    }
+    Ok(Value::Number(number * from_size / to_size))
+}
+
+/// Dimension and size of a unit name
+fn unit(name: &str) -> Result<(Dimension<'_>, f64), EvalError> {
+    if let Some(&(_, dim, size)) = UNITS.iter().find(|(n, _, _)| *n == name) {
+        return Ok((dim, size));
+    }
+    if name.is_empty() || name == "1" {
+        return Ok((Dimension::Scale(None), 1.0));
+    }
+    if is_currency(name) {
+        return Ok((Dimension::Scale(Some(name)), 1.0));
+    }
+    for &(prefix, size) in SCALES {
+        match name.strip_prefix(prefix) {
+            Some("") => return Ok((Dimension::Scale(None), size)),
+            Some(code) if is_currency(code) => return Ok((Dimension::Scale(Some(code)), size)),
+            _ => {},
+        }
+    }
+    Err(EvalError::new(format!("CONVERT: unknown unit '{name}'")))
+}
+
+/// Whether `code` looks like an ISO currency code (`USD`, `EUR`, ...)
+fn is_currency(code: &str) -> bool {
+    code.len() == 3 && code.chars().all(|c| c.is_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::eval;
+    use super::*;
+
+    fn convert(formula: &str) -> f64 {
+        eval(formula, &EvalContext::new())
+            .unwrap()
+            .as_number()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_convert_time() {
+        assert!((convert("CONVERT(36, \"hr\", \"day\")") - 1.5).abs() < 1e-12);
+        assert!((convert("CONVERT(2, \"day\", \"hr\")") - 48.0).abs() < 1e-12);
+        assert!((convert("CONVERT(90, \"mn\", \"hr\")") - 1.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_convert_mass_and_length() {
+        assert!((convert("CONVERT(1, \"lbm\", \"kg\")") - 0.453_592_37).abs() < 1e-12);
+        assert!((convert("CONVERT(1, \"mi\", \"km\")") - 1.609_344).abs() < 1e-12);
+        assert!((convert("CONVERT(12, \"in\", \"ft\")") - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_convert_scale_and_currency() {
+        assert!((convert("CONVERT(2.5, \"k\", \"1\")") - 2_500.0).abs() < 1e-9);
+        assert!((convert("CONVERT(1500, \"1\", \"k\")") - 1.5).abs() < 1e-12);
+        assert!((convert("CONVERT(3, \"MUSD\", \"kUSD\")") - 3_000.0).abs() < 1e-9);
+        assert!((convert("CONVERT(4, \"kEUR\", \"EUR\")") - 4_000.0).abs() < 1e-9);
+        // Codes starting with a scale letter are still plain currencies
+        assert!((convert("CONVERT(2, \"kBRL\", \"BRL\")") - 2_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_convert_rejects_incompatible_units() {
+        let ctx = EvalContext::new();
+        let err = eval("CONVERT(1, \"hr\", \"kg\")", &ctx).unwrap_err();
+        assert!(err.message.contains("(time)"), "{}", err.message);
+        assert!(eval("CONVERT(1, \"kUSD\", \"kEUR\")", &ctx).is_err());
+        assert!(eval("CONVERT(1, \"k\", \"kUSD\")", &ctx).is_err());
+        let err = eval("CONVERT(1, \"parsec\", \"m\")", &ctx).unwrap_err();
+        assert!(err.message.contains("unknown unit"), "{}", err.message);
+    }
+}
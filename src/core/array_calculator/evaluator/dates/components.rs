@@ -1,4 +1,5 @@
-//! Time component extraction functions: WEEKDAY, HOUR, MINUTE, SECOND (enterprise only)
+//! Time component extraction functions: WEEKDAY, WEEKNUM, ISOWEEKNUM, HOUR, MINUTE, SECOND
+//! (enterprise only)
 
 use super::{
     evaluate, parse_date_value, require_args, require_args_range, Datelike, EvalContext, EvalError,
@@ -39,6 +40,15 @@ pub fn try_evaluate(
             Value::Number(result)
         },
 
+        "WEEKNUM" => eval_weeknum(args, ctx)?,
+
+        "ISOWEEKNUM" => {
+            require_args(name, args, 1)?;
+            let val = evaluate(&args[0], ctx)?;
+            let date = parse_date_value(&val)?;
+            Value::Number(f64::from(date.iso_week().week()))
+        },
+
         "HOUR" => {
             require_args(name, args, 1)?;
             let val = evaluate(&args[0], ctx)?;
@@ -109,13 +119,43 @@ pub fn try_evaluate(
     Ok(Some(result))
 }
 
+/// `WEEKNUM(date, [return_type])`: week of the year, week 1 being the week containing Jan 1
+#[allow(clippy::cast_possible_truncation)] // return_type is a small integer code
+fn eval_weeknum(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    require_args_range("WEEKNUM", args, 1, 2)?;
+    let val = evaluate(&args[0], ctx)?;
+    let return_type = if args.len() > 1 {
+        evaluate(&args[1], ctx)?.as_number().unwrap_or(1.0) as i32
+    } else {
+        1
+    };
+    // Excel WEEKNUM return types (week 1 is the week containing Jan 1):
+    // 1 (default): weeks begin on Sunday
+    // 2: weeks begin on Monday
+    let week_start = match return_type {
+        1 => 0,
+        2 => 1,
+        _ => {
+            return Err(EvalError::new(format!(
+                "WEEKNUM: return_type must be 1 or 2, got {return_type}"
+            )))
+        },
+    };
+
+    let date = parse_date_value(&val)?;
+    let jan1 = date.with_ordinal(1).unwrap_or(date);
+    // Days of week 1 that fall before Jan 1
+    let lead = (jan1.weekday().num_days_from_sunday() + 7 - week_start) % 7;
+    Ok(Value::Number(f64::from((date.ordinal0() + lead) / 7 + 1)))
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::float_cmp)] // Exact float comparison validated against Excel/Gnumeric/R
     use super::super::super::tests::eval;
     use super::super::{EvalContext, Value};
     use crate::core::array_calculator::ArrayCalculator;
-    use crate::types::{ParsedModel, Variable};
+    use crate::types::{Column, ColumnValue, ParsedModel, Table, Variable};
 
     #[test]
     fn test_weekday() {
@@ -138,6 +178,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_weeknum() {
+        let ctx = EvalContext::new();
+        // Excel reference: 2012-03-09 is week 10 (Sunday start), 11 (Monday start)
+        assert_eq!(
+            eval("WEEKNUM(\"2012-03-09\")", &ctx).unwrap(),
+            Value::Number(10.0)
+        );
+        assert_eq!(
+            eval("WEEKNUM(\"2012-03-09\", 2)", &ctx).unwrap(),
+            Value::Number(11.0)
+        );
+        // 2024-01-07 is a Sunday: starts week 2 for type 1, still week 1 for type 2
+        assert_eq!(
+            eval("WEEKNUM(\"2024-01-07\")", &ctx).unwrap(),
+            Value::Number(2.0)
+        );
+        assert_eq!(
+            eval("WEEKNUM(\"2024-01-07\", 2)", &ctx).unwrap(),
+            Value::Number(1.0)
+        );
+        // Partial weeks count, so a year can reach week 53 (serial 45657 = 2024-12-31)
+        assert_eq!(eval("WEEKNUM(45657)", &ctx).unwrap(), Value::Number(53.0));
+        assert!(eval("WEEKNUM(\"2024-01-07\", 3)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_isoweeknum() {
+        let ctx = EvalContext::new();
+        assert_eq!(
+            eval("ISOWEEKNUM(\"2012-03-09\")", &ctx).unwrap(),
+            Value::Number(10.0)
+        );
+        // Early January can belong to the previous ISO year, late December to the next
+        assert_eq!(
+            eval("ISOWEEKNUM(\"2021-01-03\")", &ctx).unwrap(),
+            Value::Number(53.0)
+        );
+        assert_eq!(
+            eval("ISOWEEKNUM(\"2024-12-31\")", &ctx).unwrap(),
+            Value::Number(1.0)
+        );
+    }
+
+    #[test]
+    fn test_weeknum_row_wise_over_date_column() {
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("orders".to_string());
+        table.add_column(Column::new(
+            "shipped".to_string(),
+            ColumnValue::Date(vec![
+                "2024-01-01".to_string(),
+                "2024-01-07".to_string(),
+                "2021-01-03".to_string(),
+            ]),
+        ));
+        table.add_row_formula("week".to_string(), "=WEEKNUM(shipped)".to_string());
+        table.add_row_formula("iso_week".to_string(), "=ISOWEEKNUM(shipped)".to_string());
+        model.add_table(table);
+
+        let result = ArrayCalculator::new(model).calculate_all().unwrap();
+        let table = result.tables.get("orders").unwrap();
+        let column = |name: &str| match &table.columns.get(name).unwrap().values {
+            ColumnValue::Number(nums) => nums.clone(),
+            _ => panic!("Expected Number array"),
+        };
+        assert_eq!(column("week"), vec![1.0, 2.0, 2.0]);
+        assert_eq!(column("iso_week"), vec![1.0, 1.0, 53.0]);
+    }
+
     #[test]
     fn test_hour_minute_second() {
         let ctx = EvalContext::new();
@@ -0,0 +1,138 @@
+//! Text to date/time conversion functions: DATEVALUE, TIMEVALUE
+
+use chrono::{NaiveDate, NaiveTime};
+
+use super::{evaluate, require_args, Datelike, EvalContext, EvalError, Expr, Timelike, Value};
+
+/// Days from 0001-01-01 (CE day 1) to the Excel epoch, 1899-12-30
+const EXCEL_EPOCH_DAYS_FROM_CE: i32 = 693_594;
+
+/// Try to evaluate a date/time conversion function.
+pub fn try_evaluate(
+    name: &str,
+    args: &[Expr],
+    ctx: &EvalContext,
+) -> Result<Option<Value>, EvalError> {
+    let result = match name {
+        "DATEVALUE" => {
+            require_args(name, args, 1)?;
+            let text = evaluate(&args[0], ctx)?.as_text();
+            let date = parse_date_text(&text).ok_or_else(|| {
+                EvalError::new(format!(
+                    "DATEVALUE: cannot parse '{text}' as a date (expected YYYY-MM-DD or YYYY-MM)"
+                ))
+            })?;
+            // Excel serial: days since 1899-12-30, as in parse_date_value
+            Value::Number(f64::from(
+                date.num_days_from_ce() - EXCEL_EPOCH_DAYS_FROM_CE,
+            ))
+        },
+
+        "TIMEVALUE" => {
+            require_args(name, args, 1)?;
+            let text = evaluate(&args[0], ctx)?.as_text();
+            let time = parse_time_text(&text).ok_or_else(|| {
+                EvalError::new(format!(
+                    "TIMEVALUE: cannot parse '{text}' as a time (expected HH:MM or HH:MM:SS)"
+                ))
+            })?;
+            // Fraction of a day, like TIME
+            Value::Number(f64::from(time.num_seconds_from_midnight()) / 86400.0)
+        },
+
+        _ => return Ok(None),
+    };
+
+    Ok(Some(result))
+}
+
+/// Date part of `YYYY-MM-DD`, `YYYY-MM` (first of the month) or a NOW-style
+/// `YYYY-MM-DD HH:MM:SS`
+fn parse_date_text(text: &str) -> Option<NaiveDate> {
+    let text = text.trim();
+    let date = text.split_once(' ').map_or(text, |(date, _)| date);
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(&format!("{date}-01"), "%Y-%m-%d"))
+        .ok()
+}
+
+/// Time part of `HH:MM`, `HH:MM:SS` or a NOW-style `YYYY-MM-DD HH:MM:SS`
+fn parse_time_text(text: &str) -> Option<NaiveTime> {
+    let text = text.trim();
+    let time = text.rsplit_once(' ').map_or(text, |(_, time)| time);
+    NaiveTime::parse_from_str(time, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)] // Exact float comparison validated against Excel/Gnumeric/R
+    use super::super::super::tests::eval;
+    use super::super::{EvalContext, Value};
+
+    #[test]
+    fn test_datevalue() {
+        let ctx = EvalContext::new();
+        assert_eq!(
+            eval("DATEVALUE(\"2024-01-01\")", &ctx).unwrap(),
+            Value::Number(45292.0)
+        );
+        assert_eq!(
+            eval("DATEVALUE(\"2024-03\")", &ctx).unwrap(),
+            Value::Number(45352.0)
+        );
+        // Time of day is ignored; the serial works in date arithmetic
+        assert_eq!(
+            eval("DATEVALUE(\"2024-03-15 18:30:00\")", &ctx).unwrap(),
+            Value::Number(45366.0)
+        );
+        assert_eq!(
+            eval(
+                "DATEVALUE(\"2024-03-15\") - DATEVALUE(\"2024-03-01\")",
+                &ctx
+            )
+            .unwrap(),
+            Value::Number(14.0)
+        );
+        assert_eq!(
+            eval("YEAR(DATEVALUE(\"2024-03-15\"))", &ctx).unwrap(),
+            Value::Number(2024.0)
+        );
+    }
+
+    #[test]
+    fn test_timevalue() {
+        let ctx = EvalContext::new();
+        assert_eq!(
+            eval("TIMEVALUE(\"12:00\")", &ctx).unwrap(),
+            Value::Number(0.5)
+        );
+        assert_eq!(
+            eval("TIMEVALUE(\"06:00:00\")", &ctx).unwrap(),
+            Value::Number(0.25)
+        );
+        assert_eq!(
+            eval("TIMEVALUE(\"18:30:00\")", &ctx).unwrap(),
+            eval("TIME(18, 30, 0)", &ctx).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_date_time_value_reject_unparseable_text() {
+        let ctx = EvalContext::new();
+        for formula in [
+            "DATEVALUE(\"2023-02-29\")",
+            "DATEVALUE(\"March 15\")",
+            "TIMEVALUE(\"25:00\")",
+            "TIMEVALUE(\"noon\")",
+        ] {
+            let err = eval(formula, &ctx).unwrap_err();
+            assert!(
+                err.message.contains("cannot parse"),
+                "{formula}: {}",
+                err.message
+            );
+        }
+    }
+}
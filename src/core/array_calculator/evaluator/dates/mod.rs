@@ -1,10 +1,12 @@
-//! Date functions: TODAY, NOW, YEAR, MONTH, DAY, WEEKDAY, HOUR, MINUTE, SECOND, DATE, EDATE, EOMONTH, DATEDIF, DAYS, TIME, WORKDAY, etc.
+//! Date functions: TODAY, NOW, YEAR, MONTH, DAY, WEEKDAY, HOUR, MINUTE, SECOND, DATE, EDATE, EOMONTH, DATEDIF, DAYS, TIME, DATEVALUE, TIMEVALUE, WORKDAY, etc.
 //!
 //! DEMO functions (7): TODAY, DATE, YEAR, MONTH, DAY, DATEDIF, EOMONTH
-//! ENTERPRISE functions: NOW, WEEKDAY, HOUR, MINUTE, SECOND, TIME, DAYS, WORKDAY, EDATE, NETWORKDAYS, YEARFRAC
+//! ENTERPRISE functions: NOW, WEEKDAY, WEEKNUM, ISOWEEKNUM, HOUR, MINUTE, SECOND, TIME, DAYS, WORKDAY, EDATE, NETWORKDAYS, YEARFRAC,
+//! DATEVALUE, TIMEVALUE
 
 mod arithmetic;
 mod components;
+mod conversion;
 mod current;
 mod datedif;
 mod workdays;
@@ -41,6 +43,11 @@ pub fn try_evaluate(
         return Ok(Some(result));
     }
 
+    // Text conversion (DATEVALUE, TIMEVALUE)
+    if let Some(result) = conversion::try_evaluate(name, args, ctx)? {
+        return Ok(Some(result));
+    }
+
     // Workday functions (WORKDAY, NETWORKDAYS)
     if let Some(result) = workdays::try_evaluate(name, args, ctx)? {
         return Ok(Some(result));
@@ -1,8 +1,12 @@
-//! Financial functions: PMT, FV, PV, NPV, IRR, NPER, RATE, SLN, DB, DDB, MIRR, XIRR, XNPV,
-//! PPMT, IPMT, EFFECT, NOMINAL, PRICEDISC, YIELDDISC, ACCRINT
+//! Financial functions: PMT, FV, PV, NPV, IRR, NPER, RATE, SLN, SYD, DB, DDB, VDB, MIRR,
+//! XIRR, XNPV, PPMT, IPMT, CUMIPMT, CUMPRINC, EFFECT, NOMINAL, PRICEDISC, YIELDDISC, ACCRINT
 
 use super::{collect_numeric_values, evaluate, require_args, require_args_range};
-use super::{EvalContext, EvalError, Expr, Value};
+use super::{ErrorKind, EvalContext, EvalError, Expr, Value};
+
+/// Most periods VDB, CUMIPMT and CUMPRINC step through; more is `#NUM!`
+/// rather than a hang
+const MAX_PERIODS: f64 = 100_000.0;
 
 /// Try to evaluate a financial function. Returns None if function not recognized.
 #[allow(
@@ -37,19 +41,7 @@ pub fn try_evaluate(
                 0
             };
 
-            if rate == 0.0 {
-                Value::Number(-(pv + fv) / nper)
-            } else {
-                let pmt = if pmt_type == 1 {
-                    (-pv * rate).mul_add((1.0 + rate).powf(nper), -(fv * rate))
-                        / ((1.0 + rate).powf(nper) - 1.0)
-                        / (1.0 + rate)
-                } else {
-                    (-pv * rate).mul_add((1.0 + rate).powf(nper), -(fv * rate))
-                        / ((1.0 + rate).powf(nper) - 1.0)
-                };
-                Value::Number(pmt)
-            }
+            Value::Number(payment(rate, nper, pv, fv, pmt_type))
         },
 
         "FV" => {
@@ -465,14 +457,7 @@ pub fn try_evaluate(
                 Value::Number(-(pv + fv) / nper)
             } else {
                 // Calculate total payment (PMT)
-                let payment = if pmt_type == 1 {
-                    (-pv * rate).mul_add((1.0 + rate).powf(nper), -(fv * rate))
-                        / ((1.0 + rate).powf(nper) - 1.0)
-                        / (1.0 + rate)
-                } else {
-                    (-pv * rate).mul_add((1.0 + rate).powf(nper), -(fv * rate))
-                        / ((1.0 + rate).powf(nper) - 1.0)
-                };
+                let payment = payment(rate, nper, pv, fv, pmt_type);
 
                 // Calculate balance at start of period
                 // Use FV formula: FV = PV * (1+r)^n + PMT * ((1+r)^n - 1) / r
@@ -527,14 +512,7 @@ pub fn try_evaluate(
                 Value::Number(0.0)
             } else {
                 // Calculate total payment (PMT)
-                let payment = if pmt_type == 1 {
-                    (-pv * rate).mul_add((1.0 + rate).powf(nper), -(fv * rate))
-                        / ((1.0 + rate).powf(nper) - 1.0)
-                        / (1.0 + rate)
-                } else {
-                    (-pv * rate).mul_add((1.0 + rate).powf(nper), -(fv * rate))
-                        / ((1.0 + rate).powf(nper) - 1.0)
-                };
+                let payment = payment(rate, nper, pv, fv, pmt_type);
 
                 // Calculate balance at start of period
                 let periods_elapsed = per - 1.0;
@@ -552,6 +530,37 @@ pub fn try_evaluate(
             }
         },
 
+        "SYD" => {
+            require_args(name, args, 4)?;
+            let cost = evaluate(&args[0], ctx)?
+                .as_number()
+                .ok_or_else(|| EvalError::new("SYD requires cost"))?;
+            let salvage = evaluate(&args[1], ctx)?
+                .as_number()
+                .ok_or_else(|| EvalError::new("SYD requires salvage"))?;
+            let life = evaluate(&args[2], ctx)?
+                .as_number()
+                .ok_or_else(|| EvalError::new("SYD requires life"))?;
+            let period = evaluate(&args[3], ctx)?
+                .as_number()
+                .ok_or_else(|| EvalError::new("SYD requires period"))?;
+
+            if life <= 0.0 {
+                return Err(EvalError::new("SYD: life must be positive"));
+            }
+            if period < 1.0 || period > life {
+                return Err(EvalError::new("SYD: period must be between 1 and life"));
+            }
+            // Remaining life (counting this period) over the sum of the years' digits
+            Value::Number((cost - salvage) * (life - period + 1.0) * 2.0 / (life * (life + 1.0)))
+        },
+
+        "VDB" => eval_vdb(args, ctx)?,
+
+        "CUMIPMT" => eval_cumulative(name, args, ctx, false)?,
+
+        "CUMPRINC" => eval_cumulative(name, args, ctx, true)?,
+
         "EFFECT" => {
             // Effective annual interest rate
             // EFFECT(nominal_rate, npery)
@@ -725,6 +734,166 @@ pub fn try_evaluate(
     Ok(Some(result))
 }
 
+/// VDB(cost, salvage, life, `start_period`, `end_period`, [factor], [`no_switch`])
+///
+/// Builds the declining-balance schedule period by period (switching to
+/// straight-line once that is larger, unless `no_switch`) and sums it over
+/// `start..end`. A fractional bound takes the matching share of its period,
+/// so `VDB(..., 0, 0.5)` is half of the first period's depreciation.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)] // end <= life, validated
+fn eval_vdb(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    require_args_range("VDB", args, 5, 7)?;
+    let number = |i: usize, what: &str| -> Result<f64, EvalError> {
+        evaluate(&args[i], ctx)?
+            .as_number()
+            .ok_or_else(|| EvalError::new(format!("VDB requires {what}")))
+    };
+    let cost = number(0, "cost")?;
+    let salvage = number(1, "salvage")?;
+    let life = number(2, "life")?;
+    let start = number(3, "start_period")?;
+    let end = number(4, "end_period")?;
+    let factor = if args.len() > 5 {
+        number(5, "factor")?
+    } else {
+        2.0
+    };
+    let no_switch = args.len() > 6 && evaluate(&args[6], ctx)?.is_truthy();
+
+    if life <= 0.0 {
+        return Err(EvalError::new("VDB: life must be positive"));
+    }
+    if !life.is_finite() || life > MAX_PERIODS {
+        return Err(ErrorKind::Num.into());
+    }
+    if start < 0.0 || start > end || end > life {
+        return Err(EvalError::new(
+            "VDB: periods must satisfy 0 <= start_period <= end_period <= life",
+        ));
+    }
+    if cost < 0.0 || salvage < 0.0 || factor <= 0.0 {
+        return Err(EvalError::new(
+            "VDB: cost and salvage cannot be negative and factor must be positive",
+        ));
+    }
+
+    let rate = (factor / life).min(1.0);
+    let mut book = cost;
+    let mut straight_line = None;
+    let mut total = 0.0;
+    for period in 0..end.ceil() as usize {
+        let period_start = period as f64;
+        let declining = (book * rate).min(book - salvage).max(0.0);
+        if !no_switch && straight_line.is_none() {
+            let even = (book - salvage) / (life - period_start);
+            if even > declining {
+                straight_line = Some(even);
+            }
+        }
+        let depreciation = straight_line.unwrap_or(declining);
+        book -= depreciation;
+
+        let share = (period_start + 1.0).min(end) - period_start.max(start);
+        if share > 0.0 {
+            total += depreciation * share;
+        }
+    }
+    Ok(Value::Number(total))
+}
+
+/// Payment per period for a loan or annuity (PMT)
+fn payment(rate: f64, nper: f64, pv: f64, fv: f64, pmt_type: i32) -> f64 {
+    if rate == 0.0 {
+        return -(pv + fv) / nper;
+    }
+    let pmt = (-pv * rate).mul_add((1.0 + rate).powf(nper), -(fv * rate))
+        / ((1.0 + rate).powf(nper) - 1.0);
+    if pmt_type == 1 {
+        pmt / (1.0 + rate)
+    } else {
+        pmt
+    }
+}
+
+/// Future value after `nper` periods of payment `pmt` (FV with a type);
+/// for a loan this is minus the balance still owed
+fn future_value(rate: f64, nper: i32, pmt: f64, pv: f64, pmt_type: i32) -> f64 {
+    let growth = (1.0 + rate).powi(nper);
+    -pv.mul_add(
+        growth,
+        pmt * f64::from(pmt_type).mul_add(rate, 1.0) * (growth - 1.0) / rate,
+    )
+}
+
+/// CUMIPMT / CUMPRINC(rate, nper, pv, `start_period`, `end_period`, type)
+///
+/// Interest (or principal) paid over periods `start..=end`, following
+/// Excel: the future value is 0 and interest accrues on the balance owed
+/// after each payment (none in the first period when paying in advance).
+#[allow(clippy::cast_possible_truncation)] // periods are validated to [1, MAX_PERIODS]
+fn eval_cumulative(
+    name: &str,
+    args: &[Expr],
+    ctx: &EvalContext,
+    principal: bool,
+) -> Result<Value, EvalError> {
+    require_args(name, args, 6)?;
+    let number = |i: usize, what: &str| -> Result<f64, EvalError> {
+        evaluate(&args[i], ctx)?
+            .as_number()
+            .ok_or_else(|| EvalError::new(format!("{name} requires {what}")))
+    };
+    let rate = number(0, "rate")?;
+    let nper = number(1, "nper")?;
+    let pv = number(2, "present value")?;
+    let start = number(3, "start_period")?.trunc();
+    let end = number(4, "end_period")?.trunc();
+    let pmt_type = number(5, "type")?;
+
+    if rate <= 0.0 || nper <= 0.0 || pv <= 0.0 {
+        return Err(EvalError::new(format!(
+            "{name}: rate, nper and pv must be positive"
+        )));
+    }
+    if ![nper, start, end].iter().all(|p| p.is_finite()) || end > MAX_PERIODS {
+        return Err(ErrorKind::Num.into());
+    }
+    if start < 1.0 || end > nper || start > end {
+        return Err(EvalError::new(format!(
+            "{name}: periods must satisfy 1 <= start_period <= end_period <= nper"
+        )));
+    }
+    let pmt_type = match pmt_type {
+        t if t.abs() < f64::EPSILON => 0,
+        t if (t - 1.0).abs() < f64::EPSILON => 1,
+        _ => return Err(EvalError::new(format!("{name}: type must be 0 or 1"))),
+    };
+    let (start, end) = (start as i32, end as i32);
+
+    let pmt = payment(rate, nper, pv, 0.0, pmt_type);
+    let mut interest = 0.0;
+    for per in start..=end {
+        interest += match (pmt_type, per) {
+            (1, 1) => 0.0,
+            (1, _) => future_value(rate, per - 2, pmt, pv, 1) - pmt,
+            _ => future_value(rate, per - 1, pmt, pv, 0),
+        };
+    }
+    interest *= rate;
+
+    if principal {
+        Ok(Value::Number(
+            f64::from(end - start + 1).mul_add(pmt, -interest),
+        ))
+    } else {
+        Ok(Value::Number(interest))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::tests::eval;
@@ -832,6 +1001,60 @@ mod tests {
         assert!(matches!(result, Value::Number(n) if n >= 0.0));
     }
 
+    #[test]
+    fn test_syd() {
+        let ctx = EvalContext::new();
+        let num = |formula: &str| eval(formula, &ctx).unwrap().as_number().unwrap();
+        // Excel reference: $30,000 asset, $7,500 salvage, 10-year life
+        assert!((num("SYD(30000, 7500, 10, 1)") - 4_090.909_091).abs() < 1e-6);
+        assert!((num("SYD(30000, 7500, 10, 10)") - 409.090_909).abs() < 1e-6);
+        assert!(eval("SYD(30000, 7500, 10, 11)", &ctx).is_err());
+        assert!(eval("SYD(30000, 7500, 0, 1)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_vdb() {
+        let ctx = EvalContext::new();
+        let num = |formula: &str| eval(formula, &ctx).unwrap().as_number().unwrap();
+        // Excel reference: $2,400 asset, $300 salvage, 10-year life
+        assert!((num("VDB(2400, 300, 10, 0, 1)") - 480.0).abs() < 1e-9);
+        assert!((num("VDB(2400, 300, 120, 0, 1)") - 40.0).abs() < 1e-9);
+        assert!((num("VDB(2400, 300, 3650, 0, 1)") - 1.315_068).abs() < 1e-6);
+        assert!((num("VDB(2400, 300, 120, 6, 18)") - 396.306_053).abs() < 1e-6);
+        assert!((num("VDB(2400, 300, 120, 6, 18, 1.5)") - 311.808_937).abs() < 1e-6);
+        // Whole life depreciates down to salvage thanks to the straight-line switch
+        assert!((num("VDB(2400, 300, 10, 0, 10)") - 2_100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vdb_huge_life_is_num_error() {
+        let ctx = EvalContext::new();
+        let err = eval("VDB(1000, 0, 1000000000000, 0, 1000000000000)", &ctx).unwrap_err();
+        assert_eq!(err.kind, Some(ErrorKind::Num));
+        assert!(eval("VDB(1000, 0, 100000, 0, 100000)", &ctx).is_ok());
+    }
+
+    #[test]
+    fn test_vdb_fractional_periods_and_no_switch() {
+        let ctx = EvalContext::new();
+        let num = |formula: &str| eval(formula, &ctx).unwrap().as_number().unwrap();
+        // Fractional bounds take their share of the period
+        assert!((num("VDB(2400, 300, 10, 0, 0.875, 1.5)") - 315.0).abs() < 1e-9);
+        assert!((num("VDB(2400, 300, 10, 2.5, 3.5)") - 276.48).abs() < 1e-9);
+        assert!(num("VDB(2400, 300, 10, 4, 4)").abs() < 1e-12);
+
+        // Without the switch, declining balance never reaches a zero salvage
+        assert!((num("VDB(2400, 0, 10, 0, 10)") - 2_400.0).abs() < 1e-9);
+        let declining_only = 2_400.0 * (1.0 - 0.8_f64.powi(10));
+        assert!((num("VDB(2400, 0, 10, 0, 10, 2, TRUE)") - declining_only).abs() < 1e-9);
+
+        let err = |formula: &str| eval(formula, &ctx).unwrap_err().message;
+        assert!(err("VDB(2400, 300, 10, 3, 2)").contains("start_period <= end_period"));
+        assert!(err("VDB(2400, 300, 10, 0, 10.5)").contains("<= life"));
+        assert!(err("VDB(2400, 300, 0, 0, 0)").contains("life must be positive"));
+        assert!(err("VDB(2400, 300, 10, 0, 1, 0)").contains("factor must be positive"));
+    }
+
     #[test]
     fn test_mirr_positive_flows_only() {
         let ctx = EvalContext::new();
@@ -936,6 +1159,56 @@ mod tests {
         assert_eq!(result, Value::Number(0.0));
     }
 
+    #[test]
+    fn test_cumipmt_cumprinc_first_year_of_mortgage() {
+        // $125,000 over 30 years at 9% a year, paid monthly (Excel reference values)
+        let ctx = EvalContext::new();
+        let num = |formula: &str| eval(formula, &ctx).unwrap().as_number().unwrap();
+
+        let interest = num("CUMIPMT(0.09/12, 360, 125000, 1, 12, 0)");
+        let principal = num("CUMPRINC(0.09/12, 360, 125000, 1, 12, 0)");
+        assert!((interest + 11_215.342_881).abs() < 1e-5);
+        assert!((principal + 853.996_373).abs() < 1e-5);
+        // Together they are a year of payments
+        let pmt = num("PMT(0.09/12, 360, 125000)");
+        assert!(12.0f64.mul_add(-pmt, interest + principal).abs() < 1e-6);
+
+        // Second year and single period, as in Excel's documentation
+        assert!((num("CUMIPMT(0.09/12, 360, 125000, 13, 24, 0)") + 11_135.232_13).abs() < 1e-5);
+        assert!((num("CUMPRINC(0.09/12, 360, 125000, 13, 24, 0)") + 934.107_123).abs() < 1e-5);
+        assert!((num("CUMIPMT(0.09/12, 360, 125000, 1, 1, 0)") + 937.5).abs() < 1e-9);
+
+        // Paying in advance: no interest accrues before the first payment
+        assert!(num("CUMIPMT(0.09/12, 360, 125000, 1, 1, 1)").abs() < 1e-12);
+        assert!((num("CUMIPMT(0.09/12, 360, 125000, 1, 12, 1)") + 10_201.332_884).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cumipmt_validation() {
+        let ctx = EvalContext::new();
+        let err = |formula: &str| eval(formula, &ctx).unwrap_err().message;
+        assert!(err("CUMIPMT(0.01, 12, 1000, 6, 3, 0)").contains("start_period <= end_period"));
+        assert!(err("CUMPRINC(0.01, 12, 1000, 0, 3, 0)").contains("1 <= start_period"));
+        assert!(err("CUMPRINC(0.01, 12, 1000, 1, 13, 0)").contains("<= nper"));
+        assert!(err("CUMIPMT(0.01, 12, 1000, 1, 3, 2)").contains("type must be 0 or 1"));
+        assert!(err("CUMIPMT(0, 12, 1000, 1, 3, 0)").contains("must be positive"));
+    }
+
+    #[test]
+    fn test_cumipmt_huge_periods_are_num_errors() {
+        let ctx = EvalContext::new();
+        let kind = |formula: &str| eval(formula, &ctx).unwrap_err().kind;
+        assert_eq!(
+            kind("CUMIPMT(0.01, 1E+12, 1000, 1, 1E+12, 0)"),
+            Some(ErrorKind::Num)
+        );
+        assert_eq!(
+            kind("CUMPRINC(0.01, 1E+300*1E+300, 1000, 1, 12, 0)"),
+            Some(ErrorKind::Num)
+        );
+        assert!(eval("CUMPRINC(0.01, 100000, 1000, 1, 100000, 0)", &ctx).is_ok());
+    }
+
     #[test]
     fn test_effect() {
         let ctx = EvalContext::new();
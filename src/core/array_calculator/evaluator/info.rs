@@ -3,7 +3,11 @@
 // Info function casts: TYPE returns f64 type codes from small bounded integers.
 #![allow(clippy::cast_possible_truncation)]
 
-use super::{evaluate, require_args, EvalContext, EvalError, Expr, Value};
+use super::super::parser::Reference;
+use super::{
+    evaluate, require_args, AssertFailure, ErrorKind, EvalContext, EvalError, Expr, TraceEntry,
+    Value,
+};
 
 /// Try to evaluate an info function. Returns None if function not recognized.
 pub fn try_evaluate(
@@ -15,7 +19,12 @@ pub fn try_evaluate(
         "ISBLANK" => {
             require_args(name, args, 1)?;
             let val = evaluate(&args[0], ctx)?;
-            Value::Boolean(matches!(val, Value::Null))
+            // Empty text counts as blank, so imported "" cells can be cleaned
+            Value::Boolean(match val {
+                Value::Null => true,
+                Value::Text(text) => text.is_empty(),
+                _ => false,
+            })
         },
 
         "ISERROR" => {
@@ -23,7 +32,7 @@ pub fn try_evaluate(
             // Check if evaluation produces an error OR returns NA (Null)
             // In Excel, ISERROR returns TRUE for ALL error types including #N/A
             let is_error = match evaluate(&args[0], ctx) {
-                Err(_) | Ok(Value::Null) => true, // NA() returns Null, which is an error
+                Err(_) | Ok(Value::Null | Value::Error(_)) => true, // NA() returns Null
                 Ok(_) => false,
             };
             Value::Boolean(is_error)
@@ -33,7 +42,7 @@ pub fn try_evaluate(
             require_args(name, args, 1)?;
             let val = evaluate(&args[0], ctx)?;
             // In Forge, we treat Null as NA
-            Value::Boolean(matches!(val, Value::Null))
+            Value::Boolean(matches!(val, Value::Null | Value::Error(ErrorKind::NA)))
         },
 
         "ISNUMBER" => {
@@ -86,6 +95,12 @@ pub fn try_evaluate(
             Value::Boolean(false)
         },
 
+        "FORMULATEXT" => eval_formulatext(args, ctx)?,
+
+        "EXPLAIN" => eval_explain(args, ctx)?,
+
+        "ASSERT" => eval_assert(args, ctx)?,
+
         "NA" => {
             require_args(name, args, 0)?;
             // Return Null to represent #N/A
@@ -102,7 +117,8 @@ pub fn try_evaluate(
                 Value::Text(_) => 2.0,
                 Value::Boolean(_) => 4.0,
                 Value::Array(_) => 64.0,
-                Value::Null | Value::Lambda { .. } => 16.0, // Null=error, Lambda=no Excel equivalent
+                // Null=#N/A, Lambda=no Excel equivalent
+                Value::Null | Value::Error(_) | Value::Lambda { .. } => 16.0,
             };
             Value::Number(type_num)
         },
@@ -125,6 +141,62 @@ pub fn try_evaluate(
     Ok(Some(result))
 }
 
+/// FORMULATEXT(reference): the formula behind a computed scalar or column
+fn eval_formulatext(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    require_args("FORMULATEXT", args, 1)?;
+    let key = match &args[0] {
+        Expr::Reference(Reference::Scalar(name)) => name.clone(),
+        Expr::Reference(Reference::TableColumn { table, column }) => format!("{table}.{column}"),
+        _ => {
+            return Err(EvalError::new(
+                "FORMULATEXT requires a scalar or column reference",
+            ))
+        },
+    };
+    ctx.formulas
+        .get(&key)
+        .map(|formula| Value::Text(formula.clone()))
+        .ok_or_else(|| EvalError::new(format!("FORMULATEXT: '{key}' has no formula")))
+}
+
+/// EXPLAIN(expr): pass-through that records the expression and its value when tracing
+fn eval_explain(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    require_args("EXPLAIN", args, 1)?;
+    let value = evaluate(&args[0], ctx)?;
+    if let Some(sink) = &ctx.trace {
+        if let Ok(mut entries) = sink.lock() {
+            entries.push(TraceEntry {
+                expression: args[0].to_string(),
+                row: ctx.current_row,
+                value: value.clone(),
+            });
+        }
+    }
+    Ok(value)
+}
+
+/// ASSERT(condition, [message]): returns the condition, recording a failure when it is false
+fn eval_assert(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let value = evaluate(&args[0], ctx)?;
+    if value.is_truthy() {
+        return Ok(value);
+    }
+    if let Some(sink) = &ctx.assertions {
+        let message = match args.get(1) {
+            Some(arg) => Some(evaluate(arg, ctx)?.as_text()),
+            None => None,
+        };
+        if let Ok(mut failures) = sink.lock() {
+            failures.push(AssertFailure {
+                condition: args[0].to_string(),
+                message,
+                row: ctx.current_row,
+            });
+        }
+    }
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::tests::eval;
@@ -275,8 +347,47 @@ mod tests {
         let ctx = EvalContext::new();
         assert_eq!(eval("ISBLANK(NA())", &ctx).unwrap(), Value::Boolean(true));
         assert_eq!(eval("ISBLANK(0)", &ctx).unwrap(), Value::Boolean(false));
-        assert_eq!(eval("ISBLANK(\"\")", &ctx).unwrap(), Value::Boolean(false));
-        // Empty string is not blank
+        assert_eq!(eval("ISBLANK(\"\")", &ctx).unwrap(), Value::Boolean(true));
+        assert_eq!(eval("ISBLANK(\" \")", &ctx).unwrap(), Value::Boolean(false));
+    }
+
+    #[test]
+    fn test_is_predicates_row_wise() {
+        use crate::core::array_calculator::ArrayCalculator;
+        use crate::types::{Column, ColumnValue, ParsedModel, Table};
+
+        let mut model = ParsedModel::new();
+        let mut data = Table::new("data".to_string());
+        data.add_column(Column::new(
+            "raw".to_string(),
+            ColumnValue::Text(vec!["12".to_string(), "n/a".to_string(), String::new()]),
+        ));
+        data.row_formulas
+            .insert("blank".to_string(), "=ISBLANK(raw)".to_string());
+        data.row_formulas
+            .insert("numeric".to_string(), "=ISNUMBER(raw)".to_string());
+        data.row_formulas.insert(
+            "clean".to_string(),
+            "=IF(ISERROR(VALUE(raw)), 0, VALUE(raw))".to_string(),
+        );
+        model.add_table(data);
+
+        let calculator = ArrayCalculator::new(model);
+        let result = calculator.calculate_all().expect("Should calculate");
+        let columns = &result.tables["data"].columns;
+        assert_eq!(
+            columns["blank"].values,
+            ColumnValue::Boolean(vec![false, false, true])
+        );
+        // Numeric-looking text is still text, as in Excel
+        assert_eq!(
+            columns["numeric"].values,
+            ColumnValue::Boolean(vec![false, false, false])
+        );
+        assert_eq!(
+            columns["clean"].values,
+            ColumnValue::Number(vec![12.0, 0.0, 0.0])
+        );
     }
 
     #[test]
@@ -309,4 +420,81 @@ mod tests {
         // ISFORMULA always returns FALSE in current implementation
         assert_eq!(eval("ISFORMULA(5)", &ctx).unwrap(), Value::Boolean(false));
     }
+
+    #[test]
+    fn test_formulatext() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars.insert("price".to_string(), Value::Number(10.0));
+        ctx.formulas
+            .insert("sales.total".to_string(), "=SUM(sales.revenue)".to_string());
+
+        assert_eq!(
+            eval("FORMULATEXT(sales.total)", &ctx).unwrap(),
+            Value::Text("=SUM(sales.revenue)".to_string())
+        );
+        let err = eval("FORMULATEXT(price)", &ctx).unwrap_err();
+        assert!(err.message.contains("'price' has no formula"));
+        assert!(eval("FORMULATEXT(1 + 2)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_explain_passes_through() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars.insert("price".to_string(), Value::Number(10.0));
+        assert_eq!(
+            eval("EXPLAIN(price * 2) + 1", &ctx).unwrap(),
+            Value::Number(21.0)
+        );
+    }
+
+    #[test]
+    fn test_explain_records_when_tracing() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars.insert("price".to_string(), Value::Number(10.0));
+        let sink = super::super::TraceSink::default();
+        ctx.trace = Some(sink.clone());
+
+        assert_eq!(
+            eval("EXPLAIN((price + 2) * 3) - 1", &ctx).unwrap(),
+            Value::Number(35.0)
+        );
+        assert_eq!(
+            *sink.lock().unwrap(),
+            vec![TraceEntry {
+                expression: "(price + 2) * 3".to_string(),
+                row: None,
+                value: Value::Number(36.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_assert_records_false_conditions() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars.insert("npv".to_string(), Value::Number(0.4));
+        let sink = super::super::AssertSink::default();
+        ctx.assertions = Some(sink.clone());
+
+        assert_eq!(
+            eval("ASSERT(ABS(npv) < 1, \"NPV at IRR is zero\")", &ctx).unwrap(),
+            Value::Boolean(true)
+        );
+        assert!(sink.lock().unwrap().is_empty());
+
+        assert_eq!(
+            eval("ASSERT(npv > 1, \"npv too small\")", &ctx).unwrap(),
+            Value::Boolean(false)
+        );
+        assert_eq!(
+            eval("ASSERT(npv = 0)", &ctx).unwrap(),
+            Value::Boolean(false)
+        );
+        let failures = std::mem::take(&mut *sink.lock().unwrap());
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].message.as_deref(), Some("npv too small"));
+        assert_eq!(
+            failures[1].to_string(),
+            format!("{} is false", failures[1].condition)
+        );
+    }
 }
@@ -1,10 +1,11 @@
-//! Conditional functions: IF, IFERROR, IFNA, IFS
+//! Conditional functions: IF, IFERROR, IFNA, IFS, SWITCH
 //!
 //! DEMO functions: IF, IFERROR
-//! ENTERPRISE functions: IFNA, IFS
+//! ENTERPRISE functions: IFNA, IFS, SWITCH
 
 use crate::core::array_calculator::evaluator::{
-    evaluate, require_args, require_args_range, EvalContext, EvalError, Expr, Value,
+    evaluate, require_args, require_args_range, values_equal, ErrorKind, EvalContext, EvalError,
+    Expr, Value,
 };
 
 /// Evaluate IF function - conditional evaluation
@@ -25,18 +26,18 @@ pub fn eval_if(name: &str, args: &[Expr], ctx: &EvalContext) -> Result<Value, Ev
 pub fn eval_iferror(name: &str, args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
     require_args(name, args, 2)?;
     match evaluate(&args[0], ctx) {
-        Ok(val) if !matches!(val, Value::Null) => Ok(val),
-        _ => evaluate(&args[1], ctx), // Error or NA (Null) => use fallback
+        Ok(val) if !matches!(val, Value::Null | Value::Error(_)) => Ok(val),
+        _ => evaluate(&args[1], ctx), // Error, error value or NA (Null) => use fallback
     }
 }
 
-/// Evaluate IFNA function - returns fallback value when result is NA (null)
+/// Evaluate IFNA function - returns fallback value when result is #N/A (or null)
 pub fn eval_ifna(name: &str, args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
     require_args(name, args, 2)?;
     let val = evaluate(&args[0], ctx)?;
-    // In Excel, IFNA returns value_if_na when the result is #N/A
-    // Since we don't have a proper NA error type, treat Null as NA
-    if matches!(val, Value::Null) {
+    // In Excel, IFNA returns value_if_na when the result is #N/A; NA() and
+    // lookup misses produce Null, so that counts as #N/A too
+    if matches!(val, Value::Null | Value::Error(ErrorKind::NA)) {
         evaluate(&args[1], ctx)
     } else {
         Ok(val)
@@ -63,6 +64,31 @@ pub fn eval_ifs(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
     ))
 }
 
+/// Evaluate SWITCH function - map a value to a result
+///
+/// `SWITCH(expr, value1, result1, [value2, result2], ..., [default])`: `expr`
+/// is evaluated once and compared with each value using [`values_equal`]
+/// (case-insensitive text, numeric tolerance). A trailing unpaired argument
+/// is the default when nothing matches.
+pub fn eval_switch(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    if args.len() < 2 {
+        return Err(EvalError::new("SWITCH requires at least 2 arguments"));
+    }
+
+    let expr_val = evaluate(&args[0], ctx)?;
+    let cases = &args[1..];
+    for pair in cases.chunks_exact(2) {
+        if values_equal(&expr_val, &evaluate(&pair[0], ctx)?) {
+            return evaluate(&pair[1], ctx);
+        }
+    }
+
+    match cases.chunks_exact(2).remainder() {
+        [default] => evaluate(default, ctx),
+        _ => Err(EvalError::new("SWITCH: No match found")),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::tests::eval;
@@ -248,5 +274,40 @@ mod tests {
         ctx.scalars.insert("valid".to_string(), Value::Number(10.0));
         // Non-null value returns the value
         assert_eq!(eval("IFNA(valid, 0)", &ctx).unwrap(), Value::Number(10.0));
+        assert_eq!(eval("IFNA(NA(), 0)", &ctx).unwrap(), Value::Number(0.0));
+    }
+
+    #[test]
+    fn test_iferror_ifna_catch_error_values() {
+        let ctx = EvalContext::new();
+        assert_eq!(
+            eval("IFERROR(10 / 0, 7)", &ctx).unwrap(),
+            Value::Number(7.0)
+        );
+        // IFNA only catches #N/A: #DIV/0! passes through
+        assert_eq!(
+            eval("IFNA(10 / 0, 7)", &ctx).unwrap(),
+            Value::Error(ErrorKind::DivZero)
+        );
+        assert_eq!(eval("ISERROR(10 / 0)", &ctx).unwrap(), Value::Boolean(true));
+        assert_eq!(eval("ISNA(10 / 0)", &ctx).unwrap(), Value::Boolean(false));
+    }
+
+    #[test]
+    fn test_switch_text_cases_and_default() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars
+            .insert("region".to_string(), Value::Text("eu".to_string()));
+        // Text matches ignore case, like `=`
+        assert_eq!(
+            eval("SWITCH(region, \"US\", 1, \"EU\", 2, 0)", &ctx).unwrap(),
+            Value::Number(2.0)
+        );
+        assert_eq!(
+            eval("SWITCH(\"APAC\", \"US\", 1, \"EU\", 2, 0)", &ctx).unwrap(),
+            Value::Number(0.0)
+        );
+        let err = eval("SWITCH(\"APAC\", \"US\", 1, \"EU\", 2)", &ctx).unwrap_err();
+        assert!(err.message.contains("No match"), "{}", err.message);
     }
 }
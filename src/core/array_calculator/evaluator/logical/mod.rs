@@ -1,7 +1,7 @@
-//! Logical functions: IF, AND, OR, NOT, XOR, TRUE, FALSE, IFERROR, IFNA, IFS
+//! Logical functions: IF, AND, OR, NOT, XOR, TRUE, FALSE, IFERROR, IFNA, IFS, SWITCH
 //!
 //! DEMO functions (5): IF, AND, OR, NOT, IFERROR
-//! ENTERPRISE functions: IFNA, XOR, TRUE, FALSE, IFS
+//! ENTERPRISE functions: IFNA, XOR, TRUE, FALSE, IFS, SWITCH
 
 mod boolean_ops;
 mod conditionals;
@@ -42,6 +42,8 @@ pub fn try_evaluate(
 
         "IFS" => return Ok(Some(conditionals::eval_ifs(args, ctx)?)),
 
+        "SWITCH" => conditionals::eval_switch(args, ctx)?,
+
         _ => return Ok(None),
     };
 
@@ -175,13 +177,11 @@ mod integration_tests {
         );
         model.add_table(data);
 
+        // SUM over another table is computed once and compared with every row
         let calculator = ArrayCalculator::new(model);
-        let result = calculator.calculate_all();
-
-        assert!(
-            result.is_err(),
-            "Aggregation in row formula should error (invalid usage)"
-        );
+        let result = calculator.calculate_all().unwrap();
+        let above_min = &result.tables["data"].columns["above_min"].values;
+        assert_eq!(*above_min, ColumnValue::Number(vec![0.0, 1.0, 0.0]));
     }
 
     #[test]
@@ -1221,7 +1221,7 @@ mod comparison_tests {
     #![allow(clippy::float_cmp)]
 
     use crate::core::array_calculator::ArrayCalculator;
-    use crate::types::{ParsedModel, Variable};
+    use crate::types::{Column, ColumnValue, ParsedModel, Table, Variable};
 
     #[test]
     fn test_true_gt_false() {
@@ -1430,4 +1430,24 @@ mod comparison_tests {
         let result = calculator.calculate_all().expect("Should calculate");
         assert_eq!(result.scalars.get("result").unwrap().value, Some(1.0));
     }
+
+    #[test]
+    fn test_switch_row_wise_text_column() {
+        let mut model = ParsedModel::new();
+        let mut data = Table::new("data".to_string());
+        data.add_column(Column::new(
+            "region".to_string(),
+            ColumnValue::Text(vec!["US".to_string(), "eu".to_string(), "APAC".to_string()]),
+        ));
+        data.row_formulas.insert(
+            "code".to_string(),
+            "=SWITCH(region, \"US\", 1, \"EU\", 2, 0)".to_string(),
+        );
+        model.add_table(data);
+
+        let calculator = ArrayCalculator::new(model);
+        let result = calculator.calculate_all().expect("Should calculate");
+        let col = &result.tables["data"].columns["code"];
+        assert_eq!(col.values, ColumnValue::Number(vec![1.0, 2.0, 0.0]));
+    }
 }
@@ -17,11 +17,8 @@ pub fn eval_index(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError>
 
     // Evaluate array without row context to get full array
     let array_ctx = EvalContext {
-        scalars: ctx.scalars.clone(),
-        tables: ctx.tables.clone(),
-        scenarios: ctx.scenarios.clone(),
         current_row: None,
-        row_count: ctx.row_count,
+        ..ctx.clone()
     };
     let array = evaluate(&args[0], &array_ctx)?;
     let row_num = evaluate(&args[1], ctx)?.as_number().unwrap_or(1.0) as i64;
@@ -18,11 +18,8 @@ pub fn eval_match(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError>
     let lookup_value = evaluate(&args[0], ctx)?;
 
     let array_ctx = EvalContext {
-        scalars: ctx.scalars.clone(),
-        tables: ctx.tables.clone(),
-        scenarios: ctx.scenarios.clone(),
         current_row: None,
-        row_count: ctx.row_count,
+        ..ctx.clone()
     };
     let lookup_array = evaluate(&args[1], &array_ctx)?;
 
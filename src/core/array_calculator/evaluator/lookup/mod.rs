@@ -1,13 +1,14 @@
-//! Lookup functions: INDEX, MATCH, CHOOSE, XLOOKUP, INDIRECT, VLOOKUP, HLOOKUP, OFFSET, ADDRESS, ROW, COLUMN, ROWS, COLUMNS
+//! Lookup functions: INDEX, MATCH, CHOOSE, XLOOKUP, XMATCH, LOOKUP, INDIRECT, VLOOKUP, HLOOKUP, OFFSET, ADDRESS, ROW, COLUMN, ROWS, COLUMNS
 //!
 //! DEMO functions (3): INDEX, MATCH, CHOOSE
-//! ENTERPRISE functions: XLOOKUP, INDIRECT, VLOOKUP, HLOOKUP, OFFSET, ADDRESS, ROW, COLUMN, ROWS, COLUMNS
+//! ENTERPRISE functions: XLOOKUP, XMATCH, LOOKUP, INDIRECT, VLOOKUP, HLOOKUP, OFFSET, ADDRESS, ROW, COLUMN, ROWS, COLUMNS
 
 mod choose;
 mod index;
 mod match_fn;
 mod reference;
 mod xlookup;
+mod xmatch;
 
 use super::{EvalContext, EvalError, Expr, Value};
 
@@ -32,10 +33,14 @@ pub fn try_evaluate(
 
         "XLOOKUP" => xlookup::eval_xlookup(args, ctx)?,
 
+        "XMATCH" => xmatch::eval_xmatch(args, ctx)?,
+
         "VLOOKUP" => xlookup::eval_vlookup(args, ctx)?,
 
         "HLOOKUP" => xlookup::eval_hlookup(args, ctx)?,
 
+        "LOOKUP" => xlookup::eval_lookup(args, ctx)?,
+
         "OFFSET" => reference::eval_offset(args, ctx)?,
 
         "ADDRESS" => reference::eval_address(args, ctx)?,
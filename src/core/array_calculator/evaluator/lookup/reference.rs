@@ -44,11 +44,8 @@ pub fn eval_offset(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError>
     require_args_range("OFFSET", args, 3, 5)?;
 
     let array_ctx = EvalContext {
-        scalars: ctx.scalars.clone(),
-        tables: ctx.tables.clone(),
-        scenarios: ctx.scenarios.clone(),
         current_row: None,
-        row_count: ctx.row_count,
+        ..ctx.clone()
     };
     let base = evaluate(&args[0], &array_ctx)?;
     let rows = evaluate(&args[1], ctx)?
@@ -164,11 +161,8 @@ pub const fn eval_column(_args: &[Expr], _ctx: &EvalContext) -> Result<Value, Ev
 pub fn eval_rows(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
     require_args("ROWS", args, 1)?;
     let array_ctx = EvalContext {
-        scalars: ctx.scalars.clone(),
-        tables: ctx.tables.clone(),
-        scenarios: ctx.scenarios.clone(),
         current_row: None,
-        row_count: ctx.row_count,
+        ..ctx.clone()
     };
     let val = evaluate(&args[0], &array_ctx)?;
     match val {
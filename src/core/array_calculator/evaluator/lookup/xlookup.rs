@@ -1,4 +1,4 @@
-//! XLOOKUP, VLOOKUP, HLOOKUP function implementations
+//! XLOOKUP, VLOOKUP, HLOOKUP, LOOKUP function implementations
 //!
 //! ENTERPRISE functions - only available in full build
 
@@ -17,11 +17,8 @@ pub fn eval_xlookup(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError
     let lookup_val = evaluate(&args[0], ctx)?;
 
     let array_ctx = EvalContext {
-        scalars: ctx.scalars.clone(),
-        tables: ctx.tables.clone(),
-        scenarios: ctx.scenarios.clone(),
         current_row: None,
-        row_count: ctx.row_count,
+        ..ctx.clone()
     };
     let lookup_arr = evaluate(&args[1], &array_ctx)?;
     let return_arr = evaluate(&args[2], &array_ctx)?;
@@ -115,11 +112,8 @@ pub fn eval_vlookup(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError
 
     // Get the table array without row context
     let array_ctx = EvalContext {
-        scalars: ctx.scalars.clone(),
-        tables: ctx.tables.clone(),
-        scenarios: ctx.scenarios.clone(),
         current_row: None,
-        row_count: ctx.row_count,
+        ..ctx.clone()
     };
     let table_array = evaluate(&args[1], &array_ctx)?;
     let _col_index = evaluate(&args[2], ctx)?
@@ -175,11 +169,8 @@ pub fn eval_hlookup(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError
     let lookup_val = evaluate(&args[0], ctx)?;
 
     let array_ctx = EvalContext {
-        scalars: ctx.scalars.clone(),
-        tables: ctx.tables.clone(),
-        scenarios: ctx.scenarios.clone(),
         current_row: None,
-        row_count: ctx.row_count,
+        ..ctx.clone()
     };
     let table_array = evaluate(&args[1], &array_ctx)?;
     let _row_index = evaluate(&args[2], ctx)?
@@ -222,6 +213,62 @@ pub fn eval_hlookup(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError
     )
 }
 
+/// Evaluate LOOKUP function (vector form)
+/// `LOOKUP(lookup_value`, `lookup_vector`, [`result_vector`])
+///
+/// Approximate match only: `lookup_vector` is assumed sorted ascending and the
+/// result paired with the largest value <= `lookup_value` is returned (the
+/// last one when values repeat). Without `result_vector` the matched lookup
+/// value itself is returned.
+pub fn eval_lookup(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let lookup_val = evaluate(&args[0], ctx)?;
+
+    let array_ctx = EvalContext {
+        current_row: None,
+        ..ctx.clone()
+    };
+    let Value::Array(lookup_values) = evaluate(&args[1], &array_ctx)? else {
+        return Err(EvalError::new("LOOKUP lookup_vector must be an array"));
+    };
+    let return_values = if args.len() > 2 {
+        let Value::Array(values) = evaluate(&args[2], &array_ctx)? else {
+            return Err(EvalError::new("LOOKUP result_vector must be an array"));
+        };
+        if values.len() != lookup_values.len() {
+            return Err(EvalError::new(format!(
+                "LOOKUP: lookup_vector ({}) and result_vector ({}) must have same length",
+                lookup_values.len(),
+                values.len()
+            )));
+        }
+        values
+    } else {
+        lookup_values.clone()
+    };
+
+    let mut best_idx: Option<usize> = None;
+    for (i, v) in lookup_values.iter().enumerate() {
+        let not_above = match (v, &lookup_val) {
+            (Value::Number(vn), Value::Number(ln)) => vn <= ln,
+            (Value::Text(vt), Value::Text(lt)) => vt.to_lowercase() <= lt.to_lowercase(),
+            _ => false,
+        };
+        let not_below_best = best_idx.is_none_or(|b| match (v, &lookup_values[b]) {
+            (Value::Number(vn), Value::Number(bn)) => vn >= bn,
+            (Value::Text(vt), Value::Text(bt)) => vt.to_lowercase() >= bt.to_lowercase(),
+            _ => false,
+        });
+        if not_above && not_below_best {
+            best_idx = Some(i);
+        }
+    }
+
+    best_idx.map_or_else(
+        || Err(EvalError::new("LOOKUP: value not found")),
+        |i| Ok(return_values.get(i).cloned().unwrap_or(Value::Null)),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::float_cmp)] // Exact float comparison validated against Excel/Gnumeric/R
@@ -614,4 +661,69 @@ mod tests {
         let result = calculator.calculate_all().expect("Should calculate");
         assert_eq!(result.scalars.get("result").unwrap().value, Some(30.0));
     }
+
+    #[test]
+    fn test_lookup_vector_mid_range_key() {
+        let mut ctx = EvalContext::new();
+        let mut table = HashMap::new();
+        table.insert(
+            "threshold".to_string(),
+            vec![
+                Value::Number(0.0),
+                Value::Number(1000.0),
+                Value::Number(5000.0),
+                Value::Number(10000.0),
+            ],
+        );
+        table.insert(
+            "tier".to_string(),
+            vec![
+                Value::Text("Bronze".to_string()),
+                Value::Text("Silver".to_string()),
+                Value::Text("Gold".to_string()),
+                Value::Text("Platinum".to_string()),
+            ],
+        );
+        ctx.tables.insert("t".to_string(), table);
+
+        assert_eq!(
+            eval("LOOKUP(7500, t.threshold, t.tier)", &ctx).unwrap(),
+            Value::Text("Gold".to_string())
+        );
+        assert_eq!(
+            eval("LOOKUP(1000, t.threshold, t.tier)", &ctx).unwrap(),
+            Value::Text("Silver".to_string())
+        );
+        assert_eq!(
+            eval("LOOKUP(99999, t.threshold, t.tier)", &ctx).unwrap(),
+            Value::Text("Platinum".to_string())
+        );
+        // Without a result vector the matched lookup value is returned
+        assert_eq!(
+            eval("LOOKUP(7500, t.threshold)", &ctx).unwrap(),
+            Value::Number(5000.0)
+        );
+    }
+
+    #[test]
+    fn test_lookup_vector_key_below_first_element() {
+        let mut ctx = EvalContext::new();
+        let mut table = HashMap::new();
+        table.insert(
+            "keys".to_string(),
+            vec![Value::Number(10.0), Value::Number(20.0)],
+        );
+        table.insert(
+            "values".to_string(),
+            vec![Value::Number(1.0), Value::Number(2.0)],
+        );
+        table.insert("short".to_string(), vec![Value::Number(1.0)]);
+        ctx.tables.insert("t".to_string(), table);
+
+        let err = eval("LOOKUP(5, t.keys, t.values)", &ctx).unwrap_err();
+        assert!(err.to_string().contains("value not found"));
+
+        let err = eval("LOOKUP(15, t.keys, t.short)", &ctx).unwrap_err();
+        assert!(err.to_string().contains("must have same length"));
+    }
 }
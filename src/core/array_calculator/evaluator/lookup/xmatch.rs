@@ -0,0 +1,374 @@
+//! XMATCH function implementation
+//!
+//! ENTERPRISE function - only available in full build
+
+// Match casts: f64 modes to i32, array position to f64 (small bounded integers).
+#![allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+
+use crate::core::array_calculator::evaluator::{
+    evaluate, values_equal, EvalContext, EvalError, Expr, Value,
+};
+use std::cmp::Ordering;
+
+/// Evaluate XMATCH function
+/// `XMATCH(lookup_value`, `lookup_array`, [`match_mode`], [`search_mode`])
+///
+/// `match_mode`: 0 exact (default), -1 exact or next smaller, 1 exact or next
+/// larger, 2 wildcard (`*`, `?`, `~` escape).
+/// `search_mode`: 1 first-to-last (default), -1 last-to-first, 2 binary search
+/// on ascending data, -2 binary search on descending data.
+///
+/// Returns the 1-based position, or #N/A (Null, like `NA()`) when nothing matches.
+pub fn eval_xmatch(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let lookup_value = evaluate(&args[0], ctx)?;
+
+    let array_ctx = EvalContext {
+        current_row: None,
+        ..ctx.clone()
+    };
+    let lookup_array = evaluate(&args[1], &array_ctx)?;
+
+    let match_mode = if args.len() > 2 {
+        evaluate(&args[2], ctx)?.as_number().unwrap_or(0.0) as i32
+    } else {
+        0
+    };
+    let search_mode = if args.len() > 3 {
+        evaluate(&args[3], ctx)?.as_number().unwrap_or(1.0) as i32
+    } else {
+        1
+    };
+
+    let Value::Array(arr) = lookup_array else {
+        return Err(EvalError::new("XMATCH lookup_array must be an array"));
+    };
+
+    if !matches!(match_mode, -1..=2) {
+        return Err(EvalError::new(format!(
+            "XMATCH: invalid match_mode {match_mode}"
+        )));
+    }
+
+    let position = match search_mode {
+        1 => linear_search(&arr, &lookup_value, match_mode, false),
+        -1 => linear_search(&arr, &lookup_value, match_mode, true),
+        2 | -2 => {
+            if match_mode == 2 {
+                return Err(EvalError::new(
+                    "XMATCH: wildcard match_mode cannot be combined with binary search",
+                ));
+            }
+            binary_search(&arr, &lookup_value, match_mode, search_mode == -2)
+        },
+        _ => {
+            return Err(EvalError::new(format!(
+                "XMATCH: invalid search_mode {search_mode}"
+            )))
+        },
+    };
+
+    Ok(position.map_or(Value::Null, |i| Value::Number((i + 1) as f64)))
+}
+
+/// Scan every element; exact hits win immediately, otherwise the closest
+/// candidate found first in search order is kept
+fn linear_search(arr: &[Value], lookup: &Value, match_mode: i32, reverse: bool) -> Option<usize> {
+    let order: Vec<usize> = if reverse {
+        (0..arr.len()).rev().collect()
+    } else {
+        (0..arr.len()).collect()
+    };
+
+    let mut best: Option<usize> = None;
+    for i in order {
+        let val = &arr[i];
+        if match_mode == 2 {
+            if wildcard_equal(val, lookup) {
+                return Some(i);
+            }
+            continue;
+        }
+        if values_equal(val, lookup) {
+            return Some(i);
+        }
+        if match_mode == 0 {
+            continue;
+        }
+        // -1 wants the largest value below lookup, 1 the smallest above
+        let wanted = if match_mode == -1 {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        if compare_values(val, lookup) != Some(wanted) {
+            continue;
+        }
+        let closer = best.is_none_or(|b| compare_values(val, &arr[b]) == Some(wanted.reverse()));
+        if closer {
+            best = Some(i);
+        }
+    }
+    best
+}
+
+/// Binary search over data sorted ascending (or descending)
+fn binary_search(
+    arr: &[Value],
+    lookup: &Value,
+    match_mode: i32,
+    descending: bool,
+) -> Option<usize> {
+    // Index of the first element that sorts at or after lookup
+    let p = arr.partition_point(|v| {
+        let ord = sort_order(v, lookup);
+        if descending {
+            ord == Ordering::Greater
+        } else {
+            ord == Ordering::Less
+        }
+    });
+
+    if p < arr.len() && values_equal(&arr[p], lookup) {
+        return Some(p);
+    }
+    // arr[p - 1] lies before lookup in sort order, arr[p] after it
+    let before = p.checked_sub(1);
+    let after = (p < arr.len()).then_some(p);
+    match (match_mode, descending) {
+        (-1, false) | (1, true) => before,
+        (1, false) | (-1, true) => after,
+        _ => None,
+    }
+}
+
+/// Order two comparable values (numbers, or text case-insensitively)
+fn compare_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.partial_cmp(y),
+        (Value::Text(x), Value::Text(y)) => Some(x.to_lowercase().cmp(&y.to_lowercase())),
+        _ => None,
+    }
+}
+
+/// Total order used by binary search: numbers before text before booleans
+fn sort_order(a: &Value, b: &Value) -> Ordering {
+    const fn rank(v: &Value) -> u8 {
+        match v {
+            Value::Number(_) => 0,
+            Value::Text(_) => 1,
+            Value::Boolean(_) => 2,
+            _ => 3,
+        }
+    }
+    compare_values(a, b).unwrap_or_else(|| rank(a).cmp(&rank(b)))
+}
+
+/// Wildcard comparison; non-text lookups fall back to exact equality
+fn wildcard_equal(val: &Value, lookup: &Value) -> bool {
+    let Value::Text(pattern) = lookup else {
+        return values_equal(val, lookup);
+    };
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = val.as_text().to_lowercase().chars().collect();
+    wildcard_match(&pattern, &text)
+}
+
+/// One element of a wildcard pattern
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Wildcard {
+    /// `*`: any run of characters
+    Any,
+    /// `?`: any one character
+    One,
+    /// A literal character (`~` escapes the next one)
+    Char(char),
+}
+
+/// Match `*` (any run), `?` (any one char) and `~` (escape the next char)
+///
+/// Greedy with a single backtrack point: on a mismatch, the most recent `*`
+/// absorbs one more character and matching resumes after it, so the cost is
+/// at most pattern length times text length.
+fn wildcard_match(pattern: &[char], text: &[char]) -> bool {
+    let mut tokens = Vec::with_capacity(pattern.len());
+    let mut chars = pattern.iter();
+    while let Some(&c) = chars.next() {
+        tokens.push(match c {
+            '*' => Wildcard::Any,
+            '?' => Wildcard::One,
+            '~' => Wildcard::Char(chars.next().copied().unwrap_or('~')),
+            c => Wildcard::Char(c),
+        });
+    }
+
+    let (mut p, mut t) = (0, 0);
+    // Token after the last `*`, and the text position that `*` matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match tokens.get(p) {
+            Some(Wildcard::Any) => {
+                p += 1;
+                backtrack = Some((p, t));
+            },
+            Some(Wildcard::One) => (p, t) = (p + 1, t + 1),
+            Some(Wildcard::Char(c)) if *c == text[t] => (p, t) = (p + 1, t + 1),
+            _ => match backtrack {
+                Some((after_star, matched)) => {
+                    (p, t) = (after_star, matched + 1);
+                    backtrack = Some((after_star, matched + 1));
+                },
+                None => return false,
+            },
+        }
+    }
+    tokens[p..].iter().all(|token| *token == Wildcard::Any)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::array_calculator::evaluator::tests::eval;
+    use std::collections::HashMap;
+
+    fn ctx_with(name: &str, values: Vec<Value>) -> EvalContext {
+        let mut ctx = EvalContext::new();
+        let mut table = HashMap::new();
+        table.insert(name.to_string(), values);
+        ctx.tables.insert("t".to_string(), table);
+        ctx
+    }
+
+    fn numbers(values: &[f64]) -> Vec<Value> {
+        values.iter().map(|n| Value::Number(*n)).collect()
+    }
+
+    #[test]
+    fn test_xmatch_exact() {
+        let ctx = ctx_with("data", numbers(&[10.0, 20.0, 30.0, 20.0]));
+        assert_eq!(
+            eval("XMATCH(20, t.data)", &ctx).unwrap(),
+            Value::Number(2.0)
+        );
+        assert_eq!(eval("XMATCH(25, t.data)", &ctx).unwrap(), Value::Null);
+        assert_eq!(
+            eval("IFNA(XMATCH(25, t.data), -1)", &ctx).unwrap(),
+            Value::Number(-1.0)
+        );
+    }
+
+    #[test]
+    fn test_xmatch_next_larger_and_smaller() {
+        // Unsorted data: linear search still finds the closest value
+        let ctx = ctx_with("data", numbers(&[30.0, 10.0, 40.0, 20.0]));
+        assert_eq!(
+            eval("XMATCH(25, t.data, 1)", &ctx).unwrap(),
+            Value::Number(1.0)
+        );
+        assert_eq!(
+            eval("XMATCH(25, t.data, -1)", &ctx).unwrap(),
+            Value::Number(4.0)
+        );
+        assert_eq!(eval("XMATCH(50, t.data, 1)", &ctx).unwrap(), Value::Null);
+        assert_eq!(
+            eval("XMATCH(40, t.data, 1)", &ctx).unwrap(),
+            Value::Number(3.0)
+        );
+    }
+
+    #[test]
+    fn test_xmatch_reverse_search() {
+        let ctx = ctx_with("data", numbers(&[5.0, 7.0, 5.0, 9.0]));
+        assert_eq!(
+            eval("XMATCH(5, t.data, 0, 1)", &ctx).unwrap(),
+            Value::Number(1.0)
+        );
+        assert_eq!(
+            eval("XMATCH(5, t.data, 0, -1)", &ctx).unwrap(),
+            Value::Number(3.0)
+        );
+        // Ties for the next larger value resolve to the first hit in search order
+        let ctx = ctx_with("data", numbers(&[8.0, 1.0, 8.0]));
+        assert_eq!(
+            eval("XMATCH(6, t.data, 1, -1)", &ctx).unwrap(),
+            Value::Number(3.0)
+        );
+    }
+
+    #[test]
+    fn test_xmatch_wildcard() {
+        let ctx = ctx_with(
+            "names",
+            vec![
+                Value::Text("Apple".to_string()),
+                Value::Text("Banana".to_string()),
+                Value::Text("what?".to_string()),
+            ],
+        );
+        assert_eq!(
+            eval("XMATCH(\"b*\", t.names, 2)", &ctx).unwrap(),
+            Value::Number(2.0)
+        );
+        assert_eq!(
+            eval("XMATCH(\"?pple\", t.names, 2)", &ctx).unwrap(),
+            Value::Number(1.0)
+        );
+        assert_eq!(
+            eval("XMATCH(\"*~?\", t.names, 2)", &ctx).unwrap(),
+            Value::Number(3.0)
+        );
+        assert_eq!(
+            eval("XMATCH(\"c*\", t.names, 2)", &ctx).unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn test_wildcard_match_does_not_backtrack_exponentially() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        let text = chars(&"a".repeat(200));
+        assert!(!wildcard_match(
+            &chars(&format!("{}b", "*a".repeat(30))),
+            &text
+        ));
+        assert!(wildcard_match(&chars(&"*a".repeat(30)), &text));
+        assert!(wildcard_match(&chars("a*~*b?"), &chars("axy*bz")));
+        assert!(!wildcard_match(&chars("a*~*b?"), &chars("axybz")));
+        assert!(wildcard_match(&chars("50~"), &chars("50~")));
+        assert!(wildcard_match(&chars("**"), &[]));
+    }
+
+    #[test]
+    fn test_xmatch_binary_search() {
+        let ctx = ctx_with("asc", numbers(&[10.0, 20.0, 30.0, 40.0]));
+        assert_eq!(
+            eval("XMATCH(30, t.asc, 0, 2)", &ctx).unwrap(),
+            Value::Number(3.0)
+        );
+        assert_eq!(
+            eval("XMATCH(25, t.asc, -1, 2)", &ctx).unwrap(),
+            Value::Number(2.0)
+        );
+        assert_eq!(
+            eval("XMATCH(25, t.asc, 1, 2)", &ctx).unwrap(),
+            Value::Number(3.0)
+        );
+
+        let ctx = ctx_with("desc", numbers(&[40.0, 30.0, 20.0, 10.0]));
+        assert_eq!(
+            eval("XMATCH(25, t.desc, -1, -2)", &ctx).unwrap(),
+            Value::Number(3.0)
+        );
+        assert_eq!(
+            eval("XMATCH(25, t.desc, 1, -2)", &ctx).unwrap(),
+            Value::Number(2.0)
+        );
+        assert!(eval("XMATCH(\"a*\", t.desc, 2, 2)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_xmatch_invalid_modes() {
+        let ctx = ctx_with("data", numbers(&[1.0, 2.0]));
+        assert!(eval("XMATCH(1, t.data, 3)", &ctx).is_err());
+        assert!(eval("XMATCH(1, t.data, 0, 3)", &ctx).is_err());
+    }
+}
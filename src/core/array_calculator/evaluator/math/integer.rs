@@ -0,0 +1,257 @@
+//! Integer functions: GCD, LCM, FACT, COMBIN, PERMUT
+
+// Arguments are validated as non-negative and below 2^53 before casting to u64.
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+#![allow(clippy::cast_precision_loss)]
+
+use super::super::{
+    collect_numeric_values, evaluate, require_args, ErrorKind, EvalContext, EvalError, Expr, Value,
+};
+
+/// Largest integer Excel accepts in GCD/LCM (2^53)
+const MAX_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+/// Collect the arguments as non-negative integers, truncating fractions like Excel
+fn collect_integers(name: &str, args: &[Expr], ctx: &EvalContext) -> Result<Vec<u64>, EvalError> {
+    if args.is_empty() {
+        return Err(EvalError::new(format!(
+            "{name} requires at least one argument"
+        )));
+    }
+    let values = collect_numeric_values(args, ctx)?;
+    if values.is_empty() {
+        return Err(EvalError::new(format!("{name} requires numeric arguments")));
+    }
+    values
+        .into_iter()
+        .map(|v| {
+            let v = v.trunc();
+            if v < 0.0 {
+                Err(EvalError::new(format!(
+                    "{name} requires non-negative integers, got {v}"
+                )))
+            } else if v >= MAX_INTEGER {
+                Err(EvalError::new(format!("{name} argument {v} is too large")))
+            } else {
+                Ok(v as u64)
+            }
+        })
+        .collect()
+}
+
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+pub fn eval_gcd(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let values = collect_integers("GCD", args, ctx)?;
+    let result = values.into_iter().fold(0, gcd);
+    Ok(Value::Number(result as f64))
+}
+
+pub fn eval_lcm(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let values = collect_integers("LCM", args, ctx)?;
+    let mut result: u64 = 1;
+    for v in values {
+        // LCM with zero is zero (Excel)
+        if v == 0 {
+            return Ok(Value::Number(0.0));
+        }
+        result = (result / gcd(result, v))
+            .checked_mul(v)
+            .filter(|&r| (r as f64) < MAX_INTEGER)
+            .ok_or_else(|| EvalError::new("LCM result is too large"))?;
+    }
+    Ok(Value::Number(result as f64))
+}
+
+/// Evaluate one argument as a non-negative integer, truncating fractions like Excel
+fn integer_arg(name: &str, arg: &Expr, ctx: &EvalContext) -> Result<f64, EvalError> {
+    let v = evaluate(arg, ctx)?
+        .as_number()
+        .ok_or_else(|| EvalError::new(format!("{name} requires numbers")))?
+        .trunc();
+    if v < 0.0 {
+        return Err(EvalError::new(format!(
+            "{name} requires non-negative integers, got {v}"
+        )));
+    }
+    Ok(v)
+}
+
+/// Evaluate `(n, k)` for COMBIN/PERMUT, requiring `k <= n`
+fn n_k_args(name: &str, args: &[Expr], ctx: &EvalContext) -> Result<(f64, f64), EvalError> {
+    require_args(name, args, 2)?;
+    let n = integer_arg(name, &args[0], ctx)?;
+    let k = integer_arg(name, &args[1], ctx)?;
+    if k > n {
+        return Err(EvalError::new(format!(
+            "{name} requires number_chosen <= number, got {k} > {n}"
+        )));
+    }
+    Ok((n, k))
+}
+
+/// An overflowed result is `#NUM!`, as in Excel
+fn finite_or_overflow(name: &str, value: f64) -> Result<Value, EvalError> {
+    if value.is_finite() {
+        Ok(Value::Number(value))
+    } else {
+        Err(EvalError {
+            kind: Some(ErrorKind::Num),
+            ..EvalError::new(format!("{name} result is too large"))
+        })
+    }
+}
+
+pub fn eval_fact(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    require_args("FACT", args, 1)?;
+    let n = integer_arg("FACT", &args[0], ctx)?;
+    // 171! overflows f64; stop before looping over a huge range
+    if n > 170.0 {
+        return Err(EvalError::new("FACT result is too large"));
+    }
+    let result = (2..=n as u64).fold(1.0, |acc, i| acc * i as f64);
+    Ok(Value::Number(result))
+}
+
+/// COMBIN(n, k) via the multiplicative formula, never forming n! directly
+pub fn eval_combin(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let (n, k) = n_k_args("COMBIN", args, ctx)?;
+    let k = k.min(n - k);
+    let mut result = 1.0;
+    for i in 1..=k as u64 {
+        let i = i as f64;
+        result = result * (n - k + i) / i;
+        // Past f64 range: stop rather than loop through a huge k
+        if !result.is_finite() {
+            break;
+        }
+    }
+    // Each step is an exact binomial coefficient; round away float noise
+    finite_or_overflow("COMBIN", result.round())
+}
+
+/// PERMUT(n, k) = n * (n-1) * ... * (n-k+1)
+pub fn eval_permut(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let (n, k) = n_k_args("PERMUT", args, ctx)?;
+    let mut result = 1.0;
+    for i in 0..k as u64 {
+        result *= n - i as f64;
+        if !result.is_finite() {
+            break;
+        }
+    }
+    finite_or_overflow("PERMUT", result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::eval;
+    use super::super::super::{ErrorKind, EvalContext, Value};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_gcd() {
+        let ctx = EvalContext::new();
+        assert_eq!(eval("GCD(24, 36)", &ctx).unwrap(), Value::Number(12.0));
+        assert_eq!(eval("GCD(24, 36, 60)", &ctx).unwrap(), Value::Number(12.0));
+        assert_eq!(eval("GCD(7, 13)", &ctx).unwrap(), Value::Number(1.0));
+        assert_eq!(eval("GCD(5, 0)", &ctx).unwrap(), Value::Number(5.0));
+        // Fractions are truncated
+        assert_eq!(eval("GCD(24.9, 36.2)", &ctx).unwrap(), Value::Number(12.0));
+        assert!(eval("GCD(-4, 6)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_lcm() {
+        let ctx = EvalContext::new();
+        assert_eq!(eval("LCM(4, 6)", &ctx).unwrap(), Value::Number(12.0));
+        assert_eq!(eval("LCM(2, 3, 4)", &ctx).unwrap(), Value::Number(12.0));
+        assert_eq!(eval("LCM(5, 0)", &ctx).unwrap(), Value::Number(0.0));
+        assert_eq!(eval("LCM(4.7, 6.1)", &ctx).unwrap(), Value::Number(12.0));
+        assert!(eval("LCM(4, -6)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_fact() {
+        let ctx = EvalContext::new();
+        assert_eq!(eval("FACT(0)", &ctx).unwrap(), Value::Number(1.0));
+        assert_eq!(eval("FACT(5)", &ctx).unwrap(), Value::Number(120.0));
+        assert_eq!(eval("FACT(5.9)", &ctx).unwrap(), Value::Number(120.0));
+        assert!(eval("FACT(170)", &ctx).is_ok());
+        assert!(eval("FACT(171)", &ctx).is_err());
+        assert!(eval("FACT(-1)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_combin() {
+        let ctx = EvalContext::new();
+        assert_eq!(eval("COMBIN(5, 2)", &ctx).unwrap(), Value::Number(10.0));
+        assert_eq!(eval("COMBIN(5, 0)", &ctx).unwrap(), Value::Number(1.0));
+        assert_eq!(eval("COMBIN(5, 5)", &ctx).unwrap(), Value::Number(1.0));
+        // Would overflow if computed as 1000! / (3! * 997!)
+        assert_eq!(
+            eval("COMBIN(1000, 3)", &ctx).unwrap(),
+            Value::Number(166_167_000.0)
+        );
+        let err = eval("COMBIN(2, 5)", &ctx).unwrap_err();
+        assert!(
+            err.message.contains("number_chosen <= number"),
+            "got: {}",
+            err.message
+        );
+        assert!(eval("COMBIN(-5, 2)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_combin_large_arguments_overflow_quickly() {
+        let ctx = EvalContext::new();
+        let err = eval("COMBIN(1e15, 5e14)", &ctx).unwrap_err();
+        assert_eq!(err.kind, Some(ErrorKind::Num));
+        assert!(err.message.contains("too large"), "got: {}", err.message);
+        // k is mirrored to n - k, so a near-complete choice is cheap and exact
+        assert_eq!(
+            eval("COMBIN(1e15, 999999999999999)", &ctx).unwrap(),
+            Value::Number(1e15)
+        );
+    }
+
+    #[test]
+    fn test_permut() {
+        let ctx = EvalContext::new();
+        assert_eq!(eval("PERMUT(5, 2)", &ctx).unwrap(), Value::Number(20.0));
+        assert_eq!(eval("PERMUT(5, 0)", &ctx).unwrap(), Value::Number(1.0));
+        assert_eq!(
+            eval("PERMUT(1000, 2)", &ctx).unwrap(),
+            Value::Number(999_000.0)
+        );
+        assert!(eval("PERMUT(2, 5)", &ctx).is_err());
+        assert!(eval("PERMUT(1000, 500)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_gcd_lcm_flatten_arrays() {
+        let mut ctx = EvalContext::new();
+        let mut table = HashMap::new();
+        table.insert(
+            "lots".to_string(),
+            vec![Value::Number(12.0), Value::Number(18.0)],
+        );
+        ctx.tables.insert("data".to_string(), table);
+
+        assert_eq!(
+            eval("GCD(data.lots, 30)", &ctx).unwrap(),
+            Value::Number(6.0)
+        );
+        assert_eq!(
+            eval("LCM(data.lots, 5)", &ctx).unwrap(),
+            Value::Number(180.0)
+        );
+    }
+}
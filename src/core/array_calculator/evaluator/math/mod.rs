@@ -1,9 +1,11 @@
 //! Math functions: ABS, ROUND, SQRT, POW, EXP, LN, LOG, etc.
 //!
 //! DEMO functions (16): ROUND, ROUNDUP, ROUNDDOWN, ABS, SQRT, POWER, MOD, CEILING, FLOOR, EXP, LN, LOG10, INT, SIGN, TRUNC, PI
-//! ENTERPRISE functions: POW, E, LOG, RAND, RANDBETWEEN
+//! ENTERPRISE functions: POW, E, LOG, RAND, RANDBETWEEN, FLOOR.MATH, CEILING.MATH, GCD, LCM,
+//! FACT, COMBIN, PERMUT
 
 mod basic;
+mod integer;
 mod logarithm;
 mod random;
 mod rounding;
@@ -31,6 +33,8 @@ pub fn try_evaluate(
         "ROUNDDOWN" => rounding::eval_rounddown(args, ctx)?,
         "FLOOR" => rounding::eval_floor(args, ctx)?,
         "CEILING" => rounding::eval_ceiling(args, ctx)?,
+        "FLOOR.MATH" => rounding::eval_floor_math(args, ctx)?,
+        "CEILING.MATH" => rounding::eval_ceiling_math(args, ctx)?,
         "TRUNC" => rounding::eval_trunc(args, ctx)?,
         "INT" => rounding::eval_int(args, ctx)?,
 
@@ -45,6 +49,11 @@ pub fn try_evaluate(
         "E" => basic::eval_e(args, ctx)?,
         "RAND" => random::eval_rand(args, ctx)?,
         "RANDBETWEEN" => random::eval_randbetween(args, ctx)?,
+        "GCD" => integer::eval_gcd(args, ctx)?,
+        "LCM" => integer::eval_lcm(args, ctx)?,
+        "FACT" => integer::eval_fact(args, ctx)?,
+        "COMBIN" => integer::eval_combin(args, ctx)?,
+        "PERMUT" => integer::eval_permut(args, ctx)?,
 
         _ => return Ok(None),
     };
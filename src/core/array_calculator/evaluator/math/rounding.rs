@@ -1,4 +1,5 @@
-//! Rounding functions: ROUND, ROUNDUP, ROUNDDOWN, FLOOR, CEILING, TRUNC, INT
+//! Rounding functions: ROUND, ROUNDUP, ROUNDDOWN, FLOOR, CEILING, FLOOR.MATH, CEILING.MATH,
+//! TRUNC, INT
 
 // Rounding casts: f64 decimal-place counts to i32 (small bounded integers, typically 0..15).
 #![allow(clippy::cast_possible_truncation)]
@@ -87,6 +88,64 @@ pub fn eval_ceiling(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError
     Ok(Value::Number((val / sig).ceil() * sig))
 }
 
+/// Evaluate the optional `significance` and `mode` arguments shared by the `.MATH` variants
+fn math_rounding_args(
+    name: &str,
+    args: &[Expr],
+    ctx: &EvalContext,
+) -> Result<(f64, f64, bool), EvalError> {
+    require_args_range(name, args, 1, 3)?;
+    let val = evaluate(&args[0], ctx)?
+        .as_number()
+        .ok_or_else(|| EvalError::new(format!("{name} requires a number")))?;
+    // Excel ignores the sign of significance in the .MATH variants
+    let sig = if args.len() > 1 {
+        evaluate(&args[1], ctx)?.as_number().unwrap_or(1.0).abs()
+    } else {
+        1.0
+    };
+    let mode = if args.len() > 2 {
+        evaluate(&args[2], ctx)?.as_number().unwrap_or(0.0) != 0.0
+    } else {
+        false
+    };
+    Ok((val, sig, mode))
+}
+
+/// CEILING.MATH(number, [significance], [mode])
+///
+/// Negative numbers round toward zero by default; a non-zero `mode` rounds
+/// them away from zero instead.
+pub fn eval_ceiling_math(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let (val, sig, away_from_zero) = math_rounding_args("CEILING.MATH", args, ctx)?;
+    if sig == 0.0 {
+        return Ok(Value::Number(0.0));
+    }
+    let steps = if val < 0.0 && away_from_zero {
+        (val / sig).floor()
+    } else {
+        (val / sig).ceil()
+    };
+    Ok(Value::Number(steps * sig))
+}
+
+/// FLOOR.MATH(number, [significance], [mode])
+///
+/// Negative numbers round away from zero by default; a non-zero `mode` rounds
+/// them toward zero instead.
+pub fn eval_floor_math(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let (val, sig, toward_zero) = math_rounding_args("FLOOR.MATH", args, ctx)?;
+    if sig == 0.0 {
+        return Ok(Value::Number(0.0));
+    }
+    let steps = if val < 0.0 && toward_zero {
+        (val / sig).ceil()
+    } else {
+        (val / sig).floor()
+    };
+    Ok(Value::Number(steps * sig))
+}
+
 pub fn eval_int(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
     require_args("INT", args, 1)?;
     let val = evaluate(&args[0], ctx)?
@@ -151,6 +210,67 @@ mod tests {
         assert_eq!(eval("CEILING(3.5, 0)", &ctx).unwrap(), Value::Number(0.0));
     }
 
+    #[test]
+    fn test_ceiling_math_mode() {
+        let ctx = EvalContext::new();
+        // Mode 0: negatives round toward zero; mode 1: away from zero
+        assert_eq!(
+            eval("CEILING.MATH(-5.5, 1, 0)", &ctx).unwrap(),
+            Value::Number(-5.0)
+        );
+        assert_eq!(
+            eval("CEILING.MATH(-5.5, 1, 1)", &ctx).unwrap(),
+            Value::Number(-6.0)
+        );
+        assert_eq!(
+            eval("CEILING.MATH(-5.5)", &ctx).unwrap(),
+            Value::Number(-5.0)
+        );
+        // Mode has no effect on positive numbers
+        assert_eq!(
+            eval("CEILING.MATH(5.5, 1, 1)", &ctx).unwrap(),
+            Value::Number(6.0)
+        );
+        assert_eq!(
+            eval("CEILING.MATH(24.3, 5)", &ctx).unwrap(),
+            Value::Number(25.0)
+        );
+        // Sign of significance is ignored
+        assert_eq!(
+            eval("CEILING.MATH(6.7, -2)", &ctx).unwrap(),
+            Value::Number(8.0)
+        );
+        assert_eq!(
+            eval("CEILING.MATH(3.5, 0)", &ctx).unwrap(),
+            Value::Number(0.0)
+        );
+    }
+
+    #[test]
+    fn test_floor_math_mode() {
+        let ctx = EvalContext::new();
+        // Mode 0: negatives round away from zero; mode 1: toward zero
+        assert_eq!(
+            eval("FLOOR.MATH(-5.5, 1, 0)", &ctx).unwrap(),
+            Value::Number(-6.0)
+        );
+        assert_eq!(
+            eval("FLOOR.MATH(-5.5, 1, 1)", &ctx).unwrap(),
+            Value::Number(-5.0)
+        );
+        assert_eq!(
+            eval("FLOOR.MATH(24.3, 5)", &ctx).unwrap(),
+            Value::Number(20.0)
+        );
+        assert_eq!(
+            eval("FLOOR.MATH(-8.1, 2)", &ctx).unwrap(),
+            Value::Number(-10.0)
+        );
+        // Legacy FLOOR/CEILING are unchanged
+        assert_eq!(eval("FLOOR(-5.5, 1)", &ctx).unwrap(), Value::Number(-6.0));
+        assert_eq!(eval("CEILING(-5.5, 1)", &ctx).unwrap(), Value::Number(-5.0));
+    }
+
     #[test]
     fn test_int_trunc() {
         let ctx = EvalContext::new();
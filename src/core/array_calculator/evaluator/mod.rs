@@ -28,11 +28,18 @@ mod trig;
 mod advanced;
 mod array;
 mod conditional;
+mod convert;
 mod forge;
 mod info;
+mod units;
 
 use super::parser::{Expr, Reference};
-use std::collections::HashMap;
+use crate::core::unit_validator::UnitCategory;
+use crate::functions::registry::{self, FunctionDef};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock, Mutex};
+
+pub use units::infer_unit;
 
 /// Value type that can be returned from evaluation
 #[derive(Debug, Clone)]
@@ -52,6 +59,100 @@ pub enum Value {
     },
     /// Null/empty value
     Null,
+    /// An Excel error value (`#DIV/0!`, `#N/A`, ...)
+    Error(ErrorKind),
+}
+
+/// Kind of an Excel error value
+///
+/// Error values flow through operators like ordinary values and can be
+/// caught with IFERROR/IFNA or tested with ISERROR. The calculator keeps an
+/// error left in a formula's result in the output cell.
+#[allow(clippy::upper_case_acronyms)] // Excel's own names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// `#DIV/0!`: division by zero
+    DivZero,
+    /// `#N/A`: value not available
+    NA,
+    /// `#VALUE!`: wrong type of operand or argument
+    Value,
+    /// `#REF!`: invalid reference
+    Ref,
+    /// `#NAME?`: unknown name
+    Name,
+    /// `#NUM!`: invalid numeric value
+    Num,
+}
+
+/// NaN bit pattern tagging a number cell as an error; the low byte holds the kind
+const ERROR_NAN_TAG: u64 = 0x7FF8_0000_4552_0000;
+
+impl ErrorKind {
+    const ALL: [Self; 6] = [
+        Self::DivZero,
+        Self::NA,
+        Self::Value,
+        Self::Ref,
+        Self::Name,
+        Self::Num,
+    ];
+
+    /// Excel's code for the error, e.g. `#DIV/0!`
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::DivZero => "#DIV/0!",
+            Self::NA => "#N/A",
+            Self::Value => "#VALUE!",
+            Self::Ref => "#REF!",
+            Self::Name => "#NAME?",
+            Self::Num => "#NUM!",
+        }
+    }
+
+    /// Description used when the error fails a formula
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::DivZero => "Division by zero",
+            Self::NA => "Value not available",
+            Self::Value => "Wrong type of value",
+            Self::Ref => "Invalid reference",
+            Self::Name => "Unknown name",
+            Self::Num => "Invalid number",
+        }
+    }
+
+    /// The kind with Excel code `code`
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.code() == code)
+    }
+
+    /// The error as a NaN whose payload records the kind, so a number column
+    /// cell can hold it
+    #[must_use]
+    pub const fn to_nan(self) -> f64 {
+        f64::from_bits(ERROR_NAN_TAG | self as u64)
+    }
+
+    /// The kind recorded in a NaN made by [`Self::to_nan`]; `None` for any
+    /// other number (including a plain NaN)
+    #[must_use]
+    pub fn from_nan(n: f64) -> Option<Self> {
+        let bits = n.to_bits();
+        if bits & !0xFF != ERROR_NAN_TAG {
+            return None;
+        }
+        Self::ALL.get((bits & 0xFF) as usize).copied()
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
 }
 
 impl PartialEq for Value {
@@ -62,12 +163,19 @@ impl PartialEq for Value {
             (Self::Boolean(a), Self::Boolean(b)) => a == b,
             (Self::Array(a), Self::Array(b)) => a == b,
             (Self::Null, Self::Null) => true,
+            (Self::Error(a), Self::Error(b)) => a == b,
             _ => false, // Lambdas and mismatched types don't compare equal
         }
     }
 }
 
 impl Value {
+    /// A number stored in a cell; a NaN tagged by [`ErrorKind::to_nan`] is the error it records
+    #[must_use]
+    pub fn from_cell(n: f64) -> Self {
+        ErrorKind::from_nan(n).map_or(Self::Number(n), Self::Error)
+    }
+
     /// Try to convert to f64
     /// For text, tries numeric parsing first, then date parsing (YYYY-MM-DD -> Excel serial)
     ///
@@ -95,7 +203,7 @@ impl Value {
             Self::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
             // Arrays in scalar context return their length
             Self::Array(arr) => Some(arr.len() as f64),
-            Self::Lambda { .. } | Self::Null => None,
+            Self::Lambda { .. } | Self::Null | Self::Error(_) => None,
         }
     }
 
@@ -119,6 +227,7 @@ impl Value {
             Self::Lambda { params, .. } => {
                 format!("LAMBDA({})", params.join(", "))
             },
+            Self::Error(kind) => kind.code().to_string(),
         }
     }
 
@@ -162,8 +271,65 @@ pub struct EvalContext {
     pub current_row: Option<usize>,
     /// Number of rows in current table context
     pub row_count: Option<usize>,
+    /// Scalars computed by SUBTOTAL, by fully qualified name, which enclosing SUBTOTALs skip
+    pub subtotals: HashSet<String>,
+    /// Fully qualified scalar each short scalar name resolves to (`total` -> `summary.total`)
+    pub aliases: HashMap<String, String>,
+    /// Short scalar names shared by several scalars, with the scalars they could mean
+    pub ambiguous: HashMap<String, Vec<String>>,
+    /// Units of tagged columns and scalars, keyed like references (strict unit mode)
+    pub units: HashMap<String, UnitCategory>,
+    /// Formula text behind computed scalars and columns, keyed like references (FORMULATEXT)
+    pub formulas: HashMap<String, String>,
+    /// Where EXPLAIN records intermediate values; `None` unless tracing
+    pub trace: Option<TraceSink>,
+    /// Where ASSERT records false conditions; `None` unless checking assertions
+    pub assertions: Option<AssertSink>,
+}
+
+/// An intermediate value captured by EXPLAIN while tracing
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEntry {
+    /// Formula text of the explained expression
+    pub expression: String,
+    /// Row being evaluated, or `None` in scalar mode
+    pub row: Option<usize>,
+    /// Value the expression evaluated to
+    pub value: Value,
 }
 
+impl std::fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {}", self.expression, self.value.as_text())
+    }
+}
+
+/// Buffer shared by every context cloned from a tracing context
+pub type TraceSink = Arc<Mutex<Vec<TraceEntry>>>;
+
+/// A false condition recorded by ASSERT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertFailure {
+    /// Formula text of the asserted condition
+    pub condition: String,
+    /// Message passed to ASSERT, if any
+    pub message: Option<String>,
+    /// Row being evaluated, or `None` in scalar mode
+    pub row: Option<usize>,
+}
+
+impl std::fmt::Display for AssertFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{message} ({})", self.condition),
+            None => write!(f, "{} is false", self.condition),
+        }
+    }
+}
+
+/// Buffer shared by every context cloned from an assertion-checking context
+pub type AssertSink = Arc<Mutex<Vec<AssertFailure>>>;
+
 impl EvalContext {
     /// Create a new empty context
     #[must_use]
@@ -174,6 +340,13 @@ impl EvalContext {
             scenarios: HashMap::new(),
             current_row: None,
             row_count: None,
+            subtotals: HashSet::new(),
+            aliases: HashMap::new(),
+            ambiguous: HashMap::new(),
+            units: HashMap::new(),
+            formulas: HashMap::new(),
+            trace: None,
+            assertions: None,
         }
     }
 
@@ -183,6 +356,12 @@ impl EvalContext {
         self.scalars.get(name)
     }
 
+    /// Fully qualified name a scalar reference resolves to
+    #[must_use]
+    pub fn qualified_name<'a>(&'a self, name: &'a str) -> &'a str {
+        self.aliases.get(name).map_or(name, String::as_str)
+    }
+
     /// Get a table column
     #[must_use]
     pub fn get_column(&self, table: &str, column: &str) -> Option<&Vec<Value>> {
@@ -208,14 +387,27 @@ impl Default for EvalContext {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EvalError {
     pub message: String,
+    /// The error value this was raised from (e.g. SUM over a `#DIV/0!` cell)
+    pub kind: Option<ErrorKind>,
 }
 
 impl EvalError {
     pub fn new(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
+            kind: None,
         }
     }
+
+    /// The error value a formula result becomes: raised from an error value,
+    /// the formula yields that value instead of failing
+    ///
+    /// # Errors
+    ///
+    /// Returns the error unchanged if it was not raised from an error value.
+    pub fn into_value(self) -> Result<Value, Self> {
+        self.kind.map_or(Err(self), |kind| Ok(Value::Error(kind)))
+    }
 }
 
 impl std::fmt::Display for EvalError {
@@ -226,12 +418,22 @@ impl std::fmt::Display for EvalError {
 
 impl std::error::Error for EvalError {}
 
+impl From<ErrorKind> for EvalError {
+    fn from(kind: ErrorKind) -> Self {
+        Self {
+            message: kind.message().to_string(),
+            kind: Some(kind),
+        }
+    }
+}
+
 /// Evaluate an expression in the given context
 ///
 /// # Errors
 ///
 /// Returns `EvalError` if the expression cannot be evaluated (e.g., unknown
-/// function, type mismatch, or division by zero).
+/// function or type mismatch). Division by zero is not an error here: it
+/// yields [`Value::Error`] with [`ErrorKind::DivZero`].
 pub fn evaluate(expr: &Expr, ctx: &EvalContext) -> Result<Value, EvalError> {
     match expr {
         Expr::Number(n) => Ok(Value::Number(*n)),
@@ -260,6 +462,10 @@ pub fn evaluate(expr: &Expr, ctx: &EvalContext) -> Result<Value, EvalError> {
             }
         },
 
+        Expr::ArraySlice { array, start, end } => {
+            evaluate_slice(array, start.as_deref(), end.as_deref(), ctx)
+        },
+
         Expr::FunctionCall { name, args } => evaluate_function(name, args, ctx),
 
         Expr::CallResult { callable, args } => {
@@ -269,23 +475,11 @@ pub fn evaluate(expr: &Expr, ctx: &EvalContext) -> Result<Value, EvalError> {
             // It must be a Lambda
             match callable_val {
                 Value::Lambda { params, body } => {
-                    // Create new context with lambda parameters bound to arguments
-                    if args.len() != params.len() {
-                        return Err(EvalError::new(format!(
-                            "Lambda expects {} arguments, got {}",
-                            params.len(),
-                            args.len()
-                        )));
-                    }
-
-                    let mut new_ctx = ctx.clone();
-                    for (param, arg_expr) in params.iter().zip(args.iter()) {
-                        let value = evaluate(arg_expr, ctx)?;
-                        new_ctx.scalars.insert(param.clone(), value);
-                    }
-
-                    // Evaluate the body with the new context
-                    evaluate(&body, &new_ctx)
+                    let values = args
+                        .iter()
+                        .map(|arg_expr| evaluate(arg_expr, ctx))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    call_lambda(&params, &body, values, ctx)
                 },
                 _ => Err(EvalError::new("Cannot call non-lambda value")),
             }
@@ -316,14 +510,53 @@ pub fn evaluate(expr: &Expr, ctx: &EvalContext) -> Result<Value, EvalError> {
     }
 }
 
+/// Evaluate `array[start:end]`: end-exclusive, negative bounds count from the end
+///
+/// Bounds past either end are clamped; a start after the end is an error.
+fn evaluate_slice(
+    array: &Expr,
+    start: Option<&Expr>,
+    end: Option<&Expr>,
+    ctx: &EvalContext,
+) -> Result<Value, EvalError> {
+    let Value::Array(arr) = evaluate(array, ctx)? else {
+        return Err(EvalError::new("Cannot slice non-array value"));
+    };
+    let len = i64::try_from(arr.len()).unwrap_or(i64::MAX);
+    let bound = |expr: Option<&Expr>, default: i64| -> Result<i64, EvalError> {
+        let Some(expr) = expr else {
+            return Ok(default);
+        };
+        let n = evaluate(expr, ctx)?
+            .as_number()
+            .ok_or_else(|| EvalError::new("Slice bound must be a number"))? as i64;
+        Ok(if n < 0 { n + len } else { n })
+    };
+    let (start, end) = (bound(start, 0)?, bound(end, len)?);
+    if start > end {
+        return Err(EvalError::new(format!(
+            "Invalid slice: start {start} is after end {end}"
+        )));
+    }
+    let (start, end) = (start.clamp(0, len) as usize, end.clamp(0, len) as usize);
+    Ok(Value::Array(arr[start..end].to_vec()))
+}
+
 /// Evaluate a reference (scalar or table.column)
 fn evaluate_reference(reference: &Reference, ctx: &EvalContext) -> Result<Value, EvalError> {
     match reference {
         Reference::Scalar(name) => {
-            let value = ctx
-                .get_scalar(name)
-                .cloned()
-                .ok_or_else(|| EvalError::new(format!("Unknown variable: {name}")))?;
+            let value = ctx.get_scalar(name).cloned().ok_or_else(|| {
+                EvalError::new(ctx.ambiguous.get(name).map_or_else(
+                    || format!("Unknown variable: {name}"),
+                    |targets| {
+                        format!(
+                            "Ambiguous reference: {name} could be {}",
+                            targets.join(", ")
+                        )
+                    },
+                ))
+            })?;
 
             // In row-wise mode, if the value is an array, extract current row
             if let Some(row) = ctx.current_row {
@@ -377,6 +610,13 @@ fn evaluate_reference(reference: &Reference, ctx: &EvalContext) -> Result<Value,
 // Arithmetic + comparison + logical operators require many match arms.
 #[allow(clippy::too_many_lines)]
 fn evaluate_binary_op(op: &str, left: &Value, right: &Value) -> Result<Value, EvalError> {
+    // Error values propagate through every operator, the left one first (Excel)
+    if let Value::Error(kind) = left {
+        return Ok(Value::Error(*kind));
+    }
+    if let Value::Error(kind) = right {
+        return Ok(Value::Error(*kind));
+    }
     match op {
         // Arithmetic operators
         "+" => {
@@ -423,7 +663,7 @@ fn evaluate_binary_op(op: &str, left: &Value, right: &Value) -> Result<Value, Ev
                 .as_number()
                 .ok_or_else(|| EvalError::new("Right operand must be a number"))?;
             if r == 0.0 {
-                Err(EvalError::new("Division by zero"))
+                Ok(Value::Error(ErrorKind::DivZero))
             } else {
                 Ok(Value::Number(l / r))
             }
@@ -517,6 +757,9 @@ pub(crate) fn values_equal(left: &Value, right: &Value) -> bool {
 
 /// Evaluate a unary operation
 fn evaluate_unary_op(op: &str, operand: &Value) -> Result<Value, EvalError> {
+    if let Value::Error(kind) = operand {
+        return Ok(Value::Error(*kind));
+    }
     match op {
         "-" => {
             let n = operand
@@ -531,6 +774,7 @@ fn evaluate_unary_op(op: &str, operand: &Value) -> Result<Value, EvalError> {
 /// Evaluate a function call - dispatches to category-specific modules
 fn evaluate_function(name: &str, args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
     let upper_name = name.to_uppercase();
+    check_arity(&upper_name, args)?;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // DEMO FUNCTIONS (always available)
@@ -582,6 +826,9 @@ fn evaluate_function(name: &str, args: &[Expr], ctx: &EvalContext) -> Result<Val
         if let Some(result) = advanced::try_evaluate(&upper_name, args, ctx)? {
             return Ok(result);
         }
+        if let Some(result) = convert::try_evaluate(&upper_name, args, ctx)? {
+            return Ok(result);
+        }
     }
 
     Err(EvalError::new(format!("Unknown function: {name}")))
@@ -591,17 +838,62 @@ fn evaluate_function(name: &str, args: &[Expr], ctx: &EvalContext) -> Result<Val
 // SHARED HELPER FUNCTIONS (used by submodules)
 // ═══════════════════════════════════════════════════════════════════════════════
 
+/// Registry definitions by upper-case function name, built on first use
+static FUNCTIONS_BY_NAME: LazyLock<HashMap<String, &'static FunctionDef>> = LazyLock::new(|| {
+    registry::all()
+        .iter()
+        .map(|f| (f.name.to_ascii_uppercase(), f))
+        .collect()
+});
+
+/// Check the argument count against the function registry before dispatch
+///
+/// Functions not in the registry (legacy aliases) validate their own arguments.
+fn check_arity(upper_name: &str, args: &[Expr]) -> Result<(), EvalError> {
+    match FUNCTIONS_BY_NAME.get(upper_name) {
+        Some(def) if !def.accepts_args(args.len()) => {
+            Err(arity_error(def.name, &def.arity(), args.len()))
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Standard wrong-arity error (e.g., "ROUND expects 1-2 args, got 3")
+fn arity_error(func: &str, arity: &str, got: usize) -> EvalError {
+    let noun = if arity == "1" { "arg" } else { "args" };
+    EvalError::new(format!("{func} expects {arity} {noun}, got {got}"))
+}
+
+/// Call a lambda: bind `params` to `values` in a child of `ctx` and evaluate `body`
+pub(crate) fn call_lambda(
+    params: &[String],
+    body: &Expr,
+    values: Vec<Value>,
+    ctx: &EvalContext,
+) -> Result<Value, EvalError> {
+    if values.len() != params.len() {
+        return Err(EvalError::new(format!(
+            "Lambda expects {} arguments, got {}",
+            params.len(),
+            values.len()
+        )));
+    }
+
+    let mut new_ctx = ctx.clone();
+    for (param, value) in params.iter().zip(values) {
+        new_ctx.scalars.insert(param.clone(), value);
+    }
+
+    // Evaluate the body with the new context
+    evaluate(body, &new_ctx)
+}
+
 /// Require exact number of arguments
 pub(crate) fn require_args(func: &str, args: &[Expr], count: usize) -> Result<(), EvalError> {
     if args.len() == count {
         Ok(())
     } else {
-        Err(EvalError::new(format!(
-            "{} requires {} argument(s), got {}",
-            func,
-            count,
-            args.len()
-        )))
+        Err(arity_error(func, &count.to_string(), args.len()))
     }
 }
 
@@ -613,13 +905,7 @@ pub(crate) fn require_args_range(
     max: usize,
 ) -> Result<(), EvalError> {
     if args.len() < min || args.len() > max {
-        Err(EvalError::new(format!(
-            "{} requires {}-{} arguments, got {}",
-            func,
-            min,
-            max,
-            args.len()
-        )))
+        Err(arity_error(func, &format!("{min}-{max}"), args.len()))
     } else {
         Ok(())
     }
@@ -637,12 +923,24 @@ pub(crate) fn collect_numeric_values(
         match val {
             Value::Array(arr) => {
                 for v in arr {
-                    if let Some(n) = v.as_number() {
+                    // Multi-column ranges are arrays of columns
+                    if let Value::Array(col) = v {
+                        for cell in &col {
+                            if let Value::Error(kind) = cell {
+                                return Err((*kind).into());
+                            }
+                        }
+                        values.extend(col.iter().filter_map(Value::as_number));
+                    } else if let Value::Error(kind) = v {
+                        return Err(kind.into());
+                    } else if let Some(n) = v.as_number() {
                         values.push(n);
                     }
                 }
             },
             Value::Number(n) => values.push(n),
+            // Aggregates fail on an error value instead of skipping it (Excel)
+            Value::Error(kind) => return Err(kind.into()),
             _ => {},
         }
     }
@@ -762,6 +1060,33 @@ mod tests {
         assert_eq!(eval("2 ^ 3", &ctx).unwrap(), Value::Number(8.0));
     }
 
+    #[test]
+    fn test_eval_division_by_zero_is_error_value() {
+        let ctx = EvalContext::new();
+        let div_zero = Value::Error(ErrorKind::DivZero);
+        assert_eq!(eval("1 / 0", &ctx).unwrap(), div_zero);
+        // Error values propagate through operators
+        assert_eq!(eval("(1 / 0) + 5", &ctx).unwrap(), div_zero);
+        assert_eq!(eval("-(1 / 0)", &ctx).unwrap(), div_zero);
+        assert_eq!(div_zero.as_text(), "#DIV/0!");
+        assert_eq!(div_zero.as_number(), None);
+        // ...but aggregates fail on them rather than skip them
+        let err = eval("SUM(1 / 0, 2)", &ctx).unwrap_err();
+        assert_eq!(err.message, "Division by zero");
+    }
+
+    #[test]
+    fn test_error_kind_codes_and_nan_boxing() {
+        for kind in ErrorKind::ALL {
+            assert_eq!(ErrorKind::from_code(kind.code()), Some(kind));
+            assert!(kind.to_nan().is_nan());
+            assert_eq!(ErrorKind::from_nan(kind.to_nan()), Some(kind));
+        }
+        assert_eq!(ErrorKind::from_code("#BOGUS!"), None);
+        assert_eq!(ErrorKind::from_nan(f64::NAN), None);
+        assert_eq!(ErrorKind::from_nan(1.0), None);
+    }
+
     #[test]
     fn test_eval_power_zero_to_zero() {
         let ctx = EvalContext::new();
@@ -797,6 +1122,17 @@ mod tests {
         assert_eq!(eval("---5", &ctx).unwrap(), Value::Number(-5.0));
     }
 
+    #[test]
+    fn test_eval_wrong_arity_uses_registry_bounds() {
+        let ctx = EvalContext::new();
+        let err = eval("IF(TRUE, 1, 2, 3)", &ctx).unwrap_err();
+        assert_eq!(err.message, "IF expects 2-3 args, got 4");
+        let err = eval("abs(1, 2)", &ctx).unwrap_err();
+        assert_eq!(err.message, "ABS expects 1 arg, got 2");
+        let err = eval("AND()", &ctx).unwrap_err();
+        assert_eq!(err.message, "AND expects 1+ args, got 0");
+    }
+
     #[test]
     fn test_eval_comparison() {
         let ctx = EvalContext::new();
@@ -904,6 +1240,73 @@ mod tests {
         assert_eq!(eval("t.col[2]", &ctx).unwrap(), Value::Number(30.0));
     }
 
+    fn slice_ctx() -> EvalContext {
+        let mut ctx = EvalContext::new();
+        let mut table = HashMap::new();
+        table.insert(
+            "revenue".to_string(),
+            (1..=6)
+                .map(|n| Value::Number(f64::from(n) * 10.0))
+                .collect(),
+        );
+        ctx.tables.insert("data".to_string(), table);
+        ctx
+    }
+
+    #[test]
+    fn test_eval_array_slice_forward() {
+        let ctx = slice_ctx();
+        assert_eq!(
+            eval("SUM(data.revenue[0:4])", &ctx).unwrap(),
+            Value::Number(100.0)
+        );
+        assert_eq!(
+            eval("data.revenue[1:3]", &ctx).unwrap(),
+            Value::Array(vec![Value::Number(20.0), Value::Number(30.0)])
+        );
+        // Bounds may be expressions
+        assert_eq!(
+            eval("SUM(data.revenue[1 + 1:2 * 2])", &ctx).unwrap(),
+            Value::Number(70.0)
+        );
+    }
+
+    #[test]
+    fn test_eval_array_slice_open_ended() {
+        let ctx = slice_ctx();
+        assert_eq!(
+            eval("SUM(data.revenue[4:])", &ctx).unwrap(),
+            Value::Number(110.0)
+        );
+        assert_eq!(
+            eval("SUM(data.revenue[:2])", &ctx).unwrap(),
+            Value::Number(30.0)
+        );
+        assert_eq!(
+            eval("SUM(data.revenue[-2:])", &ctx).unwrap(),
+            Value::Number(110.0)
+        );
+        assert_eq!(
+            eval("COUNT(data.revenue[:])", &ctx).unwrap(),
+            Value::Number(6.0)
+        );
+    }
+
+    #[test]
+    fn test_eval_array_slice_clamps_and_rejects_inverted() {
+        let ctx = slice_ctx();
+        assert_eq!(
+            eval("SUM(data.revenue[3:100])", &ctx).unwrap(),
+            Value::Number(150.0)
+        );
+        assert_eq!(
+            eval("data.revenue[10:20]", &ctx).unwrap(),
+            Value::Array(vec![])
+        );
+        let err = eval("data.revenue[4:2]", &ctx).unwrap_err();
+        assert!(err.message.contains("start 4 is after end 2"));
+    }
+
     #[test]
     fn test_eval_row_wise() {
         let mut ctx = EvalContext::new();
@@ -1,4 +1,5 @@
-//! Statistical functions: VAR, VARP, STDEV, STDEVP, PERCENTILE, QUARTILE, CORREL, LARGE, SMALL, RANK
+//! Statistical functions: VAR, VARP, STDEV, STDEVP, GEOMEAN, HARMEAN, PERCENTILE(.INC/.EXC),
+//! QUARTILE(.INC/.EXC), CORREL, SLOPE, INTERCEPT, FORECAST(.LINEAR), LARGE, SMALL, RANK, RANK.AVG
 
 use super::{collect_numeric_values, evaluate, require_args, require_args_range};
 use super::{EvalContext, EvalError, Expr, Value};
@@ -11,7 +12,7 @@ use super::{EvalContext, EvalError, Expr, Value};
 )]
 // Spreadsheet arrays are bounded well within f64 mantissa (< 2^52 elements).
 // Index/length casts between f64, usize, and i32 are safe for practical workloads.
-// 10 statistical functions in a single dispatch — splitting would fragment related logic.
+// 15 statistical functions in a single dispatch — splitting would fragment related logic.
 pub fn try_evaluate(
     name: &str,
     args: &[Expr],
@@ -60,62 +61,60 @@ pub fn try_evaluate(
             Value::Number(variance.sqrt())
         },
 
-        "PERCENTILE" => {
-            require_args(name, args, 2)?;
-            let mut values = collect_numeric_values(&args[..1], ctx)?;
+        "GEOMEAN" => {
+            let values = collect_numeric_values(args, ctx)?;
             if values.is_empty() {
-                return Err(EvalError::new("PERCENTILE of empty set"));
+                return Err(EvalError::new("GEOMEAN requires at least 1 value"));
             }
-            let k = evaluate(&args[1], ctx)?
-                .as_number()
-                .ok_or_else(|| EvalError::new("PERCENTILE k must be a number"))?;
-            if !(0.0..=1.0).contains(&k) {
-                return Err(EvalError::new("PERCENTILE k must be between 0 and 1"));
+            if values.iter().any(|&x| x <= 0.0) {
+                return Err(EvalError::new("GEOMEAN requires positive values"));
             }
-            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
-            let n = values.len();
-            if n == 1 {
-                return Ok(Some(Value::Number(values[0])));
+            // Average the logs so long series don't overflow the product
+            let mean_ln = values.iter().map(|x| x.ln()).sum::<f64>() / values.len() as f64;
+            Value::Number(mean_ln.exp())
+        },
+
+        "HARMEAN" => {
+            let values = collect_numeric_values(args, ctx)?;
+            if values.is_empty() {
+                return Err(EvalError::new("HARMEAN requires at least 1 value"));
             }
-            let pos = k * (n - 1) as f64;
-            let lower = pos.floor() as usize;
-            let upper = pos.ceil() as usize;
-            let frac = pos - lower as f64;
-            if lower == upper {
-                Value::Number(values[lower])
-            } else {
-                Value::Number(values[lower].mul_add(1.0 - frac, values[upper] * frac))
+            if values.iter().any(|&x| x <= 0.0) {
+                return Err(EvalError::new("HARMEAN requires positive values"));
             }
+            let reciprocal_sum: f64 = values.iter().map(|x| 1.0 / x).sum();
+            Value::Number(values.len() as f64 / reciprocal_sum)
+        },
+
+        "PERCENTILE" | "PERCENTILE.INC" => eval_percentile(name, args, ctx, false, false)?,
+
+        "PERCENTILE.EXC" => eval_percentile(name, args, ctx, true, false)?,
+
+        "QUARTILE" | "QUARTILE.INC" => eval_percentile(name, args, ctx, false, true)?,
+
+        "QUARTILE.EXC" => eval_percentile(name, args, ctx, true, true)?,
+
+        "SLOPE" => {
+            require_args(name, args, 2)?;
+            let (slope, _) = least_squares(name, &args[0], &args[1], ctx)?;
+            Value::Number(slope)
         },
 
-        "QUARTILE" => {
+        "INTERCEPT" => {
             require_args(name, args, 2)?;
-            let mut values = collect_numeric_values(&args[..1], ctx)?;
-            if values.is_empty() {
-                return Err(EvalError::new("QUARTILE of empty set"));
-            }
-            let quart = evaluate(&args[1], ctx)?
+            let (_, intercept) = least_squares(name, &args[0], &args[1], ctx)?;
+            Value::Number(intercept)
+        },
+
+        "FORECAST" | "FORECAST.LINEAR" => {
+            require_args(name, args, 3)?;
+            let x = evaluate(&args[0], ctx)?
                 .as_number()
-                .ok_or_else(|| EvalError::new("QUARTILE quart must be a number"))?
-                as i32;
-            if !(0..=4).contains(&quart) {
-                return Err(EvalError::new("QUARTILE quart must be 0, 1, 2, 3, or 4"));
-            }
-            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
-            let k = f64::from(quart) / 4.0;
-            let n = values.len();
-            if n == 1 {
-                return Ok(Some(Value::Number(values[0])));
-            }
-            let pos = k * (n - 1) as f64;
-            let lower = pos.floor() as usize;
-            let upper = pos.ceil() as usize;
-            let frac = pos - lower as f64;
-            if lower == upper {
-                Value::Number(values[lower])
-            } else {
-                Value::Number(values[lower].mul_add(1.0 - frac, values[upper] * frac))
-            }
+                .ok_or_else(|| EvalError::new(format!("{name}: x must be a number")))?;
+            // Known points are taken whole, so row-wise formulas project each row's x
+            let array_ctx = array_context(ctx);
+            let (slope, intercept) = least_squares(name, &args[1], &args[2], &array_ctx)?;
+            Value::Number(slope.mul_add(x, intercept))
         },
 
         "CORREL" => {
@@ -146,82 +145,13 @@ pub fn try_evaluate(
             Value::Number(cov / (var_x.sqrt() * var_y.sqrt()))
         },
 
-        "LARGE" => {
-            require_args(name, args, 2)?;
-            let mut values = collect_numeric_values(&args[..1], ctx)?;
-            if values.is_empty() {
-                return Err(EvalError::new("LARGE: array is empty"));
-            }
-            let k = evaluate(&args[1], ctx)?
-                .as_number()
-                .ok_or_else(|| EvalError::new("LARGE: k must be a number"))?
-                as usize;
-            if k == 0 || k > values.len() {
-                return Err(EvalError::new(format!(
-                    "LARGE: k={} out of range (1..{})",
-                    k,
-                    values.len()
-                )));
-            }
-            // Sort descending and get k-th largest
-            values.sort_by(|a, b| b.partial_cmp(a).unwrap());
-            Value::Number(values[k - 1])
-        },
+        "LARGE" => eval_large(args, ctx)?,
 
-        "SMALL" => {
-            require_args(name, args, 2)?;
-            let mut values = collect_numeric_values(&args[..1], ctx)?;
-            if values.is_empty() {
-                return Err(EvalError::new("SMALL: array is empty"));
-            }
-            let k = evaluate(&args[1], ctx)?
-                .as_number()
-                .ok_or_else(|| EvalError::new("SMALL: k must be a number"))?
-                as usize;
-            if k == 0 || k > values.len() {
-                return Err(EvalError::new(format!(
-                    "SMALL: k={} out of range (1..{})",
-                    k,
-                    values.len()
-                )));
-            }
-            // Sort ascending and get k-th smallest
-            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
-            Value::Number(values[k - 1])
-        },
+        "SMALL" => eval_small(args, ctx)?,
 
-        "RANK" | "RANK.EQ" => {
-            require_args_range(name, args, 2, 3)?;
-            let number = evaluate(&args[0], ctx)?
-                .as_number()
-                .ok_or_else(|| EvalError::new("RANK: number must be a number"))?;
-            let values = collect_numeric_values(&args[1..2], ctx)?;
-            if values.is_empty() {
-                return Err(EvalError::new("RANK: array is empty"));
-            }
-            let order = if args.len() > 2 {
-                evaluate(&args[2], ctx)?.as_number().unwrap_or(0.0) as i32
-            } else {
-                0 // Default: descending (0)
-            };
-
-            // Clone and sort
-            let mut sorted = values;
-            if order == 0 {
-                // Descending order (largest = rank 1)
-                sorted.sort_by(|a, b| b.partial_cmp(a).unwrap());
-            } else {
-                // Ascending order (smallest = rank 1)
-                sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
-            }
+        "RANK" | "RANK.EQ" => eval_rank(name, args, ctx, false)?,
 
-            // Find rank (1-based)
-            let rank = sorted.iter().position(|&x| (x - number).abs() < 1e-10);
-            match rank {
-                Some(pos) => Value::Number((pos + 1) as f64),
-                None => return Err(EvalError::new("RANK: value not found in array")),
-            }
-        },
+        "RANK.AVG" => eval_rank(name, args, ctx, true)?,
 
         _ => return Ok(None),
     };
@@ -229,6 +159,200 @@ pub fn try_evaluate(
     Ok(Some(result))
 }
 
+/// Ordinary least squares fit of `known_y` against `known_x`: (slope, intercept)
+#[allow(clippy::cast_precision_loss)] // counts are far below 2^52
+fn least_squares(
+    name: &str,
+    known_y: &Expr,
+    known_x: &Expr,
+    ctx: &EvalContext,
+) -> Result<(f64, f64), EvalError> {
+    let y_vals = collect_numeric_values(std::slice::from_ref(known_y), ctx)?;
+    let x_vals = collect_numeric_values(std::slice::from_ref(known_x), ctx)?;
+    if x_vals.len() != y_vals.len() {
+        return Err(EvalError::new(format!(
+            "{name}: known_y has {} values but known_x has {}",
+            y_vals.len(),
+            x_vals.len()
+        )));
+    }
+    if x_vals.len() < 2 {
+        return Err(EvalError::new(format!(
+            "{name} requires at least 2 data points"
+        )));
+    }
+    let n = x_vals.len() as f64;
+    let x_mean = x_vals.iter().sum::<f64>() / n;
+    let y_mean = y_vals.iter().sum::<f64>() / n;
+    let mut cov = 0.0;
+    let mut var_x = 0.0;
+    for (x, y) in x_vals.iter().zip(&y_vals) {
+        let dx = x - x_mean;
+        cov += dx * (y - y_mean);
+        var_x += dx * dx;
+    }
+    if var_x == 0.0 {
+        return Err(EvalError::new(format!(
+            "{name}: known_x values are all equal (zero variance)"
+        )));
+    }
+    let slope = cov / var_x;
+    Ok((slope, slope.mul_add(-x_mean, y_mean)))
+}
+
+/// `ctx` outside row-wise mode, so column references yield whole arrays
+fn array_context(ctx: &EvalContext) -> EvalContext {
+    EvalContext {
+        current_row: None,
+        ..ctx.clone()
+    }
+}
+
+/// PERCENTILE(array, k) / PERCENTILE.INC / PERCENTILE.EXC and
+/// QUARTILE(array, quart) / QUARTILE.INC / QUARTILE.EXC (quart is k * 4)
+///
+/// Interpolates linearly between ranked values as Excel does: the inclusive
+/// forms place k at rank `1 + k * (n - 1)`, accepting any k in [0, 1]; the
+/// exclusive forms place it at rank `k * (n + 1)`, which must fall within
+/// the data (k in [1/(n+1), n/(n+1)], quart 1 to 3).
+#[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)] // ranks are far below 2^52
+fn eval_percentile(
+    name: &str,
+    args: &[Expr],
+    ctx: &EvalContext,
+    exclusive: bool,
+    quartile: bool,
+) -> Result<Value, EvalError> {
+    require_args(name, args, 2)?;
+    let mut values = collect_numeric_values(&args[..1], ctx)?;
+    if values.is_empty() {
+        return Err(EvalError::new(format!("{name} of empty set")));
+    }
+    let arg = if quartile { "quart" } else { "k" };
+    let mut k = evaluate(&args[1], ctx)?
+        .as_number()
+        .ok_or_else(|| EvalError::new(format!("{name} {arg} must be a number")))?;
+    if quartile {
+        let quarts = if exclusive { 1.0..=3.0 } else { 0.0..=4.0 };
+        k = k.trunc();
+        if !quarts.contains(&k) {
+            return Err(EvalError::new(format!(
+                "{name} quart must be an integer from {} to {}",
+                quarts.start(),
+                quarts.end()
+            )));
+        }
+        k /= 4.0;
+    } else if !(0.0..=1.0).contains(&k) {
+        return Err(EvalError::new(format!("{name} k must be between 0 and 1")));
+    }
+
+    values.sort_by(f64::total_cmp);
+    let n = values.len() as f64;
+    // 0-based fractional index of the percentile
+    let pos = if exclusive {
+        let rank = k * (n + 1.0);
+        if rank < 1.0 || rank > n {
+            return Err(EvalError::new(format!(
+                "{name} {arg} {} is outside the data (k must be between 1/(n+1) and n/(n+1))",
+                if quartile { k * 4.0 } else { k }
+            )));
+        }
+        rank - 1.0
+    } else {
+        k * (n - 1.0)
+    };
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    let frac = pos - pos.floor();
+    Ok(Value::Number(
+        values[lower].mul_add(1.0 - frac, values[upper] * frac),
+    ))
+}
+
+/// LARGE(array, k) - the k-th largest value (1-based)
+fn eval_large(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    nth_ranked("LARGE", args, ctx, |a, b| b.total_cmp(a))
+}
+
+/// SMALL(array, k) - the k-th smallest value (1-based)
+fn eval_small(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    nth_ranked("SMALL", args, ctx, f64::total_cmp)
+}
+
+/// The k-th value of the array in `order`; duplicates keep separate ranks,
+/// so LARGE of [5, 5, 3] with k=2 is 5
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // k is a small count
+fn nth_ranked(
+    name: &str,
+    args: &[Expr],
+    ctx: &EvalContext,
+    order: impl Fn(&f64, &f64) -> std::cmp::Ordering,
+) -> Result<Value, EvalError> {
+    require_args(name, args, 2)?;
+    let mut values = collect_numeric_values(&args[..1], ctx)?;
+    if values.is_empty() {
+        return Err(EvalError::new(format!("{name}: array is empty")));
+    }
+    let k = evaluate(&args[1], ctx)?
+        .as_number()
+        .ok_or_else(|| EvalError::new(format!("{name}: k must be a number")))? as usize;
+    if k == 0 || k > values.len() {
+        return Err(EvalError::new(format!(
+            "{name}: k={k} out of range (1..{})",
+            values.len()
+        )));
+    }
+    values.sort_by(order);
+    Ok(Value::Number(values[k - 1]))
+}
+
+/// RANK(value, array, [order]) / RANK.EQ / RANK.AVG - position of `value`
+/// in the array, largest first unless `order` is non-zero
+///
+/// Tied values share the best rank (RANK, RANK.EQ) or the average of their
+/// positions (RANK.AVG). The array is always taken whole, so in a row-wise
+/// formula `=RANK.EQ(score, data.score)` ranks each row within the column.
+#[allow(clippy::cast_precision_loss)] // counts are far below 2^52
+fn eval_rank(
+    name: &str,
+    args: &[Expr],
+    ctx: &EvalContext,
+    average: bool,
+) -> Result<Value, EvalError> {
+    require_args_range(name, args, 2, 3)?;
+    let number = evaluate(&args[0], ctx)?
+        .as_number()
+        .ok_or_else(|| EvalError::new(format!("{name}: number must be a number")))?;
+
+    let values = collect_numeric_values(&args[1..2], &array_context(ctx))?;
+    if values.is_empty() {
+        return Err(EvalError::new(format!("{name}: array is empty")));
+    }
+    let ascending = match args.get(2) {
+        Some(order) => evaluate(order, ctx)?.as_number().unwrap_or(0.0) != 0.0,
+        None => false, // Default: descending (largest = rank 1)
+    };
+
+    let ties = values
+        .iter()
+        .filter(|&&x| (x - number).abs() < 1e-10)
+        .count();
+    if ties == 0 {
+        return Err(EvalError::new(format!("{name}: value not found in array")));
+    }
+    let ahead = values
+        .iter()
+        .filter(|&&x| (x - number).abs() >= 1e-10 && (x < number) == ascending)
+        .count();
+    let rank = if average {
+        ahead as f64 + f64::midpoint(ties as f64, 1.0)
+    } else {
+        (ahead + 1) as f64
+    };
+    Ok(Value::Number(rank))
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::tests::eval;
@@ -311,6 +435,119 @@ mod tests {
         ); // Q2 = median
     }
 
+    #[test]
+    fn test_geomean_three_period_cagr() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars
+            .insert("growth".to_string(), array(&[1.1, 1.2, 0.9]));
+        ctx.scalars.insert("eight".to_string(), array(&[8.0]));
+        let num = |formula: &str| eval(formula, &ctx).unwrap().as_number().unwrap();
+
+        // (1.1 * 1.2 * 0.9)^(1/3) = 1.188^(1/3): a CAGR of about 5.91%
+        let geomean = num("GEOMEAN(growth)");
+        assert!((geomean - 1.188_f64.cbrt()).abs() < 1e-12);
+        assert!((geomean - 1.059_105).abs() < 1e-6);
+        // Scalars and arrays flatten together; text is ignored
+        assert!((num("GEOMEAN(2, \"x\", eight)") - 4.0).abs() < 1e-12);
+        assert!(eval("GEOMEAN(1, 0, 2)", &ctx).is_err());
+        assert!(eval("GEOMEAN(1, -2)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_slope_intercept_forecast() {
+        let mut ctx = EvalContext::new();
+        // Revenue by year; the least squares fit is y = 1.1x + 8.9
+        ctx.scalars.insert(
+            "revenue".to_string(),
+            array(&[10.0, 11.0, 12.5, 13.0, 14.5]),
+        );
+        ctx.scalars
+            .insert("year".to_string(), array(&[1.0, 2.0, 3.0, 4.0, 5.0]));
+        let num = |formula: &str| eval(formula, &ctx).unwrap().as_number().unwrap();
+
+        assert!((num("SLOPE(revenue, year)") - 1.1).abs() < 1e-12);
+        assert!((num("INTERCEPT(revenue, year)") - 8.9).abs() < 1e-12);
+        assert!((num("FORECAST.LINEAR(6, revenue, year)") - 15.5).abs() < 1e-12);
+        assert!((num("FORECAST(6, revenue, year)") - 15.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_least_squares_validation() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars.insert("y".to_string(), array(&[1.0, 2.0, 3.0]));
+        ctx.scalars.insert("short".to_string(), array(&[1.0, 2.0]));
+        ctx.scalars
+            .insert("flat".to_string(), array(&[4.0, 4.0, 4.0]));
+        ctx.scalars.insert("one".to_string(), array(&[1.0]));
+        let err = |formula: &str| eval(formula, &ctx).unwrap_err().message;
+
+        assert!(err("SLOPE(y, short)").contains("3 values but known_x has 2"));
+        assert!(err("INTERCEPT(one, one)").contains("at least 2"));
+        assert!(err("FORECAST.LINEAR(1, y, flat)").contains("zero variance"));
+    }
+
+    #[test]
+    fn test_harmean() {
+        let ctx = EvalContext::new();
+        // 3 / (1/1 + 1/2 + 1/4) = 12/7
+        let harmean = eval("HARMEAN(1, 2, 4)", &ctx).unwrap().as_number().unwrap();
+        assert!((harmean - 12.0 / 7.0).abs() < 1e-12);
+        assert!(eval("HARMEAN(1, 0)", &ctx).is_err());
+    }
+
+    fn array(values: &[f64]) -> Value {
+        Value::Array(values.iter().map(|&n| Value::Number(n)).collect())
+    }
+
+    #[test]
+    fn test_percentile_quartile_inc_exc() {
+        // Reference values agree with Excel and Gnumeric
+        let mut ctx = EvalContext::new();
+        ctx.scalars
+            .insert("a".to_string(), array(&[1.0, 3.0, 2.0, 4.0]));
+        ctx.scalars.insert(
+            "b".to_string(),
+            array(&[1.0, 2.0, 3.0, 6.0, 6.0, 6.0, 7.0, 8.0, 9.0]),
+        );
+        ctx.scalars.insert(
+            "c".to_string(),
+            array(&[
+                6.0, 7.0, 15.0, 36.0, 39.0, 40.0, 41.0, 42.0, 43.0, 47.0, 49.0,
+            ]),
+        );
+        ctx.scalars.insert(
+            "d".to_string(),
+            array(&[1.0, 2.0, 4.0, 7.0, 8.0, 9.0, 10.0, 12.0]),
+        );
+        let num = |formula: &str| eval(formula, &ctx).unwrap().as_number().unwrap();
+
+        assert!((num("PERCENTILE.INC(a, 0.3)") - 1.9).abs() < 1e-12);
+        assert!((num("PERCENTILE(a, 0.3)") - 1.9).abs() < 1e-12);
+        assert!((num("PERCENTILE.EXC(b, 0.25)") - 2.5).abs() < 1e-12);
+        assert!((num("PERCENTILE.EXC(b, 0.5)") - 6.0).abs() < 1e-12);
+        assert!((num("QUARTILE.INC(d, 1)") - 3.5).abs() < 1e-12);
+        assert!((num("QUARTILE.INC(d, 4)") - 12.0).abs() < 1e-12);
+        assert!((num("QUARTILE.EXC(c, 1)") - 15.0).abs() < 1e-12);
+        assert!((num("QUARTILE.EXC(c, 3)") - 43.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_percentile_quartile_bounds() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars
+            .insert("a".to_string(), array(&[1.0, 2.0, 3.0, 4.0]));
+        ctx.scalars.insert("empty".to_string(), array(&[]));
+        let err = |formula: &str| eval(formula, &ctx).unwrap_err().message;
+
+        assert!(err("PERCENTILE.INC(a, 1.5)").contains("between 0 and 1"));
+        // Exclusive k must land inside the data: 1/5 <= k <= 4/5
+        assert!(err("PERCENTILE.EXC(a, 0.1)").contains("outside the data"));
+        assert!(err("PERCENTILE.EXC(a, 0.9)").contains("outside the data"));
+        assert!(err("QUARTILE.EXC(a, 0)").contains("from 1 to 3"));
+        assert!(err("QUARTILE.INC(a, 5)").contains("from 0 to 4"));
+        assert!(err("PERCENTILE.INC(empty, 0.5)").contains("empty set"));
+    }
+
     #[test]
     fn test_varp_alias() {
         let ctx = EvalContext::new();
@@ -373,6 +610,35 @@ mod tests {
         assert_eq!(eval("SMALL(t.data, 3)", &ctx).unwrap(), Value::Number(3.0));
     }
 
+    #[test]
+    fn test_large_small_duplicates_and_bounds() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars.insert(
+            "revenue".to_string(),
+            Value::Array(vec![
+                Value::Number(5.0),
+                Value::Number(3.0),
+                Value::Number(5.0),
+            ]),
+        );
+        // Duplicates are ranked positionally
+        assert_eq!(eval("LARGE(revenue, 2)", &ctx).unwrap(), Value::Number(5.0));
+        assert_eq!(eval("LARGE(revenue, 3)", &ctx).unwrap(), Value::Number(3.0));
+        assert_eq!(eval("SMALL(revenue, 2)", &ctx).unwrap(), Value::Number(5.0));
+
+        for formula in [
+            "LARGE(revenue, 0)",
+            "LARGE(revenue, 4)",
+            "SMALL(revenue, -1)",
+        ] {
+            let err = eval(formula, &ctx).unwrap_err();
+            assert!(err.message.contains("out of range"), "{}", err.message);
+        }
+        ctx.scalars
+            .insert("empty".to_string(), Value::Array(Vec::new()));
+        assert!(eval("SMALL(empty, 1)", &ctx).is_err());
+    }
+
     #[test]
     fn test_rank() {
         let mut ctx = EvalContext::new();
@@ -399,4 +665,41 @@ mod tests {
             Value::Number(1.0)
         );
     }
+
+    #[test]
+    fn test_rank_ties_eq_and_avg() {
+        let mut ctx = EvalContext::new();
+        ctx.scalars.insert(
+            "scores".to_string(),
+            Value::Array(
+                [90.0, 85.0, 90.0, 70.0, 90.0]
+                    .iter()
+                    .map(|&n| Value::Number(n))
+                    .collect(),
+            ),
+        );
+        // Three-way tie for positions 1-3
+        assert_eq!(
+            eval("RANK.EQ(90, scores)", &ctx).unwrap(),
+            Value::Number(1.0)
+        );
+        assert_eq!(
+            eval("RANK.AVG(90, scores)", &ctx).unwrap(),
+            Value::Number(2.0)
+        );
+        assert_eq!(
+            eval("RANK.AVG(85, scores)", &ctx).unwrap(),
+            Value::Number(4.0)
+        );
+        // Ascending: the tie holds positions 3-5
+        assert_eq!(
+            eval("RANK.EQ(90, scores, 1)", &ctx).unwrap(),
+            Value::Number(3.0)
+        );
+        assert_eq!(
+            eval("RANK.AVG(90, scores, 1)", &ctx).unwrap(),
+            Value::Number(4.0)
+        );
+        assert!(eval("RANK.AVG(60, scores)", &ctx).is_err());
+    }
 }
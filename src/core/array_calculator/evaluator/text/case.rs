@@ -1,4 +1,4 @@
-//! Case conversion and whitespace functions: UPPER, LOWER, TRIM
+//! Case conversion, comparison and whitespace functions: UPPER, LOWER, PROPER, EXACT, TRIM
 
 use super::super::{evaluate, require_args, EvalContext, EvalError, Expr, Value};
 
@@ -16,6 +16,44 @@ pub fn eval_lower(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError>
     Ok(Value::Text(val.as_text().to_lowercase()))
 }
 
+/// PROPER(text) - Capitalizes the first letter of each word, lowercases the rest
+///
+/// As in Excel, a letter starts a word when it follows any non-letter
+/// character, so "o'brien-smith" becomes "O'Brien-Smith". Arrays are
+/// converted element-wise.
+pub fn eval_proper(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    Ok(match evaluate(&args[0], ctx)? {
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| Value::Text(proper(&item.as_text())))
+                .collect(),
+        ),
+        val => Value::Text(proper(&val.as_text())),
+    })
+}
+
+fn proper(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut after_letter = false;
+    for c in text.chars() {
+        if after_letter {
+            result.extend(c.to_lowercase());
+        } else {
+            result.extend(c.to_uppercase());
+        }
+        after_letter = c.is_alphabetic();
+    }
+    result
+}
+
+/// EXACT(text1, text2) - Case-sensitive comparison (`=` ignores case for text)
+pub fn eval_exact(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    let left = evaluate(&args[0], ctx)?.as_text();
+    let right = evaluate(&args[1], ctx)?.as_text();
+    Ok(Value::Boolean(left == right))
+}
+
 /// TRIM(text) - Removes leading/trailing spaces and collapses internal spaces
 pub fn eval_trim(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
     require_args("TRIM", args, 1)?;
@@ -98,6 +136,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_proper_function_arraycalc() {
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("vendors".to_string());
+
+        table.add_column(Column::new(
+            "name".to_string(),
+            ColumnValue::Text(vec![
+                "ACME corp".to_string(),
+                "o'brien-smith".to_string(),
+                "3m company".to_string(),
+            ]),
+        ));
+        table.add_row_formula("proper".to_string(), "=PROPER(name)".to_string());
+
+        model.add_table(table);
+        let calculator = ArrayCalculator::new(model);
+        let result = calculator
+            .calculate_all()
+            .expect("Calculation should succeed");
+        let result_table = result.tables.get("vendors").unwrap();
+
+        let proper = result_table.columns.get("proper").unwrap();
+        match &proper.values {
+            ColumnValue::Text(texts) => {
+                assert_eq!(texts[0], "Acme Corp");
+                assert_eq!(texts[1], "O'Brien-Smith");
+                assert_eq!(texts[2], "3M Company");
+            },
+            _ => panic!("Expected Text array"),
+        }
+    }
+
     #[test]
     fn test_trim_function_arraycalc() {
         let mut model = ParsedModel::new();
@@ -575,4 +646,30 @@ mod tests {
             _ => panic!("Expected Text array"),
         }
     }
+
+    #[test]
+    fn test_exact_is_case_sensitive() {
+        use crate::core::array_calculator::evaluator::tests::eval;
+        use crate::core::array_calculator::evaluator::{EvalContext, Value};
+
+        let ctx = EvalContext::new();
+        assert_eq!(
+            eval("EXACT(\"ABC\", \"abc\")", &ctx).unwrap(),
+            Value::Boolean(false)
+        );
+        assert_eq!(
+            eval("EXACT(\"ABC\", \"ABC\")", &ctx).unwrap(),
+            Value::Boolean(true)
+        );
+        // `=` stays case-insensitive
+        assert_eq!(
+            eval("\"ABC\" = \"abc\"", &ctx).unwrap(),
+            Value::Boolean(true)
+        );
+        // Numbers compare by their text form, as in Excel
+        assert_eq!(
+            eval("EXACT(10, \"10\")", &ctx).unwrap(),
+            Value::Boolean(true)
+        );
+    }
 }
@@ -0,0 +1,212 @@
+//! Delimiter functions: TEXTBEFORE, TEXTAFTER (enterprise only)
+
+// Delimiter casts: instance_num is a small signed count compared against char counts.
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+
+use super::super::{evaluate, EvalContext, EvalError, Expr, Value};
+
+/// Which side of the chosen delimiter to return
+#[derive(Clone, Copy)]
+enum Side {
+    Before,
+    After,
+}
+
+/// `TEXTBEFORE(text, delimiter, [instance_num], [match_mode], [match_end], [if_not_found])`
+pub fn eval_textbefore(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    eval_text_split("TEXTBEFORE", Side::Before, args, ctx)
+}
+
+/// `TEXTAFTER(text, delimiter, [instance_num], [match_mode], [match_end], [if_not_found])`
+pub fn eval_textafter(args: &[Expr], ctx: &EvalContext) -> Result<Value, EvalError> {
+    eval_text_split("TEXTAFTER", Side::After, args, ctx)
+}
+
+/// Shared TEXTBEFORE/TEXTAFTER logic (Excel semantics)
+///
+/// `instance_num` counts delimiters from the start (default 1) or, when
+/// negative, from the end. `match_mode` 1 matches case-insensitively.
+/// `match_end` 1 treats the end of the text (the start, for negative
+/// instances) as a delimiter. When the delimiter is not found the result is
+/// `if_not_found`, or #N/A (Null, like `NA()`) when it is omitted.
+fn eval_text_split(
+    name: &str,
+    side: Side,
+    args: &[Expr],
+    ctx: &EvalContext,
+) -> Result<Value, EvalError> {
+    let text: Vec<char> = evaluate(&args[0], ctx)?.as_text().chars().collect();
+    let delimiter: Vec<char> = evaluate(&args[1], ctx)?.as_text().chars().collect();
+    let number_arg = |i: usize, default: f64| -> Result<f64, EvalError> {
+        match args.get(i) {
+            Some(arg) => Ok(evaluate(arg, ctx)?.as_number().unwrap_or(default)),
+            None => Ok(default),
+        }
+    };
+    let instance = number_arg(2, 1.0)?.trunc() as i64;
+    let ignore_case = number_arg(3, 0.0)? != 0.0;
+    let match_end = number_arg(4, 0.0)? != 0.0;
+
+    if instance == 0 {
+        return Err(EvalError::new(format!("{name}: instance_num cannot be 0")));
+    }
+    if instance.unsigned_abs() as usize > text.len().max(1) {
+        return Err(EvalError::new(format!(
+            "{name}: instance_num {instance} exceeds the length of the text"
+        )));
+    }
+
+    let mut matches = find_delimiters(&text, &delimiter, ignore_case);
+    if match_end {
+        if instance > 0 {
+            matches.push((text.len(), text.len()));
+        } else {
+            matches.insert(0, (0, 0));
+        }
+    }
+
+    let chosen = if instance > 0 {
+        matches.get(instance as usize - 1)
+    } else {
+        matches
+            .len()
+            .checked_sub(instance.unsigned_abs() as usize)
+            .map(|i| &matches[i])
+    };
+    let Some(&(start, end)) = chosen else {
+        return args
+            .get(5)
+            .map_or(Ok(Value::Null), |default| evaluate(default, ctx));
+    };
+
+    let part = match side {
+        Side::Before => &text[..start],
+        Side::After => &text[end..],
+    };
+    Ok(Value::Text(part.iter().collect()))
+}
+
+/// Non-overlapping `(start, end)` char ranges of `delimiter` in `text`,
+/// scanning left to right
+///
+/// An empty delimiter matches once at the very start and once at the very
+/// end, as in Excel.
+fn find_delimiters(text: &[char], delimiter: &[char], ignore_case: bool) -> Vec<(usize, usize)> {
+    if delimiter.is_empty() {
+        return vec![(0, 0), (text.len(), text.len())];
+    }
+    let same = |a: &char, b: &char| {
+        if ignore_case {
+            a.to_lowercase().eq(b.to_lowercase())
+        } else {
+            a == b
+        }
+    };
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + delimiter.len() <= text.len() {
+        if text[i..i + delimiter.len()]
+            .iter()
+            .zip(delimiter)
+            .all(|(a, b)| same(a, b))
+        {
+            matches.push((i, i + delimiter.len()));
+            i += delimiter.len();
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::eval;
+    use super::*;
+
+    fn text(formula: &str) -> Value {
+        eval(formula, &EvalContext::new()).unwrap()
+    }
+
+    #[test]
+    fn test_textbefore_textafter_first_instance() {
+        assert_eq!(
+            text("TEXTBEFORE(\"ACME-US-2025\", \"-\")"),
+            Value::Text("ACME".to_string())
+        );
+        assert_eq!(
+            text("TEXTAFTER(\"ACME-US-2025\", \"-\")"),
+            Value::Text("US-2025".to_string())
+        );
+        // Multi-character delimiter
+        assert_eq!(
+            text("TEXTAFTER(\"key => value\", \" => \")"),
+            Value::Text("value".to_string())
+        );
+    }
+
+    #[test]
+    fn test_textbefore_textafter_nth_instance() {
+        assert_eq!(
+            text("TEXTBEFORE(\"ACME-US-2025\", \"-\", 2)"),
+            Value::Text("ACME-US".to_string())
+        );
+        assert_eq!(
+            text("TEXTAFTER(\"ACME-US-2025\", \"-\", 2)"),
+            Value::Text("2025".to_string())
+        );
+        // Fewer delimiters than requested: #N/A
+        assert_eq!(text("TEXTAFTER(\"ACME-US-2025\", \"-\", 3)"), Value::Null);
+    }
+
+    #[test]
+    fn test_textbefore_textafter_from_the_end() {
+        assert_eq!(
+            text("TEXTBEFORE(\"ACME-US-2025\", \"-\", -1)"),
+            Value::Text("ACME-US".to_string())
+        );
+        assert_eq!(
+            text("TEXTAFTER(\"ACME-US-2025\", \"-\", -1)"),
+            Value::Text("2025".to_string())
+        );
+        assert_eq!(
+            text("TEXTAFTER(\"ACME-US-2025\", \"-\", -2)"),
+            Value::Text("US-2025".to_string())
+        );
+    }
+
+    #[test]
+    fn test_textbefore_textafter_not_found_and_options() {
+        // Not found: #N/A, or the if_not_found default
+        assert_eq!(text("TEXTBEFORE(\"ACME\", \"-\")"), Value::Null);
+        assert_eq!(
+            text("TEXTBEFORE(\"ACME\", \"-\", 1, 0, 0, \"none\")"),
+            Value::Text("none".to_string())
+        );
+        // match_end treats the end of the text as a delimiter
+        assert_eq!(
+            text("TEXTBEFORE(\"ACME\", \"-\", 1, 0, 1)"),
+            Value::Text("ACME".to_string())
+        );
+        // match_mode 1 is case-insensitive
+        assert_eq!(text("TEXTAFTER(\"2025Q3\", \"q\")"), Value::Null);
+        assert_eq!(
+            text("TEXTAFTER(\"2025Q3\", \"q\", 1, 1)"),
+            Value::Text("3".to_string())
+        );
+        // Empty delimiter matches at the start (or the end, counting back)
+        assert_eq!(
+            text("TEXTBEFORE(\"abc\", \"\")"),
+            Value::Text(String::new())
+        );
+        assert_eq!(
+            text("TEXTAFTER(\"abc\", \"\", -1)"),
+            Value::Text(String::new())
+        );
+
+        let ctx = EvalContext::new();
+        assert!(eval("TEXTBEFORE(\"a-b\", \"-\", 0)", &ctx).is_err());
+        assert!(eval("TEXTBEFORE(\"a-b\", \"-\", 10)", &ctx).is_err());
+    }
+}
@@ -1,11 +1,12 @@
-//! Text functions: CONCAT, UPPER, LOWER, TRIM, LEN, LEFT, RIGHT, MID, REPT, TEXT, VALUE, FIND, SEARCH, REPLACE, SUBSTITUTE
+//! Text functions: CONCAT, UPPER, LOWER, TRIM, LEN, LEFT, RIGHT, MID, REPT, TEXT, VALUE, FIND, SEARCH, REPLACE, SUBSTITUTE, TEXTBEFORE, TEXTAFTER, EXACT, PROPER
 //!
 //! DEMO functions (9): CONCAT, LEFT, RIGHT, MID, REPT, LEN, UPPER, LOWER, TRIM
-//! ENTERPRISE functions: CONCATENATE, TEXT, VALUE, FIND, SEARCH, REPLACE, SUBSTITUTE
+//! ENTERPRISE functions: CONCATENATE, TEXT, VALUE, FIND, SEARCH, REPLACE, SUBSTITUTE, TEXTBEFORE, TEXTAFTER, EXACT, PROPER
 
 mod case;
 mod concat;
 mod convert;
+mod delimit;
 mod length;
 mod replace;
 mod search;
@@ -51,6 +52,14 @@ pub fn try_evaluate(
 
         "SUBSTITUTE" => replace::eval_substitute(args, ctx)?,
 
+        "TEXTBEFORE" => delimit::eval_textbefore(args, ctx)?,
+
+        "TEXTAFTER" => delimit::eval_textafter(args, ctx)?,
+
+        "EXACT" => case::eval_exact(args, ctx)?,
+
+        "PROPER" => case::eval_proper(args, ctx)?,
+
         _ => return Ok(None),
     };
 
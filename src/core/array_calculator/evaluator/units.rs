@@ -0,0 +1,243 @@
+//! Unit propagation for strict unit checking
+//!
+//! Walks a formula's AST carrying the unit of each sub-expression, so that
+//! `revenue + costs` (USD + USD) passes while `revenue + margin` (USD + %)
+//! fails instead of only producing a [`UnitValidator`] warning.
+//!
+//! [`UnitValidator`]: crate::core::unit_validator::UnitValidator
+
+use super::{EvalContext, EvalError, Expr, Reference};
+use crate::core::unit_validator::UnitCategory;
+
+/// Functions whose result carries the (common) unit of their arguments
+const UNIT_PRESERVING: &[&str] = &[
+    "SUM", "AVERAGE", "AVG", "MIN", "MAX", "MEDIAN", "ABS", "CEILING", "FLOOR", "TRUNC", "INT",
+];
+
+/// Infer the unit of an expression, failing on incompatible additions/comparisons
+///
+/// Returns `None` for dimensionless or unknown results. Only units registered in
+/// `ctx.units` participate; untagged references are compatible with anything.
+///
+/// # Errors
+///
+/// Returns an error naming both units when values of incompatible units are
+/// added, subtracted or compared.
+pub fn infer_unit(expr: &Expr, ctx: &EvalContext) -> Result<Option<UnitCategory>, EvalError> {
+    match expr {
+        Expr::Reference(reference) => {
+            let key = match reference {
+                Reference::Scalar(name) => name.clone(),
+                Reference::TableColumn { table, column } => format!("{table}.{column}"),
+            };
+            Ok(ctx.units.get(&key).cloned())
+        },
+        Expr::UnaryOp { operand, .. } => infer_unit(operand, ctx),
+        Expr::BinaryOp { op, left, right } => {
+            let l = infer_unit(left, ctx)?;
+            let r = infer_unit(right, ctx)?;
+            match op.as_str() {
+                "+" | "-" => common_unit(op, l, r),
+                "*" => Ok(multiply(l, r)),
+                "/" => Ok(divide(l, r)),
+                "=" | "<>" | "<" | ">" | "<=" | ">=" => common_unit(op, l, r).map(|_| None),
+                _ => Ok(None),
+            }
+        },
+        Expr::FunctionCall { name, args } => {
+            let name = name.to_uppercase();
+            let units = args
+                .iter()
+                .map(|arg| infer_unit(arg, ctx))
+                .collect::<Result<Vec<_>, _>>()?;
+            let combined = match name.as_str() {
+                // SUBTOTAL's first argument is the function number, IF's is the condition
+                "SUBTOTAL" | "IF" => units.get(1..).unwrap_or_default(),
+                // ROUND-style functions take a digit count after the value
+                "ROUND" | "ROUNDUP" | "ROUNDDOWN" => units.get(..1).unwrap_or_default(),
+                n if UNIT_PRESERVING.contains(&n) => &units,
+                _ => return Ok(None),
+            };
+            combined
+                .iter()
+                .cloned()
+                .try_fold(None, |acc, unit| common_unit(&name, acc, unit))
+        },
+        Expr::ArrayIndex { array, index } => {
+            infer_unit(index, ctx)?;
+            infer_unit(array, ctx)
+        },
+        Expr::ArraySlice { array, start, end } => {
+            for bound in [start, end].into_iter().flatten() {
+                infer_unit(bound, ctx)?;
+            }
+            infer_unit(array, ctx)
+        },
+        Expr::CallResult { args, .. } => {
+            for arg in args {
+                infer_unit(arg, ctx)?;
+            }
+            Ok(None)
+        },
+        Expr::Number(_) | Expr::Text(_) | Expr::Boolean(_) | Expr::Range { .. } => Ok(None),
+    }
+}
+
+/// Unit shared by two operands that must be compatible
+fn common_unit(
+    context: &str,
+    left: Option<UnitCategory>,
+    right: Option<UnitCategory>,
+) -> Result<Option<UnitCategory>, EvalError> {
+    match (left, right) {
+        (Some(l), Some(r)) if !l.can_add(&r) => Err(EvalError::new(format!(
+            "Unit mismatch in '{context}': {} and {}",
+            l.display(),
+            r.display()
+        ))),
+        (l, r) => Ok(l.or(r)),
+    }
+}
+
+/// Percentages and ratios scale the other operand's unit
+fn multiply(left: Option<UnitCategory>, right: Option<UnitCategory>) -> Option<UnitCategory> {
+    match (left, right) {
+        (Some(UnitCategory::Percentage | UnitCategory::Ratio) | None, other)
+        | (other, Some(UnitCategory::Percentage | UnitCategory::Ratio) | None) => other,
+        // Compound units (e.g. USD x count) are not tracked
+        _ => None,
+    }
+}
+
+/// Like units cancel to a ratio; dividing by a scale factor keeps the unit
+fn divide(left: Option<UnitCategory>, right: Option<UnitCategory>) -> Option<UnitCategory> {
+    match (left, right) {
+        (Some(l), Some(r)) if l == r => Some(UnitCategory::Ratio),
+        (l, None | Some(UnitCategory::Percentage | UnitCategory::Ratio)) => l,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::array_calculator::parser::parse;
+    use crate::core::array_calculator::tokenizer::tokenize;
+
+    fn unit_of(formula: &str, ctx: &EvalContext) -> Result<Option<UnitCategory>, EvalError> {
+        let ast = parse(tokenize(formula).unwrap()).unwrap();
+        infer_unit(&ast, ctx)
+    }
+
+    fn units_ctx() -> EvalContext {
+        let mut ctx = EvalContext::new();
+        let usd = UnitCategory::parse("USD");
+        ctx.units.insert("revenue".to_string(), usd.clone());
+        ctx.units.insert("costs".to_string(), usd);
+        ctx.units
+            .insert("margin".to_string(), UnitCategory::parse("%"));
+        ctx.units
+            .insert("units_sold".to_string(), UnitCategory::parse("count"));
+        ctx
+    }
+
+    #[test]
+    fn test_same_currency_adds() {
+        let ctx = units_ctx();
+        assert_eq!(
+            unit_of("revenue - costs + 10", &ctx).unwrap(),
+            Some(UnitCategory::parse("USD"))
+        );
+        assert_eq!(
+            unit_of("SUM(revenue, costs)", &ctx).unwrap(),
+            Some(UnitCategory::parse("USD"))
+        );
+    }
+
+    #[test]
+    fn test_currency_plus_percent_fails() {
+        let ctx = units_ctx();
+        let err = unit_of("revenue + margin", &ctx).unwrap_err();
+        assert!(err.message.contains("USD and %"), "{}", err.message);
+        assert!(unit_of("IF(revenue > margin, 1, 0)", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_scaling_keeps_unit() {
+        let ctx = units_ctx();
+        assert_eq!(
+            unit_of("revenue * margin + costs", &ctx).unwrap(),
+            Some(UnitCategory::parse("USD"))
+        );
+        assert_eq!(
+            unit_of("ROUND(revenue / 2, 2)", &ctx).unwrap(),
+            Some(UnitCategory::parse("USD"))
+        );
+        assert_eq!(unit_of("revenue * units_sold", &ctx).unwrap(), None);
+        assert!(unit_of("revenue * units_sold + margin", &ctx).is_ok());
+        // Functions without unit semantics accept mixed arguments
+        assert_eq!(
+            unit_of("PMT(margin, units_sold, revenue)", &ctx).unwrap(),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    #![allow(clippy::float_cmp)] // Exact float comparison on simple integer sums
+    use crate::core::array_calculator::ArrayCalculator;
+    use crate::types::{Column, ColumnValue, Metadata, ParsedModel, Table};
+
+    fn tagged(name: &str, values: Vec<f64>, unit: &str) -> Column {
+        Column::with_metadata(
+            name.to_string(),
+            ColumnValue::Number(values),
+            Metadata {
+                unit: Some(unit.to_string()),
+                ..Metadata::default()
+            },
+        )
+    }
+
+    fn model_with(formula: &str) -> ParsedModel {
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("pnl".to_string());
+        table.add_column(tagged("revenue", vec![100.0, 200.0], "USD"));
+        table.add_column(tagged("costs", vec![60.0, 90.0], "USD"));
+        table.add_column(tagged("margin", vec![0.4, 0.55], "%"));
+        table.add_row_formula("result".to_string(), formula.to_string());
+        model.add_table(table);
+        model
+    }
+
+    #[test]
+    fn test_strict_units_allows_same_currency() {
+        let result = ArrayCalculator::new(model_with("=revenue + costs"))
+            .with_strict_units(true)
+            .calculate_all()
+            .expect("USD + USD should calculate");
+        let column = &result.tables["pnl"].columns["result"];
+        assert_eq!(column.metadata.unit.as_deref(), Some("USD"));
+        match &column.values {
+            ColumnValue::Number(values) => assert_eq!(values, &vec![160.0, 290.0]),
+            other => panic!("Expected numbers, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_strict_units_rejects_currency_plus_percent() {
+        let err = ArrayCalculator::new(model_with("=revenue + margin"))
+            .with_strict_units(true)
+            .calculate_all()
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("pnl.result"), "{message}");
+        assert!(message.contains("USD and %"), "{message}");
+
+        // Without strict mode the same model only warns (via UnitValidator)
+        assert!(ArrayCalculator::new(model_with("=revenue + margin"))
+            .calculate_all()
+            .is_ok());
+    }
+}
@@ -1,10 +1,55 @@
+mod broadcast;
+mod compile;
 mod dates;
 pub mod evaluator;
+mod order;
 pub mod parser;
+mod selection;
 pub mod tokenizer;
 
+pub use compile::{check, compile, CompileError};
+pub use selection::OutputSelection;
+
+use crate::core::unit_validator::UnitCategory;
 use crate::error::{ForgeError, ForgeResult};
-use crate::types::{Column, ColumnValue, ParsedModel, Table};
+use crate::types::{Column, ColumnValue, Metadata, NamedRange, ParsedModel, Table};
+use std::collections::{BTreeMap, HashMap, HashSet};
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Callback invoked with each output name just before it is computed
+type ComputeHook = Box<dyn Fn(&str) + Send + Sync>;
+
+/// Callback invoked with each value EXPLAIN records, and the output it belongs to
+type TraceHook = Box<dyn Fn(&str, &evaluator::TraceEntry) + Send + Sync>;
+
+/// Callback invoked with each false ASSERT condition, and the output it belongs to
+type AssertHook = Box<dyn Fn(&str, &evaluator::AssertFailure) + Send + Sync>;
+
+/// Text placed in a text column cell whose formula failed with an evaluation
+/// error (continue-on-error mode); error values use their own code instead
+pub const ERROR_TEXT: &str = "#ERROR!";
+
+/// A formula failure recorded by [`ArrayCalculator::calculate_all_with_errors`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormulaError {
+    /// Output that failed (`table.column` or scalar name)
+    pub location: String,
+    /// Row that failed, or `None` if the whole formula failed
+    pub row: Option<usize>,
+    /// Description of the failure
+    pub message: String,
+}
+
+impl std::fmt::Display for FormulaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.row {
+            Some(row) => write!(f, "{} (row {row}): {}", self.location, self.message),
+            None => write!(f, "{}: {}", self.location, self.message),
+        }
+    }
+}
 
 /// Strip string literals from a formula before extracting references.
 /// This prevents content inside quotes from being parsed as column references.
@@ -29,21 +74,226 @@ fn strip_string_literals(formula: &str) -> String {
     result
 }
 
+/// Replace named-range aliases in a formula with their target references
+///
+/// Used only for dependency extraction. Slices are dropped, and a target in
+/// `table` is reduced to its bare column name so it orders like a local column.
+fn expand_names(names: &HashMap<String, NamedRange>, formula: &str, table: Option<&str>) -> String {
+    if names.is_empty() {
+        return formula.to_string();
+    }
+    let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '.';
+    let mut result = String::with_capacity(formula.len());
+    let mut word = String::new();
+    let flush = |word: &mut String, result: &mut String| {
+        match names.get(word.as_str()) {
+            Some(range) => {
+                let target = range.reference.as_str();
+                let local = table.and_then(|t| {
+                    target
+                        .strip_prefix(t)
+                        .and_then(|rest| rest.strip_prefix('.'))
+                });
+                result.push_str(local.unwrap_or(target));
+            },
+            None => result.push_str(word),
+        }
+        word.clear();
+    };
+    for c in formula.chars() {
+        if is_word(c) {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut result);
+            result.push(c);
+        }
+    }
+    flush(&mut word, &mut result);
+    result
+}
+
+/// Column cells as evaluator values; error-tagged numbers become error values
+fn column_values(values: &ColumnValue) -> Vec<evaluator::Value> {
+    match values {
+        ColumnValue::Number(nums) => nums
+            .iter()
+            .map(|n| evaluator::Value::from_cell(*n))
+            .collect(),
+        ColumnValue::Text(texts) => texts
+            .iter()
+            .map(|s| evaluator::Value::Text(s.clone()))
+            .collect(),
+        ColumnValue::Boolean(bools) => bools
+            .iter()
+            .map(|b| evaluator::Value::Boolean(*b))
+            .collect(),
+        ColumnValue::Date(dates) => dates
+            .iter()
+            .map(|d| evaluator::Value::Text(d.clone()))
+            .collect(),
+    }
+}
+
+/// Whether a formula's outermost call is SUBTOTAL, marking its result as a subtotal
+fn is_subtotal_formula(formula: Option<&str>) -> bool {
+    formula.is_some_and(|f| {
+        f.trim_start_matches('=')
+            .trim_start()
+            .get(..9)
+            .is_some_and(|head| head.eq_ignore_ascii_case("SUBTOTAL("))
+    })
+}
+
 /// Array-aware calculator for v1.0.0 models
 /// Handles both row-wise (element-wise) and aggregation formulas
 pub struct ArrayCalculator {
     model: ParsedModel,
+    /// Fail on unit-incompatible arithmetic instead of leaving it to warnings
+    strict_units: bool,
+    /// Which outputs to compute
+    selection: OutputSelection,
+    /// Instrumentation: called before each output is computed
+    compute_hook: Option<ComputeHook>,
+    /// Record failures and keep going instead of stopping at the first
+    continue_on_error: bool,
+    /// Failures recorded in continue-on-error mode
+    failures: Vec<FormulaError>,
+    /// Receives EXPLAIN traces, tagged with the output being computed
+    trace_hook: Option<TraceHook>,
+    /// Buffer EXPLAIN writes to while an output is computed (set with `trace_hook`)
+    trace: Option<evaluator::TraceSink>,
+    /// Receives false ASSERT conditions, tagged with the output being computed
+    assert_hook: Option<AssertHook>,
+    /// Buffer ASSERT writes to while an output is computed (set with `assert_hook`)
+    assertions: Option<evaluator::AssertSink>,
+    /// Parsed formulas keyed by formula text, so each distinct formula parses once
+    asts: Mutex<BTreeMap<String, Arc<parser::Expr>>>,
+    /// Formulas tokenized and parsed so far (AST cache misses)
+    #[cfg(test)]
+    parse_count: AtomicUsize,
 }
 
 impl ArrayCalculator {
     #[must_use]
     pub const fn new(model: ParsedModel) -> Self {
-        Self { model }
+        Self {
+            model,
+            strict_units: false,
+            selection: OutputSelection::All,
+            compute_hook: None,
+            continue_on_error: false,
+            failures: Vec::new(),
+            trace_hook: None,
+            trace: None,
+            assert_hook: None,
+            assertions: None,
+            asts: Mutex::new(BTreeMap::new()),
+            #[cfg(test)]
+            parse_count: AtomicUsize::new(0),
+        }
+    }
+
+    /// Enforce unit compatibility during calculation
+    ///
+    /// Units declared on columns and scalars are propagated through each formula;
+    /// adding, subtracting or comparing incompatible units (e.g. USD + %) is an
+    /// error. Calculated columns and scalars are tagged with their inferred unit.
+    #[must_use]
+    pub const fn with_strict_units(mut self, strict: bool) -> Self {
+        self.strict_units = strict;
+        self
+    }
+
+    /// Compute only a subset of outputs (see [`OutputSelection`])
+    ///
+    /// Outputs that are not selected keep their current value; unselected
+    /// formula columns are absent from the result.
+    #[must_use]
+    pub fn with_selection(mut self, selection: OutputSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Register a callback invoked with each output name (`table.column` or
+    /// scalar name) just before it is computed
+    #[must_use]
+    pub fn with_compute_hook(mut self, hook: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.compute_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Register a callback that receives each value recorded by `EXPLAIN(expr)`
+    ///
+    /// Without a trace hook EXPLAIN is a pure pass-through. With one, every
+    /// evaluation of an EXPLAIN call (once per row in table formulas) is passed
+    /// to the hook with the output name (`table.column` or scalar name) once
+    /// that output has been computed.
+    #[must_use]
+    pub fn with_trace_hook(
+        mut self,
+        hook: impl Fn(&str, &evaluator::TraceEntry) + Send + Sync + 'static,
+    ) -> Self {
+        self.trace_hook = Some(Box::new(hook));
+        self.trace = Some(evaluator::TraceSink::default());
+        self
+    }
+
+    /// Hand the traces recorded while computing `output` to the trace hook
+    fn flush_trace(&self, output: &str) {
+        let (Some(hook), Some(sink)) = (&self.trace_hook, &self.trace) else {
+            return;
+        };
+        let entries = sink.lock().map(|mut e| std::mem::take(&mut *e));
+        for entry in entries.unwrap_or_default() {
+            hook(output, &entry);
+        }
+    }
+
+    /// Register a callback that receives each false `ASSERT(condition, [message])`
+    ///
+    /// ASSERT always returns its condition; the hook is how callers such as
+    /// `calculate --strict` find out which assertions failed. Failures are
+    /// passed with the output name once that output has been computed.
+    #[must_use]
+    pub fn with_assert_hook(
+        mut self,
+        hook: impl Fn(&str, &evaluator::AssertFailure) + Send + Sync + 'static,
+    ) -> Self {
+        self.assert_hook = Some(Box::new(hook));
+        self.assertions = Some(evaluator::AssertSink::default());
+        self
+    }
+
+    /// Hand the assertion failures recorded while computing `output` to the assert hook
+    fn flush_assertions(&self, output: &str) {
+        let (Some(hook), Some(sink)) = (&self.assert_hook, &self.assertions) else {
+            return;
+        };
+        let failures = sink.lock().map(|mut f| std::mem::take(&mut *f));
+        for failure in failures.unwrap_or_default() {
+            hook(output, &failure);
+        }
+    }
+
+    /// Whether an output is selected, notifying the compute hook if so
+    fn begin_output(&self, selected: Option<&HashSet<String>>, output: &str) -> bool {
+        if selected.is_some_and(|s| !s.contains(output)) {
+            return false;
+        }
+        if let Some(hook) = &self.compute_hook {
+            hook(output);
+        }
+        true
     }
 
     /// Calculate all formulas in the model
     /// Returns updated model with calculated values
     ///
+    /// A formula whose result is an Excel error value (`1/0` is `#DIV/0!`)
+    /// does not fail: its number cells carry the error's kind (see
+    /// [`evaluator::ErrorKind::from_nan`]) and its text cells hold the error's
+    /// code, so one bad row does not abort the rest of the model.
+    ///
     /// # Errors
     ///
     /// Returns an error if formula evaluation fails (circular dependency,
@@ -53,69 +303,146 @@ impl ArrayCalculator {
     ///
     /// Panics if the model's table map is in an inconsistent state (should not
     /// occur with valid `ParsedModel` construction).
-    pub fn calculate_all(mut self) -> ForgeResult<ParsedModel> {
+    pub fn calculate_all(self) -> ForgeResult<ParsedModel> {
+        self.run(false).map(|(model, _)| model)
+    }
+
+    /// Calculate all formulas, recording each failing formula instead of stopping
+    ///
+    /// A failed row leaves an error value in its cell (`NaN` in number columns,
+    /// [`ERROR_TEXT`] in text columns, `false` in boolean columns); a failed
+    /// scalar is set to `NaN`. Excel error values are kept in their cells as
+    /// in [`Self::calculate_all`], and are also recorded as failures so the
+    /// report lists them. Formulas that do not depend on a failure compute
+    /// normally. Formulas that fail to compile (syntax, unknown references) are
+    /// reported first, followed by runtime failures in calculation order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only for model-level problems such as circular
+    /// dependencies or an invalid output selection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the model's table map is in an inconsistent state (should not
+    /// occur with valid `ParsedModel` construction).
+    pub fn calculate_all_with_errors(self) -> ForgeResult<(ParsedModel, Vec<FormulaError>)> {
+        self.run(true)
+    }
+
+    fn run(mut self, continue_on_error: bool) -> ForgeResult<(ParsedModel, Vec<FormulaError>)> {
+        self.continue_on_error = continue_on_error;
+        let selected = self.selected_outputs()?;
+
+        // Step 0: Formulas that fail to compile are recorded up front; without
+        // continue-on-error, callers run `compile::check` once before calculating
+        let errors = if continue_on_error {
+            compile(&self.model)
+        } else {
+            Vec::new()
+        };
+        let mut uncompiled = HashSet::new();
+        for error in errors {
+            if uncompiled.insert(error.location.clone()) {
+                self.failures.push(FormulaError {
+                    location: error.location,
+                    row: None,
+                    message: error.message,
+                });
+            }
+        }
+
         // Step 1: Calculate all tables (row-wise formulas) in dependency order
         let table_names: Vec<String> = self.model.tables.keys().cloned().collect();
         let calc_order = self.get_table_calculation_order(&table_names)?;
 
         for table_name in calc_order {
             let table = self.model.tables.get(&table_name).unwrap().clone();
-            let calculated_table = self.calculate_table(&table_name, &table)?;
+            let calculated_table =
+                self.calculate_table(&table_name, &table, selected.as_ref(), &uncompiled)?;
             self.model.tables.insert(table_name, calculated_table);
         }
 
         // Step 2: Calculate scalar aggregations and formulas
-        self.calculate_scalars()?;
+        self.calculate_scalars(selected.as_ref(), &uncompiled)?;
 
-        Ok(self.model)
+        Ok((self.model, self.failures))
     }
 
-    /// Get calculation order for tables (topological sort based on cross-table references)
-    fn get_table_calculation_order(&self, table_names: &[String]) -> ForgeResult<Vec<String>> {
-        use petgraph::algo::toposort;
-        use petgraph::graph::DiGraph;
-        use std::collections::HashMap;
-
-        let mut graph = DiGraph::new();
-        let mut node_indices = HashMap::new();
+    /// In continue-on-error mode, record a failed formula and return `Ok`;
+    /// otherwise return the error
+    fn record_failure(&mut self, location: &str, error: ForgeError) -> ForgeResult<()> {
+        if !self.continue_on_error {
+            return Err(error);
+        }
+        let message = match error {
+            ForgeError::Eval(message) => message,
+            other => other.to_string(),
+        };
+        self.failures.push(FormulaError {
+            location: location.to_string(),
+            row: None,
+            message,
+        });
+        Ok(())
+    }
 
-        // Create nodes for all tables
-        for name in table_names {
-            let idx = graph.add_node(name.clone());
-            node_indices.insert(name.clone(), idx);
+    /// The order in which `calculate_all` evaluates formulas
+    ///
+    /// Lists every table formula as `table.column` (tables in dependency order,
+    /// each table's formulas in dependency order) followed by every scalar
+    /// formula in dependency order. Ties are broken by declaration order, so the
+    /// order is the same on every run. The output selection is not applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tables, a table's formulas or the scalars
+    /// contain a circular dependency.
+    pub fn calculation_order(&self) -> ForgeResult<Vec<String>> {
+        let table_names: Vec<String> = self.model.tables.keys().cloned().collect();
+        let mut order = Vec::new();
+        for table_name in self.get_table_calculation_order(&table_names)? {
+            let table = &self.model.tables[&table_name];
+            for col_name in Self::get_formula_calculation_order(table, &self.model.names)? {
+                order.push(format!("{table_name}.{col_name}"));
+            }
         }
 
+        let scalar_names: Vec<String> = self
+            .model
+            .scalars
+            .iter()
+            .filter(|(_, var)| var.formula.is_some())
+            .map(|(name, _)| name.clone())
+            .collect();
+        order.extend(self.get_scalar_calculation_order(&scalar_names)?);
+        Ok(order)
+    }
+
+    /// Get calculation order for tables (topological sort based on cross-table references)
+    fn get_table_calculation_order(&self, table_names: &[String]) -> ForgeResult<Vec<String>> {
         // Add edges for cross-table dependencies
+        let mut edges = Vec::new();
         for name in table_names {
             if let Some(table) = self.model.tables.get(name) {
                 // Check all row formulas for cross-table references
                 for formula in table.row_formulas.values() {
-                    let deps = self.extract_table_dependencies_from_formula(formula)?;
-                    for dep_table in deps {
-                        // Only add edge if dependency is another table
-                        if let Some(&dep_idx) = node_indices.get(&dep_table) {
-                            if let Some(&name_idx) = node_indices.get(name) {
-                                graph.add_edge(dep_idx, name_idx, ());
-                            }
+                    let formula = expand_names(&self.model.names, formula, Some(name));
+                    for dep_table in self.extract_table_dependencies_from_formula(&formula)? {
+                        // A table reading its own columns by qualified name is not a cycle
+                        if dep_table != *name {
+                            edges.push((dep_table, name.clone()));
                         }
                     }
                 }
             }
         }
 
-        // Topological sort
-        let order = toposort(&graph, None).map_err(|_| {
-            ForgeError::CircularDependency(
-                "Circular dependency detected between tables".to_string(),
-            )
-        })?;
-
-        let ordered_names: Vec<String> = order
-            .iter()
-            .filter_map(|idx| graph.node_weight(*idx).cloned())
-            .collect();
-
-        Ok(ordered_names)
+        order::topological_order(
+            &order::declaration_sorted(table_names.iter().cloned(), &self.model.declaration_order),
+            &edges,
+            "Circular dependency detected between tables",
+        )
     }
 
     /// Extract table names referenced in a formula (e.g., "`pl_2025`" from "=`pl_2025.revenue`")
@@ -143,7 +470,13 @@ impl ArrayCalculator {
     }
 
     /// Calculate all formulas in a table
-    fn calculate_table(&self, table_name: &str, table: &Table) -> ForgeResult<Table> {
+    fn calculate_table(
+        &mut self,
+        table_name: &str,
+        table: &Table,
+        selected: Option<&HashSet<String>>,
+        uncompiled: &HashSet<String>,
+    ) -> ForgeResult<Table> {
         let mut working_table = table.clone();
 
         // Only validate column lengths if there are row formulas
@@ -155,71 +488,96 @@ impl ArrayCalculator {
         }
 
         // Build dependency order for formulas
-        let formula_order = Self::get_formula_calculation_order(&working_table)?;
+        let formula_order = Self::get_formula_calculation_order(&working_table, &self.model.names)?;
 
         // Calculate formulas in dependency order
         for col_name in formula_order {
             if let Some(formula) = working_table.row_formulas.get(&col_name) {
+                let location = format!("{table_name}.{col_name}");
+                if !self.begin_output(selected, &location) {
+                    continue;
+                }
                 let formula = formula.clone();
 
-                // Determine if this is a row-wise or aggregation formula
-                if Self::is_aggregation_formula(&formula) {
-                    // Aggregation: returns a scalar
-                    // For now, we'll skip aggregations in tables (they belong in scalars section)
-                    return Err(ForgeError::Eval(format!(
-                        "Table '{table_name}': Column '{col_name}' uses aggregation formula - aggregations should be in scalars section"
-                    )));
-                }
-                // Row-wise: returns an array (v5.2.0 AST evaluator)
-                let result = self.evaluate_rowwise_formula_ast(&working_table, &formula)?;
-                working_table.add_column(Column::new(col_name.clone(), result));
+                let computed = if uncompiled.contains(&location) {
+                    None
+                } else {
+                    match self.calculate_row_formula(&working_table, &location, &formula) {
+                        Ok(computed) => Some(computed),
+                        Err(e) => {
+                            self.record_failure(&location, e)?;
+                            None
+                        },
+                    }
+                };
+                self.flush_trace(&location);
+                self.flush_assertions(&location);
+                // A failed formula leaves an all-error column so dependents still compute
+                let (result, unit) = computed.unwrap_or_else(|| {
+                    (
+                        ColumnValue::Number(vec![f64::NAN; working_table.row_count()]),
+                        None,
+                    )
+                });
+                let metadata = Metadata {
+                    unit: unit.map(|u| u.display()),
+                    ..Metadata::default()
+                };
+                working_table.add_column(Column::with_metadata(col_name.clone(), result, metadata));
             }
         }
 
         Ok(working_table)
     }
 
-    /// Get the order in which formulas should be calculated (dependency order)
-    fn get_formula_calculation_order(table: &Table) -> ForgeResult<Vec<String>> {
-        use petgraph::algo::toposort;
-        use petgraph::graph::DiGraph;
-        use std::collections::HashMap;
-
-        let mut graph = DiGraph::new();
-        let mut node_indices = HashMap::new();
+    /// Calculate one row-wise formula, recording per-row failures in continue-on-error mode
+    fn calculate_row_formula(
+        &mut self,
+        table: &Table,
+        location: &str,
+        formula: &str,
+    ) -> ForgeResult<(ColumnValue, Option<UnitCategory>)> {
+        let unit = if self.strict_units {
+            self.check_formula_units(table, None, formula)
+                .map_err(|e| ForgeError::Eval(format!("{location}: {e}")))?
+        } else {
+            None
+        };
+        // Row-wise: returns an array (v5.2.0 AST evaluator)
+        let mut row_failures = Vec::new();
+        let failures = self.continue_on_error.then_some(&mut row_failures);
+        let result = self.evaluate_rowwise_formula_ast(table, formula, failures)?;
+        self.failures
+            .extend(row_failures.into_iter().map(|(row, message)| FormulaError {
+                location: location.to_string(),
+                row: Some(row),
+                message,
+            }));
+        Ok((result, unit))
+    }
 
-        // Create nodes for all formula columns
-        for col_name in table.row_formulas.keys() {
-            let idx = graph.add_node(col_name.clone());
-            node_indices.insert(col_name.clone(), idx);
-        }
+    /// Get the order in which formulas should be calculated (dependency order)
+    fn get_formula_calculation_order(
+        table: &Table,
+        names: &HashMap<String, NamedRange>,
+    ) -> ForgeResult<Vec<String>> {
+        let col_names =
+            order::declaration_sorted(table.row_formulas.keys().cloned(), &table.formula_order);
 
-        // Add edges for dependencies
+        // Add edges for dependencies on other formula columns
+        let mut edges = Vec::new();
         for (col_name, formula) in &table.row_formulas {
-            let deps = Self::extract_column_references(formula)?;
-            for dep in deps {
-                // Only add dependency if it's another formula column
-                if let Some(&dep_idx) = node_indices.get(&dep) {
-                    if let Some(&col_idx) = node_indices.get(col_name) {
-                        graph.add_edge(dep_idx, col_idx, ());
-                    }
-                }
+            let formula = expand_names(names, formula, Some(&table.name));
+            for dep in Self::extract_column_references(&formula)? {
+                edges.push((dep, col_name.clone()));
             }
         }
 
-        // Topological sort
-        let order = toposort(&graph, None).map_err(|_| {
-            ForgeError::CircularDependency(
-                "Circular dependency detected in table formulas".to_string(),
-            )
-        })?;
-
-        let ordered_names: Vec<String> = order
-            .iter()
-            .filter_map(|idx| graph.node_weight(*idx).cloned())
-            .collect();
-
-        Ok(ordered_names)
+        order::topological_order(
+            &col_names,
+            &edges,
+            "Circular dependency detected in table formulas",
+        )
     }
 
     /// Extract column references from a formula
@@ -282,6 +640,8 @@ impl ArrayCalculator {
                 "INDEX",
                 "MATCH",
                 "XLOOKUP",
+                "XMATCH",
+                "LOOKUP",
                 "VLOOKUP",
                 "PMT",
                 "FV",
@@ -318,110 +678,72 @@ impl ArrayCalculator {
         Ok(refs)
     }
 
-    /// Check if a formula is an aggregation (returns scalar)
-    fn is_aggregation_formula(formula: &str) -> bool {
-        let upper = formula.to_uppercase();
-        upper.contains("SUM(")
-            || upper.contains("AVERAGE(")
-            || upper.contains("AVG(")
-            || upper.contains("MAX(")
-            || upper.contains("MIN(")
-            || upper.contains("COUNT(")
-            || upper.contains("SUMIF(")
-            || upper.contains("COUNTIF(")
-            || upper.contains("AVERAGEIF(")
-            || upper.contains("SUMIFS(")
-            || upper.contains("COUNTIFS(")
-            || upper.contains("AVERAGEIFS(")
-            || upper.contains("MAXIFS(")
-            || upper.contains("MINIFS(")
-            // Statistical functions (v5.0.0)
-            || upper.contains("MEDIAN(")
-            || upper.contains("VAR(")
-            || upper.contains("VAR.S(")
-            || upper.contains("VAR.P(")
-            || upper.contains("STDEV(")
-            || upper.contains("STDEV.S(")
-            || upper.contains("STDEV.P(")
-            || upper.contains("PERCENTILE(")
-            || upper.contains("QUARTILE(")
-            || upper.contains("CORREL(")
-    }
-
     // ═══════════════════════════════════════════════════════════════════════════
     // AST-BASED FORMULA EVALUATION (v5.2.0 - Parser Architecture Refactor)
     // ═══════════════════════════════════════════════════════════════════════════
 
     /// Build an evaluation context from the model state for a given table
-    fn build_eval_context(&self, table: &Table) -> evaluator::EvalContext {
-        use std::collections::HashMap;
+    ///
+    /// `section` is the group of the scalar being calculated (`summary` for
+    /// `summary.margin`), whose scalars its formula may reference by short name.
+    fn build_eval_context(&self, table: &Table, section: Option<&str>) -> evaluator::EvalContext {
         let mut ctx = evaluator::EvalContext::new();
 
         // Add all scalars to context
         for (name, scalar) in &self.model.scalars {
+            if is_subtotal_formula(scalar.formula.as_deref()) {
+                ctx.subtotals.insert(name.clone());
+            }
             if let Some(value) = scalar.value {
                 ctx.scalars
-                    .insert(name.clone(), evaluator::Value::Number(value));
-                // Also add short name (e.g., "price" from "summary.price")
-                // so formulas can reference without prefix
-                if let Some(short_name) = name.split('.').next_back() {
-                    if short_name != name {
-                        ctx.scalars
-                            .insert(short_name.to_string(), evaluator::Value::Number(value));
-                    }
+                    .insert(name.clone(), evaluator::Value::from_cell(value));
+            }
+        }
+
+        // Also add short names (e.g., "price" from "summary.price") so formulas
+        // can reference without prefix; one shared by several scalars is an error
+        let short_names = self.model.short_scalar_names(section);
+        for (short_name, targets) in &short_names {
+            if let [name] = targets.as_slice() {
+                if let Some(value) = self.model.scalars[*name].value {
+                    ctx.scalars.insert(
+                        (*short_name).to_string(),
+                        evaluator::Value::from_cell(value),
+                    );
+                    ctx.aliases
+                        .insert((*short_name).to_string(), (*name).clone());
                 }
+            } else {
+                ctx.ambiguous.insert(
+                    (*short_name).to_string(),
+                    targets.iter().map(|name| (*name).clone()).collect(),
+                );
             }
         }
 
         // Add current table columns
         for (col_name, col) in &table.columns {
-            let values: Vec<evaluator::Value> = match &col.values {
-                ColumnValue::Number(nums) => {
-                    nums.iter().map(|n| evaluator::Value::Number(*n)).collect()
-                },
-                ColumnValue::Text(texts) => texts
-                    .iter()
-                    .map(|s| evaluator::Value::Text(s.clone()))
-                    .collect(),
-                ColumnValue::Boolean(bools) => bools
-                    .iter()
-                    .map(|b| evaluator::Value::Boolean(*b))
-                    .collect(),
-                ColumnValue::Date(dates) => dates
-                    .iter()
-                    .map(|d| evaluator::Value::Text(d.clone()))
-                    .collect(),
-            };
-            ctx.scalars
-                .insert(col_name.clone(), evaluator::Value::Array(values));
+            ctx.scalars.insert(
+                col_name.clone(),
+                evaluator::Value::Array(column_values(&col.values)),
+            );
+            ctx.aliases.remove(col_name);
         }
 
         // Add all tables to context
         for (table_name, tbl) in &self.model.tables {
-            let mut table_data: HashMap<String, Vec<evaluator::Value>> = HashMap::new();
-            for (col_name, col) in &tbl.columns {
-                let values: Vec<evaluator::Value> = match &col.values {
-                    ColumnValue::Number(nums) => {
-                        nums.iter().map(|n| evaluator::Value::Number(*n)).collect()
-                    },
-                    ColumnValue::Text(texts) => texts
-                        .iter()
-                        .map(|s| evaluator::Value::Text(s.clone()))
-                        .collect(),
-                    ColumnValue::Boolean(bools) => bools
-                        .iter()
-                        .map(|b| evaluator::Value::Boolean(*b))
-                        .collect(),
-                    ColumnValue::Date(dates) => dates
-                        .iter()
-                        .map(|d| evaluator::Value::Text(d.clone()))
-                        .collect(),
-                };
-                table_data.insert(col_name.clone(), values);
-            }
+            let table_data: HashMap<String, Vec<evaluator::Value>> = tbl
+                .columns
+                .iter()
+                .map(|(col_name, col)| (col_name.clone(), column_values(&col.values)))
+                .collect();
             ctx.tables.insert(table_name.clone(), table_data);
         }
 
+        if self.strict_units {
+            self.add_units(&mut ctx, table, &short_names);
+        }
+
         // Add scenarios to context
         for (scenario_name, scenario) in &self.model.scenarios {
             let mut overrides = HashMap::new();
@@ -431,23 +753,185 @@ impl ArrayCalculator {
             ctx.scenarios.insert(scenario_name.clone(), overrides);
         }
 
+        Self::add_named_ranges(&mut ctx, &self.model.names, &table.name);
+        self.add_formulas(&mut ctx, table, &short_names);
+        ctx.trace.clone_from(&self.trace);
+        ctx.assertions.clone_from(&self.assertions);
+
         ctx.row_count = Some(table.row_count());
         ctx
     }
 
+    /// Register formula text under the names formulas use to reference it (FORMULATEXT)
+    fn add_formulas(
+        &self,
+        ctx: &mut evaluator::EvalContext,
+        table: &Table,
+        short_names: &BTreeMap<&str, Vec<&String>>,
+    ) {
+        for (name, scalar) in &self.model.scalars {
+            if let Some(formula) = &scalar.formula {
+                ctx.formulas.insert(name.clone(), formula.clone());
+            }
+        }
+        for (short_name, targets) in short_names {
+            if let [name] = targets.as_slice() {
+                if let Some(formula) = &self.model.scalars[*name].formula {
+                    ctx.formulas
+                        .insert((*short_name).to_string(), formula.clone());
+                }
+            }
+        }
+        for (table_name, tbl) in &self.model.tables {
+            for (col_name, formula) in &tbl.row_formulas {
+                ctx.formulas
+                    .insert(format!("{table_name}.{col_name}"), formula.clone());
+            }
+        }
+        // Columns of the table being calculated are also referenced by bare name
+        for (col_name, formula) in &table.row_formulas {
+            ctx.formulas.insert(col_name.clone(), formula.clone());
+        }
+    }
+
+    /// Bind each named range to the current value of its target
+    ///
+    /// Columns of the table being calculated are taken from the context rather
+    /// than the model, so aliases see formula columns computed so far. A target
+    /// that has not been computed yet is left unbound.
+    fn add_named_ranges(
+        ctx: &mut evaluator::EvalContext,
+        names: &HashMap<String, NamedRange>,
+        current_table: &str,
+    ) {
+        for (alias, range) in names {
+            let column = range
+                .reference
+                .split_once('.')
+                .filter(|(t, _)| ctx.tables.contains_key(*t));
+            let value = match column {
+                Some((t, c)) if t == current_table => ctx.scalars.get(c).cloned(),
+                Some((t, c)) => ctx.tables[t].get(c).cloned().map(evaluator::Value::Array),
+                None => ctx.scalars.get(&range.reference).cloned(),
+            };
+            let value = match (value, range.slice) {
+                (Some(evaluator::Value::Array(values)), Some((start, end))) => {
+                    let end = end.min(values.len());
+                    let start = start.min(end);
+                    Some(evaluator::Value::Array(values[start..end].to_vec()))
+                },
+                (value, _) => value,
+            };
+            if let Some(value) = value {
+                ctx.scalars.insert(alias.clone(), value);
+                // A name bound to a scalar resolves to that scalar (e.g. for SUBTOTAL)
+                let target = column
+                    .is_none()
+                    .then(|| ctx.qualified_name(&range.reference).to_string());
+                match target {
+                    Some(target) => ctx.aliases.insert(alias.clone(), target),
+                    None => ctx.aliases.remove(alias),
+                };
+            }
+        }
+    }
+
+    /// Tokenize and parse a formula, reusing the AST of an identical formula
+    ///
+    /// Row formulas, the strict-unit check and scalars all go through here, so
+    /// a formula shared by several columns, tables or scalars is parsed once
+    /// per calculation. Formulas that fail to parse are not cached.
+    fn parse_formula(&self, formula: &str) -> ForgeResult<Arc<parser::Expr>> {
+        let formula_str = formula.trim_start_matches('=').trim();
+        let cached = self
+            .asts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(formula_str)
+            .map(Arc::clone);
+        if let Some(ast) = cached {
+            return Ok(ast);
+        }
+        let tokens = tokenizer::tokenize(formula_str)
+            .map_err(|e| ForgeError::Eval(format!("Tokenize: {}", e.message)))?;
+        let ast = Arc::new(
+            parser::parse(tokens).map_err(|e| ForgeError::Eval(format!("Parse: {}", e.message)))?,
+        );
+        #[cfg(test)]
+        self.parse_count.fetch_add(1, Ordering::Relaxed);
+        self.asts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(formula_str.to_string(), Arc::clone(&ast));
+        Ok(ast)
+    }
+
+    /// Infer a formula's unit, failing on incompatible units (strict unit mode)
+    fn check_formula_units(
+        &self,
+        table: &Table,
+        section: Option<&str>,
+        formula: &str,
+    ) -> ForgeResult<Option<UnitCategory>> {
+        let ast = self.parse_formula(formula)?;
+        let ctx = self.build_eval_context(table, section);
+        evaluator::infer_unit(&ast, &ctx).map_err(|e| ForgeError::Eval(format!("Units: {e}")))
+    }
+
+    /// Register declared units under the names formulas use to reference them
+    fn add_units(
+        &self,
+        ctx: &mut evaluator::EvalContext,
+        table: &Table,
+        short_names: &BTreeMap<&str, Vec<&String>>,
+    ) {
+        let mut add = |name: String, unit: Option<&String>| {
+            let category = unit.map(|u| UnitCategory::parse(u));
+            if let Some(category) = category.filter(|c| *c != UnitCategory::Unknown) {
+                ctx.units.insert(name, category);
+            }
+        };
+        for (name, scalar) in &self.model.scalars {
+            add(name.clone(), scalar.metadata.unit.as_ref());
+        }
+        for (short_name, targets) in short_names {
+            if let [name] = targets.as_slice() {
+                add(
+                    (*short_name).to_string(),
+                    self.model.scalars[*name].metadata.unit.as_ref(),
+                );
+            }
+        }
+        for (table_name, tbl) in &self.model.tables {
+            for (col_name, col) in &tbl.columns {
+                add(
+                    format!("{table_name}.{col_name}"),
+                    col.metadata.unit.as_ref(),
+                );
+            }
+        }
+        // The table being calculated holds columns computed so far
+        for (col_name, col) in &table.columns {
+            add(col_name.clone(), col.metadata.unit.as_ref());
+            add(
+                format!("{}.{col_name}", table.name),
+                col.metadata.unit.as_ref(),
+            );
+        }
+    }
+
     /// Evaluate a row-wise formula using the AST evaluator
+    ///
+    /// With `failures`, a failing row is recorded there and left as an error
+    /// value instead of aborting the formula.
     fn evaluate_rowwise_formula_ast(
         &self,
         table: &Table,
         formula: &str,
+        mut failures: Option<&mut Vec<(usize, String)>>,
     ) -> ForgeResult<ColumnValue> {
-        let formula_str = formula.trim_start_matches('=').trim();
-        let tokens = tokenizer::tokenize(formula_str)
-            .map_err(|e| ForgeError::Eval(format!("Tokenize: {}", e.message)))?;
-        let ast =
-            parser::parse(tokens).map_err(|e| ForgeError::Eval(format!("Parse: {}", e.message)))?;
+        let ast = self.parse_formula(formula)?;
 
-        let base_ctx = self.build_eval_context(table);
         let row_count = table.row_count();
         if row_count == 0 {
             return Err(ForgeError::Eval(
@@ -455,50 +939,101 @@ impl ArrayCalculator {
             ));
         }
 
-        // Evaluate first row to determine result type
-        let first_ctx = base_ctx.clone().with_row(0, row_count);
-        let first_result = evaluator::evaluate(&ast, &first_ctx)
-            .map_err(|e| ForgeError::Eval(format!("Row 0: {e}")))?;
+        // Whole-column aggregations are evaluated once, before any row is set;
+        // then one context serves the whole column, only the current row changes
+        let ctx = self.build_eval_context(table, None);
+        let ast = broadcast::broadcast_aggregations(&ast, table, &ctx)?;
+        let mut row_ctx = ctx.with_row(0, row_count);
+        let rows: Vec<Result<evaluator::Value, evaluator::EvalError>> = (0..row_count)
+            .map(|row_idx| {
+                row_ctx.current_row = Some(row_idx);
+                evaluator::evaluate(&ast, &row_ctx).or_else(evaluator::EvalError::into_value)
+            })
+            .collect();
 
-        // Determine column type from first result and evaluate all rows
-        match &first_result {
-            evaluator::Value::Text(_) => {
+        // A failed row is recorded (continue-on-error) or aborts the formula.
+        // An error value left in a row's result (`#DIV/0!`, ...) stays in the
+        // cell: it is recorded when collecting failures but never aborts.
+        let mut fail = |row_idx: usize, message: String, error: Option<String>| match (
+            failures.as_mut(),
+            error,
+        ) {
+            (Some(failures), _) => {
+                failures.push((row_idx, message));
+                Ok(())
+            },
+            (None, Some(error)) => Err(ForgeError::Eval(error)),
+            (None, None) => Ok(()),
+        };
+
+        // Determine column type from the first successful row
+        let first = rows.iter().find_map(|r| {
+            r.as_ref()
+                .ok()
+                .filter(|v| !matches!(v, evaluator::Value::Error(_)))
+        });
+        match first {
+            Some(evaluator::Value::Text(_)) => {
                 let mut results: Vec<String> = Vec::with_capacity(row_count);
-                results.push(first_result.as_text());
-                for row_idx in 1..row_count {
-                    let row_ctx = base_ctx.clone().with_row(row_idx, row_count);
-                    let result = evaluator::evaluate(&ast, &row_ctx)
-                        .map_err(|e| ForgeError::Eval(format!("Row {row_idx}: {e}")))?;
-                    results.push(result.as_text());
+                for (row_idx, result) in rows.into_iter().enumerate() {
+                    match result {
+                        Ok(evaluator::Value::Error(kind)) => {
+                            fail(row_idx, kind.message().to_string(), None)?;
+                            results.push(kind.code().to_string());
+                        },
+                        Ok(value) => results.push(value.as_text()),
+                        Err(e) => {
+                            let error = format!("Row {row_idx}: {e}");
+                            fail(row_idx, e.message.clone(), Some(error))?;
+                            results.push(ERROR_TEXT.to_string());
+                        },
+                    }
                 }
                 Ok(ColumnValue::Text(results))
             },
-            evaluator::Value::Boolean(_) => {
+            Some(evaluator::Value::Boolean(_)) => {
                 let mut results: Vec<bool> = Vec::with_capacity(row_count);
-                results.push(first_result.as_bool().unwrap_or(false));
-                for row_idx in 1..row_count {
-                    let row_ctx = base_ctx.clone().with_row(row_idx, row_count);
-                    let result = evaluator::evaluate(&ast, &row_ctx)
-                        .map_err(|e| ForgeError::Eval(format!("Row {row_idx}: {e}")))?;
-                    results.push(result.as_bool().unwrap_or(false));
+                for (row_idx, result) in rows.into_iter().enumerate() {
+                    match result {
+                        Ok(evaluator::Value::Error(kind)) => {
+                            fail(row_idx, kind.message().to_string(), None)?;
+                            results.push(false);
+                        },
+                        Ok(value) => results.push(value.as_bool().unwrap_or(false)),
+                        Err(e) => {
+                            let error = format!("Row {row_idx}: {e}");
+                            fail(row_idx, e.message.clone(), Some(error))?;
+                            results.push(false);
+                        },
+                    }
                 }
                 Ok(ColumnValue::Boolean(results))
             },
             _ => {
                 // Default to numeric
                 let mut results: Vec<f64> = Vec::with_capacity(row_count);
-                let first_num = first_result
-                    .as_number()
-                    .ok_or_else(|| ForgeError::Eval("Row 0 not a number".to_string()))?;
-                results.push(first_num);
-                for row_idx in 1..row_count {
-                    let row_ctx = base_ctx.clone().with_row(row_idx, row_count);
-                    let result = evaluator::evaluate(&ast, &row_ctx)
-                        .map_err(|e| ForgeError::Eval(format!("Row {row_idx}: {e}")))?;
-                    let value = result
-                        .as_number()
-                        .ok_or_else(|| ForgeError::Eval(format!("Row {row_idx} not a number")))?;
-                    results.push(value);
+                for (row_idx, result) in rows.into_iter().enumerate() {
+                    if let Ok(evaluator::Value::Error(kind)) = result {
+                        fail(row_idx, kind.message().to_string(), None)?;
+                        results.push(kind.to_nan());
+                        continue;
+                    }
+                    match result.map(|value| value.as_number()) {
+                        Ok(Some(value)) => results.push(value),
+                        Ok(None) => {
+                            fail(
+                                row_idx,
+                                "not a number".to_string(),
+                                Some(format!("Row {row_idx} not a number")),
+                            )?;
+                            results.push(f64::NAN);
+                        },
+                        Err(e) => {
+                            let error = format!("Row {row_idx}: {e}");
+                            fail(row_idx, e.message.clone(), Some(error))?;
+                            results.push(f64::NAN);
+                        },
+                    }
                 }
                 Ok(ColumnValue::Number(results))
             },
@@ -506,23 +1041,33 @@ impl ArrayCalculator {
     }
 
     /// Evaluate a scalar formula using the AST evaluator
-    fn evaluate_scalar_formula_ast(&self, formula: &str) -> ForgeResult<f64> {
-        let formula_str = formula.trim_start_matches('=').trim();
-        let tokens = tokenizer::tokenize(formula_str)
-            .map_err(|e| ForgeError::Eval(format!("Tokenize: {}", e.message)))?;
-        let ast =
-            parser::parse(tokens).map_err(|e| ForgeError::Eval(format!("Parse: {}", e.message)))?;
+    ///
+    /// An error value result is returned as [`evaluator::ErrorKind::to_nan`].
+    fn evaluate_scalar_formula_ast(
+        &self,
+        formula: &str,
+        section: Option<&str>,
+    ) -> ForgeResult<f64> {
+        let ast = self.parse_formula(formula)?;
 
         let empty_table = Table::new("_scalar_context".to_string());
-        let ctx = self.build_eval_context(&empty_table);
-        let result =
-            evaluator::evaluate(&ast, &ctx).map_err(|e| ForgeError::Eval(format!("Eval: {e}")))?;
+        let ctx = self.build_eval_context(&empty_table, section);
+        let result = evaluator::evaluate(&ast, &ctx)
+            .or_else(evaluator::EvalError::into_value)
+            .map_err(|e| ForgeError::Eval(format!("Eval: {e}")))?;
+        if let evaluator::Value::Error(kind) = result {
+            return Ok(kind.to_nan());
+        }
         result
             .as_number()
             .ok_or_else(|| ForgeError::Eval("Scalar result not a number".to_string()))
     }
 
-    fn calculate_scalars(&mut self) -> ForgeResult<()> {
+    fn calculate_scalars(
+        &mut self,
+        selected: Option<&HashSet<String>>,
+        uncompiled: &HashSet<String>,
+    ) -> ForgeResult<()> {
         // Get all scalar variable names that have formulas
         let scalar_names: Vec<String> = self
             .model
@@ -537,6 +1082,9 @@ impl ArrayCalculator {
 
         // Calculate each scalar in dependency order
         for scalar_name in calc_order {
+            if !self.begin_output(selected, &scalar_name) {
+                continue;
+            }
             let formula = self
                 .model
                 .scalars
@@ -544,12 +1092,40 @@ impl ArrayCalculator {
                 .and_then(|v| v.formula.clone());
 
             if let Some(formula) = formula {
-                // v5.2.0 AST evaluator
-                let value = self.evaluate_scalar_formula_ast(&formula)?;
+                let computed = if uncompiled.contains(&scalar_name) {
+                    None
+                } else {
+                    match self.calculate_scalar_formula(&scalar_name, &formula) {
+                        Ok(computed) => {
+                            // An error value keeps its kind; it is only
+                            // reported when collecting failures
+                            if let Some(kind) = evaluator::ErrorKind::from_nan(computed.0)
+                                .filter(|_| self.continue_on_error)
+                            {
+                                self.record_failure(
+                                    &scalar_name,
+                                    ForgeError::Eval(format!("Eval: {}", kind.message())),
+                                )?;
+                            }
+                            Some(computed)
+                        },
+                        Err(e) => {
+                            self.record_failure(&scalar_name, e)?;
+                            None
+                        },
+                    }
+                };
+                self.flush_trace(&scalar_name);
+                self.flush_assertions(&scalar_name);
+                // A failed scalar is left as NaN so dependents still compute
+                let (value, unit) = computed.unwrap_or((f64::NAN, None));
 
                 // Update the scalar with calculated value
                 if let Some(var) = self.model.scalars.get_mut(&scalar_name) {
                     var.value = Some(value);
+                    if var.metadata.unit.is_none() {
+                        var.metadata.unit = unit.map(|u| u.display());
+                    }
                 }
             }
         }
@@ -557,51 +1133,46 @@ impl ArrayCalculator {
         Ok(())
     }
 
+    /// Calculate one scalar formula and its unit (strict unit mode)
+    fn calculate_scalar_formula(
+        &self,
+        scalar_name: &str,
+        formula: &str,
+    ) -> ForgeResult<(f64, Option<UnitCategory>)> {
+        let section = scalar_name.rsplit_once('.').map(|(section, _)| section);
+        let unit = if self.strict_units {
+            let empty_table = Table::new("_scalar_context".to_string());
+            self.check_formula_units(&empty_table, section, formula)
+                .map_err(|e| ForgeError::Eval(format!("{scalar_name}: {e}")))?
+        } else {
+            None
+        };
+
+        // v5.2.0 AST evaluator
+        let value = self.evaluate_scalar_formula_ast(formula, section)?;
+        Ok((value, unit))
+    }
+
     /// Get calculation order for scalars (topological sort)
     fn get_scalar_calculation_order(&self, scalar_names: &[String]) -> ForgeResult<Vec<String>> {
-        use petgraph::algo::toposort;
-        use petgraph::graph::DiGraph;
-        use std::collections::HashMap;
-
-        let mut graph = DiGraph::new();
-        let mut node_indices = HashMap::new();
-
-        // Create nodes for all scalars
-        for name in scalar_names {
-            let idx = graph.add_node(name.clone());
-            node_indices.insert(name.clone(), idx);
-        }
-
-        // Add edges for dependencies
+        // Add edges for dependencies on other scalars
+        let mut edges = Vec::new();
         for name in scalar_names {
             if let Some(var) = self.model.scalars.get(name) {
                 if let Some(formula) = &var.formula {
-                    let deps = self.extract_scalar_dependencies(formula, name)?;
-                    for dep in deps {
-                        // Only add dependency if it's another scalar
-                        if let Some(&dep_idx) = node_indices.get(&dep) {
-                            if let Some(&name_idx) = node_indices.get(name) {
-                                graph.add_edge(dep_idx, name_idx, ());
-                            }
-                        }
+                    let formula = expand_names(&self.model.names, formula, None);
+                    for dep in self.extract_scalar_dependencies(&formula, name)? {
+                        edges.push((dep, name.clone()));
                     }
                 }
             }
         }
 
-        // Topological sort
-        let order = toposort(&graph, None).map_err(|_| {
-            ForgeError::CircularDependency(
-                "Circular dependency detected in scalar formulas".to_string(),
-            )
-        })?;
-
-        let ordered_names: Vec<String> = order
-            .iter()
-            .filter_map(|idx| graph.node_weight(*idx).cloned())
-            .collect();
-
-        Ok(ordered_names)
+        order::topological_order(
+            &order::declaration_sorted(scalar_names.iter().cloned(), &self.model.declaration_order),
+            &edges,
+            "Circular dependency detected in scalar formulas",
+        )
     }
 
     /// Extract scalar dependencies from a formula with scoping
@@ -652,3 +1223,537 @@ impl ArrayCalculator {
         Ok(deps)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NamedRange, Variable};
+
+    /// `sales.per_unit` fails on row 1 (division by zero) and `bad_total`
+    /// references an unknown variable; everything else is independent of both.
+    fn model_with_two_bad_formulas() -> ParsedModel {
+        let mut sales = Table::new("sales".to_string());
+        sales.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0, 300.0]),
+        ));
+        sales.add_column(Column::new(
+            "units".to_string(),
+            ColumnValue::Number(vec![10.0, 0.0, 30.0]),
+        ));
+        sales.add_row_formula("per_unit".to_string(), "=revenue / units".to_string());
+        sales.add_row_formula("doubled".to_string(), "=revenue * 2".to_string());
+
+        let mut model = ParsedModel::new();
+        model.add_table(sales);
+        model.add_scalar(
+            "total".to_string(),
+            Variable::new(
+                "total".to_string(),
+                None,
+                Some("=SUM(sales.doubled)".to_string()),
+            ),
+        );
+        model.add_scalar(
+            "bad_total".to_string(),
+            Variable::new("bad_total".to_string(), None, Some("=totl * 2".to_string())),
+        );
+        model
+    }
+
+    #[test]
+    fn test_calculation_order_follows_dependencies() {
+        let mut costs = Table::new("costs".to_string());
+        costs.add_column(Column::new(
+            "base".to_string(),
+            ColumnValue::Number(vec![1.0, 2.0]),
+        ));
+        costs.add_row_formula("total".to_string(), "=with_tax + shipping".to_string());
+        costs.add_row_formula("with_tax".to_string(), "=base * 1.2".to_string());
+        costs.add_row_formula("shipping".to_string(), "=base + 5".to_string());
+
+        let mut report = Table::new("a_report".to_string());
+        report.add_column(Column::new(
+            "id".to_string(),
+            ColumnValue::Number(vec![1.0, 2.0]),
+        ));
+        report.add_row_formula("cost".to_string(), "=costs.total * 2".to_string());
+
+        let mut model = ParsedModel::new();
+        model.add_table(costs);
+        model.add_table(report);
+        model.add_scalar(
+            "margin".to_string(),
+            Variable::new(
+                "margin".to_string(),
+                None,
+                Some("=grand_total * 0.1".to_string()),
+            ),
+        );
+        model.add_scalar(
+            "grand_total".to_string(),
+            Variable::new(
+                "grand_total".to_string(),
+                None,
+                Some("=SUM(a_report.cost)".to_string()),
+            ),
+        );
+
+        let expected = vec![
+            "costs.with_tax",
+            "costs.shipping",
+            "costs.total",
+            "a_report.cost",
+            "grand_total",
+            "margin",
+        ];
+        let calculator = ArrayCalculator::new(model);
+        for _ in 0..5 {
+            assert_eq!(calculator.calculation_order().unwrap(), expected);
+        }
+
+        // The order calculate_all actually uses
+        let computed = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = std::sync::Arc::clone(&computed);
+        calculator
+            .with_compute_hook(move |name| sink.lock().unwrap().push(name.to_string()))
+            .calculate_all()
+            .unwrap();
+        assert_eq!(*computed.lock().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_calculation_order_ties_follow_declaration_order() {
+        let yaml = r#"_forge_version: "5.0.0"
+zeta:
+  value: null
+  formula: "=1"
+alpha:
+  value: null
+  formula: "=2"
+orders:
+  qty: [1, 2]
+  tax: "=qty * 0.1"
+  net: "=qty * 2"
+mid:
+  value: null
+  formula: "=alpha + zeta"
+"#;
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, yaml.as_bytes()).unwrap();
+        let model = crate::parser::parse_model(file.path()).unwrap();
+
+        assert_eq!(
+            ArrayCalculator::new(model).calculation_order().unwrap(),
+            ["orders.tax", "orders.net", "zeta", "alpha", "mid"]
+        );
+    }
+
+    #[test]
+    fn test_calculate_all_fails_fast() {
+        let result = ArrayCalculator::new(model_with_two_bad_formulas()).calculate_all();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_continue_on_error_reports_all_failures() {
+        let (model, failures) = ArrayCalculator::new(model_with_two_bad_formulas())
+            .calculate_all_with_errors()
+            .unwrap();
+
+        let rendered: Vec<String> = failures.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "bad_total: Unknown variable: totl",
+                "sales.per_unit (row 1): Division by zero",
+            ]
+        );
+
+        // Failed cells hold error values; the rest of the column still computes
+        let sales = &model.tables["sales"];
+        let ColumnValue::Number(per_unit) = &sales.columns["per_unit"].values else {
+            panic!("per_unit should be numeric");
+        };
+        assert!((per_unit[0] - 10.0).abs() < f64::EPSILON);
+        assert!(per_unit[1].is_nan());
+        assert_eq!(
+            evaluator::ErrorKind::from_nan(per_unit[1]),
+            Some(evaluator::ErrorKind::DivZero)
+        );
+        assert!((per_unit[2] - 10.0).abs() < f64::EPSILON);
+        assert!(model.scalars["bad_total"].value.unwrap().is_nan());
+
+        // Unrelated formulas are unaffected
+        assert_eq!(model.scalars["total"].value, Some(1200.0));
+    }
+
+    #[test]
+    fn test_error_values_stay_in_cells() {
+        let mut model = ParsedModel::new();
+        for (name, formula) in [
+            ("zero", "=0"),
+            ("ratio", "=10 / zero"),
+            ("safe_ratio", "=IFERROR(10 / zero, 0)"),
+        ] {
+            model.add_scalar(
+                name.to_string(),
+                Variable::new(name.to_string(), None, Some(formula.to_string())),
+            );
+        }
+
+        let calculated = ArrayCalculator::new(model.clone()).calculate_all().unwrap();
+        assert_eq!(
+            evaluator::ErrorKind::from_nan(calculated.scalars["ratio"].value.unwrap()),
+            Some(evaluator::ErrorKind::DivZero)
+        );
+        assert_eq!(calculated.scalars["safe_ratio"].value, Some(0.0));
+
+        let (model, failures) = ArrayCalculator::new(model)
+            .calculate_all_with_errors()
+            .unwrap();
+        let rendered: Vec<String> = failures.iter().map(ToString::to_string).collect();
+        assert_eq!(rendered, vec!["ratio: Eval: Division by zero"]);
+        let ratio = model.scalars["ratio"].value.unwrap();
+        assert_eq!(
+            evaluator::ErrorKind::from_nan(ratio),
+            Some(evaluator::ErrorKind::DivZero)
+        );
+        assert_eq!(model.scalars["safe_ratio"].value, Some(0.0));
+
+        // A divide-by-zero row keeps its error and the other rows compute
+        let mut model = model_with_two_bad_formulas();
+        model.scalars.remove("bad_total");
+        let model = ArrayCalculator::new(model).calculate_all().unwrap();
+        let ColumnValue::Number(per_unit) = &model.tables["sales"].columns["per_unit"].values
+        else {
+            panic!("per_unit should be numeric");
+        };
+        assert_eq!(
+            evaluator::ErrorKind::from_nan(per_unit[1]),
+            Some(evaluator::ErrorKind::DivZero)
+        );
+        assert_eq!(model.scalars["total"].value, Some(1200.0));
+    }
+
+    #[test]
+    fn test_error_values_flow_into_other_formulas() {
+        let mut model = model_with_two_bad_formulas();
+        model.scalars.remove("bad_total");
+        let sales = model.tables.get_mut("sales").unwrap();
+        sales.add_row_formula("safe".to_string(), "=IFERROR(per_unit, 0)".to_string());
+        sales.add_row_formula(
+            "failed".to_string(),
+            "=IF(ISERROR(per_unit), 1, 0)".to_string(),
+        );
+        for (name, formula) in [
+            ("zero", "=0"),
+            ("ratio", "=10 / zero"),
+            ("safe_ratio", "=IFERROR(ratio, 0)"),
+            ("ratio_failed", "=IF(ISERROR(ratio), 1, 0)"),
+            ("per_unit_total", "=SUM(sales.per_unit)"),
+            ("safe_total", "=SUM(sales.safe)"),
+            ("per_unit_errors", "=SUM(sales.failed)"),
+        ] {
+            model.add_scalar(
+                name.to_string(),
+                Variable::new(name.to_string(), None, Some(formula.to_string())),
+            );
+        }
+
+        let model = ArrayCalculator::new(model).calculate_all().unwrap();
+        let value = |name: &str| model.scalars[name].value;
+        assert_eq!(value("safe_ratio"), Some(0.0));
+        assert_eq!(value("ratio_failed"), Some(1.0));
+        assert_eq!(
+            evaluator::ErrorKind::from_nan(value("per_unit_total").unwrap()),
+            Some(evaluator::ErrorKind::DivZero)
+        );
+        assert_eq!(value("safe_total"), Some(20.0));
+        assert_eq!(value("per_unit_errors"), Some(1.0));
+        assert_eq!(
+            model.tables["sales"].columns["safe"].values,
+            ColumnValue::Number(vec![10.0, 0.0, 10.0])
+        );
+    }
+
+    #[test]
+    fn test_named_ranges_match_full_references() {
+        let mut sales = Table::new("sales".to_string());
+        sales.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0, 300.0, 400.0]),
+        ));
+        sales.add_row_formula("cost".to_string(), "=revenue * 0.5".to_string());
+        // Sorts before `cost`, so it only computes correctly if the alias adds an edge
+        sales.add_row_formula("a_net".to_string(), "=rev - costs".to_string());
+        sales.add_row_formula("a_net_full".to_string(), "=revenue - cost".to_string());
+
+        let mut model = ParsedModel::new();
+        model.add_table(sales);
+        for (name, formula) in [
+            ("total_named", "=SUM(rev)"),
+            ("total_full", "=SUM(sales.revenue)"),
+            ("cost_named", "=SUM(costs)"),
+            ("cost_full", "=SUM(sales.cost)"),
+            ("first_half", "=SUM(h1)"),
+        ] {
+            model.add_scalar(
+                name.to_string(),
+                Variable::new(name.to_string(), None, Some(formula.to_string())),
+            );
+        }
+        for (alias, target) in [
+            ("rev", "sales.revenue"),
+            ("costs", "sales.cost"),
+            ("h1", "sales.revenue[0:2]"),
+        ] {
+            model.add_name(alias.to_string(), NamedRange::parse(target).unwrap());
+        }
+        assert!(model.check_names().is_empty());
+
+        let result = ArrayCalculator::new(model).calculate_all().unwrap();
+        let scalar = |name: &str| result.scalars[name].value.expect(name);
+        assert!((scalar("total_named") - scalar("total_full")).abs() < f64::EPSILON);
+        assert!((scalar("cost_named") - scalar("cost_full")).abs() < f64::EPSILON);
+        assert!((scalar("first_half") - 300.0).abs() < f64::EPSILON);
+
+        let sales = &result.tables["sales"];
+        let (ColumnValue::Number(named), ColumnValue::Number(full)) = (
+            &sales.columns["a_net"].values,
+            &sales.columns["a_net_full"].values,
+        ) else {
+            panic!("net columns should be numeric");
+        };
+        assert_eq!(named, full);
+    }
+
+    #[test]
+    fn test_formulatext_returns_formula_of_computed_scalar() {
+        let mut sales = Table::new("sales".to_string());
+        sales.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0]),
+        ));
+        sales.add_row_formula("cost".to_string(), "=revenue * 0.5".to_string());
+        sales.add_row_formula("cost_text".to_string(), "=FORMULATEXT(cost)".to_string());
+        sales.add_row_formula(
+            "total_text".to_string(),
+            "=FORMULATEXT(summary.total)".to_string(),
+        );
+
+        let mut model = ParsedModel::new();
+        model.add_table(sales);
+        model.add_scalar(
+            "summary.total".to_string(),
+            Variable::new(
+                "summary.total".to_string(),
+                None,
+                Some("=SUM(sales.revenue)".to_string()),
+            ),
+        );
+
+        let result = ArrayCalculator::new(model).calculate_all().unwrap();
+        let text = |col: &str| match &result.tables["sales"].columns[col].values {
+            ColumnValue::Text(values) => values[0].clone(),
+            other => panic!("{col} should be text, got {other:?}"),
+        };
+        assert_eq!(text("total_text"), "=SUM(sales.revenue)");
+        assert_eq!(text("cost_text"), "=revenue * 0.5");
+        assert_eq!(result.scalars["summary.total"].value, Some(300.0));
+    }
+
+    #[test]
+    fn test_short_scalar_names_resolve_deterministically() {
+        let scalar = |name: &str, value: Option<f64>, formula: Option<&str>| {
+            Variable::new(name.to_string(), value, formula.map(str::to_string))
+        };
+        let mut model = ParsedModel::new();
+        for (name, value) in [
+            ("north.price", 10.0),
+            ("south.price", 20.0),
+            ("rates.tax", 0.5),
+        ] {
+            model.add_scalar(name.to_string(), scalar(name, Some(value), None));
+        }
+        model.add_scalar(
+            "taxed".to_string(),
+            scalar("taxed", None, Some("=south.price * tax")),
+        );
+
+        // A formula in the `south` section means its own `price`
+        model.add_scalar(
+            "south.doubled".to_string(),
+            scalar("south.doubled", None, Some("=price * 2")),
+        );
+
+        let result = ArrayCalculator::new(model.clone()).calculate_all().unwrap();
+        assert_eq!(result.scalars["taxed"].value, Some(10.0));
+        assert_eq!(result.scalars["south.doubled"].value, Some(40.0));
+
+        model.add_scalar(
+            "ambiguous".to_string(),
+            scalar("ambiguous", None, Some("=price * 2")),
+        );
+        let error = ArrayCalculator::new(model).calculate_all().unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Ambiguous reference: price could be north.price, south.price"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_trace_hook_receives_explain_values() {
+        use std::sync::{Arc, Mutex};
+
+        let mut sales = Table::new("sales".to_string());
+        sales.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0]),
+        ));
+        sales.add_row_formula("net".to_string(), "=EXPLAIN(revenue * 0.5) - 1".to_string());
+        let mut model = ParsedModel::new();
+        model.add_table(sales);
+        model.add_scalar(
+            "total".to_string(),
+            Variable::new(
+                "total".to_string(),
+                None,
+                Some("=EXPLAIN(SUM(sales.net))".to_string()),
+            ),
+        );
+
+        let traces: Arc<Mutex<Vec<String>>> = Arc::default();
+        let lines = Arc::clone(&traces);
+        let result = ArrayCalculator::new(model.clone())
+            .with_trace_hook(move |output, entry| {
+                lines
+                    .lock()
+                    .unwrap()
+                    .push(format!("{output} {:?} {entry}", entry.row));
+            })
+            .calculate_all()
+            .unwrap();
+        assert_eq!(
+            *traces.lock().unwrap(),
+            vec![
+                "sales.net Some(0) revenue * 0.5 = 50",
+                "sales.net Some(1) revenue * 0.5 = 100",
+                "total None SUM(sales.net) = 148",
+            ]
+        );
+
+        // Without a hook EXPLAIN is a pass-through
+        let plain = ArrayCalculator::new(model).calculate_all().unwrap();
+        assert_eq!(plain.scalars["total"].value, result.scalars["total"].value);
+        assert_eq!(plain.scalars["total"].value, Some(148.0));
+    }
+
+    #[test]
+    fn test_assert_hook_receives_false_conditions() {
+        use std::sync::{Arc, Mutex};
+
+        let mut sales = Table::new("sales".to_string());
+        sales.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, -5.0]),
+        ));
+        sales.add_row_formula(
+            "positive".to_string(),
+            "=ASSERT(revenue > 0, \"revenue must be positive\")".to_string(),
+        );
+        let mut model = ParsedModel::new();
+        model.add_table(sales);
+        model.add_scalar(
+            "check".to_string(),
+            Variable::new(
+                "check".to_string(),
+                None,
+                Some("=ASSERT(SUM(sales.revenue) > 0)".to_string()),
+            ),
+        );
+
+        let failures: Arc<Mutex<Vec<String>>> = Arc::default();
+        let lines = Arc::clone(&failures);
+        ArrayCalculator::new(model)
+            .with_assert_hook(move |output, failure| {
+                lines
+                    .lock()
+                    .unwrap()
+                    .push(format!("{output} {:?} {failure}", failure.row));
+            })
+            .calculate_all()
+            .unwrap();
+        assert_eq!(
+            *failures.lock().unwrap(),
+            vec!["sales.positive Some(1) revenue must be positive (revenue > 0)"]
+        );
+    }
+
+    /// A `rows`-row table with one row formula, plus `rate` as a scalar
+    fn large_model(rows: usize) -> ParsedModel {
+        let mut orders = Table::new("orders".to_string());
+        orders.add_column(Column::new(
+            "units".to_string(),
+            ColumnValue::Number((0..rows).map(|i| i as f64).collect()),
+        ));
+        orders.add_row_formula("net".to_string(), "=units * rate + 1".to_string());
+        let mut model = ParsedModel::new();
+        model.add_table(orders);
+        model.add_scalar(
+            "rate".to_string(),
+            Variable::new("rate".to_string(), Some(0.5), None),
+        );
+        model
+    }
+
+    #[test]
+    fn test_large_single_column_formula() {
+        let result = ArrayCalculator::new(large_model(1_000))
+            .calculate_all()
+            .unwrap();
+        let ColumnValue::Number(net) = &result.tables["orders"].columns["net"].values else {
+            panic!("Expected Number column");
+        };
+        assert_eq!(net.len(), 1_000);
+        assert_eq!(net[..2], [1.0, 1.5]);
+        assert_eq!(net[999..], [500.5]);
+    }
+
+    #[test]
+    fn test_ast_cache_parses_each_distinct_formula_once() {
+        let model = large_model(1_000);
+        let table = model.tables["orders"].clone();
+        let calculator = ArrayCalculator::new(model).with_strict_units(true);
+
+        // The first pass parses, later passes only evaluate
+        for _ in 0..3 {
+            calculator
+                .check_formula_units(&table, None, "=units * rate + 1")
+                .unwrap();
+            calculator
+                .evaluate_rowwise_formula_ast(&table, "units * rate + 1", None)
+                .unwrap();
+        }
+        assert_eq!(calculator.parse_count.load(Ordering::Relaxed), 1);
+
+        calculator
+            .evaluate_scalar_formula_ast("=rate * 2", None)
+            .unwrap();
+        calculator
+            .evaluate_scalar_formula_ast("=rate * 2", None)
+            .unwrap();
+        assert_eq!(calculator.parse_count.load(Ordering::Relaxed), 2);
+
+        // Parse failures are reported every time and never cached
+        assert!(calculator
+            .evaluate_scalar_formula_ast("=rate *", None)
+            .is_err());
+        assert!(calculator
+            .evaluate_scalar_formula_ast("=rate *", None)
+            .is_err());
+        assert_eq!(calculator.asts.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,114 @@
+//! Deterministic dependency ordering for tables, table formulas and scalars
+
+use crate::error::{ForgeError, ForgeResult};
+use std::collections::{BTreeSet, HashMap};
+
+/// Sort `nodes` so every node comes after the nodes it depends on
+///
+/// `edges` are `(dependency, dependent)` pairs; edges to or from names outside
+/// `nodes` are ignored. Among nodes whose dependencies are all satisfied, the
+/// one listed first in `nodes` is taken next, so callers pass nodes in
+/// declaration order (see [`declaration_sorted`]).
+///
+/// # Errors
+///
+/// Returns a `CircularDependency` error with `cycle_message` if the edges form a cycle.
+pub fn topological_order(
+    nodes: &[String],
+    edges: &[(String, String)],
+    cycle_message: &str,
+) -> ForgeResult<Vec<String>> {
+    let index: HashMap<&str, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.as_str(), i))
+        .collect();
+    let mut indegree = vec![0; nodes.len()];
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for (dependency, dependent) in edges {
+        // Only dependencies on other nodes constrain the order
+        if let (Some(&from), Some(&to)) = (
+            index.get(dependency.as_str()),
+            index.get(dependent.as_str()),
+        ) {
+            indegree[to] += 1;
+            dependents[from].push(to);
+        }
+    }
+
+    let mut ready: BTreeSet<usize> = (0..nodes.len()).filter(|&i| indegree[i] == 0).collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(next) = ready.pop_first() {
+        order.push(nodes[next].clone());
+        for &dependent in &dependents[next] {
+            indegree[dependent] -= 1;
+            if indegree[dependent] == 0 {
+                ready.insert(dependent);
+            }
+        }
+    }
+
+    if order.len() == nodes.len() {
+        Ok(order)
+    } else {
+        Err(ForgeError::CircularDependency(cycle_message.to_string()))
+    }
+}
+
+/// `names` in the order they appear in `declared`; names missing from it
+/// (e.g. added to the model directly) follow alphabetically
+pub fn declaration_sorted(
+    names: impl IntoIterator<Item = String>,
+    declared: &[String],
+) -> Vec<String> {
+    let mut position: HashMap<&str, usize> = HashMap::new();
+    for (i, name) in declared.iter().enumerate() {
+        position.entry(name.as_str()).or_insert(i);
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    names.sort_by(|a, b| {
+        let rank = |name: &String| position.get(name.as_str()).copied().unwrap_or(usize::MAX);
+        rank(a).cmp(&rank(b)).then_with(|| a.cmp(b))
+    });
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(ToString::to_string).collect()
+    }
+
+    fn edge(dependency: &str, dependent: &str) -> (String, String) {
+        (dependency.to_string(), dependent.to_string())
+    }
+
+    #[test]
+    fn test_ties_broken_by_node_order() {
+        let order = topological_order(&names(&["c", "b", "a"]), &[], "cycle").unwrap();
+        assert_eq!(order, names(&["c", "b", "a"]));
+    }
+
+    #[test]
+    fn test_dependencies_come_first() {
+        let edges = [edge("z", "a"), edge("y", "z"), edge("data_column", "b")];
+        let order = topological_order(&names(&["a", "z", "y", "b"]), &edges, "cycle").unwrap();
+        assert_eq!(order, names(&["y", "z", "a", "b"]));
+    }
+
+    #[test]
+    fn test_declaration_sorted_puts_undeclared_last() {
+        let declared = names(&["zeta", "alpha", "mid"]);
+        let sorted = declaration_sorted(names(&["x", "mid", "b", "zeta", "alpha"]), &declared);
+        assert_eq!(sorted, names(&["zeta", "alpha", "mid", "b", "x"]));
+    }
+
+    #[test]
+    fn test_cycle_detected() {
+        let edges = [edge("a", "b"), edge("b", "a")];
+        let err = topological_order(&names(&["a", "b"]), &edges, "loop found").unwrap_err();
+        assert!(matches!(err, ForgeError::CircularDependency(msg) if msg == "loop found"));
+    }
+}
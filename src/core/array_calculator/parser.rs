@@ -27,6 +27,12 @@ pub enum Expr {
     Reference(Reference),
     /// Array indexing: expr[index]
     ArrayIndex { array: Box<Self>, index: Box<Self> },
+    /// Array slicing: expr[start:end] (end-exclusive, either bound optional)
+    ArraySlice {
+        array: Box<Self>,
+        start: Option<Box<Self>>,
+        end: Option<Box<Self>>,
+    },
     /// Function call: NAME(arg1, arg2, ...)
     FunctionCall { name: String, args: Vec<Self> },
     /// Calling the result of an expression: (expr)(args)
@@ -47,6 +53,92 @@ pub enum Expr {
     Range { start: Box<Self>, end: Box<Self> },
 }
 
+/// Binding strength of a binary operator (higher binds tighter)
+fn precedence(op: &str) -> u8 {
+    match op {
+        "^" => 4,
+        "*" | "/" => 3,
+        "+" | "-" | "&" => 2,
+        _ => 1,
+    }
+}
+
+/// Write `items` separated by ", "
+fn write_list(f: &mut std::fmt::Formatter<'_>, items: &[Expr]) -> std::fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+
+/// Formula text for an expression, parenthesized only where precedence requires
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "\"{}\"", s.replace('"', "\"\"")),
+            Self::Boolean(b) => write!(f, "{}", if *b { "TRUE" } else { "FALSE" }),
+            Self::Reference(Reference::Scalar(name)) => write!(f, "{name}"),
+            Self::Reference(Reference::TableColumn { table, column }) => {
+                write!(f, "{table}.{column}")
+            },
+            Self::ArrayIndex { array, index } => write!(f, "{array}[{index}]"),
+            Self::ArraySlice { array, start, end } => {
+                write!(f, "{array}[")?;
+                if let Some(start) = start {
+                    write!(f, "{start}")?;
+                }
+                write!(f, ":")?;
+                if let Some(end) = end {
+                    write!(f, "{end}")?;
+                }
+                write!(f, "]")
+            },
+            Self::FunctionCall { name, args } => {
+                write!(f, "{name}(")?;
+                write_list(f, args)?;
+                write!(f, ")")
+            },
+            Self::CallResult { callable, args } => {
+                write!(f, "{callable}(")?;
+                write_list(f, args)?;
+                write!(f, ")")
+            },
+            Self::BinaryOp { op, left, right } => {
+                let prec = precedence(op);
+                // `^` is right-associative; every other operator is left-associative
+                let right_assoc = op == "^";
+                let needs_parens = |child: &Self, is_left: bool| match child {
+                    Self::BinaryOp { op: child_op, .. } => {
+                        let child_prec = precedence(child_op);
+                        child_prec < prec || (child_prec == prec && is_left == right_assoc)
+                    },
+                    _ => false,
+                };
+                for (child, is_left) in [(left, true), (right, false)] {
+                    if !is_left {
+                        write!(f, " {op} ")?;
+                    }
+                    if needs_parens(child, is_left) {
+                        write!(f, "({child})")?;
+                    } else {
+                        write!(f, "{child}")?;
+                    }
+                }
+                Ok(())
+            },
+            Self::UnaryOp { op, operand } => match operand.as_ref() {
+                Self::BinaryOp { .. } => write!(f, "{op}({operand})"),
+                _ => write!(f, "{op}{operand}"),
+            },
+            Self::Range { start, end } => write!(f, "{start}:{end}"),
+        }
+    }
+}
+
 /// Error during parsing
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseError {
@@ -79,6 +171,8 @@ impl std::error::Error for ParseError {}
 pub struct Parser {
     tokens: Vec<Token>,
     position: usize,
+    /// Inside `[...]`, where ':' separates slice bounds instead of forming a range
+    in_brackets: bool,
 }
 
 impl Parser {
@@ -88,6 +182,7 @@ impl Parser {
         Self {
             tokens,
             position: 0,
+            in_brackets: false,
         }
     }
 
@@ -245,7 +340,7 @@ impl Parser {
         }
     }
 
-    /// Postfix: `primary ( "(" arguments? ")" | "[" expr "]" | ":" expr )*`
+    /// Postfix: `primary ( "(" arguments? ")" | "[" expr "]" | "[" expr? ":" expr? "]" | ":" expr )*`
     fn postfix(&mut self) -> Result<Expr, ParseError> {
         let mut expr = self.primary()?;
 
@@ -285,19 +380,12 @@ impl Parser {
                     },
                 };
             } else if self.match_token(&Token::OpenBracket) {
-                // Array indexing
-                let index = self.expression()?;
-                if !self.match_token(&Token::CloseBracket) {
-                    return Err(ParseError::new(
-                        "Expected ']' after array index",
-                        self.position,
-                    ));
-                }
-                expr = Expr::ArrayIndex {
-                    array: Box::new(expr),
-                    index: Box::new(index),
-                };
-            } else if self.match_token(&Token::Colon) {
+                // Array indexing or slicing
+                let outer = std::mem::replace(&mut self.in_brackets, true);
+                let indexed = self.index_or_slice(expr);
+                self.in_brackets = outer;
+                expr = indexed?;
+            } else if !self.in_brackets && self.match_token(&Token::Colon) {
                 // Range expression (A1:B10)
                 let end = self.primary()?;
                 expr = Expr::Range {
@@ -312,8 +400,52 @@ impl Parser {
         Ok(expr)
     }
 
+    /// Index or slice after "[": `expr "]"` or `expr? ":" expr? "]"`
+    fn index_or_slice(&mut self, array: Expr) -> Result<Expr, ParseError> {
+        let start = self.slice_bound()?;
+        let expr = if self.match_token(&Token::Colon) {
+            Expr::ArraySlice {
+                array: Box::new(array),
+                start,
+                end: self.slice_bound()?,
+            }
+        } else {
+            let index =
+                start.ok_or_else(|| ParseError::new("Expected array index", self.position))?;
+            Expr::ArrayIndex {
+                array: Box::new(array),
+                index,
+            }
+        };
+        if !self.match_token(&Token::CloseBracket) {
+            return Err(ParseError::new(
+                "Expected ']' after array index",
+                self.position,
+            ));
+        }
+        Ok(expr)
+    }
+
+    /// Optional slice bound: absent when followed directly by ':' or ']'
+    fn slice_bound(&mut self) -> Result<Option<Box<Expr>>, ParseError> {
+        if matches!(self.peek(), Some(Token::Colon | Token::CloseBracket)) {
+            Ok(None)
+        } else {
+            Ok(Some(Box::new(self.expression()?)))
+        }
+    }
+
     /// Arguments: ( expr ( "," expr )* )?
     fn arguments(&mut self) -> Result<Vec<Expr>, ParseError> {
+        // Ranges are allowed again inside a call, even within brackets
+        let outer = std::mem::replace(&mut self.in_brackets, false);
+        let args = self.argument_list();
+        self.in_brackets = outer;
+        args
+    }
+
+    /// Comma-separated argument expressions
+    fn argument_list(&mut self) -> Result<Vec<Expr>, ParseError> {
         let mut args = Vec::new();
 
         // Check for empty argument list
@@ -612,6 +744,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_array_slice() {
+        let column = || {
+            Box::new(Expr::Reference(Reference::TableColumn {
+                table: "data".to_string(),
+                column: "revenue".to_string(),
+            }))
+        };
+        assert_eq!(
+            parse_formula("data.revenue[0:4]").unwrap(),
+            Expr::ArraySlice {
+                array: column(),
+                start: Some(Box::new(Expr::Number(0.0))),
+                end: Some(Box::new(Expr::Number(4.0))),
+            }
+        );
+        assert_eq!(
+            parse_formula("data.revenue[2:]").unwrap(),
+            Expr::ArraySlice {
+                array: column(),
+                start: Some(Box::new(Expr::Number(2.0))),
+                end: None,
+            }
+        );
+        // Ranges still parse inside a call within brackets
+        assert!(matches!(
+            parse_formula("data.revenue[MATCH(1, A1:A3, 0)]").unwrap(),
+            Expr::ArrayIndex { .. }
+        ));
+        assert!(parse_formula("data.revenue[]").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for formula in [
+            "SUM(sales.revenue) * (1 + tax_rate)",
+            "a - (b - c)",
+            "(a - b) - c",
+            "2 ^ 3 ^ 2",
+            "(2 ^ 3) ^ 2",
+            "-(a + b) * 2",
+            "IF(x >= 10, \"say \"\"hi\"\"\", FALSE)",
+            "data.revenue[1:]",
+            "LAMBDA(x, x * 2)(5)",
+        ] {
+            let expr = parse_formula(formula).unwrap();
+            let rendered = expr.to_string();
+            assert_eq!(parse_formula(&rendered).unwrap(), expr, "{formula}");
+        }
+        assert_eq!(
+            parse_formula("(a * b) + c").unwrap().to_string(),
+            "a * b + c"
+        );
+    }
+
     #[test]
     fn test_parse_complex_formula() {
         let expr = parse_formula("SUM(sales.revenue) * (1 + tax_rate)").unwrap();
@@ -0,0 +1,284 @@
+//! Partial calculation: compute only selected outputs (and their prerequisites)
+//!
+//! Outputs are formula scalars (by full name) and formula columns (`table.column`).
+//! A bare table name selects all of that table's formula columns.
+
+use super::{strip_string_literals, ArrayCalculator};
+use crate::error::{ForgeError, ForgeResult};
+use std::collections::HashSet;
+
+/// Which outputs a calculation should compute
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum OutputSelection {
+    /// Compute every formula (default)
+    #[default]
+    All,
+    /// Compute only these outputs and everything they depend on
+    Only(Vec<String>),
+    /// Compute everything except these outputs and anything that depends on them
+    Skip(Vec<String>),
+}
+
+impl ArrayCalculator {
+    /// Resolve the selection into the set of outputs to compute (`None` = all)
+    pub(super) fn selected_outputs(&self) -> ForgeResult<Option<HashSet<String>>> {
+        let (names, only) = match &self.selection {
+            OutputSelection::All => return Ok(None),
+            OutputSelection::Only(names) => (names, true),
+            OutputSelection::Skip(names) => (names, false),
+        };
+
+        let mut roots = Vec::new();
+        for name in names {
+            let expanded = self.expand_output(name);
+            if expanded.is_empty() {
+                return Err(ForgeError::Validation(format!(
+                    "Unknown output '{name}': expected a formula scalar, table.column or table name"
+                )));
+            }
+            roots.extend(expanded);
+        }
+
+        let all = self.all_outputs();
+        if only {
+            // Walk prerequisites from the requested outputs
+            let mut selected: HashSet<String> = HashSet::new();
+            while let Some(output) = roots.pop() {
+                if selected.insert(output.clone()) {
+                    roots.extend(
+                        self.output_dependencies(&output)
+                            .into_iter()
+                            .filter(|dep| all.contains(dep)),
+                    );
+                }
+            }
+            Ok(Some(selected))
+        } else {
+            // Skip the named outputs and, transitively, everything depending on them
+            let mut skipped: HashSet<String> = roots.into_iter().collect();
+            loop {
+                let newly: Vec<String> = all
+                    .iter()
+                    .filter(|o| !skipped.contains(*o))
+                    .filter(|o| {
+                        self.output_dependencies(o)
+                            .iter()
+                            .any(|d| skipped.contains(d))
+                    })
+                    .cloned()
+                    .collect();
+                if newly.is_empty() {
+                    break;
+                }
+                skipped.extend(newly);
+            }
+            Ok(Some(all.difference(&skipped).cloned().collect()))
+        }
+    }
+
+    /// Every formula output in the model
+    fn all_outputs(&self) -> HashSet<String> {
+        let scalars = self
+            .model
+            .scalars
+            .iter()
+            .filter(|(_, var)| var.formula.is_some())
+            .map(|(name, _)| name.clone());
+        let columns = self.model.tables.iter().flat_map(|(table_name, table)| {
+            table
+                .row_formulas
+                .keys()
+                .map(move |col| format!("{table_name}.{col}"))
+        });
+        scalars.chain(columns).collect()
+    }
+
+    /// Map a user-supplied name to outputs (a table name expands to its formula columns)
+    fn expand_output(&self, name: &str) -> Vec<String> {
+        if self
+            .model
+            .scalars
+            .get(name)
+            .is_some_and(|var| var.formula.is_some())
+        {
+            return vec![name.to_string()];
+        }
+        if let Some(table) = self.model.tables.get(name) {
+            return table
+                .row_formulas
+                .keys()
+                .map(|col| format!("{name}.{col}"))
+                .collect();
+        }
+        if let Some((table_name, col)) = name.split_once('.') {
+            if self
+                .model
+                .tables
+                .get(table_name)
+                .is_some_and(|t| t.row_formulas.contains_key(col))
+            {
+                return vec![name.to_string()];
+            }
+        }
+        Vec::new()
+    }
+
+    /// Scalars and columns referenced by an output's formula
+    fn output_dependencies(&self, output: &str) -> Vec<String> {
+        let (formula, table_name, section) = self.model.scalars.get(output).map_or_else(
+            || {
+                // A `table.column` output: bare words resolve to columns of that table
+                let Some((table_name, col)) = output.split_once('.') else {
+                    return (None, None, None);
+                };
+                let formula = self
+                    .model
+                    .tables
+                    .get(table_name)
+                    .and_then(|t| t.row_formulas.get(col))
+                    .map(String::as_str);
+                (formula, Some(table_name), None)
+            },
+            |var| {
+                // Scalars may reference siblings in their section without the prefix
+                let section = output.rfind('.').map(|dot| &output[..dot]);
+                (var.formula.as_deref(), None, section)
+            },
+        );
+        let Some(formula) = formula else {
+            return Vec::new();
+        };
+
+        let stripped = strip_string_literals(formula);
+        let mut deps = Vec::new();
+        for word in stripped.split(|c: char| !c.is_alphanumeric() && c != '_' && c != '.') {
+            if word.is_empty() {
+                continue;
+            }
+            if self.model.scalars.contains_key(word) {
+                deps.push(word.to_string());
+            } else if let Some(scoped) = section
+                .map(|s| format!("{s}.{word}"))
+                .filter(|scoped| self.model.scalars.contains_key(scoped))
+            {
+                deps.push(scoped);
+            } else if let Some((t, c)) = word.split_once('.') {
+                if self
+                    .model
+                    .tables
+                    .get(t)
+                    .is_some_and(|t| t.columns.contains_key(c) || t.row_formulas.contains_key(c))
+                {
+                    deps.push(word.to_string());
+                }
+            } else if let Some(t) = table_name {
+                // Bare column name inside a row formula
+                deps.push(format!("{t}.{word}"));
+            }
+        }
+        deps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Column, ColumnValue, ParsedModel, Table, Variable};
+    use std::sync::{Arc, Mutex};
+
+    /// `final_npv` depends on `cash.net` (-> `cash.gross`) and `rate`;
+    /// `stress.expensive` is unrelated.
+    fn model() -> ParsedModel {
+        let mut model = ParsedModel::new();
+        let mut cash = Table::new("cash".to_string());
+        cash.add_column(Column::new(
+            "inflow".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0]),
+        ));
+        cash.add_row_formula("gross".to_string(), "=inflow * 2".to_string());
+        cash.add_row_formula("net".to_string(), "=gross - 50".to_string());
+        model.add_table(cash);
+
+        let mut stress = Table::new("stress".to_string());
+        stress.add_column(Column::new(
+            "x".to_string(),
+            ColumnValue::Number(vec![1.0, 2.0]),
+        ));
+        stress.add_row_formula("expensive".to_string(), "=x * x * x".to_string());
+        model.add_table(stress);
+
+        model.add_scalar(
+            "rate".to_string(),
+            Variable::new("rate".to_string(), None, Some("=0.05 * 2".to_string())),
+        );
+        model.add_scalar(
+            "final_npv".to_string(),
+            Variable::new(
+                "final_npv".to_string(),
+                None,
+                Some("=SUM(cash.net) * (1 - rate)".to_string()),
+            ),
+        );
+        model.add_scalar(
+            "stress_total".to_string(),
+            Variable::new(
+                "stress_total".to_string(),
+                None,
+                Some("=SUM(stress.expensive)".to_string()),
+            ),
+        );
+        model
+    }
+
+    fn run(selection: OutputSelection) -> (ParsedModel, Vec<String>) {
+        let computed = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&computed);
+        let result = ArrayCalculator::new(model())
+            .with_selection(selection)
+            .with_compute_hook(move |output| log.lock().unwrap().push(output.to_string()))
+            .calculate_all()
+            .expect("Should calculate");
+        let mut computed = computed.lock().unwrap().clone();
+        computed.sort();
+        (result, computed)
+    }
+
+    #[test]
+    fn test_only_computes_prerequisites() {
+        let (result, computed) = run(OutputSelection::Only(vec!["final_npv".to_string()]));
+        assert_eq!(
+            computed,
+            vec!["cash.gross", "cash.net", "final_npv", "rate"]
+        );
+        // (150 + 350) * 0.9
+        let npv = result.scalars["final_npv"].value.unwrap();
+        assert!((npv - 450.0).abs() < 1e-9);
+        assert!(!result.tables["stress"].columns.contains_key("expensive"));
+        assert_eq!(result.scalars["stress_total"].value, None);
+    }
+
+    #[test]
+    fn test_skip_excludes_dependents() {
+        let (result, computed) = run(OutputSelection::Skip(vec!["stress".to_string()]));
+        assert_eq!(
+            computed,
+            vec!["cash.gross", "cash.net", "final_npv", "rate"]
+        );
+        assert_eq!(result.scalars["stress_total"].value, None);
+    }
+
+    #[test]
+    fn test_all_computes_everything() {
+        let (_, computed) = run(OutputSelection::All);
+        assert_eq!(computed.len(), 6);
+    }
+
+    #[test]
+    fn test_unknown_output() {
+        let err = ArrayCalculator::new(model())
+            .with_selection(OutputSelection::Only(vec!["nope".to_string()]))
+            .calculate_all()
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown output 'nope'"));
+    }
+}
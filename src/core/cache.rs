@@ -0,0 +1,302 @@
+//! In-memory LRU cache for calculation results, keyed by model hash
+//!
+//! The key is a hash of the *normalized* model: the YAML is parsed and hashed
+//! structurally with mapping keys sorted, so comments, whitespace, quoting and
+//! key order do not change it while any change to a value or formula does.
+//! A multi-document file is hashed document by document, in order.
+//! Entries are never invalidated explicitly; they leave through LRU eviction
+//! or the optional time-to-live.
+
+use crate::error::ForgeResult;
+use serde::Deserialize;
+use serde_yaml_ng::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Hash of a model's normalized content (every document of a multi-document file)
+///
+/// # Errors
+///
+/// Returns an error if `content` is not valid YAML.
+pub fn model_hash(content: &str) -> ForgeResult<u64> {
+    let mut hasher = DefaultHasher::new();
+    for document in serde_yaml_ng::Deserializer::from_str(content) {
+        hash_value(&Value::deserialize(document)?, &mut hasher);
+    }
+    Ok(hasher.finish())
+}
+
+/// Hash a YAML value, visiting mapping entries in key order
+fn hash_value(value: &Value, hasher: &mut DefaultHasher) {
+    match value {
+        Value::Null => 0u8.hash(hasher),
+        Value::Bool(b) => {
+            1u8.hash(hasher);
+            b.hash(hasher);
+        },
+        Value::Number(n) => {
+            2u8.hash(hasher);
+            n.to_string().hash(hasher);
+        },
+        Value::String(s) => {
+            3u8.hash(hasher);
+            s.hash(hasher);
+        },
+        Value::Sequence(items) => {
+            4u8.hash(hasher);
+            items.len().hash(hasher);
+            for item in items {
+                hash_value(item, hasher);
+            }
+        },
+        Value::Mapping(map) => {
+            5u8.hash(hasher);
+            map.len().hash(hasher);
+            let mut entries: Vec<(String, &Value)> =
+                map.iter().map(|(k, v)| (format!("{k:?}"), v)).collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            for (key, value) in entries {
+                key.hash(hasher);
+                hash_value(value, hasher);
+            }
+        },
+        Value::Tagged(tagged) => {
+            6u8.hash(hasher);
+            tagged.tag.to_string().hash(hasher);
+            hash_value(&tagged.value, hasher);
+        },
+    }
+}
+
+struct Entry<T> {
+    value: T,
+    inserted: Instant,
+    last_used: u64,
+}
+
+struct Slots<T> {
+    entries: HashMap<u64, Entry<T>>,
+    /// Monotonic use counter for LRU ordering
+    tick: u64,
+}
+
+/// Thread-safe LRU cache of results keyed by [`model_hash`]
+pub struct CalculationCache<T: Clone> {
+    capacity: usize,
+    ttl: Option<Duration>,
+    slots: Mutex<Slots<T>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<T: Clone> CalculationCache<T> {
+    /// Create a cache holding at most `capacity` results (minimum 1), each
+    /// expiring `ttl` after it was computed (never, when `None`)
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Option<Duration>) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            ttl,
+            slots: Mutex::new(Slots {
+                entries: HashMap::new(),
+                tick: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Return the cached result for `content`, or compute and store it
+    ///
+    /// The flag is `true` on a cache hit. Errors from `compute` are returned
+    /// as-is and not cached. Content that cannot be hashed is computed
+    /// uncached, so `compute` reports its own error for invalid YAML.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `compute` fails.
+    pub fn get_or_insert_with<F>(&self, content: &str, compute: F) -> ForgeResult<(T, bool)>
+    where
+        F: FnOnce() -> ForgeResult<T>,
+    {
+        let Ok(key) = model_hash(content) else {
+            return compute().map(|value| (value, false));
+        };
+        if let Some(value) = self.lookup(key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok((value, true));
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        // Compute outside the lock so slow models don't block other lookups
+        let value = compute()?;
+        self.store(key, value.clone());
+        Ok((value, false))
+    }
+
+    fn lookup(&self, key: u64) -> Option<T> {
+        let mut slots = self.slots.lock().unwrap_or_else(PoisonError::into_inner);
+        let expired = slots
+            .entries
+            .get(&key)
+            .is_some_and(|entry| self.ttl.is_some_and(|ttl| entry.inserted.elapsed() >= ttl));
+        if expired {
+            slots.entries.remove(&key);
+            return None;
+        }
+        slots.tick += 1;
+        let tick = slots.tick;
+        slots.entries.get_mut(&key).map(|entry| {
+            entry.last_used = tick;
+            entry.value.clone()
+        })
+    }
+
+    fn store(&self, key: u64, value: T) {
+        let mut slots = self.slots.lock().unwrap_or_else(PoisonError::into_inner);
+        if !slots.entries.contains_key(&key) && slots.entries.len() >= self.capacity {
+            let oldest = slots
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(k, _)| *k);
+            if let Some(oldest) = oldest {
+                slots.entries.remove(&oldest);
+            }
+        }
+        slots.tick += 1;
+        let tick = slots.tick;
+        slots.entries.insert(
+            key,
+            Entry {
+                value,
+                inserted: Instant::now(),
+                last_used: tick,
+            },
+        );
+    }
+
+    /// Number of lookups served from the cache
+    #[must_use]
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of lookups that had to compute a result
+    #[must_use]
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Number of results currently cached
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.slots
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entries
+            .len()
+    }
+
+    /// Whether the cache holds no results
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_hash_ignores_formatting_and_key_order() {
+        let a = "# budget\nprice: {value: 10}\nunits:\n  value: 5\n";
+        let b = "units: {value: 5}\nprice:\n    value: 10   # per unit\n";
+        assert_eq!(model_hash(a).unwrap(), model_hash(b).unwrap());
+        assert_ne!(
+            model_hash(a).unwrap(),
+            model_hash("price: {value: 11}\nunits: {value: 5}\n").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_model_hash_multi_document() {
+        let doc = "---\n_name: a\nx: {value: 1}\n---\n_name: b\ny: {value: 2}\n";
+        let reformatted = "---\n_name: a\nx:\n  value: 1\n---\ny: {value: 2}\n_name: b\n";
+        assert_eq!(model_hash(doc).unwrap(), model_hash(reformatted).unwrap());
+        let changed = "---\n_name: a\nx: {value: 1}\n---\n_name: b\ny: {value: 3}\n";
+        assert_ne!(model_hash(doc).unwrap(), model_hash(changed).unwrap());
+        let swapped = "---\n_name: b\ny: {value: 2}\n---\n_name: a\nx: {value: 1}\n";
+        assert_ne!(model_hash(doc).unwrap(), model_hash(swapped).unwrap());
+
+        let cache = CalculationCache::new(4, None);
+        assert_eq!(cache.get_or_insert_with(doc, || Ok(1)).unwrap(), (1, false));
+        assert_eq!(
+            cache.get_or_insert_with(reformatted, || Ok(2)).unwrap(),
+            (1, true)
+        );
+    }
+
+    #[test]
+    fn test_cache_computes_unhashable_content_uncached() {
+        let cache = CalculationCache::new(4, None);
+        let content = "x: [unclosed";
+        assert_eq!(
+            cache.get_or_insert_with(content, || Ok(1)).unwrap(),
+            (1, false)
+        );
+        assert_eq!(
+            cache.get_or_insert_with(content, || Ok(2)).unwrap(),
+            (2, false)
+        );
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_cache_hits_and_misses() {
+        let cache = CalculationCache::new(4, None);
+        let mut computed = 0;
+        let mut run = |content: &str| {
+            cache
+                .get_or_insert_with(content, || {
+                    computed += 1;
+                    Ok(computed)
+                })
+                .unwrap()
+        };
+
+        assert_eq!(run("x: {value: 1}"), (1, false));
+        assert_eq!(run("x:   {value: 1}  # same model"), (1, true));
+        assert_eq!(run("x: {value: 2}"), (2, false));
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(cache.misses(), 2);
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let cache = CalculationCache::new(2, None);
+        let put = |content: &str| cache.get_or_insert_with(content, || Ok(1)).unwrap().1;
+
+        put("a: 1");
+        put("b: 1");
+        assert!(put("a: 1")); // refresh a
+        put("c: 1"); // evicts b
+        assert_eq!(cache.len(), 2);
+        assert!(put("a: 1"));
+        assert!(!put("b: 1"));
+    }
+
+    #[test]
+    fn test_cache_ttl_expires_entries() {
+        let cache = CalculationCache::new(4, Some(Duration::ZERO));
+        let put = |content: &str| cache.get_or_insert_with(content, || Ok(1)).unwrap().1;
+        assert!(!put("a: 1"));
+        assert!(!put("a: 1"));
+        assert_eq!(cache.hits(), 0);
+    }
+}
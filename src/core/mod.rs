@@ -1,7 +1,11 @@
 //! Core calculation engine for v1.0.0 array models
 
 pub mod array_calculator;
+pub mod cache;
 pub mod unit_validator;
 
-pub use array_calculator::ArrayCalculator;
+pub use array_calculator::{
+    check, compile, ArrayCalculator, CompileError, FormulaError, OutputSelection,
+};
+pub use cache::{model_hash, CalculationCache};
 pub use unit_validator::{UnitValidator, UnitWarning};
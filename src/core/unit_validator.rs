@@ -70,6 +70,25 @@ impl UnitCategory {
         }
     }
 
+    /// Check if values of two units can be added, subtracted or compared
+    #[must_use]
+    pub fn can_add(&self, other: &Self) -> bool {
+        match (self, other) {
+            // Same currency can be added
+            (Self::Currency(c1), Self::Currency(c2)) => c1 == c2,
+            // Same time units can be added
+            (Self::Time(t1), Self::Time(t2)) => t1 == t2,
+            // Same-category scalars and unknown are compatible
+            (Self::Count, Self::Count)
+            | (Self::Ratio, Self::Ratio)
+            | (Self::Percentage, Self::Percentage)
+            | (Self::Unknown, _)
+            | (_, Self::Unknown) => true,
+            // Everything else is incompatible
+            _ => false,
+        }
+    }
+
     /// Get a display name for the unit category
     #[must_use]
     pub fn display(&self) -> String {
@@ -243,20 +262,7 @@ impl<'a> UnitValidator<'a> {
     // Takes &self for API consistency; tested extensively via validator.can_add() in tests.
     #[allow(clippy::unused_self)]
     fn can_add(&self, a: &UnitCategory, b: &UnitCategory) -> bool {
-        match (a, b) {
-            // Same currency can be added
-            (UnitCategory::Currency(c1), UnitCategory::Currency(c2)) => c1 == c2,
-            // Same time units can be added
-            (UnitCategory::Time(t1), UnitCategory::Time(t2)) => t1 == t2,
-            // Same-category scalars and unknown are compatible
-            (UnitCategory::Count, UnitCategory::Count)
-            | (UnitCategory::Ratio, UnitCategory::Ratio)
-            | (UnitCategory::Percentage, UnitCategory::Percentage)
-            | (UnitCategory::Unknown, _)
-            | (_, UnitCategory::Unknown) => true,
-            // Everything else is incompatible
-            _ => false,
-        }
+        a.can_add(b)
     }
 
     /// Extract variable references from a formula
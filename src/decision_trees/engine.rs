@@ -36,6 +36,9 @@ pub struct TreeResult {
     pub decision_policy: HashMap<String, String>,
     /// Risk profile
     pub risk_profile: RiskProfile,
+    /// Expected value of perfect information, when requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evpi: Option<PerfectInformation>,
 }
 
 /// Risk profile showing outcome distribution
@@ -49,6 +52,21 @@ pub struct RiskProfile {
     pub probability_positive: f64,
 }
 
+/// Expected value of perfect information (EVPI) about one chance node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerfectInformation {
+    /// Chance node whose outcome is revealed before deciding
+    pub node: String,
+    /// Optimal expected value when each outcome is known in advance
+    pub outcome_values: HashMap<String, f64>,
+    /// Probability-weighted expected value with perfect information
+    pub expected_value_with_information: f64,
+    /// Optimal expected value without the information
+    pub baseline_expected_value: f64,
+    /// Value of the information (with minus baseline)
+    pub evpi: f64,
+}
+
 impl TreeResult {
     /// Export results to YAML format
     #[must_use]
@@ -112,6 +130,68 @@ impl DecisionTreeEngine {
             optimal_path,
             decision_policy,
             risk_profile,
+            evpi: None,
+        })
+    }
+
+    /// Expected value of perfect information about a chance node
+    ///
+    /// Re-solves the tree once per outcome of `chance_node` with that outcome
+    /// fixed, so every decision is taken knowing it, then probability-weights
+    /// the optimal values and subtracts the baseline expected value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node does not exist, is not a chance node, or
+    /// the tree cannot be solved.
+    pub fn value_of_perfect_information(
+        &self,
+        chance_node: &str,
+    ) -> Result<PerfectInformation, String> {
+        let node = if chance_node == "root" {
+            self.config.root.as_ref()
+        } else {
+            self.config.get_node(chance_node)
+        }
+        .ok_or_else(|| format!("Node '{chance_node}' not found"))?;
+        if node.node_type != NodeType::Chance {
+            return Err(format!("Node '{chance_node}' is not a chance node"));
+        }
+
+        let baseline_expected_value = self.analyze()?.root_expected_value;
+
+        let mut outcome_values = HashMap::new();
+        let mut expected_value_with_information = 0.0;
+        for (outcome, branch) in &node.branches {
+            // Collapse the chance node onto a single certain outcome
+            let known = Node {
+                branches: HashMap::from([(
+                    outcome.clone(),
+                    Branch {
+                        probability: 1.0,
+                        ..branch.clone()
+                    },
+                )]),
+                ..node.clone()
+            };
+            let mut config = self.config.clone();
+            if chance_node == "root" {
+                config.root = Some(known);
+            } else {
+                config.nodes.insert(chance_node.to_string(), known);
+            }
+
+            let value = Self { config }.analyze()?.root_expected_value;
+            expected_value_with_information += branch.probability * value;
+            outcome_values.insert(outcome.clone(), value);
+        }
+
+        Ok(PerfectInformation {
+            node: chance_node.to_string(),
+            outcome_values,
+            expected_value_with_information,
+            baseline_expected_value,
+            evpi: expected_value_with_information - baseline_expected_value,
         })
     }
 
@@ -460,6 +540,24 @@ mod engine_tests {
         );
     }
 
+    #[test]
+    fn test_value_of_perfect_information() {
+        let engine = DecisionTreeEngine::new(create_rnd_tree()).unwrap();
+        let info = engine.value_of_perfect_information("tech_outcome").unwrap();
+
+        // Known success: invest, license → $5M - $2M = $3M
+        // Known failure: don't invest → $0
+        // With information: 0.6 × $3M + 0.4 × $0 = $1.8M; baseline $1M
+        assert!((info.outcome_values["success"] - 3_000_000.0).abs() < 0.01);
+        assert!(info.outcome_values["failure"].abs() < 0.01);
+        assert!((info.expected_value_with_information - 1_800_000.0).abs() < 0.01);
+        assert!((info.baseline_expected_value - 1_000_000.0).abs() < 0.01);
+        assert!((info.evpi - 800_000.0).abs() < 0.01);
+
+        assert!(engine.value_of_perfect_information("root").is_err());
+        assert!(engine.value_of_perfect_information("missing").is_err());
+    }
+
     #[test]
     fn test_yaml_export() {
         let config = create_rnd_tree();
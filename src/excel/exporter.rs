@@ -1,11 +1,15 @@
 //! Excel exporter implementation
 
+use crate::core::unit_validator::UnitCategory;
 use crate::error::{ForgeError, ForgeResult};
 use crate::types::{ColumnValue, Metadata, ParsedModel, Table};
-use rust_xlsxwriter::{Formula, Note, Workbook, Worksheet};
+use rust_xlsxwriter::{Format, Formula, Note, Url, Workbook, Worksheet};
 use std::collections::HashMap;
 use std::path::Path;
 
+/// Title cell (A1) of the Summary sheet; the importer skips sheets starting with it
+pub(super) const SUMMARY_TITLE: &str = "Forge Summary";
+
 /// Location of a scalar in the exported Excel workbook
 #[derive(Clone, Debug)]
 pub struct ScalarLocation {
@@ -133,8 +137,35 @@ impl ExcelExporter {
     ///
     /// Returns an error if the workbook cannot be saved to the specified path.
     pub fn export(&self, output_path: &Path) -> ForgeResult<()> {
+        let mut workbook = self.build_workbook()?;
+
+        // Save workbook to file
+        workbook
+            .save(output_path)
+            .map_err(|e| ForgeError::IO(format!("Failed to save Excel file: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Export the model to an in-memory Excel buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the workbook cannot be serialized.
+    pub fn export_to_buffer(&self) -> ForgeResult<Vec<u8>> {
+        let mut workbook = self.build_workbook()?;
+        workbook
+            .save_to_buffer()
+            .map_err(|e| ForgeError::IO(format!("Failed to create Excel buffer: {e}")))
+    }
+
+    /// Build the workbook: a Summary sheet, then one sheet per table and scalar group
+    fn build_workbook(&self) -> ForgeResult<Workbook> {
         let mut workbook = Workbook::new();
 
+        // Overview sheet first, so it is the one Excel opens on
+        self.export_summary(&mut workbook)?;
+
         // Export each table as a separate worksheet
         for (table_name, table) in &self.model.tables {
             self.export_table(&mut workbook, table_name, table)?;
@@ -159,43 +190,101 @@ impl ExcelExporter {
             }
         }
 
-        // Save workbook to file
-        workbook
-            .save(output_path)
-            .map_err(|e| ForgeError::IO(format!("Failed to save Excel file: {e}")))?;
-
-        Ok(())
+        Ok(workbook)
     }
 
-    /// Export the model to an in-memory Excel buffer.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the workbook cannot be serialized.
-    pub fn export_to_buffer(&self) -> ForgeResult<Vec<u8>> {
-        let mut workbook = Workbook::new();
+    /// Name for the Summary sheet, avoiding a clash with a table or scalar group
+    fn summary_sheet_name(&self) -> &'static str {
+        let taken = self
+            .model
+            .tables
+            .keys()
+            .cloned()
+            .chain(self.scalar_locations.values().map(|l| l.worksheet.clone()))
+            .any(|name| name.eq_ignore_ascii_case("Summary"));
+        if taken {
+            "Forge Summary"
+        } else {
+            "Summary"
+        }
+    }
 
-        for (table_name, table) in &self.model.tables {
-            self.export_table(&mut workbook, table_name, table)?;
+    /// Export the Summary sheet: each table (linked to its sheet) with its size,
+    /// then every scalar with its value
+    #[allow(clippy::cast_possible_truncation)] // table and scalar counts are within u32 Excel limits
+    fn export_summary(&self, workbook: &mut Workbook) -> ForgeResult<()> {
+        let err = |e: rust_xlsxwriter::XlsxError| {
+            ForgeError::Export(format!("Failed to write Summary sheet: {e}"))
+        };
+        let bold = Format::new().set_bold();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(self.summary_sheet_name()).map_err(err)?;
+        worksheet
+            .write_string_with_format(0, 0, SUMMARY_TITLE, &bold)
+            .map_err(err)?;
+
+        let mut tables: Vec<(String, &Table)> = self
+            .model
+            .tables
+            .iter()
+            .map(|(name, table)| (name.clone(), table))
+            .collect();
+        for (namespace, resolved) in &self.model.resolved_includes {
+            for (name, table) in &resolved.model.tables {
+                tables.push((format!("{namespace}.{name}"), table));
+            }
         }
+        tables.sort_by(|a, b| a.0.cmp(&b.0));
 
-        if !self.model.scalars.is_empty() {
-            self.export_scalars(&mut workbook)?;
+        let mut row = 2;
+        for (col, header) in ["Table", "Rows", "Columns"].iter().enumerate() {
+            worksheet
+                .write_string_with_format(row, col as u16, *header, &bold)
+                .map_err(err)?;
+        }
+        for (name, table) in &tables {
+            row += 1;
+            let link = Url::new(format!("internal:'{name}'!A1")).set_text(name);
+            worksheet.write_url(row, 0, link).map_err(err)?;
+            worksheet
+                .write_number(row, 1, table.row_count() as f64)
+                .map_err(err)?;
+            worksheet
+                .write_number(
+                    row,
+                    2,
+                    (table.columns.len() + table.row_formulas.len()) as f64,
+                )
+                .map_err(err)?;
         }
 
-        for (namespace, resolved) in &self.model.resolved_includes {
-            for (table_name, table) in &resolved.model.tables {
-                let prefixed_name = format!("{namespace}.{table_name}");
-                self.export_table(&mut workbook, &prefixed_name, table)?;
-            }
-            if !resolved.model.scalars.is_empty() {
-                Self::export_namespaced_scalars(&mut workbook, namespace, &resolved.model)?;
+        if self.model.scalars.is_empty() {
+            return Ok(());
+        }
+        row += 2;
+        for (col, header) in ["Scalar", "Value"].iter().enumerate() {
+            worksheet
+                .write_string_with_format(row, col as u16, *header, &bold)
+                .map_err(err)?;
+        }
+        let mut scalar_names: Vec<&String> = self.model.scalars.keys().collect();
+        scalar_names.sort();
+        for path in scalar_names {
+            row += 1;
+            worksheet.write_string(row, 0, path).map_err(err)?;
+            // Link to the scalar's cell so the summary follows recalculation
+            if let Some(location) = self.scalar_locations.get(path) {
+                let mut formula =
+                    Formula::new(format!("='{}'!B{}", location.worksheet, location.row));
+                if let Some(value) = self.model.scalars[path].value {
+                    formula = formula.set_result(value.to_string());
+                }
+                worksheet.write_formula(row, 1, formula).map_err(err)?;
             }
         }
+        worksheet.set_column_width(0, 30).map_err(err)?;
 
-        workbook
-            .save_to_buffer()
-            .map_err(|e| ForgeError::IO(format!("Failed to create Excel buffer: {e}")))
+        Ok(())
     }
 
     /// Export a single table to a worksheet
@@ -210,6 +299,9 @@ impl ExcelExporter {
         worksheet
             .set_name(table_name)
             .map_err(|e| ForgeError::Export(format!("Failed to set worksheet name: {e}")))?;
+        worksheet
+            .set_freeze_panes(1, 0)
+            .map_err(|e| ForgeError::Export(format!("Failed to freeze header row: {e}")))?;
 
         // Get column names in a deterministic order (data + formula columns)
         let mut column_names: Vec<String> = Vec::new();
@@ -266,6 +358,18 @@ impl ExcelExporter {
             .next()
             .map_or(0, super::super::types::Column::len);
 
+        // Number format per column from its unit (None = General)
+        let formats: Vec<Option<Format>> = column_names
+            .iter()
+            .map(|col_name| {
+                table
+                    .columns
+                    .get(col_name)
+                    .and_then(|column| Self::number_format(&column.metadata))
+                    .map(|code| Format::new().set_num_format(code))
+            })
+            .collect();
+
         // Write data rows (starting at row 1)
         for row_idx in 0..row_count {
             let excel_row = (row_idx + 1) as u32 + 1; // +1 for header row, +1 for Excel 1-indexing = row 2 for first data row
@@ -286,6 +390,7 @@ impl ExcelExporter {
                         col_idx as u16,
                         &column.values,
                         row_idx,
+                        formats[col_idx].as_ref(),
                     )?;
                 }
             }
@@ -301,13 +406,16 @@ impl ExcelExporter {
         col: u16,
         values: &ColumnValue,
         index: usize,
+        format: Option<&Format>,
     ) -> ForgeResult<()> {
         match values {
             ColumnValue::Number(nums) => {
                 if let Some(&value) = nums.get(index) {
-                    worksheet
-                        .write_number(row, col, value)
-                        .map_err(|e| ForgeError::Export(format!("Failed to write number: {e}")))?;
+                    match format {
+                        Some(format) => worksheet.write_number_with_format(row, col, value, format),
+                        None => worksheet.write_number(row, col, value),
+                    }
+                    .map_err(|e| ForgeError::Export(format!("Failed to write number: {e}")))?;
                 }
             },
             ColumnValue::Text(texts) => {
@@ -371,6 +479,9 @@ impl ExcelExporter {
         worksheet.set_name(group_name).map_err(|e| {
             ForgeError::Export(format!("Failed to set worksheet name '{group_name}': {e}"))
         })?;
+        worksheet
+            .set_freeze_panes(1, 0)
+            .map_err(|e| ForgeError::Export(format!("Failed to freeze header row: {e}")))?;
 
         // Write header row
         worksheet
@@ -554,6 +665,16 @@ impl ExcelExporter {
         Ok(())
     }
 
+    /// Excel number format for a column's unit: currency and percent units get
+    /// a display format, anything else stays General
+    fn number_format(metadata: &Metadata) -> Option<&'static str> {
+        match UnitCategory::parse(metadata.unit.as_deref()?) {
+            UnitCategory::Currency(_) => Some("$#,##0.00"),
+            UnitCategory::Percentage => Some("0.0%"),
+            _ => None,
+        }
+    }
+
     /// Format metadata fields as a note text for Excel cell comments (v4.0)
     /// Returns None if metadata is empty
     fn format_metadata_note(metadata: &Metadata) -> Option<String> {
@@ -985,6 +1106,114 @@ mod tests {
         assert!(output_path.exists());
     }
 
+    #[test]
+    fn test_number_format_from_unit() {
+        let with_unit = |unit: &str| Metadata {
+            unit: Some(unit.to_string()),
+            ..Metadata::default()
+        };
+        assert_eq!(ExcelExporter::number_format(&with_unit("%")), Some("0.0%"));
+        assert_eq!(
+            ExcelExporter::number_format(&with_unit("USD")),
+            Some("$#,##0.00")
+        );
+        assert_eq!(ExcelExporter::number_format(&with_unit("days")), None);
+        assert_eq!(ExcelExporter::number_format(&Metadata::default()), None);
+    }
+
+    #[test]
+    fn test_export_percent_column_with_number_format() {
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("rates".to_string());
+        table.add_column(Column::with_metadata(
+            "margin".to_string(),
+            ColumnValue::Number(vec![0.125, 0.25]),
+            Metadata {
+                unit: Some("%".to_string()),
+                ..Metadata::default()
+            },
+        ));
+        model.add_table(table);
+
+        let buffer = ExcelExporter::new(model.clone())
+            .export_to_buffer()
+            .unwrap();
+        assert!(!buffer.is_empty());
+        // The format changes only the display, not the stored value
+        assert!(crate::excel::check_round_trip(&model).unwrap().is_empty());
+    }
+
+    /// Read one part (e.g. `xl/worksheets/sheet1.xml`) out of an exported workbook
+    fn xlsx_part(buffer: &[u8], name: &str) -> String {
+        use std::io::Read;
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(buffer)).unwrap();
+        let mut xml = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+        xml
+    }
+
+    #[test]
+    fn test_export_summary_sheet_and_frozen_headers() {
+        use calamine::{Data, Reader, Xlsx};
+
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("sales".to_string());
+        table.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0, 300.0]),
+        ));
+        model.add_table(table);
+        model.add_scalar(
+            "inputs.growth".to_string(),
+            Variable::new("inputs.growth".to_string(), Some(0.05), None),
+        );
+
+        let buffer = ExcelExporter::new(model).export_to_buffer().unwrap();
+        let mut workbook = Xlsx::new(std::io::Cursor::new(buffer.clone())).unwrap();
+        assert_eq!(workbook.sheet_names(), ["Summary", "sales", "inputs"]);
+
+        let summary = workbook.worksheet_range("Summary").unwrap();
+        assert_eq!(
+            summary.get((3, 0)),
+            Some(&Data::String("sales".to_string()))
+        );
+        assert_eq!(summary.get((3, 1)), Some(&Data::Float(3.0)));
+        assert_eq!(
+            summary.get((6, 0)),
+            Some(&Data::String("inputs.growth".to_string()))
+        );
+
+        // Data sheets freeze their header row; the summary does not
+        assert!(!xlsx_part(&buffer, "xl/worksheets/sheet1.xml").contains("<pane"));
+        for sheet in ["sheet2", "sheet3"] {
+            let xml = xlsx_part(&buffer, &format!("xl/worksheets/{sheet}.xml"));
+            assert!(
+                xml.contains(
+                    r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#
+                ),
+                "{sheet}: {xml}"
+            );
+        }
+
+        // Importing skips the summary
+        let imported = crate::excel::ExcelImporter::import_bytes(&buffer).unwrap();
+        assert_eq!(imported.tables.len(), 1);
+    }
+
+    #[test]
+    fn test_summary_sheet_name_avoids_table_clash() {
+        let mut model = ParsedModel::new();
+        model.add_table(Table::new("summary".to_string()));
+        assert_eq!(
+            ExcelExporter::new(model).summary_sheet_name(),
+            "Forge Summary"
+        );
+    }
+
     #[test]
     fn test_export_with_metadata() {
         use tempfile::TempDir;
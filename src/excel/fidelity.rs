@@ -0,0 +1,171 @@
+//! Round-trip fidelity check (YAML → xlsx → YAML)
+//!
+//! Exports a model to an in-memory workbook, imports it back and compares every
+//! input number. Formula results are left to Excel to recalculate, so only
+//! data columns and value-only scalars are compared.
+
+use crate::error::ForgeResult;
+use crate::excel::{ExcelExporter, ExcelImporter};
+use crate::types::{ColumnValue, ParsedModel};
+use std::fmt;
+
+/// An input number that changed or went missing in a round-trip
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drift {
+    /// `table.column[row]` or the scalar path
+    pub location: String,
+    pub original: f64,
+    /// The value read back, or `None` if it was lost
+    pub round_tripped: Option<f64>,
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.round_tripped {
+            Some(value) => write!(f, "{}: {} -> {}", self.location, self.original, value),
+            None => write!(f, "{}: {} -> missing", self.location, self.original),
+        }
+    }
+}
+
+/// Export `model` to xlsx in memory and import it back
+///
+/// # Errors
+///
+/// Returns an error if the export or the import fails.
+pub fn round_trip(model: &ParsedModel) -> ForgeResult<ParsedModel> {
+    let bytes = ExcelExporter::new(model.clone()).export_to_buffer()?;
+    ExcelImporter::import_bytes(&bytes)
+}
+
+/// Round-trip `model` and list every input number that did not survive
+///
+/// # Errors
+///
+/// Returns an error if the export or the import fails.
+pub fn check_round_trip(model: &ParsedModel) -> ForgeResult<Vec<Drift>> {
+    let back = round_trip(model)?;
+    let mut drifts = Vec::new();
+
+    let mut table_names: Vec<&String> = model.tables.keys().collect();
+    table_names.sort();
+    for table_name in table_names {
+        let table = &model.tables[table_name];
+        let mut column_names: Vec<&String> = table.columns.keys().collect();
+        column_names.sort();
+        for column_name in column_names {
+            let ColumnValue::Number(values) = &table.columns[column_name].values else {
+                continue;
+            };
+            let returned = back
+                .tables
+                .get(table_name)
+                .and_then(|t| t.columns.get(column_name))
+                .and_then(|c| match &c.values {
+                    ColumnValue::Number(v) => Some(v.as_slice()),
+                    _ => None,
+                });
+            for (row, &original) in values.iter().enumerate() {
+                let round_tripped = returned.and_then(|v| v.get(row).copied());
+                if !round_tripped.is_some_and(|v| same_number(original, v)) {
+                    drifts.push(Drift {
+                        location: format!("{table_name}.{column_name}[{row}]"),
+                        original,
+                        round_tripped,
+                    });
+                }
+            }
+        }
+    }
+
+    let mut scalar_names: Vec<&String> = model.scalars.keys().collect();
+    scalar_names.sort();
+    for path in scalar_names {
+        let var = &model.scalars[path];
+        let (None, Some(original)) = (&var.formula, var.value) else {
+            continue;
+        };
+        let round_tripped = back.scalars.get(path).and_then(|v| v.value);
+        if !round_tripped.is_some_and(|v| same_number(original, v)) {
+            drifts.push(Drift {
+                location: path.clone(),
+                original,
+                round_tripped,
+            });
+        }
+    }
+
+    Ok(drifts)
+}
+
+/// Equal to within one ulp-scale relative step
+fn same_number(a: f64, b: f64) -> bool {
+    (a - b).abs() <= f64::EPSILON * a.abs().max(b.abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Column, Table, Variable};
+
+    fn model_with(values: Vec<f64>) -> ParsedModel {
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("data".to_string());
+        table.add_column(Column::new(
+            "amount".to_string(),
+            ColumnValue::Number(values),
+        ));
+        model.add_table(table);
+        model
+    }
+
+    #[test]
+    fn test_high_precision_values_survive_round_trip() {
+        let values = vec![
+            0.123_456_789_012_345_67,
+            1.0 / 3.0,
+            std::f64::consts::PI,
+            1e-12,
+            123_456_789.123_456_78,
+        ];
+        let mut model = model_with(values.clone());
+        model.add_scalar(
+            "rate".to_string(),
+            Variable::new("rate".to_string(), Some(0.1 + 0.2), None),
+        );
+
+        assert_eq!(check_round_trip(&model).unwrap(), Vec::new());
+        let back = round_trip(&model).unwrap();
+        match &back.tables["data"].columns["amount"].values {
+            ColumnValue::Number(v) => assert!(v
+                .iter()
+                .zip(&values)
+                .all(|(a, b)| (a - b).abs() <= f64::EPSILON * b.abs())),
+            other => panic!("expected numbers, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_grouped_scalars_keep_their_paths() {
+        let mut model = model_with(vec![1.0]);
+        model.add_scalar(
+            "inputs.growth".to_string(),
+            Variable::new("inputs.growth".to_string(), Some(0.0725), None),
+        );
+
+        assert_eq!(check_round_trip(&model).unwrap(), Vec::new());
+        let back = round_trip(&model).unwrap();
+        assert!(!back.tables.contains_key("inputs"));
+        assert_eq!(back.scalars["inputs.growth"].value, Some(0.0725));
+    }
+
+    #[test]
+    fn test_drift_reports_lost_value() {
+        let drift = Drift {
+            location: "data.amount[0]".to_string(),
+            original: 1.5,
+            round_tripped: None,
+        };
+        assert_eq!(drift.to_string(), "data.amount[0]: 1.5 -> missing");
+    }
+}
@@ -274,6 +274,7 @@ impl FormulaTranslator {
                 | "INT"
                 | "TRUNC"
                 | "SIGN"
+                | "CONVERT"
                 // Trigonometric functions
                 | "SIN"
                 | "COS"
@@ -302,6 +303,10 @@ impl FormulaTranslator {
                 | "SEARCH"
                 | "REPLACE"
                 | "SUBSTITUTE"
+                | "TEXTBEFORE"
+                | "TEXTAFTER"
+                | "EXACT"
+                | "PROPER"
                 // Date functions
                 | "TODAY"
                 | "NOW"
@@ -310,6 +315,8 @@ impl FormulaTranslator {
                 | "MONTH"
                 | "DAY"
                 | "WEEKDAY"
+                | "WEEKNUM"
+                | "ISOWEEKNUM"
                 | "HOUR"
                 | "MINUTE"
                 | "SECOND"
@@ -321,6 +328,8 @@ impl FormulaTranslator {
                 | "YEARFRAC"
                 | "DAYS"
                 | "TIME"
+                | "DATEVALUE"
+                | "TIMEVALUE"
                 // Financial functions
                 | "NPV"
                 | "IRR"
@@ -335,6 +344,10 @@ impl FormulaTranslator {
                 | "SLN"
                 | "DB"
                 | "DDB"
+                | "SYD"
+                | "VDB"
+                | "CUMIPMT"
+                | "CUMPRINC"
                 // Information functions
                 | "ISEVEN"
                 | "ISODD"
@@ -353,6 +366,8 @@ impl FormulaTranslator {
                 | "VLOOKUP"
                 | "HLOOKUP"
                 | "XLOOKUP"
+                | "XMATCH"
+                | "LOOKUP"
                 | "INDEX"
                 | "MATCH"
                 | "OFFSET"
@@ -375,14 +390,29 @@ impl FormulaTranslator {
                 | "STDEV"
                 | "STDEVP"
                 | "CORREL"
+                | "SLOPE"
+                | "INTERCEPT"
+                | "FORECAST"
+                | "FORECAST.LINEAR"
+                | "GEOMEAN"
+                | "HARMEAN"
                 | "PERCENTILE"
+                | "PERCENTILE.INC"
+                | "PERCENTILE.EXC"
                 | "QUARTILE"
+                | "QUARTILE.INC"
+                | "QUARTILE.EXC"
                 | "LARGE"
                 | "SMALL"
                 | "RANK"
+                | "RANK.EQ"
+                | "RANK.AVG"
                 // Advanced functions
                 | "LET"
                 | "LAMBDA"
+                | "MAP"
+                | "REDUCE"
+                | "SCAN"
                 | "SWITCH"
                 | "IFS"
         )
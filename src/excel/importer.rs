@@ -1,15 +1,58 @@
 //! Excel importer implementation - Excel (.xlsx) → YAML
 
 use crate::error::{ForgeError, ForgeResult};
+use crate::excel::exporter::SUMMARY_TITLE;
 use crate::excel::reverse_formula_translator::ReverseFormulaTranslator;
-use crate::types::{Column, ColumnValue, ParsedModel, Table, Variable};
-use calamine::{open_workbook, Data, Range, Reader, Xlsx};
+use crate::excel::validations::{parse_area, read_list_validations, ListSource, ListValidation};
+use crate::types::{Column, ColumnValue, Metadata, ParsedModel, Table, Variable};
+use calamine::{open_workbook, CellType, Data, DataType, Range, Reader, Xlsx};
 use std::collections::HashMap;
-use std::path::Path;
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read, Seek};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// A dropdown (list data validation) over a block of sheet columns
+struct Dropdown {
+    first_col: u32,
+    last_col: u32,
+    values: Vec<String>,
+}
+
+/// Column type forced on import, overriding inference from the cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Number,
+    Text,
+    Date,
+    Boolean,
+}
+
+impl FromStr for ColumnType {
+    type Err = ForgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "number" => Ok(Self::Number),
+            "text" => Ok(Self::Text),
+            "date" => Ok(Self::Date),
+            "boolean" | "bool" => Ok(Self::Boolean),
+            other => Err(ForgeError::Import(format!(
+                "Unknown column type '{other}' (expected Number, Text, Date or Boolean)"
+            ))),
+        }
+    }
+}
 
 /// Excel importer for converting .xlsx files to v1.0.0 YAML models
 pub struct ExcelImporter {
     path: std::path::PathBuf,
+    /// Forced column types, keyed by `column` or `table.column`
+    column_types: HashMap<String, ColumnType>,
+    /// Sheets to import (empty = all)
+    sheets: Vec<String>,
+    /// Cell block read from each sheet, header in its first row (0-indexed, inclusive)
+    cell_range: Option<((u32, u32), (u32, u32))>,
 }
 
 impl ExcelImporter {
@@ -17,9 +60,43 @@ impl ExcelImporter {
     pub fn new<P: AsRef<Path>>(path: P) -> Self {
         Self {
             path: path.as_ref().to_path_buf(),
+            column_types: HashMap::new(),
+            sheets: Vec::new(),
+            cell_range: None,
         }
     }
 
+    /// Import only the named sheets
+    #[must_use]
+    pub fn with_sheets(mut self, sheets: Vec<String>) -> Self {
+        self.sheets = sheets;
+        self
+    }
+
+    /// Read each sheet's data from a cell range such as `A3:F100`, with the
+    /// header in the range's first row
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `range` is not an `A1:B2`-style cell range.
+    pub fn with_range(mut self, range: &str) -> ForgeResult<Self> {
+        let (start, end) = parse_area(range)
+            .filter(|(start, end)| start.0 <= end.0 && start.1 <= end.1)
+            .ok_or_else(|| {
+                ForgeError::Import(format!("Invalid range '{range}' (expected e.g. A3:F100)"))
+            })?;
+        self.cell_range = Some((start, end));
+        Ok(self)
+    }
+
+    /// Force column types instead of inferring them; keys are a column name
+    /// (any table) or `table.column`
+    #[must_use]
+    pub fn with_column_types(mut self, column_types: HashMap<String, ColumnType>) -> Self {
+        self.column_types = column_types;
+        self
+    }
+
     /// Import Excel file to `ParsedModel`
     ///
     /// # Errors
@@ -29,17 +106,60 @@ impl ExcelImporter {
         // Open Excel workbook
         let mut workbook: Xlsx<_> = open_workbook(&self.path)
             .map_err(|e| ForgeError::IO(format!("Failed to open Excel file: {e}")))?;
+        let validations = File::open(&self.path)
+            .map(|file| read_list_validations(BufReader::new(file)))
+            .unwrap_or_default();
+        self.import_workbook(&mut workbook, &validations)
+    }
+
+    /// Import an in-memory .xlsx workbook to `ParsedModel`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid workbook or cannot be parsed.
+    pub fn import_bytes(bytes: &[u8]) -> ForgeResult<ParsedModel> {
+        let mut workbook = Xlsx::new(Cursor::new(bytes))
+            .map_err(|e| ForgeError::IO(format!("Failed to read Excel workbook: {e}")))?;
+        let validations = read_list_validations(Cursor::new(bytes));
+        Self::new(PathBuf::new()).import_workbook(&mut workbook, &validations)
+    }
 
+    fn import_workbook<RS: Read + Seek>(
+        &self,
+        workbook: &mut Xlsx<RS>,
+        validations: &HashMap<String, Vec<ListValidation>>,
+    ) -> ForgeResult<ParsedModel> {
         // Create model
         let mut model = ParsedModel::new();
 
-        // Get all sheet names
-        let sheet_names = workbook.sheet_names();
+        // Get all sheet names, keeping only the selected ones
+        let mut sheet_names = workbook.sheet_names();
+        if !self.sheets.is_empty() {
+            let missing: Vec<&str> = self
+                .sheets
+                .iter()
+                .filter(|name| !sheet_names.contains(name))
+                .map(String::as_str)
+                .collect();
+            if !missing.is_empty() {
+                return Err(ForgeError::Import(format!(
+                    "Sheet(s) not found: {}. Available sheets: {}",
+                    missing.join(", "),
+                    sheet_names.join(", ")
+                )));
+            }
+            sheet_names.retain(|name| self.sheets.contains(name));
+        }
 
         // Process each sheet
         for sheet_name in sheet_names {
             if let Ok(range) = workbook.worksheet_range(&sheet_name) {
-                Self::process_sheet(&sheet_name, &range, &mut workbook, &mut model)?;
+                let range = match self.cell_range {
+                    Some((start, end)) => Self::sub_range(&range, start, end),
+                    None => range,
+                };
+                let lists = validations.get(&sheet_name).map_or(&[][..], Vec::as_slice);
+                self.process_sheet(&sheet_name, &range, lists, workbook, &mut model)?;
             }
         }
 
@@ -47,10 +167,12 @@ impl ExcelImporter {
     }
 
     /// Process a single worksheet
-    fn process_sheet(
+    fn process_sheet<RS: Read + Seek>(
+        &self,
         sheet_name: &str,
         range: &Range<Data>,
-        workbook: &mut Xlsx<std::io::BufReader<std::fs::File>>,
+        lists: &[ListValidation],
+        workbook: &mut Xlsx<RS>,
         model: &mut ParsedModel,
     ) -> ForgeResult<()> {
         // Check if sheet is empty
@@ -58,24 +180,96 @@ impl ExcelImporter {
             return Ok(()); // Skip empty sheets
         }
 
+        // The exporter's Summary sheet only repeats what the other sheets hold
+        if matches!(range.get((0, 0)), Some(Data::String(s)) if s == SUMMARY_TITLE) {
+            return Ok(());
+        }
+
         // Check if this is a "Scalars" sheet (special handling)
         if sheet_name.to_lowercase() == "scalars" {
-            Self::process_scalars_sheet(range, model);
+            Self::process_scalars_sheet(range, None, model);
+            return Ok(());
+        }
+
+        // Grouped scalars are exported one sheet per prefix with a Name/Value header
+        if Self::is_scalar_group_sheet(range) {
+            Self::process_scalars_sheet(range, Some(sheet_name), model);
             return Ok(());
         }
 
-        // Get formula range for this sheet
-        let formula_range = workbook.worksheet_formula(sheet_name).ok();
+        // Get formula range for this sheet, aligned with the data range so the
+        // same relative (row, col) addresses both
+        let formula_range = workbook.worksheet_formula(sheet_name).ok().map(|formulas| {
+            match (range.start(), range.end()) {
+                (Some(start), Some(end)) => Self::sub_range(&formulas, start, end),
+                _ => formulas,
+            }
+        });
+
+        // Dropdown lists, with range-based lists read from the workbook
+        let dropdowns: Vec<Dropdown> = lists
+            .iter()
+            .map(|list| Dropdown {
+                first_col: list.first_col,
+                last_col: list.last_col,
+                values: Self::list_values(&list.source, range, workbook),
+            })
+            .collect();
 
         // Process as regular table
-        Self::process_table_sheet(sheet_name, range, formula_range.as_ref(), model)
+        self.process_table_sheet(sheet_name, range, formula_range.as_ref(), &dropdowns, model)
+    }
+
+    /// The cells of `range` between `start` and `end` (absolute, inclusive)
+    fn sub_range<T: CellType>(range: &Range<T>, start: (u32, u32), end: (u32, u32)) -> Range<T> {
+        // calamine cannot slice an empty range
+        if range.is_empty() {
+            Range::empty()
+        } else {
+            range.range(start, end)
+        }
+    }
+
+    /// Allowed values of a dropdown; `range` is the validated sheet's own data
+    fn list_values<RS: Read + Seek>(
+        source: &ListSource,
+        range: &Range<Data>,
+        workbook: &mut Xlsx<RS>,
+    ) -> Vec<String> {
+        let (sheet, start, end) = match source {
+            ListSource::Values(values) => return values.clone(),
+            ListSource::Range { sheet, start, end } => (sheet, *start, *end),
+        };
+        let other;
+        let cells = match sheet {
+            Some(name) => match workbook.worksheet_range(name) {
+                Ok(sheet_range) => {
+                    other = sheet_range;
+                    &other
+                },
+                Err(_) => return Vec::new(),
+            },
+            None => range,
+        };
+        let mut values = Vec::new();
+        for row in start.0..=end.0 {
+            for col in start.1..=end.1 {
+                match cells.get_value((row, col)) {
+                    None | Some(Data::Empty) => {},
+                    Some(cell) => values.push(cell.to_string()),
+                }
+            }
+        }
+        values
     }
 
     /// Process a regular table sheet
     fn process_table_sheet(
+        &self,
         sheet_name: &str,
         range: &Range<Data>,
         formula_range: Option<&Range<String>>,
+        dropdowns: &[Dropdown],
         model: &mut ParsedModel,
     ) -> ForgeResult<()> {
         let (height, width) = range.get_size();
@@ -121,12 +315,13 @@ impl ExcelImporter {
 
         // Create table
         let table_name = Self::sanitize_table_name(sheet_name);
-        let mut table = Table::new(table_name);
+        let mut table = Table::new(table_name.clone());
 
         // Build column map for formula translation (A → revenue, B → cogs, etc.)
+        let first_col = range.start().map_or(0, |(_, c)| c as usize);
         let mut column_map = HashMap::new();
         for (idx, col_name) in column_names.iter().enumerate() {
-            let excel_col = Self::number_to_column_letter(idx);
+            let excel_col = Self::number_to_column_letter(first_col + idx);
             column_map.insert(excel_col, col_name.clone());
         }
 
@@ -168,16 +363,45 @@ impl ExcelImporter {
             if data.iter().all(|cell| matches!(cell, Data::Empty)) {
                 continue;
             }
-            let column_value = Self::convert_to_column_value(data)?;
-            table.add_column(Column::new(col_name.clone(), column_value));
+            let forced = self
+                .column_types
+                .get(&format!("{table_name}.{col_name}"))
+                .or_else(|| self.column_types.get(col_name));
+            let column_value = match forced {
+                Some(&column_type) => Self::coerce_column(col_name, data, column_type)?,
+                None => Self::convert_to_column_value(data)?,
+            };
+            // Dropdowns are kept as a note until models support allowed values
+            #[allow(clippy::cast_possible_truncation)] // Excel has at most 16,384 columns
+            let sheet_col = range.start().map_or(0, |(_, c)| c) + col_idx as u32;
+            let metadata = Metadata {
+                notes: dropdowns
+                    .iter()
+                    .find(|d| (d.first_col..=d.last_col).contains(&sheet_col))
+                    .filter(|d| !d.values.is_empty())
+                    .map(|d| format!("Allowed values: {}", d.values.join(", "))),
+                ..Metadata::default()
+            };
+            table.add_column(Column::with_metadata(
+                col_name.clone(),
+                column_value,
+                metadata,
+            ));
         }
 
         model.add_table(table);
         Ok(())
     }
 
-    /// Process the "Scalars" sheet (if present)
-    fn process_scalars_sheet(range: &Range<Data>, model: &mut ParsedModel) {
+    /// Whether a sheet holds one scalar group, as written by the exporter
+    fn is_scalar_group_sheet(range: &Range<Data>) -> bool {
+        range.width() == 2
+            && matches!(range.get((0, 0)), Some(Data::String(s)) if s == "Name")
+            && matches!(range.get((0, 1)), Some(Data::String(s)) if s == "Value")
+    }
+
+    /// Process a scalars sheet; names are prefixed with `group` (e.g. "inputs.rate")
+    fn process_scalars_sheet(range: &Range<Data>, group: Option<&str>, model: &mut ParsedModel) {
         let (height, _width) = range.get_size();
 
         // Skip header row, process data rows
@@ -186,10 +410,10 @@ impl ExcelImporter {
             // Column 1: Value
             // Column 2: Formula (optional)
 
-            let name = if let Some(cell) = range.get((row, 0)) {
-                cell.to_string()
-            } else {
-                continue; // Skip row without name
+            let name = match (range.get((row, 0)), group) {
+                (Some(cell), Some(group)) => format!("{group}.{cell}"),
+                (Some(cell), None) => cell.to_string(),
+                (None, _) => continue, // Skip row without name
             };
 
             #[allow(clippy::cast_precision_loss)] // Excel integer values fit within f64 precision
@@ -210,6 +434,79 @@ impl ExcelImporter {
         }
     }
 
+    /// Convert a column to a forced type; cells that cannot be converted are an error
+    fn coerce_column(
+        name: &str,
+        data: &[Data],
+        column_type: ColumnType,
+    ) -> ForgeResult<ColumnValue> {
+        let invalid = |row: usize, cell: &Data| {
+            ForgeError::Import(format!(
+                "Column '{name}' row {}: cannot convert '{cell}' to {column_type:?}",
+                row + 2 // 1-indexed, after the header row
+            ))
+        };
+        let cells = data.iter().enumerate();
+        Ok(match column_type {
+            ColumnType::Text => ColumnValue::Text(data.iter().map(ToString::to_string).collect()),
+            ColumnType::Number => ColumnValue::Number(
+                cells
+                    .map(|(row, cell)| match cell {
+                        Data::Empty => Ok(0.0),
+                        Data::String(s) => s.trim().parse().map_err(|_| invalid(row, cell)),
+                        other => Self::cell_number(other).ok_or_else(|| invalid(row, cell)),
+                    })
+                    .collect::<ForgeResult<_>>()?,
+            ),
+            ColumnType::Date => ColumnValue::Date(
+                cells
+                    .map(|(row, cell)| match cell {
+                        Data::Empty => Ok(String::new()),
+                        Data::String(s) | Data::DateTimeIso(s) => {
+                            Ok(s.trim().chars().take(10).collect())
+                        },
+                        other => Self::cell_number(other)
+                            .and_then(Self::serial_to_date)
+                            .ok_or_else(|| invalid(row, cell)),
+                    })
+                    .collect::<ForgeResult<_>>()?,
+            ),
+            ColumnType::Boolean => ColumnValue::Boolean(
+                cells
+                    .map(|(row, cell)| match cell {
+                        Data::Empty => Ok(false),
+                        Data::Bool(b) => Ok(*b),
+                        Data::String(s) => match s.trim().to_lowercase().as_str() {
+                            "true" | "yes" | "1" => Ok(true),
+                            "false" | "no" | "0" => Ok(false),
+                            _ => Err(invalid(row, cell)),
+                        },
+                        other => Self::cell_number(other)
+                            .map(|n| n != 0.0)
+                            .ok_or_else(|| invalid(row, cell)),
+                    })
+                    .collect::<ForgeResult<_>>()?,
+            ),
+        })
+    }
+
+    /// Numeric value of a cell; dates give their Excel serial number
+    fn cell_number(cell: &Data) -> Option<f64> {
+        match cell {
+            Data::DateTime(dt) => Some(dt.as_f64()),
+            other => other.as_f64(),
+        }
+    }
+
+    /// Excel serial number (days since 1899-12-30) to `YYYY-MM-DD`
+    fn serial_to_date(serial: f64) -> Option<String> {
+        #[allow(clippy::cast_possible_truncation)] // dates are far inside i64 range
+        let days = chrono::Duration::try_days(serial.floor() as i64)?;
+        let base = chrono::NaiveDate::from_ymd_opt(1899, 12, 30)?;
+        base.checked_add_signed(days)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+    }
+
     /// Convert Excel Data array to `ColumnValue`
     fn convert_to_column_value(data: &[Data]) -> ForgeResult<ColumnValue> {
         // Detect column type from first non-empty cell
@@ -378,6 +675,178 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_import_forced_text_keeps_codes() {
+        use rust_xlsxwriter::Workbook;
+
+        // Account codes: a numeric first cell makes the column infer as Number,
+        // which turns "00042" into 0
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet().set_name("accounts").unwrap();
+        sheet.write_string(0, 0, "code").unwrap();
+        sheet.write_string(0, 1, "balance").unwrap();
+        sheet.write_number(1, 0, 1001).unwrap();
+        sheet.write_number(1, 1, 10.5).unwrap();
+        sheet.write_string(2, 0, "00042").unwrap();
+        sheet.write_number(2, 1, 20.0).unwrap();
+        let bytes = workbook.save_to_buffer().unwrap();
+
+        let inferred = ExcelImporter::import_bytes(&bytes).unwrap();
+        assert_eq!(
+            inferred.tables["accounts"].columns["code"].values,
+            ColumnValue::Number(vec![1001.0, 0.0])
+        );
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("accounts.xlsx");
+        std::fs::write(&path, &bytes).unwrap();
+        let types = HashMap::from([("accounts.code".to_string(), ColumnType::Text)]);
+        let model = ExcelImporter::new(&path)
+            .with_column_types(types)
+            .import()
+            .unwrap();
+        let table = &model.tables["accounts"];
+        assert_eq!(
+            table.columns["code"].values,
+            ColumnValue::Text(vec!["1001".to_string(), "00042".to_string()])
+        );
+        assert_eq!(
+            table.columns["balance"].values,
+            ColumnValue::Number(vec![10.5, 20.0])
+        );
+    }
+
+    #[test]
+    fn test_import_dropdowns_as_allowed_values() {
+        use rust_xlsxwriter::{DataValidation, Formula, Workbook};
+
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet().set_name("projects").unwrap();
+        sheet.write_string(0, 0, "name").unwrap();
+        sheet.write_string(0, 1, "priority").unwrap();
+        sheet.write_string(0, 2, "region").unwrap();
+        sheet.write_string(1, 0, "Apollo").unwrap();
+        sheet.write_string(1, 1, "High").unwrap();
+        sheet.write_string(1, 2, "EMEA").unwrap();
+        let priority = DataValidation::new()
+            .allow_list_strings(&["Low", "Medium", "High"])
+            .unwrap();
+        sheet.add_data_validation(1, 1, 100, 1, &priority).unwrap();
+        let region = DataValidation::new().allow_list_formula(Formula::new("=Lists!$A$1:$A$2"));
+        sheet.add_data_validation(1, 2, 100, 2, &region).unwrap();
+
+        let lists = workbook.add_worksheet().set_name("Lists").unwrap();
+        lists.write_string(0, 0, "EMEA").unwrap();
+        lists.write_string(1, 0, "APAC").unwrap();
+        let bytes = workbook.save_to_buffer().unwrap();
+
+        let model = ExcelImporter::import_bytes(&bytes).unwrap();
+        let columns = &model.tables["projects"].columns;
+        assert_eq!(
+            columns["priority"].metadata.notes.as_deref(),
+            Some("Allowed values: Low, Medium, High")
+        );
+        assert_eq!(
+            columns["region"].metadata.notes.as_deref(),
+            Some("Allowed values: EMEA, APAC")
+        );
+        assert!(columns["name"].metadata.notes.is_none());
+    }
+
+    /// Workbook with a "notes" sheet and a "data" sheet whose table starts at B3
+    /// under a title row
+    fn offset_workbook(dir: &tempfile::TempDir) -> std::path::PathBuf {
+        use rust_xlsxwriter::{Formula, Workbook};
+
+        let mut workbook = Workbook::new();
+        let notes = workbook.add_worksheet().set_name("notes").unwrap();
+        notes.write_string(0, 0, "Prepared by finance").unwrap();
+        let data = workbook.add_worksheet().set_name("data").unwrap();
+        data.write_string(0, 0, "Quarterly revenue (CAD)").unwrap();
+        data.write_string(2, 1, "revenue").unwrap();
+        data.write_string(2, 2, "cost").unwrap();
+        data.write_string(2, 3, "profit").unwrap();
+        for (row, revenue, cost) in [(3, 100.0, 60.0), (4, 200.0, 90.0)] {
+            data.write_number(row, 1, revenue).unwrap();
+            data.write_number(row, 2, cost).unwrap();
+            data.write_formula(row, 3, Formula::new(format!("=B{0}-C{0}", row + 1)))
+                .unwrap();
+        }
+        let path = dir.path().join("offset.xlsx");
+        workbook.save(&path).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_import_selected_sheet_only() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = offset_workbook(&dir);
+
+        let model = ExcelImporter::new(&path)
+            .with_sheets(vec!["notes".to_string()])
+            .import()
+            .unwrap();
+        assert_eq!(model.tables.len(), 0);
+
+        let err = ExcelImporter::new(&path)
+            .with_sheets(vec!["data".to_string(), "summary".to_string()])
+            .import()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Sheet(s) not found: summary"), "{err}");
+        assert!(err.contains("Available sheets: notes, data"), "{err}");
+    }
+
+    #[test]
+    fn test_import_range_below_first_row() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = offset_workbook(&dir);
+
+        let model = ExcelImporter::new(&path)
+            .with_sheets(vec!["data".to_string()])
+            .with_range("B3:D5")
+            .unwrap()
+            .import()
+            .unwrap();
+        let table = &model.tables["data"];
+        assert_eq!(
+            table.columns["revenue"].values,
+            ColumnValue::Number(vec![100.0, 200.0])
+        );
+        assert_eq!(
+            table.columns["cost"].values,
+            ColumnValue::Number(vec![60.0, 90.0])
+        );
+        assert_eq!(table.row_formulas["profit"], "=revenue-cost");
+
+        assert!(ExcelImporter::new(&path).with_range("D5:B3").is_err());
+        assert!(ExcelImporter::new(&path).with_range("rows 3-5").is_err());
+    }
+
+    #[test]
+    fn test_coerce_column_to_date_and_number() {
+        let dates = ExcelImporter::coerce_column(
+            "posted",
+            &[Data::Float(45292.0), Data::String("2024-02-01".to_string())],
+            ColumnType::Date,
+        )
+        .unwrap();
+        assert_eq!(
+            dates,
+            ColumnValue::Date(vec!["2024-01-01".to_string(), "2024-02-01".to_string()])
+        );
+
+        let err = ExcelImporter::coerce_column(
+            "amount",
+            &[Data::Float(1.0), Data::String("n/a".to_string())],
+            ColumnType::Number,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Column 'amount' row 3"));
+        assert!("text".parse::<ColumnType>().is_ok());
+        assert!("money".parse::<ColumnType>().is_err());
+    }
+
     #[test]
     fn test_convert_to_column_value_empty() {
         let data = vec![Data::Empty, Data::Empty];
@@ -3,13 +3,17 @@
 //! This module provides bidirectional Excel ↔ YAML conversion:
 //! - Export: YAML → Excel (.xlsx) with formulas
 //! - Import: Excel (.xlsx) → YAML with formulas
+//! - Fidelity: YAML → Excel → YAML round-trip check for input values
 
 mod exporter;
+mod fidelity;
 mod formula_translator;
 mod importer;
 mod reverse_formula_translator;
+mod validations;
 
 pub use exporter::ExcelExporter;
+pub use fidelity::{check_round_trip, round_trip, Drift};
 pub use formula_translator::FormulaTranslator;
-pub use importer::ExcelImporter;
+pub use importer::{ColumnType, ExcelImporter};
 pub use reverse_formula_translator::ReverseFormulaTranslator;
@@ -235,6 +235,7 @@ impl ReverseFormulaTranslator {
                 | "MOD"
                 | "CEILING"
                 | "FLOOR"
+                | "CONVERT"
                 // Text functions
                 | "CONCATENATE"
                 | "CONCAT"
@@ -245,6 +246,10 @@ impl ReverseFormulaTranslator {
                 | "UPPER"
                 | "LOWER"
                 | "TRIM"
+                | "TEXTBEFORE"
+                | "TEXTAFTER"
+                | "EXACT"
+                | "PROPER"
                 // Date functions
                 | "TODAY"
                 | "NOW"
@@ -269,6 +274,8 @@ impl ReverseFormulaTranslator {
                 | "VLOOKUP"
                 | "HLOOKUP"
                 | "XLOOKUP"
+                | "XMATCH"
+                | "LOOKUP"
                 | "INDEX"
                 | "MATCH"
                 // Array functions (v4.1.0)
@@ -0,0 +1,217 @@
+//! Data-validation dropdowns (list validations) read from an .xlsx archive
+//!
+//! calamine does not expose data validations, so the sheet XML is read
+//! directly from the zip. Only `type="list"` rules are collected: either a
+//! literal list (`"Low,Medium,High"`) or a cell range (`Lists!$A$1:$A$3`) that
+//! the importer resolves against the workbook.
+
+use regex::Regex;
+use std::collections::HashMap;
+use std::io::{Read, Seek};
+
+/// Where a dropdown's allowed values come from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListSource {
+    /// Values written in the rule itself
+    Values(Vec<String>),
+    /// A cell range; `sheet` is `None` for the validated sheet itself
+    Range {
+        sheet: Option<String>,
+        start: (u32, u32),
+        end: (u32, u32),
+    },
+}
+
+/// A list validation covering a block of cells (0-indexed, inclusive)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListValidation {
+    pub first_row: u32,
+    pub last_row: u32,
+    pub first_col: u32,
+    pub last_col: u32,
+    pub source: ListSource,
+}
+
+/// Read the list validations of every sheet, keyed by sheet name.
+///
+/// Returns an empty map for archives that cannot be read; validations are
+/// decoration and never block an import.
+pub fn read_list_validations<R: Read + Seek>(reader: R) -> HashMap<String, Vec<ListValidation>> {
+    let Ok(mut archive) = zip::ZipArchive::new(reader) else {
+        return HashMap::new();
+    };
+    let mut part = |name: &str| {
+        let mut xml = String::new();
+        archive.by_name(name).ok()?.read_to_string(&mut xml).ok()?;
+        Some(xml)
+    };
+    let (Some(workbook), Some(rels)) =
+        (part("xl/workbook.xml"), part("xl/_rels/workbook.xml.rels"))
+    else {
+        return HashMap::new();
+    };
+
+    let relationship_tag = Regex::new(r"<Relationship\b[^>]*>").expect("valid regex");
+    let sheet_tag = Regex::new(r"<sheet\b[^>]*>").expect("valid regex");
+
+    let targets: HashMap<String, String> = relationship_tag
+        .find_iter(&rels)
+        .filter_map(|tag| Some((attr(tag.as_str(), "Id")?, attr(tag.as_str(), "Target")?)))
+        .collect();
+
+    let mut validations = HashMap::new();
+    for tag in sheet_tag.find_iter(&workbook) {
+        let (Some(name), Some(id)) = (attr(tag.as_str(), "name"), attr(tag.as_str(), "r:id"))
+        else {
+            continue;
+        };
+        let Some(target) = targets.get(&id) else {
+            continue;
+        };
+        let path = target
+            .strip_prefix('/')
+            .map_or_else(|| format!("xl/{target}"), ToString::to_string);
+        let Some(sheet_xml) = part(&path) else {
+            continue;
+        };
+        let lists = parse_sheet_validations(&sheet_xml);
+        if !lists.is_empty() {
+            validations.insert(name, lists);
+        }
+    }
+    validations
+}
+
+/// List validations in one worksheet's XML
+fn parse_sheet_validations(xml: &str) -> Vec<ListValidation> {
+    let validation =
+        Regex::new(r"(?s)<dataValidation\b([^>]*)>(.*?)</dataValidation>").expect("valid regex");
+    let formula1 = Regex::new(r"(?s)<formula1>(.*?)</formula1>").expect("valid regex");
+
+    let mut lists = Vec::new();
+    for rule in validation.captures_iter(xml) {
+        let attrs = &rule[1];
+        if attr(attrs, "type").as_deref() != Some("list") {
+            continue;
+        }
+        let (Some(sqref), Some(formula)) = (
+            attr(attrs, "sqref"),
+            formula1.captures(&rule[2]).map(|f| unescape(&f[1])),
+        ) else {
+            continue;
+        };
+        let Some(source) = parse_list_source(&formula) else {
+            continue;
+        };
+        for area in sqref.split_whitespace() {
+            if let Some((start, end)) = parse_area(area) {
+                lists.push(ListValidation {
+                    first_row: start.0,
+                    last_row: end.0,
+                    first_col: start.1,
+                    last_col: end.1,
+                    source: source.clone(),
+                });
+            }
+        }
+    }
+    lists
+}
+
+/// `"a,b,c"` → values; `Sheet!$A$1:$A$3` or `$A$1:$A$3` → range. Defined names
+/// and other formulas are not supported.
+fn parse_list_source(formula: &str) -> Option<ListSource> {
+    let formula = formula.trim().trim_start_matches('=');
+    if let Some(list) = formula.strip_prefix('"').and_then(|f| f.strip_suffix('"')) {
+        let values = list.split(',').map(|v| v.trim().to_string()).collect();
+        return Some(ListSource::Values(values));
+    }
+    let (sheet, area) = match formula.rsplit_once('!') {
+        Some((sheet, area)) => {
+            let sheet = sheet.trim_matches('\'').replace("''", "'");
+            (Some(sheet), area)
+        },
+        None => (None, formula),
+    };
+    let (start, end) = parse_area(area)?;
+    Some(ListSource::Range { sheet, start, end })
+}
+
+/// `B2:B50` or `B2` → 0-indexed ((row, col), (row, col))
+pub fn parse_area(area: &str) -> Option<((u32, u32), (u32, u32))> {
+    let (first, last) = area.split_once(':').unwrap_or((area, area));
+    Some((parse_cell(first)?, parse_cell(last)?))
+}
+
+/// `$B$2` → 0-indexed (row, col)
+fn parse_cell(cell: &str) -> Option<(u32, u32)> {
+    let cell = cell.trim().replace('$', "");
+    let split = cell.find(|c: char| c.is_ascii_digit())?;
+    let (letters, digits) = cell.split_at(split);
+    if letters.is_empty() || letters.len() > 3 || !letters.bytes().all(|b| b.is_ascii_uppercase()) {
+        return None;
+    }
+    let col = letters
+        .bytes()
+        .fold(0u32, |acc, b| acc * 26 + u32::from(b - b'A' + 1));
+    let row: u32 = digits.parse().ok()?;
+    Some((row.checked_sub(1)?, col - 1))
+}
+
+/// Value of `name="..."` in a tag's attribute text
+fn attr(tag: &str, name: &str) -> Option<String> {
+    let needle = format!(" {name}=\"");
+    let start = tag.find(&needle)? + needle.len();
+    let len = tag[start..].find('"')?;
+    Some(unescape(&tag[start..start + len]))
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list_sources() {
+        assert_eq!(
+            parse_list_source("\"Low, Medium,High\""),
+            Some(ListSource::Values(vec![
+                "Low".to_string(),
+                "Medium".to_string(),
+                "High".to_string()
+            ]))
+        );
+        assert_eq!(
+            parse_list_source("'My Lists'!$A$1:$A$3"),
+            Some(ListSource::Range {
+                sheet: Some("My Lists".to_string()),
+                start: (0, 0),
+                end: (2, 0),
+            })
+        );
+        assert_eq!(parse_list_source("Regions"), None);
+    }
+
+    #[test]
+    fn test_parse_sheet_validations_skips_non_lists() {
+        let xml = r#"<dataValidations count="2">
+            <dataValidation type="whole" sqref="A2:A9"><formula1>1</formula1></dataValidation>
+            <dataValidation type="list" allowBlank="1" sqref="B2:B9 D2"><formula1>"Yes,No"</formula1></dataValidation>
+        </dataValidations>"#;
+        let lists = parse_sheet_validations(xml);
+        assert_eq!(lists.len(), 2);
+        assert_eq!((lists[0].first_col, lists[0].last_row), (1, 8));
+        assert_eq!((lists[1].first_col, lists[1].first_row), (3, 1));
+        assert_eq!(
+            lists[1].source,
+            ListSource::Values(vec!["Yes".to_string(), "No".to_string()])
+        );
+    }
+}
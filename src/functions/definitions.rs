@@ -1,22 +1,24 @@
 //! Function definitions - THE SINGLE SOURCE OF TRUTH
 //!
-//! This file contains all 166 function definitions.
+//! This file contains every function definition; `registry_tests.rs` checks the totals.
 //! Separated from registry.rs to keep files under 1500 lines.
 
 use super::{Category, FunctionDef};
 
 /// All supported functions - THE SINGLE SOURCE OF TRUTH
 ///
-/// Total: 173 functions (includes aliases)
+/// Total: 233 functions (includes aliases)
 pub static FUNCTIONS: &[FunctionDef] = &[
     // ══════════════════════════════════════════════════════════════════════════
-    // MATH (16 demo + 3 enterprise = 19 total)
+    // MATH (16 demo + 15 enterprise = 31 total)
     // ══════════════════════════════════════════════════════════════════════════
     FunctionDef {
         name: "ABS",
         category: Category::Math,
         description: "Absolute value",
         syntax: "=ABS(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -25,6 +27,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Square root",
         syntax: "=SQRT(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -33,6 +37,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Round to decimals",
         syntax: "=ROUND(value, decimals)",
+        min_args: 1,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -41,6 +47,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Round up away from zero",
         syntax: "=ROUNDUP(value, decimals)",
+        min_args: 1,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -49,6 +57,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Round down toward zero",
         syntax: "=ROUNDDOWN(value, decimals)",
+        min_args: 1,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -57,6 +67,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Round down to multiple",
         syntax: "=FLOOR(value, significance)",
+        min_args: 1,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -65,14 +77,88 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Round up to multiple",
         syntax: "=CEILING(value, significance)",
+        min_args: 1,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
+    FunctionDef {
+        name: "FLOOR.MATH",
+        category: Category::Math,
+        description: "Round down to multiple, mode sets direction for negatives",
+        syntax: "=FLOOR.MATH(value, [significance], [mode])",
+        min_args: 1,
+        max_args: Some(3),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "CEILING.MATH",
+        category: Category::Math,
+        description: "Round up to multiple, mode sets direction for negatives",
+        syntax: "=CEILING.MATH(value, [significance], [mode])",
+        min_args: 1,
+        max_args: Some(3),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "GCD",
+        category: Category::Math,
+        description: "Greatest common divisor",
+        syntax: "=GCD(number1, number2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "LCM",
+        category: Category::Math,
+        description: "Least common multiple",
+        syntax: "=LCM(number1, number2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "FACT",
+        category: Category::Math,
+        description: "Factorial",
+        syntax: "=FACT(number)",
+        min_args: 1,
+        max_args: Some(1),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "COMBIN",
+        category: Category::Math,
+        description: "Number of combinations",
+        syntax: "=COMBIN(number, number_chosen)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "PERMUT",
+        category: Category::Math,
+        description: "Number of permutations",
+        syntax: "=PERMUT(number, number_chosen)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: true,
+    },
     FunctionDef {
         name: "MOD",
         category: Category::Math,
         description: "Remainder after division",
         syntax: "=MOD(number, divisor)",
+        min_args: 2,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -81,6 +167,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Number raised to power",
         syntax: "=POWER(base, exponent)",
+        min_args: 2,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -89,6 +177,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "e raised to power",
         syntax: "=EXP(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -97,6 +187,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Natural logarithm",
         syntax: "=LN(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -105,6 +197,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Base-10 logarithm",
         syntax: "=LOG10(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -113,6 +207,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Integer part",
         syntax: "=INT(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -121,6 +217,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Sign of number (-1, 0, 1)",
         syntax: "=SIGN(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -129,6 +227,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Truncate to decimals",
         syntax: "=TRUNC(value, decimals)",
+        min_args: 1,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -137,6 +237,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Alias for POWER",
         syntax: "=POW(base, exp)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -145,6 +247,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Pi constant",
         syntax: "=PI()",
+        min_args: 0,
+        max_args: Some(0),
         demo: true,
         scalar: true,
     },
@@ -153,6 +257,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Euler's number",
         syntax: "=E()",
+        min_args: 0,
+        max_args: Some(0),
         demo: false,
         scalar: true,
     },
@@ -161,6 +267,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Radians to degrees",
         syntax: "=DEGREES(radians)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -169,17 +277,61 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Math,
         description: "Degrees to radians",
         syntax: "=RADIANS(degrees)",
+        min_args: 1,
+        max_args: Some(1),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "CONVERT",
+        category: Category::Math,
+        description: "Convert between time, mass, length or scale/currency units (hr, kg, mi, kUSD)",
+        syntax: "=CONVERT(number, from_unit, to_unit)",
+        min_args: 3,
+        max_args: Some(3),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "LOG",
+        category: Category::Math,
+        description: "Base-10 logarithm",
+        syntax: "=LOG(value)",
+        min_args: 1,
+        max_args: Some(1),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "RAND",
+        category: Category::Math,
+        description: "Random number between 0 and 1",
+        syntax: "=RAND()",
+        min_args: 0,
+        max_args: Some(0),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "RANDBETWEEN",
+        category: Category::Math,
+        description: "Random integer between two values",
+        syntax: "=RANDBETWEEN(bottom, top)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
     // ══════════════════════════════════════════════════════════════════════════
-    // AGGREGATION (5 demo + 8 enterprise = 13 total)
+    // AGGREGATION (5 demo + 12 enterprise = 17 total)
     // ══════════════════════════════════════════════════════════════════════════
     FunctionDef {
         name: "SUM",
         category: Category::Aggregation,
         description: "Sum of values",
         syntax: "=SUM(value1, value2, ...)",
+        min_args: 0,
+        max_args: None,
         demo: true,
         scalar: true,
     },
@@ -188,6 +340,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Mean of values",
         syntax: "=AVERAGE(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: true,
         scalar: true,
     },
@@ -196,6 +350,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Alias for AVERAGE",
         syntax: "=AVG(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -204,6 +360,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Minimum value",
         syntax: "=MIN(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: true,
         scalar: true,
     },
@@ -212,6 +370,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Maximum value",
         syntax: "=MAX(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: true,
         scalar: true,
     },
@@ -220,6 +380,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Count of numbers",
         syntax: "=COUNT(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: true,
         scalar: true,
     },
@@ -228,6 +390,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Count non-empty",
         syntax: "=COUNTA(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -236,6 +400,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Count unique values",
         syntax: "=COUNTUNIQUE(value1, value2, ...)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -244,6 +410,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Product of values",
         syntax: "=PRODUCT(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -252,6 +420,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Nth largest value",
         syntax: "=LARGE(array, n)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: false,
     },
@@ -260,6 +430,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Nth smallest value",
         syntax: "=SMALL(array, n)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: false,
     },
@@ -268,6 +440,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Max with conditions",
         syntax: "=MAXIFS(max_range, criteria_range, criteria)",
+        min_args: 3,
+        max_args: None,
         demo: false,
         scalar: false,
     },
@@ -276,14 +450,48 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Aggregation,
         description: "Min with conditions",
         syntax: "=MINIFS(min_range, criteria_range, criteria)",
+        min_args: 3,
+        max_args: None,
         demo: false,
         scalar: false,
     },
+    FunctionDef {
+        name: "SUBTOTAL",
+        category: Category::Aggregation,
+        description: "Aggregate by function number, skipping nested subtotals",
+        syntax: "=SUBTOTAL(function_num, ref1, ...)",
+        min_args: 2,
+        max_args: None,
+        demo: false,
+        scalar: true,
+    },
     FunctionDef {
         name: "RANK.EQ",
         category: Category::Aggregation,
         description: "Rank of value",
         syntax: "=RANK.EQ(value, array, order)",
+        min_args: 2,
+        max_args: Some(3),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "RANK",
+        category: Category::Aggregation,
+        description: "Rank of value (legacy RANK.EQ)",
+        syntax: "=RANK(value, array, order)",
+        min_args: 2,
+        max_args: Some(3),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "RANK.AVG",
+        category: Category::Aggregation,
+        description: "Rank of value, ties averaged",
+        syntax: "=RANK.AVG(value, array, order)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: false,
     },
@@ -295,6 +503,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Logical,
         description: "Conditional value",
         syntax: "=IF(condition, true_value, false_value)",
+        min_args: 2,
+        max_args: Some(3),
         demo: true,
         scalar: true,
     },
@@ -303,6 +513,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Logical,
         description: "All conditions true",
         syntax: "=AND(condition1, condition2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: true,
         scalar: true,
     },
@@ -311,6 +523,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Logical,
         description: "Any condition true",
         syntax: "=OR(condition1, condition2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: true,
         scalar: true,
     },
@@ -319,6 +533,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Logical,
         description: "Negate condition",
         syntax: "=NOT(condition)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -327,6 +543,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Logical,
         description: "Handle errors",
         syntax: "=IFERROR(value, error_value)",
+        min_args: 2,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -335,6 +553,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Logical,
         description: "Exclusive or",
         syntax: "=XOR(condition1, condition2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -343,6 +563,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Logical,
         description: "Handle #N/A errors",
         syntax: "=IFNA(value, na_value)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -351,6 +573,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Logical,
         description: "Boolean TRUE",
         syntax: "=TRUE()",
+        min_args: 0,
+        max_args: Some(0),
         demo: false,
         scalar: true,
     },
@@ -359,17 +583,21 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Logical,
         description: "Boolean FALSE",
         syntax: "=FALSE()",
+        min_args: 0,
+        max_args: Some(0),
         demo: false,
         scalar: true,
     },
     // ══════════════════════════════════════════════════════════════════════════
-    // TEXT (8 demo + 7 enterprise = 15 total)
+    // TEXT (8 demo + 11 enterprise = 19 total)
     // ══════════════════════════════════════════════════════════════════════════
     FunctionDef {
         name: "CONCAT",
         category: Category::Text,
         description: "Join strings",
         syntax: "=CONCAT(text1, text2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: true,
         scalar: true,
     },
@@ -378,6 +606,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Alias for CONCAT",
         syntax: "=CONCATENATE(text1, text2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -386,6 +616,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Left characters",
         syntax: "=LEFT(text, num_chars)",
+        min_args: 1,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -394,6 +626,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Right characters",
         syntax: "=RIGHT(text, num_chars)",
+        min_args: 1,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -402,6 +636,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Middle characters",
         syntax: "=MID(text, start, num_chars)",
+        min_args: 3,
+        max_args: Some(3),
         demo: true,
         scalar: true,
     },
@@ -410,6 +646,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Text length",
         syntax: "=LEN(text)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -418,6 +656,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Uppercase text",
         syntax: "=UPPER(text)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -426,6 +666,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Lowercase text",
         syntax: "=LOWER(text)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -434,6 +676,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Remove extra spaces",
         syntax: "=TRIM(text)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -442,6 +686,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Repeat text",
         syntax: "=REPT(text, times)",
+        min_args: 2,
+        max_args: Some(2),
         demo: true,
         scalar: true,
     },
@@ -450,6 +696,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Format number as text",
         syntax: "=TEXT(value, format)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -458,6 +706,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Convert text to number",
         syntax: "=VALUE(text)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -466,6 +716,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Find text position",
         syntax: "=FIND(find_text, within_text, start)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -474,6 +726,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Find text (case insensitive)",
         syntax: "=SEARCH(find_text, within_text, start)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -482,6 +736,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Replace characters",
         syntax: "=REPLACE(text, start, num_chars, new_text)",
+        min_args: 4,
+        max_args: Some(4),
         demo: false,
         scalar: true,
     },
@@ -490,17 +746,61 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Text,
         description: "Substitute text",
         syntax: "=SUBSTITUTE(text, old_text, new_text, instance)",
+        min_args: 3,
+        max_args: Some(4),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "TEXTBEFORE",
+        category: Category::Text,
+        description: "Text before the nth delimiter (negative counts from the end)",
+        syntax: "=TEXTBEFORE(text, delimiter, [instance_num], [match_mode], [match_end], [if_not_found])",
+        min_args: 2,
+        max_args: Some(6),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "TEXTAFTER",
+        category: Category::Text,
+        description: "Text after the nth delimiter (negative counts from the end)",
+        syntax: "=TEXTAFTER(text, delimiter, [instance_num], [match_mode], [match_end], [if_not_found])",
+        min_args: 2,
+        max_args: Some(6),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "EXACT",
+        category: Category::Text,
+        description: "Case-sensitive text comparison",
+        syntax: "=EXACT(text1, text2)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "PROPER",
+        category: Category::Text,
+        description: "Capitalize the first letter of each word",
+        syntax: "=PROPER(text)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
     // ══════════════════════════════════════════════════════════════════════════
-    // DATE (6 demo + 15 enterprise = 21 total)
+    // DATE (6 demo + 19 enterprise = 25 total)
     // ══════════════════════════════════════════════════════════════════════════
     FunctionDef {
         name: "TODAY",
         category: Category::Date,
         description: "Current date",
         syntax: "=TODAY()",
+        min_args: 0,
+        max_args: Some(0),
         demo: true,
         scalar: true,
     },
@@ -509,6 +809,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Create date",
         syntax: "=DATE(year, month, day)",
+        min_args: 3,
+        max_args: Some(3),
         demo: true,
         scalar: true,
     },
@@ -517,6 +819,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Extract year",
         syntax: "=YEAR(date)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -525,6 +829,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Extract month",
         syntax: "=MONTH(date)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -533,6 +839,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Extract day",
         syntax: "=DAY(date)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -541,6 +849,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Date difference",
         syntax: "=DATEDIF(start, end, unit)",
+        min_args: 3,
+        max_args: Some(3),
         demo: true,
         scalar: true,
     },
@@ -549,6 +859,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Current date and time",
         syntax: "=NOW()",
+        min_args: 0,
+        max_args: Some(0),
         demo: false,
         scalar: true,
     },
@@ -557,6 +869,28 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Create time",
         syntax: "=TIME(hour, minute, second)",
+        min_args: 3,
+        max_args: Some(3),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "DATEVALUE",
+        category: Category::Date,
+        description: "Convert date text to a serial number",
+        syntax: "=DATEVALUE(date_text)",
+        min_args: 1,
+        max_args: Some(1),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "TIMEVALUE",
+        category: Category::Date,
+        description: "Convert time text to a fraction of a day",
+        syntax: "=TIMEVALUE(time_text)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -565,6 +899,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Extract hour",
         syntax: "=HOUR(time)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -573,6 +909,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Extract minute",
         syntax: "=MINUTE(time)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -581,6 +919,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Extract second",
         syntax: "=SECOND(time)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -589,6 +929,28 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Day of week",
         syntax: "=WEEKDAY(date, type)",
+        min_args: 1,
+        max_args: Some(2),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "WEEKNUM",
+        category: Category::Date,
+        description: "Week of the year",
+        syntax: "=WEEKNUM(date, [return_type])",
+        min_args: 1,
+        max_args: Some(2),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "ISOWEEKNUM",
+        category: Category::Date,
+        description: "ISO 8601 week of the year",
+        syntax: "=ISOWEEKNUM(date)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -597,6 +959,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Days between dates",
         syntax: "=DAYS(end_date, start_date)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -605,6 +969,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Add months to date",
         syntax: "=EDATE(date, months)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -613,6 +979,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "End of month",
         syntax: "=EOMONTH(date, months)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -621,6 +989,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Working days between",
         syntax: "=NETWORKDAYS(start, end, holidays)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -629,6 +999,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Add working days",
         syntax: "=WORKDAY(start, days, holidays)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -637,6 +1009,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Year fraction",
         syntax: "=YEARFRAC(start, end, basis)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -645,6 +1019,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Years between (shorthand)",
         syntax: "=Y(start, end)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -653,6 +1029,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Months between (shorthand)",
         syntax: "=M(start, end)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -661,6 +1039,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Date,
         description: "Days between (shorthand)",
         syntax: "=D(start, end)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -672,6 +1052,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Value by position",
         syntax: "=INDEX(array, row, col)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: false,
     },
@@ -680,6 +1062,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Find position",
         syntax: "=MATCH(lookup_value, array, type)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: false,
     },
@@ -688,6 +1072,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Pick by index",
         syntax: "=CHOOSE(index, value1, value2, ...)",
+        min_args: 2,
+        max_args: None,
         demo: true,
         scalar: true,
     },
@@ -696,6 +1082,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Vertical lookup",
         syntax: "=VLOOKUP(lookup, table, col, exact)",
+        min_args: 3,
+        max_args: Some(4),
         demo: false,
         scalar: false,
     },
@@ -704,6 +1092,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Horizontal lookup",
         syntax: "=HLOOKUP(lookup, table, row, exact)",
+        min_args: 3,
+        max_args: Some(4),
         demo: false,
         scalar: false,
     },
@@ -712,6 +1102,28 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Extended lookup",
         syntax: "=XLOOKUP(lookup, lookup_array, return_array, not_found)",
+        min_args: 3,
+        max_args: Some(6),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "XMATCH",
+        category: Category::Lookup,
+        description: "Extended position match",
+        syntax: "=XMATCH(lookup, lookup_array, match_mode, search_mode)",
+        min_args: 2,
+        max_args: Some(4),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "LOOKUP",
+        category: Category::Lookup,
+        description: "Approximate lookup in a sorted vector",
+        syntax: "=LOOKUP(lookup, lookup_vector, result_vector)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: false,
     },
@@ -720,6 +1132,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Reference offset",
         syntax: "=OFFSET(ref, rows, cols, height, width)",
+        min_args: 3,
+        max_args: Some(5),
         demo: false,
         scalar: false,
     },
@@ -728,6 +1142,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Reference from text",
         syntax: "=INDIRECT(ref_text)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: false,
     },
@@ -736,6 +1152,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Cell address text",
         syntax: "=ADDRESS(row, col, abs_type)",
+        min_args: 2,
+        max_args: Some(5),
         demo: false,
         scalar: true,
     },
@@ -744,6 +1162,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Row number",
         syntax: "=ROW(reference)",
+        min_args: 0,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -752,6 +1172,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Column number",
         syntax: "=COLUMN(reference)",
+        min_args: 0,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -760,6 +1182,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Number of rows",
         syntax: "=ROWS(array)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -768,17 +1192,21 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Lookup,
         description: "Number of columns",
         syntax: "=COLUMNS(array)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
     // ══════════════════════════════════════════════════════════════════════════
-    // FINANCIAL (0 demo + 20 enterprise = 20 total)
+    // FINANCIAL (0 demo + 24 enterprise = 24 total)
     // ══════════════════════════════════════════════════════════════════════════
     FunctionDef {
         name: "PMT",
         category: Category::Financial,
         description: "Loan payment",
         syntax: "=PMT(rate, nper, pv, fv, type)",
+        min_args: 3,
+        max_args: Some(5),
         demo: false,
         scalar: true,
     },
@@ -787,6 +1215,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Present value",
         syntax: "=PV(rate, nper, pmt, fv, type)",
+        min_args: 3,
+        max_args: Some(5),
         demo: false,
         scalar: true,
     },
@@ -795,6 +1225,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Future value",
         syntax: "=FV(rate, nper, pmt, pv, type)",
+        min_args: 3,
+        max_args: Some(5),
         demo: false,
         scalar: true,
     },
@@ -803,6 +1235,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Net present value",
         syntax: "=NPV(rate, value1, value2, ...)",
+        min_args: 2,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -811,6 +1245,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Internal rate of return",
         syntax: "=IRR(values, guess)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -819,6 +1255,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Modified IRR",
         syntax: "=MIRR(values, finance_rate, reinvest_rate)",
+        min_args: 3,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -827,6 +1265,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "NPV with dates",
         syntax: "=XNPV(rate, values, dates)",
+        min_args: 3,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -835,6 +1275,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "IRR with dates",
         syntax: "=XIRR(values, dates, guess)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -843,6 +1285,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Number of periods",
         syntax: "=NPER(rate, pmt, pv, fv, type)",
+        min_args: 3,
+        max_args: Some(5),
         demo: false,
         scalar: true,
     },
@@ -851,6 +1295,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Interest rate",
         syntax: "=RATE(nper, pmt, pv, fv, type, guess)",
+        min_args: 3,
+        max_args: Some(6),
         demo: false,
         scalar: true,
     },
@@ -859,6 +1305,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Declining balance depreciation",
         syntax: "=DB(cost, salvage, life, period, month)",
+        min_args: 4,
+        max_args: Some(5),
         demo: false,
         scalar: true,
     },
@@ -867,6 +1315,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Double declining balance",
         syntax: "=DDB(cost, salvage, life, period, factor)",
+        min_args: 4,
+        max_args: Some(5),
         demo: false,
         scalar: true,
     },
@@ -875,6 +1325,28 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Straight-line depreciation",
         syntax: "=SLN(cost, salvage, life)",
+        min_args: 3,
+        max_args: Some(3),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "SYD",
+        category: Category::Financial,
+        description: "Sum-of-years' digits depreciation",
+        syntax: "=SYD(cost, salvage, life, period)",
+        min_args: 4,
+        max_args: Some(4),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "VDB",
+        category: Category::Financial,
+        description: "Variable declining balance depreciation over a period range",
+        syntax: "=VDB(cost, salvage, life, start_period, end_period, [factor], [no_switch])",
+        min_args: 5,
+        max_args: Some(7),
         demo: false,
         scalar: true,
     },
@@ -883,6 +1355,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Interest payment",
         syntax: "=IPMT(rate, per, nper, pv, fv, type)",
+        min_args: 4,
+        max_args: Some(6),
         demo: false,
         scalar: true,
     },
@@ -891,6 +1365,28 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Principal payment",
         syntax: "=PPMT(rate, per, nper, pv, fv, type)",
+        min_args: 4,
+        max_args: Some(6),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "CUMIPMT",
+        category: Category::Financial,
+        description: "Cumulative interest paid between two periods",
+        syntax: "=CUMIPMT(rate, nper, pv, start_period, end_period, type)",
+        min_args: 6,
+        max_args: Some(6),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "CUMPRINC",
+        category: Category::Financial,
+        description: "Cumulative principal paid between two periods",
+        syntax: "=CUMPRINC(rate, nper, pv, start_period, end_period, type)",
+        min_args: 6,
+        max_args: Some(6),
         demo: false,
         scalar: true,
     },
@@ -899,6 +1395,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Accrued interest",
         syntax: "=ACCRINT(issue, first, settle, rate, par, freq)",
+        min_args: 6,
+        max_args: Some(7),
         demo: false,
         scalar: true,
     },
@@ -907,6 +1405,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Effective annual rate",
         syntax: "=EFFECT(nominal_rate, npery)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -915,6 +1415,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Nominal annual rate",
         syntax: "=NOMINAL(effect_rate, npery)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -923,6 +1425,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Price of discounted security",
         syntax: "=PRICEDISC(settle, mature, discount, redemption)",
+        min_args: 4,
+        max_args: Some(5),
         demo: false,
         scalar: true,
     },
@@ -931,17 +1435,21 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Financial,
         description: "Yield of discounted security",
         syntax: "=YIELDDISC(settle, mature, price, redemption)",
+        min_args: 4,
+        max_args: Some(5),
         demo: false,
         scalar: true,
     },
     // ══════════════════════════════════════════════════════════════════════════
-    // STATISTICAL (0 demo + 11 enterprise = 11 total)
+    // STATISTICAL (0 demo + 22 enterprise = 22 total)
     // ══════════════════════════════════════════════════════════════════════════
     FunctionDef {
         name: "MEDIAN",
         category: Category::Statistical,
         description: "Median value",
         syntax: "=MEDIAN(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -950,6 +1458,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Statistical,
         description: "Sample variance",
         syntax: "=VAR.S(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -958,6 +1468,28 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Statistical,
         description: "Population variance",
         syntax: "=VARP(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "VAR",
+        category: Category::Statistical,
+        description: "Sample variance (legacy VAR.S)",
+        syntax: "=VAR(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "VAR.P",
+        category: Category::Statistical,
+        description: "Population variance",
+        syntax: "=VAR.P(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -966,6 +1498,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Statistical,
         description: "Sample std deviation",
         syntax: "=STDEV.S(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -974,6 +1508,48 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Statistical,
         description: "Population std deviation",
         syntax: "=STDEVP(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "STDEV",
+        category: Category::Statistical,
+        description: "Sample std deviation (legacy STDEV.S)",
+        syntax: "=STDEV(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "STDEV.P",
+        category: Category::Statistical,
+        description: "Population std deviation",
+        syntax: "=STDEV.P(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "GEOMEAN",
+        category: Category::Statistical,
+        description: "Geometric mean of positive values",
+        syntax: "=GEOMEAN(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "HARMEAN",
+        category: Category::Statistical,
+        description: "Harmonic mean of positive values",
+        syntax: "=HARMEAN(value1, value2, ...)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -982,6 +1558,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Statistical,
         description: "Percentile value",
         syntax: "=PERCENTILE(array, k)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: false,
     },
@@ -990,6 +1568,48 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Statistical,
         description: "Quartile value",
         syntax: "=QUARTILE(array, quart)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "PERCENTILE.INC",
+        category: Category::Statistical,
+        description: "k-th percentile, k in [0, 1]",
+        syntax: "=PERCENTILE.INC(array, k)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "PERCENTILE.EXC",
+        category: Category::Statistical,
+        description: "k-th percentile, k exclusive of 0 and 1",
+        syntax: "=PERCENTILE.EXC(array, k)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "QUARTILE.INC",
+        category: Category::Statistical,
+        description: "Quartile, quart 0-4",
+        syntax: "=QUARTILE.INC(array, quart)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "QUARTILE.EXC",
+        category: Category::Statistical,
+        description: "Quartile, quart 1-3",
+        syntax: "=QUARTILE.EXC(array, quart)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: false,
     },
@@ -998,6 +1618,48 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Statistical,
         description: "Correlation coefficient",
         syntax: "=CORREL(array1, array2)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "SLOPE",
+        category: Category::Statistical,
+        description: "Slope of the least squares line",
+        syntax: "=SLOPE(known_y, known_x)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "INTERCEPT",
+        category: Category::Statistical,
+        description: "Intercept of the least squares line",
+        syntax: "=INTERCEPT(known_y, known_x)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "FORECAST.LINEAR",
+        category: Category::Statistical,
+        description: "Value on the least squares line at x",
+        syntax: "=FORECAST.LINEAR(x, known_y, known_x)",
+        min_args: 3,
+        max_args: Some(3),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "FORECAST",
+        category: Category::Statistical,
+        description: "Value on the least squares line at x (legacy)",
+        syntax: "=FORECAST(x, known_y, known_x)",
+        min_args: 3,
+        max_args: Some(3),
         demo: false,
         scalar: false,
     },
@@ -1009,6 +1671,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Trigonometric,
         description: "Sine",
         syntax: "=SIN(angle)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -1017,6 +1681,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Trigonometric,
         description: "Cosine",
         syntax: "=COS(angle)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -1025,6 +1691,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Trigonometric,
         description: "Tangent",
         syntax: "=TAN(angle)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -1033,6 +1701,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Trigonometric,
         description: "Arcsine",
         syntax: "=ASIN(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -1041,6 +1711,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Trigonometric,
         description: "Arccosine",
         syntax: "=ACOS(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -1049,6 +1721,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Trigonometric,
         description: "Arctangent",
         syntax: "=ATAN(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: true,
         scalar: true,
     },
@@ -1057,6 +1731,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Trigonometric,
         description: "Hyperbolic sine",
         syntax: "=SINH(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1065,6 +1741,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Trigonometric,
         description: "Hyperbolic cosine",
         syntax: "=COSH(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1073,17 +1751,21 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Trigonometric,
         description: "Hyperbolic tangent",
         syntax: "=TANH(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
     // ══════════════════════════════════════════════════════════════════════════
-    // INFORMATION (0 demo + 13 enterprise = 13 total)
+    // INFORMATION (0 demo + 14 enterprise = 14 total)
     // ══════════════════════════════════════════════════════════════════════════
     FunctionDef {
         name: "ISBLANK",
         category: Category::Information,
-        description: "Is cell empty",
+        description: "Is cell empty (null or empty text)",
         syntax: "=ISBLANK(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1092,6 +1774,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Is error value",
         syntax: "=ISERROR(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1100,6 +1784,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Is #N/A error",
         syntax: "=ISNA(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1108,6 +1794,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Is numeric",
         syntax: "=ISNUMBER(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1116,6 +1804,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Is text",
         syntax: "=ISTEXT(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1124,6 +1814,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Is boolean",
         syntax: "=ISLOGICAL(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1132,6 +1824,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Is even number",
         syntax: "=ISEVEN(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1140,6 +1834,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Is odd number",
         syntax: "=ISODD(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1148,6 +1844,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Is reference",
         syntax: "=ISREF(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1156,6 +1854,38 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Is formula",
         syntax: "=ISFORMULA(value)",
+        min_args: 1,
+        max_args: Some(1),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "FORMULATEXT",
+        category: Category::Information,
+        description: "Formula behind a scalar or column as text",
+        syntax: "=FORMULATEXT(reference)",
+        min_args: 1,
+        max_args: Some(1),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "EXPLAIN",
+        category: Category::Information,
+        description: "Pass-through that records a value for calculate --trace",
+        syntax: "=EXPLAIN(expr)",
+        min_args: 1,
+        max_args: Some(1),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "ASSERT",
+        category: Category::Information,
+        description: "Return a condition, recording a failure for --strict when it is false",
+        syntax: "=ASSERT(condition, [message])",
+        min_args: 1,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1164,6 +1894,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Return #N/A",
         syntax: "=NA()",
+        min_args: 0,
+        max_args: Some(0),
         demo: false,
         scalar: true,
     },
@@ -1172,6 +1904,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Type of value",
         syntax: "=TYPE(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1180,6 +1914,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Information,
         description: "Convert to number",
         syntax: "=N(value)",
+        min_args: 1,
+        max_args: Some(1),
         demo: false,
         scalar: true,
     },
@@ -1191,14 +1927,18 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Conditional,
         description: "Multiple conditions",
         syntax: "=IFS(cond1, val1, cond2, val2, ...)",
+        min_args: 2,
+        max_args: None,
         demo: false,
         scalar: true,
     },
     FunctionDef {
         name: "SWITCH",
         category: Category::Conditional,
-        description: "Switch case",
-        syntax: "=SWITCH(expr, case1, val1, case2, val2, ...)",
+        description: "Result for the first value matching expr, or a default",
+        syntax: "=SWITCH(expr, value1, result1, [value2, result2], ..., [default])",
+        min_args: 2,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -1207,6 +1947,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Conditional,
         description: "Sum with condition",
         syntax: "=SUMIF(range, criteria, sum_range)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: false,
     },
@@ -1215,6 +1957,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Conditional,
         description: "Sum with conditions",
         syntax: "=SUMIFS(sum_range, range1, crit1, ...)",
+        min_args: 3,
+        max_args: None,
         demo: false,
         scalar: false,
     },
@@ -1223,6 +1967,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Conditional,
         description: "Count with condition",
         syntax: "=COUNTIF(range, criteria)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: false,
     },
@@ -1231,6 +1977,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Conditional,
         description: "Count with conditions",
         syntax: "=COUNTIFS(range1, crit1, range2, crit2, ...)",
+        min_args: 2,
+        max_args: None,
         demo: false,
         scalar: false,
     },
@@ -1239,6 +1987,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Conditional,
         description: "Average with condition",
         syntax: "=AVERAGEIF(range, criteria, avg_range)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: false,
     },
@@ -1247,17 +1997,21 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Conditional,
         description: "Average with conditions",
         syntax: "=AVERAGEIFS(avg_range, range1, crit1, ...)",
+        min_args: 3,
+        max_args: None,
         demo: false,
         scalar: false,
     },
     // ══════════════════════════════════════════════════════════════════════════
-    // ARRAY (0 demo + 5 enterprise = 5 total)
+    // ARRAY (0 demo + 16 enterprise = 16 total)
     // ══════════════════════════════════════════════════════════════════════════
     FunctionDef {
         name: "UNIQUE",
         category: Category::Array,
         description: "Unique values",
-        syntax: "=UNIQUE(array)",
+        syntax: "=UNIQUE(array, [by_col], [exactly_once])",
+        min_args: 1,
+        max_args: Some(3),
         demo: false,
         scalar: false,
     },
@@ -1266,6 +2020,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Array,
         description: "Filter array",
         syntax: "=FILTER(array, include, if_empty)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: false,
     },
@@ -1274,6 +2030,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Array,
         description: "Sort array",
         syntax: "=SORT(array, sort_index, order)",
+        min_args: 1,
+        max_args: Some(2),
         demo: false,
         scalar: false,
     },
@@ -1282,6 +2040,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Array,
         description: "Generate sequence",
         syntax: "=SEQUENCE(rows, cols, start, step)",
+        min_args: 1,
+        max_args: Some(4),
         demo: false,
         scalar: false,
     },
@@ -1290,6 +2050,118 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Array,
         description: "Random array",
         syntax: "=RANDARRAY(rows, cols, min, max)",
+        min_args: 0,
+        max_args: Some(5),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "CHOOSECOLS",
+        category: Category::Array,
+        description: "Select columns of a range by index",
+        syntax: "=CHOOSECOLS(range, col1, col2, ...)",
+        min_args: 2,
+        max_args: None,
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "CHOOSEROWS",
+        category: Category::Array,
+        description: "Select rows of a range by index",
+        syntax: "=CHOOSEROWS(range, row1, row2, ...)",
+        min_args: 2,
+        max_args: None,
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "HSTACK",
+        category: Category::Array,
+        description: "Combine arrays side by side as columns",
+        syntax: "=HSTACK(array1, array2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "VSTACK",
+        category: Category::Array,
+        description: "Append arrays end-to-end",
+        syntax: "=VSTACK(array1, array2, ...)",
+        min_args: 1,
+        max_args: None,
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "TAKE",
+        category: Category::Array,
+        description: "First n rows, or last n if negative",
+        syntax: "=TAKE(array, n)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "DROP",
+        category: Category::Array,
+        description: "Remove first n rows, or last n if negative",
+        syntax: "=DROP(array, n)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "WRAPROWS",
+        category: Category::Array,
+        description: "Reshape a 1D array into rows of a given width",
+        syntax: "=WRAPROWS(array, width, pad_with)",
+        min_args: 2,
+        max_args: Some(3),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "WRAPCOLS",
+        category: Category::Array,
+        description: "Reshape a 1D array into columns of a given height",
+        syntax: "=WRAPCOLS(array, height, pad_with)",
+        min_args: 2,
+        max_args: Some(3),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "MAP",
+        category: Category::Array,
+        description: "Apply a LAMBDA to each element",
+        syntax: "=MAP(array, LAMBDA(x, expr))",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "REDUCE",
+        category: Category::Array,
+        description: "Fold an array with a LAMBDA",
+        syntax: "=REDUCE(initial, array, LAMBDA(acc, x, expr))",
+        min_args: 3,
+        max_args: Some(3),
+        demo: false,
+        scalar: false,
+    },
+    FunctionDef {
+        name: "SCAN",
+        category: Category::Array,
+        description: "Running REDUCE, returning each step",
+        syntax: "=SCAN(initial, array, LAMBDA(acc, x, expr))",
+        min_args: 3,
+        max_args: Some(3),
         demo: false,
         scalar: false,
     },
@@ -1301,6 +2173,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Advanced,
         description: "Define variables",
         syntax: "=LET(name1, val1, name2, val2, ..., calc)",
+        min_args: 3,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -1309,6 +2183,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::Advanced,
         description: "Anonymous function",
         syntax: "=LAMBDA(param1, param2, ..., expression)",
+        min_args: 1,
+        max_args: None,
         demo: false,
         scalar: true,
     },
@@ -1316,7 +2192,9 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         name: "SCENARIO",
         category: Category::Advanced,
         description: "Scenario lookup",
-        syntax: "=SCENARIO(name)",
+        syntax: "=SCENARIO(scenario, variable)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1328,6 +2206,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::ForgeNative,
         description: "Actual vs budget variance",
         syntax: "=VARIANCE(actual, budget)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1336,6 +2216,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::ForgeNative,
         description: "Variance percentage",
         syntax: "=VARIANCE_PCT(actual, budget)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1344,6 +2226,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::ForgeNative,
         description: "Variance status",
         syntax: "=VARIANCE_STATUS(actual, budget)",
+        min_args: 2,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -1352,6 +2236,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::ForgeNative,
         description: "Break-even units",
         syntax: "=BREAKEVEN_UNITS(fixed, price, variable)",
+        min_args: 3,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -1360,6 +2246,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::ForgeNative,
         description: "Break-even revenue",
         syntax: "=BREAKEVEN_REVENUE(fixed, margin_pct)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1368,6 +2256,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::ForgeNative,
         description: "Years and days since",
         syntax: "=YD(start, end)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1376,6 +2266,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::ForgeNative,
         description: "Years and months since",
         syntax: "=YM(start, end)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1384,17 +2276,21 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::ForgeNative,
         description: "Months and days since",
         syntax: "=MD(start, end)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
     // ══════════════════════════════════════════════════════════════════════════
-    // MONTE CARLO (0 demo + 6 enterprise = 6 total)
+    // MONTE CARLO (0 demo + 10 enterprise = 10 total)
     // ══════════════════════════════════════════════════════════════════════════
     FunctionDef {
         name: "MC.Normal",
         category: Category::MonteCarlo,
         description: "Normal (Gaussian) distribution",
         syntax: "=MC.Normal(mean, std_dev)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1403,6 +2299,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::MonteCarlo,
         description: "Triangular distribution",
         syntax: "=MC.Triangular(min, mode, max)",
+        min_args: 3,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -1411,6 +2309,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::MonteCarlo,
         description: "Uniform distribution",
         syntax: "=MC.Uniform(min, max)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1419,6 +2319,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::MonteCarlo,
         description: "PERT distribution",
         syntax: "=MC.PERT(min, mode, max)",
+        min_args: 3,
+        max_args: Some(3),
         demo: false,
         scalar: true,
     },
@@ -1427,6 +2329,48 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::MonteCarlo,
         description: "Lognormal distribution",
         syntax: "=MC.Lognormal(mean, std_dev)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "MC.Beta",
+        category: Category::MonteCarlo,
+        description: "Beta distribution",
+        syntax: "=MC.Beta(alpha, beta)",
+        min_args: 2,
+        max_args: Some(2),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "MC.Exponential",
+        category: Category::MonteCarlo,
+        description: "Exponential distribution",
+        syntax: "=MC.Exponential(lambda)",
+        min_args: 1,
+        max_args: Some(1),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "MC.Poisson",
+        category: Category::MonteCarlo,
+        description: "Poisson distribution",
+        syntax: "=MC.Poisson(lambda)",
+        min_args: 1,
+        max_args: Some(1),
+        demo: false,
+        scalar: true,
+    },
+    FunctionDef {
+        name: "MC.Gamma",
+        category: Category::MonteCarlo,
+        description: "Gamma distribution",
+        syntax: "=MC.Gamma(shape, scale)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
@@ -1435,6 +2379,8 @@ pub static FUNCTIONS: &[FunctionDef] = &[
         category: Category::MonteCarlo,
         description: "Discrete distribution",
         syntax: "=MC.Discrete(values, probabilities)",
+        min_args: 2,
+        max_args: Some(2),
         demo: false,
         scalar: true,
     },
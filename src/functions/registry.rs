@@ -5,6 +5,7 @@
 //! - Category
 //! - Description
 //! - Syntax
+//! - Argument count bounds
 //! - Demo availability (demo: true = included in demo build)
 //! - Scalar compatibility (scalar: true = works with v1.0.0 schema, no tables needed)
 //!
@@ -66,6 +67,10 @@ pub struct FunctionDef {
     pub description: &'static str,
     /// Usage syntax (e.g., "=SUM(value1, value2, ...)")
     pub syntax: &'static str,
+    /// Minimum number of arguments
+    pub min_args: usize,
+    /// Maximum number of arguments (None = variadic)
+    pub max_args: Option<usize>,
     /// Available in demo build (false = enterprise only)
     pub demo: bool,
     /// Scalar compatible (true = works with v1.0.0 schema without tables/arrays)
@@ -74,6 +79,30 @@ pub struct FunctionDef {
     pub scalar: bool,
 }
 
+impl FunctionDef {
+    /// Check whether `count` arguments are within the function's bounds
+    #[must_use]
+    pub fn accepts_args(&self, count: usize) -> bool {
+        count >= self.min_args && self.max_args.is_none_or(|max| count <= max)
+    }
+
+    /// Human-readable argument count (e.g., "2", "2-3", "1+")
+    #[must_use]
+    pub fn arity(&self) -> String {
+        match self.max_args {
+            Some(max) if max == self.min_args => max.to_string(),
+            Some(max) => format!("{}-{max}", self.min_args),
+            None => format!("{}+", self.min_args),
+        }
+    }
+}
+
+/// Full function registry, in definition order
+#[must_use]
+pub fn all() -> &'static [FunctionDef] {
+    FUNCTIONS
+}
+
 /// Get demo functions only
 pub fn demo_functions() -> impl Iterator<Item = &'static FunctionDef> {
     FUNCTIONS.iter().filter(|f| f.demo)
@@ -14,11 +14,11 @@ mod tests {
 
     #[test]
     fn test_enterprise_count() {
-        // 173 total functions (includes aliases like AVG, CONCATENATE, 6 MC.* functions)
+        // 233 total functions (includes aliases like AVG, CONCATENATE, 10 MC.* functions)
         assert_eq!(
             count_enterprise(),
-            173,
-            "Enterprise should have 173 functions"
+            233,
+            "Enterprise should have 233 functions"
         );
     }
 
@@ -69,7 +69,7 @@ mod tests {
         let array_only = count_array_only();
         assert_eq!(
             scalar + array_only,
-            173,
+            233,
             "Scalar + array-only should equal total"
         );
         // 24 array-only functions:
@@ -77,7 +77,7 @@ mod tests {
         // Conditional (6): SUMIF, SUMIFS, COUNTIF, COUNTIFS, AVERAGEIF, AVERAGEIFS
         // Aggregation (5): MAXIFS, MINIFS, RANK.EQ, LARGE, SMALL
         // Statistical (3): PERCENTILE, QUARTILE, CORREL
-        // Lookup (5): INDEX, MATCH, VLOOKUP, HLOOKUP, XLOOKUP, OFFSET, INDIRECT
+        // Lookup (5): INDEX, MATCH, VLOOKUP, HLOOKUP, XLOOKUP, XMATCH, LOOKUP, OFFSET, INDIRECT
         assert!(
             array_only >= 20,
             "Should have at least 20 array-only functions"
@@ -107,4 +107,33 @@ mod tests {
             assert!(!f.scalar, "{name} should be scalar=false");
         }
     }
+
+    #[test]
+    fn test_all_exposes_sum_metadata() {
+        let sum = crate::functions::all()
+            .iter()
+            .find(|f| f.name == "SUM")
+            .expect("SUM should be registered");
+        assert_eq!(sum.category, Category::Aggregation);
+        assert_eq!(sum.min_args, 0);
+        assert_eq!(sum.max_args, None);
+        assert_eq!(sum.description, "Sum of values");
+        assert_eq!(sum.arity(), "0+");
+        assert!(sum.accepts_args(5));
+    }
+
+    #[test]
+    fn test_arg_bounds_are_consistent() {
+        assert_eq!(all().len(), count_enterprise());
+        for f in all() {
+            assert!(
+                f.max_args.is_none_or(|max| max >= f.min_args),
+                "{} has max_args below min_args",
+                f.name
+            );
+        }
+        let round = find_function("ROUND").unwrap();
+        assert_eq!(round.arity(), "1-2");
+        assert!(!round.accepts_args(0) && !round.accepts_args(3));
+    }
 }
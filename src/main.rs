@@ -4,21 +4,28 @@
 use clap::{Parser, Subcommand};
 use mollendorff_forge::api::{run_api_server, server::ApiConfig};
 use mollendorff_forge::cli;
+use mollendorff_forge::core::OutputSelection;
 use mollendorff_forge::error::{ForgeError, ForgeResult};
 use mollendorff_forge::mcp::ForgeMcpServer;
+use mollendorff_forge::parser::{self, ParseLimits, DEFAULT_MAX_CELLS, DEFAULT_MAX_ROWS};
 use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "forge")]
-#[command(about = "Git-native financial modeling. 173 functions. Zero hallucinations.")]
+#[command(about = "Git-native financial modeling. 233 functions. Zero hallucinations.")]
 #[command(long_about = "Forge - Git-native financial modeling
-173 functions | Fully tested | E2E: forge-e2e repo
+233 functions | Fully tested | E2E: forge-e2e repo
 
 COMMANDS:
   calculate     - Execute formulas, update values
   validate      - Check model integrity
-  audit         - Trace formula dependencies (SOX compliance)
-  functions     - List all 173 supported functions
+  snapshot      - Compare outputs against a golden snapshot file
+  compile       - Check formula syntax and references
+  audit         - Trace formula dependencies (SOX compliance), or --dependents
+  graph         - Export the dependency graph (Graphviz DOT or Mermaid)
+  stats         - Profile numeric columns (count, mean, std, nulls)
+  sample        - Draw random rows from a table
+  functions     - List all 233 supported functions
   schema        - Display JSON schema for model validation
   examples      - Show runnable YAML examples
   simulate      - Monte Carlo simulation with distributions
@@ -31,12 +38,14 @@ COMMANDS:
   sensitivity   - One/two-variable data tables
   goal-seek     - Find input for target output
   break-even    - Find zero-crossing point
+  dcf           - Discounted cash flow schedule (NPV/XNPV)
   variance      - Budget vs actual analysis
   compare       - Multi-scenario comparison
   export        - YAML -> Excel (.xlsx) with formulas
   import        - Excel -> YAML
   watch         - Auto-calculate on save
   upgrade       - Upgrade YAML to latest schema
+  fmt           - Rewrite YAML in canonical style
   update        - Check for updates and self-update
   mcp           - Start MCP server for AI integration
   serve         - Start HTTP REST API server
@@ -56,6 +65,26 @@ Docs: https://mollendorff.ai/forge")]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Reject any table column with more rows than this while parsing
+    #[arg(long, global = true, value_name = "N", default_value_t = DEFAULT_MAX_ROWS)]
+    max_rows: usize,
+
+    /// Reject any table with more cells (rows x columns) than this while parsing
+    #[arg(long, global = true, value_name = "N", default_value_t = DEFAULT_MAX_CELLS)]
+    max_cells: usize,
+
+    /// Schema version for files without `_forge_version` (1.0.0, 5.0.0, or auto to detect)
+    #[arg(long, global = true, value_name = "VERSION", value_parser = ["auto", "1.0.0", "5.0.0"])]
+    assume_version: Option<String>,
+
+    /// Number separators and date order in printed output (en-US, en-GB, de-DE)
+    #[arg(long, global = true, value_name = "LOCALE", env = "FORGE_LOCALE")]
+    locale: Option<cli::Locale>,
+
+    /// Output symbols: emoji (default) or plain ASCII for limited terminals and logs
+    #[arg(long, global = true, value_name = "THEME", env = "FORGE_THEME", default_value_t = cli::Theme::Emoji)]
+    theme: cli::Theme,
 }
 
 #[derive(Subcommand)]
@@ -81,7 +110,8 @@ IMPORTANT: Calculate updates ALL files in the chain (Excel-style)!
   If pricing.yaml has stale formulas, they will be recalculated too.
   This ensures data integrity across all referenced files.
 
-Use --dry-run to preview changes without modifying files.")]
+Use --dry-run to preview changes without modifying files, or --output to
+write the results to a separate .yaml or .xlsx file.")]
     /// Calculate all formulas in a YAML file
     Calculate {
         /// Path to YAML file (can include other files via 'includes' section)
@@ -98,6 +128,50 @@ Use --dry-run to preview changes without modifying files.")]
         /// Scenario name to apply (uses variable overrides from 'scenarios' section)
         #[arg(short, long)]
         scenario: Option<String>,
+
+        /// Round written formula results to N decimals (calculation keeps full precision)
+        #[arg(long, value_name = "N")]
+        round: Option<u32>,
+
+        /// Write results to this file (.yaml or .xlsx) and leave the input untouched
+        #[arg(short, long, conflicts_with = "dry_run")]
+        output: Option<PathBuf>,
+
+        /// Fail on unit-incompatible arithmetic (e.g. USD + %) instead of warning
+        #[arg(long)]
+        strict_units: bool,
+
+        /// Record failing formulas and keep calculating, then report them all
+        #[arg(long)]
+        continue_on_error: bool,
+
+        /// Print the intermediate values captured by EXPLAIN(expr) calls
+        #[arg(long)]
+        trace: bool,
+
+        /// Fail (and write nothing) when any ASSERT(condition) is false
+        #[arg(long)]
+        strict: bool,
+
+        /// Compute only these outputs (scalars, table.column or table) and their prerequisites
+        #[arg(
+            long,
+            value_delimiter = ',',
+            value_name = "NAMES",
+            conflicts_with = "skip"
+        )]
+        only: Vec<String>,
+
+        /// Compute everything except these outputs and whatever depends on them
+        #[arg(long, value_delimiter = ',', value_name = "NAMES")]
+        skip: Vec<String>,
+
+        /// Print the result as a JSON envelope (for tooling)
+        #[arg(
+            long,
+            conflicts_with_all = ["verbose", "round", "output", "strict_units", "continue_on_error", "trace", "only", "skip"]
+        )]
+        json: bool,
     },
 
     /// Show audit trail for a specific variable
@@ -107,6 +181,31 @@ Use --dry-run to preview changes without modifying files.")]
 
         /// Variable name to audit
         variable: String,
+
+        /// Show what depends on the variable (impact analysis) instead of what it depends on
+        #[arg(long)]
+        dependents: bool,
+    },
+
+    #[command(long_about = "Export the model's formula dependency graph.
+
+Prints a node for every scalar and table column and an edge from each
+variable to the formulas that reference it. Columns are grouped by table.
+Row formulas reaching into another table are drawn as bold blue edges and
+references into included files (@alias.var) as dashed orange edges, which
+makes unintended couplings easy to spot before a refactor.
+
+EXAMPLES:
+  forge graph model.yaml | dot -Tsvg > model.svg   # Graphviz
+  forge graph model.yaml --format mermaid          # Mermaid flowchart")]
+    /// Export the formula dependency graph (DOT or Mermaid)
+    Graph {
+        /// Path to YAML model file
+        file: PathBuf,
+
+        /// Output format
+        #[arg(long, value_parser = ["dot", "mermaid"], default_value = "dot")]
+        format: String,
     },
 
     #[command(long_about = "Validate formulas without calculating.
@@ -130,12 +229,53 @@ NOTE: Validation checks ALL files in the chain.
 
 BATCH VALIDATION:
   forge validate file1.yaml file2.yaml file3.yaml
-  Validates multiple files in sequence, reporting all errors.")]
+  Validates multiple files in sequence, reporting all errors.
+
+EXPLAINING MISMATCHES:
+  forge validate model.yaml --explain
+  Shows each stale scalar's formula and the calculated input values
+  that produce the expected value.
+
+ASSERTIONS:
+  forge validate model.yaml --strict
+  Formulas may check invariants with ASSERT(condition, \"message\"). False
+  assertions are reported as warnings; --strict makes them fail validation.
+
+JSON OUTPUT:
+  forge validate model.yaml --json
+  Prints {command, ok, result, warnings, errors}; ok is false when any
+  file is stale or fails to parse.")]
     /// Validate formulas without calculating
     Validate {
         /// Path to YAML file(s) to validate
         #[arg(required = true)]
         files: Vec<PathBuf>,
+
+        /// For each mismatched scalar, show its formula and input values
+        #[arg(long)]
+        explain: bool,
+
+        /// Fail when any ASSERT(condition) is false instead of warning
+        #[arg(long)]
+        strict: bool,
+
+        /// Print the results as a JSON envelope (for tooling)
+        #[arg(long, conflicts_with_all = ["explain", "strict"])]
+        json: bool,
+    },
+
+    #[command(long_about = "Check every formula without calculating.
+
+Tokenizes and parses each table and scalar formula and resolves every
+reference and function name against the model. All problems are reported
+at once, so a typo in a rarely used formula is caught before calculation.
+
+EXAMPLE:
+  forge compile model.yaml")]
+    /// Check every formula for syntax errors and unknown references
+    Compile {
+        /// Path to YAML file
+        file: PathBuf,
     },
 
     #[command(long_about = "Export v1.0.0 array model to Excel .xlsx format.
@@ -152,6 +292,14 @@ SUPPORTED FEATURES (Phase 3.1 - Basic Export):
 EXAMPLE:
   forge export quarterly_pl.yaml quarterly_pl.xlsx
 
+SINGLE-TABLE EXPORT:
+  forge export model.yaml --table sales --markdown sales.md
+  forge export model.yaml --table sales --json-records sales.json
+  forge export model.yaml --table sales --parquet sales.parquet
+  Calculates the model and writes one table as a Markdown table, a JSON
+  array of row objects, or a typed Parquet file (requires the 'parquet'
+  build feature).
+
 NOTE: Only works with v1.0.0 array models. v0.2.0 scalar models are not supported.")]
     /// Export v1.0.0 array model to Excel .xlsx
     Export {
@@ -159,7 +307,28 @@ NOTE: Only works with v1.0.0 array models. v0.2.0 scalar models are not supporte
         input: PathBuf,
 
         /// Output Excel file path (.xlsx)
-        output: PathBuf,
+        #[arg(required_unless_present_any = ["markdown", "json_records", "parquet"])]
+        output: Option<PathBuf>,
+
+        /// Table to export (for single-table formats; optional if the model has one table)
+        #[arg(long)]
+        table: Option<String>,
+
+        /// Write the calculated table as a Markdown table to this file
+        #[arg(long, value_name = "FILE", conflicts_with = "output")]
+        markdown: Option<PathBuf>,
+
+        /// Write the calculated table as a JSON array of row objects to this file
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["output", "markdown"])]
+        json_records: Option<PathBuf>,
+
+        /// Write the calculated table as a Parquet file (requires the 'parquet' feature)
+        #[arg(
+            long,
+            value_name = "FILE",
+            conflicts_with_all = ["output", "markdown", "json_records"]
+        )]
+        parquet: Option<PathBuf>,
 
         /// Show verbose export steps
         #[arg(short, long)]
@@ -183,8 +352,15 @@ WORKFLOW:
   3. Export back to Excel
   4. Round-trip: Excel -> YAML -> Excel
 
-EXAMPLE:
+COLUMN TYPES:
+  Column types are inferred from the first non-empty cell. Use --types to
+  force a type (Number, Text, Date, Boolean), e.g. to keep account codes
+  like 00123 as text. Keys are a column name or table.column.
+
+EXAMPLES:
   forge import quarterly_pl.xlsx quarterly_pl.yaml
+  forge import ledger.xlsx ledger.yaml --types account=Text,posted=Date
+  forge import book.xlsx q3.yaml --sheets Revenue,Costs --range A3:F100
 
 NOTE: Formulas are preserved as Excel syntax (Phase 4.1).
       Formula translation to YAML syntax coming in Phase 4.3.")]
@@ -207,6 +383,18 @@ NOTE: Formulas are preserved as Excel syntax (Phase 4.1).
         /// Create multi-document YAML with --- separators (v4.4.2)
         #[arg(long)]
         multi_doc: bool,
+
+        /// Force column types instead of inferring them (e.g. account=Text,posted=Date)
+        #[arg(long, value_delimiter = ',', value_name = "COL=TYPE")]
+        types: Vec<String>,
+
+        /// Import only these sheets (comma-separated)
+        #[arg(long, value_delimiter = ',', value_name = "SHEETS")]
+        sheets: Vec<String>,
+
+        /// Cell range read from each sheet, header in its first row (e.g. A3:F100)
+        #[arg(long)]
+        range: Option<String>,
     },
 
     #[command(long_about = "Watch YAML files and auto-calculate on changes.
@@ -351,13 +539,16 @@ TWO-VARIABLE ANALYSIS:
       --range 0.01,0.15,0.02 --range2 0.05,0.15,0.05 --output npv
 
   Shows a matrix of NPV values for each combination of inputs.
+  Add --heatmap to color cells from lowest (red) to highest (green), or
+  --csv to write the matrix as CSV.
 
 RANGE FORMAT:
   start,end,step - e.g., 0.01,0.15,0.02 means 0.01, 0.03, 0.05, ..., 0.15
 
 EXAMPLES:
   forge sensitivity model.yaml -v growth_rate -r 0.05,0.20,0.05 -o profit
-  forge sensitivity model.yaml -v price -v2 volume -r 10,50,10 -r2 100,500,100 -o revenue")]
+  forge sensitivity model.yaml -v price -v2 volume -r 10,50,10 -r2 100,500,100 -o revenue
+  forge sensitivity model.yaml -v price --vary2 volume -r 10,50,10 --range2 100,500,100 -o revenue --csv > grid.csv")]
     /// Run sensitivity analysis on model variables
     Sensitivity {
         /// Path to YAML file
@@ -386,6 +577,18 @@ EXAMPLES:
         /// Show verbose output
         #[arg(long)]
         verbose: bool,
+
+        /// Color a two-variable table as a heatmap
+        #[arg(long, requires = "vary2")]
+        heatmap: bool,
+
+        /// Write a two-variable matrix as CSV
+        #[arg(long, requires = "vary2", conflicts_with_all = ["heatmap", "verbose"])]
+        csv: bool,
+
+        /// Print the result as a JSON envelope (for tooling)
+        #[arg(long, conflicts_with_all = ["heatmap", "csv", "verbose"])]
+        json: bool,
     },
 
     #[command(long_about = "Find the input value needed to achieve a target output.
@@ -400,15 +603,23 @@ EXAMPLES:
   forge goal-seek model.yaml --target npv --value 0 --vary discount_rate
   -> Find the discount rate that makes NPV = 0 (IRR)
 
+  forge goal-seek model.yaml --target summary.total_margin --value 0.2 --vary 'pricing.price[3]'
+  -> Find the price in row 3 of the pricing table that makes the total margin 20%
+
+  forge goal-seek model.yaml --target npv --value 1000000 --vary revenue_growth --scenario bull
+  -> Find the bull-case growth that makes expected NPV across scenarios = $1M
+
 OPTIONS:
   --min, --max: Override automatic bounds for the search
+  --scenario: Vary a scenario override; target is the expected value across scenarios
+  --method newton: Newton-Raphson iteration, far fewer steps on smooth targets like NPV
   --tolerance: Precision of the result (default: 0.0001)")]
     /// Find input value to achieve target output
     GoalSeek {
         /// Path to YAML file
         file: PathBuf,
 
-        /// Target variable to achieve
+        /// Target variable to achieve (a scalar, or a cell as table.column[row])
         #[arg(short, long)]
         target: String,
 
@@ -416,7 +627,7 @@ OPTIONS:
         #[arg(long)]
         value: f64,
 
-        /// Variable to adjust
+        /// Variable to adjust (a scalar, or a cell as table.column[row])
         #[arg(short, long)]
         vary: String,
 
@@ -432,6 +643,14 @@ OPTIONS:
         #[arg(long, default_value = "0.0001")]
         tolerance: f64,
 
+        /// Vary this scenario's override and target the expected value across scenarios
+        #[arg(long)]
+        scenario: Option<String>,
+
+        /// Solver: bisection, or newton (Newton-Raphson, falls back to bisection)
+        #[arg(long, value_parser = ["bisection", "newton"], default_value = "bisection")]
+        method: String,
+
         /// Show verbose output
         #[arg(long)]
         verbose: bool,
@@ -486,6 +705,10 @@ DISTRIBUTIONS:
   MC.PERT(min, mode, max)       - Smooth project estimates
   MC.Lognormal(mean, stdev)     - Non-negative values (prices, revenue)
   MC.Discrete(vals, probs)      - Custom scenarios with probabilities
+  MC.Beta(alpha, beta)          - Rates and proportions in [0, 1]
+  MC.Exponential(lambda)        - Waiting times between events
+  MC.Poisson(lambda)            - Event counts per period
+  MC.Gamma(shape, scale)        - Positive, right-skewed amounts
 
 YAML CONFIGURATION:
   monte_carlo:
@@ -565,10 +788,12 @@ OUTPUT:
   - Per-scenario results with all calculated outputs
   - Expected value (probability-weighted) for each output
   - Risk profile showing best/worst case outcomes
+  - Value-at-Risk (VaR) and Conditional VaR at --confidence
 
 EXAMPLES:
   forge scenarios model.yaml                    # Run all scenarios
   forge scenarios model.yaml --scenario bull    # Run specific scenario
+  forge scenarios model.yaml --confidence 0.90  # VaR/CVaR at 90%
   forge scenarios model.yaml -o results.yaml    # Export results"
     )]
     /// Run scenario analysis with probability weights
@@ -580,6 +805,10 @@ EXAMPLES:
         #[arg(short, long)]
         scenario: Option<String>,
 
+        /// Confidence level for VaR/CVaR (default: 0.95)
+        #[arg(long, default_value = "0.95")]
+        confidence: f64,
+
         /// Output file (.yaml)
         #[arg(short, long)]
         output: Option<PathBuf>,
@@ -627,10 +856,12 @@ OUTPUT:
   - Expected value at root
   - Decision policy (what to do at each decision node)
   - Risk profile (best/worst case)
+  - Expected value of perfect information (with --evpi)
 
 EXAMPLES:
   forge decision-tree model.yaml              # Analyze tree
   forge decision-tree model.yaml --dot        # Export DOT for Graphviz
+  forge decision-tree model.yaml --evpi tech_outcome  # Value of knowing the outcome
   forge decision-tree model.yaml -o out.yaml  # Export results")]
     /// Analyze decision trees with backward induction
     DecisionTree {
@@ -641,6 +872,10 @@ EXAMPLES:
         #[arg(long)]
         dot: bool,
 
+        /// Compute the expected value of perfect information for this chance node
+        #[arg(long, value_name = "CHANCE_NODE")]
+        evpi: Option<String>,
+
         /// Output file (.yaml or .dot)
         #[arg(short, long)]
         output: Option<PathBuf>,
@@ -733,6 +968,10 @@ YAML CONFIGURATION:
         low: 0.15
         high: 0.25
 
+WITHOUT A TORNADO SECTION:
+  Every input scalar (a value and no formula) is varied by ±10% around its
+  value. The output is --output-var, or the model's only formula scalar.
+
 OUTPUT:
   - Bars sorted by impact (largest first)
   - Base value reference
@@ -740,11 +979,11 @@ OUTPUT:
 
 EXAMPLES:
   forge tornado model.yaml                  # Generate diagram
-  forge tornado model.yaml --output npv     # Override output variable
+  forge tornado model.yaml --output-var npv # Override output variable
   forge tornado model.yaml -o results.yaml  # Export results")]
     /// Generate tornado sensitivity diagram
     Tornado {
-        /// Path to YAML file with tornado section
+        /// Path to YAML file (tornado section optional)
         file: PathBuf,
 
         /// Override output variable to analyze
@@ -772,11 +1011,13 @@ YAML CONFIGURATION:
     seed: 12345
     data: [0.05, -0.02, 0.08, 0.03, -0.05, 0.12]
     statistic: mean  # or median, std, var
+    method: iid      # or block, with block_size: 12 for time series
+    ci_type: percentile  # or bca (bias-corrected and accelerated)
 
 OUTPUT:
   - Original statistic value
   - Bootstrap mean and standard error
-  - Confidence intervals at each level
+  - Confidence intervals at each level (percentile or BCa)
   - Bias estimate
 
 EXAMPLES:
@@ -834,7 +1075,8 @@ YAML CONFIGURATION:
 EXAMPLES:
   forge bayesian model.yaml                           # Query all nodes
   forge bayesian model.yaml --query default_prob      # Query specific node
-  forge bayesian model.yaml -e economy=bad            # Set evidence")]
+  forge bayesian model.yaml -e economy=bad            # Set evidence
+  forge bayesian model.yaml --map -e default=high     # Most probable joint explanation")]
     /// Bayesian network inference
     Bayesian {
         /// Path to YAML file with `bayesian_network` section
@@ -848,6 +1090,10 @@ EXAMPLES:
         #[arg(short, long, value_delimiter = ',')]
         evidence: Vec<String>,
 
+        /// Most probable joint state of every unobserved node (MAP)
+        #[arg(long, conflicts_with = "query")]
+        map: bool,
+
         /// Output file (.yaml)
         #[arg(short, long)]
         output: Option<PathBuf>,
@@ -885,6 +1131,21 @@ EXAMPLES:
         json: bool,
     },
 
+    #[command(long_about = "Report the feature set compiled into this Forge binary.
+
+Shows the edition, the enterprise analysis modules, optional cargo features
+(e.g. parquet) and the number of registered functions.
+
+EXAMPLES:
+  forge features           # Human-readable report
+  forge features --json    # Output as JSON (for tooling)")]
+    /// Show which features and functions this build includes
+    Features {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     #[command(long_about = "Display JSON schema for validating Forge YAML models.
 
 Forge supports two schema versions:
@@ -951,6 +1212,8 @@ TRANSFORMATIONS:
     - Scalars with value only -> inputs section
     - Scalars with formula -> outputs section
   - Adds _name field for multi-document files
+  - Migrates legacy v0.2.0 scalar models (includes -> _includes,
+    formula-only variables get value: null)
   - Preserves all existing metadata
 
 RECURSIVE PROCESSING:
@@ -982,6 +1245,152 @@ BACKUP:
         verbose: bool,
     },
 
+    #[command(long_about = "Profile the numeric columns of a model.
+
+For each numeric column prints count, mean, median, standard deviation
+(sample), min, max and the number of missing values. Formula columns are
+calculated first, so they are profiled too. Useful for catching data-quality
+issues (outliers, gaps, constant columns) before modeling.
+
+EXAMPLES:
+  forge stats model.yaml                 # All tables
+  forge stats model.yaml --table sales   # One table
+  forge stats model.yaml --json          # Output as JSON (for tooling)")]
+    /// Profile numeric columns (count, mean, median, std, min, max, nulls)
+    Stats {
+        /// Path to YAML model file
+        file: PathBuf,
+
+        /// Only profile this table
+        #[arg(long)]
+        table: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    #[command(long_about = "Draw a random sample of rows from a table.
+
+Picks N rows without replacement and prints them as a standalone Forge
+model, with every column's type preserved (formula columns carry their
+calculated values). Pass --seed to make the draw reproducible; the seed
+used is always recorded in the output header.
+
+EXAMPLES:
+  forge sample model.yaml --table sales --n 10            # 10 random rows
+  forge sample model.yaml --table sales --n 10 --seed 42  # Reproducible
+  forge sample model.yaml --n 50 --with-replacement       # Bootstrap-style")]
+    /// Draw random rows from a table
+    Sample {
+        /// Path to YAML model file
+        file: PathBuf,
+
+        /// Table to sample (optional when the model has one table)
+        #[arg(long)]
+        table: Option<String>,
+
+        /// Number of rows to draw
+        #[arg(short = 'n', long = "n", value_name = "N")]
+        n: usize,
+
+        /// Random seed for a reproducible sample
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Allow rows to be picked more than once (and N above the row count)
+        #[arg(long)]
+        with_replacement: bool,
+    },
+
+    #[command(long_about = "Build a discounted cash flow schedule.
+
+Writes a Forge model with a 'dcf' table (period, cash_flow, discount_factor,
+discounted_value) and a summary.npv scalar that re-sums the discounted
+values. Without dates the first flow is one period out, like NPV. With
+--dates each flow is discounted by the years since the first date
+(actual/365), like XNPV.
+
+EXAMPLES:
+  forge dcf --rate 0.1 --flows 100,200,300 -o dcf.yaml
+  forge dcf --rate 0.08 --flows=-1000,400,800 \\
+            --dates 2025-01-01,2025-07-01,2026-03-15 -o dcf.yaml
+  forge dcf --rate 0.1 --flows 100,200,300          # Print to stdout")]
+    /// Build a discounted cash flow schedule (NPV/XNPV per period)
+    Dcf {
+        /// Discount rate per period (annual rate with --dates)
+        #[arg(long, allow_hyphen_values = true)]
+        rate: f64,
+
+        /// Comma-separated cash flows
+        #[arg(
+            long,
+            value_delimiter = ',',
+            required = true,
+            allow_hyphen_values = true
+        )]
+        flows: Vec<f64>,
+
+        /// Comma-separated dates (YYYY-MM-DD), one per cash flow
+        #[arg(long, value_delimiter = ',')]
+        dates: Vec<String>,
+
+        /// Output YAML file (prints to stdout when omitted)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    #[command(long_about = "Rewrite YAML models in canonical style.
+
+Parses each file and re-emits it with consistent formatting. The model's
+data is unchanged (verified by re-reading the output), and the original is
+saved as .yaml.bak. The leading comment block is kept; a file with other
+comments is reported and left as is, since formatting would drop them.
+
+STYLE:
+  - Two-space indentation
+  - _forge_version, _name and _includes first; other keys keep their order
+  - value and formula lead each variable
+  - Arrays of plain values in flow style: [1, 2, 3]
+  - Formulas always double-quoted: \"=revenue - cost\"
+
+EXAMPLES:
+  forge fmt model.yaml              # Format in place
+  forge fmt models/*.yaml --check   # CI: fail if any file is unformatted")]
+    /// Rewrite YAML models in canonical style
+    Fmt {
+        /// YAML files to format
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Report unformatted files and exit non-zero instead of rewriting
+        #[arg(long)]
+        check: bool,
+    },
+
+    #[command(long_about = "Pin a model's calculated outputs and catch regressions.
+
+Calculates the model and compares every scalar and table cell with the
+golden file next to it (model.yaml -> model.snapshot.json). The first run
+writes the snapshot; later runs fail with a per-value diff when outputs
+change. Numbers are compared with the same tolerance as 'forge validate'.
+
+Commit the .snapshot.json file alongside the model and run 'forge snapshot'
+in CI; after an intended change, accept the new outputs with --update.
+
+EXAMPLES:
+  forge snapshot model.yaml            # Compare (or create the snapshot)
+  forge snapshot model.yaml --update   # Regenerate after an intended change")]
+    /// Compare calculated outputs against a golden snapshot file
+    Snapshot {
+        /// YAML model to snapshot
+        file: PathBuf,
+
+        /// Rewrite the snapshot with the current outputs instead of comparing
+        #[arg(long)]
+        update: bool,
+    },
+
     #[command(
         long_about = "Check for updates and optionally install the latest version.
 
@@ -1064,10 +1473,13 @@ Features:
   Graceful shutdown on SIGINT/SIGTERM
   JSON response format with request IDs
   Tracing and structured logging
+  Optional LRU cache for dry-run calculations (--cache-size), keyed by
+  a hash of the normalized model so unchanged models are not recalculated
 
 EXAMPLES:
   forge serve                              # Start on localhost:8080
-  forge serve --host 0.0.0.0 --port 3000   # Custom bind address")]
+  forge serve --host 0.0.0.0 --port 3000   # Custom bind address
+  forge serve --cache-size 256 --cache-ttl 600   # Cache up to 256 results for 10 minutes")]
     /// Start HTTP REST API server
     Serve {
         /// Host address to bind to (use 0.0.0.0 for all interfaces)
@@ -1077,6 +1489,14 @@ EXAMPLES:
         /// Port to listen on
         #[arg(short, long, default_value = "8080", env = "FORGE_PORT")]
         port: u16,
+
+        /// Cache up to N dry-run calculation results (0 disables caching)
+        #[arg(long, default_value = "0", value_name = "N", env = "FORGE_CACHE_SIZE")]
+        cache_size: usize,
+
+        /// Expire cached results after this many seconds (default: never)
+        #[arg(long, value_name = "SECONDS")]
+        cache_ttl: Option<u64>,
     },
 }
 
@@ -1088,6 +1508,10 @@ EXAMPLES:
 #[allow(clippy::too_many_lines)] // CLI dispatch function — splitting would fragment command handling
 fn main() -> ForgeResult<()> {
     let cli = Cli::parse();
+    let limits = ParseLimits::new(cli.max_rows, cli.max_cells);
+    parser::set_assumed_version(cli.assume_version);
+    cli::set_locale(cli.locale);
+    cli::set_theme(cli.theme);
 
     match cli.command {
         Commands::Calculate {
@@ -1095,17 +1519,96 @@ fn main() -> ForgeResult<()> {
             dry_run,
             verbose,
             scenario,
-        } => cli::calculate(&file, dry_run, verbose, scenario.as_deref()),
+            round,
+            output,
+            strict_units,
+            continue_on_error,
+            trace,
+            strict,
+            only,
+            skip,
+            json,
+        } => {
+            if json {
+                return cli::calculate_json(&file, dry_run, scenario.as_deref(), limits);
+            }
+            let selection = if !only.is_empty() {
+                OutputSelection::Only(only)
+            } else if !skip.is_empty() {
+                OutputSelection::Skip(skip)
+            } else {
+                OutputSelection::All
+            };
+            cli::calculate(
+                &file,
+                &cli::CalculateOptions {
+                    dry_run,
+                    verbose,
+                    scenario: scenario.as_deref(),
+                    round,
+                    output: output.as_deref(),
+                    strict_units,
+                    continue_on_error,
+                    trace,
+                    strict,
+                    selection,
+                    limits,
+                },
+            )
+        },
 
-        Commands::Audit { file, variable } => cli::audit(&file, &variable),
+        Commands::Graph { file, format } => cli::graph(&file, &format, limits),
 
-        Commands::Validate { files } => cli::validate(&files),
+        Commands::Audit {
+            file,
+            variable,
+            dependents,
+        } => {
+            if dependents {
+                cli::audit_dependents(&file, &variable, limits)
+            } else {
+                cli::audit(&file, &variable, limits)
+            }
+        },
+
+        Commands::Validate {
+            files,
+            explain,
+            strict,
+            json,
+        } => {
+            if json {
+                cli::validate_json(&files, limits)
+            } else {
+                cli::validate(&files, explain, strict, limits)
+            }
+        },
+
+        Commands::Compile { file } => cli::compile(&file, limits),
 
         Commands::Export {
             input,
             output,
+            table,
+            markdown,
+            json_records,
+            parquet,
             verbose,
-        } => cli::export(&input, &output, verbose),
+        } => match (markdown, json_records, parquet, output) {
+            (Some(markdown), ..) => {
+                cli::export_markdown(&input, table.as_deref(), &markdown, limits)
+            },
+            (_, Some(json), ..) => {
+                cli::export_json_records(&input, table.as_deref(), &json, limits)
+            },
+            (_, _, Some(parquet), _) => {
+                cli::export_parquet(&input, table.as_deref(), &parquet, limits)
+            },
+            (_, _, _, Some(output)) => cli::export(&input, &output, verbose, limits),
+            (None, None, None, None) => {
+                unreachable!("clap requires an output path or a format flag")
+            },
+        },
 
         Commands::Import {
             input,
@@ -1113,19 +1616,33 @@ fn main() -> ForgeResult<()> {
             verbose,
             split_files,
             multi_doc,
-        } => cli::import(&input, &output, verbose, split_files, multi_doc),
+            types,
+            sheets,
+            range,
+        } => cli::import(
+            &input,
+            &output,
+            &cli::ImportOptions {
+                split_files,
+                multi_doc,
+                types: &types,
+                sheets: &sheets,
+                range: range.as_deref(),
+            },
+            verbose,
+        ),
 
         Commands::Watch {
             file,
             validate,
             verbose,
-        } => cli::watch(&file, validate, verbose),
+        } => cli::watch(&file, validate, verbose, limits),
 
         Commands::Compare {
             file,
             scenarios,
             verbose,
-        } => cli::compare(&file, &scenarios, verbose),
+        } => cli::compare(&file, &scenarios, verbose, limits),
 
         Commands::Variance {
             budget,
@@ -1133,7 +1650,14 @@ fn main() -> ForgeResult<()> {
             threshold,
             output,
             verbose,
-        } => cli::variance(&budget, &actual, threshold, output.as_deref(), verbose),
+        } => cli::variance(
+            &budget,
+            &actual,
+            threshold,
+            output.as_deref(),
+            verbose,
+            limits,
+        ),
 
         Commands::Sensitivity {
             file,
@@ -1143,15 +1667,30 @@ fn main() -> ForgeResult<()> {
             range2,
             output,
             verbose,
-        } => cli::sensitivity(
-            &file,
-            &vary,
-            &range,
-            vary2.as_deref(),
-            range2.as_deref(),
-            &output,
-            verbose,
-        ),
+            heatmap,
+            csv,
+            json,
+        } => {
+            let format = if json {
+                cli::SensitivityFormat::Json
+            } else if csv {
+                cli::SensitivityFormat::Csv
+            } else if heatmap {
+                cli::SensitivityFormat::Heatmap
+            } else {
+                cli::SensitivityFormat::Table { verbose }
+            };
+            cli::sensitivity(
+                &file,
+                &vary,
+                &range,
+                vary2.as_deref(),
+                range2.as_deref(),
+                &output,
+                format,
+                limits,
+            )
+        },
 
         Commands::GoalSeek {
             file,
@@ -1161,8 +1700,23 @@ fn main() -> ForgeResult<()> {
             min,
             max,
             tolerance,
+            scenario,
+            method,
+            verbose,
+        } => cli::goal_seek(
+            &file,
+            &target,
+            value,
+            &vary,
+            &cli::GoalSeekOptions {
+                bounds: (min, max),
+                tolerance,
+                scenario: scenario.as_deref(),
+                method: method.parse()?,
+                limits,
+            },
             verbose,
-        } => cli::goal_seek(&file, &target, value, &vary, (min, max), tolerance, verbose),
+        ),
 
         Commands::BreakEven {
             file,
@@ -1171,7 +1725,7 @@ fn main() -> ForgeResult<()> {
             min,
             max,
             verbose,
-        } => cli::break_even(&file, &output, &vary, min, max, verbose),
+        } => cli::break_even(&file, &output, &vary, min, max, verbose, limits),
 
         Commands::Simulate {
             file,
@@ -1187,21 +1741,31 @@ fn main() -> ForgeResult<()> {
             sampling.as_deref(),
             output,
             verbose,
+            limits,
         ),
 
         Commands::Scenarios {
             file,
             scenario,
+            confidence,
+            output,
+            verbose,
+        } => cli::scenarios(
+            &file,
+            scenario.as_deref(),
+            confidence,
             output,
             verbose,
-        } => cli::scenarios(&file, scenario.as_deref(), output, verbose),
+            limits,
+        ),
 
         Commands::DecisionTree {
             file,
             dot,
+            evpi,
             output,
             verbose,
-        } => cli::decision_tree(&file, dot, output, verbose),
+        } => cli::decision_tree(&file, dot, evpi.as_deref(), output, verbose),
 
         Commands::RealOptions {
             file,
@@ -1216,7 +1780,7 @@ fn main() -> ForgeResult<()> {
             output_var,
             output,
             verbose,
-        } => cli::tornado(&file, output_var.as_deref(), output, verbose),
+        } => cli::tornado(&file, output_var.as_deref(), output, verbose, limits),
 
         Commands::Bootstrap {
             file,
@@ -1231,12 +1795,15 @@ fn main() -> ForgeResult<()> {
             file,
             query,
             evidence,
+            map,
             output,
             verbose,
-        } => cli::bayesian(&file, query.as_deref(), &evidence, output, verbose),
+        } => cli::bayesian(&file, query.as_deref(), &evidence, map, output, verbose),
 
         Commands::Functions { json } => cli::functions(json),
 
+        Commands::Features { json } => cli::features(json),
+
         Commands::Schema { version, list } => cli::schema(version.as_deref(), list),
 
         Commands::Examples { name, run, json } => cli::examples(name, run, json),
@@ -1248,6 +1815,32 @@ fn main() -> ForgeResult<()> {
             verbose,
         } => cli::upgrade(&file, dry_run, &to, verbose),
 
+        Commands::Stats { file, table, json } => cli::stats(&file, table.as_deref(), json, limits),
+
+        Commands::Sample {
+            file,
+            table,
+            n,
+            seed,
+            with_replacement,
+        } => cli::sample(&file, table.as_deref(), n, seed, with_replacement, limits),
+
+        Commands::Dcf {
+            rate,
+            flows,
+            dates,
+            output,
+        } => cli::dcf(
+            rate,
+            &flows,
+            (!dates.is_empty()).then_some(dates.as_slice()),
+            output.as_deref(),
+        ),
+
+        Commands::Fmt { files, check } => cli::fmt(&files, check),
+
+        Commands::Snapshot { file, update } => cli::snapshot(&file, update, limits),
+
         Commands::Update { check, verbose } => cli::update(check, verbose),
 
         Commands::Mcp => {
@@ -1267,8 +1860,18 @@ fn main() -> ForgeResult<()> {
             })
         },
 
-        Commands::Serve { host, port } => {
-            let config = ApiConfig { host, port };
+        Commands::Serve {
+            host,
+            port,
+            cache_size,
+            cache_ttl,
+        } => {
+            let config = ApiConfig {
+                host,
+                port,
+                cache_capacity: cache_size,
+                cache_ttl: cache_ttl.map(std::time::Duration::from_secs),
+            };
             let rt = tokio::runtime::Runtime::new()
                 .map_err(|e| ForgeError::Validation(format!("Failed to create runtime: {e}")))?;
             rt.block_on(run_api_server(config))
@@ -14,11 +14,12 @@ use tempfile::TempDir;
 
 use crate::cli::calculate_core;
 use crate::cli::{
-    audit_core, bayesian_core, bootstrap_core, compare_core, decision_tree_core, examples_core,
-    export_buffer_core, export_core, functions_core, goal_seek_core, import_core,
+    audit_core, bayesian_core, bootstrap_core, break_even_core, compare_core, decision_tree_core,
+    examples_core, export_buffer_core, export_core, functions_core, goal_seek_core, import_core,
     real_options_core, scenarios_core, schema_core, sensitivity_core, simulate_core, tornado_core,
-    validate_core, variance_core,
+    validate_core, variance_core, GoalSeekOptions, ImportOptions, SolverMethod,
 };
+use crate::parser::ParseLimits;
 
 use super::types::{
     AuditRequest, BayesianRequest, BootstrapRequest, BreakEvenRequest, CalculateRequest,
@@ -156,7 +157,7 @@ impl ForgeMcpServer {
             req.content.as_deref(),
             req.includes.as_ref(),
         )?;
-        validate_core(&path)
+        validate_core(&path, ParseLimits::default())
             .map(|r| to_json(&r))
             .map_err(|e| format!("Validation failed: {e}"))
     }
@@ -172,8 +173,13 @@ impl ForgeMcpServer {
             req.content.as_deref(),
             req.includes.as_ref(),
         )?;
-        let result = calculate_core(path.as_path(), req.dry_run, req.scenario.as_deref())
-            .map_err(|e| format!("Calculation failed: {e}"))?;
+        let result = calculate_core(
+            path.as_path(),
+            req.dry_run,
+            req.scenario.as_deref(),
+            ParseLimits::default(),
+        )
+        .map_err(|e| format!("Calculation failed: {e}"))?;
 
         let mut json_val: serde_json::Value =
             serde_json::to_value(&result).map_err(|e| format!("Serialization failed: {e}"))?;
@@ -198,7 +204,7 @@ impl ForgeMcpServer {
             req.content.as_deref(),
             req.includes.as_ref(),
         )?;
-        audit_core(&path, &req.variable)
+        audit_core(&path, &req.variable, ParseLimits::default())
             .map(|r| to_json(&r))
             .map_err(|e| format!("Audit failed: {e}"))
     }
@@ -218,12 +224,12 @@ impl ForgeMcpServer {
         )?;
         req.excel_path.as_ref().map_or_else(
             || {
-                export_buffer_core(&path)
+                export_buffer_core(&path, ParseLimits::default())
                     .map(|r| to_json(&r))
                     .map_err(|e| format!("Export failed: {e}"))
             },
             |excel_path| {
-                export_core(&path, Path::new(excel_path))
+                export_core(&path, Path::new(excel_path), ParseLimits::default())
                     .map(|r| to_json(&r))
                     .map_err(|e| format!("Export failed: {e}"))
             },
@@ -238,8 +244,7 @@ impl ForgeMcpServer {
         import_core(
             Path::new(&req.excel_path),
             Path::new(&req.yaml_path),
-            false,
-            false,
+            &ImportOptions::default(),
         )
         .map(|r| to_json(&r))
         .map_err(|e| format!("Import failed: {e}"))
@@ -269,6 +274,7 @@ impl ForgeMcpServer {
             req.vary2.as_deref(),
             req.range2.as_deref(),
             &req.output,
+            ParseLimits::default(),
         )
         .map(|r| to_json(&r))
         .map_err(|e| format!("Sensitivity analysis failed: {e}"))
@@ -276,9 +282,15 @@ impl ForgeMcpServer {
 
     #[tool(
         name = "forge_goal_seek",
-        description = "Find the input value needed to achieve a target output. Uses bisection solver. Example: 'What price do I need for $100K profit?'"
+        description = "Find the input value needed to achieve a target output. Uses bisection, or Newton-Raphson with method 'newton'. Example: 'What price do I need for $100K profit?'"
     )]
     fn goal_seek(&self, Parameters(req): Parameters<GoalSeekRequest>) -> Result<String, String> {
+        let method: SolverMethod = req
+            .method
+            .as_deref()
+            .unwrap_or("bisection")
+            .parse()
+            .map_err(|e| format!("Goal seek failed: {e}"))?;
         let (path, _tmpdir) = resolve_model_input(
             req.file_path.as_deref(),
             req.content.as_deref(),
@@ -289,8 +301,13 @@ impl ForgeMcpServer {
             &req.target,
             req.value,
             &req.vary,
-            (req.min, req.max),
-            req.tolerance,
+            &GoalSeekOptions {
+                bounds: (req.min, req.max),
+                tolerance: req.tolerance,
+                scenario: req.scenario.as_deref(),
+                method,
+                limits: ParseLimits::default(),
+            },
         )
         .map(|r| to_json(&r))
         .map_err(|e| format!("Goal seek failed: {e}"))
@@ -298,7 +315,7 @@ impl ForgeMcpServer {
 
     #[tool(
         name = "forge_break_even",
-        description = "Find the break-even point where an output equals zero. Example: 'At what units does profit = 0?'"
+        description = "Find the break-even point where an output equals zero, with the input's current value and margin of safety (percent above break-even). Example: 'At what units does profit = 0?'"
     )]
     fn break_even(&self, Parameters(req): Parameters<BreakEvenRequest>) -> Result<String, String> {
         let (path, _tmpdir) = resolve_model_input(
@@ -306,13 +323,12 @@ impl ForgeMcpServer {
             req.content.as_deref(),
             req.includes.as_ref(),
         )?;
-        goal_seek_core(
+        break_even_core(
             &path,
             &req.output,
-            0.0,
             &req.vary,
             (req.min, req.max),
-            0.0001,
+            ParseLimits::default(),
         )
         .map(|r| to_json(&r))
         .map_err(|e| format!("Break-even analysis failed: {e}"))
@@ -334,9 +350,14 @@ impl ForgeMcpServer {
             req.includes.as_ref(),
         )?;
         let threshold = req.threshold.unwrap_or(10.0);
-        variance_core(&budget_path, &actual_path, threshold)
-            .map(|r| to_json(&r))
-            .map_err(|e| format!("Variance analysis failed: {e}"))
+        variance_core(
+            &budget_path,
+            &actual_path,
+            threshold,
+            ParseLimits::default(),
+        )
+        .map(|r| to_json(&r))
+        .map_err(|e| format!("Variance analysis failed: {e}"))
     }
 
     #[tool(
@@ -349,7 +370,7 @@ impl ForgeMcpServer {
             req.content.as_deref(),
             req.includes.as_ref(),
         )?;
-        compare_core(&path, &req.scenarios)
+        compare_core(&path, &req.scenarios, ParseLimits::default())
             .map(|r| to_json(&r))
             .map_err(|e| format!("Scenario comparison failed: {e}"))
     }
@@ -360,7 +381,7 @@ impl ForgeMcpServer {
 
     #[tool(
         name = "forge_simulate",
-        description = "Run Monte Carlo simulation with probabilistic distributions (MC.Normal, MC.Triangular, MC.Uniform, MC.PERT, MC.Lognormal). Returns statistics, percentiles, and threshold probabilities."
+        description = "Run Monte Carlo simulation with probabilistic distributions (MC.Normal, MC.Triangular, MC.Uniform, MC.PERT, MC.Lognormal, MC.Beta, MC.Exponential, MC.Poisson, MC.Gamma). Returns statistics, percentiles, and threshold probabilities."
     )]
     fn simulate(&self, Parameters(req): Parameters<SimulateRequest>) -> Result<String, String> {
         let (path, _tmpdir) = resolve_model_input(
@@ -372,12 +393,18 @@ impl ForgeMcpServer {
         #[allow(clippy::cast_possible_truncation)]
         let iterations = req.iterations.map(|n| n as usize);
 
-        simulate_core(&path, iterations, req.seed, req.sampling.as_deref())
-            .map_err(|e| format!("Simulation failed: {e}"))
-            .and_then(|r| {
-                r.to_json()
-                    .map_err(|e| format!("Serialization failed: {e}"))
-            })
+        simulate_core(
+            &path,
+            iterations,
+            req.seed,
+            req.sampling.as_deref(),
+            ParseLimits::default(),
+        )
+        .map_err(|e| format!("Simulation failed: {e}"))
+        .and_then(|r| {
+            r.to_json()
+                .map_err(|e| format!("Serialization failed: {e}"))
+        })
     }
 
     #[tool(
@@ -390,9 +417,13 @@ impl ForgeMcpServer {
             req.content.as_deref(),
             req.includes.as_ref(),
         )?;
-        scenarios_core(&path, req.scenario_filter.as_deref())
-            .map(|r| to_json(&r))
-            .map_err(|e| format!("Scenario analysis failed: {e}"))
+        scenarios_core(
+            &path,
+            req.scenario_filter.as_deref(),
+            ParseLimits::default(),
+        )
+        .map(|r| to_json(&r))
+        .map_err(|e| format!("Scenario analysis failed: {e}"))
     }
 
     #[tool(
@@ -433,7 +464,7 @@ impl ForgeMcpServer {
 
     #[tool(
         name = "forge_tornado",
-        description = "Generate tornado sensitivity diagram. Varies each input one-at-a-time to show which inputs have the greatest impact on the output."
+        description = "Generate tornado sensitivity diagram. Varies each input one-at-a-time to show which inputs have the greatest impact on the output. Without a tornado section, every input scalar is varied by ±10%."
     )]
     fn tornado(&self, Parameters(req): Parameters<TornadoRequest>) -> Result<String, String> {
         let (path, _tmpdir) = resolve_model_input(
@@ -441,7 +472,7 @@ impl ForgeMcpServer {
             req.content.as_deref(),
             req.includes.as_ref(),
         )?;
-        tornado_core(&path, req.output_var.as_deref())
+        tornado_core(&path, req.output_var.as_deref(), ParseLimits::default())
             .map(|r| to_json(&r))
             .map_err(|e| format!("Tornado analysis failed: {e}"))
     }
@@ -494,7 +525,7 @@ impl ForgeMcpServer {
 
     #[tool(
         name = "forge_functions",
-        description = "List all 173 supported Excel-compatible functions with descriptions and syntax. Organized by category (Financial, Statistical, Math, Lookup, etc.)."
+        description = "List all 233 supported Excel-compatible functions with descriptions and syntax. Organized by category (Financial, Statistical, Math, Lookup, etc.)."
     )]
     fn functions(&self, Parameters(_req): Parameters<FunctionsRequest>) -> Result<String, String> {
         functions_core()
@@ -517,7 +548,7 @@ impl ForgeMcpServer {
 impl ServerHandler for ForgeMcpServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo::new(ServerCapabilities::builder().enable_tools().build())
-            .with_instructions("Forge MCP Server - 20 tools for AI-native financial modeling. Core: validate, calculate, audit, export, import. Analysis: sensitivity, goal-seek, break-even, variance, compare. Engines: simulate (Monte Carlo), scenarios, decision-tree, real-options, tornado, bootstrap, bayesian. Discovery: schema, functions, examples. 173 Excel-compatible functions. All tools return structured JSON.")
+            .with_instructions("Forge MCP Server - 20 tools for AI-native financial modeling. Core: validate, calculate, audit, export, import. Analysis: sensitivity, goal-seek, break-even, variance, compare. Engines: simulate (Monte Carlo), scenarios, decision-tree, real-options, tornado, bootstrap, bayesian. Discovery: schema, functions, examples. 233 Excel-compatible functions. All tools return structured JSON.")
             .with_server_info(
                 rmcp::model::Implementation::new("forge", env!("CARGO_PKG_VERSION"))
             )
@@ -769,6 +800,8 @@ scalars:
             min: Some(50_000.0),
             max: Some(200_000.0),
             tolerance: 0.01,
+            scenario: None,
+            method: None,
         }));
     }
 
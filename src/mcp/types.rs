@@ -117,6 +117,12 @@ pub struct GoalSeekRequest {
     /// Solution tolerance
     #[serde(default = "default_tolerance")]
     pub tolerance: f64,
+    /// Scenario whose override is varied; the target becomes its expected value
+    /// across all scenarios
+    pub scenario: Option<String>,
+    /// Solver: `bisection` (default) or `newton` (Newton-Raphson, falling back
+    /// to bisection)
+    pub method: Option<String>,
 }
 
 const fn default_tolerance() -> f64 {
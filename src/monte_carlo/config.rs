@@ -4,6 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::correlation::CorrelationMatrix;
+use super::statistics::Threshold;
+
 /// Monte Carlo simulation configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonteCarloConfig {
@@ -27,9 +30,65 @@ pub struct MonteCarloConfig {
     #[serde(default)]
     pub outputs: Vec<OutputConfig>,
 
-    /// Correlation specifications (Phase 3)
+    /// Pairwise correlations between sampled variables
     #[serde(default)]
     pub correlations: Vec<CorrelationConfig>,
+
+    /// Full correlation matrix, instead of pairwise `correlations`
+    #[serde(default)]
+    pub correlation_matrix: Option<CorrelationMatrixConfig>,
+
+    /// Number of histogram bins per output (default: 50)
+    #[serde(default = "default_histogram_bins")]
+    pub histogram_bins: usize,
+
+    /// Early stopping once output means stabilize (optional)
+    #[serde(default)]
+    pub convergence: Option<ConvergenceConfig>,
+}
+
+/// Convergence-based early stopping
+///
+/// Every `check_interval` iterations the running mean of each output is
+/// compared with the previous check. Once every output's relative change stays
+/// below `tolerance` for `consecutive_checks` checks in a row, the simulation
+/// stops. With `relative_standard_error` set, the simulation instead stops at
+/// the first check where every output's standard error of the mean is within
+/// that fraction of the mean. `iterations` remains the hard cap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvergenceConfig {
+    /// Maximum relative change in the running mean between checks (default: 0.001)
+    #[serde(default = "default_convergence_tolerance")]
+    pub tolerance: f64,
+
+    /// Iterations between convergence checks (default: 500)
+    #[serde(default = "default_check_interval")]
+    pub check_interval: usize,
+
+    /// Consecutive passing checks required to stop (default: 3)
+    #[serde(default = "default_consecutive_checks")]
+    pub consecutive_checks: usize,
+
+    /// Never stop before this many iterations (default: 1000)
+    #[serde(default = "default_min_iterations")]
+    pub min_iterations: usize,
+
+    /// Target standard error of each output's mean, relative to the mean
+    /// (e.g. 0.001 for 0.1%); replaces the `tolerance` test when set
+    #[serde(default)]
+    pub relative_standard_error: Option<f64>,
+}
+
+impl Default for ConvergenceConfig {
+    fn default() -> Self {
+        Self {
+            tolerance: default_convergence_tolerance(),
+            check_interval: default_check_interval(),
+            consecutive_checks: default_consecutive_checks(),
+            min_iterations: default_min_iterations(),
+            relative_standard_error: None,
+        }
+    }
 }
 
 /// Output variable configuration
@@ -42,7 +101,7 @@ pub struct OutputConfig {
     #[serde(default = "default_percentiles")]
     pub percentiles: Vec<u8>,
 
-    /// Probability threshold (e.g., "> 0", "< 100000")
+    /// Probability threshold (e.g., "> 0", "<= 100000", "between 10 and 20")
     #[serde(default)]
     pub threshold: Option<String>,
 
@@ -61,6 +120,25 @@ pub struct CorrelationConfig {
     pub coefficient: f64,
 }
 
+/// Full correlation matrix over a list of variables
+///
+/// ```yaml
+/// correlation_matrix:
+///   variables: [revenue, costs, growth]
+///   matrix:
+///     - [1.0, 0.6, 0.4]
+///     - [0.6, 1.0, 0.2]
+///     - [0.4, 0.2, 1.0]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrelationMatrixConfig {
+    /// Variables, in row/column order
+    pub variables: Vec<String>,
+
+    /// Symmetric, positive semi-definite coefficients with a unit diagonal
+    pub matrix: Vec<Vec<f64>>,
+}
+
 impl Default for MonteCarloConfig {
     fn default() -> Self {
         Self {
@@ -70,6 +148,9 @@ impl Default for MonteCarloConfig {
             seed: None,
             outputs: Vec::new(),
             correlations: Vec::new(),
+            correlation_matrix: None,
+            histogram_bins: default_histogram_bins(),
+            convergence: None,
         }
     }
 }
@@ -102,6 +183,13 @@ impl MonteCarloConfig {
         self
     }
 
+    /// Builder: enable convergence-based early stopping
+    #[must_use]
+    pub const fn with_convergence(mut self, convergence: ConvergenceConfig) -> Self {
+        self.convergence = Some(convergence);
+        self
+    }
+
     /// Builder: enable Monte Carlo
     #[must_use]
     pub const fn enabled(mut self) -> Self {
@@ -114,7 +202,8 @@ impl MonteCarloConfig {
     /// # Errors
     ///
     /// Returns an error if iterations is zero or exceeds 1,000,000,
-    /// sampling method is invalid, or correlation specs are malformed.
+    /// sampling method is invalid, an output threshold is malformed,
+    /// or correlation specs are malformed or not positive semi-definite.
     pub fn validate(&self) -> Result<(), String> {
         if self.iterations == 0 {
             return Err("iterations must be > 0".to_string());
@@ -124,6 +213,35 @@ impl MonteCarloConfig {
             return Err("iterations must be <= 1,000,000".to_string());
         }
 
+        if self.histogram_bins == 0 {
+            return Err("histogram_bins must be > 0".to_string());
+        }
+
+        if let Some(convergence) = &self.convergence {
+            if convergence.tolerance <= 0.0 {
+                return Err("convergence.tolerance must be > 0".to_string());
+            }
+            if convergence.check_interval == 0 {
+                return Err("convergence.check_interval must be > 0".to_string());
+            }
+            if convergence.consecutive_checks == 0 {
+                return Err("convergence.consecutive_checks must be > 0".to_string());
+            }
+            if convergence
+                .relative_standard_error
+                .is_some_and(|target| target <= 0.0)
+            {
+                return Err("convergence.relative_standard_error must be > 0".to_string());
+            }
+        }
+
+        for output in &self.outputs {
+            if let Some(threshold) = &output.threshold {
+                Threshold::parse(threshold)
+                    .map_err(|e| format!("output '{}': {e}", output.variable))?;
+            }
+        }
+
         let valid_sampling = ["monte_carlo", "latin_hypercube"];
         if !valid_sampling.contains(&self.sampling.as_str()) {
             return Err(format!("sampling must be one of: {valid_sampling:?}"));
@@ -136,16 +254,77 @@ impl MonteCarloConfig {
             if corr.coefficient < -1.0 || corr.coefficient > 1.0 {
                 return Err("correlation coefficient must be between -1.0 and 1.0".to_string());
             }
+            if corr.variables[0] == corr.variables[1] {
+                return Err(format!(
+                    "correlation must pair two different variables, got {} twice",
+                    corr.variables[0]
+                ));
+            }
         }
+        self.correlation_matrix()?;
 
         Ok(())
     }
+
+    /// Correlation matrix from `correlations` or `correlation_matrix`
+    ///
+    /// Returns `None` when no correlations are configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if both forms are given, or the coefficients do not
+    /// form a valid correlation matrix (see [`CorrelationMatrix::from_matrix`]).
+    pub fn correlation_matrix(&self) -> Result<Option<CorrelationMatrix>, String> {
+        let matrix = match (&self.correlation_matrix, self.correlations.is_empty()) {
+            (Some(_), false) => {
+                return Err("use either correlations or correlation_matrix, not both".to_string())
+            },
+            (Some(full), true) => {
+                CorrelationMatrix::from_matrix(full.variables.clone(), &full.matrix)
+            },
+            (None, false) => CorrelationMatrix::new(
+                &self
+                    .correlations
+                    .iter()
+                    .map(|c| {
+                        (
+                            c.variables[0].clone(),
+                            c.variables[1].clone(),
+                            c.coefficient,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            (None, true) => return Ok(None),
+        };
+        matrix.map(Some).map_err(|e| format!("correlations: {e}"))
+    }
 }
 
 const fn default_iterations() -> usize {
     10_000
 }
 
+const fn default_histogram_bins() -> usize {
+    50
+}
+
+const fn default_convergence_tolerance() -> f64 {
+    0.001
+}
+
+const fn default_check_interval() -> usize {
+    500
+}
+
+const fn default_consecutive_checks() -> usize {
+    3
+}
+
+const fn default_min_iterations() -> usize {
+    1000
+}
+
 fn default_sampling() -> String {
     "latin_hypercube".to_string()
 }
@@ -208,6 +387,45 @@ mod tests {
 
         config.correlations[0].coefficient = 0.7;
         assert!(config.validate().is_ok());
+
+        // Pairs that contradict each other cannot be sampled
+        config.correlations.extend([
+            CorrelationConfig {
+                variables: vec!["a".to_string(), "c".to_string()],
+                coefficient: 0.9,
+            },
+            CorrelationConfig {
+                variables: vec!["b".to_string(), "c".to_string()],
+                coefficient: -0.9,
+            },
+        ]);
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("positive semi-definite"), "got: {err}");
+        config.correlations.truncate(1);
+
+        config.outputs.push(OutputConfig {
+            variable: "npv".to_string(),
+            percentiles: default_percentiles(),
+            threshold: Some("between 0".to_string()),
+            label: None,
+        });
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("output 'npv'"), "got: {err}");
+        config.outputs[0].threshold = Some("between 0 and 100".to_string());
+        assert!(config.validate().is_ok());
+
+        config.convergence = Some(ConvergenceConfig {
+            check_interval: 0,
+            ..ConvergenceConfig::default()
+        });
+        assert!(config.validate().is_err());
+
+        config.convergence = Some(ConvergenceConfig {
+            relative_standard_error: Some(0.0),
+            ..ConvergenceConfig::default()
+        });
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("relative_standard_error"), "got: {err}");
     }
 
     #[test]
@@ -235,4 +453,34 @@ correlations:
         assert_eq!(config.correlations.len(), 1);
         assert_eq!(config.correlations[0].coefficient, -0.3);
     }
+
+    #[test]
+    fn test_correlation_matrix_config() {
+        let yaml = r"
+correlation_matrix:
+  variables: [revenue, costs]
+  matrix:
+    - [1.0, 0.8]
+    - [0.8, 1.0]
+";
+        let mut config: MonteCarloConfig = serde_yaml_ng::from_str(yaml).unwrap();
+        let matrix = config.correlation_matrix().unwrap().unwrap();
+        assert_eq!(matrix.get_correlation("revenue", "costs"), Some(0.8));
+
+        config.correlation_matrix.as_mut().unwrap().matrix[1][0] = 0.5;
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("not symmetric"), "got: {err}");
+
+        config.correlation_matrix.as_mut().unwrap().matrix[1][0] = 0.8;
+        config.correlations.push(CorrelationConfig {
+            variables: vec!["revenue".to_string(), "costs".to_string()],
+            coefficient: 0.5,
+        });
+        assert!(config.validate().is_err());
+
+        assert!(MonteCarloConfig::default()
+            .correlation_matrix()
+            .unwrap()
+            .is_none());
+    }
 }
@@ -5,6 +5,9 @@
 
 use std::collections::HashMap;
 
+/// Slack for rounding in user-supplied matrices and the Cholesky pivots
+const TOLERANCE: f64 = 1e-9;
+
 /// Correlation matrix for correlated sampling
 #[derive(Debug, Clone)]
 pub struct CorrelationMatrix {
@@ -22,7 +25,7 @@ impl CorrelationMatrix {
     /// # Errors
     ///
     /// Returns an error if any correlation coefficient is outside [-1, 1]
-    /// or the resulting matrix is not positive definite.
+    /// or the resulting matrix is not positive semi-definite.
     pub fn new(correlations: &[(String, String, f64)]) -> Result<Self, String> {
         // Collect unique variables
         let mut variables: Vec<String> = correlations
@@ -80,6 +83,53 @@ impl CorrelationMatrix {
         Ok(matrix)
     }
 
+    /// Create a correlation matrix from a full matrix over `variables`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square with one row per variable,
+    /// has a diagonal other than 1, is not symmetric, has a coefficient outside
+    /// [-1, 1], or is not positive semi-definite.
+    pub fn from_matrix(variables: Vec<String>, rows: &[Vec<f64>]) -> Result<Self, String> {
+        let n = variables.len();
+        if rows.len() != n || rows.iter().any(|row| row.len() != n) {
+            return Err(format!(
+                "Correlation matrix must be {n}x{n}, one row and column per variable"
+            ));
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            if (row[i] - 1.0).abs() > TOLERANCE {
+                return Err(format!(
+                    "Correlation of {} with itself must be 1, got {}",
+                    variables[i], row[i]
+                ));
+            }
+            for (j, rho) in row.iter().enumerate().skip(i + 1) {
+                if !(-1.0..=1.0).contains(rho) {
+                    return Err(format!(
+                        "Correlation between {} and {} must be between -1 and 1, got {rho}",
+                        variables[i], variables[j]
+                    ));
+                }
+                if (rho - rows[j][i]).abs() > TOLERANCE {
+                    return Err(format!(
+                        "Correlation matrix is not symmetric: {} -> {} is {rho} but {} -> {} is {}",
+                        variables[i], variables[j], variables[j], variables[i], rows[j][i]
+                    ));
+                }
+            }
+        }
+
+        let mut matrix = Self {
+            variables,
+            coefficients: rows.concat(),
+            cholesky_factor: None,
+        };
+        matrix.compute_cholesky()?;
+        Ok(matrix)
+    }
+
     /// Create identity correlation matrix (no correlations)
     #[must_use]
     pub fn identity(variables: Vec<String>) -> Self {
@@ -106,9 +156,19 @@ impl CorrelationMatrix {
     }
 
     /// Compute Cholesky decomposition (L where Σ = L * L^T)
+    ///
+    /// Positive semi-definite matrices are accepted: a zero pivot (a variable
+    /// fully determined by earlier ones, e.g. a coefficient of 1) gets a zero
+    /// column, provided the rest of that column is consistent with it.
     fn compute_cholesky(&mut self) -> Result<(), String> {
         let n = self.dim();
         let mut l = vec![0.0; n * n];
+        let not_psd = |i: usize, j: usize| {
+            format!(
+                "Correlation matrix is not positive semi-definite (element [{i},{j}]); \
+                 the coefficients contradict each other"
+            )
+        };
 
         for i in 0..n {
             for j in 0..=i {
@@ -120,18 +180,21 @@ impl CorrelationMatrix {
                         sum += l[j * n + k] * l[j * n + k];
                     }
                     let diag = self.coefficients[j * n + j] - sum;
-                    if diag <= 0.0 {
-                        return Err(format!(
-                            "Correlation matrix is not positive definite (element [{i},{j}])"
-                        ));
+                    if diag < -TOLERANCE {
+                        return Err(not_psd(i, j));
                     }
-                    l[j * n + j] = diag.sqrt();
+                    l[j * n + j] = diag.max(0.0).sqrt();
                 } else {
                     // Off-diagonal elements
                     for k in 0..j {
                         sum += l[i * n + k] * l[j * n + k];
                     }
-                    l[i * n + j] = (self.coefficients[i * n + j] - sum) / l[j * n + j];
+                    let residual = self.coefficients[i * n + j] - sum;
+                    if l[j * n + j] > TOLERANCE {
+                        l[i * n + j] = residual / l[j * n + j];
+                    } else if residual.abs() > TOLERANCE {
+                        return Err(not_psd(i, j));
+                    }
                 }
             }
         }
@@ -224,6 +287,58 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_from_matrix_validation() {
+        let vars = || vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        let matrix = CorrelationMatrix::from_matrix(
+            vars(),
+            &[
+                vec![1.0, 0.6, 0.2],
+                vec![0.6, 1.0, 0.4],
+                vec![0.2, 0.4, 1.0],
+            ],
+        )
+        .unwrap();
+        assert_eq!(matrix.get_correlation("c", "b"), Some(0.4));
+
+        let asymmetric = CorrelationMatrix::from_matrix(
+            vars(),
+            &[
+                vec![1.0, 0.6, 0.2],
+                vec![0.5, 1.0, 0.4],
+                vec![0.2, 0.4, 1.0],
+            ],
+        )
+        .unwrap_err();
+        assert!(asymmetric.contains("not symmetric"), "{asymmetric}");
+
+        // a~b and a~c strongly positive, yet b~c strongly negative
+        let contradictory = CorrelationMatrix::from_matrix(
+            vars(),
+            &[
+                vec![1.0, 0.9, 0.9],
+                vec![0.9, 1.0, -0.9],
+                vec![0.9, -0.9, 1.0],
+            ],
+        )
+        .unwrap_err();
+        assert!(
+            contradictory.contains("not positive semi-definite"),
+            "{contradictory}"
+        );
+
+        assert!(CorrelationMatrix::from_matrix(vars(), &[vec![1.0, 0.5], vec![0.5, 1.0]]).is_err());
+    }
+
+    #[test]
+    fn test_perfect_correlation_is_semi_definite() {
+        let correlations = vec![("a".to_string(), "b".to_string(), 1.0)];
+        let matrix = CorrelationMatrix::new(&correlations).unwrap();
+        let correlated = matrix.correlate(&[1.5, -3.0]).unwrap();
+        assert!((correlated[0] - correlated[1]).abs() < 1e-10);
+    }
+
     #[test]
     fn test_correlate_identity() {
         let vars = vec!["a".to_string(), "b".to_string()];
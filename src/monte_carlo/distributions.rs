@@ -6,9 +6,15 @@
 //! - MC.Uniform(min, max) - Complete uncertainty
 //! - MC.PERT(min, mode, max) - Smooth project estimates
 //! - MC.Lognormal(mean, stdev) - Non-negative values (Phase 4)
+//! - MC.Beta(alpha, beta) - Rates and proportions in [0, 1]
+//! - MC.Exponential(lambda) - Waiting times between events
+//! - MC.Poisson(lambda) - Event counts per period
+//! - MC.Gamma(shape, scale) - Positive, right-skewed durations and amounts
 
 use rand::{Rng, RngExt};
-use rand_distr::{Distribution as RandDistribution, Normal, Triangular, Uniform};
+use rand_distr::{
+    Distribution as RandDistribution, Exp, Gamma, Normal, Poisson, Triangular, Uniform,
+};
 use std::fmt;
 
 /// Distribution type enumeration
@@ -31,6 +37,21 @@ pub enum DistributionType {
     /// For non-negative values like prices, revenue
     Lognormal { mean: f64, stdev: f64 },
 
+    /// Beta distribution: MC.Beta(alpha, beta)
+    /// For rates and proportions in [0, 1]
+    Beta { alpha: f64, beta: f64 },
+
+    /// Exponential distribution: MC.Exponential(lambda)
+    /// Waiting time between events arriving at rate `lambda`
+    Exponential { lambda: f64 },
+
+    /// Poisson distribution: MC.Poisson(lambda)
+    /// Number of events in a period with mean `lambda`
+    Poisson { lambda: f64 },
+
+    /// Gamma distribution: MC.Gamma(shape, scale)
+    Gamma { shape: f64, scale: f64 },
+
     /// Discrete distribution: MC.Discrete({value: prob, ...})
     /// For scenario probabilities
     Discrete {
@@ -57,6 +78,10 @@ impl fmt::Display for DistributionType {
             Self::Lognormal { mean, stdev } => {
                 write!(f, "MC.Lognormal({mean}, {stdev})")
             },
+            Self::Beta { alpha, beta } => write!(f, "MC.Beta({alpha}, {beta})"),
+            Self::Exponential { lambda } => write!(f, "MC.Exponential({lambda})"),
+            Self::Poisson { lambda } => write!(f, "MC.Poisson({lambda})"),
+            Self::Gamma { shape, scale } => write!(f, "MC.Gamma({shape}, {scale})"),
             Self::Discrete {
                 values,
                 probabilities,
@@ -152,6 +177,62 @@ impl Distribution {
         })
     }
 
+    /// Create a Beta distribution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` or `beta` is not positive.
+    pub fn beta(alpha: f64, beta: f64) -> Result<Self, String> {
+        if alpha <= 0.0 || beta <= 0.0 {
+            return Err("Beta distribution alpha and beta must be > 0".to_string());
+        }
+        Ok(Self {
+            dist_type: DistributionType::Beta { alpha, beta },
+        })
+    }
+
+    /// Create an Exponential distribution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rate `lambda` is not positive.
+    pub fn exponential(lambda: f64) -> Result<Self, String> {
+        if lambda <= 0.0 {
+            return Err("Exponential distribution lambda (rate) must be > 0".to_string());
+        }
+        Ok(Self {
+            dist_type: DistributionType::Exponential { lambda },
+        })
+    }
+
+    /// Create a Poisson distribution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mean `lambda` is not positive.
+    pub fn poisson(lambda: f64) -> Result<Self, String> {
+        if lambda <= 0.0 {
+            return Err("Poisson distribution lambda (mean) must be > 0".to_string());
+        }
+        Ok(Self {
+            dist_type: DistributionType::Poisson { lambda },
+        })
+    }
+
+    /// Create a Gamma distribution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shape` or `scale` is not positive.
+    pub fn gamma(shape: f64, scale: f64) -> Result<Self, String> {
+        if shape <= 0.0 || scale <= 0.0 {
+            return Err("Gamma distribution shape and scale must be > 0".to_string());
+        }
+        Ok(Self {
+            dist_type: DistributionType::Gamma { shape, scale },
+        })
+    }
+
     /// Create a Discrete distribution
     ///
     /// # Errors
@@ -211,6 +292,19 @@ impl Distribution {
                 sample_pert(rng, *min, *mode, *max)
             },
             DistributionType::Lognormal { mean, stdev } => sample_lognormal(rng, *mean, *stdev),
+            DistributionType::Beta { alpha, beta } => sample_beta(rng, *alpha, *beta),
+            DistributionType::Exponential { lambda } => {
+                let dist = Exp::new(*lambda).unwrap();
+                dist.sample(rng)
+            },
+            DistributionType::Poisson { lambda } => {
+                let dist = Poisson::new(*lambda).unwrap();
+                dist.sample(rng)
+            },
+            DistributionType::Gamma { shape, scale } => {
+                let dist = Gamma::new(*shape, *scale).unwrap();
+                dist.sample(rng)
+            },
             DistributionType::Discrete {
                 values,
                 probabilities,
@@ -238,6 +332,10 @@ impl Distribution {
                 let sigma_sq = (variance / (mean * mean)).ln_1p();
                 (mu + sigma_sq / 2.0).exp()
             },
+            DistributionType::Beta { alpha, beta } => alpha / (alpha + beta),
+            DistributionType::Exponential { lambda } => 1.0 / lambda,
+            DistributionType::Poisson { lambda } => *lambda,
+            DistributionType::Gamma { shape, scale } => shape * scale,
             DistributionType::Discrete {
                 values,
                 probabilities,
@@ -271,6 +369,14 @@ impl Distribution {
                 let variance = stdev * stdev;
                 ((variance / (mean * mean)).ln() + 1.0).exp_m1()
             },
+            DistributionType::Beta { alpha, beta } => {
+                let total = alpha + beta;
+                let denominator = total.powi(2) * (total + 1.0);
+                alpha * beta / denominator
+            },
+            DistributionType::Exponential { lambda } => 1.0 / (lambda * lambda),
+            DistributionType::Poisson { lambda } => *lambda,
+            DistributionType::Gamma { shape, scale } => shape * scale * scale,
             DistributionType::Discrete {
                 values,
                 probabilities,
@@ -308,8 +414,6 @@ fn sample_pert<R: Rng>(rng: &mut R, min: f64, mode: f64, max: f64) -> f64 {
 
 /// Sample from Beta distribution using gamma ratio method
 fn sample_beta<R: Rng>(rng: &mut R, alpha: f64, beta: f64) -> f64 {
-    use rand_distr::Gamma;
-
     let gamma_a = Gamma::new(alpha, 1.0).unwrap();
     let gamma_b = Gamma::new(beta, 1.0).unwrap();
 
@@ -413,6 +517,30 @@ pub fn parse_distribution(formula: &str) -> Result<Distribution, String> {
             }
             Distribution::lognormal(args[0], args[1])
         },
+        "BETA" => {
+            if args.len() != 2 {
+                return Err("MC.Beta requires 2 arguments: (alpha, beta)".to_string());
+            }
+            Distribution::beta(args[0], args[1])
+        },
+        "EXPONENTIAL" => {
+            if args.len() != 1 {
+                return Err("MC.Exponential requires 1 argument: (lambda)".to_string());
+            }
+            Distribution::exponential(args[0])
+        },
+        "POISSON" => {
+            if args.len() != 1 {
+                return Err("MC.Poisson requires 1 argument: (lambda)".to_string());
+            }
+            Distribution::poisson(args[0])
+        },
+        "GAMMA" => {
+            if args.len() != 2 {
+                return Err("MC.Gamma requires 2 arguments: (shape, scale)".to_string());
+            }
+            Distribution::gamma(args[0], args[1])
+        },
         _ => Err(format!("Unknown distribution type: {func_name}")),
     }
 }
@@ -497,6 +625,37 @@ mod tests {
         assert!(samples.iter().all(|&x| (0.0..=10.0).contains(&x)));
     }
 
+    #[test]
+    fn test_beta_exponential_poisson_gamma_means_converge() {
+        let cases = [
+            Distribution::beta(2.0, 5.0).unwrap(),
+            Distribution::exponential(0.25).unwrap(),
+            Distribution::poisson(3.5).unwrap(),
+            Distribution::gamma(2.0, 3.0).unwrap(),
+        ];
+        for dist in cases {
+            let mut rng = seeded_rng();
+            let samples: Vec<f64> = dist.sample_n(&mut rng, 20000);
+            let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+            let expected = dist.theoretical_mean();
+            // Within 4 standard errors of the theoretical mean
+            let tolerance = 4.0 * (dist.theoretical_variance() / samples.len() as f64).sqrt();
+            assert!(
+                (mean - expected).abs() < tolerance,
+                "{}: mean {mean} not close to {expected}",
+                dist.dist_type
+            );
+        }
+
+        let mut rng = seeded_rng();
+        let beta = Distribution::beta(2.0, 5.0)
+            .unwrap()
+            .sample_n(&mut rng, 1000);
+        assert!(beta.iter().all(|&x| (0.0..=1.0).contains(&x)));
+        let poisson = Distribution::poisson(3.5).unwrap().sample_n(&mut rng, 1000);
+        assert!(poisson.iter().all(|&k| k >= 0.0 && k.fract() == 0.0));
+    }
+
     #[test]
     fn test_parse_distribution() {
         let dist = parse_distribution("MC.Normal(100, 15)").unwrap();
@@ -536,6 +695,30 @@ mod tests {
                 max: 10.0
             }
         ));
+
+        let dist = parse_distribution("MC.Beta(2, 5)").unwrap();
+        assert_eq!(
+            dist.dist_type,
+            DistributionType::Beta {
+                alpha: 2.0,
+                beta: 5.0
+            }
+        );
+        let dist = parse_distribution("MC.Exponential(0.5)").unwrap();
+        assert_eq!(
+            dist.dist_type,
+            DistributionType::Exponential { lambda: 0.5 }
+        );
+        let dist = parse_distribution("MC.Poisson(4)").unwrap();
+        assert_eq!(dist.dist_type, DistributionType::Poisson { lambda: 4.0 });
+        let dist = parse_distribution("MC.Gamma(2, 1.5)").unwrap();
+        assert_eq!(
+            dist.dist_type,
+            DistributionType::Gamma {
+                shape: 2.0,
+                scale: 1.5
+            }
+        );
     }
 
     #[test]
@@ -545,6 +728,10 @@ mod tests {
         assert!(parse_distribution("MC.Normal(100, -5)").is_err()); // Negative stdev
         assert!(parse_distribution("MC.Triangular(10, 5, 0)").is_err()); // min > max
         assert!(parse_distribution("MC.Unknown(1, 2)").is_err()); // Unknown type
+        assert!(parse_distribution("MC.Beta(0, 2)").is_err()); // Non-positive shape
+        assert!(parse_distribution("MC.Exponential(-1)").is_err()); // Negative rate
+        assert!(parse_distribution("MC.Poisson(2, 3)").is_err()); // Wrong arg count
+        assert!(parse_distribution("MC.Gamma(2, 0)").is_err()); // Zero scale
     }
 
     #[test]
@@ -7,15 +7,22 @@
 //! 4. Compute output statistics
 
 use std::collections::HashMap;
+use std::ops::Range;
 use std::str::FromStr;
 use std::time::Instant;
 
-use super::config::MonteCarloConfig;
+use rayon::prelude::*;
+
+use super::config::{ConvergenceConfig, MonteCarloConfig};
+use super::correlation::CorrelationMatrix;
 use super::distributions::{parse_distribution, Distribution};
 use super::sampler::{Sampler, SamplingMethod};
-use super::statistics::{evaluate_threshold, parse_threshold, Histogram, Statistics};
+use super::statistics::{Histogram, Statistics, Threshold};
 use crate::types::ParsedModel;
 
+/// Iterations evaluated between output collections when early stopping is off
+const EVALUATION_CHUNK: usize = 4096;
+
 /// Result of a Monte Carlo simulation
 #[derive(Debug, Clone)]
 pub struct SimulationResult {
@@ -40,6 +47,8 @@ pub struct OutputResult {
     pub statistics: Statistics,
     /// All simulated values
     pub samples: Vec<f64>,
+    /// Standard error of the mean (std dev / sqrt(iterations))
+    pub standard_error: f64,
     /// Histogram data
     pub histogram: Histogram,
     /// Probability thresholds (threshold string -> probability)
@@ -51,6 +60,7 @@ pub struct MonteCarloEngine {
     config: MonteCarloConfig,
     sampler: Sampler,
     distributions: HashMap<String, Distribution>,
+    correlation: Option<CorrelationMatrix>,
 }
 
 impl MonteCarloEngine {
@@ -58,17 +68,20 @@ impl MonteCarloEngine {
     ///
     /// # Errors
     ///
-    /// Returns an error if the configuration is invalid (see [`MonteCarloConfig::validate`]).
+    /// Returns an error if the configuration is invalid (see [`MonteCarloConfig::validate`]),
+    /// including correlations that do not form a valid correlation matrix.
     pub fn new(config: MonteCarloConfig) -> Result<Self, String> {
         config.validate()?;
 
         let method = SamplingMethod::from_str(&config.sampling)?;
         let sampler = Sampler::new(method, config.seed);
+        let correlation = config.correlation_matrix()?;
 
         Ok(Self {
             config,
             sampler,
             distributions: HashMap::new(),
+            correlation,
         })
     }
 
@@ -99,22 +112,33 @@ impl MonteCarloEngine {
         Ok(())
     }
 
+    /// Draw `n` samples per distribution, correlated as configured
+    fn sample_inputs(&self, n: usize) -> Result<HashMap<String, Vec<f64>>, String> {
+        let mut input_samples: HashMap<String, Vec<f64>> = self
+            .distributions
+            .iter()
+            .map(|(var_name, dist)| {
+                let samples = dist.sample_n(&mut self.sampler.substream(var_name), n);
+                (var_name.clone(), samples)
+            })
+            .collect();
+        if let Some(matrix) = &self.correlation {
+            self.sampler.correlate(&mut input_samples, matrix)?;
+        }
+        Ok(input_samples)
+    }
+
     /// Run the simulation
     ///
     /// # Errors
     ///
-    /// Returns an error if output variable samples cannot be resolved.
+    /// Returns an error if output variable samples cannot be resolved, or a
+    /// correlated variable has no distribution.
     pub fn run(&mut self) -> Result<SimulationResult, String> {
         let start = Instant::now();
         let n = self.config.iterations;
 
-        // Generate samples for each distribution
-        let mut input_samples: HashMap<String, Vec<f64>> = HashMap::new();
-
-        for (var_name, dist) in &self.distributions {
-            let samples = dist.sample_n(self.sampler.rng_mut(), n);
-            input_samples.insert(var_name.clone(), samples);
-        }
+        let input_samples = self.sample_inputs(n)?;
 
         // For now, output results are the same as input samples
         // (Full formula evaluation will be added when integrating with calculator)
@@ -134,15 +158,15 @@ impl MonteCarloEngine {
             // Calculate statistics
             let statistics = Statistics::from_samples(&samples);
 
-            // Create histogram (50 bins default)
-            let histogram = Histogram::from_samples(&samples, 50);
+            // Create histogram
+            let histogram = Histogram::from_samples(&samples, self.config.histogram_bins);
 
             // Evaluate thresholds
             let mut threshold_probabilities = HashMap::new();
             if let Some(threshold_str) = &output_config.threshold {
-                if let Ok((op, value)) = parse_threshold(threshold_str) {
-                    let prob = evaluate_threshold(&samples, &op, value);
-                    threshold_probabilities.insert(threshold_str.clone(), prob);
+                if let Ok(threshold) = Threshold::parse(threshold_str) {
+                    threshold_probabilities
+                        .insert(threshold_str.clone(), threshold.probability(&samples));
                 }
             }
 
@@ -150,6 +174,7 @@ impl MonteCarloEngine {
                 var.clone(),
                 OutputResult {
                     variable: var.clone(),
+                    standard_error: standard_error(&statistics, &samples),
                     statistics,
                     samples,
                     histogram,
@@ -177,52 +202,96 @@ impl MonteCarloEngine {
     ///
     /// # Errors
     ///
-    /// Returns an error if output variable samples cannot be resolved.
-    ///
-    /// # Panics
-    ///
-    /// Panics if an output variable configured in config is missing from `output_samples`.
+    /// Returns an error if output variable samples cannot be resolved, or a
+    /// correlated variable has no distribution.
     pub fn run_with_evaluator<F>(&mut self, mut evaluator: F) -> Result<SimulationResult, String>
     where
         F: FnMut(&HashMap<String, f64>) -> HashMap<String, f64>,
+    {
+        self.run_in_chunks(|inputs, rows| {
+            rows.map(|i| evaluator(&iteration_inputs(inputs, i)))
+                .collect()
+        })
+    }
+
+    /// Run simulation with a custom evaluator, spreading iterations over threads
+    ///
+    /// Inputs are drawn before any evaluation from per-variable seeded streams,
+    /// and outputs are collected in iteration order, so a fixed seed gives the
+    /// same result as [`Self::run_with_evaluator`] on any number of threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if output variable samples cannot be resolved, or a
+    /// correlated variable has no distribution.
+    pub fn run_parallel<F>(&mut self, evaluator: F) -> Result<SimulationResult, String>
+    where
+        F: Fn(&HashMap<String, f64>) -> HashMap<String, f64> + Sync,
+    {
+        self.run_in_chunks(|inputs, rows| {
+            rows.into_par_iter()
+                .map(|i| evaluator(&iteration_inputs(inputs, i)))
+                .collect()
+        })
+    }
+
+    /// Evaluate iterations chunk by chunk, checking convergence between chunks
+    ///
+    /// With early stopping a chunk is one check interval, so the simulation
+    /// stops at exactly the iteration a one-at-a-time loop would.
+    fn run_in_chunks<E>(&self, mut evaluate: E) -> Result<SimulationResult, String>
+    where
+        E: FnMut(&HashMap<String, Vec<f64>>, Range<usize>) -> Vec<HashMap<String, f64>>,
     {
         let start = Instant::now();
         let n = self.config.iterations;
 
-        // Generate samples for each distribution
-        let mut input_samples: HashMap<String, Vec<f64>> = HashMap::new();
-        for (var_name, dist) in &self.distributions {
-            let samples = dist.sample_n(self.sampler.rng_mut(), n);
-            input_samples.insert(var_name.clone(), samples);
-        }
+        let mut input_samples = self.sample_inputs(n)?;
 
-        // Initialize output sample storage
         let output_vars: Vec<String> = self
             .config
             .outputs
             .iter()
             .map(|o| o.variable.clone())
             .collect();
-        let mut output_samples: HashMap<String, Vec<f64>> = output_vars
-            .iter()
-            .map(|v| (v.clone(), Vec::with_capacity(n)))
-            .collect();
+        let mut output_columns: Vec<Vec<f64>> =
+            output_vars.iter().map(|_| Vec::with_capacity(n)).collect();
 
-        // Run iterations
-        for i in 0..n {
-            // Collect input values for this iteration
-            let mut inputs: HashMap<String, f64> = HashMap::new();
-            for (var, samples) in &input_samples {
-                inputs.insert(var.clone(), samples[i]);
+        let mut monitor = self
+            .config
+            .convergence
+            .as_ref()
+            .map(|c| ConvergenceMonitor::new(c, output_vars.len()));
+        let chunk_size = self
+            .config
+            .convergence
+            .as_ref()
+            .map_or(EVALUATION_CHUNK, |c| c.check_interval);
+
+        let mut completed = 0;
+        'chunks: while completed < n {
+            let rows = completed..n.min(completed + chunk_size);
+            for outputs in evaluate(&input_samples, rows) {
+                let values: Vec<f64> = output_vars
+                    .iter()
+                    .map(|v| outputs.get(v).copied().unwrap_or(0.0))
+                    .collect();
+                for (column, &value) in output_columns.iter_mut().zip(&values) {
+                    column.push(value);
+                }
+                completed += 1;
+                if monitor.as_mut().is_some_and(|m| m.record(&values)) {
+                    break 'chunks;
+                }
             }
+        }
+        let output_samples: HashMap<String, Vec<f64>> =
+            output_vars.into_iter().zip(output_columns).collect();
 
-            // Evaluate
-            let outputs = evaluator(&inputs);
-
-            // Store output values
-            for var in &output_vars {
-                let value = outputs.get(var).copied().unwrap_or(0.0);
-                output_samples.get_mut(var).unwrap().push(value);
+        // Drop pre-generated inputs that were never evaluated
+        if completed < n {
+            for samples in input_samples.values_mut() {
+                samples.truncate(completed);
             }
         }
 
@@ -233,13 +302,13 @@ impl MonteCarloEngine {
             let samples = output_samples.get(var).cloned().unwrap_or_default();
 
             let statistics = Statistics::from_samples(&samples);
-            let histogram = Histogram::from_samples(&samples, 50);
+            let histogram = Histogram::from_samples(&samples, self.config.histogram_bins);
 
             let mut threshold_probabilities = HashMap::new();
             if let Some(threshold_str) = &output_config.threshold {
-                if let Ok((op, value)) = parse_threshold(threshold_str) {
-                    let prob = evaluate_threshold(&samples, &op, value);
-                    threshold_probabilities.insert(threshold_str.clone(), prob);
+                if let Ok(threshold) = Threshold::parse(threshold_str) {
+                    threshold_probabilities
+                        .insert(threshold_str.clone(), threshold.probability(&samples));
                 }
             }
 
@@ -247,6 +316,7 @@ impl MonteCarloEngine {
                 var.clone(),
                 OutputResult {
                     variable: var.clone(),
+                    standard_error: standard_error(&statistics, &samples),
                     statistics,
                     samples,
                     histogram,
@@ -261,7 +331,7 @@ impl MonteCarloEngine {
 
         Ok(SimulationResult {
             config: self.config.clone(),
-            iterations_completed: n,
+            iterations_completed: completed,
             execution_time_ms,
             outputs,
             input_samples,
@@ -280,6 +350,98 @@ impl MonteCarloEngine {
     }
 }
 
+/// Tracks running output means and decides when a simulation has converged
+struct ConvergenceMonitor {
+    tolerance: f64,
+    check_interval: usize,
+    consecutive_checks: usize,
+    min_iterations: usize,
+    relative_standard_error: Option<f64>,
+    /// Running mean of each output (Welford)
+    means: Vec<f64>,
+    /// Running sum of squared deviations of each output (Welford)
+    squared_deviations: Vec<f64>,
+    previous_means: Option<Vec<f64>>,
+    iterations: usize,
+    streak: usize,
+}
+
+impl ConvergenceMonitor {
+    fn new(config: &ConvergenceConfig, num_outputs: usize) -> Self {
+        Self {
+            tolerance: config.tolerance,
+            check_interval: config.check_interval,
+            consecutive_checks: config.consecutive_checks,
+            min_iterations: config.min_iterations,
+            relative_standard_error: config.relative_standard_error,
+            means: vec![0.0; num_outputs],
+            squared_deviations: vec![0.0; num_outputs],
+            previous_means: None,
+            iterations: 0,
+            streak: 0,
+        }
+    }
+
+    /// Record one iteration's outputs; returns true once the simulation may stop
+    fn record(&mut self, values: &[f64]) -> bool {
+        self.iterations += 1;
+        #[allow(clippy::cast_precision_loss)] // iteration counts are capped at 1,000,000
+        let count = self.iterations as f64;
+        for ((mean, squared), value) in self
+            .means
+            .iter_mut()
+            .zip(self.squared_deviations.iter_mut())
+            .zip(values)
+        {
+            let delta = value - *mean;
+            *mean += delta / count;
+            *squared += delta * (value - *mean);
+        }
+
+        if !self.iterations.is_multiple_of(self.check_interval) {
+            return false;
+        }
+
+        if let Some(target) = self.relative_standard_error {
+            let precise = self
+                .means
+                .iter()
+                .zip(&self.squared_deviations)
+                .all(|(mean, squared)| (squared / count / count).sqrt() <= target * mean.abs());
+            return self.iterations >= self.min_iterations && precise;
+        }
+
+        if let Some(previous) = &self.previous_means {
+            let stable = self.means.iter().zip(previous).all(|(mean, prev)| {
+                let change = (mean - prev).abs() / prev.abs().max(f64::EPSILON);
+                change < self.tolerance
+            });
+            self.streak = if stable { self.streak + 1 } else { 0 };
+        }
+        self.previous_means = Some(self.means.clone());
+
+        self.iterations >= self.min_iterations && self.streak >= self.consecutive_checks
+    }
+}
+
+/// Input values of iteration `i`
+fn iteration_inputs(input_samples: &HashMap<String, Vec<f64>>, i: usize) -> HashMap<String, f64> {
+    input_samples
+        .iter()
+        .map(|(var, samples)| (var.clone(), samples[i]))
+        .collect()
+}
+
+/// Standard error of the mean of `samples`
+fn standard_error(statistics: &Statistics, samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    #[allow(clippy::cast_precision_loss)] // iteration counts are capped at 1,000,000
+    let count = samples.len() as f64;
+    statistics.std_dev / count.sqrt()
+}
+
 impl SimulationResult {
     /// Format results as YAML string
     #[must_use]
@@ -302,6 +464,7 @@ impl SimulationResult {
             let _ = writeln!(output, "      mean: {:.4}", result.statistics.mean);
             let _ = writeln!(output, "      median: {:.4}", result.statistics.median);
             let _ = writeln!(output, "      std_dev: {:.4}", result.statistics.std_dev);
+            let _ = writeln!(output, "      standard_error: {:.6}", result.standard_error);
             let _ = writeln!(output, "      min: {:.4}", result.statistics.min);
             let _ = writeln!(output, "      max: {:.4}", result.statistics.max);
 
@@ -350,6 +513,7 @@ impl SimulationResult {
                     "mean": result.statistics.mean,
                     "median": result.statistics.median,
                     "std_dev": result.statistics.std_dev,
+                    "standard_error": result.standard_error,
                     "min": result.statistics.min,
                     "max": result.statistics.max,
                     "percentiles": percentiles,
@@ -390,6 +554,9 @@ mod tests {
                 label: None,
             }],
             correlations: vec![],
+            correlation_matrix: None,
+            histogram_bins: 50,
+            convergence: None,
         }
     }
 
@@ -445,6 +612,9 @@ mod tests {
                 label: None,
             }],
             correlations: vec![],
+            correlation_matrix: None,
+            histogram_bins: 50,
+            convergence: None,
         };
 
         let mut engine = MonteCarloEngine::new(config).unwrap();
@@ -471,6 +641,188 @@ mod tests {
         assert!(*prob > 0.9);
     }
 
+    #[test]
+    fn test_convergence_early_stop() {
+        let config = MonteCarloConfig {
+            enabled: true,
+            iterations: 100_000,
+            sampling: "monte_carlo".to_string(),
+            seed: Some(42),
+            outputs: vec![OutputConfig {
+                variable: "price".to_string(),
+                percentiles: vec![50],
+                threshold: None,
+                label: None,
+            }],
+            correlations: vec![],
+            correlation_matrix: None,
+            histogram_bins: 50,
+            convergence: Some(ConvergenceConfig {
+                tolerance: 0.0001,
+                check_interval: 500,
+                consecutive_checks: 3,
+                min_iterations: 2000,
+                relative_standard_error: None,
+            }),
+        };
+
+        let mut engine = MonteCarloEngine::new(config).unwrap();
+        engine.add_distribution("price", Distribution::normal(100.0, 0.5).unwrap());
+
+        let result = engine
+            .run_with_evaluator(|inputs| {
+                let mut outputs = HashMap::new();
+                outputs.insert("price".to_string(), inputs["price"]);
+                outputs
+            })
+            .unwrap();
+
+        // Low variance: the mean settles long before the 100k cap, but never
+        // before the configured minimum
+        assert!(result.iterations_completed >= 2000);
+        assert!(
+            result.iterations_completed < 10_000,
+            "expected early stop, ran {} iterations",
+            result.iterations_completed
+        );
+        assert_eq!(result.iterations_completed % 500, 0);
+        assert_eq!(
+            result.outputs["price"].samples.len(),
+            result.iterations_completed
+        );
+        assert_eq!(
+            result.input_samples["price"].len(),
+            result.iterations_completed
+        );
+        assert!((result.outputs["price"].statistics.mean - 100.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_convergence_relative_standard_error() {
+        let run = |convergence: Option<ConvergenceConfig>| {
+            let config = MonteCarloConfig {
+                iterations: 100_000,
+                sampling: "monte_carlo".to_string(),
+                outputs: vec![OutputConfig {
+                    variable: "revenue".to_string(),
+                    percentiles: vec![50],
+                    threshold: None,
+                    label: None,
+                }],
+                convergence,
+                ..test_config()
+            };
+            let mut engine = MonteCarloEngine::new(config).unwrap();
+            engine.add_distribution("revenue", Distribution::normal(100.0, 20.0).unwrap());
+            engine
+                .run_with_evaluator(|inputs| {
+                    HashMap::from([("revenue".to_string(), inputs["revenue"])])
+                })
+                .unwrap()
+        };
+
+        // SE / mean = (20 / sqrt(n)) / 100 <= 0.002 once n >= 10,000
+        let result = run(Some(ConvergenceConfig {
+            check_interval: 1000,
+            relative_standard_error: Some(0.002),
+            ..ConvergenceConfig::default()
+        }));
+        assert!(
+            (9_000..=12_000).contains(&result.iterations_completed),
+            "stopped after {} iterations",
+            result.iterations_completed
+        );
+        let revenue = &result.outputs["revenue"];
+        assert!(revenue.standard_error <= 0.002 * revenue.statistics.mean);
+        assert!(result.to_json().unwrap().contains("\"standard_error\""));
+
+        // Without the block every iteration runs
+        let result = run(None);
+        assert_eq!(result.iterations_completed, 100_000);
+        let se = result.outputs["revenue"].standard_error;
+        assert!(
+            (se - 20.0 / 100_000f64.sqrt()).abs() < 0.005,
+            "standard error {se}"
+        );
+    }
+
+    #[test]
+    fn test_parallel_matches_serial() {
+        let evaluate = |inputs: &HashMap<String, f64>| {
+            let profit = inputs["revenue"].mul_add(1.0 - inputs["margin_drag"], -inputs["costs"]);
+            HashMap::from([("profit".to_string(), profit)])
+        };
+        let run = |parallel: bool, convergence: Option<ConvergenceConfig>| {
+            let config = MonteCarloConfig {
+                iterations: 20_000,
+                outputs: vec![OutputConfig {
+                    variable: "profit".to_string(),
+                    percentiles: vec![5, 50, 95],
+                    threshold: Some("> 0".to_string()),
+                    label: None,
+                }],
+                convergence,
+                ..test_config()
+            };
+            let mut engine = MonteCarloEngine::new(config).unwrap();
+            engine.add_distribution("revenue", Distribution::normal(100.0, 15.0).unwrap());
+            engine.add_distribution("costs", Distribution::triangular(60.0, 75.0, 95.0).unwrap());
+            engine.add_distribution("margin_drag", Distribution::beta(2.0, 20.0).unwrap());
+            if parallel {
+                engine.run_parallel(evaluate).unwrap()
+            } else {
+                engine.run_with_evaluator(evaluate).unwrap()
+            }
+        };
+
+        let serial = run(false, None);
+        let parallel = run(true, None);
+        assert_eq!(parallel.iterations_completed, 20_000);
+        assert_eq!(
+            serial.outputs["profit"].statistics.percentiles,
+            parallel.outputs["profit"].statistics.percentiles
+        );
+        assert_eq!(
+            serial.outputs["profit"].samples,
+            parallel.outputs["profit"].samples
+        );
+
+        // Early stopping lands on the same iteration
+        let convergence = ConvergenceConfig {
+            check_interval: 250,
+            min_iterations: 500,
+            ..ConvergenceConfig::default()
+        };
+        let serial = run(false, Some(convergence.clone()));
+        let parallel = run(true, Some(convergence));
+        assert!(serial.iterations_completed < 20_000);
+        assert_eq!(serial.iterations_completed, parallel.iterations_completed);
+        assert_eq!(
+            serial.outputs["profit"].statistics.percentiles,
+            parallel.outputs["profit"].statistics.percentiles
+        );
+    }
+
+    #[test]
+    fn test_unrelated_variable_does_not_shift_stream() {
+        let config = test_config();
+
+        let mut engine1 = MonteCarloEngine::new(config.clone()).unwrap();
+        engine1.add_distribution("revenue", Distribution::normal(100.0, 10.0).unwrap());
+        let result1 = engine1.run().unwrap();
+
+        let mut engine2 = MonteCarloEngine::new(config).unwrap();
+        engine2.add_distribution("marketing", Distribution::uniform(0.0, 1.0).unwrap());
+        engine2.add_distribution("revenue", Distribution::normal(100.0, 10.0).unwrap());
+        engine2.add_distribution("headcount", Distribution::normal(50.0, 5.0).unwrap());
+        let result2 = engine2.run().unwrap();
+
+        assert_eq!(
+            result1.input_samples["revenue"],
+            result2.input_samples["revenue"]
+        );
+    }
+
     #[test]
     fn test_output_yaml() {
         let config = test_config();
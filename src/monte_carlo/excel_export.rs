@@ -303,6 +303,9 @@ mod tests {
                 label: None,
             }],
             correlations: vec![],
+            correlation_matrix: None,
+            histogram_bins: 50,
+            convergence: None,
         };
 
         let mut engine = MonteCarloEngine::new(config).unwrap();
@@ -1,7 +1,8 @@
 //! Monte Carlo Simulation Module (Enterprise Only)
 //!
 //! Provides probabilistic analysis capabilities for FP&A:
-//! - Distribution sampling (Normal, Triangular, Uniform, PERT, Lognormal)
+//! - Distribution sampling (Normal, Triangular, Uniform, PERT, Lognormal, Beta,
+//!   Exponential, Poisson, Gamma)
 //! - Latin Hypercube and Monte Carlo sampling methods
 //! - Statistical output (percentiles, probability thresholds)
 //! - Sensitivity analysis via correlation coefficients
@@ -36,7 +37,7 @@ pub mod sensitivity;
 pub mod statistics;
 
 // Re-exports
-pub use config::MonteCarloConfig;
+pub use config::{ConvergenceConfig, MonteCarloConfig};
 pub use correlation::CorrelationMatrix;
 pub use distributions::{Distribution, DistributionType};
 pub use engine::{MonteCarloEngine, SimulationResult};
@@ -3,9 +3,17 @@
 //! Supports:
 //! - Monte Carlo (pure random sampling)
 //! - Latin Hypercube (stratified sampling, 5x faster convergence)
+//!
+//! Correlated inputs are handled after sampling by reordering each variable's
+//! samples (Iman-Conover), which keeps every marginal distribution intact.
+
+use std::collections::HashMap;
 
 use rand::rngs::StdRng;
 use rand::{RngExt, SeedableRng};
+use rand_distr::{Distribution as _, StandardNormal};
+
+use super::correlation::CorrelationMatrix;
 
 /// Sampling method enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,14 +51,83 @@ impl std::fmt::Display for SamplingMethod {
 pub struct Sampler {
     method: SamplingMethod,
     rng: StdRng,
+    base_seed: u64,
 }
 
 impl Sampler {
     /// Create a new sampler with the given method and optional seed
     #[must_use]
     pub fn new(method: SamplingMethod, seed: Option<u64>) -> Self {
-        let rng = seed.map_or_else(|| StdRng::from_rng(&mut rand::rng()), StdRng::seed_from_u64);
-        Self { method, rng }
+        let base_seed = seed.unwrap_or_else(|| rand::rng().random());
+        let rng = StdRng::seed_from_u64(base_seed);
+        Self {
+            method,
+            rng,
+            base_seed,
+        }
+    }
+
+    /// Independent RNG stream for a named variable
+    ///
+    /// The stream depends only on the sampler's seed and the variable name, so
+    /// with a fixed seed a variable draws the same sequence regardless of which
+    /// other variables exist (common random numbers for paired comparisons).
+    #[must_use]
+    pub fn substream(&self, name: &str) -> StdRng {
+        StdRng::seed_from_u64(splitmix64(self.base_seed ^ fnv1a(name)))
+    }
+
+    /// Reorder sampled columns to induce the rank correlation in `matrix`
+    ///
+    /// Standard normal scores are drawn for every correlated variable and
+    /// combined row by row with the matrix's Cholesky factor; each variable's
+    /// samples are then rearranged to follow the ranks of its scores. Values
+    /// are only permuted, so each column keeps its distribution.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a correlated variable has no samples, or the columns
+    /// differ in length.
+    pub fn correlate(
+        &self,
+        samples: &mut HashMap<String, Vec<f64>>,
+        matrix: &CorrelationMatrix,
+    ) -> Result<(), String> {
+        let mut columns = Vec::with_capacity(matrix.dim());
+        for var in &matrix.variables {
+            let column = samples
+                .get(var)
+                .ok_or_else(|| format!("correlated variable '{var}' has no MC.* distribution"))?;
+            columns.push(column.clone());
+        }
+        let n = columns.first().map_or(0, Vec::len);
+        if columns.iter().any(|c| c.len() != n) {
+            return Err("correlated variables must have the same number of samples".to_string());
+        }
+
+        // '#' cannot appear in a variable name, so this never shares a stream
+        let mut rng = self.substream("#correlation");
+        let mut scores = vec![Vec::with_capacity(n); matrix.dim()];
+        for _ in 0..n {
+            let independent: Vec<f64> = (0..matrix.dim())
+                .map(|_| StandardNormal.sample(&mut rng))
+                .collect();
+            for (score, value) in scores.iter_mut().zip(matrix.correlate(&independent)?) {
+                score.push(value);
+            }
+        }
+
+        for ((var, mut column), score) in matrix.variables.iter().zip(columns).zip(scores) {
+            column.sort_by(f64::total_cmp);
+            let mut order: Vec<usize> = (0..n).collect();
+            order.sort_by(|&a, &b| score[a].total_cmp(&score[b]));
+            let mut reordered = vec![0.0; n];
+            for (rank, &row) in order.iter().enumerate() {
+                reordered[row] = column[rank];
+            }
+            samples.insert(var.clone(), reordered);
+        }
+        Ok(())
     }
 
     /// Get the sampling method
@@ -117,6 +194,21 @@ impl Sampler {
     }
 }
 
+/// FNV-1a hash; stable across platforms and Rust releases, unlike `DefaultHasher`
+fn fnv1a(name: &str) -> u64 {
+    name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// `SplitMix64` finalizer to decorrelate nearby seeds
+const fn splitmix64(mut z: u64) -> u64 {
+    z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
 /// Statistics about a sample set
 #[derive(Debug, Clone)]
 pub struct SampleStats {
@@ -274,6 +366,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_substreams_are_stable_and_distinct() {
+        let draw = |sampler: &Sampler, name: &str| -> Vec<f64> {
+            let mut rng = sampler.substream(name);
+            (0..10).map(|_| rng.random()).collect()
+        };
+
+        let sampler = Sampler::new(SamplingMethod::MonteCarlo, Some(42));
+        let revenue = draw(&sampler, "revenue");
+        assert_eq!(revenue, draw(&sampler, "revenue"));
+        assert_ne!(revenue, draw(&sampler, "costs"));
+
+        let reseeded = Sampler::new(SamplingMethod::MonteCarlo, Some(43));
+        assert_ne!(revenue, draw(&reseeded, "revenue"));
+    }
+
+    #[test]
+    fn test_correlate_induces_requested_correlation() {
+        use crate::monte_carlo::distributions::Distribution;
+        use crate::monte_carlo::sensitivity::spearman_correlation;
+
+        let sampler = Sampler::new(SamplingMethod::MonteCarlo, Some(7));
+        let revenue = Distribution::normal(100.0, 15.0)
+            .unwrap()
+            .sample_n(&mut sampler.substream("revenue"), 5000);
+        let costs = Distribution::triangular(40.0, 50.0, 70.0)
+            .unwrap()
+            .sample_n(&mut sampler.substream("costs"), 5000);
+        assert!(spearman_correlation(&revenue, &costs).abs() < 0.05);
+
+        let mut samples = HashMap::from([
+            ("revenue".to_string(), revenue.clone()),
+            ("costs".to_string(), costs),
+        ]);
+        let matrix =
+            CorrelationMatrix::new(&[("revenue".to_string(), "costs".to_string(), 0.7)]).unwrap();
+        sampler.correlate(&mut samples, &matrix).unwrap();
+
+        let rho = spearman_correlation(&samples["revenue"], &samples["costs"]);
+        assert!((rho - 0.7).abs() < 0.05, "rank correlation {rho}");
+
+        // Only the order changes, not the values
+        let mut sorted = samples["revenue"].clone();
+        sorted.sort_by(f64::total_cmp);
+        let mut expected = revenue;
+        expected.sort_by(f64::total_cmp);
+        assert_eq!(sorted, expected);
+
+        samples.remove("costs");
+        let err = sampler.correlate(&mut samples, &matrix).unwrap_err();
+        assert!(err.contains("'costs'"), "{err}");
+    }
+
     #[test]
     fn test_multidimensional_samples() {
         let mut sampler = Sampler::new(SamplingMethod::LatinHypercube, Some(12345));
@@ -271,6 +271,97 @@ impl Histogram {
             .map(|w| f64::midpoint(w[0], w[1]))
             .collect()
     }
+
+    /// Render as a one-line Unicode sparkline, one block character per bin
+    ///
+    /// Bar heights are scaled to the tallest bin; empty bins render as spaces.
+    #[must_use]
+    pub fn sparkline(&self) -> String {
+        const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+        let max_count = self.counts.iter().copied().max().unwrap_or(0);
+        if max_count == 0 {
+            return " ".repeat(self.counts.len());
+        }
+
+        self.counts
+            .iter()
+            .map(|&count| {
+                if count == 0 {
+                    return ' ';
+                }
+                // Non-empty bins map to 1..=8 so a single sample stays visible
+                let level = (count * BLOCKS.len()).div_ceil(max_count);
+                BLOCKS[level.clamp(1, BLOCKS.len()) - 1]
+            })
+            .collect()
+    }
+}
+
+/// A probability threshold on an output distribution
+#[derive(Debug, Clone, PartialEq)]
+pub enum Threshold {
+    /// Single comparison, e.g. `< 0` or `>= 1000000`
+    Compare { operator: String, value: f64 },
+    /// Inclusive range, e.g. `between 100 and 200`
+    Between { low: f64, high: f64 },
+}
+
+impl Threshold {
+    /// Parse a threshold string
+    ///
+    /// Accepts `<`, `<=`, `>`, `>=`, `=` followed by a number, or
+    /// `between <a> and <b>` (inclusive, case-insensitive).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the expected syntax if the string is malformed.
+    pub fn parse(threshold: &str) -> Result<Self, String> {
+        let trimmed = threshold.trim();
+        let lower = trimmed.to_ascii_lowercase();
+
+        if let Some(rest) = lower.strip_prefix("between") {
+            let Some((low_str, high_str)) = rest.split_once(" and ") else {
+                return Err(format!(
+                    "Invalid threshold '{trimmed}': expected 'between <low> and <high>'"
+                ));
+            };
+            let parse_bound = |s: &str| {
+                s.trim().parse::<f64>().map_err(|_| {
+                    format!(
+                        "Invalid threshold '{trimmed}': '{}' is not a number",
+                        s.trim()
+                    )
+                })
+            };
+            let low = parse_bound(low_str)?;
+            let high = parse_bound(high_str)?;
+            if low > high {
+                return Err(format!(
+                    "Invalid threshold '{trimmed}': lower bound {low} exceeds upper bound {high}"
+                ));
+            }
+            return Ok(Self::Between { low, high });
+        }
+
+        let (operator, value) = parse_threshold(trimmed)?;
+        Ok(Self::Compare { operator, value })
+    }
+
+    /// Empirical probability that a sample satisfies this threshold
+    #[must_use]
+    pub fn probability(&self, samples: &[f64]) -> f64 {
+        match self {
+            Self::Compare { operator, value } => evaluate_threshold(samples, operator, *value),
+            Self::Between { low, high } => {
+                if samples.is_empty() {
+                    return 0.0;
+                }
+                let count = samples.iter().filter(|&&x| x >= *low && x <= *high).count();
+                count as f64 / samples.len() as f64
+            },
+        }
+    }
 }
 
 /// Parse threshold string (e.g., "> 0", "< 100000", ">= 50")
@@ -293,7 +384,7 @@ pub fn parse_threshold(threshold: &str) -> Result<(String, f64), String> {
     }
 
     Err(format!(
-        "Invalid threshold format: {threshold}. Use '> 0', '< 100', etc."
+        "Invalid threshold format: {threshold}. Use '> 0', '<= 100', 'between 10 and 20', etc."
     ))
 }
 
@@ -389,6 +480,30 @@ mod tests {
         assert!((freq_sum - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_histogram_sparkline() {
+        // Extra samples pile up around 5, making that bin the mode
+        let mut samples: Vec<f64> = (0..10).map(f64::from).collect();
+        samples.extend([5.2, 5.4, 5.6, 5.8, 4.5]);
+        let hist = Histogram::from_samples(&samples, 10);
+        let line = hist.sparkline();
+
+        assert_eq!(line.chars().count(), 10);
+        let modal_bin = hist
+            .counts
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, &c)| c)
+            .map(|(i, _)| i)
+            .unwrap();
+        let tallest = line
+            .chars()
+            .position(|c| c == '█')
+            .expect("tallest bar should be a full block");
+        assert_eq!(tallest, modal_bin);
+        assert_eq!(line.chars().filter(|&c| c == '█').count(), 1);
+    }
+
     #[test]
     fn test_parse_threshold() {
         let (op, val) = parse_threshold("> 0").unwrap();
@@ -406,6 +521,55 @@ mod tests {
         assert!(parse_threshold("invalid").is_err());
     }
 
+    #[test]
+    fn test_threshold_lower_tail() {
+        let samples = vec![-2.0, -1.0, 0.0, 1.0, 2.0];
+        let threshold = Threshold::parse("< 0").unwrap();
+        assert_eq!(threshold.probability(&samples), 0.4);
+    }
+
+    #[test]
+    fn test_threshold_upper_tail_inclusive() {
+        let samples = vec![500_000.0, 999_999.0, 1_000_000.0, 2_000_000.0];
+        let threshold = Threshold::parse(">= 1000000").unwrap();
+        assert_eq!(
+            threshold,
+            Threshold::Compare {
+                operator: ">=".to_string(),
+                value: 1_000_000.0
+            }
+        );
+        assert_eq!(threshold.probability(&samples), 0.5);
+    }
+
+    #[test]
+    fn test_threshold_between() {
+        let samples: Vec<f64> = (1..=10).map(f64::from).collect();
+        let threshold = Threshold::parse("between 3 and 6").unwrap();
+        assert_eq!(
+            threshold,
+            Threshold::Between {
+                low: 3.0,
+                high: 6.0
+            }
+        );
+        assert_eq!(threshold.probability(&samples), 0.4); // 3, 4, 5, 6
+
+        assert!(Threshold::parse("Between -1.5 AND 2").is_ok());
+    }
+
+    #[test]
+    fn test_threshold_invalid_syntax() {
+        let err = Threshold::parse("between 5").unwrap_err();
+        assert!(err.contains("between <low> and <high>"), "got: {err}");
+        assert!(Threshold::parse("between 9 and 1")
+            .unwrap_err()
+            .contains("exceeds"));
+        assert!(Threshold::parse("between a and 1").is_err());
+        assert!(Threshold::parse("~ 5").is_err());
+        assert!(Threshold::parse("> abc").is_err());
+    }
+
     #[test]
     fn test_evaluate_threshold() {
         let samples = vec![1.0, 2.0, 3.0, 4.0, 5.0];
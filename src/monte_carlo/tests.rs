@@ -21,6 +21,9 @@ fn test_full_simulation_workflow() {
             label: Some("Net Present Value".to_string()),
         }],
         correlations: vec![],
+        correlation_matrix: None,
+        histogram_bins: 50,
+        convergence: None,
     };
 
     let mut engine = MonteCarloEngine::new(config).unwrap();
@@ -103,6 +106,9 @@ fn test_lhs_vs_mc_convergence() {
             label: None,
         }],
         correlations: vec![],
+        correlation_matrix: None,
+        histogram_bins: 50,
+        convergence: None,
     };
 
     let mut mc_engine = MonteCarloEngine::new(mc_config).unwrap();
@@ -122,6 +128,9 @@ fn test_lhs_vs_mc_convergence() {
             label: None,
         }],
         correlations: vec![],
+        correlation_matrix: None,
+        histogram_bins: 50,
+        convergence: None,
     };
 
     let mut lhs_engine = MonteCarloEngine::new(lhs_config).unwrap();
@@ -284,6 +293,9 @@ fn test_yaml_output() {
             label: None,
         }],
         correlations: vec![],
+        correlation_matrix: None,
+        histogram_bins: 50,
+        convergence: None,
     };
 
     let mut engine = MonteCarloEngine::new(config).unwrap();
@@ -321,6 +333,9 @@ fn test_json_output() {
             label: None,
         }],
         correlations: vec![],
+        correlation_matrix: None,
+        histogram_bins: 50,
+        convergence: None,
     };
 
     let mut engine = MonteCarloEngine::new(config).unwrap();
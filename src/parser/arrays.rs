@@ -2,12 +2,18 @@
 //!
 //! Handles parsing of typed column arrays (Number, Text, Date, Boolean).
 
+use crate::core::array_calculator::evaluator::ErrorKind;
 use crate::error::{ForgeError, ForgeResult};
 use crate::types::ColumnValue;
+use chrono::NaiveDate;
 use serde_yaml_ng::Value;
 
 /// Parse a YAML array into a typed `ColumnValue`
 ///
+/// Excel error codes (`#DIV/0!`, ...) that `calculate` writes for error values
+/// are read back into number columns as the error (see [`ErrorKind::to_nan`]);
+/// the column type is detected from the first element that is not an error code.
+///
 /// # Errors
 ///
 /// Returns an error if the array is empty, contains mixed types, or has invalid values.
@@ -18,42 +24,15 @@ pub fn parse_array_value(col_name: &str, seq: &[Value]) -> ForgeResult<ColumnVal
         )));
     }
 
-    // Detect the type from the first element
-    let array_type = detect_array_type(&seq[0])?;
+    // Detect the type from the first element that is not an error code
+    let first = seq
+        .iter()
+        .find(|val| error_code(val).is_none())
+        .unwrap_or(&seq[0]);
+    let array_type = detect_array_type(first)?;
 
     match array_type {
-        "Number" => {
-            let mut numbers = Vec::new();
-            for (i, val) in seq.iter().enumerate() {
-                match val {
-                    Value::Number(n) => {
-                        if let Some(f) = n.as_f64() {
-                            numbers.push(f);
-                        } else {
-                            return Err(ForgeError::Parse(format!(
-                                "Column '{col_name}' row {i}: Invalid number format"
-                            )));
-                        }
-                    },
-                    Value::Null => {
-                        // Provide clear error for null values in numeric arrays
-                        return Err(ForgeError::Parse(format!(
-                            "Column '{col_name}' row {i}: null values not allowed in numeric arrays. \
-                            Use 0 or remove the row if the value is missing."
-                        )));
-                    },
-                    _ => {
-                        return Err(ForgeError::Parse(format!(
-                            "Column '{}' row {}: Expected Number, found {}",
-                            col_name,
-                            i,
-                            type_name(val)
-                        )));
-                    },
-                }
-            }
-            Ok(ColumnValue::Number(numbers))
-        },
+        "Number" => parse_number_array(col_name, seq).map(ColumnValue::Number),
         "Text" => {
             let mut texts = Vec::new();
             for (i, val) in seq.iter().enumerate() {
@@ -77,11 +56,16 @@ pub fn parse_array_value(col_name: &str, seq: &[Value]) -> ForgeResult<ColumnVal
                 match val {
                     Value::String(s) => {
                         // Validate date format (YYYY-MM or YYYY-MM-DD)
-                        if !is_valid_date_format(s) {
+                        if !has_date_shape(s) {
                             return Err(ForgeError::Parse(format!(
                                 "Column '{col_name}' row {i}: Invalid date format '{s}' (expected YYYY-MM or YYYY-MM-DD)"
                             )));
                         }
+                        if !is_valid_date_format(s) {
+                            return Err(ForgeError::Parse(format!(
+                                "Column '{col_name}' row {i}: Invalid date '{s}' (no such day on the calendar)"
+                            )));
+                        }
                         dates.push(s.clone());
                     },
                     _ => {
@@ -119,6 +103,49 @@ pub fn parse_array_value(col_name: &str, seq: &[Value]) -> ForgeResult<ColumnVal
     }
 }
 
+/// Parse the elements of a Number column, reading error codes as error values
+fn parse_number_array(col_name: &str, seq: &[Value]) -> ForgeResult<Vec<f64>> {
+    let mut numbers = Vec::new();
+    for (i, val) in seq.iter().enumerate() {
+        match val {
+            Value::Number(n) => {
+                if let Some(f) = n.as_f64() {
+                    numbers.push(f);
+                } else {
+                    return Err(ForgeError::Parse(format!(
+                        "Column '{col_name}' row {i}: Invalid number format"
+                    )));
+                }
+            },
+            Value::Null => {
+                // Provide clear error for null values in numeric arrays
+                return Err(ForgeError::Parse(format!(
+                    "Column '{col_name}' row {i}: null values not allowed in numeric arrays. \
+                    Use 0 or remove the row if the value is missing."
+                )));
+            },
+            _ => {
+                let Some(kind) = error_code(val) else {
+                    return Err(ForgeError::Parse(format!(
+                        "Column '{}' row {}: Expected Number, found {}",
+                        col_name,
+                        i,
+                        type_name(val)
+                    )));
+                };
+                numbers.push(kind.to_nan());
+            },
+        }
+    }
+    Ok(numbers)
+}
+
+/// The Excel error a YAML value spells, e.g. `#DIV/0!`
+#[must_use]
+pub(super) fn error_code(val: &Value) -> Option<ErrorKind> {
+    val.as_str().and_then(ErrorKind::from_code)
+}
+
 /// Detect the type of a YAML value
 ///
 /// # Errors
@@ -128,8 +155,8 @@ pub fn detect_array_type(val: &Value) -> ForgeResult<&'static str> {
     match val {
         Value::Number(_) => Ok("Number"),
         Value::String(s) => {
-            // Check if it's a date string
-            if is_valid_date_format(s) {
+            // Check if it's a date string (calendar checked when parsing)
+            if has_date_shape(s) {
                 Ok("Date")
             } else {
                 Ok("Text")
@@ -146,9 +173,28 @@ pub fn detect_array_type(val: &Value) -> ForgeResult<&'static str> {
     }
 }
 
-/// Check if a string is a valid date format (YYYY-MM or YYYY-MM-DD)
+/// Check if a string is a valid date (YYYY-MM or YYYY-MM-DD) on the calendar
+///
+/// Month must be 1-12 and the day must exist in that month, so
+/// `2024-02-29` is valid but `2023-02-29` and `2023-13-45` are not.
 #[must_use]
 pub fn is_valid_date_format(s: &str) -> bool {
+    if !has_date_shape(s) {
+        return false;
+    }
+    if s.len() == 7 {
+        return s[5..7]
+            .parse::<u32>()
+            .is_ok_and(|month| (1..=12).contains(&month));
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok()
+}
+
+/// Check if a string is laid out like a date (YYYY-MM or YYYY-MM-DD digits)
+///
+/// Used to detect Date columns, so an impossible date in one is reported
+/// instead of silently turning the column into Text.
+fn has_date_shape(s: &str) -> bool {
     // YYYY-MM format
     if s.len() == 7 {
         let parts: Vec<&str> = s.split('-').collect();
@@ -209,6 +255,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_number_array_with_error_codes() {
+        let yaml_seq: Vec<Value> = vec![
+            Value::String("#DIV/0!".to_string()),
+            Value::Number(2.into()),
+            Value::String("#N/A".to_string()),
+        ];
+        let ColumnValue::Number(nums) = parse_array_value("test_col", &yaml_seq).unwrap() else {
+            panic!("Expected Number array");
+        };
+        assert_eq!(ErrorKind::from_nan(nums[0]), Some(ErrorKind::DivZero));
+        assert!((nums[1] - 2.0).abs() < f64::EPSILON);
+        assert_eq!(ErrorKind::from_nan(nums[2]), Some(ErrorKind::NA));
+
+        // Other text in a number column is still rejected
+        let yaml_seq = vec![Value::Number(1.into()), Value::String("#OOPS".to_string())];
+        assert!(parse_array_value("test_col", &yaml_seq).is_err());
+    }
+
     #[test]
     fn test_parse_text_array() {
         let yaml_seq: Vec<Value> = vec![
@@ -301,6 +366,39 @@ mod tests {
         assert!(!is_valid_date_format("not-a-date"));
     }
 
+    #[test]
+    fn test_date_calendar_validation() {
+        // Feb 29 only exists in leap years (2000 is one, 1900 is not)
+        assert!(is_valid_date_format("2024-02-29"));
+        assert!(is_valid_date_format("2000-02-29"));
+        assert!(!is_valid_date_format("2023-02-29"));
+        assert!(!is_valid_date_format("1900-02-29"));
+        assert!(!is_valid_date_format("2025-04-31"));
+        assert!(!is_valid_date_format("2025-00-10"));
+        assert!(!is_valid_date_format("2025-13"));
+        assert!(!is_valid_date_format("2025-00"));
+    }
+
+    #[test]
+    fn test_impossible_date_names_column_row_and_value() {
+        let yaml_seq: Vec<Value> = vec![
+            Value::String("2024-02-29".to_string()),
+            Value::String("2023-02-29".to_string()),
+        ];
+        let err = parse_array_value("close_date", &yaml_seq)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Column 'close_date' row 1"), "{err}");
+        assert!(err.contains("'2023-02-29'"), "{err}");
+
+        // An impossible first value is still a Date column, not Text
+        let yaml_seq: Vec<Value> = vec![Value::String("2023-13-45".to_string())];
+        let err = parse_array_value("month", &yaml_seq)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("row 0: Invalid date '2023-13-45'"), "{err}");
+    }
+
     #[test]
     fn test_parse_date_format_yyyy_mm_dd() {
         let yaml_seq: Vec<Value> = vec![
@@ -5,13 +5,39 @@
 use crate::error::{ForgeError, ForgeResult};
 use crate::types::{Include, ParsedModel, ResolvedInclude};
 use serde_yaml_ng::Value;
-use std::collections::HashSet;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
+use super::limits::ParseLimits;
 use super::model::parse_v1_model;
 
+/// Included models already parsed during one top-level parse, keyed by canonical path
+///
+/// Lets a file shared by several includes (e.g. a diamond A -> B, C -> D) be read
+/// and parsed once, with every includer receiving the same resolved model.
+#[derive(Debug, Default)]
+pub struct IncludeCache {
+    models: HashMap<PathBuf, ParsedModel>,
+    parses: usize,
+}
+
+impl IncludeCache {
+    /// Create an empty cache
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of included files read and parsed so far (cache misses)
+    #[must_use]
+    pub const fn parse_count(&self) -> usize {
+        self.parses
+    }
+}
+
 /// Resolve all includes in a model, loading and parsing referenced files.
-/// Detects circular dependencies.
+/// Detects circular dependencies. Files already in `cache` are reused rather
+/// than parsed again.
 ///
 /// # Errors
 ///
@@ -20,7 +46,9 @@ use super::model::parse_v1_model;
 pub fn resolve_includes<S: std::hash::BuildHasher>(
     model: &mut ParsedModel,
     base_path: &Path,
-    visited: &mut HashSet<std::path::PathBuf, S>,
+    visited: &mut HashSet<PathBuf, S>,
+    cache: &mut IncludeCache,
+    limits: ParseLimits,
 ) -> ForgeResult<()> {
     let base_dir = base_path.parent().unwrap_or_else(|| Path::new("."));
 
@@ -48,20 +76,34 @@ pub fn resolve_includes<S: std::hash::BuildHasher>(
             )));
         }
 
-        // Parse the included file
-        let content = std::fs::read_to_string(&include_path)?;
-        let yaml: Value = serde_yaml_ng::from_str(&content)?;
-        let mut included_model = parse_v1_model(&yaml)?;
+        let resolved_path = include_path
+            .canonicalize()
+            .unwrap_or_else(|_| include_path.clone());
 
-        // Recursively resolve includes in the included file
-        if !included_model.includes.is_empty() {
-            resolve_includes(&mut included_model, &include_path, visited)?;
-        }
+        let included_model = if let Some(cached) = cache.models.get(&resolved_path) {
+            cached.clone()
+        } else {
+            // Parse the included file
+            let content = std::fs::read_to_string(&include_path)?;
+            let yaml: Value = serde_yaml_ng::from_str(&content)?;
+            let mut included_model = parse_v1_model(&yaml, limits)?;
+            cache.parses += 1;
+
+            // Recursively resolve includes in the included file
+            if !included_model.includes.is_empty() {
+                resolve_includes(&mut included_model, &include_path, visited, cache, limits)?;
+            }
+
+            cache
+                .models
+                .insert(resolved_path.clone(), included_model.clone());
+            included_model
+        };
 
         // Store resolved include
         let resolved = ResolvedInclude {
             include: include.clone(),
-            resolved_path: include_path.canonicalize().unwrap_or(include_path),
+            resolved_path,
             model: included_model,
         };
         model
@@ -154,9 +196,16 @@ main_data:
 
         let content = std::fs::read_to_string(&main_path).unwrap();
         let yaml: Value = serde_yaml_ng::from_str(&content).unwrap();
-        let mut model = parse_v1_model(&yaml).unwrap();
+        let mut model = parse_v1_model(&yaml, ParseLimits::default()).unwrap();
 
-        resolve_includes(&mut model, &main_path, &mut HashSet::new()).unwrap();
+        resolve_includes(
+            &mut model,
+            &main_path,
+            &mut HashSet::new(),
+            &mut IncludeCache::new(),
+            ParseLimits::default(),
+        )
+        .unwrap();
 
         assert!(model.tables.contains_key("main_data"));
         assert!(model.resolved_includes.contains_key("ext"));
@@ -178,9 +227,15 @@ data:
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
         let yaml: Value = serde_yaml_ng::from_str(&content).unwrap();
-        let mut model = parse_v1_model(&yaml).unwrap();
+        let mut model = parse_v1_model(&yaml, ParseLimits::default()).unwrap();
 
-        let result = resolve_includes(&mut model, temp_file.path(), &mut HashSet::new());
+        let result = resolve_includes(
+            &mut model,
+            temp_file.path(),
+            &mut HashSet::new(),
+            &mut IncludeCache::new(),
+            ParseLimits::default(),
+        );
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("not found") || err_msg.contains("nonexistent"));
@@ -225,4 +280,50 @@ data:
             assert!(result.is_err());
         }
     }
+
+    #[test]
+    fn test_diamond_include_parsed_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let write = |name: &str, content: &str| {
+            std::fs::write(temp_dir.path().join(name), content).unwrap();
+        };
+
+        write(
+            "d.yaml",
+            "_forge_version: \"5.0.0\"\nshared:\n  values: [1, 2, 3]\n",
+        );
+        for name in ["b", "c"] {
+            write(
+                &format!("{name}.yaml"),
+                &format!(
+                    "_forge_version: \"5.0.0\"\n_includes:\n  - file: \"d.yaml\"\n    as: \"d\"\n{name}_data:\n  values: [4, 5]\n"
+                ),
+            );
+        }
+        let main_path = temp_dir.path().join("a.yaml");
+        write(
+            "a.yaml",
+            "_forge_version: \"5.0.0\"\n_includes:\n  - file: \"b.yaml\"\n    as: \"b\"\n  - file: \"c.yaml\"\n    as: \"c\"\n",
+        );
+
+        let yaml: Value =
+            serde_yaml_ng::from_str(&std::fs::read_to_string(&main_path).unwrap()).unwrap();
+        let mut model = parse_v1_model(&yaml, ParseLimits::default()).unwrap();
+        let mut cache = IncludeCache::new();
+        resolve_includes(
+            &mut model,
+            &main_path,
+            &mut HashSet::new(),
+            &mut cache,
+            ParseLimits::default(),
+        )
+        .unwrap();
+
+        // b, c, and d once - not d twice
+        assert_eq!(cache.parse_count(), 3);
+        for name in ["b", "c"] {
+            let shared = &model.resolved_includes[name].model.resolved_includes["d"];
+            assert!(shared.model.tables.contains_key("shared"));
+        }
+    }
 }
@@ -0,0 +1,109 @@
+//! Size limits enforced while parsing tables
+//!
+//! A malformed or hostile YAML file can declare columns with millions of
+//! entries. The document is first loaded as a generic YAML value; these limits
+//! are then checked against its sequence lengths before any column is converted
+//! to typed data or calculated, so an oversized table is rejected with a clear
+//! error instead of multiplying its memory through every later stage. They do
+//! not bound the YAML load itself.
+//!
+//! Limits are passed explicitly to the parsing functions;
+//! [`parse_model`](super::parse_model) uses the defaults.
+
+use crate::error::{ForgeError, ForgeResult};
+
+/// Default maximum number of rows in a single table column
+pub const DEFAULT_MAX_ROWS: usize = 1_000_000;
+
+/// Default maximum number of cells (rows x columns) in a single table
+pub const DEFAULT_MAX_CELLS: usize = 10_000_000;
+
+/// Row and cell limits applied to every table during parsing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Maximum number of values in any one column
+    pub max_rows: usize,
+    /// Maximum number of values across all data columns of a table
+    pub max_cells: usize,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_rows: DEFAULT_MAX_ROWS,
+            max_cells: DEFAULT_MAX_CELLS,
+        }
+    }
+}
+
+impl ParseLimits {
+    /// Create limits with explicit row and cell caps
+    #[must_use]
+    pub const fn new(max_rows: usize, max_cells: usize) -> Self {
+        Self {
+            max_rows,
+            max_cells,
+        }
+    }
+
+    /// Check a column of `rows` values, given the `cells` already accepted for its table
+    ///
+    /// Returns the table's new cell count.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column exceeds `max_rows` or the table would exceed `max_cells`.
+    pub fn check_column(
+        &self,
+        table: &str,
+        column: &str,
+        rows: usize,
+        cells: usize,
+    ) -> ForgeResult<usize> {
+        if rows > self.max_rows {
+            return Err(ForgeError::Parse(format!(
+                "Column '{column}' in table '{table}' has {rows} rows, exceeding the limit of {} \
+                 (raise it with --max-rows)",
+                self.max_rows
+            )));
+        }
+        let cells = cells.saturating_add(rows);
+        if cells > self.max_cells {
+            return Err(ForgeError::Parse(format!(
+                "Table '{table}' has more than {} cells (raise the limit with --max-cells)",
+                self.max_cells
+            )));
+        }
+        Ok(cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_column_under_limits() {
+        let limits = ParseLimits::new(3, 6);
+        assert_eq!(limits.check_column("t", "a", 3, 0).unwrap(), 3);
+        assert_eq!(limits.check_column("t", "b", 3, 3).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_check_column_too_many_rows() {
+        let err = ParseLimits::new(3, 100)
+            .check_column("t", "a", 4, 0)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'a'") && err.contains("--max-rows"));
+    }
+
+    #[test]
+    fn test_check_column_too_many_cells() {
+        let err = ParseLimits::new(10, 5)
+            .check_column("t", "b", 3, 3)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'t'") && err.contains("--max-cells"));
+    }
+}
@@ -8,11 +8,13 @@
 //! - `schema`: JSON Schema validation (v1.0.0 and v5.0.0)
 //! - `multi_doc`: Multi-document YAML parsing (v4.4.2)
 //! - `includes`: Cross-file include resolution (v4.0)
+//! - `limits`: Row and cell limits checked before table data is allocated
 //! - `variables`: Table and scalar variable parsing
 //! - `model`: Core model parsing logic
 
 mod arrays;
 mod includes;
+mod limits;
 mod model;
 mod multi_doc;
 mod schema;
@@ -20,17 +22,22 @@ mod variables;
 
 // Re-export commonly used functions
 pub use arrays::{detect_array_type, is_valid_date_format, parse_array_value, type_name};
-pub use includes::{parse_includes, resolve_includes};
+pub use includes::{parse_includes, resolve_includes, IncludeCache};
+pub use limits::{ParseLimits, DEFAULT_MAX_CELLS, DEFAULT_MAX_ROWS};
 pub use model::{parse_nested_scalars, parse_scenarios, parse_v1_model};
 pub use multi_doc::{
-    detect_multi_document, parse_multi_document_yaml, parse_single_document_yaml,
-    split_yaml_documents,
+    detect_multi_document, detect_multi_document_reader, parse_multi_document_reader,
+    parse_multi_document_yaml, parse_single_document_yaml, split_yaml_documents, YamlDocuments,
+};
+pub use schema::{
+    assumed_version, detect_version, is_legacy_version, set_assumed_version,
+    validate_against_schema, validate_v1_0_0_no_tables,
 };
-pub use schema::{validate_against_schema, validate_v1_0_0_no_tables};
 pub use variables::{is_nested_scalar_section, parse_metadata, parse_scalar_variable, parse_table};
 
 use crate::error::ForgeResult;
 use crate::types::ParsedModel;
+use std::io::BufReader;
 
 /// Parse a Forge model file (v1.0.0 array format) and return a `ParsedModel`.
 ///
@@ -45,8 +52,8 @@ use crate::types::ParsedModel;
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be read, contains invalid YAML, or fails
-/// schema validation.
+/// Returns an error if the file cannot be read, contains invalid YAML, fails
+/// schema validation, or has a table exceeding the default [`ParseLimits`].
 ///
 /// # Example
 /// ```no_run
@@ -58,25 +65,40 @@ use crate::types::ParsedModel;
 /// # Ok::<(), mollendorff_forge::error::ForgeError>(())
 /// ```
 pub fn parse_model(path: &std::path::Path) -> ForgeResult<ParsedModel> {
-    let content = std::fs::read_to_string(path)?;
+    parse_model_with_limits(path, ParseLimits::default())
+}
 
+/// Parse a Forge model file, rejecting tables that exceed `limits`.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, contains invalid YAML, fails
+/// schema validation, or has a column over `limits.max_rows` or a table over
+/// `limits.max_cells`.
+pub fn parse_model_with_limits(
+    path: &std::path::Path,
+    limits: ParseLimits,
+) -> ForgeResult<ParsedModel> {
     // Check if this is a multi-document YAML file (v4.4.2)
     // Multi-doc files have at least two document separators (---) on their own lines
-    // We need to skip comments and whitespace when detecting
-    let is_multi_doc = detect_multi_document(&content);
+    // Detection stops at the second separator, so large files are not read twice
+    let is_multi_doc = detect_multi_document_reader(BufReader::new(std::fs::File::open(path)?))?;
 
     if is_multi_doc {
-        // Parse all documents and merge (v4.4.2)
-        parse_multi_document_yaml(&content, path)
+        // Stream documents one at a time and merge (v4.4.2)
+        let reader = BufReader::new(std::fs::File::open(path)?);
+        parse_multi_document_reader(reader, path, limits)
     } else {
         // Single document parsing (original behavior)
-        parse_single_document_yaml(&content, path)
+        let content = std::fs::read_to_string(path)?;
+        parse_single_document_yaml(&content, path, limits)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fmt::Write as _;
     use std::io::Write;
     use std::path::Path;
     use tempfile::NamedTempFile;
@@ -112,4 +134,42 @@ data:
         let result = parse_model(temp_file.path());
         assert!(result.is_err());
     }
+
+    fn write_table(rows: usize, columns: usize) -> NamedTempFile {
+        let values: Vec<String> = (0..rows).map(|r| r.to_string()).collect();
+        let mut yaml = String::from("_forge_version: \"5.0.0\"\ndata:\n");
+        for c in 0..columns {
+            writeln!(yaml, "  col{c}: [{}]", values.join(", ")).unwrap();
+        }
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml.as_bytes()).unwrap();
+        temp_file
+    }
+
+    #[test]
+    fn test_parse_table_under_limits() {
+        let temp_file = write_table(5, 2);
+        let model = parse_model_with_limits(temp_file.path(), ParseLimits::new(5, 10)).unwrap();
+        assert_eq!(model.tables["data"].row_count(), 5);
+    }
+
+    #[test]
+    fn test_parse_table_exceeding_max_rows() {
+        let temp_file = write_table(6, 1);
+        let err = parse_model_with_limits(temp_file.path(), ParseLimits::new(5, 100))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("6 rows"));
+        assert!(err.contains("--max-rows"));
+    }
+
+    #[test]
+    fn test_parse_table_exceeding_max_cells() {
+        let temp_file = write_table(5, 3);
+        let err = parse_model_with_limits(temp_file.path(), ParseLimits::new(5, 10))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'data'"));
+        assert!(err.contains("--max-cells"));
+    }
 }
@@ -3,10 +3,13 @@
 //! Handles parsing of the main model structure including tables, scalars, and scenarios.
 
 use crate::error::{ForgeError, ForgeResult};
-use crate::types::{ParsedModel, Scenario};
+use crate::scenarios::config::inheritance_chain;
+use crate::types::{NamedRange, ParsedModel, Scenario};
 use serde_yaml_ng::Value;
+use std::collections::HashMap;
 
 use super::includes::parse_includes;
+use super::limits::ParseLimits;
 use super::schema::validate_against_schema;
 use super::variables::{is_nested_scalar_section, parse_scalar_variable, parse_table};
 
@@ -14,12 +17,14 @@ use super::variables::{is_nested_scalar_section, parse_scalar_variable, parse_ta
 ///
 /// # Errors
 ///
-/// Returns an error if the YAML fails schema validation or contains invalid table/scalar definitions.
-pub fn parse_v1_model(yaml: &Value) -> ForgeResult<ParsedModel> {
+/// Returns an error if the YAML fails schema validation, contains invalid table/scalar definitions,
+/// or has a table exceeding `limits`.
+pub fn parse_v1_model(yaml: &Value, limits: ParseLimits) -> ForgeResult<ParsedModel> {
     // Validate against JSON Schema - this is mandatory
-    validate_against_schema(yaml)?;
+    let version_warning = validate_against_schema(yaml)?;
 
     let mut model = ParsedModel::new();
+    model.parse_warnings.extend(version_warning);
 
     // Parse each top-level key as either a table or scalar
     if let Value::Mapping(map) = yaml {
@@ -48,6 +53,17 @@ pub fn parse_v1_model(yaml: &Value) -> ForgeResult<ParsedModel> {
                 continue;
             }
 
+            // Parse names section - a mapping of alias -> reference string
+            // (a table named "names" has arrays as column values instead)
+            if key_str == "names" {
+                if let Value::Mapping(names_map) = value {
+                    if names_map.iter().all(|(_, v)| v.is_string()) {
+                        parse_names(names_map, &mut model)?;
+                        continue;
+                    }
+                }
+            }
+
             // Parse scenarios section - but only if it looks like scenario overrides
             // (mapping of scenario_name -> {variable: value}), not a table (mapping of column_name -> array)
             if key_str == "scenarios" {
@@ -60,7 +76,11 @@ pub fn parse_v1_model(yaml: &Value) -> ForgeResult<ParsedModel> {
                         .all(|(_, v)| matches!(v, Value::Mapping(_)))
                         && scenarios_map.iter().any(|(_, v)| {
                             if let Value::Mapping(m) = v {
-                                m.iter().any(|(_, vv)| matches!(vv, Value::Number(_)))
+                                m.iter().any(|(k, vv)| {
+                                    matches!(vv, Value::Number(_))
+                                        || (k.as_str() == Some("tables")
+                                            && matches!(vv, Value::Mapping(_)))
+                                })
                             } else {
                                 false
                             }
@@ -86,13 +106,21 @@ pub fn parse_v1_model(yaml: &Value) -> ForgeResult<ParsedModel> {
                     parse_nested_scalars(key_str, inner_map, &mut model)?;
                 } else {
                     // This is a table - parse it
-                    let table = parse_table(key_str, inner_map)?;
+                    let table = parse_table(key_str, inner_map, limits)?;
                     model.add_table(table);
                 }
             }
         }
     }
 
+    let name_errors = model.check_names();
+    if !name_errors.is_empty() {
+        return Err(ForgeError::Validation(format!(
+            "Invalid names section:\n  - {}",
+            name_errors.join("\n  - ")
+        )));
+    }
+
     // Note: Table column length validation is deferred to calculation time
     // This allows test files to have columns of different lengths when used independently
     // Row-wise operations will still validate at runtime in array_calculator
@@ -127,6 +155,34 @@ pub fn parse_nested_scalars(
     Ok(())
 }
 
+/// Parse the `names:` section (alias -> column, scalar, or column slice)
+///
+/// ```yaml
+/// names:
+///   q1_revenue: sales.revenue[0:3]
+///   tax: inputs.tax_rate
+/// ```
+///
+/// Targets are checked once the whole model is parsed.
+///
+/// # Errors
+///
+/// Returns an error if an alias or target is not a string, or a slice is malformed.
+pub fn parse_names(names_map: &serde_yaml_ng::Mapping, model: &mut ParsedModel) -> ForgeResult<()> {
+    for (alias, target) in names_map {
+        let alias = alias
+            .as_str()
+            .ok_or_else(|| ForgeError::Parse("Name alias must be a string".to_string()))?;
+        let target = target
+            .as_str()
+            .ok_or_else(|| ForgeError::Parse(format!("Name '{alias}': target must be a string")))?;
+        let range = NamedRange::parse(target)
+            .map_err(|e| ForgeError::Parse(format!("Name '{alias}': {e}")))?;
+        model.add_name(alias.to_string(), range);
+    }
+    Ok(())
+}
+
 /// Parse scenarios section from YAML
 ///
 /// Supports two formats:
@@ -150,14 +206,27 @@ pub fn parse_nested_scalars(
 ///       churn_rate: 0.02
 /// ```
 ///
+/// Either format may set `extends: <scenario>` to inherit the parent's overrides
+/// and only list the variables that differ, and a `tables:` block replacing
+/// whole columns:
+/// ```yaml
+/// scenarios:
+///   bull:
+///     tables:
+///       forecast.monthly_growth: [0.04, 0.05, 0.06]
+/// ```
+///
 /// # Errors
 ///
 /// Returns an error if a scenario name or variable is not valid, if a variable
-/// value is not a number, or if the `scalars` field is not a mapping.
+/// value is not a number, if the `scalars` or `tables` field is malformed, or
+/// if an `extends` chain references an unknown scenario or forms a cycle.
 pub fn parse_scenarios(
     scenarios_map: &serde_yaml_ng::Mapping,
     model: &mut ParsedModel,
 ) -> ForgeResult<()> {
+    let mut parents: HashMap<String, String> = HashMap::new();
+
     for (scenario_name, scenario_value) in scenarios_map {
         let name = scenario_name
             .as_str()
@@ -166,6 +235,19 @@ pub fn parse_scenarios(
         if let Value::Mapping(overrides_map) = scenario_value {
             let mut scenario = Scenario::new();
 
+            if let Some(parent) = overrides_map.get("extends") {
+                let parent = parent.as_str().ok_or_else(|| {
+                    ForgeError::Parse(format!(
+                        "Scenario '{name}': 'extends' must be a scenario name"
+                    ))
+                })?;
+                parents.insert(name.to_string(), parent.to_string());
+            }
+
+            if let Some(tables) = overrides_map.get("tables") {
+                parse_column_overrides(name, tables, &mut scenario)?;
+            }
+
             // Detect structured format: has reserved keys "probability" or "scalars"
             let is_structured =
                 overrides_map.contains_key("probability") || overrides_map.contains_key("scalars");
@@ -209,6 +291,10 @@ pub fn parse_scenarios(
                         ForgeError::Parse("Variable name must be a string".to_string())
                     })?;
 
+                    if var_name_str == "extends" || var_name_str == "tables" {
+                        continue;
+                    }
+
                     let value = match var_value {
                         Value::Number(n) => n.as_f64().ok_or_else(|| {
                             ForgeError::Parse(format!(
@@ -234,6 +320,91 @@ pub fn parse_scenarios(
         }
     }
 
+    resolve_scenario_inheritance(&parents, model)
+}
+
+/// Parse a scenario's `tables:` block mapping `table.column` to a replacement array
+///
+/// Row counts are checked against the table when the scenario is applied,
+/// since tables may appear after the `scenarios` section.
+///
+/// # Errors
+///
+/// Returns an error if the block is not a mapping, a key is not `table.column`,
+/// or a value is not an array of numbers.
+fn parse_column_overrides(name: &str, tables: &Value, scenario: &mut Scenario) -> ForgeResult<()> {
+    let Value::Mapping(columns) = tables else {
+        return Err(ForgeError::Parse(format!(
+            "Scenario '{name}': 'tables' must be a mapping of table.column to arrays"
+        )));
+    };
+
+    for (target, values) in columns {
+        let target = target.as_str().filter(|t| t.contains('.')).ok_or_else(|| {
+            ForgeError::Parse(format!(
+                "Scenario '{name}': column overrides must be keyed by 'table.column'"
+            ))
+        })?;
+        let not_numeric = || {
+            ForgeError::Parse(format!(
+                "Scenario '{name}': column override '{target}' must be an array of numbers"
+            ))
+        };
+        let Value::Sequence(items) = values else {
+            return Err(not_numeric());
+        };
+        let values = items
+            .iter()
+            .map(|v| v.as_f64().ok_or_else(not_numeric))
+            .collect::<ForgeResult<Vec<f64>>>()?;
+
+        scenario.add_column_override(target.to_string(), values);
+    }
+
+    Ok(())
+}
+
+/// Merge inherited overrides into every scenario that `extends` another
+///
+/// Overrides are applied root-first along the parent chain, so a child's own
+/// values win over anything it inherits.
+///
+/// # Errors
+///
+/// Returns an error if a scenario extends an unknown scenario or the
+/// inheritance chain loops back on itself.
+fn resolve_scenario_inheritance(
+    parents: &HashMap<String, String>,
+    model: &mut ParsedModel,
+) -> ForgeResult<()> {
+    let mut resolved: HashMap<String, Scenario> = HashMap::new();
+
+    for name in parents.keys() {
+        let chain = inheritance_chain(
+            name,
+            |n| parents.get(n).map(String::as_str),
+            |n| model.scenarios.contains_key(n),
+        )
+        .map_err(ForgeError::Parse)?;
+
+        let mut merged = Scenario::new();
+        for ancestor in chain.iter().rev() {
+            if let Some(scenario) = model.scenarios.get(*ancestor) {
+                merged.overrides.extend(scenario.overrides.clone());
+                merged
+                    .column_overrides
+                    .extend(scenario.column_overrides.clone());
+            }
+        }
+        resolved.insert(name.clone(), merged);
+    }
+
+    for (name, merged) in resolved {
+        if let Some(scenario) = model.scenarios.get_mut(&name) {
+            *scenario = merged;
+        }
+    }
+
     Ok(())
 }
 
@@ -366,7 +537,7 @@ scenarios:
   revenue: [1000, 2000, 3000]
 "#;
         let yaml: Value = serde_yaml_ng::from_str(yaml_str).unwrap();
-        let result = parse_v1_model(&yaml).unwrap();
+        let result = parse_v1_model(&yaml, ParseLimits::default()).unwrap();
         assert!(result.tables.contains_key("scenarios"));
         assert!(result.scenarios.is_empty());
     }
@@ -452,6 +623,135 @@ scenarios:
         assert_eq!(low.overrides.get("price"), Some(&50.0));
     }
 
+    #[test]
+    fn test_parse_scenarios_extends_inherits_parent() {
+        let yaml_content = r#"
+_forge_version: "1.0.0"
+
+growth_rate:
+  value: 0.05
+  formula: null
+churn_rate:
+  value: 0.02
+  formula: null
+discount_rate:
+  value: 0.10
+  formula: null
+
+scenarios:
+  base:
+    growth_rate: 0.05
+    churn_rate: 0.02
+    discount_rate: 0.10
+  bull:
+    extends: base
+    growth_rate: 0.12
+"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+
+        let result = parse_model(temp_file.path()).unwrap();
+
+        let bull = result.scenarios.get("bull").unwrap();
+        assert_eq!(bull.overrides.len(), 3);
+        assert_eq!(bull.overrides.get("growth_rate"), Some(&0.12));
+        assert_eq!(bull.overrides.get("churn_rate"), Some(&0.02));
+        assert_eq!(bull.overrides.get("discount_rate"), Some(&0.10));
+        assert!(!bull.overrides.contains_key("extends"));
+
+        // Parent is untouched by its child
+        let base = result.scenarios.get("base").unwrap();
+        assert_eq!(base.overrides.get("growth_rate"), Some(&0.05));
+    }
+
+    #[test]
+    fn test_parse_scenarios_column_overrides() {
+        let yaml_content = r#"
+_forge_version: "5.0.0"
+
+forecast:
+  month: ["Jan", "Feb", "Mar"]
+  monthly_growth: [0.01, 0.01, 0.01]
+
+growth_rate:
+  value: 0.05
+  formula: null
+
+scenarios:
+  base:
+    growth_rate: 0.05
+  bull:
+    extends: base
+    tables:
+      forecast.monthly_growth: [0.04, 0.05, 0.06]
+  boom:
+    tables:
+      forecast.monthly_growth: [0.1, 0.1, 0.1]
+"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+
+        let result = parse_model(temp_file.path()).unwrap();
+
+        let bull = result.scenarios.get("bull").unwrap();
+        assert_eq!(bull.overrides.get("growth_rate"), Some(&0.05));
+        assert!(!bull.overrides.contains_key("tables"));
+        assert_eq!(
+            bull.column_overrides.get("forecast.monthly_growth"),
+            Some(&vec![0.04, 0.05, 0.06])
+        );
+        assert!(result.scenarios["base"].column_overrides.is_empty());
+        assert_eq!(result.scenarios["boom"].column_overrides.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_scenarios_column_override_not_numeric() {
+        let scenarios: serde_yaml_ng::Mapping = serde_yaml_ng::from_str(
+            "bull:\n  tables:\n    forecast.monthly_growth: [\"high\", \"low\"]\n",
+        )
+        .unwrap();
+        let mut model = ParsedModel::new();
+
+        let err = parse_scenarios(&scenarios, &mut model)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("must be an array of numbers"), "{err}");
+
+        let scenarios: serde_yaml_ng::Mapping =
+            serde_yaml_ng::from_str("bull:\n  tables:\n    monthly_growth: [0.1]\n").unwrap();
+        let err = parse_scenarios(&scenarios, &mut model)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'table.column'"), "{err}");
+    }
+
+    #[test]
+    fn test_parse_scenarios_extends_cycle_rejected() {
+        let yaml_content = r#"
+_forge_version: "1.0.0"
+
+rate:
+  value: 0.05
+  formula: null
+
+scenarios:
+  a:
+    extends: b
+    rate: 0.01
+  b:
+    extends: a
+    rate: 0.02
+"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+
+        let err = parse_model(temp_file.path()).unwrap_err().to_string();
+        assert!(err.contains("inheritance cycle"), "got: {err}");
+    }
+
     #[test]
     fn test_parse_scenarios_structured_no_scalars() {
         // Structured format with empty/missing scalars section is valid (no overrides)
@@ -523,7 +823,7 @@ tornado:
       high: 60
 "#;
         let yaml: Value = serde_yaml_ng::from_str(yaml_str).unwrap();
-        let result = parse_v1_model(&yaml).unwrap();
+        let result = parse_v1_model(&yaml, ParseLimits::default()).unwrap();
 
         // Tornado should NOT be parsed as a table
         assert!(!result.tables.contains_key("tornado"));
@@ -565,7 +865,7 @@ decision_tree:
           value: 20000
 "#;
         let yaml: Value = serde_yaml_ng::from_str(yaml_str).unwrap();
-        let result = parse_v1_model(&yaml).unwrap();
+        let result = parse_v1_model(&yaml, ParseLimits::default()).unwrap();
 
         // Decision tree should NOT be parsed as a table
         assert!(!result.tables.contains_key("decision_tree"));
@@ -590,7 +890,7 @@ monte_carlo:
       std: 10000
 "#;
         let yaml: Value = serde_yaml_ng::from_str(yaml_str).unwrap();
-        let result = parse_v1_model(&yaml).unwrap();
+        let result = parse_v1_model(&yaml, ParseLimits::default()).unwrap();
 
         // Monte Carlo should NOT be parsed as a table
         assert!(!result.tables.contains_key("monte_carlo"));
@@ -795,4 +1095,55 @@ sales:
         assert!(sales.row_formulas.contains_key("profit"));
         assert_eq!(sales.row_formulas.get("profit").unwrap(), "=revenue * 0.3");
     }
+
+    #[test]
+    fn test_parse_names_section() {
+        let yaml_content = r#"
+_forge_version: "5.0.0"
+
+sales:
+  revenue: [100, 200, 300, 400]
+  cost: "=revenue * 0.5"
+
+rate:
+  value: 0.2
+  formula: null
+
+names:
+  rev: sales.revenue
+  first_half: sales.revenue[0:2]
+  costs: sales.cost
+  tax: rate
+"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+
+        let result = parse_model(temp_file.path()).unwrap();
+
+        assert_eq!(result.names.len(), 4);
+        assert!(!result.tables.contains_key("names"));
+        assert_eq!(result.names["rev"].reference, "sales.revenue");
+        assert_eq!(result.names["rev"].slice, None);
+        assert_eq!(result.names["first_half"].slice, Some((0, 2)));
+        assert_eq!(result.names["tax"].reference, "rate");
+    }
+
+    #[test]
+    fn test_parse_names_rejects_bad_targets() {
+        let parse = |names: &str| {
+            let yaml_content = format!(
+                "_forge_version: \"5.0.0\"\nsales:\n  revenue: [1, 2, 3]\nrate:\n  value: 0.2\n  formula: null\nnames:\n{names}"
+            );
+            let mut temp_file = NamedTempFile::new().unwrap();
+            temp_file.write_all(yaml_content.as_bytes()).unwrap();
+            parse_model(temp_file.path()).unwrap_err().to_string()
+        };
+
+        assert!(parse("  rev: sales.revnue\n").contains("unknown column 'sales.revnue'"));
+        assert!(parse("  rev: missing\n").contains("unknown reference 'missing'"));
+        assert!(parse("  rev: sales.revenue[1:5]\n").contains("slice end 5"));
+        assert!(parse("  rev: rate[0:1]\n").contains("cannot be sliced"));
+        assert!(parse("  sales: rate\n").contains("already used"));
+    }
 }
@@ -1,32 +1,54 @@
 //! Multi-document YAML parsing for Forge models (v4.4.2)
 //!
 //! Handles parsing of YAML files with multiple documents (--- separators).
+//! Documents are read from a `BufRead` one at a time and merged as they are
+//! parsed, so only the current document is held in memory.
 
 use crate::error::{ForgeError, ForgeResult};
 use crate::types::ParsedModel;
 use serde_yaml_ng::Value;
 use std::collections::HashSet;
+use std::io::BufRead;
+use std::ops::Range;
 use std::path::Path;
 
-use super::includes::resolve_includes;
+use super::includes::{resolve_includes, IncludeCache};
+use super::limits::ParseLimits;
 use super::model::parse_v1_model;
 
 /// Detect if content is a multi-document YAML file
 /// A multi-document file has at least two document separators (---) on their own lines
 #[must_use]
 pub fn detect_multi_document(content: &str) -> bool {
+    content
+        .lines()
+        .filter(|line| is_document_separator(line))
+        .nth(1)
+        .is_some()
+}
+
+/// Detect a multi-document YAML file from a reader, stopping at the second separator
+///
+/// # Errors
+///
+/// Returns an error if the reader fails or yields invalid UTF-8.
+pub fn detect_multi_document_reader<R: BufRead>(reader: R) -> std::io::Result<bool> {
     let mut separator_count = 0;
-    for line in content.lines() {
-        let trimmed = line.trim();
-        // Document separator is "---" optionally followed by whitespace
-        if trimmed == "---" || trimmed.starts_with("--- ") {
+    for line in reader.lines() {
+        if is_document_separator(&line?) {
             separator_count += 1;
             if separator_count >= 2 {
-                return true;
+                return Ok(true);
             }
         }
     }
-    false
+    Ok(false)
+}
+
+/// Document separator is "---" optionally followed by whitespace
+fn is_document_separator(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed == "---" || trimmed.starts_with("--- ")
 }
 
 /// Parse a single YAML document
@@ -34,18 +56,28 @@ pub fn detect_multi_document(content: &str) -> bool {
 /// # Errors
 ///
 /// Returns an error if the YAML content is invalid or fails schema validation.
-pub fn parse_single_document_yaml(content: &str, path: &Path) -> ForgeResult<ParsedModel> {
+pub fn parse_single_document_yaml(
+    content: &str,
+    path: &Path,
+    limits: ParseLimits,
+) -> ForgeResult<ParsedModel> {
     // Strip leading document marker if present
     let content = content.trim_start();
     let content = content.strip_prefix("---").map_or(content, str::trim_start);
 
     let yaml: Value = serde_yaml_ng::from_str(content)?;
 
-    let mut model = parse_v1_model(&yaml)?;
+    let mut model = parse_v1_model(&yaml, limits)?;
 
     // Resolve includes if any (v4.0)
     if !model.includes.is_empty() {
-        resolve_includes(&mut model, path, &mut HashSet::new())?;
+        resolve_includes(
+            &mut model,
+            path,
+            &mut HashSet::new(),
+            &mut IncludeCache::new(),
+            limits,
+        )?;
     }
 
     Ok(model)
@@ -59,14 +91,35 @@ pub fn parse_single_document_yaml(content: &str, path: &Path) -> ForgeResult<Par
 ///
 /// Returns an error if any document in the file contains invalid YAML or fails
 /// schema validation.
-pub fn parse_multi_document_yaml(content: &str, path: &Path) -> ForgeResult<ParsedModel> {
+pub fn parse_multi_document_yaml(
+    content: &str,
+    path: &Path,
+    limits: ParseLimits,
+) -> ForgeResult<ParsedModel> {
+    parse_multi_document_reader(content.as_bytes(), path, limits)
+}
+
+/// Parse a multi-document YAML stream, merging each document as it is read
+///
+/// Produces the same model as [`parse_multi_document_yaml`] without holding
+/// more than one document in memory.
+///
+/// # Errors
+///
+/// Returns an error if the reader fails, or any document contains invalid YAML
+/// or fails schema validation.
+pub fn parse_multi_document_reader<R: BufRead>(
+    reader: R,
+    path: &Path,
+    limits: ParseLimits,
+) -> ForgeResult<ParsedModel> {
     let mut merged_model = ParsedModel::new();
     let mut doc_index = 0;
 
     // Split by document separator lines (--- on its own line)
-    let docs = split_yaml_documents(content);
-
-    for doc_content in docs {
+    let mut documents = YamlDocuments::new(reader);
+    while let Some(doc_content) = documents.next() {
+        let doc_content = doc_content?;
         let doc_content = doc_content.trim();
         if doc_content.is_empty() {
             continue;
@@ -94,7 +147,7 @@ pub fn parse_multi_document_yaml(content: &str, path: &Path) -> ForgeResult<Pars
             },
         };
 
-        let doc_model = parse_v1_model(&yaml)?;
+        let doc_model = parse_v1_model(&yaml, limits)?;
 
         // Get document name from _name field or generate one
         let doc_name = if let Some(Value::String(name)) = yaml.get("_name") {
@@ -103,6 +156,19 @@ pub fn parse_multi_document_yaml(content: &str, path: &Path) -> ForgeResult<Pars
             format!("doc{doc_index}")
         };
 
+        merged_model.parse_warnings.extend(
+            doc_model
+                .parse_warnings
+                .iter()
+                .map(|warning| format!("{doc_name}: {warning}")),
+        );
+        merged_model.declaration_order.extend(
+            doc_model
+                .declaration_order
+                .iter()
+                .map(|name| format!("{doc_name}.{name}")),
+        );
+
         // Merge tables with document prefix
         for (table_name, table) in doc_model.tables {
             let prefixed_name = format!("{doc_name}.{table_name}");
@@ -131,11 +197,18 @@ pub fn parse_multi_document_yaml(content: &str, path: &Path) -> ForgeResult<Pars
 
         // Store document metadata
         merged_model.documents.push(doc_name);
+        merged_model.document_spans.push(documents.span());
     }
 
     // Resolve includes if any (v4.0)
     if !merged_model.includes.is_empty() {
-        resolve_includes(&mut merged_model, path, &mut HashSet::new())?;
+        resolve_includes(
+            &mut merged_model,
+            path,
+            &mut HashSet::new(),
+            &mut IncludeCache::new(),
+            limits,
+        )?;
     }
 
     Ok(merged_model)
@@ -144,39 +217,93 @@ pub fn parse_multi_document_yaml(content: &str, path: &Path) -> ForgeResult<Pars
 /// Split YAML content into separate documents by "---" separator lines
 #[must_use]
 pub fn split_yaml_documents(content: &str) -> Vec<String> {
-    let mut documents = Vec::new();
-    let mut current_doc = String::new();
-    let mut in_document = false;
-
-    for line in content.lines() {
-        let trimmed = line.trim();
-        // Check if this is a document separator
-        if trimmed == "---" || trimmed.starts_with("--- ") {
-            if in_document && !current_doc.trim().is_empty() {
-                documents.push(std::mem::take(&mut current_doc));
-            }
-            in_document = true;
-            current_doc.clear();
-        } else {
-            // Add line to current document
-            if !current_doc.is_empty() {
-                current_doc.push('\n');
-            }
-            current_doc.push_str(line);
+    YamlDocuments::new(content.as_bytes())
+        .map_while(Result::ok)
+        .collect()
+}
+
+/// Iterator over the documents of a YAML stream, split on "---" separator lines
+///
+/// Content before the first separator is discarded once a separator is seen,
+/// and blank documents are skipped.
+pub struct YamlDocuments<R> {
+    reader: R,
+    current_doc: String,
+    in_document: bool,
+    /// Bytes read so far
+    offset: usize,
+    /// Where the document being read starts
+    doc_start: usize,
+    /// Byte range of the last document returned
+    span: Range<usize>,
+}
+
+impl<R: BufRead> YamlDocuments<R> {
+    /// Read documents from `reader` line by line
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            current_doc: String::new(),
+            in_document: false,
+            offset: 0,
+            doc_start: 0,
+            span: 0..0,
         }
     }
 
-    // Don't forget the last document
-    if !current_doc.trim().is_empty() {
-        documents.push(current_doc);
+    /// Byte range in the stream of the document last returned by `next`,
+    /// excluding its separator lines
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
     }
+}
+
+impl<R: BufRead> Iterator for YamlDocuments<R> {
+    type Item = std::io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = String::new();
+        loop {
+            buf.clear();
+            let line_start = self.offset;
+            match self.reader.read_line(&mut buf) {
+                Ok(0) => break,
+                Ok(read) => self.offset += read,
+                Err(e) => return Some(Err(e)),
+            }
+            let line = buf.strip_suffix('\n').unwrap_or(&buf);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if is_document_separator(line) {
+                let finished = std::mem::take(&mut self.current_doc);
+                let emit = self.in_document && !finished.trim().is_empty();
+                self.in_document = true;
+                self.span = self.doc_start..line_start;
+                self.doc_start = self.offset;
+                if emit {
+                    return Some(Ok(finished));
+                }
+            } else {
+                // Add line to current document
+                if !self.current_doc.is_empty() {
+                    self.current_doc.push('\n');
+                }
+                self.current_doc.push_str(line);
+            }
+        }
 
-    documents
+        // Don't forget the last document
+        let last = std::mem::take(&mut self.current_doc);
+        self.span = self.doc_start..self.offset;
+        self.doc_start = self.offset;
+        (!last.trim().is_empty()).then_some(Ok(last))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ColumnValue;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -245,7 +372,8 @@ expenses:
         temp_file.write_all(yaml_content.as_bytes()).unwrap();
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
-        let result = parse_multi_document_yaml(&content, temp_file.path()).unwrap();
+        let result =
+            parse_multi_document_yaml(&content, temp_file.path(), ParseLimits::default()).unwrap();
 
         assert!(result.tables.contains_key("revenue.data"));
         assert!(result.tables.contains_key("costs.expenses"));
@@ -270,7 +398,8 @@ data2:
         temp_file.write_all(yaml_content.as_bytes()).unwrap();
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
-        let result = parse_multi_document_yaml(&content, temp_file.path()).unwrap();
+        let result =
+            parse_multi_document_yaml(&content, temp_file.path(), ParseLimits::default()).unwrap();
 
         assert!(result.tables.contains_key("doc1.data1"));
         assert!(result.tables.contains_key("doc2.data2"));
@@ -295,7 +424,8 @@ values:
         temp_file.write_all(yaml_content.as_bytes()).unwrap();
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
-        let result = parse_multi_document_yaml(&content, temp_file.path()).unwrap();
+        let result =
+            parse_multi_document_yaml(&content, temp_file.path(), ParseLimits::default()).unwrap();
 
         assert!(result.scalars.contains_key("config.rate"));
         assert!(result.tables.contains_key("data.values"));
@@ -316,7 +446,8 @@ data:
         temp_file.write_all(yaml_content.as_bytes()).unwrap();
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
-        let result = parse_multi_document_yaml(&content, temp_file.path()).unwrap();
+        let result =
+            parse_multi_document_yaml(&content, temp_file.path(), ParseLimits::default()).unwrap();
         assert!(!result.tables.is_empty());
     }
 
@@ -338,7 +469,8 @@ data2:
         temp_file.write_all(yaml_content.as_bytes()).unwrap();
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
-        let result = parse_multi_document_yaml(&content, temp_file.path()).unwrap();
+        let result =
+            parse_multi_document_yaml(&content, temp_file.path(), ParseLimits::default()).unwrap();
         assert_eq!(result.tables.len(), 2);
     }
 
@@ -356,7 +488,7 @@ invalid: yaml: [[[
         temp_file.write_all(yaml_content.as_bytes()).unwrap();
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
-        let result = parse_multi_document_yaml(&content, temp_file.path());
+        let result = parse_multi_document_yaml(&content, temp_file.path(), ParseLimits::default());
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -388,7 +520,8 @@ scenarios:
         temp_file.write_all(yaml_content.as_bytes()).unwrap();
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
-        let result = parse_multi_document_yaml(&content, temp_file.path()).unwrap();
+        let result =
+            parse_multi_document_yaml(&content, temp_file.path(), ParseLimits::default()).unwrap();
         assert!(result.scenarios.contains_key("doc1.optimistic"));
         assert!(result.scenarios.contains_key("doc2.pessimistic"));
     }
@@ -408,10 +541,129 @@ sales:
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
         // Single doc with leading separator should be parsed as single doc
-        let result = parse_single_document_yaml(&content, temp_file.path()).unwrap();
+        let result =
+            parse_single_document_yaml(&content, temp_file.path(), ParseLimits::default()).unwrap();
 
         assert_eq!(result.tables.len(), 1);
         let sales = result.tables.get("sales").unwrap();
         assert_eq!(sales.row_count(), 3);
     }
+
+    #[test]
+    fn test_yaml_documents_matches_split() {
+        let content = "ignored: 0\n---\na: 1\n\n--- two\n\n---\n# c\nb: 2\n";
+        let streamed: Vec<String> = YamlDocuments::new(content.as_bytes())
+            .collect::<std::io::Result<_>>()
+            .unwrap();
+        assert_eq!(streamed, vec!["a: 1\n", "# c\nb: 2"]);
+        assert_eq!(streamed, split_yaml_documents(content));
+    }
+
+    #[test]
+    fn test_yaml_documents_spans() {
+        let content = "ignored: 0\n---\na: 1\r\n--- two\n# c\nb: 2";
+        let mut documents = YamlDocuments::new(content.as_bytes());
+        let mut spans = Vec::new();
+        while let Some(doc) = documents.next() {
+            let span = documents.span();
+            assert_eq!(content[span.clone()].trim(), doc.unwrap().trim());
+            spans.push(span);
+        }
+        assert_eq!(spans, vec![15..21, 29..37]);
+    }
+
+    #[test]
+    fn test_detect_multi_document_reader() {
+        assert!(detect_multi_document_reader(&b"---\na: 1\n---\nb: 2\n"[..]).unwrap());
+        assert!(!detect_multi_document_reader(&b"---\na: 1\n"[..]).unwrap());
+    }
+
+    #[test]
+    fn test_parse_multi_doc_from_reader_matches_string() {
+        let content = r#"
+---
+_forge_version: "5.0.0"
+_name: "sales"
+revenue:
+  month: [1, 2, 3]
+  amount: [100, 200, 300]
+---
+_forge_version: "5.0.0"
+costs:
+  amount: [10, 20, 30]
+  share: "=amount / 100"
+"#;
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
+
+        let from_string =
+            parse_multi_document_yaml(content, temp_file.path(), ParseLimits::default()).unwrap();
+        let file = std::fs::File::open(temp_file.path()).unwrap();
+        let from_reader = parse_multi_document_reader(
+            std::io::BufReader::new(file),
+            temp_file.path(),
+            ParseLimits::default(),
+        )
+        .unwrap();
+
+        assert_eq!(from_reader.documents, vec!["sales", "doc2"]);
+        let mut tables: Vec<&String> = from_reader.tables.keys().collect();
+        tables.sort();
+        assert_eq!(tables, ["doc2.costs", "sales.revenue"]);
+        let numbers =
+            |table: &str, column: &str| match &from_reader.tables[table].columns[column].values {
+                ColumnValue::Number(values) => values.clone(),
+                other => panic!("expected numbers, got {other:?}"),
+            };
+        assert_eq!(numbers("sales.revenue", "amount"), [100.0, 200.0, 300.0]);
+        assert_eq!(numbers("doc2.costs", "amount"), [10.0, 20.0, 30.0]);
+        assert_eq!(
+            from_reader.tables["doc2.costs"].row_formulas["share"],
+            "=amount / 100"
+        );
+        assert_eq!(
+            serde_json::to_value(&from_reader).unwrap(),
+            serde_json::to_value(&from_string).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_multi_doc_write_back_keeps_documents() {
+        let content = r#"---
+_forge_version: "5.0.0"
+_name: "sales"
+revenue:
+  value: 100
+---
+_forge_version: "5.0.0"
+_name: "costs"
+rent:
+  value: 40
+margin:
+  value: 0
+  formula: "=rent * 2"
+"#;
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("model.yaml");
+        std::fs::write(&path, content).unwrap();
+
+        let model = crate::parser::parse_model(&path).unwrap();
+        let result = crate::core::ArrayCalculator::new(model)
+            .calculate_all()
+            .unwrap();
+        crate::writer::write_calculated_results(&path, &result).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(split_yaml_documents(&written).len(), 2, "{written}");
+        let reparsed = crate::parser::parse_model(&path).unwrap();
+        assert_eq!(reparsed.documents, vec!["sales", "costs"]);
+        let value = |name: &str| reparsed.scalars[name].value;
+        assert_eq!(value("sales.revenue"), Some(100.0));
+        assert_eq!(value("costs.rent"), Some(40.0));
+        assert_eq!(value("costs.margin"), Some(80.0));
+        assert_eq!(
+            reparsed.scalars["costs.margin"].formula.as_deref(),
+            Some("=rent * 2")
+        );
+    }
 }
@@ -1,32 +1,95 @@
 //! JSON Schema validation for Forge YAML models
 //!
 //! Validates YAML against embedded JSON schemas (v1.0.0 and v5.0.0).
+//! Files without `_forge_version` are rejected unless a fallback version is
+//! set with [`set_assumed_version`] (the CLI's `--assume-version`).
 
 use crate::error::{ForgeError, ForgeResult};
 use serde_yaml_ng::Value;
+use std::sync::RwLock;
+
+/// Top-level sections only the v5.0.0 schema accepts
+const V5_ONLY_SECTIONS: &[&str] = &[
+    "_includes",
+    "decision_tree",
+    "inputs",
+    "monte_carlo",
+    "names",
+    "outputs",
+    "tornado",
+];
+
+static ASSUMED_VERSION: RwLock<Option<String>> = RwLock::new(None);
+
+/// Set the process-wide version used for files without `_forge_version`
+///
+/// `None` rejects such files; `"auto"` picks one with [`detect_version`].
+pub fn set_assumed_version(version: Option<String>) {
+    if let Ok(mut current) = ASSUMED_VERSION.write() {
+        *current = version;
+    }
+}
+
+/// Process-wide version used for files without `_forge_version`
+#[must_use]
+pub fn assumed_version() -> Option<String> {
+    ASSUMED_VERSION.read().ok().and_then(|v| v.clone())
+}
+
+/// Guess the schema version of a model that has no `_forge_version`
+///
+/// Tables, arrays or v5-only sections imply 5.0.0; scalars alone imply 1.0.0.
+#[must_use]
+pub fn detect_version(yaml: &Value) -> &'static str {
+    let has_v5_section = yaml
+        .as_mapping()
+        .is_some_and(|map| V5_ONLY_SECTIONS.iter().any(|s| map.contains_key(*s)));
+    if has_v5_section || validate_v1_0_0_no_tables(yaml).is_err() {
+        "5.0.0"
+    } else {
+        "1.0.0"
+    }
+}
 
 /// Validate YAML against the appropriate Forge JSON Schema based on _`forge_version`
 ///
+/// Returns a warning naming the version assumed for a file without
+/// `_forge_version`, for the caller to report.
+///
 /// # Errors
 ///
-/// Returns an error if the YAML is missing `_forge_version`, specifies an unsupported
-/// version, or fails schema validation.
-pub fn validate_against_schema(yaml: &Value) -> ForgeResult<()> {
+/// Returns an error if the YAML is missing `_forge_version` (and no fallback is set),
+/// specifies an unsupported version, or fails schema validation.
+pub fn validate_against_schema(yaml: &Value) -> ForgeResult<Option<String>> {
+    validate_with_fallback(yaml, assumed_version().as_deref())
+}
+
+/// Validate against the schema for `_forge_version`, or for `fallback` when it is missing
+fn validate_with_fallback(yaml: &Value, fallback: Option<&str>) -> ForgeResult<Option<String>> {
     // Extract the _forge_version to determine which schema to use
-    let version = yaml
-        .get("_forge_version")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| {
-            ForgeError::Validation(
-                "Missing required field: _forge_version. Must be \"1.0.0\" or \"5.0.0\""
+    let declared = yaml.get("_forge_version").and_then(|v| v.as_str());
+    let version = match (declared, fallback) {
+        (None, Some("auto")) => detect_version(yaml),
+        (Some(version), _) | (None, Some(version)) => version,
+        (None, None) => {
+            return Err(ForgeError::Validation(
+                "Missing required field: _forge_version. Must be \"1.0.0\" or \"5.0.0\" \
+                 (or pass --assume-version)"
                     .to_string(),
-            )
-        })?;
+            ))
+        },
+    };
 
     // Load the appropriate schema based on version
     let schema_str = match version {
         "1.0.0" => include_str!("../../schema/forge-v1.0.0.schema.json"),
         "5.0.0" => include_str!("../../schema/forge-v5.0.0.schema.json"),
+        _ if is_legacy_version(version) => {
+            return Err(ForgeError::Validation(format!(
+                "Legacy _forge_version: '{version}'. Pre-1.0 scalar models are no longer parsed directly; \
+                 run 'forge upgrade <file>' to migrate it to v5.0.0"
+            )));
+        },
         _ => {
             return Err(ForgeError::Validation(format!(
                 "Unsupported _forge_version: '{version}'. Supported versions: 1.0.0 (scalar-only), 5.0.0 (arrays/tables)"
@@ -38,8 +101,12 @@ pub fn validate_against_schema(yaml: &Value) -> ForgeResult<()> {
         .map_err(|e| ForgeError::Validation(format!("Failed to parse schema: {e}")))?;
 
     // Convert YAML to JSON for validation
-    let json_value: serde_json::Value = serde_json::to_value(yaml)
+    let mut json_value: serde_json::Value = serde_json::to_value(yaml)
         .map_err(|e| ForgeError::Validation(format!("Failed to convert YAML to JSON: {e}")))?;
+    if let Some(map) = json_value.as_object_mut() {
+        map.entry("_forge_version")
+            .or_insert_with(|| version.into());
+    }
 
     // Build the validator
     let validator = jsonschema::validator_for(&schema_value)
@@ -62,7 +129,15 @@ pub fn validate_against_schema(yaml: &Value) -> ForgeResult<()> {
         validate_v1_0_0_no_tables(yaml)?;
     }
 
-    Ok(())
+    Ok(declared
+        .is_none()
+        .then(|| format!("No _forge_version found; assuming {version}")))
+}
+
+/// Pre-1.0 scalar model version (e.g. "0.2.0"), superseded by the v1.0.0 array model
+#[must_use]
+pub fn is_legacy_version(version: &str) -> bool {
+    version.starts_with("0.")
 }
 
 /// Runtime validation: v1.0.0 models must NOT contain tables (arrays)
@@ -283,4 +358,35 @@ price:
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Unsupported"));
     }
+
+    #[test]
+    fn test_detect_version_tables_imply_v5() {
+        let yaml: Value =
+            serde_yaml_ng::from_str("sales:\n  revenue: [100, 200]\n  cost: [60, 90]\n").unwrap();
+        assert_eq!(detect_version(&yaml), "5.0.0");
+        assert!(validate_with_fallback(&yaml, Some("auto")).is_ok());
+    }
+
+    #[test]
+    fn test_detect_version_scalars_imply_v1() {
+        let yaml: Value = serde_yaml_ng::from_str(
+            "price:\n  value: 10\n  formula: null\ntotal:\n  value: null\n  formula: \"=price * 2\"\n",
+        )
+        .unwrap();
+        assert_eq!(detect_version(&yaml), "1.0.0");
+        assert_eq!(
+            validate_with_fallback(&yaml, Some("auto"))
+                .unwrap()
+                .as_deref(),
+            Some("No _forge_version found; assuming 1.0.0")
+        );
+    }
+
+    #[test]
+    fn test_missing_version_without_fallback_is_an_error() {
+        let yaml: Value = serde_yaml_ng::from_str("price:\n  value: 10\n").unwrap();
+        let err = validate_with_fallback(&yaml, None).unwrap_err().to_string();
+        assert!(err.contains("_forge_version") && err.contains("--assume-version"));
+        assert!(validate_with_fallback(&yaml, Some("5.0.0")).is_ok());
+    }
 }
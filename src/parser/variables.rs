@@ -2,19 +2,27 @@
 //!
 //! Handles parsing of tables (with columns and row formulas) and scalar variables.
 
+use crate::core::array_calculator::evaluator::ErrorKind;
 use crate::error::{ForgeError, ForgeResult};
 use crate::types::{Column, Metadata, Table, Variable};
 use serde_yaml_ng::Value;
 
-use super::arrays::parse_array_value;
+use super::arrays::{error_code, parse_array_value};
+use super::limits::ParseLimits;
 
 /// Parse a table from a YAML mapping (v4.0 enhanced with metadata)
 ///
 /// # Errors
 ///
-/// Returns an error if a column name is not a string, or column data has an invalid type or format.
-pub fn parse_table(name: &str, map: &serde_yaml_ng::Mapping) -> ForgeResult<Table> {
+/// Returns an error if a column name is not a string, column data has an invalid type or format,
+/// or the table exceeds `limits`.
+pub fn parse_table(
+    name: &str,
+    map: &serde_yaml_ng::Mapping,
+    limits: ParseLimits,
+) -> ForgeResult<Table> {
     let mut table = Table::new(name.to_string());
+    let mut cells = 0;
 
     for (key, value) in map {
         let col_name = key
@@ -39,6 +47,7 @@ pub fn parse_table(name: &str, map: &serde_yaml_ng::Mapping) -> ForgeResult<Tabl
         if let Value::Mapping(col_map) = value {
             // Check if it has a 'value' key with an array (v4.0 rich format)
             if let Some(Value::Sequence(seq)) = col_map.get("value") {
+                cells = limits.check_column(name, col_name, seq.len(), cells)?;
                 let column_value = parse_array_value(col_name, seq)?;
                 let metadata = parse_metadata(col_map);
                 let column = Column::with_metadata(col_name.to_string(), column_value, metadata);
@@ -60,6 +69,7 @@ pub fn parse_table(name: &str, map: &serde_yaml_ng::Mapping) -> ForgeResult<Tabl
 
         // Otherwise, it's a simple data column (array) - v1.0 format
         if let Value::Sequence(seq) = value {
+            cells = limits.check_column(name, col_name, seq.len(), cells)?;
             let column_value = parse_array_value(col_name, seq)?;
             let column = Column::new(col_name.to_string(), column_value);
             table.add_column(column);
@@ -80,7 +90,10 @@ pub fn parse_table(name: &str, map: &serde_yaml_ng::Mapping) -> ForgeResult<Tabl
 /// Returns an error if the value is not a mapping.
 pub fn parse_scalar_variable(value: &Value, path: &str) -> ForgeResult<Variable> {
     if let Value::Mapping(map) = value {
-        let val = map.get("value").and_then(serde_yaml_ng::Value::as_f64);
+        // An error code written back by `calculate` is read as the error value
+        let val = map
+            .get("value")
+            .and_then(|v| v.as_f64().or_else(|| error_code(v).map(ErrorKind::to_nan)));
         let formula = map
             .get("formula")
             .and_then(|f| f.as_str().map(std::string::ToString::to_string));
@@ -161,7 +174,7 @@ mod tests {
         let parsed: Value = serde_yaml_ng::from_str(yaml).unwrap();
 
         if let Value::Mapping(map) = parsed {
-            let table = parse_table("test_table", &map).unwrap();
+            let table = parse_table("test_table", &map, ParseLimits::default()).unwrap();
 
             assert_eq!(table.name, "test_table");
             assert_eq!(table.columns.len(), 2);
@@ -183,7 +196,7 @@ mod tests {
         let parsed: Value = serde_yaml_ng::from_str(yaml).unwrap();
 
         if let Value::Mapping(map) = parsed {
-            let table = parse_table("test_table", &map).unwrap();
+            let table = parse_table("test_table", &map, ParseLimits::default()).unwrap();
 
             assert_eq!(table.columns.len(), 2);
             assert_eq!(table.row_formulas.len(), 1);
@@ -350,7 +363,7 @@ price:
     fn test_parse_table_column_scalar_not_array() {
         let mut map = serde_yaml_ng::Mapping::new();
         map.insert(Value::String("col".to_string()), Value::Number(42.into()));
-        let result = parse_table("test", &map);
+        let result = parse_table("test", &map, ParseLimits::default());
         assert!(result.is_err());
     }
 }
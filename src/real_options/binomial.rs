@@ -4,6 +4,8 @@
 //! Supports early exercise and path-dependent features.
 //! Validated against `QuantLib`.
 
+use serde::{Deserialize, Serialize};
+
 /// Binomial tree model for option pricing
 pub struct BinomialTree {
     /// Spot price (current value)
@@ -20,14 +22,18 @@ pub struct BinomialTree {
     pub steps: usize,
     /// Dividend yield (continuous)
     pub dividend_yield: f64,
+    /// Exercise style used by the real-option valuations
+    pub exercise: OptionStyle,
 }
 
 /// Option style (American vs European)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum OptionStyle {
     /// Can only exercise at expiry
     European,
     /// Can exercise anytime
+    #[default]
     American,
 }
 
@@ -50,6 +56,7 @@ impl BinomialTree {
             maturity,
             steps,
             dividend_yield: 0.0,
+            exercise: OptionStyle::American,
         }
     }
 
@@ -60,6 +67,13 @@ impl BinomialTree {
         self
     }
 
+    /// Set the exercise style for real-option valuations
+    #[must_use]
+    pub const fn with_exercise(mut self, exercise: OptionStyle) -> Self {
+        self.exercise = exercise;
+        self
+    }
+
     /// Calculate time step
     // Truncation is mathematically impossible: steps is bounded by practical tree sizes (< 2^52)
     #[allow(clippy::cast_precision_loss)]
@@ -161,9 +175,10 @@ impl BinomialTree {
             max_deferral.min(self.maturity),
             self.steps,
         )
-        .with_dividend_yield(self.dividend_yield);
+        .with_dividend_yield(self.dividend_yield)
+        .with_exercise(self.exercise);
 
-        defer_tree.call_price(OptionStyle::American)
+        defer_tree.call_price(self.exercise)
     }
 
     /// Price an expand option
@@ -181,9 +196,10 @@ impl BinomialTree {
             self.maturity,
             self.steps,
         )
-        .with_dividend_yield(self.dividend_yield);
+        .with_dividend_yield(self.dividend_yield)
+        .with_exercise(self.exercise);
 
-        expand_tree.call_price(OptionStyle::American)
+        expand_tree.call_price(self.exercise)
     }
 
     /// Price an abandon option
@@ -199,9 +215,10 @@ impl BinomialTree {
             self.maturity,
             self.steps,
         )
-        .with_dividend_yield(self.dividend_yield);
+        .with_dividend_yield(self.dividend_yield)
+        .with_exercise(self.exercise);
 
-        abandon_tree.put_price(OptionStyle::American)
+        abandon_tree.put_price(self.exercise)
     }
 
     /// Price a contract option
@@ -219,9 +236,10 @@ impl BinomialTree {
             self.maturity,
             self.steps,
         )
-        .with_dividend_yield(self.dividend_yield);
+        .with_dividend_yield(self.dividend_yield)
+        .with_exercise(self.exercise);
 
-        contract_tree.put_price(OptionStyle::American)
+        contract_tree.put_price(self.exercise)
     }
 
     /// Get early exercise boundary (for American options)
@@ -360,6 +378,24 @@ mod binomial_tests {
         );
     }
 
+    #[test]
+    fn test_abandon_american_vs_european() {
+        let tree = BinomialTree::new(10_000_000.0, 10_000_000.0, 0.05, 0.30, 3.0, 100);
+        let american = tree
+            .with_exercise(OptionStyle::American)
+            .abandon_option_value(8_000_000.0);
+        let european = BinomialTree::new(10_000_000.0, 10_000_000.0, 0.05, 0.30, 3.0, 100)
+            .with_exercise(OptionStyle::European)
+            .abandon_option_value(8_000_000.0);
+
+        // Early exercise can only add value; a deep salvage put is strictly worth more
+        assert!(
+            american > european,
+            "American abandon ({american}) should exceed European ({european})"
+        );
+        assert!(european > 0.0);
+    }
+
     #[test]
     fn test_expand_option() {
         let tree = BinomialTree::new(10_000_000.0, 10_000_000.0, 0.05, 0.30, 3.0, 100);
@@ -2,6 +2,7 @@
 //!
 //! Handles parsing and validation of real options definitions from YAML.
 
+use super::binomial::OptionStyle;
 use serde::{Deserialize, Serialize};
 
 /// Type of real option
@@ -58,6 +59,9 @@ pub struct OptionDefinition {
     /// Contraction factor (for contract options)
     #[serde(default = "default_contraction_factor")]
     pub contraction_factor: f64,
+    /// Exercise style for binomial valuation (`american` or `european`)
+    #[serde(default)]
+    pub exercise: OptionStyle,
 }
 
 const fn default_expansion_factor() -> f64 {
@@ -80,6 +84,7 @@ impl OptionDefinition {
             max_deferral,
             expansion_factor: 1.0,
             contraction_factor: 1.0,
+            exercise: OptionStyle::American,
         }
     }
 
@@ -94,6 +99,7 @@ impl OptionDefinition {
             max_deferral: 0.0,
             expansion_factor,
             contraction_factor: 1.0,
+            exercise: OptionStyle::American,
         }
     }
 
@@ -108,6 +114,7 @@ impl OptionDefinition {
             max_deferral: 0.0,
             expansion_factor: 1.0,
             contraction_factor: 1.0,
+            exercise: OptionStyle::American,
         }
     }
 
@@ -122,8 +129,16 @@ impl OptionDefinition {
             max_deferral: 0.0,
             expansion_factor: 1.0,
             contraction_factor,
+            exercise: OptionStyle::American,
         }
     }
+
+    /// Set the exercise style
+    #[must_use]
+    pub const fn with_exercise(mut self, exercise: OptionStyle) -> Self {
+        self.exercise = exercise;
+        self
+    }
 }
 
 /// Underlying asset configuration
@@ -335,5 +350,17 @@ mod config_tests {
         let abandon = OptionDefinition::abandon("Exit", 200_000.0);
         assert_eq!(abandon.option_type, OptionType::Abandon);
         assert_eq!(abandon.salvage_value, 200_000.0);
+        assert_eq!(abandon.exercise, OptionStyle::American);
+    }
+
+    #[test]
+    fn test_exercise_style_parsing() {
+        let yaml = "type: abandon\nname: Exit\nsalvage_value: 100\nexercise: european\n";
+        let option: OptionDefinition = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(option.exercise, OptionStyle::European);
+
+        let yaml = "type: abandon\nname: Exit\nsalvage_value: 100\n";
+        let option: OptionDefinition = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(option.exercise, OptionStyle::American);
     }
 }
@@ -231,7 +231,8 @@ impl RealOptionsEngine {
             u.time_horizon,
             steps,
         )
-        .with_dividend_yield(u.dividend_yield);
+        .with_dividend_yield(u.dividend_yield)
+        .with_exercise(option.exercise);
 
         match option.option_type {
             OptionType::Defer => tree.defer_option_value(option.max_deferral, option.exercise_cost),
@@ -41,7 +41,7 @@ pub mod config;
 pub mod engine;
 
 // Re-exports
-pub use binomial::BinomialTree;
+pub use binomial::{BinomialTree, OptionStyle};
 pub use black_scholes::BlackScholes;
 pub use config::{
     OptionDefinition, OptionType, RealOptionsConfig, UnderlyingConfig, ValuationMethod,
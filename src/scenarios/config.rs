@@ -13,6 +13,9 @@ pub struct ScenarioDefinition {
     /// Human-readable description
     #[serde(default)]
     pub description: String,
+    /// Parent scenario whose scalar overrides are inherited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
     /// Scalar overrides for this scenario
     #[serde(default)]
     pub scalars: HashMap<String, ScalarOverride>,
@@ -105,6 +108,47 @@ impl ScenarioConfig {
         Ok(())
     }
 
+    /// Resolve `extends` so every scenario carries its inherited scalar overrides
+    ///
+    /// Overrides are merged root-first along the parent chain; a scenario's own
+    /// values always win. Probability and description are never inherited.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a scenario extends an unknown scenario or the
+    /// inheritance chain forms a cycle.
+    pub fn resolve_inheritance(&mut self) -> Result<(), String> {
+        let mut resolved: HashMap<String, HashMap<String, ScalarOverride>> = HashMap::new();
+
+        for (name, scenario) in &self.scenarios {
+            if scenario.extends.is_none() {
+                continue;
+            }
+
+            let chain = inheritance_chain(
+                name,
+                |n| self.scenarios.get(n).and_then(|d| d.extends.as_deref()),
+                |n| self.scenarios.contains_key(n),
+            )?;
+
+            let mut scalars = HashMap::new();
+            for ancestor in chain.iter().rev() {
+                if let Some(def) = self.scenarios.get(*ancestor) {
+                    scalars.extend(def.scalars.clone());
+                }
+            }
+            resolved.insert(name.clone(), scalars);
+        }
+
+        for (name, scalars) in resolved {
+            if let Some(scenario) = self.scenarios.get_mut(&name) {
+                scenario.scalars = scalars;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get scenario names
     pub fn scenario_names(&self) -> Vec<&str> {
         self.scenarios
@@ -126,6 +170,41 @@ impl ScenarioConfig {
     }
 }
 
+/// Follow `extends` links from `name` up to its root scenario
+///
+/// Returns the chain starting at `name`, so ancestors are applied by walking it
+/// in reverse. Shared by model-level scenarios and scenario analysis configs.
+///
+/// # Errors
+///
+/// Returns an error naming an unknown parent, or spelling out an inheritance
+/// cycle (`a -> b -> a`).
+pub(crate) fn inheritance_chain<'a>(
+    name: &'a str,
+    parent_of: impl Fn(&str) -> Option<&'a str>,
+    exists: impl Fn(&str) -> bool,
+) -> Result<Vec<&'a str>, String> {
+    let mut chain = vec![name];
+    let mut current = name;
+    while let Some(parent) = parent_of(current) {
+        if !exists(parent) {
+            return Err(format!(
+                "Scenario '{current}' extends unknown scenario '{parent}'"
+            ));
+        }
+        let is_cycle = chain.contains(&parent);
+        chain.push(parent);
+        if is_cycle {
+            return Err(format!(
+                "Scenario inheritance cycle: {}",
+                chain.join(" -> ")
+            ));
+        }
+        current = parent;
+    }
+    Ok(chain)
+}
+
 /// Builder pattern for `ScenarioDefinition`
 impl ScenarioDefinition {
     /// Create a new scenario with given probability
@@ -134,10 +213,18 @@ impl ScenarioDefinition {
         Self {
             probability,
             description: String::new(),
+            extends: None,
             scalars: HashMap::new(),
         }
     }
 
+    /// Inherit scalar overrides from another scenario
+    #[must_use]
+    pub fn with_extends(mut self, parent: &str) -> Self {
+        self.extends = Some(parent.to_string());
+        self
+    }
+
     /// Set the description
     #[must_use]
     pub fn with_description(mut self, description: &str) -> Self {
@@ -212,6 +299,47 @@ mod config_tests {
         assert!(result.unwrap_err().contains("sum to 1.0"));
     }
 
+    #[test]
+    fn test_resolve_inheritance() {
+        let mut config = ScenarioConfig::new();
+        config.add_scenario(
+            "base",
+            ScenarioDefinition::new(0.5)
+                .with_scalar("revenue_growth", 0.05)
+                .with_scalar("margin", 0.20)
+                .with_scalar("discount_rate", 0.10),
+        );
+        config.add_scenario(
+            "bull",
+            ScenarioDefinition::new(0.5)
+                .with_extends("base")
+                .with_scalar("revenue_growth", 0.15),
+        );
+
+        config.resolve_inheritance().unwrap();
+
+        let bull = config.get_scenario("bull").unwrap();
+        assert_eq!(bull.scalars.len(), 3);
+        assert_eq!(bull.scalars["revenue_growth"].as_value(), Some(0.15));
+        assert_eq!(bull.scalars["margin"].as_value(), Some(0.20));
+        assert_eq!(bull.scalars["discount_rate"].as_value(), Some(0.10));
+        assert!((bull.probability - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_resolve_inheritance_errors() {
+        let mut config = ScenarioConfig::new();
+        config.add_scenario("a", ScenarioDefinition::new(0.5).with_extends("b"));
+        config.add_scenario("b", ScenarioDefinition::new(0.5).with_extends("a"));
+        let err = config.resolve_inheritance().unwrap_err();
+        assert!(err.contains("inheritance cycle"), "got: {err}");
+
+        let mut config = ScenarioConfig::new();
+        config.add_scenario("a", ScenarioDefinition::new(1.0).with_extends("missing"));
+        let err = config.resolve_inheritance().unwrap_err();
+        assert!(err.contains("unknown scenario 'missing'"), "got: {err}");
+    }
+
     #[test]
     fn test_scalar_override_types() {
         let scenario = ScenarioDefinition::new(0.5)
@@ -6,7 +6,7 @@ use super::config::{ScalarOverride, ScenarioConfig, ScenarioDefinition};
 use crate::core::ArrayCalculator;
 use crate::types::{ParsedModel, Variable};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Result for a single scenario
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +34,12 @@ pub struct ScenarioResults {
     pub probability_positive: HashMap<String, f64>,
     /// Min/max ranges across scenarios
     pub ranges: HashMap<String, (f64, f64)>,
+    /// Confidence level used for the risk metrics (e.g. 0.95)
+    pub confidence: f64,
+    /// Value-at-Risk: outcome at the (1 - confidence) quantile of each variable, by name
+    pub value_at_risk: BTreeMap<String, f64>,
+    /// Conditional `VaR`: probability-weighted mean of outcomes at or below `VaR`
+    pub conditional_value_at_risk: BTreeMap<String, f64>,
 }
 
 impl ScenarioResults {
@@ -53,11 +59,15 @@ impl ScenarioResults {
     }
 }
 
+/// Default confidence level for `VaR`/`CVaR`
+pub const DEFAULT_CONFIDENCE: f64 = 0.95;
+
 /// Scenario Analysis Engine
 pub struct ScenarioEngine {
     config: ScenarioConfig,
     base_model: ParsedModel,
     output_variables: Vec<String>,
+    confidence: f64,
 }
 
 impl ScenarioEngine {
@@ -65,16 +75,26 @@ impl ScenarioEngine {
     ///
     /// # Errors
     ///
-    /// Returns an error if the scenario configuration is invalid.
-    pub fn new(config: ScenarioConfig, base_model: ParsedModel) -> Result<Self, String> {
+    /// Returns an error if the scenario configuration is invalid or its
+    /// `extends` inheritance cannot be resolved.
+    pub fn new(mut config: ScenarioConfig, base_model: ParsedModel) -> Result<Self, String> {
+        config.resolve_inheritance()?;
         config.validate()?;
         Ok(Self {
             config,
             base_model,
             output_variables: Vec::new(),
+            confidence: DEFAULT_CONFIDENCE,
         })
     }
 
+    /// Set the confidence level for `VaR`/`CVaR` (exclusive range 0 to 1)
+    #[must_use]
+    pub const fn with_confidence(mut self, confidence: f64) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
     /// Set which variables to track as outputs
     #[must_use]
     pub fn with_outputs(mut self, outputs: Vec<String>) -> Self {
@@ -91,8 +111,16 @@ impl ScenarioEngine {
     ///
     /// # Errors
     ///
-    /// Returns an error if any scenario calculation fails.
+    /// Returns an error if the confidence level is out of range or any
+    /// scenario calculation fails.
     pub fn run(&self) -> Result<ScenarioResults, String> {
+        if self.confidence <= 0.0 || self.confidence >= 1.0 {
+            return Err(format!(
+                "Confidence level must be between 0 and 1 (exclusive), got {}",
+                self.confidence
+            ));
+        }
+
         let mut scenario_results = Vec::new();
 
         // Run each scenario
@@ -110,11 +138,18 @@ impl ScenarioEngine {
         // Calculate ranges
         let ranges = self.calculate_ranges(&scenario_results);
 
+        // Calculate downside risk metrics
+        let (value_at_risk, conditional_value_at_risk) =
+            Self::calculate_risk_metrics(&scenario_results, self.confidence);
+
         Ok(ScenarioResults {
             scenarios: scenario_results,
             expected_values,
             probability_positive,
             ranges,
+            confidence: self.confidence,
+            value_at_risk,
+            conditional_value_at_risk,
         })
     }
 
@@ -246,6 +281,57 @@ impl ScenarioEngine {
         ranges
     }
 
+    /// Calculate Value-at-Risk and Conditional `VaR` for every variable
+    ///
+    /// Each variable's outcomes form a discrete distribution weighted by scenario
+    /// probability. `VaR` is the lowest outcome whose cumulative probability reaches
+    /// the `1 - confidence` tail; `CVaR` averages the outcomes inside that tail,
+    /// taking only the needed share of the scenario that straddles the boundary.
+    fn calculate_risk_metrics(
+        results: &[ScenarioResult],
+        confidence: f64,
+    ) -> (BTreeMap<String, f64>, BTreeMap<String, f64>) {
+        const EPSILON: f64 = 1e-9;
+
+        let mut var = BTreeMap::new();
+        let mut cvar = BTreeMap::new();
+        let tail = 1.0 - confidence;
+
+        let Some(first) = results.first() else {
+            return (var, cvar);
+        };
+
+        for var_name in first.scalars.keys() {
+            let mut outcomes: Vec<(f64, f64)> = results
+                .iter()
+                .filter_map(|r| r.scalars.get(var_name).map(|v| (*v, r.probability)))
+                .collect();
+            outcomes.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut cumulative = 0.0;
+            if let Some((value, _)) = outcomes.iter().find(|(_, p)| {
+                cumulative += p;
+                cumulative >= tail - EPSILON
+            }) {
+                var.insert(var_name.clone(), *value);
+            }
+
+            let mut remaining = tail;
+            let mut tail_sum = 0.0;
+            for (value, probability) in &outcomes {
+                if remaining <= EPSILON {
+                    break;
+                }
+                let weight = probability.min(remaining);
+                tail_sum += weight * value;
+                remaining -= weight;
+            }
+            cvar.insert(var_name.clone(), tail_sum / tail);
+        }
+
+        (var, cvar)
+    }
+
     /// Get the scenario configuration
     #[must_use]
     pub const fn config(&self) -> &ScenarioConfig {
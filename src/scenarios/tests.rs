@@ -228,6 +228,61 @@ fn test_ranges_calculation() {
     );
 }
 
+#[test]
+fn test_value_at_risk_and_cvar() {
+    let mut config = ScenarioConfig::new();
+    config.add_scenario(
+        "base",
+        ScenarioDefinition::new(0.60).with_scalar("npv", 500.0),
+    );
+    config.add_scenario(
+        "bull",
+        ScenarioDefinition::new(0.30).with_scalar("npv", 900.0),
+    );
+    config.add_scenario(
+        "bear",
+        ScenarioDefinition::new(0.10).with_scalar("npv", -200.0),
+    );
+
+    let mut model = ParsedModel::new();
+    model.scalars.insert(
+        "npv".to_string(),
+        Variable::new("npv".to_string(), Some(0.0), None),
+    );
+
+    let engine = ScenarioEngine::new(config, model)
+        .unwrap()
+        .with_confidence(0.80);
+
+    let results = engine.run().unwrap();
+    assert!((results.confidence - 0.80).abs() < f64::EPSILON);
+
+    // 20% tail: bear (10%) alone is not enough, so VaR lands on base (cumulative 70%)
+    let var = results.value_at_risk.get("npv").unwrap();
+    assert!((var - 500.0).abs() < 0.01, "Expected VaR of 500, got {var}");
+
+    // CVaR = (0.10*(-200) + 0.10*500) / 0.20 = 150
+    let cvar = results.conditional_value_at_risk.get("npv").unwrap();
+    assert!(
+        (cvar - 150.0).abs() < 0.01,
+        "Expected CVaR of 150, got {cvar}"
+    );
+}
+
+#[test]
+fn test_invalid_confidence_rejected() {
+    let config = create_three_scenario_config();
+    let model = create_profit_model();
+
+    let engine = ScenarioEngine::new(config, model)
+        .unwrap()
+        .with_confidence(1.0);
+
+    let result = engine.run();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("Confidence level"));
+}
+
 #[test]
 fn test_invalid_probabilities_rejected() {
     let mut config = ScenarioConfig::new();
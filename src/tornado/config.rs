@@ -2,8 +2,12 @@
 //!
 //! Handles parsing and validation of sensitivity analysis definitions.
 
+use crate::types::ParsedModel;
 use serde::{Deserialize, Serialize};
 
+/// Relative swing applied to each input when no `tornado:` section is given
+pub const DEFAULT_AUTO_SWING: f64 = 0.10;
+
 /// Configuration for an input variable range
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InputRange {
@@ -95,6 +99,28 @@ impl TornadoConfig {
         }
     }
 
+    /// Derive a configuration from a model: every scalar with a non-zero
+    /// value and no formula is varied by `swing` (relative) around its value
+    #[must_use]
+    pub fn from_model(model: &ParsedModel, output: &str, swing: f64) -> Self {
+        let mut inputs: Vec<InputRange> = model
+            .scalars
+            .iter()
+            .filter(|(_, var)| var.formula.is_none())
+            .filter_map(|(name, var)| {
+                let value = var.value.filter(|v| *v != 0.0 && v.is_finite())?;
+                let (a, b) = (value * (1.0 - swing), value * (1.0 + swing));
+                Some(InputRange::new(name, a.min(b), a.max(b)).with_base(value))
+            })
+            .collect();
+        inputs.sort_by(|a, b| a.name.cmp(&b.name));
+        Self {
+            output: output.to_string(),
+            inputs,
+            steps: default_steps(),
+        }
+    }
+
     /// Add an input variable
     #[must_use]
     pub fn with_input(mut self, input: InputRange) -> Self {
@@ -1,5 +1,8 @@
+use crate::core::unit_validator::{UnitValidator, WarningSeverity};
+use crate::error::{ForgeError, ForgeResult};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
 
 //==============================================================================
 // Forge v1.0.0 Array Model Types
@@ -94,6 +97,9 @@ pub struct Table {
     pub columns: HashMap<String, Column>,
     /// Row-wise formulas (e.g., "profit: =revenue - expenses")
     pub row_formulas: HashMap<String, String>,
+    /// Formula column names in the order they were added
+    #[serde(skip)]
+    pub formula_order: Vec<String>,
 }
 
 impl Table {
@@ -103,6 +109,7 @@ impl Table {
             name,
             columns: HashMap::new(),
             row_formulas: HashMap::new(),
+            formula_order: Vec::new(),
         }
     }
 
@@ -111,6 +118,7 @@ impl Table {
     }
 
     pub fn add_row_formula(&mut self, name: String, formula: String) {
+        self.formula_order.push(name.clone());
         self.row_formulas.insert(name, formula);
     }
 
@@ -250,6 +258,9 @@ pub struct ResolvedInclude {
 pub struct Scenario {
     /// Variable overrides for this scenario (`variable_name` -> value)
     pub overrides: HashMap<String, f64>,
+    /// Column replacements for this scenario (`table.column` -> values)
+    #[serde(default)]
+    pub column_overrides: HashMap<String, Vec<f64>>,
 }
 
 impl Scenario {
@@ -257,12 +268,67 @@ impl Scenario {
     pub fn new() -> Self {
         Self {
             overrides: HashMap::new(),
+            column_overrides: HashMap::new(),
         }
     }
 
     pub fn add_override(&mut self, name: String, value: f64) {
         self.overrides.insert(name, value);
     }
+
+    pub fn add_column_override(&mut self, column: String, values: Vec<f64>) {
+        self.column_overrides.insert(column, values);
+    }
+}
+
+//==============================================================================
+// Named Ranges
+//==============================================================================
+
+/// An alias for a column, a scalar, or a slice of a column (`names:` section)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamedRange {
+    /// Target reference: `table.column` or a scalar name
+    pub reference: String,
+    /// Half-open row range `[start, end)` applied to a column target
+    #[serde(default)]
+    pub slice: Option<(usize, usize)>,
+}
+
+impl NamedRange {
+    /// Parse `table.column`, `table.column[start:end]` or a scalar name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slice suffix is malformed or `start > end`.
+    pub fn parse(target: &str) -> Result<Self, String> {
+        let target = target.trim();
+        let Some(open) = target.find('[') else {
+            return Ok(Self {
+                reference: target.to_string(),
+                slice: None,
+            });
+        };
+        let bounds = target[open + 1..]
+            .strip_suffix(']')
+            .and_then(|inner| inner.split_once(':'))
+            .ok_or_else(|| format!("Invalid slice in '{target}': expected [start:end]"))?;
+        let parse_bound = |b: &str| {
+            b.trim()
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid slice bound '{}' in '{target}'", b.trim()))
+        };
+        let (start, end) = (parse_bound(bounds.0)?, parse_bound(bounds.1)?);
+        if start > end {
+            return Err(format!(
+                "Invalid slice in '{target}': start {start} is after end {end}"
+            ));
+        }
+        Ok(Self {
+            reference: target[..open].trim().to_string(),
+            slice: Some((start, end)),
+        })
+    }
 }
 
 //==============================================================================
@@ -296,6 +362,25 @@ pub struct ParsedModel {
     /// Empty for single-document files
     #[serde(default)]
     pub documents: Vec<String>,
+
+    /// Byte range of each document's text in the source file, parallel to
+    /// `documents`; used to write calculated values back in place
+    #[serde(skip)]
+    pub document_spans: Vec<Range<usize>>,
+
+    /// Named ranges from the `names:` section (alias -> target)
+    #[serde(default)]
+    pub names: HashMap<String, NamedRange>,
+
+    /// Table and scalar names in the order they were added; breaks ties in
+    /// the calculation order
+    #[serde(skip)]
+    pub declaration_order: Vec<String>,
+
+    /// Non-fatal findings while parsing, such as an assumed `_forge_version`;
+    /// the CLI prints them
+    #[serde(skip)]
+    pub parse_warnings: Vec<String>,
 }
 
 impl ParsedModel {
@@ -309,6 +394,10 @@ impl ParsedModel {
             includes: Vec::new(),
             resolved_includes: HashMap::new(),
             documents: Vec::new(),
+            document_spans: Vec::new(),
+            names: HashMap::new(),
+            declaration_order: Vec::new(),
+            parse_warnings: Vec::new(),
         }
     }
 
@@ -368,14 +457,101 @@ impl ParsedModel {
         self.scenarios.keys().collect()
     }
 
+    /// Scalars each short name refers to (`price` for `summary.price`), in name order
+    ///
+    /// A short name that is also the full name of a scalar means that scalar
+    /// and is left out. In a formula of a scalar in `section` (`summary` for
+    /// `summary.margin`), a short name defined in that section means that
+    /// scalar. Otherwise a short name with more than one scalar is ambiguous.
+    #[must_use]
+    pub fn short_scalar_names(&self, section: Option<&str>) -> BTreeMap<&str, Vec<&String>> {
+        let mut names: Vec<&String> = self.scalars.keys().collect();
+        names.sort();
+        let mut short_names: BTreeMap<&str, Vec<&String>> = BTreeMap::new();
+        for name in names {
+            if let Some((_, short_name)) = name.rsplit_once('.') {
+                if !self.scalars.contains_key(short_name) {
+                    short_names.entry(short_name).or_default().push(name);
+                }
+            }
+        }
+        if let Some(section) = section {
+            for (short_name, targets) in &mut short_names {
+                let scoped = format!("{section}.{short_name}");
+                if let Some(name) = targets.iter().copied().find(|name| **name == scoped) {
+                    *targets = vec![name];
+                }
+            }
+        }
+        short_names
+    }
+
     pub fn add_table(&mut self, table: Table) {
+        self.declaration_order.push(table.name.clone());
         self.tables.insert(table.name.clone(), table);
     }
 
     pub fn add_scalar(&mut self, name: String, variable: Variable) {
+        self.declaration_order.push(name.clone());
         self.scalars.insert(name, variable);
     }
 
+    /// Add a named range (`names:` section)
+    pub fn add_name(&mut self, alias: String, range: NamedRange) {
+        self.names.insert(alias, range);
+    }
+
+    /// Check that every named range has a valid alias and an existing target
+    ///
+    /// A column target is `table.column` (data or formula column); anything
+    /// else must be a scalar. Slices are only allowed on columns, and must lie
+    /// within a data column's length. Returns one message per problem, sorted
+    /// by alias.
+    #[must_use]
+    pub fn check_names(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut aliases: Vec<&String> = self.names.keys().collect();
+        aliases.sort();
+        for alias in aliases {
+            let range = &self.names[alias];
+            let reference = &range.reference;
+            if alias.is_empty() || !alias.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                errors.push(format!(
+                    "name '{alias}': aliases may only contain letters, digits and '_'"
+                ));
+            } else if self.tables.contains_key(alias) || self.scalars.contains_key(alias) {
+                errors.push(format!("name '{alias}': already used by a table or scalar"));
+            }
+            let table = reference
+                .split_once('.')
+                .and_then(|(t, c)| self.tables.get(t).map(|table| (table, c)));
+            match (table, range.slice) {
+                (Some((table, column)), slice) => {
+                    let len = table.columns.get(column).map(Column::len);
+                    if len.is_none() && !table.row_formulas.contains_key(column) {
+                        errors.push(format!("name '{alias}': unknown column '{reference}'"));
+                    } else if let (Some(len), Some((_, end))) = (len, slice) {
+                        if end > len {
+                            errors.push(format!(
+                                "name '{alias}': slice end {end} is past the {len} rows of '{reference}'"
+                            ));
+                        }
+                    }
+                },
+                (None, _) if !self.scalars.contains_key(reference) => {
+                    errors.push(format!("name '{alias}': unknown reference '{reference}'"));
+                },
+                (None, Some(_)) => {
+                    errors.push(format!(
+                        "name '{alias}': scalar '{reference}' cannot be sliced"
+                    ));
+                },
+                (None, None) => {},
+            }
+        }
+        errors
+    }
+
     pub fn add_aggregation(&mut self, name: String, formula: String) {
         self.aggregations.insert(name, formula);
     }
@@ -423,6 +599,216 @@ impl ParsedModel {
         // No match — return as-is (caller creates a new top-level scalar)
         Ok(name.to_string())
     }
+
+    /// Check that a scenario column override (`table.column`) can be applied
+    ///
+    /// Returns the table and column names on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reference is not `table.column`, the table or
+    /// column does not exist, the column is not numeric, or the number of
+    /// values differs from the table's row count.
+    pub fn check_column_override<'a>(
+        &self,
+        target: &'a str,
+        values: &[f64],
+    ) -> Result<(&'a str, &'a str), String> {
+        let (table_name, column_name) = target
+            .rsplit_once('.')
+            .ok_or_else(|| format!("Column override '{target}' must be 'table.column'"))?;
+        let table = self
+            .tables
+            .get(table_name)
+            .ok_or_else(|| format!("Column override '{target}': table '{table_name}' not found"))?;
+
+        match table.columns.get(column_name) {
+            Some(column) if !matches!(column.values, ColumnValue::Number(_)) => {
+                return Err(format!(
+                    "Column override '{target}': column '{column_name}' is not numeric"
+                ));
+            },
+            Some(_) => {},
+            None if table.row_formulas.contains_key(column_name) => {},
+            None => {
+                return Err(format!(
+                    "Column override '{target}': column '{column_name}' not found in table '{table_name}'"
+                ));
+            },
+        }
+
+        let rows = table.row_count();
+        if values.len() != rows {
+            return Err(format!(
+                "Column override '{target}' has {} values but table '{table_name}' has {rows} rows",
+                values.len()
+            ));
+        }
+
+        Ok((table_name, column_name))
+    }
+
+    /// Replace a table column with scenario values
+    ///
+    /// A calculated column loses its row formula, the same way scalar
+    /// overrides clear their formula.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the override is invalid (see
+    /// [`ParsedModel::check_column_override`]).
+    pub fn override_column(&mut self, target: &str, values: &[f64]) -> Result<(), String> {
+        let (table_name, column_name) = self.check_column_override(target, values)?;
+        if let Some(table) = self.tables.get_mut(table_name) {
+            if let Some(column) = table.columns.get_mut(column_name) {
+                column.values = ColumnValue::Number(values.to_vec());
+            } else {
+                table.row_formulas.remove(column_name);
+                table.add_column(Column::new(
+                    column_name.to_string(),
+                    ColumnValue::Number(values.to_vec()),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the model without calculating it
+    ///
+    /// Works on models built in memory as well as parsed ones. Checks that every
+    /// table's columns have the same length, formulas start with `=`, no name is
+    /// both a data column and a row formula, scenario overrides resolve
+    /// unambiguously, scenario column overrides match their table, and formula
+    /// units are consistent.
+    ///
+    /// Returns non-fatal findings (unit mismatches, empty tables, scalars with
+    /// neither a value nor a formula) as warnings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Validation` error listing every hard failure found.
+    pub fn validate(&self) -> ForgeResult<Vec<Warning>> {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+
+        let mut table_names: Vec<&String> = self.tables.keys().collect();
+        table_names.sort();
+        for table_name in table_names {
+            let table = &self.tables[table_name];
+            if table.columns.is_empty() && table.row_formulas.is_empty() {
+                warnings.push(Warning::new(table_name, "table has no columns"));
+            }
+            if let Err(e) = table.validate_lengths() {
+                errors.push(format!("{table_name}: {e}"));
+            }
+            let mut formula_names: Vec<&String> = table.row_formulas.keys().collect();
+            formula_names.sort();
+            for col_name in formula_names {
+                if table.columns.contains_key(col_name) {
+                    errors.push(format!(
+                        "{table_name}.{col_name}: defined as both a data column and a formula"
+                    ));
+                }
+                if !table.row_formulas[col_name].starts_with('=') {
+                    errors.push(format!(
+                        "{table_name}.{col_name}: formula must start with '='"
+                    ));
+                }
+            }
+        }
+
+        let mut scalar_names: Vec<&String> = self.scalars.keys().collect();
+        scalar_names.sort();
+        for name in scalar_names {
+            let scalar = &self.scalars[name];
+            match &scalar.formula {
+                Some(formula) if !formula.starts_with('=') => {
+                    errors.push(format!("{name}: formula must start with '='"));
+                },
+                None if scalar.value.is_none() => {
+                    warnings.push(Warning::new(
+                        name,
+                        "scalar has neither a value nor a formula",
+                    ));
+                },
+                _ => {},
+            }
+        }
+
+        let mut scenario_names: Vec<&String> = self.scenarios.keys().collect();
+        scenario_names.sort();
+        for scenario_name in scenario_names {
+            let mut overrides: Vec<&String> =
+                self.scenarios[scenario_name].overrides.keys().collect();
+            overrides.sort();
+            for name in overrides {
+                if let Err(e) = self.resolve_scalar_name(name) {
+                    errors.push(format!("scenario '{scenario_name}': {e}"));
+                }
+            }
+            let scenario = &self.scenarios[scenario_name];
+            let mut columns: Vec<&String> = scenario.column_overrides.keys().collect();
+            columns.sort();
+            for target in columns {
+                if let Err(e) =
+                    self.check_column_override(target, &scenario.column_overrides[target])
+                {
+                    errors.push(format!("scenario '{scenario_name}': {e}"));
+                }
+            }
+        }
+
+        errors.extend(self.check_names());
+
+        for unit_warning in UnitValidator::new(self).validate() {
+            match unit_warning.severity {
+                WarningSeverity::Error => errors.push(format!(
+                    "{}: {} ({})",
+                    unit_warning.location, unit_warning.message, unit_warning.formula
+                )),
+                WarningSeverity::Warning => warnings.push(Warning::new(
+                    &unit_warning.location,
+                    &format!("{} ({})", unit_warning.message, unit_warning.formula),
+                )),
+            }
+        }
+        warnings.sort_by(|a, b| a.location.cmp(&b.location));
+
+        if errors.is_empty() {
+            Ok(warnings)
+        } else {
+            Err(ForgeError::Validation(format!(
+                "Model has {} error(s):\n  - {}",
+                errors.len(),
+                errors.join("\n  - ")
+            )))
+        }
+    }
+}
+
+/// A non-fatal issue reported by [`ParsedModel::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// Location (table, table.column, or scalar name)
+    pub location: String,
+    /// Description of the issue
+    pub message: String,
+}
+
+impl Warning {
+    #[must_use]
+    pub fn new(location: &str, message: &str) -> Self {
+        Self {
+            location: location.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
 }
 
 impl Default for ParsedModel {
@@ -727,6 +1113,51 @@ mod tests {
         assert_eq!(scenario.overrides.get("growth_rate"), Some(&0.15));
     }
 
+    #[test]
+    fn test_override_column() {
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("sales".to_string());
+        table.add_column(Column::new(
+            "growth".to_string(),
+            ColumnValue::Number(vec![0.01, 0.01, 0.01]),
+        ));
+        table.add_column(Column::new(
+            "month".to_string(),
+            ColumnValue::Text(vec!["Jan".into(), "Feb".into(), "Mar".into()]),
+        ));
+        table.add_row_formula("revenue".to_string(), "=growth * 100".to_string());
+        model.add_table(table);
+
+        model
+            .override_column("sales.growth", &[0.05, 0.06, 0.07])
+            .unwrap();
+        model
+            .override_column("sales.revenue", &[1.0, 2.0, 3.0])
+            .unwrap();
+        let table = &model.tables["sales"];
+        assert_eq!(
+            table.columns["growth"].values,
+            ColumnValue::Number(vec![0.05, 0.06, 0.07])
+        );
+        assert!(!table.row_formulas.contains_key("revenue"));
+
+        let err = model.override_column("sales.growth", &[0.05]).unwrap_err();
+        assert!(
+            err.contains("has 1 values but table 'sales' has 3 rows"),
+            "{err}"
+        );
+        assert!(model
+            .override_column("sales.month", &[1.0, 2.0, 3.0])
+            .is_err());
+        assert!(model
+            .override_column("sales.missing", &[1.0, 2.0, 3.0])
+            .is_err());
+        assert!(model
+            .override_column("costs.growth", &[1.0, 2.0, 3.0])
+            .is_err());
+        assert!(model.override_column("growth", &[1.0, 2.0, 3.0]).is_err());
+    }
+
     // =========================================================================
     // ParsedModel Tests
     // =========================================================================
@@ -985,4 +1416,49 @@ mod tests {
         // Unknown name passes through (caller creates new scalar)
         assert_eq!(model.resolve_scalar_name("unknown").unwrap(), "unknown");
     }
+
+    #[test]
+    fn test_validate_programmatic_model_ok() {
+        let mut table = Table::new("sales".into());
+        let mut revenue = Column::new("revenue".into(), ColumnValue::Number(vec![1.0, 2.0]));
+        revenue.metadata.unit = Some("USD".into());
+        table.add_column(revenue);
+        let mut pct = Column::new("pct".into(), ColumnValue::Number(vec![0.1, 0.2]));
+        pct.metadata.unit = Some("%".into());
+        table.add_column(pct);
+        table.add_row_formula("mixed".into(), "=revenue + pct".into());
+
+        let mut model = ParsedModel::new();
+        model.add_table(table);
+        model.add_table(Table::new("empty".into()));
+        model.add_scalar("total".into(), Variable::new("total".into(), None, None));
+
+        let warnings = model.validate().unwrap();
+        let locations: Vec<&str> = warnings.iter().map(|w| w.location.as_str()).collect();
+        assert_eq!(locations, vec!["empty", "sales.mixed", "total"]);
+    }
+
+    #[test]
+    fn test_validate_inconsistent_programmatic_model() {
+        let mut table = Table::new("sales".into());
+        table.add_column(Column::new(
+            "revenue".into(),
+            ColumnValue::Number(vec![1.0, 2.0, 3.0]),
+        ));
+        table.add_column(Column::new("cost".into(), ColumnValue::Number(vec![1.0])));
+        table.add_row_formula("cost".into(), "=revenue * 0.5".into());
+        table.add_row_formula("margin".into(), "revenue - cost".into());
+
+        let mut model = ParsedModel::new();
+        model.add_table(table);
+
+        let err = model.validate().unwrap_err().to_string();
+        assert!(err.contains("3 error(s)"), "{err}");
+        assert!(err.contains("expected"), "{err}");
+        assert!(err.contains("sales.cost: defined as both"), "{err}");
+        assert!(
+            err.contains("sales.margin: formula must start with '='"),
+            "{err}"
+        );
+    }
 }
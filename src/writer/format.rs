@@ -0,0 +1,326 @@
+//! Canonical YAML formatting for `forge fmt`
+//!
+//! Re-emits a model with two-space indentation, metadata keys first,
+//! `value`/`formula` leading each variable, flow-style arrays of plain values
+//! and every formula double-quoted. Formatting works on the YAML value tree, so
+//! the data is unchanged. A document's leading comment block is kept; a
+//! document with comments anywhere else is left unformatted rather than lose them.
+
+use crate::error::{ForgeError, ForgeResult};
+use crate::parser::{detect_multi_document, split_yaml_documents};
+use serde_yaml_ng::{Mapping, Value};
+use std::fmt::Write;
+
+/// Root keys emitted first, in this order
+const ROOT_KEY_ORDER: &[&str] = &["_forge_version", "_name", "_includes"];
+
+/// Variable keys emitted first, in this order
+const VARIABLE_KEY_ORDER: &[&str] = &["value", "formula"];
+
+const INDENT: usize = 2;
+
+/// Serialize one YAML document in canonical Forge style
+#[must_use]
+pub fn to_yaml(yaml: &Value) -> String {
+    let mut out = String::new();
+    match yaml {
+        Value::Mapping(map) => write_mapping(&mut out, map, 0, ROOT_KEY_ORDER),
+        other => {
+            out.push_str(&scalar(other));
+            out.push('\n');
+        },
+    }
+    out
+}
+
+/// Format YAML source (single or multi-document) in canonical Forge style
+///
+/// # Errors
+///
+/// Returns an error if the source is not valid YAML, has comments after a
+/// document's leading comment block, or if the formatted output would not read
+/// back as the same data.
+pub fn format_yaml(content: &str) -> ForgeResult<String> {
+    if !detect_multi_document(content) {
+        return format_document(content);
+    }
+    let mut out = String::new();
+    for doc in split_yaml_documents(content) {
+        out.push_str("---\n");
+        out.push_str(&format_document(&doc)?);
+    }
+    Ok(out)
+}
+
+fn format_document(content: &str) -> ForgeResult<String> {
+    let yaml: Value = serde_yaml_ng::from_str(content)?;
+    if let Some(comment) = body_comment(content) {
+        return Err(ForgeError::Validation(format!(
+            "Formatting would drop the comment '{comment}'; file left unformatted"
+        )));
+    }
+    let formatted = leading_comments(content) + &to_yaml(&yaml);
+    let reparsed: Value = serde_yaml_ng::from_str(&formatted)?;
+    if reparsed != yaml {
+        return Err(ForgeError::Validation(
+            "Formatting would change the model's data; file left unformatted".to_string(),
+        ));
+    }
+    Ok(formatted)
+}
+
+/// The comment block before the first YAML line, with trailing blank lines collapsed
+fn leading_comments(content: &str) -> String {
+    let header: Vec<&str> = content
+        .lines()
+        .take_while(|line| line.trim().is_empty() || line.trim_start().starts_with('#'))
+        .collect();
+    let header = header.join("\n");
+    let header = header.trim();
+    if header.is_empty() {
+        String::new()
+    } else {
+        format!("{header}\n\n")
+    }
+}
+
+/// The first comment after the leading comment block, if any
+///
+/// A `#` starts a comment at the start of a line or after whitespace, outside a
+/// quoted scalar (so `"#DIV/0!"` is not one). A `#` line inside a block scalar
+/// also counts, which only makes formatting more cautious.
+fn body_comment(content: &str) -> Option<&str> {
+    content
+        .lines()
+        .skip_while(|line| line.trim().is_empty() || line.trim_start().starts_with('#'))
+        .find_map(|line| {
+            let mut quote = None;
+            let mut escaped = false;
+            let mut prev = ' ';
+            for (i, c) in line.char_indices() {
+                match quote {
+                    Some(_) if escaped => escaped = false,
+                    Some('"') if c == '\\' => escaped = true,
+                    Some(q) if c == q => quote = None,
+                    // Quotes only open a scalar where one starts, not mid-word (`it's`)
+                    None if (c == '"' || c == '\'')
+                        && (prev.is_whitespace() || "[{,".contains(prev)) =>
+                    {
+                        quote = Some(c);
+                    },
+                    None if c == '#' && prev.is_whitespace() => return Some(line[i..].trim_end()),
+                    Some(_) | None => {},
+                }
+                prev = c;
+            }
+            None
+        })
+}
+
+fn write_mapping(out: &mut String, map: &Mapping, indent: usize, key_order: &[&str]) {
+    for (key, value) in ordered_entries(map, key_order) {
+        let _ = write!(out, "{:indent$}{}:", "", scalar(key));
+        match value.as_str() {
+            // Keep the version a string at a glance, as in every example model
+            Some(version) if indent == 0 && key.as_str() == Some("_forge_version") => {
+                let _ = writeln!(out, " {}", double_quoted(version));
+            },
+            _ => write_nested(out, value, indent),
+        }
+    }
+}
+
+/// Write the value after a `key:` (or `-`), inline when it fits on the same line
+fn write_nested(out: &mut String, value: &Value, indent: usize) {
+    match value {
+        Value::Mapping(map) if !map.is_empty() => {
+            out.push('\n');
+            let order = if is_variable(map) {
+                VARIABLE_KEY_ORDER
+            } else {
+                &[]
+            };
+            write_mapping(out, map, indent + INDENT, order);
+        },
+        Value::Sequence(items) if !items.iter().all(is_plain) => {
+            out.push('\n');
+            for item in items {
+                let mut entry = String::new();
+                write_nested(&mut entry, item, indent + INDENT);
+                // Pull the first line of a block item up onto the "- " line
+                let _ = write!(
+                    out,
+                    "{:width$}- {}",
+                    "",
+                    entry.trim_start(),
+                    width = indent + INDENT
+                );
+            }
+        },
+        _ => {
+            out.push(' ');
+            out.push_str(&inline(value));
+            out.push('\n');
+        },
+    }
+}
+
+/// Entries with `key_order` keys first, then the rest in source order
+fn ordered_entries<'a>(map: &'a Mapping, key_order: &[&str]) -> Vec<(&'a Value, &'a Value)> {
+    let rank = |key: &Value| {
+        key.as_str()
+            .and_then(|k| key_order.iter().position(|o| *o == k))
+            .unwrap_or(key_order.len())
+    };
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by_key(|(key, _)| rank(key));
+    entries
+}
+
+/// A scalar variable: a mapping with a `value` or `formula` key
+fn is_variable(map: &Mapping) -> bool {
+    map.contains_key("value") || map.contains_key("formula")
+}
+
+/// A value that can be written on one line
+fn is_plain(value: &Value) -> bool {
+    match value {
+        Value::Mapping(map) => map.is_empty(),
+        Value::Sequence(items) => items.iter().all(is_plain),
+        Value::Tagged(_) => false,
+        _ => true,
+    }
+}
+
+/// One-line form of a plain value (flow style for arrays)
+fn inline(value: &Value) -> String {
+    match value {
+        Value::Sequence(items) => {
+            let items: Vec<String> = items.iter().map(inline).collect();
+            format!("[{}]", items.join(", "))
+        },
+        Value::Mapping(_) => "{}".to_string(),
+        other => scalar(other),
+    }
+}
+
+/// Scalar text: formulas are always double-quoted, other strings only when needed
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(s) if s.starts_with('=') => double_quoted(s),
+        Value::String(s) => {
+            let emitted = emit(value);
+            // Block scalars (multi-line strings) cannot sit after a key
+            if emitted.contains('\n') {
+                double_quoted(s)
+            } else {
+                emitted
+            }
+        },
+        other => emit(other),
+    }
+}
+
+/// A double-quoted YAML string (JSON string escapes are valid YAML)
+fn double_quoted(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| format!("{s:?}"))
+}
+
+/// serde's own rendering of a scalar, without the trailing newline
+fn emit(value: &Value) -> String {
+    serde_yaml_ng::to_string(value)
+        .map(|s| s.trim_end().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNTIDY: &str = r"sales:
+    revenue:
+        - 100
+        - 200
+    profit: '=revenue - cost'
+    cost: [60,   90]
+total:
+    formula: =SUM(sales.profit)
+    value: 150
+_forge_version: '5.0.0'
+_includes:
+  - as: shared
+    file: shared.yaml
+";
+
+    const CANONICAL: &str = r#"_forge_version: "5.0.0"
+_includes:
+  - as: shared
+    file: shared.yaml
+sales:
+  revenue: [100, 200]
+  profit: "=revenue - cost"
+  cost: [60, 90]
+total:
+  value: 150
+  formula: "=SUM(sales.profit)"
+"#;
+
+    #[test]
+    fn test_format_untidy_model() {
+        assert_eq!(format_yaml(UNTIDY).unwrap(), CANONICAL);
+    }
+
+    #[test]
+    fn test_format_is_idempotent() {
+        assert_eq!(format_yaml(CANONICAL).unwrap(), CANONICAL);
+    }
+
+    #[test]
+    fn test_format_keeps_strings_that_need_quotes() {
+        let yaml = "notes:\n  value: null\n  notes: \"yes\"\n  source: \"line one\\nline two\"\n";
+        let formatted = format_yaml(yaml).unwrap();
+        let original: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let reparsed: Value = serde_yaml_ng::from_str(&formatted).unwrap();
+        assert_eq!(original, reparsed);
+    }
+
+    #[test]
+    fn test_format_keeps_header_comments() {
+        let yaml = "\n# Budget model\n# Owner: finance\n\n\nrate: {value: 0.1}\n";
+        let formatted = format_yaml(yaml).unwrap();
+        assert_eq!(
+            formatted,
+            "# Budget model\n# Owner: finance\n\nrate:\n  value: 0.1\n"
+        );
+        assert_eq!(format_yaml(&formatted).unwrap(), formatted);
+    }
+
+    #[test]
+    fn test_format_multi_document() {
+        let yaml = "---\n_forge_version: '5.0.0'\na:\n  value: 1\n---\n_forge_version: '5.0.0'\nb:\n  value: 2\n";
+        assert_eq!(
+            format_yaml(yaml).unwrap(),
+            "---\n_forge_version: \"5.0.0\"\na:\n  value: 1\n---\n_forge_version: \"5.0.0\"\nb:\n  value: 2\n"
+        );
+    }
+
+    #[test]
+    fn test_format_refuses_to_drop_body_comments() {
+        let inline = "rate:\n  value: 0.1  # agreed with finance\n";
+        let err = format_yaml(inline).unwrap_err().to_string();
+        assert!(err.contains("'# agreed with finance'"), "{err}");
+
+        let own_line = "# Header\nrate:\n  value: 0.1\n# Costs\ncost:\n  value: 5\n";
+        let err = format_yaml(own_line).unwrap_err().to_string();
+        assert!(err.contains("'# Costs'"), "{err}");
+    }
+
+    #[test]
+    fn test_format_hash_inside_strings_is_not_a_comment() {
+        let yaml = "label:\n  value: null\n  notes: \"tag #1\"\n  source: 'a#b'\nid:\n  value: 1\n  formula: \"=IFERROR(x, 0)\"\n";
+        assert!(format_yaml(yaml).is_ok());
+        assert!(format_yaml("url:\n  source: http://example.com/#top\n").is_ok());
+        let err = format_yaml("note:\n  notes: it's done # really\n").unwrap_err();
+        assert!(err.to_string().contains("'# really'"), "{err}");
+    }
+}
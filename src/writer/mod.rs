@@ -1,4 +1,10 @@
+mod format;
+
+pub use format::{format_yaml, to_yaml};
+
+use crate::core::array_calculator::evaluator::ErrorKind;
 use crate::error::ForgeResult;
+use crate::parser::detect_multi_document;
 use crate::types::{ColumnValue, ParsedModel, Variable};
 use serde_yaml_ng::Value;
 use std::collections::HashMap;
@@ -39,41 +45,139 @@ pub fn update_yaml_file<S: BuildHasher>(
 ///
 /// Returns an error if the file cannot be read, parsed, backed up, or written back.
 pub fn write_calculated_results(path: &Path, result: &ParsedModel) -> ForgeResult<bool> {
+    write_calculated_results_rounded(path, result, None)
+}
+
+/// Write calculated results back to YAML file, optionally rounding for display
+///
+/// With `round` set, formula results are rounded to that many decimals; inputs
+/// are written back as entered. Only the file output is affected; the
+/// calculation itself is done at full precision.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, parsed, backed up, or written back.
+pub fn write_calculated_results_rounded(
+    path: &Path,
+    result: &ParsedModel,
+    round: Option<u32>,
+) -> ForgeResult<bool> {
+    write_calculated_results_to(path, path, result, round)
+}
+
+/// Write calculated results from `source` into `dest`, leaving `source` untouched
+///
+/// The structure of `source` is preserved as in
+/// [`write_calculated_results_rounded`]. A backup is only made when `dest` is
+/// `source` itself; a separate destination is simply overwritten.
+///
+/// # Errors
+///
+/// Returns an error if the source cannot be read or parsed, or the
+/// destination (or backup) cannot be written.
+pub fn write_calculated_results_to(
+    source: &Path,
+    dest: &Path,
+    result: &ParsedModel,
+    round: Option<u32>,
+) -> ForgeResult<bool> {
     // Read original content to check for multi-document YAML
-    let content = fs::read_to_string(path)?;
-    let content_trimmed = content.trim_start();
+    let content = fs::read_to_string(source)?;
 
-    // Check if this is a multi-document YAML file (v4.4.2)
-    // Multi-doc files cannot be written back directly - skip with warning
-    if content_trimmed.starts_with("---") && content_trimmed[3..].contains("\n---") {
-        return Ok(false); // Indicate write was skipped
+    // Multi-document YAML (v4.4.2) is rewritten one document at a time
+    let updated_content = if detect_multi_document(&content) {
+        match update_documents(&content, result, round)? {
+            Some(updated) => updated,
+            None => return Ok(false), // Indicate write was skipped
+        }
+    } else {
+        // Read original YAML to preserve structure/comments
+        let mut yaml: Value = serde_yaml_ng::from_str(&content)?;
+        apply_results(&mut yaml, result, None, round);
+        serde_yaml_ng::to_string(&yaml)?
+    };
+
+    // Create backup when rewriting in place
+    if dest == source {
+        let backup_path = source.with_extension("yaml.bak");
+        fs::copy(source, &backup_path)?;
     }
 
-    // Create backup
-    let backup_path = path.with_extension("yaml.bak");
-    fs::copy(path, &backup_path)?;
+    // Write back to file
+    fs::write(dest, updated_content)?;
 
-    // Read original YAML to preserve structure/comments
-    let mut yaml: Value = serde_yaml_ng::from_str(&content)?;
+    Ok(true)
+}
+
+/// Re-emit a multi-document file with each document's values updated
+///
+/// Documents are located by the spans recorded when the model was parsed and
+/// rewritten in place, so separators, document order and `_name` fields are
+/// kept. Returns `None` if the model carries no spans for this content.
+fn update_documents(
+    content: &str,
+    result: &ParsedModel,
+    round: Option<u32>,
+) -> ForgeResult<Option<String>> {
+    if result.documents.is_empty() || result.documents.len() != result.document_spans.len() {
+        return Ok(None);
+    }
+    let mut out = String::with_capacity(content.len());
+    let mut copied = 0;
+    for (name, span) in result.documents.iter().zip(&result.document_spans) {
+        let (Some(between), Some(doc)) =
+            (content.get(copied..span.start), content.get(span.clone()))
+        else {
+            return Ok(None);
+        };
+        let mut yaml: Value = serde_yaml_ng::from_str(doc)?;
+        apply_results(&mut yaml, result, Some(name), round);
+        out.push_str(between);
+        out.push_str(&serde_yaml_ng::to_string(&yaml)?);
+        copied = span.end;
+    }
+    out.push_str(&content[copied..]);
+    Ok(Some(out))
+}
+
+/// Update a YAML document with calculated table values and scalars
+///
+/// For a document of a multi-document file, `doc` is its name and only
+/// results prefixed with it (`doc.table`, `doc.scalar`) are applied.
+fn apply_results(yaml: &mut Value, result: &ParsedModel, doc: Option<&str>, round: Option<u32>) {
+    // Only formula results are rounded; inputs keep the precision they were entered with
+    let present = |v: f64, computed: bool| match round {
+        Some(decimals) if computed => round_to(v, decimals),
+        _ => v,
+    };
 
     // Update table value arrays
-    if let Value::Mapping(ref mut root) = yaml {
+    if let Value::Mapping(root) = &mut *yaml {
         for (table_name, table) in &result.tables {
-            if let Some(Value::Mapping(table_map)) = root.get_mut(Value::String(table_name.clone()))
+            let Some(table_name) = local_name(table_name, doc) else {
+                continue;
+            };
+            if let Some(Value::Mapping(table_map)) =
+                root.get_mut(Value::String(table_name.to_string()))
             {
                 // Look for "value" column and update it
                 if let Some(col) = table.columns.get("value") {
+                    let computed = table.row_formulas.contains_key("value");
                     if let ColumnValue::Number(values) = &col.values {
                         let yaml_values: Vec<Value> = values
                             .iter()
-                            .map(|v| {
+                            .map(|&v| {
+                                if ErrorKind::from_nan(v).is_some() {
+                                    return number_to_yaml(v);
+                                }
+                                let v = present(v, computed);
                                 // Format nicely: remove unnecessary decimal places
                                 #[allow(clippy::cast_possible_truncation)]
                                 // guarded by v.abs() < 1e10 check
                                 if v.fract() == 0.0 && v.abs() < 1e10 {
-                                    Value::Number(serde_yaml_ng::Number::from(*v as i64))
+                                    Value::Number(serde_yaml_ng::Number::from(v as i64))
                                 } else {
-                                    Value::Number(serde_yaml_ng::Number::from(*v))
+                                    Value::Number(serde_yaml_ng::Number::from(v))
                                 }
                             })
                             .collect();
@@ -88,17 +192,41 @@ pub fn write_calculated_results(path: &Path, result: &ParsedModel) -> ForgeResul
 
         // Update scalar values
         for (name, var) in &result.scalars {
-            if let Some(value) = var.value {
-                update_value_in_yaml(&mut yaml, name, value);
+            if let (Some(name), Some(value)) = (local_name(name, doc), var.value) {
+                update_value_in_yaml(yaml, name, present(value, var.formula.is_some()));
             }
         }
     }
+}
 
-    // Write back to file
-    let updated_content = serde_yaml_ng::to_string(&yaml)?;
-    fs::write(path, updated_content)?;
+/// Name of a result within document `doc` (`doc.revenue` -> `revenue`), or
+/// `None` if it belongs to another document
+fn local_name<'a>(name: &'a str, doc: Option<&str>) -> Option<&'a str> {
+    match doc {
+        Some(doc) => name.strip_prefix(doc)?.strip_prefix('.'),
+        None => Some(name),
+    }
+}
 
-    Ok(true)
+/// Round to a fixed number of decimal places
+fn round_to(value: f64, decimals: u32) -> f64 {
+    let factor = 10f64.powi(i32::try_from(decimals).unwrap_or(i32::MAX));
+    let rounded = (value * factor).round() / factor;
+    // Very large values or decimal counts overflow the scaling; keep the original
+    if rounded.is_finite() {
+        rounded
+    } else {
+        value
+    }
+}
+
+/// YAML for a calculated number; an Excel error value (see
+/// [`ErrorKind::from_nan`]) is written as its code, e.g. `#DIV/0!`
+fn number_to_yaml(value: f64) -> Value {
+    ErrorKind::from_nan(value).map_or_else(
+        || Value::Number(serde_yaml_ng::Number::from(value)),
+        |kind| Value::String(kind.code().to_string()),
+    )
 }
 
 /// Update scalar values in a model file
@@ -143,7 +271,7 @@ fn update_value_recursive(yaml: &mut Value, path_parts: &[&str], index: usize, n
                 if inner_map.contains_key(Value::String("value".to_string())) {
                     inner_map.insert(
                         Value::String("value".to_string()),
-                        Value::Number(serde_yaml_ng::Number::from(new_value)),
+                        number_to_yaml(new_value),
                     );
                 }
             }
@@ -263,6 +391,51 @@ revenue:
         assert!(!result, "Multi-doc YAML should be skipped");
     }
 
+    #[test]
+    fn test_write_calculated_results_multidoc_round_trip() {
+        use crate::core::ArrayCalculator;
+        use crate::parser::parse_model;
+
+        let yaml_content = r#"# Pricing and volume
+---
+_forge_version: "5.0.0"
+_name: "pricing"
+price:
+  value: 10
+total:
+  value: null
+  formula: "=price * 3"
+---
+_forge_version: "5.0.0"
+_name: "volume"
+units:
+  value: 4
+doubled:
+  value: 0
+  formula: "=units * 2"
+"#;
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+        let path = temp_file.path();
+
+        let model = parse_model(path).unwrap();
+        let calculated = ArrayCalculator::new(model).calculate_all().unwrap();
+        assert!(write_calculated_results(path, &calculated).unwrap());
+
+        let written = fs::read_to_string(path).unwrap();
+        assert!(
+            written.starts_with("# Pricing and volume\n---\n"),
+            "{written}"
+        );
+        let reparsed = parse_model(path).unwrap();
+        assert_eq!(reparsed.documents, vec!["pricing", "volume"]);
+        assert_eq!(reparsed.scalars["pricing.total"].value, Some(30.0));
+        assert_eq!(reparsed.scalars["volume.doubled"].value, Some(8.0));
+        assert_eq!(reparsed.scalars["volume.units"].value, Some(4.0));
+
+        let _ = fs::remove_file(path.with_extension("yaml.bak"));
+    }
+
     #[test]
     fn test_write_calculated_results_creates_backup() {
         use crate::types::ParsedModel;
@@ -291,6 +464,25 @@ test_scalar:
         let _ = fs::remove_file(backup_path);
     }
 
+    #[test]
+    fn test_write_calculated_results_to_separate_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let source = dir.path().join("model.yaml");
+        let dest = dir.path().join("out.yaml");
+        let original = "price:\n  value: 1.0\n  formula: null\n";
+        fs::write(&source, original).unwrap();
+
+        let mut model = ParsedModel::new();
+        let var = Variable::new("price".to_string(), Some(42.0), None);
+        model.scalars.insert("price".to_string(), var);
+        assert!(write_calculated_results_to(&source, &dest, &model, None).unwrap());
+
+        // Source untouched and not backed up; results land in the destination
+        assert_eq!(fs::read_to_string(&source).unwrap(), original);
+        assert!(!source.with_extension("yaml.bak").exists());
+        assert!(fs::read_to_string(&dest).unwrap().contains("42"));
+    }
+
     #[test]
     fn test_write_calculated_results_with_scalars() {
         use crate::types::ParsedModel;
@@ -350,6 +542,42 @@ financials:
         let _ = fs::remove_file(path.with_extension("yaml.bak"));
     }
 
+    #[test]
+    fn test_write_results_error_values_as_codes() {
+        use crate::types::{Column, ColumnValue, ParsedModel, Table};
+
+        let yaml_content = r#"
+ratio:
+  value: 0
+  formula: "=1/0"
+financials:
+  value: [0, 0]
+"#;
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+        let path = temp_file.path();
+
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("financials".to_string());
+        table.add_column(Column::new(
+            "value".to_string(),
+            ColumnValue::Number(vec![ErrorKind::NA.to_nan(), 2.0]),
+        ));
+        model.tables.insert("financials".to_string(), table);
+        let mut ratio = Variable::new("ratio".to_string(), None, Some("=1/0".to_string()));
+        ratio.value = Some(ErrorKind::DivZero.to_nan());
+        model.scalars.insert("ratio".to_string(), ratio);
+
+        write_calculated_results(path, &model).unwrap();
+
+        let updated: Value = serde_yaml_ng::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(updated["ratio"]["value"].as_str(), Some("#DIV/0!"));
+        assert_eq!(updated["financials"]["value"][0].as_str(), Some("#N/A"));
+        assert_eq!(updated["financials"]["value"][1].as_i64(), Some(2));
+
+        let _ = fs::remove_file(path.with_extension("yaml.bak"));
+    }
+
     #[test]
     fn test_update_value_empty_path() {
         let mut yaml: Value = serde_yaml_ng::from_str("test: 1").unwrap();
@@ -418,6 +646,91 @@ rate:
         let _ = fs::remove_file(path.with_extension("yaml.bak"));
     }
 
+    #[test]
+    fn test_write_results_rounded() {
+        use crate::types::{Column, ColumnValue, ParsedModel, Table};
+
+        let yaml_content = r"
+margin:
+  value: 0.0
+ledger:
+  value: [0, 0]
+";
+        let mut model = ParsedModel::new();
+        let var = Variable::new(
+            "margin".to_string(),
+            Some(1_234.567_890_12),
+            Some("=1234.56789012".to_string()),
+        );
+        model.scalars.insert("margin".to_string(), var);
+        let mut table = Table::new("ledger".to_string());
+        table.add_column(Column::new(
+            "value".to_string(),
+            ColumnValue::Number(vec![1.006_6, 2.0]),
+        ));
+        table.add_row_formula("value".to_string(), "=1.0066".to_string());
+        model.tables.insert("ledger".to_string(), table);
+
+        let mut rounded_file = NamedTempFile::new().unwrap();
+        rounded_file.write_all(yaml_content.as_bytes()).unwrap();
+        write_calculated_results_rounded(rounded_file.path(), &model, Some(2)).unwrap();
+        let rounded = fs::read_to_string(rounded_file.path()).unwrap();
+        assert!(rounded.contains("1234.57"), "{rounded}");
+        assert!(!rounded.contains("1234.567"), "{rounded}");
+        assert!(rounded.contains("1.01"), "{rounded}");
+
+        let mut full_file = NamedTempFile::new().unwrap();
+        full_file.write_all(yaml_content.as_bytes()).unwrap();
+        write_calculated_results(full_file.path(), &model).unwrap();
+        let full = fs::read_to_string(full_file.path()).unwrap();
+        assert!(full.contains("1234.56789012"), "{full}");
+
+        let _ = fs::remove_file(rounded_file.path().with_extension("yaml.bak"));
+        let _ = fs::remove_file(full_file.path().with_extension("yaml.bak"));
+    }
+
+    #[test]
+    fn test_write_results_rounded_keeps_inputs() {
+        use crate::types::{Column, ColumnValue, ParsedModel, Table};
+
+        let yaml_content = r"
+rate:
+  value: 0.123456
+margin:
+  value: 0.0
+ledger:
+  value: [1.23456, 2.5]
+";
+        let mut model = ParsedModel::new();
+        let rate = Variable::new("rate".to_string(), Some(0.123_456), None);
+        model.scalars.insert("rate".to_string(), rate);
+        let margin = Variable::new(
+            "margin".to_string(),
+            Some(12.345_6),
+            Some("=rate * 100".to_string()),
+        );
+        model.scalars.insert("margin".to_string(), margin);
+        let mut table = Table::new("ledger".to_string());
+        table.add_column(Column::new(
+            "value".to_string(),
+            ColumnValue::Number(vec![1.234_56, 2.5]),
+        ));
+        model.tables.insert("ledger".to_string(), table);
+
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(yaml_content.as_bytes()).unwrap();
+        write_calculated_results_rounded(file.path(), &model, Some(1)).unwrap();
+        let written: Value =
+            serde_yaml_ng::from_str(&fs::read_to_string(file.path()).unwrap()).unwrap();
+
+        assert_eq!(written["rate"]["value"].as_f64(), Some(0.123_456));
+        assert_eq!(written["margin"]["value"].as_f64(), Some(12.3));
+        assert_eq!(written["ledger"]["value"][0].as_f64(), Some(1.234_56));
+        assert_eq!(written["ledger"]["value"][1].as_f64(), Some(2.5));
+
+        let _ = fs::remove_file(file.path().with_extension("yaml.bak"));
+    }
+
     #[test]
     fn test_write_results_integer_values() {
         use crate::types::{Column, ColumnValue, ParsedModel, Table};